@@ -137,6 +137,8 @@ pub enum AstKind<'a> {
     TSNullKeyword(&'a TSNullKeyword),
     TSTypeLiteral(&'a TSTypeLiteral<'a>),
     TSTypeReference(&'a TSTypeReference<'a>),
+    TSQualifiedName(&'a TSQualifiedName<'a>),
+    TSTypeQuery(&'a TSTypeQuery<'a>),
     TSUnionType(&'a TSUnionType<'a>),
     TSVoidKeyword(&'a TSVoidKeyword),
 
@@ -153,6 +155,8 @@ pub enum AstKind<'a> {
 
     TSImportEqualsDeclaration(&'a TSImportEqualsDeclaration<'a>),
     TSInterfaceDeclaration(&'a TSInterfaceDeclaration<'a>),
+    TSInterfaceHeritage(&'a TSInterfaceHeritage<'a>),
+    TSClassImplements(&'a TSClassImplements<'a>),
     TSModuleDeclaration(&'a TSModuleDeclaration<'a>),
     TSTypeAliasDeclaration(&'a TSTypeAliasDeclaration<'a>),
     TSTypeAnnotation(&'a TSTypeAnnotation<'a>),
@@ -213,6 +217,8 @@ impl<'a> AstKind<'a> {
             Self::TSIntersectionType(_)
                 | Self::TSLiteralType(_)
                 | Self::TSTypeReference(_)
+                | Self::TSQualifiedName(_)
+                | Self::TSTypeQuery(_)
                 | Self::TSMethodSignature(_)
         )
     }
@@ -441,6 +447,8 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::TSNullKeyword(x) => x.span,
             Self::TSTypeLiteral(x) => x.span,
             Self::TSTypeReference(x) => x.span,
+            Self::TSQualifiedName(x) => x.span,
+            Self::TSTypeQuery(x) => x.span,
             Self::TSUnionType(x) => x.span,
             Self::TSVoidKeyword(x) => x.span,
 
@@ -457,6 +465,8 @@ impl<'a> GetSpan for AstKind<'a> {
 
             Self::TSImportEqualsDeclaration(x) => x.span,
             Self::TSInterfaceDeclaration(x) => x.span,
+            Self::TSInterfaceHeritage(x) => x.span,
+            Self::TSClassImplements(x) => x.span,
             Self::TSModuleDeclaration(x) => x.span,
             Self::TSTypeAliasDeclaration(x) => x.span,
             Self::TSTypeAnnotation(x) => x.span,
@@ -617,6 +627,8 @@ impl<'a> AstKind<'a> {
             Self::TSNullKeyword(_) => "TSNullKeyword".into(),
             Self::TSTypeLiteral(_) => "TSTypeLiteral".into(),
             Self::TSTypeReference(_) => "TSTypeReference".into(),
+            Self::TSQualifiedName(_) => "TSQualifiedName".into(),
+            Self::TSTypeQuery(_) => "TSTypeQuery".into(),
             Self::TSUnionType(_) => "TSUnionType".into(),
             Self::TSVoidKeyword(_) => "TSVoidKeyword".into(),
 
@@ -634,6 +646,8 @@ impl<'a> AstKind<'a> {
 
             Self::TSImportEqualsDeclaration(_) => "TSImportEqualsDeclaration".into(),
             Self::TSInterfaceDeclaration(_) => "TSInterfaceDeclaration".into(),
+            Self::TSInterfaceHeritage(_) => "TSInterfaceHeritage".into(),
+            Self::TSClassImplements(_) => "TSClassImplements".into(),
             Self::TSModuleDeclaration(_) => "TSModuleDeclaration".into(),
             Self::TSTypeAliasDeclaration(_) => "TSTypeAliasDeclaration".into(),
             Self::TSTypeAnnotation(_) => "TSTypeAnnotation".into(),
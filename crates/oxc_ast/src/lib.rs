@@ -29,7 +29,7 @@ pub use crate::{
     ast_kind::AstKind,
     trivia::{Comment, CommentKind, Trivias, TriviasMap},
     visit::Visit,
-    visit_mut::VisitMut,
+    visit_mut::{walk_function_mut, Ancestors, VisitMut},
 };
 
 // After experimenting with two types of boxed enum variants:
@@ -453,6 +453,11 @@ pub trait Visit<'a>: Sized {
         if let Some(super_parameters) = &class.super_type_parameters {
             self.visit_ts_type_parameter_instantiation(super_parameters);
         }
+        if let Some(implements) = &class.implements {
+            for implement in implements {
+                self.visit_ts_class_implements(implement);
+            }
+        }
         self.visit_class_body(&class.body);
         self.leave_node(kind);
         if is_class_expr {
@@ -1497,12 +1502,37 @@ pub trait Visit<'a>: Sized {
         if let Some(parameters) = &decl.type_parameters {
             self.visit_ts_type_parameter_declaration(parameters);
         }
+        if let Some(extends) = &decl.extends {
+            for heritage in extends {
+                self.visit_ts_interface_heritage(heritage);
+            }
+        }
         for signature in &decl.body.body {
             self.visit_ts_signature(signature);
         }
         self.leave_node(kind);
     }
 
+    fn visit_ts_interface_heritage(&mut self, heritage: &TSInterfaceHeritage<'a>) {
+        let kind = AstKind::TSInterfaceHeritage(self.alloc(heritage));
+        self.enter_node(kind);
+        self.visit_expression(&heritage.expression);
+        if let Some(parameters) = &heritage.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_class_implements(&mut self, implements: &TSClassImplements<'a>) {
+        let kind = AstKind::TSClassImplements(self.alloc(implements));
+        self.enter_node(kind);
+        self.visit_ts_type_name(&implements.expression);
+        if let Some(parameters) = &implements.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+        self.leave_node(kind);
+    }
+
     fn visit_ts_as_expression(&mut self, expr: &TSAsExpression<'a>) {
         let kind = AstKind::TSAsExpression(self.alloc(expr));
         self.enter_node(kind);
@@ -1565,6 +1595,7 @@ pub trait Visit<'a>: Sized {
             TSType::TSTypePredicate(ty) => self.visit_ts_type_predicate(ty),
             TSType::TSTypeLiteral(ty) => self.visit_ts_type_literal(ty),
             TSType::TSIndexedAccessType(ty) => self.visit_ts_indexed_access_type(ty),
+            TSType::TSTypeQuery(ty) => self.visit_ts_type_query(ty),
             _ => {}
         }
     }
@@ -1684,10 +1715,27 @@ pub trait Visit<'a>: Sized {
     fn visit_ts_type_name(&mut self, name: &TSTypeName<'a>) {
         match &name {
             TSTypeName::IdentifierReference(ident) => self.visit_identifier_reference(ident),
-            TSTypeName::QualifiedName(_) => {}
+            TSTypeName::QualifiedName(name) => self.visit_ts_qualified_name(name),
         }
     }
 
+    fn visit_ts_qualified_name(&mut self, name: &TSQualifiedName<'a>) {
+        let kind = AstKind::TSQualifiedName(self.alloc(name));
+        self.enter_node(kind);
+        self.visit_ts_type_name(&name.left);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_type_query(&mut self, ty: &TSTypeQuery<'a>) {
+        let kind = AstKind::TSTypeQuery(self.alloc(ty));
+        self.enter_node(kind);
+        self.visit_ts_type_name(&ty.expr_name);
+        if let Some(parameters) = &ty.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+        self.leave_node(kind);
+    }
+
     fn visit_ts_null_keyword(&mut self, ty: &TSNullKeyword) {
         let kind = AstKind::TSNullKeyword(self.alloc(ty));
         self.enter_node(kind);
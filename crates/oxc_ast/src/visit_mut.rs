@@ -6,6 +6,62 @@ use oxc_syntax::scope::ScopeFlags;
 
 use crate::{ast::*, AstKind};
 
+/// The nodes enclosing the node being visited, from the [Program] down to its parent.
+///
+/// ```ignore
+/// struct InJsxAttribute<'a> {
+///     ancestors: Ancestors<'a>,
+/// }
+///
+/// impl<'a> VisitMut<'a> for InJsxAttribute<'a> {
+///     fn ancestors_mut(&mut self) -> Option<&mut Ancestors<'a>> {
+///         Some(&mut self.ancestors)
+///     }
+///
+///     fn visit_string_literal(&mut self, lit: &mut StringLiteral) {
+///         if self.ancestors.is_inside(|kind| matches!(kind, AstKind::JSXAttributeItem(_))) {
+///             // ...
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct Ancestors<'a> {
+    stack: std::vec::Vec<AstKind<'a>>,
+}
+
+impl<'a> Ancestors<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn parent(&self) -> Option<AstKind<'a>> {
+        self.stack.last().copied()
+    }
+
+    /// Ancestors from the parent up to the [Program].
+    pub fn iter(&self) -> impl Iterator<Item = AstKind<'a>> + '_ {
+        self.stack.iter().rev().copied()
+    }
+
+    /// The closest ancestor matching `predicate`.
+    pub fn find<F: Fn(&AstKind<'a>) -> bool>(&self, predicate: F) -> Option<AstKind<'a>> {
+        self.iter().find(|kind| predicate(kind))
+    }
+
+    pub fn is_inside<F: Fn(&AstKind<'a>) -> bool>(&self, predicate: F) -> bool {
+        self.find(predicate).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+}
+
 /// Syntax tree traversal to mutate an exclusive borrow of a syntax tree in place.
 pub trait VisitMut<'a>: Sized {
     fn enter_node(&mut self, _kind: AstKind<'a>) {}
@@ -14,6 +70,27 @@ pub trait VisitMut<'a>: Sized {
     fn enter_scope(&mut self, _flags: ScopeFlags) {}
     fn leave_scope(&mut self) {}
 
+    /// Return the [Ancestors] owned by the visitor to have them maintained during traversal.
+    ///
+    /// Within `enter_node` and `leave_node`, the ancestors are the nodes enclosing the node:
+    /// the node itself is pushed after `enter_node`, before its children are visited, and popped
+    /// after them, before `leave_node`.
+    fn ancestors_mut(&mut self) -> Option<&mut Ancestors<'a>> {
+        None
+    }
+
+    fn push_ancestor(&mut self, kind: AstKind<'a>) {
+        if let Some(ancestors) = self.ancestors_mut() {
+            ancestors.stack.push(kind);
+        }
+    }
+
+    fn pop_ancestor(&mut self) {
+        if let Some(ancestors) = self.ancestors_mut() {
+            ancestors.stack.pop();
+        }
+    }
+
     fn alloc<T>(&self, t: &T) -> &'a T {
         // SAFETY:
         // This should be safe as long as `src` is an reference from the allocator.
@@ -31,11 +108,14 @@ pub trait VisitMut<'a>: Sized {
             flags
         });
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for directive in program.directives.iter_mut() {
             self.visit_directive(directive);
         }
         self.visit_statements(&mut program.body);
 
+        self.pop_ancestor();
+
         self.leave_node(kind);
         self.leave_scope();
     }
@@ -82,7 +162,9 @@ pub trait VisitMut<'a>: Sized {
         let kind = AstKind::BlockStatement(self.alloc(stmt));
         self.enter_scope(ScopeFlags::empty());
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_statements(&mut stmt.body);
+        self.pop_ancestor();
         self.leave_node(kind);
         self.leave_scope();
     }
@@ -90,45 +172,57 @@ pub trait VisitMut<'a>: Sized {
     fn visit_break_statement(&mut self, stmt: &mut BreakStatement) {
         let kind = AstKind::BreakStatement(self.alloc(stmt));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         if let Some(break_target) = &mut stmt.label {
             self.visit_label_identifier(break_target);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_continue_statement(&mut self, stmt: &mut ContinueStatement) {
         let kind = AstKind::ContinueStatement(self.alloc(stmt));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         if let Some(continue_target) = &mut stmt.label {
             self.visit_label_identifier(continue_target);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_debugger_statement(&mut self, stmt: &mut DebuggerStatement) {
         let kind = AstKind::DebuggerStatement(self.alloc(stmt));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_do_while_statement(&mut self, stmt: &mut DoWhileStatement<'a>) {
         let kind = AstKind::DoWhileStatement(self.alloc(stmt));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_statement(&mut stmt.body);
         self.visit_expression(&mut stmt.test);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_empty_statement(&mut self, stmt: &mut EmptyStatement) {
         let kind = AstKind::EmptyStatement(self.alloc(stmt));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_expression_statement(&mut self, stmt: &mut ExpressionStatement<'a>) {
         let kind = AstKind::ExpressionStatement(self.alloc(stmt));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut stmt.expression);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -140,6 +234,7 @@ pub trait VisitMut<'a>: Sized {
             self.enter_scope(ScopeFlags::empty());
         }
         self.enter_node(kind);
+        self.push_ancestor(kind);
         if let Some(init) = &mut stmt.init {
             self.visit_for_statement_init(init);
         }
@@ -150,6 +245,7 @@ pub trait VisitMut<'a>: Sized {
             self.visit_expression(update);
         }
         self.visit_statement(&mut stmt.body);
+        self.pop_ancestor();
         self.leave_node(kind);
         if is_lexical_declaration {
             self.leave_scope();
@@ -159,6 +255,7 @@ pub trait VisitMut<'a>: Sized {
     fn visit_for_statement_init(&mut self, init: &mut ForStatementInit<'a>) {
         let kind = AstKind::ForStatementInit(self.alloc(init));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         match init {
             ForStatementInit::VariableDeclaration(decl) => {
                 self.visit_variable_declaration(decl);
@@ -168,6 +265,7 @@ pub trait VisitMut<'a>: Sized {
                 self.visit_using_declaration(decl);
             }
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -178,9 +276,11 @@ pub trait VisitMut<'a>: Sized {
             self.enter_scope(ScopeFlags::empty());
         }
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_for_statement_left(&mut stmt.left);
         self.visit_expression(&mut stmt.right);
         self.visit_statement(&mut stmt.body);
+        self.pop_ancestor();
         self.leave_node(kind);
         if is_lexical_declaration {
             self.leave_scope();
@@ -194,9 +294,11 @@ pub trait VisitMut<'a>: Sized {
             self.enter_scope(ScopeFlags::empty());
         }
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_for_statement_left(&mut stmt.left);
         self.visit_expression(&mut stmt.right);
         self.visit_statement(&mut stmt.body);
+        self.pop_ancestor();
         self.leave_node(kind);
         if is_lexical_declaration {
             self.leave_scope();
@@ -218,28 +320,34 @@ pub trait VisitMut<'a>: Sized {
     fn visit_if_statement(&mut self, stmt: &mut IfStatement<'a>) {
         let kind = AstKind::IfStatement(self.alloc(stmt));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut stmt.test);
         self.visit_statement(&mut stmt.consequent);
         if let Some(alternate) = &mut stmt.alternate {
             self.visit_statement(alternate);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_labeled_statement(&mut self, stmt: &mut LabeledStatement<'a>) {
         let kind = AstKind::LabeledStatement(self.alloc(stmt));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_label_identifier(&mut stmt.label);
         self.visit_statement(&mut stmt.body);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
         let kind = AstKind::ReturnStatement(self.alloc(stmt));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         if let Some(arg) = &mut stmt.argument {
             self.visit_expression(arg);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -247,10 +355,12 @@ pub trait VisitMut<'a>: Sized {
         let kind = AstKind::SwitchStatement(self.alloc(stmt));
         self.enter_scope(ScopeFlags::empty());
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut stmt.discriminant);
         for case in stmt.cases.iter_mut() {
             self.visit_switch_case(case);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
         self.leave_scope();
     }
@@ -258,23 +368,28 @@ pub trait VisitMut<'a>: Sized {
     fn visit_switch_case(&mut self, case: &mut SwitchCase<'a>) {
         let kind = AstKind::SwitchCase(self.alloc(case));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         if let Some(expr) = &mut case.test {
             self.visit_expression(expr);
         }
         self.visit_statements(&mut case.consequent);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_throw_statement(&mut self, stmt: &mut ThrowStatement<'a>) {
         let kind = AstKind::ThrowStatement(self.alloc(stmt));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut stmt.argument);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_try_statement(&mut self, stmt: &mut TryStatement<'a>) {
         let kind = AstKind::TryStatement(self.alloc(stmt));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_block_statement(&mut stmt.block);
         if let Some(handler) = &mut stmt.handler {
             self.visit_catch_clause(handler);
@@ -282,6 +397,7 @@ pub trait VisitMut<'a>: Sized {
         if let Some(finalizer) = &mut stmt.finalizer {
             self.visit_finally_clause(finalizer);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -289,10 +405,12 @@ pub trait VisitMut<'a>: Sized {
         let kind = AstKind::CatchClause(self.alloc(clause));
         self.enter_scope(ScopeFlags::empty());
         self.enter_node(kind);
+        self.push_ancestor(kind);
         if let Some(param) = &mut clause.param {
             self.visit_binding_pattern(param);
         }
         self.visit_statements(&mut clause.body.body);
+        self.pop_ancestor();
         self.leave_node(kind);
         self.leave_scope();
     }
@@ -300,30 +418,38 @@ pub trait VisitMut<'a>: Sized {
     fn visit_finally_clause(&mut self, clause: &mut BlockStatement<'a>) {
         let kind = AstKind::FinallyClause(self.alloc(clause));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_block_statement(clause);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_while_statement(&mut self, stmt: &mut WhileStatement<'a>) {
         let kind = AstKind::WhileStatement(self.alloc(stmt));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut stmt.test);
         self.visit_statement(&mut stmt.body);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_with_statement(&mut self, stmt: &mut WithStatement<'a>) {
         let kind = AstKind::WithStatement(self.alloc(stmt));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut stmt.object);
         self.visit_statement(&mut stmt.body);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_directive(&mut self, directive: &mut Directive) {
         let kind = AstKind::Directive(self.alloc(directive));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_string_literal(&mut directive.expression);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -332,89 +458,78 @@ pub trait VisitMut<'a>: Sized {
     fn visit_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
         let kind = AstKind::VariableDeclaration(self.alloc(decl));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for declarator in decl.declarations.iter_mut() {
             self.visit_variable_declarator(declarator);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_variable_declarator(&mut self, declarator: &mut VariableDeclarator<'a>) {
         let kind = AstKind::VariableDeclarator(self.alloc(declarator));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_binding_pattern(&mut declarator.id);
         if let Some(init) = &mut declarator.init {
             self.visit_expression(init);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_using_declaration(&mut self, declaration: &mut UsingDeclaration<'a>) {
         let kind = AstKind::UsingDeclaration(self.alloc(declaration));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for decl in declaration.declarations.iter_mut() {
             self.visit_variable_declarator(decl);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     /* ----------  Function ---------- */
 
     fn visit_function(&mut self, func: &mut Function<'a>, flags: Option<ScopeFlags>) {
-        let kind = AstKind::Function(self.alloc(func));
-        self.enter_scope({
-            let mut flags = flags.unwrap_or(ScopeFlags::empty()) | ScopeFlags::Function;
-            if func.is_strict() {
-                flags |= ScopeFlags::StrictMode;
-            }
-            flags
-        });
-        self.enter_node(kind);
-        if let Some(ident) = &mut func.id {
-            self.visit_binding_identifier(ident);
-        }
-        self.visit_formal_parameters(&mut func.params);
-        if let Some(body) = &mut func.body {
-            self.visit_function_body(body);
-        }
-        if let Some(parameters) = &mut func.type_parameters {
-            self.visit_ts_type_parameter_declaration(parameters);
-        }
-        if let Some(annotation) = &mut func.return_type {
-            self.visit_ts_type_annotation(annotation);
-        }
-        self.leave_node(kind);
-        self.leave_scope();
+        walk_function_mut(self, func, flags);
     }
 
     fn visit_function_body(&mut self, body: &mut FunctionBody<'a>) {
         let kind = AstKind::FunctionBody(self.alloc(body));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for directive in body.directives.iter_mut() {
             self.visit_directive(directive);
         }
         self.visit_statements(&mut body.statements);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_formal_parameters(&mut self, params: &mut FormalParameters<'a>) {
         let kind = AstKind::FormalParameters(self.alloc(params));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for param in params.items.iter_mut() {
             self.visit_formal_parameter(param);
         }
         if let Some(rest) = &mut params.rest {
             self.visit_rest_element(rest);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_formal_parameter(&mut self, param: &mut FormalParameter<'a>) {
         let kind = AstKind::FormalParameter(self.alloc(param));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for decorator in param.decorators.iter_mut() {
             self.visit_decorator(decorator);
         }
         self.visit_binding_pattern(&mut param.pattern);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -423,7 +538,9 @@ pub trait VisitMut<'a>: Sized {
     fn visit_decorator(&mut self, decorator: &mut Decorator<'a>) {
         let kind = AstKind::Decorator(self.alloc(decorator));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut decorator.expression);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -443,6 +560,8 @@ pub trait VisitMut<'a>: Sized {
         }
 
         self.enter_node(kind);
+
+        self.push_ancestor(kind);
         if let Some(id) = &mut class.id {
             self.visit_binding_identifier(id);
         }
@@ -457,6 +576,7 @@ pub trait VisitMut<'a>: Sized {
             self.visit_ts_type_parameter_instantiation(super_parameters);
         }
         self.visit_class_body(&mut class.body);
+        self.pop_ancestor();
         self.leave_node(kind);
         if is_class_expr {
             self.leave_scope();
@@ -466,7 +586,9 @@ pub trait VisitMut<'a>: Sized {
     fn visit_class_heritage(&mut self, expr: &mut Expression<'a>) {
         let kind = AstKind::ClassHeritage(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(expr);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -496,7 +618,9 @@ pub trait VisitMut<'a>: Sized {
         let kind = AstKind::StaticBlock(self.alloc(block));
         self.enter_scope(ScopeFlags::ClassStaticBlock);
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_statements(&mut block.body);
+        self.pop_ancestor();
         self.leave_node(kind);
         self.leave_scope();
     }
@@ -504,6 +628,7 @@ pub trait VisitMut<'a>: Sized {
     fn visit_method_definition(&mut self, def: &mut MethodDefinition<'a>) {
         let kind = AstKind::MethodDefinition(self.alloc(def));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for decorator in def.decorators.iter_mut() {
             self.visit_decorator(decorator);
         }
@@ -516,12 +641,14 @@ pub trait VisitMut<'a>: Sized {
         };
         self.visit_property_key(&mut def.key);
         self.visit_function(&mut def.value, Some(flags));
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_property_definition(&mut self, def: &mut PropertyDefinition<'a>) {
         let kind = AstKind::PropertyDefinition(self.alloc(def));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for decorator in def.decorators.iter_mut() {
             self.visit_decorator(decorator);
         }
@@ -532,6 +659,7 @@ pub trait VisitMut<'a>: Sized {
         if let Some(annotation) = &mut def.type_annotation {
             self.visit_ts_type_annotation(annotation);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -598,64 +726,80 @@ pub trait VisitMut<'a>: Sized {
     fn visit_meta_property(&mut self, meta: &mut MetaProperty) {
         let kind = AstKind::MetaProperty(self.alloc(meta));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_array_expression(&mut self, expr: &mut ArrayExpression<'a>) {
         let kind = AstKind::ArrayExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for elem in expr.elements.iter_mut() {
             self.visit_array_expression_element(elem);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_array_expression_element(&mut self, arg: &mut ArrayExpressionElement<'a>) {
         let kind = AstKind::ArrayExpressionElement(self.alloc(arg));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         match arg {
             ArrayExpressionElement::SpreadElement(spread) => self.visit_spread_element(spread),
             ArrayExpressionElement::Expression(expr) => self.visit_expression_array_element(expr),
             ArrayExpressionElement::Elision(span) => self.visit_elision(*span),
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_argument(&mut self, arg: &mut Argument<'a>) {
         let kind = AstKind::Argument(self.alloc(arg));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         match arg {
             Argument::SpreadElement(spread) => self.visit_spread_element(spread),
             Argument::Expression(expr) => self.visit_expression(expr),
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_spread_element(&mut self, elem: &mut SpreadElement<'a>) {
         let kind = AstKind::SpreadElement(self.alloc(elem));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut elem.argument);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_expression_array_element(&mut self, expr: &mut Expression<'a>) {
         let kind = AstKind::ExpressionArrayElement(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(expr);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_elision(&mut self, span: Span) {
         let kind = AstKind::Elision(span);
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_assignment_expression(&mut self, expr: &mut AssignmentExpression<'a>) {
         let kind = AstKind::AssignmentExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_assignment_target(&mut expr.left);
         self.visit_expression(&mut expr.right);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -663,11 +807,13 @@ pub trait VisitMut<'a>: Sized {
         let kind = AstKind::ArrowExpression(self.alloc(expr));
         self.enter_scope(ScopeFlags::Function | ScopeFlags::Arrow);
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_formal_parameters(&mut expr.params);
         self.visit_function_body(&mut expr.body);
         if let Some(parameters) = &mut expr.type_parameters {
             self.visit_ts_type_parameter_declaration(parameters);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
         self.leave_scope();
     }
@@ -675,21 +821,26 @@ pub trait VisitMut<'a>: Sized {
     fn visit_await_expression(&mut self, expr: &mut AwaitExpression<'a>) {
         let kind = AstKind::AwaitExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut expr.argument);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_binary_expression(&mut self, expr: &mut BinaryExpression<'a>) {
         let kind = AstKind::BinaryExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut expr.left);
         self.visit_expression(&mut expr.right);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_call_expression(&mut self, expr: &mut CallExpression<'a>) {
         let kind = AstKind::CallExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for arg in expr.arguments.iter_mut() {
             self.visit_argument(arg);
         }
@@ -697,13 +848,16 @@ pub trait VisitMut<'a>: Sized {
         if let Some(parameters) = &mut expr.type_parameters {
             self.visit_ts_type_parameter_instantiation(parameters);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_chain_expression(&mut self, expr: &mut ChainExpression<'a>) {
         let kind = AstKind::ChainExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_chain_element(&mut expr.expression);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -717,9 +871,11 @@ pub trait VisitMut<'a>: Sized {
     fn visit_conditional_expression(&mut self, expr: &mut ConditionalExpression<'a>) {
         let kind = AstKind::ConditionalExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut expr.test);
         self.visit_expression(&mut expr.consequent);
         self.visit_expression(&mut expr.alternate);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -733,14 +889,17 @@ pub trait VisitMut<'a>: Sized {
     fn visit_logical_expression(&mut self, expr: &mut LogicalExpression<'a>) {
         let kind = AstKind::LogicalExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut expr.left);
         self.visit_expression(&mut expr.right);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_member_expression(&mut self, expr: &mut MemberExpression<'a>) {
         let kind = AstKind::MemberExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         match expr {
             MemberExpression::ComputedMemberExpression(expr) => {
                 self.visit_computed_member_expression(expr);
@@ -752,6 +911,7 @@ pub trait VisitMut<'a>: Sized {
                 self.visit_private_field_expression(expr);
             }
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -773,6 +933,7 @@ pub trait VisitMut<'a>: Sized {
     fn visit_new_expression(&mut self, expr: &mut NewExpression<'a>) {
         let kind = AstKind::NewExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut expr.callee);
         if let Some(parameters) = &mut expr.type_parameters {
             self.visit_ts_type_parameter_instantiation(parameters);
@@ -780,15 +941,18 @@ pub trait VisitMut<'a>: Sized {
         for arg in expr.arguments.iter_mut() {
             self.visit_argument(arg);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_object_expression(&mut self, expr: &mut ObjectExpression<'a>) {
         let kind = AstKind::ObjectExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for prop in expr.properties.iter_mut() {
             self.visit_object_property_kind(prop);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -802,26 +966,32 @@ pub trait VisitMut<'a>: Sized {
     fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
         let kind = AstKind::ObjectProperty(self.alloc(prop));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_property_key(&mut prop.key);
         self.visit_expression(&mut prop.value);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_property_key(&mut self, key: &mut PropertyKey<'a>) {
         let kind = AstKind::PropertyKey(self.alloc(key));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         match key {
             PropertyKey::Identifier(ident) => self.visit_identifier_name(ident),
             PropertyKey::PrivateIdentifier(ident) => self.visit_private_identifier(ident),
             PropertyKey::Expression(expr) => self.visit_expression(expr),
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_parenthesized_expression(&mut self, expr: &mut ParenthesizedExpression<'a>) {
         let kind = AstKind::ParenthesizedExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut expr.expression);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -833,58 +1003,73 @@ pub trait VisitMut<'a>: Sized {
     fn visit_sequence_expression(&mut self, expr: &mut SequenceExpression<'a>) {
         let kind = AstKind::SequenceExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for expr in expr.expressions.iter_mut() {
             self.visit_expression(expr);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_tagged_template_expression(&mut self, expr: &mut TaggedTemplateExpression<'a>) {
         let kind = AstKind::TaggedTemplateExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut expr.tag);
         self.visit_template_literal(&mut expr.quasi);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_this_expression(&mut self, expr: &mut ThisExpression) {
         let kind = AstKind::ThisExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_unary_expression(&mut self, expr: &mut UnaryExpression<'a>) {
         let kind = AstKind::UnaryExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut expr.argument);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_update_expression(&mut self, expr: &mut UpdateExpression<'a>) {
         let kind = AstKind::UpdateExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_simple_assignment_target(&mut expr.argument);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_yield_expression(&mut self, expr: &mut YieldExpression<'a>) {
         let kind = AstKind::YieldExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         if let Some(argument) = &mut expr.argument {
             self.visit_expression(argument);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_super(&mut self, expr: &mut Super) {
         let kind = AstKind::Super(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_assignment_target(&mut self, target: &mut AssignmentTarget<'a>) {
         let kind = AstKind::AssignmentTarget(self.alloc(target));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         match target {
             AssignmentTarget::SimpleAssignmentTarget(target) => {
                 self.visit_simple_assignment_target(target);
@@ -893,12 +1078,14 @@ pub trait VisitMut<'a>: Sized {
                 self.visit_assignment_target_pattern(pat);
             }
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
         let kind = AstKind::SimpleAssignmentTarget(self.alloc(target));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         match target {
             SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
                 self.visit_identifier_reference(ident);
@@ -919,6 +1106,7 @@ pub trait VisitMut<'a>: Sized {
                 self.visit_expression(&mut expr.expression);
             }
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -962,8 +1150,10 @@ pub trait VisitMut<'a>: Sized {
     ) {
         let kind = AstKind::AssignmentTargetWithDefault(self.alloc(target));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_assignment_target(&mut target.binding);
         self.visit_expression(&mut target.init);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1010,6 +1200,7 @@ pub trait VisitMut<'a>: Sized {
     fn visit_jsx_element(&mut self, elem: &mut JSXElement<'a>) {
         let kind = AstKind::JSXElement(self.alloc(elem));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_jsx_opening_element(&mut elem.opening_element);
         for child in elem.children.iter_mut() {
             self.visit_jsx_child(child);
@@ -1017,81 +1208,98 @@ pub trait VisitMut<'a>: Sized {
         if let Some(closing_elem) = &mut elem.closing_element {
             self.visit_jsx_closing_element(closing_elem);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_jsx_opening_element(&mut self, elem: &mut JSXOpeningElement<'a>) {
         let kind = AstKind::JSXOpeningElement(self.alloc(elem));
         self.enter_node(kind);
+        self.push_ancestor(kind);
 
         self.visit_jsx_element_name(&mut elem.name);
         for attribute in elem.attributes.iter_mut() {
             self.visit_jsx_attribute_item(attribute);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_jsx_closing_element(&mut self, elem: &mut JSXClosingElement<'a>) {
         let kind = AstKind::JSXClosingElement(self.alloc(elem));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_jsx_element_name(&mut elem.name);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_jsx_element_name(&mut self, name: &mut JSXElementName<'a>) {
         let kind = AstKind::JSXElementName(self.alloc(name));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         match name {
             JSXElementName::Identifier(ident) => self.visit_jsx_identifier(ident),
             JSXElementName::MemberExpression(expr) => self.visit_jsx_member_expression(expr),
             JSXElementName::NamespacedName(name) => self.visit_jsx_namespaced_name(name),
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_jsx_identifier(&mut self, ident: &mut JSXIdentifier) {
         let kind = AstKind::JSXIdentifier(self.alloc(ident));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_jsx_member_expression(&mut self, expr: &mut JSXMemberExpression<'a>) {
         let kind = AstKind::JSXMemberExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_jsx_member_expression_object(&mut expr.object);
         self.visit_jsx_identifier(&mut expr.property);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_jsx_member_expression_object(&mut self, expr: &mut JSXMemberExpressionObject<'a>) {
         let kind = AstKind::JSXMemberExpressionObject(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         match expr {
             JSXMemberExpressionObject::Identifier(ident) => self.visit_jsx_identifier(ident),
             JSXMemberExpressionObject::MemberExpression(expr) => {
                 self.visit_jsx_member_expression(expr);
             }
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_jsx_namespaced_name(&mut self, name: &mut JSXNamespacedName) {
         let kind = AstKind::JSXNamespacedName(self.alloc(name));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_jsx_identifier(&mut name.namespace);
         self.visit_jsx_identifier(&mut name.property);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_jsx_attribute_item(&mut self, item: &mut JSXAttributeItem<'a>) {
         let kind = AstKind::JSXAttributeItem(self.alloc(item));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         match item {
             JSXAttributeItem::Attribute(attribute) => self.visit_jsx_attribute(attribute),
             JSXAttributeItem::SpreadAttribute(attribute) => {
                 self.visit_jsx_spread_attribute(attribute);
             }
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1119,7 +1327,9 @@ pub trait VisitMut<'a>: Sized {
     fn visit_jsx_expression_container(&mut self, expr: &mut JSXExpressionContainer<'a>) {
         let kind = AstKind::JSXExpressionContainer(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_jsx_expression(&mut expr.expression);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1133,9 +1343,11 @@ pub trait VisitMut<'a>: Sized {
     fn visit_jsx_fragment(&mut self, elem: &mut JSXFragment<'a>) {
         let kind = AstKind::JSXFragment(self.alloc(elem));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for child in elem.children.iter_mut() {
             self.visit_jsx_child(child);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1156,6 +1368,8 @@ pub trait VisitMut<'a>: Sized {
     fn visit_jsx_text(&mut self, child: &JSXText) {
         let kind = AstKind::JSXText(self.alloc(child));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1178,18 +1392,22 @@ pub trait VisitMut<'a>: Sized {
     fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier) {
         let kind = AstKind::BindingIdentifier(self.alloc(ident));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_object_pattern(&mut self, pat: &mut ObjectPattern<'a>) {
         let kind = AstKind::ObjectPattern(self.alloc(pat));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for prop in pat.properties.iter_mut() {
             self.visit_binding_property(prop);
         }
         if let Some(rest) = &mut pat.rest {
             self.visit_rest_element(rest);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1201,27 +1419,33 @@ pub trait VisitMut<'a>: Sized {
     fn visit_array_pattern(&mut self, pat: &mut ArrayPattern<'a>) {
         let kind = AstKind::ArrayPattern(self.alloc(pat));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for pat in pat.elements.iter_mut().flatten() {
             self.visit_binding_pattern(pat);
         }
         if let Some(rest) = &mut pat.rest {
             self.visit_rest_element(rest);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_rest_element(&mut self, pat: &mut RestElement<'a>) {
         let kind = AstKind::RestElement(self.alloc(pat));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_binding_pattern(&mut pat.argument);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_assignment_pattern(&mut self, pat: &mut AssignmentPattern<'a>) {
         let kind = AstKind::AssignmentPattern(self.alloc(pat));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_binding_pattern(&mut pat.left);
         self.visit_expression(&mut pat.right);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1230,24 +1454,32 @@ pub trait VisitMut<'a>: Sized {
     fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference) {
         let kind = AstKind::IdentifierReference(self.alloc(ident));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_private_identifier(&mut self, ident: &mut PrivateIdentifier) {
         let kind = AstKind::PrivateIdentifier(self.alloc(ident));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_label_identifier(&mut self, ident: &mut LabelIdentifier) {
         let kind = AstKind::LabelIdentifier(self.alloc(ident));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_identifier_name(&mut self, ident: &mut IdentifierName) {
         let kind = AstKind::IdentifierName(self.alloc(ident));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1256,48 +1488,62 @@ pub trait VisitMut<'a>: Sized {
     fn visit_number_literal(&mut self, lit: &mut NumberLiteral<'a>) {
         let kind = AstKind::NumberLiteral(self.alloc(lit));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_boolean_literal(&mut self, lit: &mut BooleanLiteral) {
         let kind = AstKind::BooleanLiteral(self.alloc(lit));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_null_literal(&mut self, lit: &mut NullLiteral) {
         let kind = AstKind::NullLiteral(self.alloc(lit));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_bigint_literal(&mut self, lit: &mut BigintLiteral) {
         let kind = AstKind::BigintLiteral(self.alloc(lit));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_string_literal(&mut self, lit: &mut StringLiteral) {
         let kind = AstKind::StringLiteral(self.alloc(lit));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_template_literal(&mut self, lit: &mut TemplateLiteral<'a>) {
         let kind = AstKind::TemplateLiteral(self.alloc(lit));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for elem in lit.quasis.iter_mut() {
             self.visit_template_element(elem);
         }
         for expr in lit.expressions.iter_mut() {
             self.visit_expression(expr);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_reg_expr_literal(&mut self, lit: &mut RegExpLiteral) {
         let kind = AstKind::RegExpLiteral(self.alloc(lit));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1308,6 +1554,7 @@ pub trait VisitMut<'a>: Sized {
     fn visit_module_declaration(&mut self, decl: &mut ModuleDeclaration<'a>) {
         let kind = AstKind::ModuleDeclaration(self.alloc(decl));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         match decl {
             ModuleDeclaration::ImportDeclaration(decl) => {
                 self.visit_import_declaration(decl);
@@ -1326,6 +1573,7 @@ pub trait VisitMut<'a>: Sized {
             }
             ModuleDeclaration::TSNamespaceExportDeclaration(_) => {}
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1388,9 +1636,11 @@ pub trait VisitMut<'a>: Sized {
     fn visit_enum_member(&mut self, member: &mut TSEnumMember<'a>) {
         let kind = AstKind::TSEnumMember(self.alloc(member));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         if let Some(initializer) = &mut member.initializer {
             self.visit_expression(initializer);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1398,9 +1648,11 @@ pub trait VisitMut<'a>: Sized {
         let kind = AstKind::TSEnumBody(self.alloc(body));
         self.enter_scope(ScopeFlags::empty());
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for member in body.members.iter_mut() {
             self.visit_enum_member(member);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
         self.leave_scope();
     }
@@ -1408,8 +1660,10 @@ pub trait VisitMut<'a>: Sized {
     fn visit_enum(&mut self, decl: &mut TSEnumDeclaration<'a>) {
         let kind = AstKind::TSEnumDeclaration(self.alloc(decl));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_binding_identifier(&mut decl.id);
         self.visit_enum_body(&mut decl.body);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1442,13 +1696,16 @@ pub trait VisitMut<'a>: Sized {
     fn visit_ts_import_equals_declaration(&mut self, decl: &mut TSImportEqualsDeclaration<'a>) {
         let kind = AstKind::TSImportEqualsDeclaration(self.alloc(decl));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_binding_identifier(&mut decl.id);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_ts_module_declaration(&mut self, decl: &mut TSModuleDeclaration<'a>) {
         let kind = AstKind::TSModuleDeclaration(self.alloc(decl));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         match &mut decl.id {
             TSModuleDeclarationName::Identifier(ident) => self.visit_identifier_name(ident),
             TSModuleDeclarationName::StringLiteral(lit) => self.visit_string_literal(lit),
@@ -1459,6 +1716,7 @@ pub trait VisitMut<'a>: Sized {
             }
             TSModuleDeclarationBody::TSModuleBlock(block) => self.visit_ts_module_block(block),
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1466,7 +1724,9 @@ pub trait VisitMut<'a>: Sized {
         let kind = AstKind::TSModuleBlock(self.alloc(block));
         self.enter_scope(ScopeFlags::TsModuleBlock);
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_statements(&mut block.body);
+        self.pop_ancestor();
         self.leave_node(kind);
         self.leave_scope();
     }
@@ -1474,17 +1734,20 @@ pub trait VisitMut<'a>: Sized {
     fn visit_ts_type_alias_declaration(&mut self, decl: &mut TSTypeAliasDeclaration<'a>) {
         let kind = AstKind::TSTypeAliasDeclaration(self.alloc(decl));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_binding_identifier(&mut decl.id);
         if let Some(parameters) = &mut decl.type_parameters {
             self.visit_ts_type_parameter_declaration(parameters);
         }
         self.visit_ts_type(&mut decl.type_annotation);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_ts_interface_declaration(&mut self, decl: &mut TSInterfaceDeclaration<'a>) {
         let kind = AstKind::TSInterfaceDeclaration(self.alloc(decl));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_binding_identifier(&mut decl.id);
         if let Some(parameters) = &mut decl.type_parameters {
             self.visit_ts_type_parameter_declaration(parameters);
@@ -1492,37 +1755,46 @@ pub trait VisitMut<'a>: Sized {
         for signature in decl.body.body.iter_mut() {
             self.visit_ts_signature(signature);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_ts_as_expression(&mut self, expr: &mut TSAsExpression<'a>) {
         let kind = AstKind::TSAsExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut expr.expression);
         self.visit_ts_type(&mut expr.type_annotation);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_ts_satisfies_expression(&mut self, expr: &mut TSSatisfiesExpression<'a>) {
         let kind = AstKind::TSSatisfiesExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut expr.expression);
         self.visit_ts_type(&mut expr.type_annotation);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_ts_non_null_expression(&mut self, expr: &mut TSNonNullExpression<'a>) {
         let kind = AstKind::TSNonNullExpression(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut expr.expression);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_ts_type_assertion(&mut self, expr: &mut TSTypeAssertion<'a>) {
         let kind = AstKind::TSTypeAssertion(self.alloc(expr));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_expression(&mut expr.expression);
         self.visit_ts_type(&mut expr.type_annotation);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1534,7 +1806,9 @@ pub trait VisitMut<'a>: Sized {
     fn visit_ts_type_annotation(&mut self, annotation: &mut TSTypeAnnotation<'a>) {
         let kind = AstKind::TSTypeAnnotation(self.alloc(annotation));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_ts_type(&mut annotation.type_annotation);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1564,17 +1838,21 @@ pub trait VisitMut<'a>: Sized {
     fn visit_ts_type_literal(&mut self, ty: &mut TSTypeLiteral<'a>) {
         let kind = AstKind::TSTypeLiteral(self.alloc(ty));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for signature in ty.members.iter_mut() {
             self.visit_ts_signature(signature);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_ts_indexed_access_type(&mut self, ty: &mut TSIndexedAccessType<'a>) {
         let kind = AstKind::TSIndexedAccessType(self.alloc(ty));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_ts_type(&mut ty.object_type);
         self.visit_ts_type(&mut ty.index_type);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1625,6 +1903,7 @@ pub trait VisitMut<'a>: Sized {
         let kind = AstKind::TSTypeParameter(self.alloc(ty));
         self.enter_scope(ScopeFlags::empty());
         self.enter_node(kind);
+        self.push_ancestor(kind);
         if let Some(constraint) = &mut ty.constraint {
             self.visit_ts_type(constraint);
         }
@@ -1632,6 +1911,7 @@ pub trait VisitMut<'a>: Sized {
         if let Some(default) = &mut ty.default {
             self.visit_ts_type(default);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
         self.leave_scope();
     }
@@ -1639,18 +1919,22 @@ pub trait VisitMut<'a>: Sized {
     fn visit_ts_type_parameter_instantiation(&mut self, ty: &mut TSTypeParameterInstantiation<'a>) {
         let kind = AstKind::TSTypeParameterInstantiation(self.alloc(ty));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for ts_parameter in ty.params.iter_mut() {
             self.visit_ts_type(ts_parameter);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_ts_type_parameter_declaration(&mut self, ty: &mut TSTypeParameterDeclaration<'a>) {
         let kind = AstKind::TSTypeParameterDeclaration(self.alloc(ty));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for ts_parameter in ty.params.iter_mut() {
             self.visit_ts_type_parameter(ts_parameter);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1683,52 +1967,65 @@ pub trait VisitMut<'a>: Sized {
     fn visit_ts_null_keyword(&mut self, ty: &mut TSNullKeyword) {
         let kind = AstKind::TSNullKeyword(self.alloc(ty));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_ts_any_keyword(&mut self, ty: &mut TSAnyKeyword) {
         let kind = AstKind::TSAnyKeyword(self.alloc(ty));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_ts_void_keyword(&mut self, ty: &mut TSVoidKeyword) {
         let kind = AstKind::TSVoidKeyword(self.alloc(ty));
         self.enter_node(kind);
+        self.push_ancestor(kind);
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_ts_intersection_type(&mut self, ty: &mut TSIntersectionType<'a>) {
         let kind = AstKind::TSIntersectionType(self.alloc(ty));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for ty in ty.types.iter_mut() {
             self.visit_ts_type(ty);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_ts_type_reference(&mut self, ty: &mut TSTypeReference<'a>) {
         let kind = AstKind::TSTypeReference(self.alloc(ty));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_ts_type_name(&mut ty.type_name);
         if let Some(parameters) = &mut ty.type_parameters {
             self.visit_ts_type_parameter_instantiation(parameters);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_ts_union_type(&mut self, ty: &mut TSUnionType<'a>) {
         let kind = AstKind::TSUnionType(self.alloc(ty));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         for ty in ty.types.iter_mut() {
             self.visit_ts_type(ty);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
     fn visit_ts_literal_type(&mut self, ty: &mut TSLiteralType<'a>) {
         let kind = AstKind::TSLiteralType(self.alloc(ty));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         match &mut ty.literal {
             TSLiteral::BigintLiteral(lit) => self.visit_bigint_literal(lit),
             TSLiteral::BooleanLiteral(lit) => self.visit_boolean_literal(lit),
@@ -1739,6 +2036,7 @@ pub trait VisitMut<'a>: Sized {
             TSLiteral::TemplateLiteral(lit) => self.visit_template_literal(lit),
             TSLiteral::UnaryExpression(expr) => self.visit_unary_expression(expr),
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1772,6 +2070,7 @@ pub trait VisitMut<'a>: Sized {
     fn visit_ts_method_signature(&mut self, signature: &mut TSMethodSignature<'a>) {
         let kind = AstKind::TSMethodSignature(self.alloc(signature));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_formal_parameters(&mut signature.params);
         if let Some(parameters) = &mut signature.type_parameters {
             self.visit_ts_type_parameter_declaration(parameters);
@@ -1779,6 +2078,7 @@ pub trait VisitMut<'a>: Sized {
         if let Some(annotation) = &mut signature.return_type {
             self.visit_ts_type_annotation(annotation);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1797,10 +2097,12 @@ pub trait VisitMut<'a>: Sized {
     fn visit_ts_property_signature(&mut self, signature: &mut TSPropertySignature<'a>) {
         let kind = AstKind::TSPropertySignature(self.alloc(signature));
         self.enter_node(kind);
+        self.push_ancestor(kind);
         self.visit_property_key(&mut signature.key);
         if let Some(annotation) = &mut signature.type_annotation {
             self.visit_ts_type_annotation(annotation);
         }
+        self.pop_ancestor();
         self.leave_node(kind);
     }
 
@@ -1818,3 +2120,110 @@ pub trait VisitMut<'a>: Sized {
         }
     }
 }
+
+/// The default traversal of [VisitMut::visit_function], for visitors which override it to
+/// transform the function before its children are visited.
+pub fn walk_function_mut<'a, V: VisitMut<'a>>(
+    visitor: &mut V,
+    func: &mut Function<'a>,
+    flags: Option<ScopeFlags>,
+) {
+    let kind = AstKind::Function(visitor.alloc(func));
+    visitor.enter_scope({
+        let mut flags = flags.unwrap_or(ScopeFlags::empty()) | ScopeFlags::Function;
+        if func.is_strict() {
+            flags |= ScopeFlags::StrictMode;
+        }
+        flags
+    });
+    visitor.enter_node(kind);
+    visitor.push_ancestor(kind);
+    if let Some(ident) = &mut func.id {
+        visitor.visit_binding_identifier(ident);
+    }
+    visitor.visit_formal_parameters(&mut func.params);
+    if let Some(body) = &mut func.body {
+        visitor.visit_function_body(body);
+    }
+    if let Some(parameters) = &mut func.type_parameters {
+        visitor.visit_ts_type_parameter_declaration(parameters);
+    }
+    if let Some(annotation) = &mut func.return_type {
+        visitor.visit_ts_type_annotation(annotation);
+    }
+    visitor.pop_ancestor();
+    visitor.leave_node(kind);
+    visitor.leave_scope();
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::{SourceType, Span};
+
+    use super::{Ancestors, VisitMut};
+    use crate::{ast::StringLiteral, AstBuilder, AstKind};
+
+    fn name(kind: AstKind) -> &'static str {
+        match kind {
+            AstKind::Program(_) => "Program",
+            AstKind::ExpressionStatement(_) => "ExpressionStatement",
+            AstKind::StringLiteral(_) => "StringLiteral",
+            _ => unreachable!(),
+        }
+    }
+
+    #[derive(Default)]
+    struct Collector<'a> {
+        ancestors: Ancestors<'a>,
+        /// `enter_node` or `leave_node`, the node and its ancestors
+        calls: std::vec::Vec<(&'static str, &'static str, std::vec::Vec<&'static str>)>,
+    }
+
+    impl<'a> Collector<'a> {
+        fn record(&mut self, call: &'static str, kind: AstKind<'a>) {
+            let ancestors = self.ancestors.iter().map(name).collect();
+            self.calls.push((call, name(kind), ancestors));
+        }
+    }
+
+    impl<'a> VisitMut<'a> for Collector<'a> {
+        fn enter_node(&mut self, kind: AstKind<'a>) {
+            self.record("enter", kind);
+        }
+
+        fn leave_node(&mut self, kind: AstKind<'a>) {
+            self.record("leave", kind);
+        }
+
+        fn ancestors_mut(&mut self) -> Option<&mut Ancestors<'a>> {
+            Some(&mut self.ancestors)
+        }
+    }
+
+    #[test]
+    fn ancestors() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        let literal =
+            ast.literal_string_expression(StringLiteral::new(Span::new(0, 3), "a".into()));
+        let body = ast.new_vec_single(ast.expression_statement(Span::new(0, 4), literal));
+        let mut program =
+            ast.program(Span::new(0, 4), SourceType::default(), ast.new_vec(), None, body);
+
+        let mut collector = Collector::default();
+        collector.visit_program(&mut program);
+        assert!(collector.ancestors.is_empty());
+        assert_eq!(
+            collector.calls,
+            vec![
+                ("enter", "Program", vec![]),
+                ("enter", "ExpressionStatement", vec!["Program"]),
+                ("enter", "StringLiteral", vec!["ExpressionStatement", "Program"]),
+                ("leave", "StringLiteral", vec!["ExpressionStatement", "Program"]),
+                ("leave", "ExpressionStatement", vec!["Program"]),
+                ("leave", "Program", vec![]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,21 @@
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+// padding
+export {};
@@ -0,0 +1,174 @@
+mod rules;
+
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_esquery::Query;
+use oxc_linter::Fix;
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use similar::TextDiff;
+
+use crate::{command::CodemodOptions, result::CliRunResult, walk::Walk, Runner};
+
+use self::rules::{read_rules, RewriteRule};
+
+pub struct CodemodRunner {
+    options: CodemodOptions,
+}
+
+enum Rewrite {
+    /// The rewritten code and the number of rewritten nodes
+    Changed(String, usize),
+    /// The rewritten code has syntax errors, the file is left as it is
+    Invalid,
+}
+
+impl Runner for CodemodRunner {
+    type Options = CodemodOptions;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        let CodemodOptions { rules, dry_run, paths, ignore_options, .. } = &self.options;
+
+        if paths.is_empty() {
+            return CliRunResult::InvalidOptions { message: "No paths are provided.".to_string() };
+        }
+
+        let rules = match read_rules(rules) {
+            Ok(rules) => rules,
+            Err(message) => return CliRunResult::InvalidOptions { message },
+        };
+
+        let paths = Walk::new(paths, ignore_options).paths();
+        let results = paths
+            .par_iter()
+            .filter_map(|path| {
+                let source_text = std::fs::read_to_string(path).ok()?;
+                let rewrite = rewrite(path, &source_text, &rules)?;
+                Some((path, source_text, rewrite))
+            })
+            .collect::<Vec<_>>();
+
+        let mut number_of_files = 0;
+        let mut number_of_rewrites = 0;
+        for (path, source_text, rewrite) in results {
+            let (fixed_code, count) = match rewrite {
+                Rewrite::Changed(fixed_code, count) => (fixed_code, count),
+                Rewrite::Invalid => {
+                    println!("{}: the rewrite produces syntax errors, skipped", path.display());
+                    continue;
+                }
+            };
+            number_of_files += 1;
+            number_of_rewrites += count;
+            if *dry_run {
+                let name = path.to_string_lossy();
+                let diff = TextDiff::from_lines(&source_text, &fixed_code);
+                print!("{}", diff.unified_diff().header(&name, &name));
+            } else if let Err(error) = std::fs::write(path, fixed_code) {
+                println!("Failed to write {}: {error}", path.display());
+            }
+        }
+
+        let verb = if *dry_run { "Would rewrite" } else { "Rewrote" };
+        println!("{verb} {number_of_rewrites} matches in {number_of_files} files.");
+        CliRunResult::None
+    }
+}
+
+/// Apply the rules to the code of `path`, `None` for files without matches or which do not parse.
+///
+/// A node matched by several rules is rewritten by the first one, and the nodes inside a
+/// rewritten node are left as they are.
+fn rewrite(path: &Path, source_text: &str, rules: &[RewriteRule]) -> Option<Rewrite> {
+    let source_type = SourceType::from_path(path).ok()?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if !ret.errors.is_empty() {
+        return None;
+    }
+    let program = serde_json::to_value(&ret.program).ok()?;
+    let query = Query::new(&program);
+
+    let mut edits: Vec<(Span, String)> = vec![];
+    for rule in rules {
+        for (node, bindings) in query.find(&rule.pattern) {
+            let bindings = bindings
+                .iter()
+                .map(|(name, span)| (name.clone(), span.source_text(source_text)))
+                .collect();
+            edits.push((node.span(), rule.apply(&bindings)));
+        }
+    }
+    // Outermost nodes first, `Fix::from_edits` drops the edits overlapping a previous one
+    edits.sort_by_key(|(span, _)| (span.start, std::cmp::Reverse(span.end)));
+    let mut end = 0;
+    edits.retain(|(span, _)| {
+        let keep = span.start >= end;
+        end = end.max(span.end);
+        keep
+    });
+    if edits.is_empty() {
+        return None;
+    }
+
+    let count = edits.len();
+    let fix = Fix::from_edits(source_text, edits);
+    let fixed_code = format!(
+        "{}{}{}",
+        &source_text[..fix.span.start as usize],
+        fix.content,
+        &source_text[fix.span.end as usize..]
+    );
+    let allocator = Allocator::default();
+    if !Parser::new(&allocator, &fixed_code, source_type).parse().errors.is_empty() {
+        return Some(Rewrite::Invalid);
+    }
+    Some(Rewrite::Changed(fixed_code, count))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::{rewrite, rules::RewriteRule, Rewrite};
+
+    fn rules(rules: &serde_json::Value) -> Vec<RewriteRule> {
+        rules
+            .as_array()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| RewriteRule::from_value(index, rule).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn rewrite_nodes() {
+        let rules = rules(&json!([
+            { "pattern": "console.log($MSG)", "rewrite": "logger.debug($MSG)" },
+            { "pattern": "$A + $B", "rewrite": "add($A, $B)" },
+        ]));
+        let source = "console.log( 'a' );\nconsole.warn(1);\nx = a + b + c;\n";
+        let Some(Rewrite::Changed(fixed_code, count)) = rewrite(Path::new("a.js"), source, &rules)
+        else {
+            panic!("expected a rewrite");
+        };
+        assert_eq!(count, 2);
+        assert_eq!(fixed_code, "logger.debug('a');\nconsole.warn(1);\nx = add(a + b, c);\n");
+        assert!(rewrite(Path::new("a.js"), "console.warn(1)", &rules).is_none());
+    }
+
+    #[test]
+    fn invalid_rewrite() {
+        let rules = rules(&json!([{ "pattern": "foo($A)", "rewrite": "$A +" }]));
+        assert!(matches!(rewrite(Path::new("a.js"), "foo(1)", &rules), Some(Rewrite::Invalid)));
+    }
+}
@@ -0,0 +1,96 @@
+use std::{collections::BTreeMap, path::Path};
+
+use lazy_static::lazy_static;
+use oxc_esquery::Pattern;
+use regex::{Captures, Regex};
+use serde_json::Value;
+
+lazy_static! {
+    static ref METAVARIABLE: Regex = Regex::new(r"\$[A-Z0-9_]+").unwrap();
+}
+
+/// A rewrite of the nodes matching `pattern` into `rewrite`, in which the metavariables of the
+/// pattern are replaced by the code they matched
+#[derive(Debug)]
+pub struct RewriteRule {
+    pub id: String,
+    pub pattern: Pattern,
+    rewrite: String,
+}
+
+impl RewriteRule {
+    pub fn from_value(index: usize, value: &Value) -> Result<Self, String> {
+        let id = value
+            .get("id")
+            .and_then(Value::as_str)
+            .map_or_else(|| format!("rule {}", index + 1), ToString::to_string);
+        let field = |key: &str| {
+            value
+                .get(key)
+                .and_then(Value::as_str)
+                .ok_or_else(|| format!("{id}: `{key}` is not a string"))
+        };
+        let source = field("pattern")?;
+        let rewrite = field("rewrite")?;
+        let pattern = Pattern::parse(source).map_err(|error| format!("{id}: {error}"))?;
+        if let Some(name) = METAVARIABLE.find_iter(rewrite).map(|m| m.as_str()).find(|name| {
+            *name == "$_" || !METAVARIABLE.find_iter(source).any(|m| m.as_str() == *name)
+        }) {
+            return Err(format!("{id}: `{name}` of the rewrite is not bound by the pattern"));
+        }
+        Ok(Self { id, pattern, rewrite: rewrite.to_string() })
+    }
+
+    /// The rewrite with the code bound to each metavariable
+    pub fn apply(&self, bindings: &BTreeMap<String, &str>) -> String {
+        METAVARIABLE
+            .replace_all(&self.rewrite, |captures: &Captures| {
+                let name = &captures[0];
+                bindings.get(name).map_or_else(|| name.to_string(), ToString::to_string)
+            })
+            .into_owned()
+    }
+}
+
+/// Read the `rules` of a JSON file, or of a TOML file for the `.toml` extension.
+pub fn read_rules(path: &Path) -> Result<Vec<RewriteRule>, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+    let value: Value = if path.extension().is_some_and(|ext| ext == "toml") {
+        toml::from_str(&text).map_err(|error| error.to_string())?
+    } else {
+        serde_json::from_str(&text).map_err(|error| error.to_string())?
+    };
+    let Some(rules) = value.get("rules").and_then(Value::as_array) else {
+        return Err(format!("{} has no `rules` array", path.display()));
+    };
+    rules.iter().enumerate().map(|(index, rule)| RewriteRule::from_value(index, rule)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
+    use super::RewriteRule;
+
+    #[test]
+    fn apply() {
+        let rule = json!({ "pattern": "assert($A == $B)", "rewrite": "expect($A).toBe($B)" });
+        let rule = RewriteRule::from_value(0, &rule).unwrap();
+        assert_eq!(rule.id, "rule 1");
+        let bindings = BTreeMap::from([("$A".to_string(), "a.b"), ("$B".to_string(), "f(1)")]);
+        assert_eq!(rule.apply(&bindings), "expect(a.b).toBe(f(1))");
+    }
+
+    #[test]
+    fn unbound_metavariable() {
+        let rule = json!({ "id": "log", "pattern": "console.log($_)", "rewrite": "log($_)" });
+        let error = RewriteRule::from_value(0, &rule).unwrap_err();
+        assert_eq!(error, "log: `$_` of the rewrite is not bound by the pattern");
+        let rule = json!({ "pattern": "foo($A)", "rewrite": "bar($B)" });
+        assert!(RewriteRule::from_value(0, &rule).is_err());
+        assert!(RewriteRule::from_value(0, &json!({ "pattern": "foo($A)" })).is_err());
+    }
+}
@@ -1,6 +1,8 @@
 use bpaf::{doc::Style, Bpaf};
-use oxc_linter::AllowWarnDeny;
-use std::{ffi::OsString, path::PathBuf};
+use oxc_diagnostics::OutputFormat;
+use oxc_linter::{AllowWarnDeny, Newline, Preset};
+use oxc_transformer::{ModuleFormat, TransformTarget};
+use std::{ffi::OsString, path::PathBuf, str::FromStr};
 
 #[derive(Debug, Clone, Bpaf)]
 #[bpaf(options)]
@@ -16,6 +18,22 @@ pub enum CliCommand {
     /// Use Ezno to type check source code (experimental and work in progress)
     #[bpaf(command)]
     Check(#[bpaf(external(check_options))] CheckOptions),
+
+    /// Transform TypeScript and JSX into JavaScript
+    #[bpaf(command)]
+    Transform(#[bpaf(external(transform_options))] TransformOptions),
+
+    /// Print the import dependency graph of this repository
+    #[bpaf(command)]
+    Graph(#[bpaf(external(graph_options))] GraphOptions),
+
+    /// Search the nodes matching a selector or a code pattern
+    #[bpaf(command)]
+    Search(#[bpaf(external(search_options))] SearchOptions),
+
+    /// Rewrite the code matching the patterns of a rule file
+    #[bpaf(command)]
+    Codemod(#[bpaf(external(codemod_options))] CodemodOptions),
 }
 
 impl CliCommand {
@@ -27,6 +45,18 @@ impl CliCommand {
             Self::Format(options) => {
                 Self::set_rayon_threads(options.misc_options.threads);
             }
+            Self::Transform(options) => {
+                Self::set_rayon_threads(options.misc_options.threads);
+            }
+            Self::Graph(options) => {
+                Self::set_rayon_threads(options.misc_options.threads);
+            }
+            Self::Search(options) => {
+                Self::set_rayon_threads(options.misc_options.threads);
+            }
+            Self::Codemod(options) => {
+                Self::set_rayon_threads(options.misc_options.threads);
+            }
             Self::Check(_) => {}
         }
     }
@@ -44,10 +74,23 @@ impl CliCommand {
 #[derive(Debug, Clone, Bpaf)]
 #[bpaf(options)]
 pub struct LintCommand {
+    #[bpaf(external(check_config_command), optional)]
+    pub check_config: Option<CheckConfigCommand>,
+
     #[bpaf(external(lint_options))]
     pub lint_options: LintOptions,
 }
 
+/// Validate a configuration file and exit with a non-zero code if it has problems
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(command("check-config"))]
+pub struct CheckConfigCommand {
+    /// The configuration file, `oxlint.config.json` or `.eslintrc.json` of the current directory
+    /// by default
+    #[bpaf(positional("PATH"))]
+    pub path: Option<PathBuf>,
+}
+
 impl LintCommand {
     pub fn handle_threads(&self) {
         CliCommand::set_rayon_threads(self.lint_options.misc_options.threads);
@@ -72,15 +115,64 @@ impl FormatCommand {
 #[derive(Debug, Clone, Bpaf)]
 pub struct MiscOptions {
     /// Display the execution time of each lint rule
-    #[bpaf(switch, env("TIMING"), hide_usage)]
+    #[bpaf(switch, env("OXLINT_TIMING"), env("TIMING"), hide_usage)]
     pub timing: bool,
 
+    /// Warn about rules which take longer than this number of milliseconds on a single file
+    #[bpaf(argument("MS"), env("OXLINT_TIMING_THRESHOLD"), hide_usage)]
+    pub timing_threshold: Option<u64>,
+
+    /// Write the time of each rule on each file to this file, in the collapsed stack format
+    /// of flamegraph tools
+    #[bpaf(argument("PATH"), env("OXLINT_PROFILE"), hide_usage)]
+    pub profile: Option<PathBuf>,
+
     /// list all the rules that are currently registered
     #[bpaf(switch, hide_usage)]
     pub rules: bool,
 
+    /// List the rules of a preset of `extends`, `recommended`, `strict` or `all`, with the
+    /// release which added each of them to the preset. Implies `--rules`
+    #[bpaf(argument("NAME"), hide_usage)]
+    pub preset: Option<Preset>,
+
+    /// Explain whether the given files are linted, what excludes them,
+    /// and which configuration, plugins and rules apply to them
+    #[bpaf(switch, hide_usage)]
+    pub inspect: bool,
+
+    /// Break the number of files, warnings and errors down per package
+    /// of the pnpm, yarn or npm workspace
+    #[bpaf(switch, env("OXLINT_PACKAGE_SUMMARY"), hide_usage)]
+    pub package_summary: bool,
+
+    /// List the diagnostics silenced by disable directives
+    /// and count them per rule, to audit what is suppressed
+    #[bpaf(switch, env("OXLINT_SHOW_SUPPRESSED"), hide_usage)]
+    pub show_suppressed: bool,
+
+    /// Report the `eslint-disable` and `oxlint-disable` comments which silence no diagnostic
+    ///
+    /// `eslint-disable` comments disabling all rules, or rules which are not run, are left out:
+    /// they may silence the diagnostics of ESLint
+    #[bpaf(switch, env("OXLINT_REPORT_UNUSED_DISABLE_DIRECTIVES"), hide_usage)]
+    pub report_unused_disable_directives: bool,
+
+    /// Lint the files in lexicographic order of their paths and print their diagnostics in the
+    /// same order, so that the output is the same on every platform and file system
+    ///
+    /// Paths are compared component by component, byte-wise. The diagnostics are printed once
+    /// all files are linted, instead of while linting
+    #[bpaf(switch, env("OXLINT_SORT_PATHS"), hide_usage)]
+    pub sort_paths: bool,
+
+    /// Append a report of each internal error to this file, with the panic, a backtrace
+    /// and the source of the file reduced to the lines which reproduce it, for bug reports
+    #[bpaf(argument("PATH"), env("OXLINT_CRASH_REPORT"), hide_usage)]
+    pub crash_report: Option<PathBuf>,
+
     /// Number of threads to use. Set to 1 for using only 1 CPU core
-    #[bpaf(argument("INT"), hide_usage)]
+    #[bpaf(argument("INT"), env("OXLINT_THREADS"), hide_usage)]
     pub threads: Option<usize>,
 }
 
@@ -88,16 +180,35 @@ pub struct MiscOptions {
 #[derive(Debug, Clone, Bpaf)]
 pub struct EnablePlugins {
     /// Enable the experimental import plugin and detect ESM problems
-    #[bpaf(switch, hide_usage)]
+    #[bpaf(switch, env("OXLINT_IMPORT_PLUGIN"), hide_usage)]
     pub import_plugin: bool,
 
+    /// TypeScript config used by the import plugin to resolve `paths` aliases,
+    /// defaults to the nearest tsconfig.json
+    #[bpaf(argument("PATH"), env("OXLINT_TSCONFIG"), hide_usage)]
+    pub tsconfig: Option<PathBuf>,
+
+    /// File to keep the module resolutions of the import plugin in between runs,
+    /// e.g. node_modules/.cache/oxlint/resolve.json
+    #[bpaf(argument("PATH"), env("OXLINT_RESOLVE_CACHE"), hide_usage)]
+    pub resolve_cache: Option<PathBuf>,
+
     /// Enable the Jest plugin and detect test problems
-    #[bpaf(switch, hide_usage)]
+    ///
+    /// The rules only run on the test files matched by `testMatch` or `testRegex` of
+    /// `jest.config.json` or the `jest` key of `package.json`, which also define the jest globals
+    #[bpaf(switch, env("OXLINT_JEST_PLUGIN"), hide_usage)]
     pub jest_plugin: bool,
 
     /// Enable the JSX-a11y plugin and detect accessibility problems
-    #[bpaf(switch, hide_usage)]
+    #[bpaf(switch, env("OXLINT_JSX_A11Y_PLUGIN"), hide_usage)]
     pub jsx_a11y_plugin: bool,
+
+    /// Enable the secrets plugin and detect hard-coded AWS keys, GitHub tokens and private keys.
+    /// High-entropy strings are detected with `-D no-high-entropy-strings`, values are allowed
+    /// with the `allowlist` of the rules in the config
+    #[bpaf(switch, env("OXLINT_SECRETS_PLUGIN"), hide_usage)]
+    pub secrets_plugin: bool,
 }
 
 #[derive(Debug, Clone, Bpaf)]
@@ -117,6 +228,9 @@ pub struct LintOptions {
     #[bpaf(external)]
     pub warning_options: WarningOptions,
 
+    #[bpaf(external)]
+    pub budget_options: BudgetOptions,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
@@ -126,9 +240,90 @@ pub struct LintOptions {
     /// ESLint configuration file (experimental)
     ///
     /// * only `.json` extension is supported
-    #[bpaf(long("config"), short('c'), argument("PATH"))]
+    /// * `extends` can name shareable config packages, e.g. `@company/oxlint-config`, and
+    ///   relative paths to other config files
+    /// * `extends` can enable the rules of the presets `oxlint:recommended`, `oxlint:strict` and
+    ///   `oxlint:all`, pinned to those of a release with e.g. `oxlint:recommended@0.0.22`
+    /// * `env` presets such as `browser`, `node`, `worker`, `jest`, `mocha` and `es2024` define
+    ///   the globals of their environment
+    /// * `ignorePatterns` exclude files relative to the directory of the config, in addition to
+    ///   `.eslintignore` and `--ignore-pattern`
+    /// * `messages` override the messages of rules, e.g. `{ "no-console": "{message} See ..." }`,
+    ///   where `{message}` is the message of the rule and `{rule}` its name
+    /// * `parserOptions.sourceType` and `parserOptions.ecmaFeatures.jsx` change how the files are
+    ///   parsed, e.g. `"script"` for files which are not modules
+    /// * a rule can be limited to paths with `files` and `excludedFiles`, e.g.
+    ///   `"no-console": { "severity": "error", "excludedFiles": ["scripts/**"] }`
+    /// * a flat config, an array of config objects with `files`, `ignores`, `rules` and
+    ///   `languageOptions` like `eslint.config.js`, is also accepted and is loaded from
+    ///   `oxlint.config.json` in the working directory by default
+    #[bpaf(long("config"), short('c'), env("OXLINT_CONFIG"), argument("PATH"))]
     pub config: Option<PathBuf>,
 
+    /// Only report syntax errors found by the parser, skipping semantic analysis and all rules.
+    /// A fast sanity check, e.g. before committing
+    #[bpaf(switch, env("OXLINT_SYNTAX_ONLY"))]
+    pub syntax_only: bool,
+
+    /// Output format of the diagnostics: graphical (default), json as printed by ESLint,
+    /// unix for `path:line:column: message` lines, checkstyle for its XML, github for the
+    /// annotations of GitHub Actions, or stream for a JSON object per line and per diagnostic
+    ///
+    /// The summary of the run is not printed with json, checkstyle and stream
+    #[bpaf(
+        short('f'),
+        long("format"),
+        env("OXLINT_FORMAT"),
+        argument("graphical|json|unix|checkstyle|github|stream"),
+        fallback(OutputFormat::Graphical)
+    )]
+    pub format: OutputFormat,
+
+    /// Lint the files given as PATH whatever their extension, as JavaScript when the extension
+    /// is not supported, e.g. scripts without an extension
+    #[bpaf(switch, env("OXLINT_FORCE"), hide_usage)]
+    pub force: bool,
+
+    /// Lint the files listed in this file, or in the standard input with `-`, instead of walking
+    /// PATH, e.g. `git diff --name-only -z | oxlint --files-from -`
+    ///
+    /// The paths are separated by newlines, or by NUL characters if there is any. Ignore files
+    /// and `--ignore-pattern` are not applied. Missing files, e.g. deleted files of a diff, and
+    /// files with an unsupported extension are skipped, the latter unless `--force` is given
+    #[bpaf(argument("PATH"), env("OXLINT_FILES_FROM"), hide_usage)]
+    pub files_from: Option<PathBuf>,
+
+    /// Skip the files which had no problems in the previous run, as long as their content, the
+    /// configuration and the version of oxlint did not change
+    ///
+    /// Files with problems are linted again to report them. Not used with the import plugin,
+    /// whose diagnostics depend on other files
+    #[bpaf(switch, env("OXLINT_CACHE"), hide_usage)]
+    pub cache: bool,
+
+    /// File of the cache, `.oxlintcache` in the working directory by default
+    #[bpaf(argument("PATH"), env("OXLINT_CACHE_LOCATION"), hide_usage)]
+    pub cache_location: Option<PathBuf>,
+
+    /// Keep running and lint the files again when they change, with the files importing them
+    /// when the import plugin is enabled
+    ///
+    /// The diagnostics and the summary of the changed files are printed after each change.
+    /// All files are linted again when the config changes
+    #[bpaf(switch, env("OXLINT_WATCH"), hide_usage)]
+    pub watch: bool,
+
+    /// Keep running and lint the files requested on the standard input, one JSON request per
+    /// line, for editor plugins which do not speak the language server protocol
+    ///
+    /// `{"id": 1, "method": "lint", "params": {"paths": ["src/a.ts"]}}` prints the `file` and
+    /// `diagnostic` events of `--format stream` as the files are linted, between a `start` event
+    /// and an `end` event with the `id` of the request and the number of files, errors and
+    /// warnings. Files without diagnostics have no event. `{"method": "shutdown"}` or the end of
+    /// the input stops the process
+    #[bpaf(switch, hide_usage)]
+    pub server_lite: bool,
+
     /// Single file, single path or list of paths
     #[bpaf(positional("PATH"), many)]
     pub paths: Vec<PathBuf>,
@@ -141,6 +336,8 @@ pub struct LintOptions {
 /// ㅤ
 ///  The default category is "-D correctness".
 ///  Use "--rules" for rule names.
+///  Select the rules of a plugin with "import/*" or "plugin:import",
+///  and rules by name with the wildcards "*" and "?", e.g. "-A jest/no-*".
 ///  Use "--help --help" for rule categories.
 ///
 /// The categories are:
@@ -163,6 +360,11 @@ pub enum LintFilter {
         #[bpaf(short('D'), long("deny"), argument("NAME"))]
         String,
     ),
+    Warn(
+        /// Warn about the rule or category
+        #[bpaf(short('W'), long("warn"), argument("NAME"))]
+        String,
+    ),
 }
 
 impl LintFilter {
@@ -170,6 +372,7 @@ impl LintFilter {
         match self {
             Self::Allow(s) => (AllowWarnDeny::Allow, s),
             Self::Deny(s) => (AllowWarnDeny::Deny, s),
+            Self::Warn(s) => (AllowWarnDeny::Warn, s),
         }
     }
 }
@@ -187,11 +390,183 @@ pub struct FormatOptions {
     pub paths: Vec<PathBuf>,
 }
 
+#[derive(Debug, Clone, Bpaf)]
+pub struct TransformOptions {
+    /// Lower syntax the target runtime does not support: es5, es2015 to es2024 or esnext (default)
+    #[bpaf(argument("TARGET"), fallback(TransformTarget::ESNext))]
+    pub target: TransformTarget,
+
+    /// Module format of the output: esm (default) keeps `import` and `export`, cjs lowers them to
+    /// `require` and `exports`
+    #[bpaf(argument("esm|cjs"), fallback(ModuleFormat::Esm))]
+    pub module: ModuleFormat,
+
+    /// Lower JSX to `React.createElement` calls (classic) or to auto imported `jsx` calls (automatic)
+    #[bpaf(argument("classic|automatic"), fallback(JsxRuntime::Automatic), hide_usage)]
+    pub jsx_runtime: JsxRuntime,
+
+    /// Module to import the JSX functions from for the automatic runtime, `react` by default
+    #[bpaf(argument("SOURCE"), hide_usage)]
+    pub jsx_import_source: Option<String>,
+
+    /// Register the components with React Refresh, for the hot reloading of dev servers
+    #[bpaf(switch, hide_usage)]
+    pub react_refresh: bool,
+
+    /// Write the output files to this directory instead of next to the input files
+    #[bpaf(argument("DIR"))]
+    pub out_dir: Option<PathBuf>,
+
+    /// Write a `.map` source map file for each output file
+    #[bpaf(switch)]
+    pub source_map: bool,
+
+    #[bpaf(external)]
+    pub misc_options: MiscOptions,
+
+    #[bpaf(external)]
+    pub ignore_options: IgnoreOptions,
+
+    /// Single file, single path or list of paths
+    #[bpaf(positional("PATH"), many)]
+    pub paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsxRuntime {
+    Classic,
+    Automatic,
+}
+
+impl FromStr for JsxRuntime {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "classic" => Ok(Self::Classic),
+            "automatic" => Ok(Self::Automatic),
+            _ => Err(format!("expected `classic` or `automatic`, received `{s}`")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Bpaf)]
+pub struct GraphOptions {
+    /// Output format: json (default) or dot for Graphviz
+    #[bpaf(argument("json|dot"), fallback(GraphFormat::Json))]
+    pub format: GraphFormat,
+
+    /// Show each package in `node_modules` as a single module
+    #[bpaf(switch)]
+    pub collapse_node_modules: bool,
+
+    /// Color modules and imports which are part of a cycle in the dot output
+    #[bpaf(switch)]
+    pub highlight_cycles: bool,
+
+    /// TypeScript `tsconfig.json` to read `paths` from, the one in the current directory by default
+    #[bpaf(argument("PATH"))]
+    pub tsconfig: Option<PathBuf>,
+
+    #[bpaf(external)]
+    pub misc_options: MiscOptions,
+
+    #[bpaf(external)]
+    pub ignore_options: IgnoreOptions,
+
+    /// Single file, single path or list of paths
+    #[bpaf(positional("PATH"), many)]
+    pub paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    Json,
+    Dot,
+}
+
+impl FromStr for GraphFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "dot" => Ok(Self::Dot),
+            _ => Err(format!("expected `json` or `dot`, received `{s}`")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Bpaf)]
+pub struct SearchOptions {
+    /// ESLint selector of the nodes to find, e.g. `CallExpression[callee.name='require']`
+    #[bpaf(argument("SELECTOR"))]
+    pub selector: Option<String>,
+
+    /// Code of the nodes to find, where `$NAME` matches any node, e.g. `console.log($MSG)`
+    #[bpaf(argument("CODE"))]
+    pub pattern: Option<String>,
+
+    /// Output format: default or json
+    #[bpaf(argument("default|json"), fallback(SearchFormat::Default))]
+    pub format: SearchFormat,
+
+    #[bpaf(external)]
+    pub misc_options: MiscOptions,
+
+    #[bpaf(external)]
+    pub ignore_options: IgnoreOptions,
+
+    /// Single file, single path or list of paths
+    #[bpaf(positional("PATH"), many)]
+    pub paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchFormat {
+    Default,
+    Json,
+}
+
+impl FromStr for SearchFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Self::Default),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("expected `default` or `json`, received `{s}`")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Bpaf)]
+pub struct CodemodOptions {
+    /// JSON or TOML file of the rewrite rules, e.g. `[[rules]]` tables with a `pattern` such as
+    /// `console.log($MSG)` and its `rewrite` such as `logger.debug($MSG)`
+    #[bpaf(argument("PATH"))]
+    pub rules: PathBuf,
+
+    /// Print the changes as a diff instead of writing them
+    #[bpaf(switch)]
+    pub dry_run: bool,
+
+    #[bpaf(external)]
+    pub misc_options: MiscOptions,
+
+    #[bpaf(external)]
+    pub ignore_options: IgnoreOptions,
+
+    /// Single file, single path or list of paths
+    #[bpaf(positional("PATH"), many)]
+    pub paths: Vec<PathBuf>,
+}
+
 /// Codeowners
 #[derive(Debug, Clone, Bpaf)]
 pub struct CodeownerOptions {
     /// Path to CODEOWNERS file
-    #[bpaf(argument("PATH"), hide_usage)]
+    #[bpaf(argument("PATH"), env("OXLINT_CODEOWNERS_FILE"), hide_usage)]
     pub codeowners_file: Option<OsString>,
 
     /// Code owner names, e.g. @Boshen
@@ -203,8 +578,34 @@ pub struct CodeownerOptions {
 #[derive(Debug, Clone, Bpaf)]
 pub struct FixOptions {
     /// Fix as many issues as possible. Only unfixed issues are reported in the output
-    #[bpaf(switch)]
+    #[bpaf(switch, env("OXLINT_FIX"))]
     pub fix: bool,
+
+    /// Also apply fixes which may change the behavior of the code, implies `--fix`.
+    /// Rules list the kind of their fixes in `--rules`, suggestions are never applied
+    #[bpaf(switch, env("OXLINT_FIX_UNSAFE"))]
+    pub fix_unsafe: bool,
+
+    /// Silence the diagnostics of RULE with `// eslint-disable-next-line RULE -- TODO` comments
+    /// instead of fixing them, implies `--fix`. Can be repeated for several rules
+    #[bpaf(argument("RULE"))]
+    pub fix_suppress: Vec<String>,
+
+    /// Apply the fixes without writing the files and list the files which would be fixed,
+    /// implies `--fix`. Only unfixed issues are reported, as with `--fix`
+    #[bpaf(switch)]
+    pub fix_dry_run: bool,
+
+    /// Print the fixes as a unified diff instead of writing the files, implies `--fix-dry-run`.
+    /// The diff is printed after the unfixed issues and applies with `git apply`
+    #[bpaf(switch)]
+    pub fix_diff: bool,
+
+    /// Line endings of the fixed files. `auto` writes the fixes with the line ending of the first
+    /// line of each file, `lf` and `crlf` convert all lines. Files keep their final newline, or
+    /// the lack of one
+    #[bpaf(argument("lf|crlf|auto"), fallback(Newline::Auto), hide_usage)]
+    pub newline: Newline,
 }
 
 const NO_IGNORE_HELP: &[(&str, Style)] = &[
@@ -212,14 +613,16 @@ const NO_IGNORE_HELP: &[(&str, Style)] = &[
     ("--ignore-path", Style::Literal),
     (" flags and ", Style::Text),
     ("--ignore-pattern", Style::Literal),
-    (" flags", Style::Text),
+    (" flags and the ", Style::Text),
+    ("ignorePatterns", Style::Literal),
+    (" of the config", Style::Text),
 ];
 
 /// Ignore Files
 #[derive(Debug, Clone, Bpaf)]
 pub struct IgnoreOptions {
     /// Specify the file to use as your .eslintignore
-    #[bpaf(argument("PATH"), fallback(".eslintignore".into()), hide_usage)]
+    #[bpaf(argument("PATH"), env("OXLINT_IGNORE_PATH"), fallback(".eslintignore".into()), hide_usage)]
     pub ignore_path: OsString,
 
     /// Specify patterns of files to ignore (in addition to those in .eslintignore)
@@ -230,25 +633,77 @@ pub struct IgnoreOptions {
     pub ignore_pattern: Vec<String>,
 
     ///
-    #[bpaf(switch, hide_usage, help(NO_IGNORE_HELP))]
+    #[bpaf(switch, env("OXLINT_NO_IGNORE"), hide_usage, help(NO_IGNORE_HELP))]
     pub no_ignore: bool,
+
+    /// Skip files larger than this number of bytes, 0 for no limit. Defaults to 5 MB
+    #[bpaf(
+        argument("BYTES"),
+        env("OXLINT_MAX_FILE_SIZE"),
+        fallback(DEFAULT_MAX_FILE_SIZE),
+        hide_usage
+    )]
+    pub max_file_size: u64,
+}
+
+/// Large enough for any handwritten source file, bundles and generated files above this size stall
+/// a worker for seconds.
+pub const DEFAULT_MAX_FILE_SIZE: u64 = 5 * 1024 * 1024;
+
+/// Pathological Files
+#[derive(Debug, Clone, Bpaf)]
+pub struct BudgetOptions {
+    /// Lint files larger than this number of bytes with the correctness rules only,
+    /// 0 for no limit. Defaults to 1 MB
+    #[bpaf(
+        argument("BYTES"),
+        env("OXLINT_REDUCED_RULES_SIZE"),
+        fallback(DEFAULT_REDUCED_RULES_SIZE),
+        hide_usage
+    )]
+    pub reduced_rules_size: usize,
+
+    /// Stop linting a file after this number of milliseconds and report it as skipped,
+    /// 0 for no limit
+    #[bpaf(argument("MS"), env("OXLINT_FILE_TIME_BUDGET"), fallback(0), hide_usage)]
+    pub file_time_budget: u64,
 }
 
+/// Files above this size are mostly generated, the style and pedantic rules only add noise and time.
+pub const DEFAULT_REDUCED_RULES_SIZE: usize = 1024 * 1024;
+
 /// Handle Warnings
 #[derive(Debug, Clone, Bpaf)]
 pub struct WarningOptions {
     /// Disable reporting on warnings, only errors are reported
-    #[bpaf(switch, hide_usage)]
+    #[bpaf(switch, env("OXLINT_QUIET"), hide_usage)]
     pub quiet: bool,
 
     /// Ensure warnings produce a non-zero exit code
-    #[bpaf(switch, hide_usage)]
+    #[bpaf(switch, env("OXLINT_DENY_WARNINGS"), hide_usage)]
     pub deny_warnings: bool,
 
     /// Specify a warning threshold,
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
-    #[bpaf(argument("INT"), hide_usage)]
+    #[bpaf(argument("INT"), env("OXLINT_MAX_WARNINGS"), hide_usage)]
     pub max_warnings: Option<usize>,
+
+    /// Print the number of warnings and errors per rule and per file instead of the diagnostics,
+    /// e.g. to collect metrics
+    #[bpaf(switch, env("OXLINT_SUMMARY_ONLY"), hide_usage)]
+    pub summary_only: bool,
+
+    /// Only report the diagnostics which are not in the same files of the git revision given by
+    /// `--base`, so that a check fails on new problems only
+    ///
+    /// A diagnostic of the base revision hides one diagnostic with the same file, message and
+    /// code, wherever the code moved
+    #[bpaf(switch, env("OXLINT_DENY_NEW"), hide_usage)]
+    pub deny_new: bool,
+
+    /// Git revision compared against with `--deny-new`, e.g. origin/main
+    #[bpaf(argument("REF"), env("OXLINT_BASE"), hide_usage)]
+    pub base: Option<String>,
 }
 
 #[derive(Debug, Clone, Bpaf)]
@@ -268,6 +723,10 @@ pub struct CheckOptions {
 
 #[cfg(test)]
 mod misc_options {
+    use std::path::PathBuf;
+
+    use oxc_linter::Preset;
+
     use super::{lint_command, MiscOptions};
 
     fn get_misc_options(arg: &str) -> MiscOptions {
@@ -280,9 +739,59 @@ mod misc_options {
         let options = get_misc_options(".");
         assert!(!options.timing);
         assert!(!options.rules);
+        assert!(!options.inspect);
+        assert!(!options.package_summary);
+        assert!(!options.show_suppressed);
+        assert!(!options.report_unused_disable_directives);
+        assert!(!options.sort_paths);
+        assert!(options.timing_threshold.is_none());
+        assert!(options.profile.is_none());
         assert!(options.threads.is_none());
     }
 
+    #[test]
+    fn package_summary() {
+        let options = get_misc_options("--package-summary .");
+        assert!(options.package_summary);
+    }
+
+    #[test]
+    fn profiling() {
+        let options = get_misc_options("--timing-threshold 50 --profile lint.folded .");
+        assert_eq!(options.timing_threshold, Some(50));
+        assert_eq!(options.profile, Some(PathBuf::from("lint.folded")));
+    }
+
+    #[test]
+    fn show_suppressed() {
+        let options = get_misc_options("--show-suppressed .");
+        assert!(options.show_suppressed);
+    }
+
+    #[test]
+    fn report_unused_disable_directives() {
+        let options = get_misc_options("--report-unused-disable-directives .");
+        assert!(options.report_unused_disable_directives);
+    }
+
+    #[test]
+    fn sort_paths() {
+        let options = get_misc_options("--sort-paths .");
+        assert!(options.sort_paths);
+    }
+
+    #[test]
+    fn crash_report() {
+        let options = get_misc_options("--crash-report crash.md .");
+        assert_eq!(options.crash_report, Some(PathBuf::from("crash.md")));
+    }
+
+    #[test]
+    fn inspect() {
+        let options = get_misc_options("--inspect src/foo.js");
+        assert!(options.inspect);
+    }
+
     #[test]
     fn timing() {
         let options = get_misc_options("--timing .");
@@ -299,6 +808,37 @@ mod misc_options {
     fn list_rules() {
         let options = get_misc_options("--rules");
         assert!(options.rules);
+        assert_eq!(options.preset, None);
+    }
+
+    #[test]
+    fn list_preset_rules() {
+        let options = get_misc_options("--rules --preset strict");
+        assert_eq!(options.preset, Some(Preset::Strict));
+    }
+}
+
+#[cfg(test)]
+mod budget_options {
+    use super::{lint_command, BudgetOptions};
+
+    fn get_budget_options(arg: &str) -> BudgetOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().lint_options.budget_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_budget_options(".");
+        assert_eq!(options.reduced_rules_size, super::DEFAULT_REDUCED_RULES_SIZE);
+        assert_eq!(options.file_time_budget, 0);
+    }
+
+    #[test]
+    fn budget() {
+        let options = get_budget_options("--reduced-rules-size 0 --file-time-budget 500 .");
+        assert_eq!(options.reduced_rules_size, 0);
+        assert_eq!(options.file_time_budget, 500);
     }
 }
 
@@ -316,6 +856,7 @@ mod warning_options {
         let options = get_warning_options(".");
         assert!(!options.quiet);
         assert_eq!(options.max_warnings, None);
+        assert!(!options.summary_only);
     }
 
     #[test]
@@ -329,12 +870,26 @@ mod warning_options {
         let options = get_warning_options("--max-warnings 10 .");
         assert_eq!(options.max_warnings, Some(10));
     }
+
+    #[test]
+    fn deny_new() {
+        let options = get_warning_options("--deny-new --base origin/main .");
+        assert!(options.deny_new);
+        assert_eq!(options.base, Some("origin/main".to_string()));
+    }
+
+    #[test]
+    fn summary_only() {
+        let options = get_warning_options("--summary-only .");
+        assert!(options.summary_only);
+    }
 }
 
 #[cfg(test)]
 mod lint_options {
     use super::{lint_command, LintOptions};
-    use oxc_linter::AllowWarnDeny;
+    use oxc_diagnostics::OutputFormat;
+    use oxc_linter::{AllowWarnDeny, Newline};
     use std::path::PathBuf;
 
     fn get_lint_options(arg: &str) -> LintOptions {
@@ -347,6 +902,75 @@ mod lint_options {
         let options = get_lint_options(".");
         assert_eq!(options.paths, vec![PathBuf::from(".")]);
         assert!(!options.fix_options.fix);
+        assert!(!options.syntax_only);
+    }
+
+    #[test]
+    fn check_config() {
+        let args = ["check-config", "oxlint.config.json"];
+        let command = lint_command().run_inner(args.as_slice()).unwrap();
+        assert_eq!(command.check_config.unwrap().path, Some(PathBuf::from("oxlint.config.json")));
+
+        let command = lint_command().run_inner(["check-config"].as_slice()).unwrap();
+        assert_eq!(command.check_config.unwrap().path, None);
+        assert!(lint_command().run_inner(["src"].as_slice()).unwrap().check_config.is_none());
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(get_lint_options("src").format, OutputFormat::Graphical);
+        assert_eq!(get_lint_options("--format json src").format, OutputFormat::Json);
+        assert_eq!(get_lint_options("-f github src").format, OutputFormat::Github);
+        assert_eq!(get_lint_options("-f stream src").format, OutputFormat::Stream);
+        let args = ["--format", "html", "src"];
+        assert!(lint_command().run_inner(args.as_slice()).is_err());
+    }
+
+    #[test]
+    fn syntax_only() {
+        let options = get_lint_options("--syntax-only src");
+        assert!(options.syntax_only);
+    }
+
+    #[test]
+    fn force() {
+        let options = get_lint_options("--force bin/script");
+        assert!(options.force);
+        assert!(!get_lint_options("bin/script").force);
+    }
+
+    #[test]
+    fn files_from() {
+        let options = get_lint_options("--files-from -");
+        assert_eq!(options.files_from, Some(PathBuf::from("-")));
+        assert!(options.paths.is_empty());
+        assert!(get_lint_options("foo").files_from.is_none());
+    }
+
+    #[test]
+    fn cache() {
+        let options = get_lint_options("--cache --cache-location .cache/oxlint src");
+        assert!(options.cache);
+        assert_eq!(options.cache_location, Some(PathBuf::from(".cache/oxlint")));
+        let options = get_lint_options("src");
+        assert!(!options.cache);
+        assert_eq!(options.cache_location, None);
+    }
+
+    #[test]
+    fn watch() {
+        let options = get_lint_options("--watch src");
+        assert!(options.watch);
+        assert_eq!(options.paths, vec![PathBuf::from("src")]);
+        assert!(!get_lint_options("src").watch);
+    }
+
+    #[test]
+    fn server_lite() {
+        let options = get_lint_options("--server-lite --import-plugin");
+        assert!(options.server_lite);
+        assert!(options.paths.is_empty());
+        assert!(!get_lint_options("src").server_lite);
     }
 
     #[test]
@@ -364,17 +988,65 @@ mod lint_options {
         assert!(options.fix_options.fix);
     }
 
+    #[test]
+    fn fix_unsafe() {
+        let options = get_lint_options("--fix-unsafe test.js");
+        assert!(options.fix_options.fix_unsafe);
+        assert!(!get_lint_options("--fix test.js").fix_options.fix_unsafe);
+    }
+
+    #[test]
+    fn fix_suppress() {
+        let options = get_lint_options("--fix-suppress no-console --fix-suppress eqeqeq test.js");
+        assert_eq!(options.fix_options.fix_suppress, vec!["no-console", "eqeqeq"]);
+        assert!(get_lint_options("--fix test.js").fix_options.fix_suppress.is_empty());
+    }
+
+    #[test]
+    fn fix_dry_run() {
+        let options = get_lint_options("--fix-dry-run test.js");
+        assert!(options.fix_options.fix_dry_run);
+        assert!(!options.fix_options.fix_diff);
+        assert!(get_lint_options("--fix-diff test.js").fix_options.fix_diff);
+        assert!(!get_lint_options("--fix test.js").fix_options.fix_dry_run);
+    }
+
+    #[test]
+    fn newline() {
+        let options = get_lint_options("--fix --newline crlf test.js");
+        assert_eq!(options.fix_options.newline, Newline::Crlf);
+        assert_eq!(get_lint_options("--fix test.js").fix_options.newline, Newline::Auto);
+    }
+
+    #[test]
+    fn tsconfig() {
+        let options = get_lint_options("--import-plugin --tsconfig tsconfig.base.json src");
+        assert!(options.enable_plugins.import_plugin);
+        assert_eq!(options.enable_plugins.tsconfig, Some(PathBuf::from("tsconfig.base.json")));
+        assert_eq!(get_lint_options("src").enable_plugins.tsconfig, None);
+    }
+
+    #[test]
+    fn resolve_cache() {
+        let options = get_lint_options("--import-plugin --resolve-cache .cache/resolve.json src");
+        let path = PathBuf::from(".cache/resolve.json");
+        assert_eq!(options.enable_plugins.resolve_cache, Some(path));
+        assert_eq!(get_lint_options("src").enable_plugins.resolve_cache, None);
+    }
+
     #[test]
     fn filter() {
-        let options =
-            get_lint_options("-D suspicious --deny pedantic -A no-debugger --allow no-var src");
+        let options = get_lint_options(
+            "-D suspicious --deny pedantic -A no-debugger --allow no-var -W import/* src",
+        );
         assert_eq!(
             options.filter,
             [
                 (AllowWarnDeny::Deny, "suspicious".into()),
                 (AllowWarnDeny::Deny, "pedantic".into()),
                 (AllowWarnDeny::Allow, "no-debugger".into()),
-                (AllowWarnDeny::Allow, "no-var".into())
+                (AllowWarnDeny::Allow, "no-var".into()),
+                (AllowWarnDeny::Warn, "import/*".into())
             ]
         );
     }
@@ -396,6 +1068,13 @@ mod ignore_options {
         assert_eq!(options.ignore_path, OsString::from(".eslintignore"));
         assert!(!options.no_ignore);
         assert!(options.ignore_pattern.is_empty());
+        assert_eq!(options.max_file_size, super::DEFAULT_MAX_FILE_SIZE);
+    }
+
+    #[test]
+    fn max_file_size() {
+        let options = get_ignore_options("--max-file-size 1024 foo.js");
+        assert_eq!(options.max_file_size, 1024);
     }
 
     #[test]
@@ -422,3 +1101,204 @@ mod ignore_options {
         assert_eq!(options.ignore_pattern, vec![String::from("./test"), String::from("bar.js")]);
     }
 }
+
+#[cfg(test)]
+mod transform_options {
+    use super::{cli_command, CliCommand, JsxRuntime, TransformOptions};
+    use oxc_transformer::{ModuleFormat, TransformTarget};
+    use std::path::PathBuf;
+
+    fn get_transform_options(arg: &str) -> TransformOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        match cli_command().run_inner(args.as_slice()).unwrap() {
+            CliCommand::Transform(options) => options,
+            command => panic!("expected the transform command, received {command:?}"),
+        }
+    }
+
+    #[test]
+    fn default() {
+        let options = get_transform_options("transform src");
+        assert_eq!(options.paths, vec![PathBuf::from("src")]);
+        assert_eq!(options.target, TransformTarget::ESNext);
+        assert_eq!(options.module, ModuleFormat::Esm);
+        assert_eq!(options.jsx_runtime, JsxRuntime::Automatic);
+        assert!(options.jsx_import_source.is_none());
+        assert!(options.out_dir.is_none());
+        assert!(!options.source_map);
+    }
+
+    #[test]
+    fn jsx() {
+        let options =
+            get_transform_options("transform --jsx-runtime classic --jsx-import-source preact src");
+        assert_eq!(options.jsx_runtime, JsxRuntime::Classic);
+        assert_eq!(options.jsx_import_source, Some("preact".to_string()));
+        assert!(!options.react_refresh);
+        let options = get_transform_options("transform --react-refresh src");
+        assert!(options.react_refresh);
+    }
+
+    #[test]
+    fn module() {
+        let options = get_transform_options("transform --module cjs src");
+        assert_eq!(options.module, ModuleFormat::CommonJs);
+    }
+
+    #[test]
+    fn invalid_jsx_runtime() {
+        let args = ["transform", "--jsx-runtime", "foo", "src"].map(String::from);
+        assert!(cli_command().run_inner(args.as_slice()).is_err());
+    }
+
+    #[test]
+    fn target() {
+        let options = get_transform_options("transform --target es2018 src");
+        assert_eq!(options.target, TransformTarget::ES2018);
+        let options = get_transform_options("transform --target ES5 src");
+        assert_eq!(options.target, TransformTarget::ES5);
+    }
+
+    #[test]
+    fn invalid_target() {
+        let args = ["transform", "--target", "es2000", "src"].map(String::from);
+        assert!(cli_command().run_inner(args.as_slice()).is_err());
+    }
+
+    #[test]
+    fn output() {
+        let options = get_transform_options("transform --out-dir dist --source-map src");
+        assert_eq!(options.out_dir, Some(PathBuf::from("dist")));
+        assert!(options.source_map);
+    }
+}
+
+#[cfg(test)]
+mod graph_options {
+    use super::{cli_command, CliCommand, GraphFormat, GraphOptions};
+    use std::path::PathBuf;
+
+    fn get_graph_options(arg: &str) -> GraphOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        match cli_command().run_inner(args.as_slice()).unwrap() {
+            CliCommand::Graph(options) => options,
+            command => panic!("expected the graph command, received {command:?}"),
+        }
+    }
+
+    #[test]
+    fn default() {
+        let options = get_graph_options("graph src");
+        assert_eq!(options.paths, vec![PathBuf::from("src")]);
+        assert_eq!(options.format, GraphFormat::Json);
+        assert!(!options.collapse_node_modules);
+        assert!(!options.highlight_cycles);
+    }
+
+    #[test]
+    fn dot() {
+        let options =
+            get_graph_options("graph --format dot --collapse-node-modules --highlight-cycles src");
+        assert_eq!(options.format, GraphFormat::Dot);
+        assert!(options.collapse_node_modules);
+        assert!(options.highlight_cycles);
+    }
+
+    #[test]
+    fn invalid_format() {
+        let args = ["graph", "--format", "svg", "src"].map(String::from);
+        assert!(cli_command().run_inner(args.as_slice()).is_err());
+    }
+}
+
+#[cfg(test)]
+mod search_options {
+    use super::{cli_command, CliCommand, SearchFormat, SearchOptions};
+    use std::path::PathBuf;
+
+    fn get_search_options(args: &[&str]) -> SearchOptions {
+        let args = args.iter().map(std::string::ToString::to_string).collect::<Vec<_>>();
+        match cli_command().run_inner(args.as_slice()).unwrap() {
+            CliCommand::Search(options) => options,
+            command => panic!("expected the search command, received {command:?}"),
+        }
+    }
+
+    #[test]
+    fn selector() {
+        let options = get_search_options(&["search", "--selector", "DebuggerStatement", "src"]);
+        assert_eq!(options.selector, Some("DebuggerStatement".to_string()));
+        assert_eq!(options.pattern, None);
+        assert_eq!(options.format, SearchFormat::Default);
+        assert_eq!(options.paths, vec![PathBuf::from("src")]);
+    }
+
+    #[test]
+    fn pattern() {
+        let options =
+            get_search_options(&["search", "--pattern", "foo($A, $B)", "--format", "json", "src"]);
+        assert_eq!(options.pattern, Some("foo($A, $B)".to_string()));
+        assert_eq!(options.format, SearchFormat::Json);
+    }
+}
+
+#[cfg(test)]
+mod codemod_options {
+    use super::{cli_command, CliCommand, CodemodOptions};
+    use std::path::PathBuf;
+
+    fn get_codemod_options(arg: &str) -> CodemodOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        match cli_command().run_inner(args.as_slice()).unwrap() {
+            CliCommand::Codemod(options) => options,
+            command => panic!("expected the codemod command, received {command:?}"),
+        }
+    }
+
+    #[test]
+    fn default() {
+        let options = get_codemod_options("codemod --rules rules.toml src");
+        assert_eq!(options.rules, PathBuf::from("rules.toml"));
+        assert!(!options.dry_run);
+        assert_eq!(options.paths, vec![PathBuf::from("src")]);
+    }
+
+    #[test]
+    fn dry_run() {
+        assert!(get_codemod_options("codemod --rules rules.json --dry-run src").dry_run);
+    }
+
+    #[test]
+    fn missing_rules() {
+        let args = ["codemod", "src"].map(String::from);
+        assert!(cli_command().run_inner(args.as_slice()).is_err());
+    }
+}
+
+#[cfg(test)]
+mod env_options {
+    use std::{env, ffi::OsString};
+
+    use super::lint_command;
+
+    fn get_codeowners_file(arg: &str) -> Option<OsString> {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command()
+            .run_inner(args.as_slice())
+            .unwrap()
+            .lint_options
+            .codeowner_options
+            .codeowners_file
+    }
+
+    #[test]
+    fn fallback() {
+        // Tests run in parallel in one process, the variable is not read by other tests.
+        env::set_var("OXLINT_CODEOWNERS_FILE", "CODEOWNERS");
+        let from_env = get_codeowners_file(".");
+        let from_cli = get_codeowners_file("--codeowners-file .github/CODEOWNERS .");
+        env::remove_var("OXLINT_CODEOWNERS_FILE");
+        assert_eq!(from_env, Some(OsString::from("CODEOWNERS")));
+        assert_eq!(from_cli, Some(OsString::from(".github/CODEOWNERS")));
+    }
+}
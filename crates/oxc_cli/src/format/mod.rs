@@ -1,6 +1,7 @@
 use std::path::Path;
 
 use oxc_allocator::Allocator;
+use oxc_linter::is_binary;
 use oxc_parser::Parser;
 use oxc_prettier::{Prettier, PrettierOptions};
 use oxc_span::SourceType;
@@ -49,9 +50,19 @@ impl Runner for FormatRunner {
 impl FormatRunner {
     fn format(path: &Path) {
         let source_text = std::fs::read_to_string(path).unwrap();
+        if is_binary(source_text.as_bytes()) {
+            return;
+        }
         let allocator = Allocator::default();
         let source_type = SourceType::from_path(path).unwrap();
-        let ret = Parser::new(&allocator, &source_text, source_type).preserve_parens(false).parse();
+        // Statements with syntax errors are kept as written, the rest of the file is formatted
+        let ret = Parser::new(&allocator, &source_text, source_type)
+            .preserve_parens(false)
+            .error_recovery(true)
+            .parse();
+        if ret.panicked {
+            return;
+        }
         let _ = Prettier::new(&allocator, &source_text, ret.trivias, PrettierOptions::default())
             .build(&ret.program);
     }
@@ -0,0 +1,340 @@
+use std::{
+    fmt::Write,
+    path::{Component, Path},
+};
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_resolver::{ResolveOptions, Resolver, TsconfigOptions, TsconfigReferences};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{SourceType, VALID_EXTENSIONS};
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use rustc_hash::FxHashMap;
+use serde_json::json;
+
+use crate::{
+    command::{GraphFormat, GraphOptions},
+    result::CliRunResult,
+    walk::Walk,
+    Runner,
+};
+
+pub struct GraphRunner {
+    options: GraphOptions,
+}
+
+impl Runner for GraphRunner {
+    type Options = GraphOptions;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        let GraphOptions { paths, ignore_options, .. } = &self.options;
+
+        if paths.is_empty() {
+            return CliRunResult::InvalidOptions { message: "No paths are provided.".to_string() };
+        }
+
+        let cwd = std::env::current_dir().unwrap();
+        let paths = Walk::new(paths, ignore_options).paths();
+        let resolver = self.resolver(&cwd);
+
+        // `(file, resolved imports)` in walk order
+        let imports = paths
+            .par_iter()
+            .map(|path| {
+                let path = cwd.join(path);
+                let dir = path.parent().unwrap_or(&cwd);
+                let resolved = requested_modules(&path)
+                    .iter()
+                    .filter_map(|specifier| resolver.resolve(dir, specifier).ok())
+                    .map(|resolution| resolution.path().to_path_buf())
+                    .collect::<Vec<_>>();
+                (path, resolved)
+            })
+            .collect::<Vec<_>>();
+
+        let mut graph = ModuleGraph::default();
+        for (path, resolved) in imports {
+            let from = graph.add_node(self.node_name(&cwd, &path));
+            for path in resolved {
+                let to = graph.add_node(self.node_name(&cwd, &path));
+                // Imports between files of the same collapsed package are not interesting
+                if from != to || !self.options.collapse_node_modules {
+                    graph.add_edge(from, to);
+                }
+            }
+        }
+
+        let output = match self.options.format {
+            GraphFormat::Json => graph.to_json(),
+            GraphFormat::Dot => graph.to_dot(self.options.highlight_cycles),
+        };
+        println!("{output}");
+
+        CliRunResult::None
+    }
+}
+
+impl GraphRunner {
+    fn resolver(&self, cwd: &Path) -> Resolver {
+        resolver(cwd, self.options.tsconfig.as_deref())
+    }
+
+    /// Paths relative to the current working directory, or the package name for files inside
+    /// `node_modules` with `--collapse-node-modules`.
+    fn node_name(&self, cwd: &Path, path: &Path) -> String {
+        if self.options.collapse_node_modules {
+            if let Some(package) = node_modules_package(path) {
+                return package;
+            }
+        }
+        let path = path.strip_prefix(cwd).unwrap_or(path);
+        path.to_string_lossy().replace('\\', "/")
+    }
+}
+
+/// Resolver of the specifiers of the files in `cwd`, with the `paths` of `tsconfig` or else of the
+/// `tsconfig.json` of `cwd`.
+pub(crate) fn resolver(cwd: &Path, tsconfig: Option<&Path>) -> Resolver {
+    let tsconfig = tsconfig.map_or_else(
+        || Some(cwd.join("tsconfig.json")).filter(|path| path.is_file()),
+        |tsconfig| Some(cwd.join(tsconfig)),
+    );
+    Resolver::new(ResolveOptions {
+        extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+        tsconfig: tsconfig.map(|config_file| TsconfigOptions {
+            config_file,
+            references: TsconfigReferences::Auto,
+        }),
+        ..ResolveOptions::default()
+    })
+}
+
+/// Specifiers of all `import` and `export ... from` declarations of the file at `path`.
+pub(crate) fn requested_modules(path: &Path) -> Vec<String> {
+    let Ok(source_text) = std::fs::read_to_string(path) else { return vec![] };
+    let Ok(source_type) = SourceType::from_path(path) else { return vec![] };
+    let allocator = Allocator::default();
+    // Files with syntax errors still contribute the imports the parser could recover
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    let program = allocator.alloc(ret.program);
+    let semantic = SemanticBuilder::new(&source_text, source_type)
+        .build_module_record(path.to_path_buf(), program);
+    let module_record = semantic.module_record();
+    module_record.requested_modules.keys().map(ToString::to_string).collect()
+}
+
+/// `node_modules/@scope/name/lib/index.js` -> `@scope/name`, for the innermost `node_modules`.
+fn node_modules_package(path: &Path) -> Option<String> {
+    let components = path.components().collect::<Vec<_>>();
+    let index = components.iter().rposition(|c| c.as_os_str() == "node_modules")?;
+    let mut names = components[index + 1..].iter().filter_map(|c| match c {
+        Component::Normal(name) => name.to_str(),
+        _ => None,
+    });
+    let name = names.next()?;
+    if name.starts_with('@') {
+        return Some(format!("{name}/{}", names.next()?));
+    }
+    Some(name.to_string())
+}
+
+#[derive(Debug, Default)]
+struct ModuleGraph {
+    nodes: Vec<String>,
+    node_ids: FxHashMap<String, usize>,
+    /// Sorted and deduplicated outgoing edges of each node
+    edges: Vec<Vec<usize>>,
+}
+
+impl ModuleGraph {
+    fn add_node(&mut self, name: String) -> usize {
+        if let Some(&id) = self.node_ids.get(&name) {
+            return id;
+        }
+        let id = self.nodes.len();
+        self.node_ids.insert(name.clone(), id);
+        self.nodes.push(name);
+        self.edges.push(vec![]);
+        id
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize) {
+        let edges = &mut self.edges[from];
+        if let Err(index) = edges.binary_search(&to) {
+            edges.insert(index, to);
+        }
+    }
+
+    /// Strongly connected components with more than one node or a self import,
+    /// found with Tarjan's algorithm.
+    fn cycles(&self) -> Vec<Vec<usize>> {
+        Tarjan::new(self).run()
+    }
+
+    fn to_json(&self) -> String {
+        let modules = self
+            .nodes
+            .iter()
+            .zip(&self.edges)
+            .map(|(name, edges)| {
+                let imports = edges.iter().map(|&to| &self.nodes[to]).collect::<Vec<_>>();
+                json!({ "path": name, "imports": imports })
+            })
+            .collect::<Vec<_>>();
+        let cycles = self
+            .cycles()
+            .into_iter()
+            .map(|cycle| cycle.into_iter().map(|id| &self.nodes[id]).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let graph = json!({ "modules": modules, "cycles": cycles });
+        serde_json::to_string_pretty(&graph).unwrap()
+    }
+
+    fn to_dot(&self, highlight_cycles: bool) -> String {
+        // Component index of every node in a cycle
+        let mut cycle_of = vec![None; self.nodes.len()];
+        if highlight_cycles {
+            for (index, cycle) in self.cycles().into_iter().enumerate() {
+                for id in cycle {
+                    cycle_of[id] = Some(index);
+                }
+            }
+        }
+
+        let mut dot = String::from("digraph {\n");
+        for (id, name) in self.nodes.iter().enumerate() {
+            let color = if cycle_of[id].is_some() { ", color=red" } else { "" };
+            writeln!(dot, "    {id} [label={name:?}{color}];").unwrap();
+        }
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &to in edges {
+                let in_cycle = cycle_of[from].is_some() && cycle_of[from] == cycle_of[to];
+                let color = if in_cycle { " [color=red]" } else { "" };
+                writeln!(dot, "    {from} -> {to}{color};").unwrap();
+            }
+        }
+        dot.push('}');
+        dot
+    }
+}
+
+struct Tarjan<'g> {
+    graph: &'g ModuleGraph,
+    index: usize,
+    indices: Vec<Option<usize>>,
+    low_links: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    components: Vec<Vec<usize>>,
+}
+
+impl<'g> Tarjan<'g> {
+    fn new(graph: &'g ModuleGraph) -> Self {
+        let len = graph.nodes.len();
+        Self {
+            graph,
+            index: 0,
+            indices: vec![None; len],
+            low_links: vec![0; len],
+            on_stack: vec![false; len],
+            stack: vec![],
+            components: vec![],
+        }
+    }
+
+    fn run(mut self) -> Vec<Vec<usize>> {
+        for node in 0..self.graph.nodes.len() {
+            if self.indices[node].is_none() {
+                self.visit(node);
+            }
+        }
+        self.components
+    }
+
+    /// Iterative to not overflow the stack on long import chains.
+    fn visit(&mut self, root: usize) {
+        // `(node, index of the next edge to follow)`
+        let mut work = vec![(root, 0)];
+        self.open(root);
+        while let Some(&(node, next_edge)) = work.last() {
+            if let Some(&to) = self.graph.edges[node].get(next_edge) {
+                work.last_mut().unwrap().1 += 1;
+                match self.indices[to] {
+                    None => {
+                        self.open(to);
+                        work.push((to, 0));
+                    }
+                    Some(index) if self.on_stack[to] => {
+                        self.low_links[node] = self.low_links[node].min(index);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+            work.pop();
+            if let Some(&(parent, _)) = work.last() {
+                self.low_links[parent] = self.low_links[parent].min(self.low_links[node]);
+            }
+            if Some(self.low_links[node]) == self.indices[node] {
+                self.close(node);
+            }
+        }
+    }
+
+    fn open(&mut self, node: usize) {
+        self.indices[node] = Some(self.index);
+        self.low_links[node] = self.index;
+        self.index += 1;
+        self.stack.push(node);
+        self.on_stack[node] = true;
+    }
+
+    fn close(&mut self, node: usize) {
+        let mut component = vec![];
+        while let Some(member) = self.stack.pop() {
+            self.on_stack[member] = false;
+            component.push(member);
+            if member == node {
+                break;
+            }
+        }
+        if component.len() > 1 || self.graph.edges[node].contains(&node) {
+            component.sort_unstable();
+            self.components.push(component);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{node_modules_package, ModuleGraph};
+
+    #[test]
+    fn cycles() {
+        let mut graph = ModuleGraph::default();
+        for (from, to) in [("a", "b"), ("b", "c"), ("c", "a"), ("c", "d"), ("e", "e"), ("d", "f")] {
+            let from = graph.add_node(from.to_string());
+            let to = graph.add_node(to.to_string());
+            graph.add_edge(from, to);
+        }
+        assert_eq!(graph.cycles(), vec![vec![0, 1, 2], vec![4]]);
+        let dot = graph.to_dot(true);
+        assert!(dot.contains("0 -> 1 [color=red];"));
+        assert!(dot.contains("2 -> 3;"));
+    }
+
+    #[test]
+    fn collapse_node_modules() {
+        let path = Path::new("/app/node_modules/@babel/core/lib/index.js");
+        assert_eq!(node_modules_package(path), Some("@babel/core".to_string()));
+        let path = Path::new("/app/node_modules/a/node_modules/b/index.js");
+        assert_eq!(node_modules_package(path), Some("b".to_string()));
+        assert_eq!(node_modules_package(Path::new("/app/src/index.js")), None);
+    }
+}
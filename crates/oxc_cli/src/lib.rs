@@ -1,17 +1,25 @@
+mod codemod;
 mod codeowners;
 mod command;
 mod format;
+mod graph;
 mod lint;
 mod result;
 mod runner;
+mod search;
+mod transform;
 mod type_check;
 mod walk;
 
 pub use crate::{
+    codemod::CodemodRunner,
     command::*,
     format::FormatRunner,
+    graph::GraphRunner,
     lint::LintRunner,
     result::{CliRunResult, LintResult},
     runner::Runner,
+    search::SearchRunner,
+    transform::TransformRunner,
     type_check::TypeCheckRunner,
 };
@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+use oxc_diagnostics::GraphicalReportHandler;
+use oxc_linter::check_config;
+
+use super::{LintRunner, DEFAULT_ESLINTRC_CONFIG, DEFAULT_FLAT_CONFIG};
+use crate::CliRunResult;
+
+impl LintRunner {
+    /// Validate the config file at `path`, printing all of its problems.
+    pub fn check_config(path: Option<PathBuf>) -> CliRunResult {
+        let Some(path) = path.or_else(|| {
+            let cwd = std::env::current_dir().ok()?;
+            [DEFAULT_FLAT_CONFIG, DEFAULT_ESLINTRC_CONFIG]
+                .into_iter()
+                .map(|name| cwd.join(name))
+                .find(|path| path.is_file())
+        }) else {
+            return CliRunResult::InvalidOptions {
+                message: format!(
+                    "No `{DEFAULT_FLAT_CONFIG}` or `{DEFAULT_ESLINTRC_CONFIG}` found in the current directory."
+                ),
+            };
+        };
+        if !path.is_file() {
+            return CliRunResult::PathNotFound { paths: vec![path] };
+        }
+
+        let problems = check_config(&path);
+        let handler = GraphicalReportHandler::new();
+        for problem in &problems {
+            let mut output = String::new();
+            handler.render_report(&mut output, problem.as_ref()).unwrap();
+            eprintln!("{output}");
+        }
+        CliRunResult::ConfigCheckResult { path, number_of_problems: problems.len() }
+    }
+}
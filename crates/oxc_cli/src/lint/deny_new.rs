@@ -0,0 +1,203 @@
+//! Diagnostics introduced since a git revision for `--deny-new --base <REF>`.
+//!
+//! The files of the base revision are linted with the same linters and their diagnostics are
+//! matched against the current ones by fingerprint, so only the remaining diagnostics are
+//! reported and counted.
+
+use std::{
+    io,
+    path::{Component, Path, PathBuf},
+    process::Command,
+    sync::{mpsc, Arc},
+    thread,
+};
+
+use oxc_diagnostics::{DiagnosticSender, DiagnosticTuple, Error};
+use oxc_linter::{LintFileSystem, LintService, Linter};
+use rustc_hash::FxHashMap;
+
+/// Files of a git revision, read with `git show`.
+pub struct GitFileSystem {
+    cwd: Box<Path>,
+    revision: String,
+}
+
+impl GitFileSystem {
+    pub fn new(cwd: Box<Path>, revision: String) -> Self {
+        Self { cwd, revision }
+    }
+
+    /// Whether `revision` names a commit of the repository of `cwd`.
+    pub fn is_revision(cwd: &Path, revision: &str) -> bool {
+        Command::new("git")
+            .current_dir(cwd)
+            .args(["rev-parse", "--verify", "--quiet", &format!("{revision}^{{commit}}")])
+            .output()
+            .is_ok_and(|output| output.status.success())
+    }
+}
+
+impl LintFileSystem for GitFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+        // `<rev>:./<path>` is relative to the working directory of git
+        let path = path
+            .components()
+            .filter(|component| !matches!(component, Component::CurDir))
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let output = Command::new("git")
+            .current_dir(&self.cwd)
+            .arg("show")
+            .arg(format!("{}:./{path}", self.revision))
+            .output()?;
+        if !output.status.success() {
+            return Err(io::Error::from(io::ErrorKind::NotFound));
+        }
+        String::from_utf8(output.stdout)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    fn write(&self, _path: &Path, _contents: &str) -> io::Result<()> {
+        Err(io::Error::from(io::ErrorKind::Unsupported))
+    }
+}
+
+/// A diagnostic is the same in both revisions when it has the same file, message and code at its
+/// first label, wherever the lines moved.
+type Fingerprint = (PathBuf, String, String);
+
+fn fingerprint(path: &Path, diagnostic: &Error) -> Fingerprint {
+    let code = diagnostic
+        .labels()
+        .and_then(|mut labels| labels.next())
+        .and_then(|label| {
+            let contents = diagnostic.source_code()?.read_span(label.inner(), 0, 0).ok()?;
+            Some(String::from_utf8_lossy(contents.data()).to_string())
+        })
+        .unwrap_or_default();
+    (path.to_path_buf(), diagnostic.to_string(), code)
+}
+
+/// Number of diagnostics of the base revision per fingerprint.
+#[derive(Debug, Default)]
+pub struct BaseDiagnostics(FxHashMap<Fingerprint, usize>);
+
+impl BaseDiagnostics {
+    /// Lint the base revision with `services`, which read from a [GitFileSystem].
+    pub fn collect(services: &[LintService]) -> Self {
+        let (tx_error, rx_error) = mpsc::channel();
+        for service in services {
+            service.run(&tx_error);
+        }
+        drop(tx_error);
+        let mut counts = FxHashMap::default();
+        for (path, diagnostics) in rx_error.into_iter().flatten() {
+            for diagnostic in &diagnostics {
+                *counts.entry(fingerprint(&path, diagnostic)).or_default() += 1;
+            }
+        }
+        Self(counts)
+    }
+
+    /// Forward the diagnostics of `services` lint services which are not in the base revision
+    /// to `tx_error`, along with the end of the diagnostics of each service.
+    pub fn spawn_filter(
+        mut self,
+        services: usize,
+        rx_error: mpsc::Receiver<Option<DiagnosticTuple>>,
+        tx_error: DiagnosticSender,
+    ) {
+        thread::spawn(move || {
+            let mut remaining = services;
+            for message in rx_error {
+                let Some((path, diagnostics)) = message else {
+                    tx_error.send(None).unwrap();
+                    remaining -= 1;
+                    if remaining == 0 {
+                        break;
+                    }
+                    continue;
+                };
+                let diagnostics = self.retain_new(&path, diagnostics);
+                tx_error.send(Some((path, diagnostics))).unwrap();
+            }
+        });
+    }
+
+    /// Each diagnostic of the base revision matches one diagnostic with the same fingerprint.
+    fn retain_new(&mut self, path: &Path, diagnostics: Vec<Error>) -> Vec<Error> {
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| match self.0.get_mut(&fingerprint(path, diagnostic)) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            })
+            .collect()
+    }
+}
+
+/// Lint services reading the files of `revision`, a service for each group of paths.
+pub fn base_services(
+    cwd: &Path,
+    revision: &str,
+    linters: Vec<Linter>,
+    groups: &[Vec<Box<Path>>],
+) -> Vec<LintService> {
+    let file_system = Arc::new(GitFileSystem::new(cwd.into(), revision.to_string()));
+    linters
+        .into_iter()
+        .zip(groups)
+        .map(|(linter, paths)| {
+            LintService::with_file_system(cwd.into(), paths, linter, Arc::clone(&file_system))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_diagnostics::{
+        miette::{self, Diagnostic},
+        thiserror::{self, Error},
+        DiagnosticService,
+    };
+    use oxc_span::Span;
+
+    use super::{fingerprint, BaseDiagnostics};
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("eslint(no-debugger): `debugger` statement is not allowed")]
+    struct NoDebugger(#[label] Span);
+
+    fn diagnostics(source_text: &str) -> Vec<oxc_diagnostics::Error> {
+        let path = Path::new("a.js");
+        let errors = source_text
+            .match_indices("debugger")
+            .map(|(start, _)| {
+                let start = u32::try_from(start).unwrap();
+                NoDebugger(Span::new(start, start + 8)).into()
+            })
+            .collect();
+        DiagnosticService::wrap_diagnostics(path, source_text, errors).1
+    }
+
+    #[test]
+    fn only_new_diagnostics() {
+        let path = Path::new("a.js");
+        let base = diagnostics("debugger;");
+        let mut base_diagnostics = BaseDiagnostics(
+            base.iter().map(|diagnostic| (fingerprint(path, diagnostic), 1)).collect(),
+        );
+
+        // The existing statement moved to the third line, a second one was added.
+        let current = diagnostics("foo();\n\ndebugger;\ndebugger;");
+        let new = base_diagnostics.retain_new(path, current);
+        assert_eq!(new.len(), 1);
+    }
+}
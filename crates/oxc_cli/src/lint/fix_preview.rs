@@ -0,0 +1,128 @@
+//! `--fix-dry-run` and `--fix-diff`: the fixes are kept in memory instead of being written, then
+//! listed or printed as a unified diff once all files are linted.
+
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use oxc_linter::{LintFileSystem, OsFileSystem};
+use similar::TextDiff;
+
+/// Reads the files from the disk and keeps the fixed code of the files which would change.
+#[derive(Debug, Default)]
+pub struct FixPreview {
+    file_system: OsFileSystem,
+    /// Path, source text and fixed code of each fixed file
+    fixes: Mutex<Vec<(PathBuf, String, String)>>,
+}
+
+impl FixPreview {
+    /// Paths of the fixed files, relative to `cwd` and sorted.
+    fn fixes(&self, cwd: &Path) -> Vec<(PathBuf, String, String)> {
+        let mut fixes = std::mem::take(&mut *self.fixes.lock().unwrap())
+            .into_iter()
+            .map(|(path, source_text, fixed_code)| {
+                let path = path.strip_prefix(cwd).map_or_else(|_| path.clone(), Path::to_path_buf);
+                (path, source_text, fixed_code)
+            })
+            .collect::<Vec<_>>();
+        fixes.sort_by(|a, b| a.0.cmp(&b.0));
+        fixes
+    }
+
+    /// Print the fixes as a patch which `git apply` or `patch -p1` applies, or only the paths of
+    /// the fixed files.
+    pub fn print<W: Write>(&self, writer: &mut W, cwd: &Path, diff: bool) {
+        let fixes = self.fixes(cwd);
+        for (path, source_text, fixed_code) in &fixes {
+            let name = path.to_string_lossy().replace('\\', "/");
+            if diff {
+                let diff = TextDiff::from_lines(source_text, fixed_code);
+                write!(
+                    writer,
+                    "{}",
+                    diff.unified_diff().header(&format!("a/{name}"), &format!("b/{name}"))
+                )
+                .unwrap();
+            } else {
+                writeln!(writer, "Would fix {name}").unwrap();
+            }
+        }
+        if !diff {
+            writeln!(writer, "Would fix {} files.", fixes.len()).unwrap();
+        }
+    }
+}
+
+impl LintFileSystem for FixPreview {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.file_system.read_to_string(path)
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()> {
+        let source_text = self.file_system.read_to_string(path)?;
+        if source_text != contents {
+            self.fixes.lock().unwrap().push((
+                path.to_path_buf(),
+                source_text,
+                contents.to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_linter::LintFileSystem;
+
+    use super::FixPreview;
+
+    #[test]
+    fn keeps_changed_files() {
+        let preview = FixPreview::default();
+        let path = Path::new("fixtures/linter/debugger.js");
+        let source_text = preview.read_to_string(path).unwrap();
+        preview.write(path, &source_text).unwrap();
+        preview.write(path, "").unwrap();
+        let fixes = preview.fixes(Path::new("fixtures"));
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].0, Path::new("linter/debugger.js"));
+        assert_eq!(fixes[0].1, source_text);
+        // The file on disk is left as it is
+        assert_eq!(preview.read_to_string(path).unwrap(), source_text);
+    }
+
+    #[test]
+    fn fix_diff() {
+        let preview = FixPreview::default();
+        let path = Path::new("fixtures/linter/debugger.js");
+        preview.write(path, "").unwrap();
+        let mut output = vec![];
+        preview.print(&mut output, Path::new("fixtures"), true);
+        let output = String::from_utf8(output).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "--- a/linter/debugger.js");
+        assert_eq!(lines[1], "+++ b/linter/debugger.js");
+        assert!(lines[2].starts_with("@@ -1"));
+        assert_eq!(lines[3], "-debugger;");
+        assert!(!output.contains("Would fix"));
+    }
+
+    #[test]
+    fn fix_dry_run() {
+        let preview = FixPreview::default();
+        preview.write(Path::new("fixtures/linter/debugger.js"), "").unwrap();
+        preview.write(Path::new("fixtures/linter/nan.js"), "").unwrap();
+        let mut output = vec![];
+        preview.print(&mut output, Path::new("fixtures"), false);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Would fix linter/debugger.js\nWould fix linter/nan.js\nWould fix 2 files.\n"
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use oxc_linter::{AllowWarnDeny, Linter};
+
+use super::{workspace::Workspace, LintRunner};
+use crate::{walk::Walk, CliRunResult};
+
+impl LintRunner {
+    /// Print for each path whether it would be linted, and if so with which configuration,
+    /// plugins and rules. Answers "why is my file not linted" without reading the walker code.
+    pub(super) fn inspect(&self) -> CliRunResult {
+        match self.inspect_output() {
+            Ok(output) => {
+                print!("{output}");
+                CliRunResult::None
+            }
+            Err(result) => result,
+        }
+    }
+
+    pub(super) fn inspect_output(&self) -> Result<String, CliRunResult> {
+        let options = &self.options;
+        if options.paths.is_empty() {
+            return Err(CliRunResult::InvalidOptions {
+                message: "`--inspect` needs the paths to inspect.".to_string(),
+            });
+        }
+
+        let cwd = std::env::current_dir().unwrap();
+        // The config and the package configs are resolved as when linting, see `LintRunner::run`.
+        let config = Self::effective_config(
+            &cwd,
+            options.config.clone(),
+            &options.filter,
+            &options.enable_plugins,
+        );
+        let workspace = Workspace::discover(&cwd);
+        let package_configs = workspace
+            .as_ref()
+            .map(|workspace| Self::package_configs(workspace, config.as_deref()))
+            .unwrap_or_default();
+
+        let mut output = String::new();
+        for path in &options.paths {
+            let absolute_path = cwd.join(path);
+            let package_config = workspace
+                .as_ref()
+                .and_then(|workspace| workspace.package_of(&absolute_path))
+                .and_then(|package| package_configs[package].clone());
+            let lint_options = Self::lint_options(
+                options.filter.clone(),
+                package_config.clone().or_else(|| config.clone()),
+                &options.fix_options,
+                options.misc_options.timing,
+                options.enable_plugins.clone(),
+            )
+            .with_no_ignore(options.ignore_options.no_ignore)
+            .with_force(options.force);
+            let lint_options = if package_config.is_some() {
+                lint_options.with_base_config_path(config.clone())
+            } else {
+                lint_options
+            };
+            let linter = match Linter::from_options(lint_options) {
+                Ok(linter) => linter,
+                Err(diagnostic) => {
+                    return Err(CliRunResult::InvalidOptions {
+                        message: format!("Failed to parse configuration file.\n{diagnostic:?}"),
+                    });
+                }
+            };
+
+            writeln!(output, "{}", path.display()).unwrap();
+            let reason = self.exclude_reason(&cwd, &absolute_path).or_else(|| {
+                linter.is_ignored(&absolute_path).then(|| {
+                    "the file is ignored by the `ignorePatterns` or `ignores` of the config"
+                        .to_string()
+                })
+            });
+            match reason {
+                Some(reason) => {
+                    writeln!(output, "  Linted: no, {reason}").unwrap();
+                }
+                None => {
+                    writeln!(output, "  Linted: yes").unwrap();
+                    let configs = Configs {
+                        config: config.as_deref(),
+                        package_config: package_config.as_deref(),
+                    };
+                    self.write_configuration(&mut output, &linter, &absolute_path, &configs);
+                }
+            }
+        }
+        Ok(output)
+    }
+
+    /// Why the walker would not yield `path`, checked in the order the walker applies them.
+    pub(super) fn exclude_reason(&self, cwd: &Path, path: &Path) -> Option<String> {
+        let ignore_options = &self.options.ignore_options;
+        if !path.exists() {
+            return Some("the path does not exist".to_string());
+        }
+        if path.is_dir() {
+            return Some(
+                "the path is a directory, inspect the files inside of it instead".to_string(),
+            );
+        }
+        if !self.options.force && !Walk::is_wanted_path(path, &Self::extensions()) {
+            return Some(
+                "the file extension is not supported or the file name marks a minified file"
+                    .to_string(),
+            );
+        }
+        if let Some(reason) = Walk::ignore_reason(cwd, path, ignore_options) {
+            return Some(format!("the file is ignored, {reason}"));
+        }
+        if let Some((_, reason)) = Walk::skip_reason(path, ignore_options.max_file_size) {
+            return Some(format!("the file is skipped, {reason}"));
+        }
+        if self.options.codeowner_options.codeowners_file.is_some() {
+            let relative_path = path.strip_prefix(cwd).unwrap_or(path);
+            let paths = vec![PathBuf::from(relative_path).into_boxed_path()];
+            match Self::apply_codeowners_file(&self.options.codeowner_options, paths) {
+                Ok(paths) if paths.is_empty() => {
+                    return Some("the file is not owned by any of `--codeowners`".to_string());
+                }
+                Ok(_) => {}
+                Err(_) => return Some("the codeowners file could not be read".to_string()),
+            }
+        }
+        None
+    }
+
+    fn write_configuration(
+        &self,
+        output: &mut String,
+        linter: &Linter,
+        path: &Path,
+        configs: &Configs,
+    ) {
+        let options = &self.options;
+        match (configs.package_config, configs.config) {
+            (Some(package_config), Some(config)) => writeln!(
+                output,
+                "  Config: {}, cascading from {}",
+                package_config.display(),
+                config.display()
+            )
+            .unwrap(),
+            (Some(config), None) | (None, Some(config)) => {
+                writeln!(output, "  Config: {}", config.display()).unwrap();
+            }
+            (None, None) if options.filter.is_empty() => {
+                writeln!(output, "  Config: none, using the default `-D correctness`").unwrap();
+            }
+            (None, None) => {
+                let filters = options
+                    .filter
+                    .iter()
+                    .map(|(severity, name)| {
+                        let flag = match severity {
+                            AllowWarnDeny::Allow => "-A",
+                            AllowWarnDeny::Warn => "-W",
+                            AllowWarnDeny::Deny => "-D",
+                        };
+                        format!("`{flag} {name}`")
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                writeln!(output, "  Config: none, using the filters {filters}").unwrap();
+            }
+        }
+
+        // The rules of the config objects and overrides which match the file
+        let rule_names = linter.rule_names_for(path);
+        let mut plugins = rule_names.iter().map(|(plugin, _)| plugin.as_str()).collect::<Vec<_>>();
+        plugins.sort_unstable();
+        plugins.dedup();
+        if options.enable_plugins.import_plugin {
+            plugins.push("import (module resolution)");
+        }
+        writeln!(output, "  Plugins: {}", plugins.join(", ")).unwrap();
+
+        let mut rules =
+            rule_names.iter().map(|(plugin, name)| format!("{plugin}/{name}")).collect::<Vec<_>>();
+        rules.sort_unstable();
+        writeln!(output, "  Rules ({}):", rules.len()).unwrap();
+        for rule in rules {
+            writeln!(output, "    {rule}").unwrap();
+        }
+    }
+}
+
+/// The configs a file is linted with.
+struct Configs<'a> {
+    /// `--config`, or the default flat config
+    config: Option<&'a Path>,
+    /// The config of the package of the file, cascading from `config`
+    package_config: Option<&'a Path>,
+}
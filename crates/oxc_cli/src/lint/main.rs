@@ -15,6 +15,9 @@ fn main() -> CliRunResult {
     init_miette();
 
     let command = oxc_cli::lint_command().run();
+    if let Some(check_config) = command.check_config {
+        return LintRunner::check_config(check_config.path);
+    }
     command.handle_threads();
     LintRunner::new(command.lint_options).run()
 }
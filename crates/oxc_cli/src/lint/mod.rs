@@ -1,23 +1,109 @@
-use std::{env, io::BufWriter, path::Path, vec::Vec};
+mod check_config;
+mod deny_new;
+mod fix_preview;
+mod inspect;
+mod server_lite;
+mod summary;
+mod watch;
+mod workspace;
+
+use std::{
+    env,
+    ffi::OsStr,
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{mpsc, Arc},
+    time::Duration,
+    vec::Vec,
+};
 
 use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
-use oxc_linter::{partial_loader::LINT_PARTIAL_LOADER_EXT, LintOptions, LintService, Linter};
+use oxc_linter::{
+    partial_loader::LINT_PARTIAL_LOADER_EXT, AllowWarnDeny, FileBudget, FixKind, FlatConfig,
+    LintOptions, LintService, Linter, Newline, SkippedFiles,
+};
 use oxc_span::VALID_EXTENSIONS;
+use rustc_hash::FxHashMap;
 
 use crate::{
     codeowners,
-    command::LintOptions as CliLintOptions,
+    command::{FixOptions, LintOptions as CliLintOptions},
     walk::{Extensions, Walk},
-    CliRunResult, CodeownerOptions, LintResult, Runner,
+    CliRunResult, CodeownerOptions, EnablePlugins, LintResult, Runner,
+};
+
+use self::{
+    deny_new::{BaseDiagnostics, GitFileSystem},
+    fix_preview::FixPreview,
+    summary::PackageSummary,
+    workspace::Workspace,
 };
 
+/// Flat config loaded from the working directory when neither `--config` nor rule filters are given
+const DEFAULT_FLAT_CONFIG: &str = "oxlint.config.json";
+/// Config of `check-config` without a path and no flat config, and of packages without a root config
+const DEFAULT_ESLINTRC_CONFIG: &str = ".eslintrc.json";
+/// Cache file of `--cache` without `--cache-location`
+const DEFAULT_CACHE: &str = ".oxlintcache";
+
 pub struct LintRunner {
     options: CliLintOptions,
+    /// Count the diagnostics per rule and per file in [LintResult::counts]
+    count: bool,
 }
 
 impl LintRunner {
+    #[must_use]
+    pub fn with_count(mut self, yes: bool) -> Self {
+        self.count = yes;
+        self
+    }
+
     fn check_options(&self) -> CliRunResult {
-        let CliLintOptions { filter, enable_plugins, config, .. } = &self.options;
+        let CliLintOptions {
+            filter,
+            enable_plugins,
+            config,
+            syntax_only,
+            warning_options,
+            files_from,
+            watch,
+            server_lite,
+            paths,
+            ..
+        } = &self.options;
+
+        if files_from.is_some() && !paths.is_empty() {
+            return CliRunResult::InvalidOptions {
+                message: "`--files-from` and PATH cannot be used together.".to_string(),
+            };
+        }
+
+        if files_from.is_some() && *watch {
+            return CliRunResult::InvalidOptions {
+                message: "`--files-from` and `--watch` cannot be used together.".to_string(),
+            };
+        }
+
+        if *server_lite && (files_from.is_some() || *watch || !paths.is_empty()) {
+            return CliRunResult::InvalidOptions {
+                message: "`--server-lite` lints the paths of its requests, it cannot be used with PATH, `--files-from` or `--watch`.".to_string(),
+            };
+        }
+
+        if warning_options.deny_new != warning_options.base.is_some() {
+            return CliRunResult::InvalidOptions {
+                message: "`--deny-new` and `--base` must be used together.".to_string(),
+            };
+        }
+
+        if *syntax_only && enable_plugins.import_plugin {
+            return CliRunResult::InvalidOptions {
+                message: "`--syntax-only` and `--import-plugin` cannot be used together."
+                    .to_string(),
+            };
+        }
 
         // disallow passing config path and filter at the same time
         if config.is_some() && !filter.is_empty() {
@@ -30,7 +116,8 @@ impl LintRunner {
         if config.is_some()
             && (enable_plugins.import_plugin
                 || enable_plugins.jest_plugin
-                || enable_plugins.jsx_a11y_plugin)
+                || enable_plugins.jsx_a11y_plugin
+                || enable_plugins.secrets_plugin)
         {
             return CliRunResult::InvalidOptions {
                 message: "`--config` and plugin options cannot currently be used together. \nPlease use `--config` to specify a config file, or plugin options to enable plugins."
@@ -46,10 +133,15 @@ impl Runner for LintRunner {
     type Options = CliLintOptions;
 
     fn new(options: Self::Options) -> Self {
-        Self { options }
+        Self { options, count: false }
     }
 
     fn run(self) -> CliRunResult {
+        if let Some(preset) = self.options.misc_options.preset {
+            let mut stdout = BufWriter::new(std::io::stdout());
+            Linter::print_preset_rules(&mut stdout, preset);
+            return CliRunResult::None;
+        }
         if self.options.misc_options.rules {
             let mut stdout = BufWriter::new(std::io::stdout());
             Linter::print_rules(&mut stdout);
@@ -62,21 +154,59 @@ impl Runner for LintRunner {
             return result;
         }
 
+        if self.options.misc_options.inspect {
+            return self.inspect();
+        }
+
+        if self.options.watch {
+            return watch::watch(self.options, self.count);
+        }
+
+        if self.options.server_lite {
+            return server_lite::serve(self.options);
+        }
+
         let CliLintOptions {
             paths,
             filter,
             warning_options,
+            budget_options,
             ignore_options,
             fix_options,
             misc_options,
             codeowner_options,
             enable_plugins,
             config,
+            syntax_only,
+            format,
+            force,
+            files_from,
+            cache,
+            cache_location,
+            watch: _,
+            server_lite: _,
         } = self.options;
 
+        // The list of files given by `--files-from` replaces the walk.
+        let files_from = match files_from.as_deref().map(Self::read_files_from).transpose() {
+            Ok(files_from) => files_from.map(|files| {
+                let extensions = Self::extensions();
+                files
+                    .into_iter()
+                    .filter(|path| path.is_file())
+                    .filter(|path| force || Walk::is_wanted_path(path, &extensions))
+                    .collect::<Vec<_>>()
+            }),
+            Err(error) => {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to read the list of files: {error}"),
+                };
+            }
+        };
+
         let mut paths = paths;
 
-        if paths.is_empty() {
+        if paths.is_empty() && files_from.is_none() {
             if let Ok(cwd) = env::current_dir() {
                 paths.push(cwd);
             } else {
@@ -88,76 +218,399 @@ impl Runner for LintRunner {
 
         let now = std::time::Instant::now();
 
-        let extensions = VALID_EXTENSIONS
-            .iter()
-            .chain(LINT_PARTIAL_LOADER_EXT.iter())
-            .copied()
-            .collect::<Vec<&'static str>>();
-
-        let paths =
-            Walk::new(&paths, &ignore_options).with_extensions(Extensions(extensions)).paths();
-
-        let paths = match Self::apply_codeowners_file(&codeowner_options, paths) {
-            Ok(new_paths) => new_paths,
-            Err(err) => return err,
-        };
-
-        let number_of_files = paths.len();
-
         let cwd = std::env::current_dir().unwrap().into_boxed_path();
-        let lint_options = LintOptions::default()
-            .with_filter(filter)
-            .with_config_path(config)
-            .with_fix(fix_options.fix)
-            .with_timing(misc_options.timing)
-            .with_import_plugin(enable_plugins.import_plugin)
-            .with_jest_plugin(enable_plugins.jest_plugin)
-            .with_jsx_a11y_plugin(enable_plugins.jsx_a11y_plugin);
-
-        let linter = match Linter::from_options(lint_options) {
-            Ok(lint_service) => lint_service,
-            Err(diagnostic) => {
-                let handler = GraphicalReportHandler::new();
-                let mut err = String::new();
-                handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
-                eprintln!("{err}");
+        let config = Self::effective_config(&cwd, config, &filter, &enable_plugins);
+        if let Some(base) = &warning_options.base {
+            if !GitFileSystem::is_revision(&cwd, base) {
                 return CliRunResult::InvalidOptions {
-                    message: "Failed to parse configuration file.".to_string(),
+                    message: format!("`{base}` is not a revision of the git repository."),
                 };
             }
+        }
+        let workspace = Workspace::discover(&cwd);
+        let package_configs = workspace
+            .as_ref()
+            .map(|workspace| Self::package_configs(workspace, config.as_deref()))
+            .unwrap_or_default();
+        if misc_options.package_summary && workspace.is_none() {
+            eprintln!("No pnpm, yarn or npm workspace found, `--package-summary` is ignored.");
+        }
+        // Lint each package with its own config, or count the diagnostics of each package.
+        let per_package = workspace.is_some()
+            && (misc_options.package_summary || package_configs.iter().any(Option::is_some));
+
+        let walk = Walk::new(&paths, &ignore_options)
+            .with_extensions(Self::extensions())
+            .with_force(force);
+        let walk_skipped = walk.skipped();
+
+        // Lint files while the walk is still running, unless the complete list of paths is needed:
+        // the import plugin only lints the walked files and not their dependencies,
+        // the codeowners filter is applied to all paths at once,
+        // the paths of a workspace are grouped by the config of their package,
+        // `--sort-paths` sorts all paths before linting them,
+        // and `--deny-new` lints the same paths in the base revision first.
+        let stream = files_from.is_none()
+            && !enable_plugins.import_plugin
+            && codeowner_options.codeowners_file.is_none()
+            && !per_package
+            && !misc_options.sort_paths
+            && !warning_options.deny_new;
+        let (paths, stream) = if stream {
+            (vec![], Some(walk.stream()))
+        } else {
+            let paths = files_from.unwrap_or_else(|| walk.paths());
+            match Self::apply_codeowners_file(&codeowner_options, paths) {
+                Ok(mut paths) => {
+                    if misc_options.sort_paths {
+                        paths.sort_unstable();
+                    }
+                    (paths, None)
+                }
+                Err(err) => return err,
+            }
+        };
+
+        let file_budget = FileBudget {
+            reduced_rules_size: Some(budget_options.reduced_rules_size).filter(|size| *size > 0),
+            time: Some(Duration::from_millis(budget_options.file_time_budget))
+                .filter(|time| !time.is_zero()),
         };
 
-        let lint_service = LintService::new(cwd, &paths, linter);
+        let timing_threshold = misc_options.timing_threshold.map(Duration::from_millis);
+        let cache = cache.then(|| cache_location.unwrap_or_else(|| PathBuf::from(DEFAULT_CACHE)));
+
+        // The linter of the files outside of packages with their own config, then the linters of
+        // these packages, cascading from the root config.
+        let build_linters = |fix_options: &FixOptions,
+                             cache: Option<PathBuf>|
+         -> Result<Vec<Linter>, CliRunResult> {
+            let root_options = Self::lint_options(
+                filter.clone(),
+                config.clone(),
+                fix_options,
+                misc_options.timing,
+                enable_plugins.clone(),
+            );
+            let package_options = package_configs.iter().flatten().map(|package_config| {
+                Self::lint_options(
+                    filter.clone(),
+                    Some(package_config.clone()),
+                    fix_options,
+                    misc_options.timing,
+                    enable_plugins.clone(),
+                )
+                .with_base_config_path(config.clone())
+            });
+            std::iter::once(root_options)
+                .chain(package_options)
+                .map(|lint_options| {
+                    Self::linter(
+                        lint_options
+                            .with_syntax_only(syntax_only)
+                            .with_file_budget(file_budget)
+                            .with_show_suppressed(misc_options.show_suppressed)
+                            .with_report_unused_directives(
+                                misc_options.report_unused_disable_directives,
+                            )
+                            .with_timing_threshold(timing_threshold)
+                            .with_profile(misc_options.profile.is_some())
+                            .with_no_ignore(ignore_options.no_ignore)
+                            .with_force(force)
+                            .with_crash_report(misc_options.crash_report.clone())
+                            .with_cache(cache.clone()),
+                    )
+                })
+                .collect()
+        };
+        let linters = match build_linters(&fix_options, cache) {
+            Ok(linters) => linters,
+            Err(err) => return err,
+        };
+
+        // The first service lints the files outside of packages with their own config.
+        let mut groups = vec![vec![]; linters.len()];
+        let mut package_group = vec![0; package_configs.len()];
+        let mut next_group = 1;
+        for (index, package_config) in package_configs.iter().enumerate() {
+            if package_config.is_some() {
+                package_group[index] = next_group;
+                next_group += 1;
+            }
+        }
+        for path in &paths {
+            let package =
+                workspace.as_ref().and_then(|workspace| workspace.package_of(&cwd.join(path)));
+            groups[package.map_or(0, |package| package_group[package])].push(path.clone());
+        }
+        // The fixes of `--fix-dry-run` and `--fix-diff` are kept in memory.
+        let fix_preview = (fix_options.fix_dry_run || fix_options.fix_diff)
+            .then(|| Arc::new(FixPreview::default()));
+        let lint_services = linters
+            .into_iter()
+            .zip(&groups)
+            .map(|(linter, paths)| match &fix_preview {
+                Some(fix_preview) => LintService::with_file_system(
+                    cwd.clone(),
+                    paths,
+                    linter,
+                    Arc::clone(fix_preview),
+                ),
+                None => LintService::new(cwd.clone(), paths, linter),
+            })
+            .collect::<Vec<_>>();
 
         let diagnostic_service = DiagnosticService::default()
             .with_quiet(warning_options.quiet)
-            .with_max_warnings(warning_options.max_warnings);
+            .with_max_warnings(warning_options.max_warnings)
+            .with_summary_only(warning_options.summary_only)
+            .with_sort_paths(misc_options.sort_paths)
+            .with_count(self.count)
+            .with_format(format);
+
+        // Diagnostics of a workspace are counted per package on their way to the diagnostic service.
+        let (tx_error, package_summary) = match &workspace {
+            Some(workspace) if per_package => {
+                let (tx_error, rx_error) = mpsc::channel();
+                let summary = PackageSummary::spawn(
+                    workspace.clone(),
+                    cwd.clone(),
+                    lint_services.len(),
+                    rx_error,
+                    diagnostic_service.sender().clone(),
+                );
+                (tx_error, Some(summary))
+            }
+            _ => (diagnostic_service.sender().clone(), None),
+        };
+
+        // The diagnostics of the base revision are filtered out before they are counted.
+        let tx_error = if let Some(base) = warning_options.base.as_ref() {
+            let no_fix = FixOptions {
+                fix: false,
+                fix_unsafe: false,
+                fix_suppress: vec![],
+                fix_dry_run: false,
+                fix_diff: false,
+                newline: Newline::Auto,
+            };
+            // The files of the base revision are not written to the cache of the working tree.
+            let base_linters = match build_linters(&no_fix, None) {
+                Ok(linters) => linters,
+                Err(err) => return err,
+            };
+            let base_services = deny_new::base_services(&cwd, base, base_linters, &groups);
+            let (tx_new, rx_new) = mpsc::channel();
+            BaseDiagnostics::collect(&base_services).spawn_filter(
+                lint_services.len(),
+                rx_new,
+                tx_error,
+            );
+            tx_new
+        } else {
+            tx_error
+        };
 
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
+        let (tx_number_of_files, rx_number_of_files) = mpsc::channel();
         rayon::spawn({
-            let tx_error = diagnostic_service.sender().clone();
-            let lint_service = lint_service.clone();
+            let lint_services = lint_services.clone();
+            let number_of_files = paths.len();
             move || {
-                lint_service.run(&tx_error);
+                let number_of_files = match stream {
+                    Some(paths) => lint_services[0].run_stream(paths, &tx_error),
+                    None => {
+                        for lint_service in &lint_services {
+                            lint_service.run(&tx_error);
+                        }
+                        number_of_files
+                    }
+                };
+                tx_number_of_files.send(number_of_files).unwrap();
             }
         });
         diagnostic_service.run();
+        let number_of_files = rx_number_of_files.recv().unwrap();
+        if let Some(fix_preview) = &fix_preview {
+            let mut stdout = BufWriter::new(std::io::stdout());
+            fix_preview.print(&mut stdout, &cwd, fix_options.fix_diff);
+        }
 
-        lint_service.linter().print_execution_times_if_enable();
+        for lint_service in &lint_services {
+            lint_service.linter().print_execution_times_if_enable();
+        }
+        if misc_options.package_summary {
+            if let Some(package_summary) = package_summary {
+                package_summary.print(&paths);
+            }
+        }
+        if misc_options.show_suppressed {
+            Self::print_suppressed(&lint_services);
+        }
+        if let Some(profile) = &misc_options.profile {
+            if let Err(error) = Self::write_profile(profile, &lint_services) {
+                eprintln!("Failed to write the profile to {}: {error}", profile.display());
+            }
+        }
+
+        let skipped = SkippedFiles::default();
+        skipped.extend(&walk_skipped);
+        for lint_service in &lint_services {
+            skipped.extend(lint_service.linter().skipped());
+        }
 
         CliRunResult::LintResult(LintResult {
             duration: now.elapsed(),
-            number_of_rules: lint_service.linter().number_of_rules(),
+            number_of_rules: if syntax_only {
+                0
+            } else {
+                lint_services[0].linter().number_of_rules()
+            },
             number_of_files,
             number_of_warnings: diagnostic_service.warnings_count(),
             number_of_errors: diagnostic_service.errors_count(),
             max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
             deny_warnings: warning_options.deny_warnings,
+            counts: self.count.then(|| diagnostic_service.counts()),
+            skipped,
+            print_summary: !format.is_document(),
         })
     }
 }
 
 impl LintRunner {
+    fn extensions() -> Extensions {
+        Extensions(VALID_EXTENSIONS.iter().chain(LINT_PARTIAL_LOADER_EXT.iter()).copied().collect())
+    }
+
+    /// The paths of `--files-from`, separated by NUL characters or else by newlines.
+    fn read_files_from(path: &Path) -> std::io::Result<Vec<Box<Path>>> {
+        let text = if path == Path::new("-") {
+            std::io::read_to_string(std::io::stdin())?
+        } else {
+            std::fs::read_to_string(path)?
+        };
+        let separator = if text.contains('\0') { '\0' } else { '\n' };
+        Ok(text
+            .split(separator)
+            .map(|line| line.trim_end_matches('\r'))
+            .filter(|line| !line.is_empty())
+            .map(|line| PathBuf::from(line).into_boxed_path())
+            .collect())
+    }
+
+    fn lint_options(
+        filter: Vec<(AllowWarnDeny, String)>,
+        config: Option<PathBuf>,
+        fix_options: &FixOptions,
+        timing: bool,
+        enable_plugins: EnablePlugins,
+    ) -> LintOptions {
+        LintOptions::default()
+            .with_filter(filter)
+            .with_config_path(config)
+            .with_fix(
+                fix_options.fix
+                    || fix_options.fix_unsafe
+                    || !fix_options.fix_suppress.is_empty()
+                    || fix_options.fix_dry_run
+                    || fix_options.fix_diff,
+            )
+            .with_fix_kind(if fix_options.fix_unsafe { FixKind::Unsafe } else { FixKind::Safe })
+            .with_fix_suppress(fix_options.fix_suppress.clone())
+            .with_newline(fix_options.newline)
+            .with_timing(timing)
+            .with_import_plugin(enable_plugins.import_plugin)
+            .with_tsconfig(enable_plugins.tsconfig)
+            .with_resolve_cache(enable_plugins.resolve_cache)
+            .with_jest_plugin(enable_plugins.jest_plugin)
+            .with_jsx_a11y_plugin(enable_plugins.jsx_a11y_plugin)
+            .with_secrets_plugin(enable_plugins.secrets_plugin)
+    }
+
+    /// Number of suppressed diagnostics per rule, summed over the linters of all packages.
+    fn print_suppressed(lint_services: &[LintService]) {
+        let mut counts = FxHashMap::<&str, usize>::default();
+        for lint_service in lint_services {
+            for (rule_name, count) in lint_service.linter().suppressed_counts() {
+                *counts.entry(rule_name).or_default() += count;
+            }
+        }
+        let mut counts = counts.into_iter().collect::<Vec<_>>();
+        counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+        println!();
+        println!("Suppressed diagnostics by disable directives:");
+        for (rule_name, count) in &counts {
+            println!("{count:>7} | {rule_name}");
+        }
+        println!("Total: {}", counts.iter().map(|(_, count)| count).sum::<usize>());
+    }
+
+    fn write_profile(path: &Path, lint_services: &[LintService]) -> std::io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        for lint_service in lint_services {
+            lint_service.linter().write_profile(&mut writer)?;
+        }
+        writer.flush()
+    }
+
+    fn linter(lint_options: LintOptions) -> Result<Linter, CliRunResult> {
+        Linter::from_options(lint_options).map_err(|diagnostic| {
+            let handler = GraphicalReportHandler::new();
+            let mut err = String::new();
+            handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
+            eprintln!("{err}");
+            CliRunResult::InvalidOptions {
+                message: "Failed to parse configuration file.".to_string(),
+            }
+        })
+    }
+
+    /// `--config`, or the flat config of the working directory when neither rule filters nor
+    /// plugin flags are given.
+    fn effective_config(
+        cwd: &Path,
+        config: Option<PathBuf>,
+        filter: &[(AllowWarnDeny, String)],
+        enable_plugins: &EnablePlugins,
+    ) -> Option<PathBuf> {
+        config.or_else(|| {
+            let default_config = cwd.join(DEFAULT_FLAT_CONFIG);
+            let plugins = enable_plugins.import_plugin
+                || enable_plugins.jest_plugin
+                || enable_plugins.jsx_a11y_plugin
+                || enable_plugins.secrets_plugin;
+            (filter.is_empty() && !plugins && default_config.is_file()).then_some(default_config)
+        })
+    }
+
+    /// For each package of the workspace, its config with the same file name as the root config,
+    /// or its `oxlint.config.json` or `.eslintrc.json` when there is no root config.
+    /// A flat root config configures the packages with `files` patterns instead.
+    fn package_configs(workspace: &Workspace, config: Option<&Path>) -> Vec<Option<PathBuf>> {
+        if config.is_some_and(FlatConfig::is_flat_config) {
+            return vec![None; workspace.packages.len()];
+        }
+        let root_config = config.and_then(|config| config.canonicalize().ok());
+        let file_names = match config {
+            Some(config) => config.file_name().into_iter().collect::<Vec<_>>(),
+            None => vec![OsStr::new(DEFAULT_FLAT_CONFIG), OsStr::new(DEFAULT_ESLINTRC_CONFIG)],
+        };
+        workspace
+            .packages
+            .iter()
+            .map(|package| {
+                file_names.iter().map(|file_name| package.root.join(file_name)).find(
+                    |package_config| {
+                        package_config.is_file()
+                            && package_config.canonicalize().ok() != root_config
+                    },
+                )
+            })
+            .collect()
+    }
+
     fn apply_codeowners_file(
         options: &CodeownerOptions,
         paths: Vec<Box<Path>>,
@@ -206,7 +659,9 @@ impl LintRunner {
 
 #[cfg(all(test, not(target_os = "windows")))]
 mod test {
-    use super::LintRunner;
+    use oxc_linter::SkipReason;
+
+    use super::{LintRunner, Workspace};
     use crate::{lint_command, CliRunResult, LintResult, Runner};
 
     fn test(args: &[&str]) -> LintResult {
@@ -231,7 +686,7 @@ mod test {
         let args = &[];
         let result = test(args);
         assert!(result.number_of_rules > 0);
-        assert_eq!(result.number_of_files, 5);
+        assert_eq!(result.number_of_files, 7);
         assert_eq!(result.number_of_warnings, 3);
         assert_eq!(result.number_of_errors, 0);
     }
@@ -264,6 +719,26 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn fix_diff() {
+        let args = &["--fix-diff", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+        let source_text = std::fs::read_to_string("fixtures/linter/debugger.js").unwrap();
+        assert_eq!(source_text, "debugger;\n");
+    }
+
+    #[test]
+    fn syntax_only() {
+        let args = &["--syntax-only", "fixtures/linter"];
+        let result = test(args);
+        assert_eq!(result.number_of_rules, 0);
+        assert_eq!(result.number_of_files, 3);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn wrong_extension() {
         let args = &["foo.asdf"];
@@ -273,6 +748,40 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn force() {
+        let args = &["fixtures/force/script"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 0);
+
+        let args = &["--force", "fixtures/force/script"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+
+        // Files inside of a directory are still filtered by extension
+        let args = &["--force", "fixtures/force"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 0);
+    }
+
+    #[test]
+    fn files_from() {
+        let args = &["--files-from", "fixtures/files_from/files.txt"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+
+        // Separated by NUL, files with an unsupported extension are skipped
+        let args = &["--files-from", "fixtures/files_from/files.nul"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn ignore_pattern() {
         let args = &["--ignore-pattern", "**/*.js", "--ignore-pattern", "**/*.vue", "fixtures"];
@@ -300,6 +809,29 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn count() {
+        let args = &["fixtures/linter/debugger.js", "fixtures/linter/nan.js"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        let CliRunResult::LintResult(LintResult { counts: Some(counts), .. }) =
+            LintRunner::new(options).with_count(true).run()
+        else {
+            unreachable!()
+        };
+        assert_eq!(counts.rules.get("eslint(no-debugger)"), Some(&(1, 0)));
+        assert_eq!(counts.files.len(), 2);
+        assert!(test(args).counts.is_none());
+    }
+
+    #[test]
+    fn filter_allow_glob() {
+        let args = &["-D", "correctness", "-A", "no-debug*", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn test_lint_vue_file() {
         let args = &["fixtures/linter/debugger.vue"];
@@ -308,4 +840,59 @@ mod test {
         assert_eq!(result.number_of_warnings, 1);
         assert_eq!(result.number_of_errors, 0);
     }
+
+    #[test]
+    fn inspect_exclude_reason() {
+        let args = ["--ignore-pattern", "**/*.vue", "--inspect", "fixtures"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        let runner = LintRunner::new(options);
+        let cwd = std::env::current_dir().unwrap();
+        let reason = |path: &str| runner.exclude_reason(&cwd, &cwd.join(path));
+
+        assert_eq!(reason("fixtures/linter/debugger.js"), None);
+        assert!(reason("fixtures/missing.js").unwrap().contains("does not exist"));
+        assert!(reason("fixtures/linter").unwrap().contains("directory"));
+        assert!(reason("fixtures/test.min.js").unwrap().contains("minified"));
+        assert!(reason("fixtures/walk_dir/bar.vue").unwrap().contains("--ignore-pattern **/*.vue"));
+        assert!(reason("fixtures/walk_skip/binary.js").unwrap().contains("binary"));
+    }
+
+    #[test]
+    fn inspect_per_file_rules() {
+        let args = [
+            "-c",
+            "fixtures/inspect/.eslintrc.json",
+            "--inspect",
+            "fixtures/inspect/src/index.js",
+            "fixtures/inspect/scripts/build.js",
+        ];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        let output = LintRunner::new(options).inspect_output().ok().unwrap();
+        let (index, build) = output.split_once("fixtures/inspect/scripts/build.js").unwrap();
+
+        assert!(index.contains("Config: fixtures/inspect/.eslintrc.json"));
+        assert!(index.contains("Rules (2):\n    eslint/no-console\n    eslint/no-debugger\n"));
+        assert!(build.contains("Rules (1):\n    eslint/no-debugger\n"));
+    }
+
+    #[test]
+    fn skipped_files() {
+        let args = &["--max-file-size", "100", "fixtures/walk_skip"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(
+            result.skipped.counts(),
+            vec![(SkipReason::SizeLimit, 1), (SkipReason::Binary, 1)]
+        );
+    }
+
+    #[test]
+    fn package_configs_without_config() {
+        let dir = std::env::current_dir().unwrap().join("fixtures/workspace");
+        let workspace = Workspace::discover(&dir).unwrap();
+        let package_configs = LintRunner::package_configs(&workspace, None);
+        assert_eq!(package_configs.len(), 2);
+        assert_eq!(package_configs[0], None);
+        assert!(package_configs[1].as_ref().unwrap().ends_with("packages/b/.eslintrc.json"));
+    }
 }
@@ -0,0 +1,91 @@
+//! `--server-lite`: lint the files requested on the standard input until it ends
+//!
+//! Each line of the input is a request, e.g.
+//! `{"id": 1, "method": "lint", "params": {"paths": ["src/a.ts"]}}`, and each line of the output
+//! an event:
+//!
+//! * `{"event": "start", "id": 1}`
+//! * `{"event": "file", ...}` and `{"event": "diagnostic", ...}` of `--format stream`, for the
+//!   files with diagnostics as soon as they are linted
+//! * `{"event": "end", "id": 1, "files": 1, "errors": 0, "warnings": 2}`
+//! * `{"event": "error", "id": 1, "message": "..."}` for an invalid request, instead of the end
+
+use std::{
+    io::{self, BufRead, Write},
+    path::PathBuf,
+};
+
+use oxc_diagnostics::OutputFormat;
+use serde_json::{json, Value};
+
+use crate::{command::LintOptions as CliLintOptions, CliRunResult, Runner};
+
+use super::LintRunner;
+
+pub fn serve(options: CliLintOptions) -> CliRunResult {
+    let options = CliLintOptions { server_lite: false, format: OutputFormat::Stream, ..options };
+    for line in io::stdin().lock().lines() {
+        let Ok(line) = line else { break };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let request = match serde_json::from_str::<Value>(&line) {
+            Ok(request) => request,
+            Err(error) => {
+                send(&json!({ "event": "error", "id": null, "message": error.to_string() }));
+                continue;
+            }
+        };
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        match request.get("method").and_then(Value::as_str) {
+            Some("lint") => lint(&options, id, request.get("params")),
+            Some("shutdown") => break,
+            method => {
+                let message = format!("unknown method {}", method.unwrap_or("null"));
+                send(&json!({ "event": "error", "id": id, "message": message }));
+            }
+        }
+    }
+    CliRunResult::None
+}
+
+fn lint(options: &CliLintOptions, id: Value, params: Option<&Value>) {
+    let paths = params
+        .and_then(|params| params.get("paths"))
+        .and_then(Value::as_array)
+        .map(|paths| paths.iter().filter_map(Value::as_str).map(PathBuf::from).collect::<Vec<_>>())
+        .unwrap_or_default();
+    if paths.is_empty() {
+        let message = "expected `params.paths` with at least one path";
+        send(&json!({ "event": "error", "id": id, "message": message }));
+        return;
+    }
+
+    send(&json!({ "event": "start", "id": id }));
+    let options = CliLintOptions { paths, ..options.clone() };
+    let event = match LintRunner::new(options).run() {
+        CliRunResult::LintResult(result) => json!({
+            "event": "end",
+            "id": id,
+            "files": result.number_of_files,
+            "errors": result.number_of_errors,
+            "warnings": result.number_of_warnings,
+        }),
+        CliRunResult::InvalidOptions { message } => {
+            json!({ "event": "error", "id": id, "message": message })
+        }
+        CliRunResult::PathNotFound { paths } => {
+            let message = format!("paths {paths:?} do not exist");
+            json!({ "event": "error", "id": id, "message": message })
+        }
+        result => json!({ "event": "error", "id": id, "message": format!("{result:?}") }),
+    };
+    send(&event);
+}
+
+/// Write an event and flush it, the editor waits for it.
+fn send(event: &Value) {
+    let mut stdout = io::stdout().lock();
+    let _ = writeln!(stdout, "{event}");
+    let _ = stdout.flush();
+}
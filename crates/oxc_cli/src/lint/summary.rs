@@ -0,0 +1,88 @@
+//! Number of files, warnings and errors of each workspace package for `--package-summary`.
+
+use std::{
+    path::Path,
+    sync::mpsc,
+    thread::{self, JoinHandle},
+};
+
+use oxc_diagnostics::{DiagnosticSender, DiagnosticTuple, Severity};
+
+use super::workspace::Workspace;
+
+pub struct PackageSummary {
+    workspace: Workspace,
+    cwd: Box<Path>,
+    counts: JoinHandle<Vec<Counts>>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Counts {
+    files: usize,
+    warnings: usize,
+    errors: usize,
+}
+
+impl PackageSummary {
+    /// Count the diagnostics of `services` lint services and forward them to `tx_error`,
+    /// which receives the end of the diagnostics once all services are done.
+    pub fn spawn(
+        workspace: Workspace,
+        cwd: Box<Path>,
+        services: usize,
+        rx_error: mpsc::Receiver<Option<DiagnosticTuple>>,
+        tx_error: DiagnosticSender,
+    ) -> Self {
+        let counts = thread::spawn({
+            let workspace = workspace.clone();
+            let cwd = cwd.clone();
+            move || {
+                // The last entry counts the files outside of packages.
+                let mut counts = vec![Counts::default(); workspace.packages.len() + 1];
+                let mut remaining = services;
+                for message in rx_error {
+                    let Some((path, diagnostics)) = message else {
+                        remaining -= 1;
+                        if remaining == 0 {
+                            break;
+                        }
+                        continue;
+                    };
+                    let package = workspace.package_of(&cwd.join(&path));
+                    let counts = &mut counts[package.unwrap_or(workspace.packages.len())];
+                    for diagnostic in &diagnostics {
+                        match diagnostic.severity() {
+                            Some(Severity::Warning) => counts.warnings += 1,
+                            None | Some(Severity::Error) => counts.errors += 1,
+                            Some(Severity::Advice) => {}
+                        }
+                    }
+                    tx_error.send(Some((path, diagnostics))).unwrap();
+                }
+                tx_error.send(None).unwrap();
+                counts
+            }
+        });
+        Self { workspace, cwd, counts }
+    }
+
+    /// Print a line for each package with linted files, in the order of the package roots.
+    pub fn print(self, paths: &[Box<Path>]) {
+        let mut counts = self.counts.join().unwrap();
+        for path in paths {
+            let package = self.workspace.package_of(&self.cwd.join(path));
+            counts[package.unwrap_or(self.workspace.packages.len())].files += 1;
+        }
+
+        println!();
+        let names = self.workspace.packages.iter().map(|package| package.name.as_str());
+        for (name, counts) in names.chain(["<root>"]).zip(counts) {
+            if counts.files == 0 {
+                continue;
+            }
+            let Counts { files, warnings, errors } = counts;
+            let s = if files == 1 { "" } else { "s" };
+            println!("{name}: {files} file{s}, {warnings} warnings, {errors} errors");
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! `--watch`: lint the files again when they change
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    process::Termination,
+    sync::mpsc,
+    time::Duration,
+};
+
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use oxc_linter::clear_package_json_cache;
+use oxc_resolver::Resolver;
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    command::LintOptions as CliLintOptions,
+    graph::{requested_modules, resolver},
+    walk::Walk,
+    CliRunResult, Runner,
+};
+
+use super::{LintRunner, DEFAULT_FLAT_CONFIG};
+
+/// Changes made within this time are linted together, e.g. the files written by a `git checkout`
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Lint the files, then lint the changed files again until the process is stopped.
+pub fn watch(options: CliLintOptions, count: bool) -> CliRunResult {
+    let options = CliLintOptions { watch: false, ..options };
+    let cwd = std::env::current_dir().unwrap();
+    let roots = if options.paths.is_empty() {
+        vec![cwd.clone()]
+    } else {
+        options.paths.iter().map(|path| cwd.join(path)).collect()
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(tx) {
+        Ok(watcher) => watcher,
+        Err(error) => {
+            return CliRunResult::InvalidOptions {
+                message: format!("Failed to watch the files: {error}"),
+            }
+        }
+    };
+    for root in &roots {
+        if let Err(error) = watcher.watch(root, RecursiveMode::Recursive) {
+            return CliRunResult::InvalidOptions {
+                message: format!("Failed to watch {}: {error}", root.display()),
+            };
+        }
+    }
+
+    let mut state = Watch::new(options, count, cwd, roots);
+    state.lint_all();
+    while let Ok(event) = rx.recv() {
+        // The events of a batch of changes, e.g. the files written by a `git checkout`
+        let events = std::iter::once(event)
+            .chain(std::iter::from_fn(|| rx.recv_timeout(DEBOUNCE).ok()))
+            .filter_map(Result::ok);
+        state.on_change(events);
+    }
+    CliRunResult::None
+}
+
+struct Watch {
+    options: CliLintOptions,
+    count: bool,
+    cwd: PathBuf,
+    roots: Vec<PathBuf>,
+    config: Option<PathBuf>,
+    /// The files found by the walk of the paths, with the hash of their content when they were
+    /// last linted. Writing a file without changing it, e.g. by `--fix`, does not lint it again.
+    files: FxHashMap<PathBuf, Option<u64>>,
+    /// With the import plugin
+    imports: Option<Imports>,
+}
+
+impl Watch {
+    fn new(options: CliLintOptions, count: bool, cwd: PathBuf, roots: Vec<PathBuf>) -> Self {
+        let config = options.config.as_ref().map(|config| cwd.join(config));
+        let mut watch =
+            Self { options, count, cwd, roots, config, files: FxHashMap::default(), imports: None };
+        watch.walk();
+        if watch.options.enable_plugins.import_plugin {
+            let tsconfig = watch.options.enable_plugins.tsconfig.as_deref();
+            watch.imports = Some(Imports::new(&watch.cwd, tsconfig, watch.files.keys()));
+        }
+        watch
+    }
+
+    /// Find the files to lint, applying the ignore files to the new ones.
+    fn walk(&mut self) {
+        let paths = Walk::new(&self.roots, &self.options.ignore_options)
+            .with_extensions(LintRunner::extensions())
+            .with_force(self.options.force)
+            .paths();
+        let mut files = FxHashMap::default();
+        for path in paths {
+            let path = self.cwd.join(path);
+            let hash = self.files.get(&path).copied().flatten();
+            files.insert(path, hash);
+        }
+        self.files = files;
+    }
+
+    fn lint_all(&mut self) {
+        self.lint(self.options.paths.clone());
+        let hashes = self
+            .files
+            .par_iter()
+            .map(|(path, _)| (path.clone(), content_hash(path)))
+            .collect::<Vec<_>>();
+        self.files.extend(hashes);
+    }
+
+    fn on_change(&mut self, events: impl Iterator<Item = Event>) {
+        let mut changed = FxHashSet::default();
+        let mut created = false;
+        for event in events {
+            if matches!(event.kind, EventKind::Access(_)) {
+                continue;
+            }
+            created |= matches!(event.kind, EventKind::Create(_));
+            changed.extend(event.paths);
+        }
+
+        if changed.iter().any(|path| path.file_name().is_some_and(|name| name == "package.json")) {
+            clear_package_json_cache();
+        }
+
+        // Everything is linted again with the new config.
+        if changed.iter().any(|path| {
+            self.config.as_ref() == Some(path)
+                || path.file_name().is_some_and(|name| name == DEFAULT_FLAT_CONFIG)
+        }) {
+            self.lint_all();
+            return;
+        }
+
+        if created && changed.iter().any(|path| path.is_file() && !self.files.contains_key(path)) {
+            self.walk();
+        }
+        let mut changed = changed
+            .into_iter()
+            .filter(|path| match self.files.get(path) {
+                Some(hash) => hash.is_none() || *hash != content_hash(path),
+                None => false,
+            })
+            .collect::<FxHashSet<_>>();
+        if changed.is_empty() {
+            return;
+        }
+        if let Some(imports) = &mut self.imports {
+            imports.update(&changed);
+            changed.extend(imports.importers(&changed));
+        }
+        // Deleted files are not linted, the files importing them are.
+        self.files.retain(|path, _| !changed.contains(path) || path.is_file());
+        let paths = changed.into_iter().filter(|path| path.is_file()).collect::<Vec<_>>();
+        if paths.is_empty() {
+            return;
+        }
+        self.lint(paths.iter().map(|path| self.relative(path)).collect());
+        for path in paths {
+            let hash = content_hash(&path);
+            self.files.insert(path, hash);
+        }
+    }
+
+    /// Lint `paths` and print the summary of the run.
+    fn lint(&self, paths: Vec<PathBuf>) {
+        let options = CliLintOptions { paths, ..self.options.clone() };
+        let _ = LintRunner::new(options).with_count(self.count).run().report();
+        println!("Watching for changes, press Ctrl+C to stop.");
+    }
+
+    /// Diagnostics are printed with the paths relative to the working directory.
+    fn relative(&self, path: &Path) -> PathBuf {
+        path.strip_prefix(&self.cwd).map_or_else(|_| path.to_path_buf(), Path::to_path_buf)
+    }
+}
+
+fn content_hash(path: &Path) -> Option<u64> {
+    let content = fs::read(path).ok()?;
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+/// The resolved imports of each file, to lint the files importing a changed file with the import
+/// plugin, whose diagnostics depend on the imported modules.
+struct Imports {
+    resolver: Resolver,
+    imports: FxHashMap<PathBuf, Vec<PathBuf>>,
+}
+
+impl Imports {
+    fn new<'a>(
+        cwd: &Path,
+        tsconfig: Option<&Path>,
+        paths: impl Iterator<Item = &'a PathBuf>,
+    ) -> Self {
+        let resolver = resolver(cwd, tsconfig);
+        let paths = paths.collect::<Vec<_>>();
+        let imports = paths
+            .par_iter()
+            .map(|path| ((*path).clone(), Self::resolve(&resolver, path)))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .collect();
+        Self { resolver, imports }
+    }
+
+    fn resolve(resolver: &Resolver, path: &Path) -> Vec<PathBuf> {
+        let Some(dir) = path.parent() else { return vec![] };
+        requested_modules(path)
+            .iter()
+            .filter_map(|specifier| resolver.resolve(dir, specifier).ok())
+            .map(|resolution| resolution.path().to_path_buf())
+            .collect()
+    }
+
+    /// Resolve the imports of the changed files again, forgetting the deleted files.
+    fn update(&mut self, changed: &FxHashSet<PathBuf>) {
+        // A new file may be found by the specifiers which failed to resolve.
+        self.resolver.clear_cache();
+        for path in changed {
+            if path.is_file() {
+                self.imports.insert(path.clone(), Self::resolve(&self.resolver, path));
+            } else {
+                self.imports.remove(path);
+            }
+        }
+    }
+
+    /// The files importing one of `paths`, directly or through other files
+    fn importers(&self, paths: &FxHashSet<PathBuf>) -> FxHashSet<PathBuf> {
+        let mut importers = FxHashSet::default();
+        let mut queue = paths.iter().cloned().collect::<Vec<_>>();
+        while let Some(path) = queue.pop() {
+            for (importer, imports) in &self.imports {
+                if imports.contains(&path)
+                    && !paths.contains(importer)
+                    && importers.insert(importer.clone())
+                {
+                    queue.push(importer.clone());
+                }
+            }
+        }
+        importers
+    }
+}
@@ -0,0 +1,145 @@
+//! Monorepo workspaces of pnpm (`pnpm-workspace.yaml`), yarn and npm (`workspaces` in `package.json`).
+//!
+//! Each package of a workspace is a config root: a config file with the same name as `--config`
+//! in the package directory cascades from the config of the repository root. Without `--config`,
+//! the `oxlint.config.json` or `.eslintrc.json` of a package configures it.
+//! `.eslintignore` files are already scoped to the directory they are in by the walker,
+//! so ignores of a package do not leak into its siblings.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    pub root: PathBuf,
+    /// Sorted by root
+    pub packages: Vec<Package>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Package {
+    pub name: String,
+    pub root: PathBuf,
+}
+
+impl Workspace {
+    /// Find the workspace containing `dir` by looking for a workspace root in its ancestors.
+    pub fn discover(dir: &Path) -> Option<Self> {
+        dir.ancestors().find_map(|root| {
+            let patterns = package_patterns(root)?;
+            let packages = packages(root, &patterns);
+            Some(Self { root: root.to_path_buf(), packages })
+        })
+    }
+
+    /// Index of the innermost package containing the absolute `path`.
+    pub fn package_of(&self, path: &Path) -> Option<usize> {
+        self.packages
+            .iter()
+            .enumerate()
+            .filter(|(_, package)| path.starts_with(&package.root))
+            .max_by_key(|(_, package)| package.root.components().count())
+            .map(|(index, _)| index)
+    }
+}
+
+/// Package globs of the workspace rooted at `root`, `None` if `root` is not a workspace root.
+fn package_patterns(root: &Path) -> Option<Vec<String>> {
+    if let Ok(yaml) = fs::read_to_string(root.join("pnpm-workspace.yaml")) {
+        return Some(pnpm_packages(&yaml));
+    }
+    let package_json = fs::read_to_string(root.join("package.json")).ok()?;
+    let package_json: Value = serde_json::from_str(&package_json).ok()?;
+    let workspaces = package_json.get("workspaces")?;
+    // yarn also accepts `{ "packages": [...], "nohoist": [...] }`
+    let patterns = workspaces.as_array().or_else(|| workspaces.get("packages")?.as_array())?;
+    Some(patterns.iter().filter_map(Value::as_str).map(String::from).collect())
+}
+
+/// The `packages` sequence of `pnpm-workspace.yaml`.
+fn pnpm_packages(yaml: &str) -> Vec<String> {
+    let Ok(yaml) = serde_yaml::from_str::<serde_yaml::Value>(yaml) else { return vec![] };
+    yaml.get("packages")
+        .and_then(serde_yaml::Value::as_sequence)
+        .into_iter()
+        .flatten()
+        .filter_map(serde_yaml::Value::as_str)
+        .map(String::from)
+        .collect()
+}
+
+/// Directories with a `package.json` matched by `patterns`, `!` negates a pattern.
+fn packages(root: &Path, patterns: &[String]) -> Vec<Package> {
+    let excludes = patterns
+        .iter()
+        .filter_map(|pattern| pattern.strip_prefix('!'))
+        .filter_map(|pattern| glob::Pattern::new(&root.join(pattern).to_string_lossy()).ok())
+        .collect::<Vec<_>>();
+
+    let mut packages = patterns
+        .iter()
+        .filter(|pattern| !pattern.starts_with('!'))
+        .filter_map(|pattern| glob::glob(&root.join(pattern).to_string_lossy()).ok())
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|dir| dir.join("package.json").is_file())
+        .filter(|dir| !excludes.iter().any(|exclude| exclude.matches_path(dir)))
+        .map(|dir| Package { name: package_name(root, &dir), root: dir })
+        .collect::<Vec<_>>();
+    packages.sort_by(|a, b| a.root.cmp(&b.root));
+    packages.dedup();
+    packages
+}
+
+/// `name` of the `package.json`, or the path relative to the workspace root for private packages.
+fn package_name(root: &Path, dir: &Path) -> String {
+    fs::read_to_string(dir.join("package.json"))
+        .ok()
+        .and_then(|package_json| serde_json::from_str::<Value>(&package_json).ok())
+        .and_then(|package_json| package_json.get("name")?.as_str().map(String::from))
+        .unwrap_or_else(|| dir.strip_prefix(root).unwrap_or(dir).to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::{pnpm_packages, Workspace};
+
+    #[test]
+    fn npm_workspace() {
+        let dir = env::current_dir().unwrap().join("fixtures/workspace/packages/a");
+        let workspace = Workspace::discover(&dir).unwrap();
+        assert!(workspace.root.ends_with("fixtures/workspace"));
+
+        let names = workspace.packages.iter().map(|p| p.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["@workspace/a", "packages/b"]);
+
+        let file = workspace.root.join("packages/b/./index.js");
+        assert_eq!(workspace.package_of(&file), Some(1));
+        assert_eq!(workspace.package_of(&workspace.root.join("index.js")), None);
+    }
+
+    #[test]
+    fn pnpm_workspace() {
+        let dir = env::current_dir().unwrap().join("fixtures/workspace_pnpm");
+        let workspace = Workspace::discover(&dir).unwrap();
+        let names = workspace.packages.iter().map(|p| p.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["c"]);
+    }
+
+    #[test]
+    fn pnpm_packages_list() {
+        let yaml = "packages:\n  # all packages\n  - 'packages/*'\n  - \"apps/**\" # apps\n  - '!**/test/**'\ncatalog:\n  - ignored\n";
+        assert_eq!(pnpm_packages(yaml), vec!["packages/*", "apps/**", "!**/test/**"]);
+
+        let yaml = "packages: ['packages/*', \"apps/**\"] # flow sequence\n";
+        assert_eq!(pnpm_packages(yaml), vec!["packages/*", "apps/**"]);
+        assert!(pnpm_packages("packages:\n").is_empty());
+        assert!(pnpm_packages("packages: [").is_empty());
+    }
+}
@@ -8,7 +8,10 @@ static GLOBAL: jemallocator::Jemalloc = jemallocator::Jemalloc;
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
-use oxc_cli::{CliCommand, CliRunResult, FormatRunner, LintRunner, Runner, TypeCheckRunner};
+use oxc_cli::{
+    CliCommand, CliRunResult, CodemodRunner, FormatRunner, GraphRunner, LintRunner, Runner,
+    SearchRunner, TransformRunner, TypeCheckRunner,
+};
 
 fn main() -> CliRunResult {
     let options = oxc_cli::cli_command().fallback_to_usage().run();
@@ -17,5 +20,9 @@ fn main() -> CliRunResult {
         CliCommand::Lint(options) => LintRunner::new(options).run(),
         CliCommand::Format(options) => FormatRunner::new(options).run(),
         CliCommand::Check(options) => TypeCheckRunner::new(options).run(),
+        CliCommand::Transform(options) => TransformRunner::new(options).run(),
+        CliCommand::Graph(options) => GraphRunner::new(options).run(),
+        CliCommand::Search(options) => SearchRunner::new(options).run(),
+        CliCommand::Codemod(options) => CodemodRunner::new(options).run(),
     }
 }
@@ -1,3 +1,5 @@
+use oxc_diagnostics::DiagnosticCounts;
+use oxc_linter::SkippedFiles;
 use std::{
     path::PathBuf,
     process::{ExitCode, Termination},
@@ -11,7 +13,9 @@ pub enum CliRunResult {
     PathNotFound { paths: Vec<PathBuf> },
     LintResult(LintResult),
     FormatResult(FormatResult),
+    TransformResult(TransformResult),
     TypeCheckResult { duration: Duration, number_of_diagnostics: usize },
+    ConfigCheckResult { path: PathBuf, number_of_problems: usize },
 }
 
 #[derive(Debug)]
@@ -23,6 +27,12 @@ pub struct LintResult {
     pub number_of_errors: usize,
     pub max_warnings_exceeded: bool,
     pub deny_warnings: bool,
+    /// Warnings and errors per rule and per file, with [crate::LintRunner::with_count]
+    pub counts: Option<DiagnosticCounts>,
+    /// Files which were walked but not linted, by cause
+    pub skipped: SkippedFiles,
+    /// `false` when the output is a JSON or XML document read by other tools
+    pub print_summary: bool,
 }
 
 #[derive(Debug)]
@@ -31,6 +41,13 @@ pub struct FormatResult {
     pub number_of_files: usize,
 }
 
+#[derive(Debug)]
+pub struct TransformResult {
+    pub duration: Duration,
+    pub number_of_files: usize,
+    pub number_of_errors: usize,
+}
+
 impl Termination for CliRunResult {
     fn report(self) -> ExitCode {
         match self {
@@ -51,7 +68,16 @@ impl Termination for CliRunResult {
                 number_of_errors,
                 max_warnings_exceeded,
                 deny_warnings,
+                skipped,
+                print_summary,
+                ..
             }) => {
+                let exit_code =
+                    u8::from((number_of_warnings > 0 && deny_warnings) || number_of_errors > 0);
+                if !print_summary {
+                    return ExitCode::from(if max_warnings_exceeded { 1 } else { exit_code });
+                }
+
                 let threads = rayon::current_num_threads();
                 let number_of_diagnostics = number_of_warnings + number_of_errors;
 
@@ -64,6 +90,11 @@ impl Termination for CliRunResult {
                 println!(
                     "Finished in {time} on {number_of_files} file{s} with {number_of_rules} rules using {threads} threads."
                 );
+                let number_of_skipped = skipped.total();
+                if number_of_skipped > 0 {
+                    let s = if number_of_skipped == 1 { "" } else { "s" };
+                    println!("Skipped {number_of_skipped} file{s}: {skipped}.");
+                }
 
                 if max_warnings_exceeded {
                     println!("Exceeded maximum number of warnings. Found {number_of_warnings}.");
@@ -76,8 +107,6 @@ impl Termination for CliRunResult {
                     if number_of_errors == 1 { "" } else { "s" }
                 );
 
-                let exit_code =
-                    u8::from((number_of_warnings > 0 && deny_warnings) || number_of_errors > 0);
                 ExitCode::from(exit_code)
             }
             Self::FormatResult(FormatResult { duration, number_of_files }) => {
@@ -89,6 +118,26 @@ impl Termination for CliRunResult {
                 );
                 ExitCode::from(0)
             }
+            Self::TransformResult(TransformResult {
+                duration,
+                number_of_files,
+                number_of_errors,
+            }) => {
+                let threads = rayon::current_num_threads();
+                let time = Self::get_execution_time(&duration);
+                let s = if number_of_files == 1 { "" } else { "s" };
+                println!(
+                    "Finished in {time} on {number_of_files} file{s} using {threads} threads."
+                );
+
+                if number_of_errors > 0 {
+                    let s = if number_of_errors == 1 { "" } else { "s" };
+                    println!("Found {number_of_errors} error{s}.");
+                    return ExitCode::from(1);
+                }
+
+                ExitCode::from(0)
+            }
             Self::TypeCheckResult { duration, number_of_diagnostics } => {
                 let time = Self::get_execution_time(&duration);
                 println!("Finished in {time}.");
@@ -100,6 +149,16 @@ impl Termination for CliRunResult {
 
                 ExitCode::from(0)
             }
+            Self::ConfigCheckResult { path, number_of_problems } => {
+                if number_of_problems > 0 {
+                    let s = if number_of_problems == 1 { "" } else { "s" };
+                    println!("Found {number_of_problems} problem{s} in {}.", path.display());
+                    return ExitCode::from(1);
+                }
+
+                println!("{} is valid.", path.display());
+                ExitCode::from(0)
+            }
         }
     }
 }
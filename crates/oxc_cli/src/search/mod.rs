@@ -0,0 +1,150 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+use oxc_allocator::Allocator;
+use oxc_esquery::{parse_selector, Pattern, Query, Selector};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use serde_json::json;
+
+use crate::{
+    command::{SearchFormat, SearchOptions},
+    result::CliRunResult,
+    walk::Walk,
+    Runner,
+};
+
+pub struct SearchRunner {
+    options: SearchOptions,
+}
+
+enum Matcher {
+    Selector(Selector),
+    Pattern(Pattern),
+}
+
+/// A node found in a file, with the source text of the nodes bound to the metavariables of a
+/// pattern
+struct SearchMatch {
+    path: String,
+    span: Span,
+    /// 1-based line and column of the start of the node, in characters
+    line: usize,
+    column: usize,
+    text: String,
+    bindings: BTreeMap<String, String>,
+}
+
+impl Runner for SearchRunner {
+    type Options = SearchOptions;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        let SearchOptions { selector, pattern, paths, ignore_options, .. } = &self.options;
+
+        if paths.is_empty() {
+            return CliRunResult::InvalidOptions { message: "No paths are provided.".to_string() };
+        }
+
+        let matcher = match (selector, pattern) {
+            (Some(selector), None) => {
+                parse_selector(selector).map(Matcher::Selector).map_err(|error| error.to_string())
+            }
+            (None, Some(pattern)) => {
+                Pattern::parse(pattern).map(Matcher::Pattern).map_err(|error| error.to_string())
+            }
+            _ => Err("Provide either a --selector or a --pattern.".to_string()),
+        };
+        let matcher = match matcher {
+            Ok(matcher) => matcher,
+            Err(message) => return CliRunResult::InvalidOptions { message },
+        };
+
+        let paths = Walk::new(paths, ignore_options).paths();
+        // Matches of each file in walk order
+        let matches =
+            paths.par_iter().flat_map_iter(|path| search_file(path, &matcher)).collect::<Vec<_>>();
+
+        match self.options.format {
+            SearchFormat::Default => {
+                for m in &matches {
+                    let text = m.text.lines().next().unwrap_or_default();
+                    println!("{}:{}:{}: {text}", m.path, m.line, m.column);
+                }
+                let number_of_files =
+                    matches.iter().map(|m| &m.path).collect::<BTreeSet<_>>().len();
+                println!(
+                    "Found {} matches in {number_of_files} of {} files.",
+                    matches.len(),
+                    paths.len()
+                );
+            }
+            SearchFormat::Json => {
+                let matches = matches
+                    .iter()
+                    .map(|m| {
+                        json!({
+                            "path": m.path,
+                            "start": m.span.start,
+                            "end": m.span.end,
+                            "line": m.line,
+                            "column": m.column,
+                            "text": m.text,
+                            "bindings": m.bindings,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                println!("{}", serde_json::to_string_pretty(&matches).unwrap());
+            }
+        }
+
+        CliRunResult::None
+    }
+}
+
+fn search_file(path: &Path, matcher: &Matcher) -> Vec<SearchMatch> {
+    let Ok(source_text) = std::fs::read_to_string(path) else { return vec![] };
+    let Ok(source_type) = SourceType::from_path(path) else { return vec![] };
+    let allocator = Allocator::default();
+    // Files with syntax errors are searched in the program the parser could recover
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    let Ok(program) = serde_json::to_value(&ret.program) else { return vec![] };
+    let query = Query::new(&program);
+
+    let found: Vec<(Span, BTreeMap<String, Span>)> = match matcher {
+        Matcher::Selector(selector) => {
+            query.select(selector).map(|node| (node.span(), BTreeMap::new())).collect()
+        }
+        Matcher::Pattern(pattern) => {
+            query.find(pattern).map(|(node, bindings)| (node.span(), bindings)).collect()
+        }
+    };
+
+    let text = |span: Span| span.source_text(&source_text).to_string();
+    found
+        .into_iter()
+        .map(|(span, bindings)| {
+            let (line, column) = line_column(&source_text, span.start);
+            SearchMatch {
+                path: path.to_string_lossy().to_string(),
+                span,
+                line,
+                column,
+                text: text(span),
+                bindings: bindings.into_iter().map(|(name, span)| (name, text(span))).collect(),
+            }
+        })
+        .collect()
+}
+
+fn line_column(source_text: &str, offset: u32) -> (usize, usize) {
+    let before = &source_text[..offset as usize];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+}
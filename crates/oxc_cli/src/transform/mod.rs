@@ -0,0 +1,289 @@
+use std::{
+    borrow::Cow,
+    path::{Component, Path, PathBuf},
+};
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions, SourceMap};
+use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error};
+use oxc_linter::partial_loader::{PartialLoader, LINT_PARTIAL_LOADER_EXT};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{
+    ReactJsxOptions, ReactJsxRuntime, ReactJsxRuntimeOption, ReactRefreshOptions, TransformOptions,
+    TransformTarget, Transformer,
+};
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::{
+    command::{JsxRuntime, TransformOptions as CliTransformOptions},
+    result::{CliRunResult, TransformResult},
+    walk::{Extensions, Walk},
+    Runner,
+};
+
+/// Files with syntax that needs to be lowered, plain JavaScript files are left untouched.
+const TRANSFORM_EXTENSIONS: [&str; 5] = ["ts", "mts", "cts", "tsx", "jsx"];
+
+/// Plain JavaScript files are lowered as well when a `--target` and an `--out-dir` are provided.
+const LOWER_EXTENSIONS: [&str; 3] = ["js", "mjs", "cjs"];
+
+pub struct TransformRunner {
+    options: CliTransformOptions,
+}
+
+impl Runner for TransformRunner {
+    type Options = CliTransformOptions;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        let CliTransformOptions { paths, ignore_options, .. } = &self.options;
+
+        if paths.is_empty() {
+            return CliRunResult::InvalidOptions { message: "No paths are provided.".to_string() };
+        }
+
+        let now = std::time::Instant::now();
+
+        let mut extensions = TRANSFORM_EXTENSIONS.to_vec();
+        extensions.extend(LINT_PARTIAL_LOADER_EXT);
+        if self.options.target < TransformTarget::ESNext && self.options.out_dir.is_some() {
+            extensions.extend(LOWER_EXTENSIONS);
+        }
+        let paths =
+            Walk::new(paths, ignore_options).with_extensions(Extensions(extensions)).paths();
+
+        let diagnostic_service = DiagnosticService::default();
+
+        // Transform in another thread so diagnostics can be printed immediately from diagnostic_service.run.
+        std::thread::scope(|s| {
+            let tx_error = diagnostic_service.sender().clone();
+            let paths = &paths;
+            let runner = &self;
+            s.spawn(move || {
+                paths.par_iter().for_each(|path| runner.transform(path, &tx_error));
+                tx_error.send(None).unwrap();
+            });
+            diagnostic_service.run();
+        });
+
+        CliRunResult::TransformResult(TransformResult {
+            duration: now.elapsed(),
+            number_of_files: paths.len(),
+            number_of_errors: diagnostic_service.errors_count(),
+        })
+    }
+}
+
+impl TransformRunner {
+    fn transform(&self, path: &Path, tx_error: &DiagnosticSender) {
+        let source_text = match std::fs::read_to_string(path) {
+            Ok(source_text) => source_text,
+            Err(err) => {
+                let error = miette::miette!("Failed to read {}: {err}", path.display());
+                tx_error.send(Some((path.to_path_buf(), vec![error]))).unwrap();
+                return;
+            }
+        };
+        let (source_type, script_text) = match SourceType::from_path(path) {
+            Ok(source_type) => (source_type, None),
+            Err(_) => {
+                let Some(partial_loader) = path
+                    .extension()
+                    .and_then(std::ffi::OsStr::to_str)
+                    .and_then(PartialLoader::from_extension)
+                else {
+                    return;
+                };
+                let ret = partial_loader.parse(&source_text);
+                (ret.source_type, Some(ret.source_text))
+            }
+        };
+        if source_type.is_typescript_definition() {
+            return;
+        }
+        if let Err(errors) =
+            self.transform_source(path, &source_text, script_text.as_deref(), source_type)
+        {
+            let diagnostics = DiagnosticService::wrap_diagnostics(path, &source_text, errors);
+            tx_error.send(Some(diagnostics)).unwrap();
+        }
+    }
+
+    /// `script_text` is the script extracted from a single file component. Its spans are valid in
+    /// `source_text`, so the source map points straight into the component.
+    fn transform_source(
+        &self,
+        path: &Path,
+        source_text: &str,
+        script_text: Option<&str>,
+        source_type: SourceType,
+    ) -> Result<(), Vec<Error>> {
+        let script_text = script_text.unwrap_or(source_text);
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, script_text, source_type).parse();
+        if !ret.errors.is_empty() {
+            return Err(ret.errors);
+        }
+
+        let semantic = SemanticBuilder::new(script_text, source_type)
+            .with_trivias(ret.trivias)
+            .build(&ret.program)
+            .semantic;
+        let program = allocator.alloc(ret.program);
+        Transformer::new(&allocator, source_type, semantic, self.transform_options())
+            .build(program)?;
+
+        let output_path = self.output_path(path).ok_or_else(|| {
+            vec![miette::miette!(
+                "{} is outside of the current working directory and cannot be written to `--out-dir`.",
+                path.display()
+            )]
+        })?;
+        if output_path == path {
+            return Err(vec![miette::miette!(
+                "Refusing to overwrite the input file {}, use `--out-dir`.",
+                path.display()
+            )]);
+        }
+
+        let mut codegen = Codegen::<false>::new(source_text.len(), CodegenOptions);
+        if self.options.source_map {
+            let source_name = relative_path(&output_path, path);
+            codegen = codegen.with_source_map(&source_name.to_string_lossy(), source_text);
+        }
+        let ret = codegen.build_with_source_map(program);
+
+        let mut output = ret.source_text;
+        let output_file_name = output_path.file_name().unwrap().to_string_lossy().to_string();
+        let mut files = vec![];
+        if let Some(source_map) = ret.source_map {
+            let source_map_path = output_path.with_file_name(format!("{output_file_name}.map"));
+            output.push_str(&SourceMap::to_url_comment(&format!("{output_file_name}.map")));
+            output.push('\n');
+            let source_map = source_map.with_file(output_file_name).to_json_string();
+            files.push((source_map_path, source_map));
+        }
+        files.push((output_path, output));
+
+        for (path, contents) in files {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).map_err(|err| write_error(&path, &err))?;
+            }
+            std::fs::write(&path, contents).map_err(|err| write_error(&path, &err))?;
+        }
+        Ok(())
+    }
+
+    fn transform_options(&self) -> TransformOptions {
+        let runtime = match self.options.jsx_runtime {
+            JsxRuntime::Classic => ReactJsxRuntime::Classic,
+            JsxRuntime::Automatic => ReactJsxRuntime::Automatic,
+        };
+        let mut react_jsx = ReactJsxOptions {
+            runtime: Some(ReactJsxRuntimeOption::Valid(runtime)),
+            ..ReactJsxOptions::default()
+        };
+        if let Some(import_source) = &self.options.jsx_import_source {
+            react_jsx.import_source = Cow::Owned(import_source.clone());
+        }
+        TransformOptions {
+            target: self.options.target,
+            module: self.options.module,
+            react_jsx: Some(react_jsx),
+            react_refresh: self.options.react_refresh.then(ReactRefreshOptions::default),
+            ..TransformOptions::default()
+        }
+    }
+
+    /// `foo.ts` -> `foo.js`, `foo.mts` -> `foo.mjs`, `foo.cts` -> `foo.cjs`, `foo.vue` -> `foo.vue.js`.
+    /// The directory structure relative to the current working directory is kept with `--out-dir`,
+    /// `None` for the files outside of it.
+    fn output_path(&self, path: &Path) -> Option<PathBuf> {
+        let path = match path.extension().and_then(std::ffi::OsStr::to_str) {
+            Some("mts" | "mjs") => path.with_extension("mjs"),
+            Some("cts" | "cjs") => path.with_extension("cjs"),
+            Some(ext) if LINT_PARTIAL_LOADER_EXT.contains(&ext) => {
+                path.with_extension(format!("{ext}.js"))
+            }
+            _ => path.with_extension("js"),
+        };
+        let Some(out_dir) = &self.options.out_dir else { return Some(path) };
+        let cwd = std::env::current_dir().unwrap_or_default();
+        Some(out_dir.join(path_in(&cwd, &path)?))
+    }
+}
+
+/// `path` relative to `root` with its `.` and `..` components resolved, `None` when it is outside
+/// of `root`.
+fn path_in(root: &Path, path: &Path) -> Option<PathBuf> {
+    let path = path.strip_prefix(root).unwrap_or(path);
+    let mut relative = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(name) => relative.push(name),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !relative.pop() {
+                    return None;
+                }
+            }
+            // Absolute paths which are not in `root`
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(relative)
+}
+
+/// Path of `to` relative to the directory of `from`, used for the `sources` of the source map.
+fn relative_path(from: &Path, to: &Path) -> PathBuf {
+    let absolute = |path: &Path| {
+        if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().unwrap_or_default().join(path)
+        }
+    };
+    let from = absolute(from);
+    let to = absolute(to);
+    let from_dir = from.parent().unwrap_or(&from);
+    let from_components = from_dir.components().collect::<Vec<_>>();
+    let to_components = to.components().collect::<Vec<_>>();
+    let common = from_components.iter().zip(&to_components).take_while(|(a, b)| a == b).count();
+    let mut relative = PathBuf::new();
+    for _ in common..from_components.len() {
+        relative.push("..");
+    }
+    for component in &to_components[common..] {
+        relative.push(component);
+    }
+    relative
+}
+
+fn write_error(path: &Path, err: &std::io::Error) -> Vec<Error> {
+    vec![miette::miette!("Failed to write {}: {err}", path.display())]
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use super::path_in;
+
+    #[test]
+    fn path_in_root() {
+        let root = Path::new("/project");
+        assert_eq!(path_in(root, Path::new("a/x.js")), Some(PathBuf::from("a/x.js")));
+        assert_eq!(path_in(root, Path::new("./a/./x.js")), Some(PathBuf::from("a/x.js")));
+        assert_eq!(path_in(root, Path::new("/project/a/x.js")), Some(PathBuf::from("a/x.js")));
+        assert_eq!(path_in(root, Path::new("b/../a/x.js")), Some(PathBuf::from("a/x.js")));
+        // `../a/x.js` and `a/x.js` must not be written to the same output file
+        assert_eq!(path_in(root, Path::new("../a/x.js")), None);
+        assert_eq!(path_in(root, Path::new("a/../../x.js")), None);
+        assert_eq!(path_in(root, Path::new("/other/a/x.js")), None);
+    }
+}
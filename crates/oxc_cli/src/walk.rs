@@ -1,9 +1,12 @@
 use std::{
+    fs::File,
+    io::Read,
     path::{Path, PathBuf},
-    sync::mpsc,
+    sync::{mpsc, Arc},
 };
 
-use ignore::{overrides::OverrideBuilder, DirEntry};
+use ignore::{gitignore::GitignoreBuilder, overrides::OverrideBuilder, DirEntry, Match};
+use oxc_linter::{is_binary, SkipReason, SkippedFiles, BINARY_SNIFF_LEN};
 use oxc_span::VALID_EXTENSIONS;
 
 use crate::IgnoreOptions;
@@ -21,44 +24,61 @@ pub struct Walk {
     inner: ignore::WalkParallel,
     /// The file extensions to include during the traversal.
     extensions: Extensions,
+    /// Files larger than this are skipped, `0` for no limit.
+    max_file_size: u64,
+    /// Include the files given as paths whatever their extension.
+    force: bool,
+    /// Files which are not yielded because of their size
+    skipped: Arc<SkippedFiles>,
 }
 
 struct WalkBuilder {
-    sender: mpsc::Sender<Vec<Box<Path>>>,
+    sender: mpsc::Sender<Box<Path>>,
     extensions: Extensions,
+    max_file_size: u64,
+    force: bool,
+    skipped: Arc<SkippedFiles>,
 }
 
 impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
     fn build(&mut self) -> Box<dyn ignore::ParallelVisitor + 's> {
         Box::new(WalkCollector {
-            paths: vec![],
             sender: self.sender.clone(),
             extensions: self.extensions.clone(),
+            max_file_size: self.max_file_size,
+            force: self.force,
+            skipped: Arc::clone(&self.skipped),
         })
     }
 }
 
 struct WalkCollector {
-    paths: Vec<Box<Path>>,
-    sender: mpsc::Sender<Vec<Box<Path>>>,
+    sender: mpsc::Sender<Box<Path>>,
     extensions: Extensions,
-}
-
-impl Drop for WalkCollector {
-    fn drop(&mut self) {
-        let paths = std::mem::take(&mut self.paths);
-        self.sender.send(paths).unwrap();
-    }
+    max_file_size: u64,
+    force: bool,
+    skipped: Arc<SkippedFiles>,
 }
 
 impl ignore::ParallelVisitor for WalkCollector {
     fn visit(&mut self, entry: Result<ignore::DirEntry, ignore::Error>) -> ignore::WalkState {
         match entry {
             Ok(entry) => {
+                // Files given as paths are at depth 0
+                let forced = self.force && entry.depth() == 0;
                 if entry.file_type().is_some_and(|ft| !ft.is_dir())
-                    && Walk::is_wanted_entry(&entry, &self.extensions)
+                    && (forced || Walk::is_wanted_entry(&entry, &self.extensions))
                 {
-                    self.paths.push(entry.path().to_path_buf().into_boxed_path());
+                    // Binary files are skipped by the linter when it reads them
+                    let size = entry.metadata().map_or(0, |metadata| metadata.len());
+                    if self.max_file_size > 0 && size > self.max_file_size {
+                        self.skipped.add(SkipReason::SizeLimit);
+                        return ignore::WalkState::Continue;
+                    }
+                    // The receiving end is gone when the consumer stopped early
+                    if self.sender.send(entry.path().to_path_buf().into_boxed_path()).is_err() {
+                        return ignore::WalkState::Quit;
+                    }
                 }
                 ignore::WalkState::Continue
             }
@@ -105,15 +125,39 @@ impl Walk {
         // * following symlinks is a really slow syscall
         // * it is super rare to have symlinked source code
         let inner = inner.ignore(false).git_global(false).follow_links(false).build_parallel();
-        Self { inner, extensions: Extensions::default() }
+        Self {
+            inner,
+            extensions: Extensions::default(),
+            max_file_size: options.max_file_size,
+            force: false,
+            skipped: Arc::default(),
+        }
     }
 
+    /// Walk all paths and collect the matching files.
     pub fn paths(self) -> Vec<Box<Path>> {
-        let (sender, receiver) = mpsc::channel::<Vec<Box<Path>>>();
-        let mut builder = WalkBuilder { sender, extensions: self.extensions };
+        let (sender, receiver) = mpsc::channel::<Box<Path>>();
+        self.visit(sender);
+        receiver.into_iter().collect()
+    }
+
+    /// Walk in a background thread and yield the matching files as soon as they are found,
+    /// so they can be processed while the walk is still in progress.
+    pub fn stream(self) -> mpsc::Receiver<Box<Path>> {
+        let (sender, receiver) = mpsc::channel::<Box<Path>>();
+        std::thread::spawn(move || self.visit(sender));
+        receiver
+    }
+
+    fn visit(self, sender: mpsc::Sender<Box<Path>>) {
+        let mut builder = WalkBuilder {
+            sender,
+            extensions: self.extensions,
+            max_file_size: self.max_file_size,
+            force: self.force,
+            skipped: self.skipped,
+        };
         self.inner.visit(&mut builder);
-        drop(builder);
-        receiver.into_iter().flatten().collect()
     }
 
     pub fn with_extensions(mut self, extensions: Extensions) -> Self {
@@ -121,26 +165,135 @@ impl Walk {
         self
     }
 
+    /// The files skipped by the walk, counted while it runs.
+    pub fn skipped(&self) -> Arc<SkippedFiles> {
+        Arc::clone(&self.skipped)
+    }
+
+    /// Include the files given as paths even if their extension is not one of the extensions.
+    /// Files found in the given directories are still filtered by extension.
+    #[must_use]
+    pub fn with_force(mut self, yes: bool) -> Self {
+        self.force = yes;
+        self
+    }
+
     fn is_wanted_entry(dir_entry: &DirEntry, extensions: &Extensions) -> bool {
         let Some(file_type) = dir_entry.file_type() else { return false };
         if file_type.is_dir() {
             return false;
         }
-        let Some(file_name) = dir_entry.path().file_name() else { return false };
+        Self::is_wanted_path(dir_entry.path(), extensions)
+    }
+
+    /// Whether the file name of `path` is one of the `extensions` and not a minified file.
+    pub(crate) fn is_wanted_path(path: &Path, extensions: &Extensions) -> bool {
+        let Some(file_name) = path.file_name() else { return false };
         if [".min.", "-min.", "_min."].iter().any(|e| file_name.to_string_lossy().contains(e)) {
             return false;
         }
-        let Some(extension) = dir_entry.path().extension() else { return false };
+        let Some(extension) = path.extension() else { return false };
         let extension = extension.to_string_lossy();
         extensions.0.contains(&extension.as_ref())
     }
+
+    /// Files which would stall a worker without being worth linting: files above the size limit,
+    /// e.g. bundles, skipped by the walk, and binary files with a source file extension, skipped
+    /// by the linter.
+    pub(crate) fn skip_reason(path: &Path, max_file_size: u64) -> Option<(SkipReason, String)> {
+        let size = std::fs::metadata(path).ok()?.len();
+        if max_file_size > 0 && size > max_file_size {
+            return Some((
+                SkipReason::SizeLimit,
+                format!(
+                    "file size of {size} bytes exceeds the limit of {max_file_size} bytes, \
+                     see `--max-file-size`"
+                ),
+            ));
+        }
+        Self::is_binary(path).then(|| (SkipReason::Binary, "file has binary content".to_string()))
+    }
+
+    /// The ignore file or pattern excluding the file at `path` from a walk started in `cwd`,
+    /// mirroring the rules set up in [Walk::new]. `path` must be absolute.
+    pub(crate) fn ignore_reason(
+        cwd: &Path,
+        path: &Path,
+        options: &IgnoreOptions,
+    ) -> Option<String> {
+        let dir = path.parent()?;
+        let in_git_repository = dir.ancestors().any(|dir| dir.join(".git").exists());
+
+        if !options.no_ignore && !options.ignore_pattern.is_empty() {
+            let mut override_builder = OverrideBuilder::new(Path::new("/"));
+            for pattern in &options.ignore_pattern {
+                override_builder.add(&format!("!{pattern}")).ok()?;
+            }
+            let overrides = override_builder.build().ok()?;
+            // A matched directory is not descended into
+            for (depth, path) in path.ancestors().enumerate() {
+                if let Match::Ignore(glob) = overrides.matched(path, depth > 0) {
+                    let pattern = glob.original().trim_start_matches('!');
+                    return Some(format!("matched by `--ignore-pattern {pattern}`"));
+                }
+            }
+        }
+
+        // Deeper ignore files take precedence, and the custom ignore file over `.gitignore`
+        let mut ignore_files = vec![];
+        if !options.no_ignore {
+            ignore_files.push(PathBuf::from(&options.ignore_path));
+        }
+        if in_git_repository {
+            ignore_files.push(PathBuf::from(".gitignore"));
+        }
+        for dir in dir.ancestors() {
+            for file_name in &ignore_files {
+                let ignore_file = dir.join(file_name);
+                if !ignore_file.is_file() {
+                    continue;
+                }
+                let mut builder = GitignoreBuilder::new(dir);
+                builder.add(&ignore_file);
+                let Ok(gitignore) = builder.build() else { continue };
+                match gitignore.matched_path_or_any_parents(path, false) {
+                    Match::Ignore(glob) => {
+                        return Some(format!(
+                            "matched by `{}` in {}",
+                            glob.original(),
+                            ignore_file.display()
+                        ));
+                    }
+                    Match::Whitelist(_) => return None,
+                    Match::None => {}
+                }
+            }
+        }
+
+        // Hidden files and directories are skipped by the walker
+        let relative_path = path.strip_prefix(cwd).unwrap_or(path);
+        let hidden = relative_path.components().find_map(|component| {
+            let name = component.as_os_str().to_str()?;
+            (name.starts_with('.') && name != "." && name != "..").then_some(name)
+        });
+        hidden.map(|name| format!("`{name}` is hidden"))
+    }
+
+    fn is_binary(path: &Path) -> bool {
+        let Ok(file) = File::open(path) else { return false };
+        let mut buffer = Vec::with_capacity(BINARY_SNIFF_LEN);
+        if file.take(BINARY_SNIFF_LEN as u64).read_to_end(&mut buffer).is_err() {
+            return false;
+        }
+        is_binary(&buffer)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use std::{env, ffi::OsString};
 
-    use crate::IgnoreOptions;
+    use crate::{IgnoreOptions, DEFAULT_MAX_FILE_SIZE};
 
     use super::{Extensions, Walk};
 
@@ -152,6 +305,7 @@ mod test {
             no_ignore: false,
             ignore_path: OsString::from(".gitignore"),
             ignore_pattern: vec![],
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
         };
 
         let mut paths = Walk::new(&fixtures, &ignore_options)
@@ -164,4 +318,52 @@ mod test {
 
         assert_eq!(paths, vec!["bar.vue", "foo.js"]);
     }
+
+    #[test]
+    fn test_walk_stream() {
+        let fixture = env::current_dir().unwrap().join("fixtures/walk_dir");
+        let fixtures = vec![fixture.clone()];
+        let ignore_options = IgnoreOptions {
+            no_ignore: false,
+            ignore_path: OsString::from(".gitignore"),
+            ignore_pattern: vec![],
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+        };
+
+        let mut paths = Walk::new(&fixtures, &ignore_options)
+            .with_extensions(Extensions(["js", "vue"].to_vec()))
+            .stream()
+            .into_iter()
+            .map(|path| path.strip_prefix(&fixture).unwrap().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        assert_eq!(paths, vec!["bar.vue", "foo.js"]);
+    }
+
+    #[test]
+    fn test_walk_skips_large_files() {
+        let fixture = env::current_dir().unwrap().join("fixtures/walk_skip");
+        let fixtures = vec![fixture.clone()];
+        let walk = |max_file_size| {
+            let ignore_options = IgnoreOptions {
+                no_ignore: false,
+                ignore_path: OsString::from(".gitignore"),
+                ignore_pattern: vec![],
+                max_file_size,
+            };
+            let mut paths = Walk::new(&fixtures, &ignore_options)
+                .paths()
+                .into_iter()
+                .map(|path| path.strip_prefix(&fixture).unwrap().to_string_lossy().to_string())
+                .collect::<Vec<_>>();
+            paths.sort();
+            paths
+        };
+
+        // Binary files are only read by the linter
+        assert_eq!(walk(DEFAULT_MAX_FILE_SIZE), vec!["binary.js", "large.js", "small.js"]);
+        assert_eq!(walk(100), vec!["binary.js", "small.js"]);
+        assert_eq!(walk(0), vec!["binary.js", "large.js", "small.js"]);
+    }
 }
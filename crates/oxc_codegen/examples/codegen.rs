@@ -1,7 +1,8 @@
 use std::{env, path::Path};
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_ast::TriviasMap;
+use oxc_codegen::{Codegen, CodegenOptions, PreserveComments};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -29,7 +30,10 @@ fn main() {
     println!("{source_text}");
 
     let codegen_options = CodegenOptions;
-    let printed = Codegen::<false>::new(source_text.len(), codegen_options).build(&ret.program);
+    let trivias = TriviasMap::from(ret.trivias);
+    let printed = Codegen::<false>::new(source_text.len(), codegen_options)
+        .with_comments(&source_text, &trivias, PreserveComments::All)
+        .build(&ret.program);
     println!("Printed:");
     println!("{printed}");
 
@@ -1,6 +1,7 @@
 use oxc_allocator::{Box, Vec};
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
+use oxc_span::GetSpan;
 use oxc_syntax::{
     identifier::{LS, PS},
     operator::{BinaryOperator, UnaryOperator},
@@ -42,6 +43,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for Program<'a> {
             hashbang.gen(p, ctx);
         }
         print_directives_and_statements(p, &self.directives, &self.body, ctx);
+        p.print_leading_comments(u32::MAX);
     }
 }
 
@@ -92,6 +94,7 @@ impl<const MINIFY: bool> Gen<MINIFY> for Hashbang {
 
 impl<const MINIFY: bool> Gen<MINIFY> for Directive {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
+        p.print_leading_comments(self.span.start);
         // Use the string value instead of the raw self.directive because it can cannot escaped values.
         print_str(self.expression.value.as_str(), p);
         p.print_semicolon();
@@ -100,6 +103,8 @@ impl<const MINIFY: bool> Gen<MINIFY> for Directive {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for Statement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
+        p.print_leading_comments(self.span().start);
+        p.add_source_mapping(self.span());
         match self {
             Self::BlockStatement(stmt) => stmt.gen(p, ctx),
             Self::BreakStatement(stmt) => stmt.gen(p, ctx),
@@ -617,6 +622,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for FunctionBody<'a> {
             ctx,
             true,
         );
+        p.print_leading_comments(self.span.end);
         p.print_block_end();
         p.needs_semicolon = false;
     }
@@ -847,6 +853,8 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ExportDefaultDeclarationKind<'a> {
 
 impl<'a, const MINIFY: bool> GenExpr<MINIFY> for Expression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
+        p.print_inline_comments(self.span().start);
+        p.add_source_mapping(self.span());
         match self {
             Self::BooleanLiteral(lit) => lit.gen(p, ctx),
             Self::NullLiteral(lit) => lit.gen(p, ctx),
@@ -902,18 +910,21 @@ impl<const MINIFY: bool> Gen<MINIFY> for IdentifierReference {
         // }
         // }
         // }
+        p.add_source_mapping_for_name(self.span, &self.name);
         p.print_str(self.name.as_bytes());
     }
 }
 
 impl<const MINIFY: bool> Gen<MINIFY> for IdentifierName {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
+        p.add_source_mapping(self.span);
         p.print_str(self.name.as_bytes());
     }
 }
 
 impl<const MINIFY: bool> Gen<MINIFY> for BindingIdentifier {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
+        p.add_source_mapping_for_name(self.span, &self.name);
         p.print_symbol(self.symbol_id.get(), &self.name);
     }
 }
@@ -1235,6 +1246,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for ObjectExpression<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ObjectPropertyKind<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
+        p.print_inline_comments(self.span().start);
         match self {
             Self::ObjectProperty(prop) => prop.gen(p, ctx),
             Self::SpreadProperty(elem) => elem.gen(p, ctx),
@@ -1729,6 +1741,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for Class<'a> {
                 if item.is_typescript_syntax() {
                     continue;
                 }
+                p.print_leading_comments(item.span().start);
                 p.print_indent();
                 p.print_semicolon_if_needed();
                 item.gen(p, ctx);
@@ -1740,6 +1753,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for Class<'a> {
                 }
                 p.print_soft_newline();
             }
+            p.print_leading_comments(self.body.span.end);
             p.print_block_end();
             p.needs_semicolon = false;
         });
@@ -2028,6 +2042,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for PropertyDefinition<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for AccessorProperty<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
+        self.decorators.gen(p, ctx);
         if self.r#static {
             p.print_str(b"static ");
         }
@@ -4,6 +4,7 @@
 //!
 //! * whitespace removal
 //! * sourcemaps
+//! * comment preservation
 //!
 //! Code adapted from
 //! * [esbuild](https://github.com/evanw/esbuild/blob/main/internal/js_printer/js_printer.go)
@@ -11,12 +12,15 @@
 mod context;
 mod gen;
 mod operator;
+mod sourcemap;
+mod sourcemap_builder;
 
 use std::str::from_utf8_unchecked;
 
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
-use oxc_span::Atom;
+use oxc_ast::{CommentKind, TriviasMap};
+use oxc_span::{Atom, Span, SPAN};
 use oxc_syntax::{
     identifier::is_identifier_part,
     operator::{BinaryOperator, UnaryOperator, UpdateOperator},
@@ -28,12 +32,41 @@ use self::{
     context::Context,
     gen::{Gen, GenExpr},
     operator::Operator,
+    sourcemap_builder::SourcemapBuilder,
 };
+pub use crate::sourcemap::{SourceMap, SourceMapToken};
 // use crate::mangler::Mangler;
 
 #[derive(Debug, Default, Clone, Copy)]
 pub struct CodegenOptions;
 
+/// Which comments of the source text are kept in the output.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PreserveComments {
+    /// Strip all comments
+    #[default]
+    None,
+    /// Keep legal comments, i.e. comments starting with `//!` or `/*!`,
+    /// or containing `@license` or `@preserve`
+    Legal,
+    /// Keep all comments
+    All,
+}
+
+/// A comment to be printed before the first statement, class element, block end or expression
+/// after it.
+struct SourceComment {
+    start: u32,
+    kind: CommentKind,
+    /// Text without the `//`, `/*` and `*/` delimiters
+    text: String,
+}
+
+pub struct CodegenReturn {
+    pub source_text: String,
+    pub source_map: Option<SourceMap>,
+}
+
 pub struct Codegen<const MINIFY: bool> {
     #[allow(unused)]
     options: CodegenOptions,
@@ -58,6 +91,15 @@ pub struct Codegen<const MINIFY: bool> {
 
     /// Track the current indentation level
     indentation: u8,
+
+    sourcemap_builder: Option<SourcemapBuilder>,
+    /// Source map of a previous pass, composed with the generated source map
+    input_source_map: Option<SourceMap>,
+
+    /// Comments to preserve, sorted by position
+    comments: Vec<SourceComment>,
+    /// Index of the first comment in `comments` which has not been printed yet
+    next_comment: usize,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -85,9 +127,60 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
             start_of_arrow_expr: 0,
             start_of_default_export: 0,
             indentation: 0,
+            sourcemap_builder: None,
+            input_source_map: None,
+            comments: vec![],
+            next_comment: 0,
         }
     }
 
+    /// Generate a source map for `source_text`, which is referred to as `source_name` in the source map.
+    #[must_use]
+    pub fn with_source_map(mut self, source_name: &str, source_text: &str) -> Self {
+        self.sourcemap_builder = Some(SourcemapBuilder::new(source_name, source_text));
+        self
+    }
+
+    /// Compose the generated source map with `input_source_map`, the source map of a previous
+    /// pass which produced `source_text` of [Codegen::with_source_map], e.g. a transform or an
+    /// earlier minification. The source map of [Codegen::build_with_source_map] then maps back
+    /// to the sources of `input_source_map`. See [SourceMap::compose].
+    #[must_use]
+    pub fn with_input_source_map(mut self, input_source_map: SourceMap) -> Self {
+        self.input_source_map = Some(input_source_map);
+        self
+    }
+
+    /// Keep comments of `source_text`. Comments between statements are printed on their own line
+    /// before the statement, class element or end of block following them. Comments inside of a
+    /// statement are printed inline before the expression or object property following them.
+    #[must_use]
+    pub fn with_comments(
+        mut self,
+        source_text: &str,
+        trivias: &TriviasMap,
+        preserve: PreserveComments,
+    ) -> Self {
+        self.comments = trivias
+            .comments_spans()
+            .filter_map(|(comment, span)| {
+                let text = span.source_text(source_text);
+                let keep = match preserve {
+                    PreserveComments::None => false,
+                    PreserveComments::Legal => is_legal_comment(text),
+                    PreserveComments::All => true,
+                };
+                keep.then(|| SourceComment {
+                    start: span.start,
+                    kind: comment.kind(),
+                    text: text.to_string(),
+                })
+            })
+            .collect();
+        self.next_comment = 0;
+        self
+    }
+
     // fn with_mangler(&mut self, mangler: Mangler) {
     // self.mangler = Some(mangler);
     // }
@@ -97,6 +190,18 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
         self.into_code()
     }
 
+    /// The source map is `None` without [Codegen::with_source_map], or if the source map of
+    /// [Codegen::with_input_source_map] is malformed.
+    pub fn build_with_source_map(mut self, program: &Program<'_>) -> CodegenReturn {
+        program.gen(&mut self, Context::default());
+        let source_map = self.sourcemap_builder.take().map(SourcemapBuilder::into_sourcemap);
+        let source_map = match (source_map, self.input_source_map.take()) {
+            (Some(source_map), Some(input_source_map)) => source_map.compose(&input_source_map),
+            (source_map, _) => source_map,
+        };
+        CodegenReturn { source_text: self.into_code(), source_map }
+    }
+
     pub fn into_code(self) -> String {
         // SAFETY: criteria of `from_utf8_unchecked`.are met.
         unsafe { String::from_utf8_unchecked(self.code) }
@@ -110,6 +215,96 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
         self.code().len()
     }
 
+    /// Map the current output position to the start of `span`.
+    fn add_source_mapping(&mut self, span: Span) {
+        // Nodes created by transforms have no position in the original source.
+        if span == SPAN {
+            return;
+        }
+        if let Some(sourcemap_builder) = &mut self.sourcemap_builder {
+            sourcemap_builder.add_source_mapping(&self.code, span.start);
+        }
+    }
+
+    /// Map the current output position to the identifier at `span`, which is printed as `name`.
+    /// The original name is recorded in the source map if the identifier has been renamed.
+    fn add_source_mapping_for_name(&mut self, span: Span, name: &str) {
+        if span == SPAN {
+            return;
+        }
+        if let Some(sourcemap_builder) = &mut self.sourcemap_builder {
+            sourcemap_builder.add_source_mapping_for_name(&self.code, span, name);
+        }
+    }
+
+    /// Print the comments starting before `position` which have not been printed yet.
+    fn print_leading_comments(&mut self, position: u32) {
+        while let Some(comment) = self.comments.get_mut(self.next_comment) {
+            if comment.start >= position {
+                break;
+            }
+            let kind = comment.kind;
+            let text = std::mem::take(&mut comment.text);
+            self.next_comment += 1;
+            self.print_indent();
+            match kind {
+                CommentKind::SingleLine => {
+                    self.print_str(b"//");
+                    self.print_str(text.as_bytes());
+                    // A line comment always needs to be terminated, even when minifying.
+                    self.print(b'\n');
+                }
+                CommentKind::MultiLine => {
+                    self.print_str(b"/*");
+                    self.print_str(text.as_bytes());
+                    self.print_str(b"*/");
+                    self.print_soft_newline();
+                }
+            }
+        }
+    }
+
+    /// Print the comments starting before `position` which have not been printed yet, inline as
+    /// block comments. A line comment inside of an expression could end it early, e.g. in
+    /// `return // a` followed by an expression on the next line.
+    fn print_inline_comments(&mut self, position: u32) {
+        let code_len = self.code_len();
+        let at_start_of_stmt = self.start_of_stmt == code_len;
+        let at_start_of_arrow_expr = self.start_of_arrow_expr == code_len;
+        let at_start_of_default_export = self.start_of_default_export == code_len;
+        let mut printed = false;
+        while let Some(comment) = self.comments.get_mut(self.next_comment) {
+            if comment.start >= position {
+                break;
+            }
+            let kind = comment.kind;
+            let text = std::mem::take(&mut comment.text);
+            self.next_comment += 1;
+            self.print_str(b"/*");
+            match kind {
+                CommentKind::SingleLine => self.print_str(text.replace("*/", "* /").as_bytes()),
+                CommentKind::MultiLine => self.print_str(text.as_bytes()),
+            }
+            self.print_str(b"*/");
+            self.print_soft_space();
+            printed = true;
+        }
+        // The checks for wrapping `{`, `function` and `class` in parentheses at the start of a
+        // statement look past the comments.
+        if printed {
+            let code_len = self.code_len();
+            if at_start_of_stmt {
+                self.start_of_stmt = code_len;
+            }
+            if at_start_of_arrow_expr {
+                self.start_of_arrow_expr = code_len;
+            }
+            if at_start_of_default_export {
+                self.start_of_default_export = code_len;
+            }
+        }
+    }
+
     /// Push a single character into the buffer
     fn print(&mut self, ch: u8) {
         self.code.push(ch);
@@ -236,6 +431,7 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
             self.print_semicolon_if_needed();
             item.gen(self, ctx);
         }
+        self.print_leading_comments(stmt.span.end);
         self.print_block_end();
         self.needs_semicolon = false;
     }
@@ -327,3 +523,7 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
         }
     }
 }
+
+fn is_legal_comment(text: &str) -> bool {
+    text.starts_with('!') || text.contains("@license") || text.contains("@preserve")
+}
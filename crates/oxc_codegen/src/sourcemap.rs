@@ -0,0 +1,333 @@
+//! Source Map Revision 3
+//!
+//! <https://sourcemaps.info/spec.html>
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceMap {
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(default)]
+    pub sources: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources_content: Vec<String>,
+    #[serde(default)]
+    pub names: Vec<String>,
+    pub mappings: String,
+}
+
+impl SourceMap {
+    #[must_use]
+    pub fn with_file(mut self, file: String) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Serializing plain strings and integers does not fail.
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    /// The `//# sourceMappingURL=` comment appended to the generated code.
+    pub fn to_url_comment(file_name: &str) -> String {
+        format!("//# sourceMappingURL={file_name}")
+    }
+
+    /// Decode the `mappings`, `None` if they are malformed.
+    pub fn tokens(&self) -> Option<Vec<SourceMapToken>> {
+        let mut tokens = vec![];
+        // Source, original line, original column and name are relative to the previous segment.
+        let mut prev = [0i64; 4];
+        for (generated_line, line) in self.mappings.split(';').enumerate() {
+            let mut generated_column = 0i64;
+            for segment in line.split(',').filter(|segment| !segment.is_empty()) {
+                let fields = decode_vlq_segment(segment)?;
+                generated_column += fields.first()?;
+                let original = match fields.len() {
+                    1 => None,
+                    4 | 5 => {
+                        for (prev, delta) in prev.iter_mut().zip(&fields[1..4]) {
+                            *prev += delta;
+                        }
+                        Some((to_u32(prev[0])?, to_u32(prev[1])?, to_u32(prev[2])?))
+                    }
+                    _ => return None,
+                };
+                let name = match fields.get(4) {
+                    Some(delta) => {
+                        prev[3] += delta;
+                        Some(to_u32(prev[3])?)
+                    }
+                    None => None,
+                };
+                tokens.push(SourceMapToken {
+                    generated_line: u32::try_from(generated_line).ok()?,
+                    generated_column: to_u32(generated_column)?,
+                    original,
+                    name,
+                });
+            }
+        }
+        Some(tokens)
+    }
+
+    /// Encode `tokens`, which must be sorted by generated position, into `mappings`.
+    pub fn encode_mappings(tokens: &[SourceMapToken]) -> String {
+        let mut mappings = String::new();
+        let mut prev_line = 0;
+        let mut prev_column = 0;
+        let mut prev = [0i64; 4];
+        let mut has_segment_on_line = false;
+        for token in tokens {
+            while prev_line < token.generated_line {
+                mappings.push(';');
+                prev_line += 1;
+                prev_column = 0;
+                has_segment_on_line = false;
+            }
+            if has_segment_on_line {
+                mappings.push(',');
+            }
+            has_segment_on_line = true;
+            encode_vlq(&mut mappings, i64::from(token.generated_column) - i64::from(prev_column));
+            prev_column = token.generated_column;
+            let Some((source, line, column)) = token.original else { continue };
+            for (prev, value) in prev.iter_mut().zip([source, line, column]) {
+                encode_vlq(&mut mappings, i64::from(value) - *prev);
+                *prev = i64::from(value);
+            }
+            if let Some(name) = token.name {
+                encode_vlq(&mut mappings, i64::from(name) - prev[3]);
+                prev[3] = i64::from(name);
+            }
+        }
+        mappings
+    }
+
+    /// Compose this source map with the source map of a previous pass.
+    ///
+    /// `self` maps the output of this pass back to its input, `previous` maps that input back to
+    /// the original sources. The result maps the output of this pass to the original sources, so
+    /// the source maps of several passes (e.g. transform, then minify) can be chained.
+    ///
+    /// Segments which can not be traced back to the original sources are dropped.
+    /// Returns `None` if either source map is malformed.
+    pub fn compose(&self, previous: &Self) -> Option<Self> {
+        let previous_tokens = previous.tokens()?;
+        let mut previous_lines: Vec<Vec<&SourceMapToken>> = vec![];
+        for token in &previous_tokens {
+            let line = token.generated_line as usize;
+            if previous_lines.len() <= line {
+                previous_lines.resize_with(line + 1, Vec::new);
+            }
+            previous_lines[line].push(token);
+        }
+
+        let mut names = vec![];
+        let mut name_indices = HashMap::new();
+        let mut add_name = |name: &str| {
+            *name_indices.entry(name.to_string()).or_insert_with(|| {
+                names.push(name.to_string());
+                names.len() - 1
+            })
+        };
+
+        let mut tokens = vec![];
+        for token in self.tokens()? {
+            let Some((_, line, column)) = token.original else { continue };
+            let Some(previous_line) = previous_lines.get(line as usize) else { continue };
+            // The closest segment at or before the column
+            let index = previous_line.partition_point(|t| t.generated_column <= column);
+            let Some(previous_token) = index.checked_sub(1).map(|index| previous_line[index])
+            else {
+                continue;
+            };
+            let Some(original) = previous_token.original else { continue };
+            // Prefer the name from the earliest pass, which is the name in the original source.
+            let name = previous_token
+                .name
+                .and_then(|name| previous.names.get(name as usize))
+                .or_else(|| token.name.and_then(|name| self.names.get(name as usize)))
+                .map(|name| add_name(name))
+                .and_then(|index| u32::try_from(index).ok());
+            tokens.push(SourceMapToken {
+                generated_line: token.generated_line,
+                generated_column: token.generated_column,
+                original: Some(original),
+                name,
+            });
+        }
+
+        Some(Self {
+            version: 3,
+            file: self.file.clone(),
+            sources: previous.sources.clone(),
+            sources_content: previous.sources_content.clone(),
+            names,
+            mappings: Self::encode_mappings(&tokens),
+        })
+    }
+}
+
+/// A decoded segment of [SourceMap::mappings]. Lines and columns are zero based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceMapToken {
+    pub generated_line: u32,
+    pub generated_column: u32,
+    /// Index into `sources`, line and column in that source
+    pub original: Option<(u32, u32, u32)>,
+    /// Index into `names`
+    pub name: Option<u32>,
+}
+
+fn to_u32(value: i64) -> Option<u32> {
+    u32::try_from(value).ok()
+}
+
+/// Decode the base64 VLQ fields of a segment.
+fn decode_vlq_segment(segment: &str) -> Option<Vec<i64>> {
+    let mut fields = vec![];
+    let mut value = 0i64;
+    let mut shift = 0;
+    for byte in segment.bytes() {
+        let digit = i64::try_from(BASE64.iter().position(|b| *b == byte)?).ok()?;
+        value |= (digit & 0b11111).checked_shl(shift)?;
+        if digit & 0b100000 == 0 {
+            fields.push(if value & 1 == 1 { -(value >> 1) } else { value >> 1 });
+            value = 0;
+            shift = 0;
+        } else {
+            shift += 5;
+            if shift > 60 {
+                return None;
+            }
+        }
+    }
+    (shift == 0).then_some(fields)
+}
+
+/// Append `value` as a base64 VLQ.
+pub(crate) fn encode_vlq(out: &mut String, value: i64) {
+    let mut vlq = if value < 0 { ((-value) << 1) | 1 } else { value << 1 };
+    loop {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let mut digit = (vlq & 0b11111) as usize;
+        vlq >>= 5;
+        if vlq > 0 {
+            digit |= 0b100000;
+        }
+        out.push(BASE64[digit] as char);
+        if vlq == 0 {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_vlq_segment, encode_vlq, SourceMap, SourceMapToken};
+
+    fn token(
+        generated: (u32, u32),
+        original: Option<(u32, u32, u32)>,
+        name: Option<u32>,
+    ) -> SourceMapToken {
+        SourceMapToken {
+            generated_line: generated.0,
+            generated_column: generated.1,
+            original,
+            name,
+        }
+    }
+
+    #[test]
+    fn vlq_round_trip() {
+        let values = [0, 1, -1, 15, -15, 16, -16, 31, 32, 1000, -123_456, i64::from(i32::MAX)];
+        let mut segment = String::new();
+        for value in values {
+            encode_vlq(&mut segment, value);
+        }
+        assert_eq!(decode_vlq_segment(&segment), Some(values.to_vec()));
+
+        let mut encoded = String::new();
+        encode_vlq(&mut encoded, 16);
+        assert_eq!(encoded, "gB");
+        // A continuation bit without a following digit
+        assert_eq!(decode_vlq_segment("g"), None);
+        assert_eq!(decode_vlq_segment("A*"), None);
+    }
+
+    #[test]
+    fn mappings_round_trip() {
+        let tokens = vec![
+            token((0, 0), Some((0, 0, 0)), None),
+            token((0, 4), Some((0, 0, 4)), Some(0)),
+            token((0, 9), None, None),
+            token((2, 2), Some((1, 3, 2)), Some(1)),
+            token((2, 20), Some((0, 1, 0)), Some(0)),
+        ];
+        let mappings = SourceMap::encode_mappings(&tokens);
+        assert_eq!(mappings, "AAAA,IAAIA,K;;ECGFC,kBDFFD");
+        let source_map = SourceMap { version: 3, mappings, ..SourceMap::default() };
+        assert_eq!(source_map.tokens(), Some(tokens));
+
+        let malformed =
+            SourceMap { version: 3, mappings: "AA".to_string(), ..SourceMap::default() };
+        assert_eq!(malformed.tokens(), None);
+    }
+
+    #[test]
+    fn compose() {
+        // `function foo() {}` transformed into `let foo = function() {}` on a second line
+        let previous = SourceMap {
+            version: 3,
+            sources: vec!["a.js".to_string()],
+            names: vec!["foo".to_string()],
+            mappings: SourceMap::encode_mappings(&[
+                token((1, 0), Some((0, 0, 0)), None),
+                token((1, 4), Some((0, 0, 9)), Some(0)),
+                token((1, 10), Some((0, 0, 0)), None),
+            ]),
+            ..SourceMap::default()
+        };
+        // Then minified into `let a=function(){}`, which renamed `foo`
+        let current = SourceMap {
+            version: 3,
+            sources: vec!["b.js".to_string()],
+            names: vec!["foo".to_string()],
+            mappings: SourceMap::encode_mappings(&[
+                token((0, 0), Some((0, 1, 0)), None),
+                token((0, 4), Some((0, 1, 4)), Some(0)),
+                token((0, 6), Some((0, 1, 12)), None),
+                // Not generated from the input of this pass
+                token((0, 17), Some((0, 0, 0)), None),
+            ]),
+            ..SourceMap::default()
+        };
+
+        let composed = current.compose(&previous).unwrap();
+        assert_eq!(composed.sources, vec!["a.js".to_string()]);
+        assert_eq!(composed.names, vec!["foo".to_string()]);
+        assert_eq!(
+            composed.tokens().unwrap(),
+            vec![
+                token((0, 0), Some((0, 0, 0)), None),
+                token((0, 4), Some((0, 0, 9)), Some(0)),
+                token((0, 6), Some((0, 0, 0)), None),
+            ]
+        );
+
+        let malformed =
+            SourceMap { version: 3, mappings: "AA".to_string(), ..SourceMap::default() };
+        assert_eq!(current.compose(&malformed), None);
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use oxc_span::{LineIndex, Span};
+
+use crate::sourcemap::{SourceMap, SourceMapToken};
+
+/// Builds the `mappings` of a [SourceMap] while code is being printed.
+///
+/// Lines are zero based, columns are counted in UTF-16 code units as required by the spec.
+pub struct SourcemapBuilder {
+    source_name: String,
+    source_text: String,
+    line_index: LineIndex,
+
+    tokens: Vec<SourceMapToken>,
+    /// Original names of renamed identifiers, e.g. by the mangler
+    names: Vec<String>,
+    name_indices: HashMap<String, u32>,
+
+    generated_line: u32,
+    generated_column: u32,
+    /// Bytes of the output code that have been accounted for in `generated_line` and `generated_column`.
+    generated_offset: usize,
+}
+
+impl SourcemapBuilder {
+    pub fn new(source_name: &str, source_text: &str) -> Self {
+        Self {
+            source_name: source_name.to_string(),
+            source_text: source_text.to_string(),
+            line_index: LineIndex::new(source_text),
+            tokens: vec![],
+            names: vec![],
+            name_indices: HashMap::new(),
+            generated_line: 0,
+            generated_column: 0,
+            generated_offset: 0,
+        }
+    }
+
+    /// Map the end of `output` to the byte `position` of the original source text.
+    pub fn add_source_mapping(&mut self, output: &[u8], position: u32) {
+        self.add_token(output, position, None);
+    }
+
+    /// Map the end of `output` to the identifier at `span` in the original source text,
+    /// recording its original name if it is printed as `name`.
+    pub fn add_source_mapping_for_name(&mut self, output: &[u8], span: Span, name: &str) {
+        let original = self
+            .source_text
+            .get(span.start as usize..span.end as usize)
+            .filter(|original| *original != name)
+            .map(str::to_owned);
+        let name = original.map(|original| self.add_name(&original));
+        self.add_token(output, span.start, name);
+    }
+
+    pub fn into_sourcemap(self) -> SourceMap {
+        SourceMap {
+            version: 3,
+            file: None,
+            sources: vec![self.source_name],
+            sources_content: vec![self.source_text],
+            names: self.names,
+            mappings: SourceMap::encode_mappings(&self.tokens),
+        }
+    }
+
+    fn add_token(&mut self, output: &[u8], position: u32, name: Option<u32>) {
+        self.update_generated_position(output);
+        let (original_line, original_column) =
+            self.line_index.line_column_utf16(&self.source_text, position);
+        let token = SourceMapToken {
+            generated_line: self.generated_line,
+            generated_column: self.generated_column,
+            // There is only one source.
+            original: Some((0, original_line, original_column)),
+            name,
+        };
+        // Replace the last token if it starts at the same generated position.
+        if let Some(last) = self.tokens.last_mut() {
+            if last.generated_line == token.generated_line
+                && last.generated_column == token.generated_column
+            {
+                *last = token;
+                return;
+            }
+        }
+        self.tokens.push(token);
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn add_name(&mut self, name: &str) -> u32 {
+        if let Some(index) = self.name_indices.get(name) {
+            return *index;
+        }
+        let index = self.names.len() as u32;
+        self.names.push(name.to_string());
+        self.name_indices.insert(name.to_string(), index);
+        index
+    }
+
+    fn update_generated_position(&mut self, output: &[u8]) {
+        for &byte in &output[self.generated_offset..] {
+            if byte == b'\n' {
+                self.generated_line += 1;
+                self.generated_column = 0;
+            } else if byte & 0b1100_0000 != 0b1000_0000 {
+                // Count lead bytes only, 4 byte sequences are surrogate pairs in UTF-16.
+                self.generated_column += if byte >= 0b1111_0000 { 2 } else { 1 };
+            }
+        }
+        self.generated_offset = output.len();
+    }
+}
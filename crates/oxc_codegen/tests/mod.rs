@@ -0,0 +1,105 @@
+use oxc_allocator::Allocator;
+use oxc_ast::TriviasMap;
+use oxc_codegen::{Codegen, CodegenOptions, PreserveComments, SourceMap};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn codegen_with_comments(source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty(), "for source {source_text}");
+    let trivias = TriviasMap::from(ret.trivias);
+    Codegen::<false>::new(source_text.len(), CodegenOptions)
+        .with_comments(source_text, &trivias, PreserveComments::All)
+        .build(&ret.program)
+}
+
+fn test_snapshot<S>(name: &str, sources: S)
+where
+    S: IntoIterator<Item = &'static str>,
+{
+    let snapshot: String = sources
+        .into_iter()
+        .map(|source| {
+            let printed = codegen_with_comments(source);
+            format!(
+                "==================================== SOURCE ====================================
+{source}
+
+=================================== PRINTED ====================================
+{printed}
+"
+            )
+        })
+        .fold(String::new(), |mut acc, snapshot| {
+            acc.push_str(snapshot.as_str());
+            acc
+        });
+    insta::with_settings!({ prepend_module_to_snapshot => false }, {
+        insta::assert_snapshot!(name, snapshot);
+    });
+}
+
+#[test]
+fn comments() {
+    test_snapshot(
+        "comments",
+        [
+            // Comments between statements stay on their own line
+            "// leading\nlet a = 1; // trailing\nfoo();",
+            // Comments inside of a statement are printed before the following expression
+            "foo(/* a */ b, // c\n\td);",
+            "x = {\n\t// b\n\tb: 1,\n\t/* c */ c\n};",
+            "function f() {\n\treturn ( // why\n\t\tx\n\t);\n}",
+            // The function expression is still wrapped after the comment
+            "(/* c */ function () {})();",
+        ],
+    );
+}
+
+/// Line and column of the first occurrence of `needle`
+fn position_of(source_text: &str, needle: &str) -> (u32, u32) {
+    let offset = source_text.find(needle).unwrap();
+    let before = &source_text[..offset];
+    let line = before.matches('\n').count();
+    let column = offset - before.rfind('\n').map_or(0, |index| index + 1);
+    (u32::try_from(line).unwrap(), u32::try_from(column).unwrap())
+}
+
+#[test]
+fn input_source_map() {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default();
+    let source_text = "let a = 1;\nfoo(a);";
+
+    // Minify, then print the minified code again
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let first = Codegen::<true>::new(source_text.len(), CodegenOptions)
+        .with_source_map("a.js", source_text)
+        .build_with_source_map(&program);
+    let program = Parser::new(&allocator, &first.source_text, source_type).parse().program;
+    let second = Codegen::<false>::new(first.source_text.len(), CodegenOptions)
+        .with_source_map("b.js", &first.source_text)
+        .with_input_source_map(first.source_map.unwrap())
+        .build_with_source_map(&program);
+
+    let source_map = second.source_map.unwrap();
+    assert_eq!(source_map.sources, vec!["a.js".to_string()]);
+    let (line, column) = position_of(&second.source_text, "foo");
+    let token = source_map
+        .tokens()
+        .unwrap()
+        .into_iter()
+        .find(|token| (token.generated_line, token.generated_column) == (line, column))
+        .unwrap();
+    assert_eq!(token.original, Some((0, 1, 0)));
+
+    // A malformed input source map can not be composed
+    let malformed = SourceMap { version: 3, mappings: "AA".to_string(), ..SourceMap::default() };
+    let ret = Codegen::<false>::new(first.source_text.len(), CodegenOptions)
+        .with_source_map("b.js", &first.source_text)
+        .with_input_source_map(malformed)
+        .build_with_source_map(&program);
+    assert!(ret.source_map.is_none());
+}
@@ -3,11 +3,15 @@
 
 mod graphic_reporter;
 mod graphical_theme;
+mod reporter;
 mod service;
 
 use std::path::PathBuf;
 
-pub use crate::service::{DiagnosticSender, DiagnosticService, DiagnosticTuple};
+pub use crate::{
+    reporter::{DiagnosticReporter, OutputFormat},
+    service::{DiagnosticCounts, DiagnosticSender, DiagnosticService, DiagnosticTuple},
+};
 pub use graphic_reporter::{GraphicalReportHandler, GraphicalTheme};
 pub use miette;
 pub use thiserror;
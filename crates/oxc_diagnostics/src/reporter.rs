@@ -0,0 +1,413 @@
+//! Output formats of the diagnostics printed by [crate::DiagnosticService]
+
+use std::{fmt::Write, path::Path, str::FromStr};
+
+use miette::SourceSpan;
+use serde_json::json;
+
+use crate::{Error, GraphicalReportHandler, MinifiedFileError, Severity};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// The code of each diagnostic with its labels, for humans
+    #[default]
+    Graphical,
+    /// The `--format json` output of ESLint, with every linted file
+    Json,
+    /// `path:line:column: message [Severity/rule]`, one diagnostic per line as printed by compilers
+    Unix,
+    /// XML of Checkstyle with every linted file, read by Jenkins and code review tools
+    Checkstyle,
+    /// Workflow commands annotating the files of a pull request in GitHub Actions
+    Github,
+    /// A JSON object per line for each file and each of its diagnostics, written as soon as the
+    /// file is linted, for editor plugins
+    Stream,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "graphical" => Ok(Self::Graphical),
+            "json" => Ok(Self::Json),
+            "unix" => Ok(Self::Unix),
+            "checkstyle" => Ok(Self::Checkstyle),
+            "github" => Ok(Self::Github),
+            "stream" => Ok(Self::Stream),
+            _ => Err(format!(
+                "expected `graphical`, `json`, `unix`, `checkstyle`, `github` or `stream`, received `{s}`"
+            )),
+        }
+    }
+}
+
+impl OutputFormat {
+    /// Whether the output is a single document, or a stream of documents, which any other line
+    /// printed to the standard output, such as the summary of a run, would make invalid.
+    pub fn is_document(self) -> bool {
+        matches!(self, Self::Json | Self::Checkstyle | Self::Stream)
+    }
+
+    pub fn reporter(self) -> Box<dyn DiagnosticReporter> {
+        match self {
+            Self::Graphical => Box::<GraphicalReporter>::default(),
+            Self::Json => Box::<JsonReporter>::default(),
+            Self::Unix => Box::new(UnixReporter),
+            Self::Checkstyle => Box::<CheckstyleReporter>::default(),
+            Self::Github => Box::new(GithubReporter),
+            Self::Stream => Box::new(StreamReporter),
+        }
+    }
+}
+
+/// Renders the diagnostics of each file as they are received, then what closes the output.
+/// Every linted file is rendered, files without diagnostics with an empty slice.
+pub trait DiagnosticReporter {
+    fn render_file(&mut self, path: &Path, diagnostics: &[Error]) -> String;
+
+    fn finish(&mut self) -> String {
+        String::new()
+    }
+}
+
+/// `eslint(no-debugger)` of a message starting with the plugin and the rule, e.g.
+/// `eslint(no-debugger): ...`
+pub fn rule_of(message: &str) -> Option<&str> {
+    message
+        .split_once(": ")
+        .map(|(prefix, _)| prefix)
+        .filter(|prefix| prefix.ends_with(')') && !prefix.contains(' '))
+}
+
+/// The fields of a diagnostic shared by the text formats
+struct Info {
+    rule: Option<String>,
+    /// The message without the rule
+    message: String,
+    help: Option<String>,
+    severity: Severity,
+    /// 1-based line and column of the start and the end of the first label
+    start: Option<(usize, usize)>,
+    end: Option<(usize, usize)>,
+}
+
+impl Info {
+    fn new(diagnostic: &Error) -> Self {
+        let text = diagnostic.to_string();
+        let rule = rule_of(&text).map(ToString::to_string);
+        let message =
+            rule.as_ref().map_or_else(|| text.clone(), |rule| text[rule.len() + 2..].to_string());
+        let help = diagnostic.help().map(|help| help.to_string());
+        let severity = diagnostic.severity().unwrap_or(Severity::Error);
+        let label = diagnostic.labels().and_then(|mut labels| labels.next());
+        let position = |offset: usize| {
+            let source = diagnostic.source_code()?;
+            let contents = source.read_span(&SourceSpan::from((offset, 0)), 0, 0).ok()?;
+            Some((contents.line() + 1, contents.column() + 1))
+        };
+        let start = label.as_ref().and_then(|label| position(label.offset()));
+        let end = label.as_ref().and_then(|label| position(label.offset() + label.len()));
+        Self { rule, message, help, severity, start, end }
+    }
+
+    fn severity_name(&self) -> &'static str {
+        match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Advice => "info",
+        }
+    }
+}
+
+#[derive(Default)]
+struct GraphicalReporter {
+    handler: GraphicalReportHandler,
+}
+
+impl DiagnosticReporter for GraphicalReporter {
+    fn render_file(&mut self, path: &Path, diagnostics: &[Error]) -> String {
+        let mut output = String::new();
+        for diagnostic in diagnostics {
+            let mut err = String::new();
+            self.handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
+            // Skip large output and print only once
+            if err.lines().any(|line| line.len() >= 400) {
+                let minified_diagnostic = Error::new(MinifiedFileError(path.to_path_buf()));
+                return format!("{minified_diagnostic:?}");
+            }
+            output.push_str(&err);
+        }
+        output
+    }
+}
+
+/// An array of files with their messages, written once all files are linted
+#[derive(Default)]
+struct JsonReporter {
+    files: Vec<serde_json::Value>,
+}
+
+impl DiagnosticReporter for JsonReporter {
+    fn render_file(&mut self, path: &Path, diagnostics: &[Error]) -> String {
+        let infos = diagnostics.iter().map(Info::new).collect::<Vec<_>>();
+        let count = |severity| infos.iter().filter(|info| info.severity == severity).count();
+        // Keys are sorted, the order of the keys of `serde_json` depends on its features
+        let messages = infos
+            .iter()
+            .map(|info| {
+                json!({
+                    "column": info.start.map(|(_, column)| column),
+                    "endColumn": info.end.map(|(_, column)| column),
+                    "endLine": info.end.map(|(line, _)| line),
+                    "help": info.help,
+                    "line": info.start.map(|(line, _)| line),
+                    "message": info.message,
+                    "ruleId": info.rule.as_deref().map(eslint_rule_id),
+                    "severity": if info.severity == Severity::Error { 2 } else { 1 },
+                })
+            })
+            .collect::<Vec<_>>();
+        self.files.push(json!({
+            "errorCount": count(Severity::Error),
+            "filePath": path.to_string_lossy(),
+            "messages": messages,
+            "warningCount": count(Severity::Warning),
+        }));
+        String::new()
+    }
+
+    fn finish(&mut self) -> String {
+        let files = std::mem::take(&mut self.files);
+        format!("{}\n", serde_json::Value::Array(files))
+    }
+}
+
+/// The rule as ESLint names it, e.g. `no-debugger` for `eslint(no-debugger)` and `react/jsx-key`
+/// for `eslint-plugin-react(jsx-key)`
+fn eslint_rule_id(rule: &str) -> String {
+    let Some((plugin, name)) = rule.strip_suffix(')').and_then(|rule| rule.split_once('(')) else {
+        return rule.to_string();
+    };
+    match plugin {
+        "eslint" => name.to_string(),
+        "typescript-eslint" => format!("@typescript-eslint/{name}"),
+        plugin => format!("{}/{name}", plugin.strip_prefix("eslint-plugin-").unwrap_or(plugin)),
+    }
+}
+
+struct UnixReporter;
+
+impl DiagnosticReporter for UnixReporter {
+    fn render_file(&mut self, path: &Path, diagnostics: &[Error]) -> String {
+        let mut output = String::new();
+        for info in diagnostics.iter().map(Info::new) {
+            let (line, column) = info.start.unwrap_or((1, 1));
+            let severity = match info.severity {
+                Severity::Error => "Error",
+                Severity::Warning => "Warning",
+                Severity::Advice => "Info",
+            };
+            let rule = info.rule.as_ref().map(|rule| format!("/{rule}")).unwrap_or_default();
+            let _ = writeln!(
+                output,
+                "{}:{line}:{column}: {} [{severity}{rule}]",
+                path.display(),
+                info.message
+            );
+        }
+        output
+    }
+}
+
+#[derive(Default)]
+struct CheckstyleReporter {
+    files: String,
+}
+
+impl DiagnosticReporter for CheckstyleReporter {
+    fn render_file(&mut self, path: &Path, diagnostics: &[Error]) -> String {
+        let _ = write!(self.files, "<file name=\"{}\">", xml_escape(&path.to_string_lossy()));
+        for info in diagnostics.iter().map(Info::new) {
+            let (line, column) = info.start.unwrap_or((1, 1));
+            let _ = write!(
+                self.files,
+                "<error line=\"{line}\" column=\"{column}\" severity=\"{}\" message=\"{}\" source=\"{}\" />",
+                info.severity_name(),
+                xml_escape(&info.message),
+                xml_escape(info.rule.as_deref().unwrap_or_default()),
+            );
+        }
+        self.files.push_str("</file>");
+        String::new()
+    }
+
+    fn finish(&mut self) -> String {
+        format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?><checkstyle version=\"4.3\">{}</checkstyle>\n",
+            std::mem::take(&mut self.files)
+        )
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// <https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions#setting-an-error-message>
+struct GithubReporter;
+
+impl DiagnosticReporter for GithubReporter {
+    fn render_file(&mut self, path: &Path, diagnostics: &[Error]) -> String {
+        let mut output = String::new();
+        for info in diagnostics.iter().map(Info::new) {
+            let command = match info.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Advice => "notice",
+            };
+            let mut properties = format!("file={}", github_escape(&path.to_string_lossy(), true));
+            if let (Some((line, column)), Some((end_line, end_column))) = (info.start, info.end) {
+                let _ = write!(
+                    properties,
+                    ",line={line},endLine={end_line},col={column},endColumn={end_column}"
+                );
+            }
+            if let Some(rule) = &info.rule {
+                let _ = write!(properties, ",title={}", github_escape(rule, true));
+            }
+            let _ = writeln!(
+                output,
+                "::{command} {properties}::{}",
+                github_escape(&info.message, false)
+            );
+        }
+        output
+    }
+}
+
+/// Workflow commands end at a line break, and their properties at `,` and `::`.
+fn github_escape(text: &str, property: bool) -> String {
+    let escaped = text.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A");
+    if property {
+        escaped.replace(':', "%3A").replace(',', "%2C")
+    } else {
+        escaped
+    }
+}
+
+/// `{"event":"file",...}` with the number of errors and warnings of a file, followed by a
+/// `{"event":"diagnostic",...}` line for each of its diagnostics
+struct StreamReporter;
+
+impl DiagnosticReporter for StreamReporter {
+    fn render_file(&mut self, path: &Path, diagnostics: &[Error]) -> String {
+        let infos = diagnostics.iter().map(Info::new).collect::<Vec<_>>();
+        let count = |severity| infos.iter().filter(|info| info.severity == severity).count();
+        let path = path.to_string_lossy();
+        let mut output = json!({
+            "event": "file",
+            "path": path,
+            "errors": count(Severity::Error),
+            "warnings": count(Severity::Warning),
+        })
+        .to_string();
+        output.push('\n');
+        for info in &infos {
+            let diagnostic = json!({
+                "event": "diagnostic",
+                "path": path,
+                "ruleId": info.rule,
+                "severity": info.severity_name(),
+                "message": info.message,
+                "help": info.help,
+                "line": info.start.map(|(line, _)| line),
+                "column": info.start.map(|(_, column)| column),
+                "endLine": info.end.map(|(line, _)| line),
+                "endColumn": info.end.map(|(_, column)| column),
+            });
+            let _ = writeln!(output, "{diagnostic}");
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use miette::{Diagnostic, SourceSpan};
+    use thiserror::Error;
+
+    use super::OutputFormat;
+    use crate::DiagnosticService;
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("eslint(no-debugger): `debugger` statement is not allowed")]
+    #[diagnostic(severity(warning), help("Delete this code."))]
+    struct NoDebugger(#[label] SourceSpan);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("eslint-plugin-react(no-unescaped-entities): Escape \"<a>\" & 'b' in JSX, 100%")]
+    struct NoUnescapedEntities(#[label] SourceSpan);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("Unexpected token")]
+    struct UnexpectedToken;
+
+    /// The output of `format` for a file with a warning and an error, a file without
+    /// diagnostics and a file with a diagnostic without labels
+    fn render(format: OutputFormat) -> String {
+        let files = [
+            (
+                "src/a&b,c.js",
+                "debugger;\nlet x = '<a>';\n",
+                vec![NoDebugger((0, 9).into()).into(), NoUnescapedEntities((18, 5).into()).into()],
+            ),
+            ("src/clean.js", "let y = 1;\n", vec![]),
+            ("src/broken.js", "let = 1;\n", vec![UnexpectedToken.into()]),
+        ];
+        let mut reporter = format.reporter();
+        let mut output = String::new();
+        for (path, source_text, diagnostics) in files {
+            let (path, diagnostics) =
+                DiagnosticService::wrap_diagnostics(Path::new(path), source_text, diagnostics);
+            output.push_str(&reporter.render_file(&path, &diagnostics));
+        }
+        output.push_str(&reporter.finish());
+        output
+    }
+
+    #[test]
+    fn json() {
+        let output = serde_json::from_str::<serde_json::Value>(&render(OutputFormat::Json));
+        insta::assert_snapshot!("json", serde_json::to_string_pretty(&output.unwrap()).unwrap());
+    }
+
+    #[test]
+    fn unix() {
+        insta::assert_snapshot!("unix", render(OutputFormat::Unix));
+    }
+
+    #[test]
+    fn checkstyle() {
+        insta::assert_snapshot!("checkstyle", render(OutputFormat::Checkstyle));
+    }
+
+    #[test]
+    fn github() {
+        insta::assert_snapshot!("github", render(OutputFormat::Github));
+    }
+}
@@ -1,17 +1,31 @@
 use std::{
-    cell::Cell,
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
     io::{BufWriter, Write},
     path::{Path, PathBuf},
     sync::mpsc,
     sync::Arc,
 };
 
-use crate::{miette::NamedSource, Error, GraphicalReportHandler, MinifiedFileError, Severity};
+use crate::{
+    miette::NamedSource,
+    reporter::{rule_of, OutputFormat},
+    Error, Severity,
+};
 
 pub type DiagnosticTuple = (PathBuf, Vec<Error>);
 pub type DiagnosticSender = mpsc::Sender<Option<DiagnosticTuple>>;
 pub type DiagnosticReceiver = mpsc::Receiver<Option<DiagnosticTuple>>;
 
+/// `(warnings, errors)` per rule and per file
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DiagnosticCounts {
+    /// By the plugin and rule of the message, e.g. `eslint(no-debugger)`, or `<other>` for
+    /// diagnostics of the parser and the semantic analysis
+    pub rules: BTreeMap<String, (usize, usize)>,
+    pub files: BTreeMap<PathBuf, (usize, usize)>,
+}
+
 pub struct DiagnosticService {
     /// Disable reporting on warnings, only errors are reported
     quiet: bool,
@@ -26,6 +40,20 @@ pub struct DiagnosticService {
     /// Total number of errors received
     errors_count: Cell<usize>,
 
+    /// Print the number of warnings and errors per rule and per file instead of the diagnostics
+    summary_only: bool,
+
+    /// Print the diagnostics once all are received, ordered by the paths of their files
+    sort_paths: bool,
+
+    /// Count the diagnostics per rule and per file, see [DiagnosticService::counts]
+    count: bool,
+
+    format: OutputFormat,
+
+    /// Counts per rule and per file with `summary_only` or `count`
+    counts: RefCell<DiagnosticCounts>,
+
     sender: DiagnosticSender,
     receiver: DiagnosticReceiver,
 }
@@ -38,6 +66,11 @@ impl Default for DiagnosticService {
             max_warnings: None,
             warnings_count: Cell::new(0),
             errors_count: Cell::new(0),
+            summary_only: false,
+            sort_paths: false,
+            count: false,
+            format: OutputFormat::default(),
+            counts: RefCell::default(),
             sender,
             receiver,
         }
@@ -57,6 +90,30 @@ impl DiagnosticService {
         self
     }
 
+    #[must_use]
+    pub fn with_summary_only(mut self, yes: bool) -> Self {
+        self.summary_only = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_sort_paths(mut self, yes: bool) -> Self {
+        self.sort_paths = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_count(mut self, yes: bool) -> Self {
+        self.count = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+
     pub fn sender(&self) -> &DiagnosticSender {
         &self.sender
     }
@@ -69,6 +126,12 @@ impl DiagnosticService {
         self.errors_count.get()
     }
 
+    /// Warnings and errors reported per rule and per file, empty without `count` or
+    /// `summary_only`
+    pub fn counts(&self) -> DiagnosticCounts {
+        self.counts.borrow().clone()
+    }
+
     pub fn max_warnings_exceeded(&self) -> bool {
         self.max_warnings.map_or(false, |max_warnings| self.warnings_count.get() > max_warnings)
     }
@@ -91,10 +154,19 @@ impl DiagnosticService {
     /// * When the writer fails to write
     pub fn run(&self) {
         let mut buf_writer = BufWriter::new(std::io::stdout());
-        let handler = GraphicalReportHandler::new();
+        let mut reporter = self.format.reporter();
+
+        let received = std::iter::from_fn(|| self.receiver.recv().ok().flatten());
+        let received: Box<dyn Iterator<Item = DiagnosticTuple>> = if self.sort_paths {
+            let mut received = received.collect::<Vec<_>>();
+            received.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Box::new(received.into_iter())
+        } else {
+            Box::new(received)
+        };
 
-        while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
-            let mut output = String::new();
+        for (path, diagnostics) in received {
+            let mut file_diagnostics = vec![];
             for diagnostic in diagnostics {
                 let severity = diagnostic.severity();
                 let is_warning = severity == Some(Severity::Warning);
@@ -114,6 +186,13 @@ impl DiagnosticService {
                         continue;
                     }
 
+                    if self.summary_only || self.count {
+                        self.add_to_counts(&path, &diagnostic, is_warning);
+                    }
+                    if self.summary_only {
+                        continue;
+                    }
+
                     if let Some(max_warnings) = self.max_warnings {
                         if self.warnings_count() > max_warnings {
                             continue;
@@ -121,20 +200,61 @@ impl DiagnosticService {
                     }
                 }
 
-                let mut err = String::new();
-                handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
-                // Skip large output and print only once
-                if err.lines().any(|line| line.len() >= 400) {
-                    let minified_diagnostic = Error::new(MinifiedFileError(path.clone()));
-                    err = format!("{minified_diagnostic:?}");
-                    output = err;
-                    break;
+                if self.summary_only {
+                    continue;
                 }
-                output.push_str(&err);
+
+                file_diagnostics.push(diagnostic);
             }
+            // Files without diagnostics are rendered too, the JSON and Checkstyle formats list
+            // every linted file
+            let output = reporter.render_file(&path, &file_diagnostics);
             buf_writer.write_all(output.as_bytes()).unwrap();
+            if self.format == OutputFormat::Stream {
+                buf_writer.flush().unwrap();
+            }
         }
 
+        if self.summary_only {
+            self.write_summary(&mut buf_writer).unwrap();
+        } else {
+            buf_writer.write_all(reporter.finish().as_bytes()).unwrap();
+        }
         buf_writer.flush().unwrap();
     }
+
+    fn add_to_counts(&self, path: &Path, diagnostic: &Error, is_warning: bool) {
+        let message = diagnostic.to_string();
+        let rule = rule_of(&message).unwrap_or("<other>");
+        let mut counts = self.counts.borrow_mut();
+        let DiagnosticCounts { rules, files } = &mut *counts;
+        for counts in [
+            rules.entry(rule.to_string()).or_default(),
+            files.entry(path.to_path_buf()).or_default(),
+        ] {
+            if is_warning {
+                counts.0 += 1;
+            } else {
+                counts.1 += 1;
+            }
+        }
+    }
+
+    fn write_summary<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let counts = self.counts.borrow();
+        let DiagnosticCounts { rules, files } = &*counts;
+        let rules = rules.iter().map(|(rule, counts)| (rule.clone(), *counts));
+        let files =
+            files.iter().map(|(path, counts)| (path.to_string_lossy().to_string(), *counts));
+        for (title, rows) in [("Rule", rules.collect::<Vec<_>>()), ("File", files.collect())] {
+            let width =
+                rows.iter().map(|(name, _)| name.len()).chain([title.len()]).max().unwrap_or(0);
+            writeln!(writer, "{title:<width$}  Warnings  Errors")?;
+            for (name, (warnings, errors)) in rows {
+                writeln!(writer, "{name:<width$}  {warnings:>8}  {errors:>6}")?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,126 @@
+//! ESQuery
+//!
+//! [esquery](https://github.com/estools/esquery) compatible selectors for the oxc AST,
+//! as used by ESLint's `no-restricted-syntax` and rule listeners.
+//!
+//! ```ignore
+//! let program = serde_json::to_value(&program)?;
+//! let selector = parse_selector("CallExpression[callee.name='require']")?;
+//! for node in Query::new(&program).select(&selector) {
+//!     println!("{:?}", node.span());
+//! }
+//! ```
+//!
+//! Code patterns with metavariables, e.g. `console.log($MSG)`, are matched with [Query::find].
+
+mod pattern;
+mod query;
+mod selector;
+
+pub use crate::{
+    pattern::{Bindings, InvalidPattern, Pattern},
+    query::{Node, Query},
+    selector::{
+        parse_selector, AttributeOperator, AttributeValue, InvalidSelector, NodeClass, Selector,
+    },
+};
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::{GetSpan, SourceType};
+
+    use crate::{parse_selector, Pattern, Query};
+
+    fn select(source_text: &str, selector: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        assert!(ret.errors.is_empty());
+        let program = serde_json::to_value(&ret.program).unwrap();
+        let selector = parse_selector(selector).unwrap();
+        Query::new(&program)
+            .select(&selector)
+            .map(|node| node.span().source_text(source_text).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn attribute() {
+        let source = "const a = require('a'); const b = load('b'); require.resolve('c');";
+        assert_eq!(select(source, "CallExpression[callee.name='require']"), ["require('a')"]);
+        assert_eq!(select(source, "CallExpression[callee.name!='require']").len(), 2);
+        assert_eq!(select(source, "CallExpression[callee.name=/^re/]"), ["require('a')"]);
+        assert_eq!(select(source, "CallExpression[callee.property]"), ["require.resolve('c')"]);
+        assert_eq!(select(source, "[value=type(string)]"), ["'a'", "'b'", "'c'"]);
+    }
+
+    #[test]
+    fn estree_names() {
+        let source = "foo.bar; const f = () => 1;";
+        assert_eq!(select(source, "MemberExpression > Identifier"), ["foo", "bar"]);
+        assert_eq!(select(source, "ArrowFunctionExpression Literal"), ["1"]);
+        assert_eq!(select(source, ":function"), ["() => 1"]);
+    }
+
+    #[test]
+    fn combinators() {
+        let source = "function f() { a; b; c; }";
+        assert_eq!(select(source, "FunctionBody > ExpressionStatement"), ["a;", "b;", "c;"]);
+        assert_eq!(select(source, "ExpressionStatement + ExpressionStatement"), ["b;", "c;"]);
+        assert_eq!(select(source, "ExpressionStatement:first-child ~ *"), ["b;", "c;"]);
+        assert_eq!(select(source, "ExpressionStatement:nth-last-child(2)"), ["b;"]);
+        assert_eq!(select(source, "FunctionDeclaration IdentifierReference[name='c']"), ["c"]);
+        assert_eq!(select(source, "ExpressionStatement:not(:first-child, :last-child)"), ["b;"]);
+    }
+
+    #[test]
+    fn has_and_field() {
+        let source = "if (x) { y(); } if (z) {}";
+        assert_eq!(select(source, "IfStatement:has(CallExpression)"), ["if (x) { y(); }"]);
+        assert_eq!(select(source, ".test"), ["x", "z"]);
+        assert_eq!(select(source, "IfStatement > .consequent:matches(BlockStatement)").len(), 2);
+    }
+
+    fn find(source_text: &str, pattern: &str) -> Vec<(String, Vec<(String, String)>)> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        assert!(ret.errors.is_empty());
+        let program = serde_json::to_value(&ret.program).unwrap();
+        let pattern = Pattern::parse(pattern).unwrap();
+        Query::new(&program)
+            .find(&pattern)
+            .map(|(node, bindings)| {
+                let bindings = bindings
+                    .into_iter()
+                    .map(|(name, span)| (name, span.source_text(source_text).to_string()))
+                    .collect();
+                (node.span().source_text(source_text).to_string(), bindings)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pattern() {
+        let source = "console.log( 'a' ); console.log(a, b); console.warn(x); log(1)";
+        let found = find(source, "console.log($MSG)");
+        assert_eq!(found, [("console.log( 'a' )".into(), vec![("$MSG".into(), "'a'".into())])]);
+        assert_eq!(find(source, "console.$M($_)").len(), 2);
+        assert_eq!(find(source, "console.log($_, $_)").len(), 1);
+        assert_eq!(find(source, "$.log(1)").len(), 0);
+    }
+
+    #[test]
+    fn repeated_metavariable() {
+        let source = "a === a; a === b; f(x.y) === f(x.y);";
+        let found = find(source, "$A === $A").into_iter().map(|(text, _)| text).collect::<Vec<_>>();
+        assert_eq!(found, ["a === a", "f(x.y) === f(x.y)"]);
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        assert!(Pattern::parse("a; b").is_err());
+        assert!(Pattern::parse("a +").is_err());
+        assert!(Pattern::parse("if ($A) {}").is_ok());
+    }
+}
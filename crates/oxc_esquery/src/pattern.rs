@@ -0,0 +1,142 @@
+//! Code patterns
+//!
+//! A pattern is a snippet of code, e.g. `console.log($MSG)`, matching the nodes with the same
+//! structure whatever their formatting and comments. Identifiers made of `$` and upper case
+//! letters, digits or underscores are metavariables matching any node, `$_` without binding it.
+//! A metavariable used twice, as in `$A === $A`, matches the same code twice.
+
+use std::collections::BTreeMap;
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid pattern: {0}")]
+#[diagnostic()]
+pub struct InvalidPattern(pub String);
+
+/// Spans of the nodes bound to the metavariables of a [Pattern], by their name, e.g. `$MSG`
+pub type Bindings = BTreeMap<String, Span>;
+
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    /// The serialized expression or statement of the pattern
+    node: Map<String, Value>,
+}
+
+impl Pattern {
+    /// Parse a pattern made of a single expression or statement, with TypeScript and JSX syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns [InvalidPattern] for code which does not parse or is not a single expression or
+    /// statement.
+    pub fn parse(source: &str) -> Result<Self, InvalidPattern> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_typescript(true).with_jsx(true);
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        if let Some(error) = ret.errors.first() {
+            return Err(InvalidPattern(error.to_string()));
+        }
+        let program = serde_json::to_value(&ret.program)
+            .map_err(|error| InvalidPattern(error.to_string()))?;
+        let statement = match program.get("body").and_then(Value::as_array).map(Vec::as_slice) {
+            Some([Value::Object(statement)]) => statement,
+            _ => {
+                return Err(InvalidPattern("expected a single expression or statement".to_string()))
+            }
+        };
+        let node = match statement.get("expression") {
+            Some(Value::Object(expression))
+                if type_name(statement) == Some("ExpressionStatement") =>
+            {
+                expression
+            }
+            _ => statement,
+        };
+        Ok(Self { node: node.clone() })
+    }
+
+    /// The bindings of the metavariables when the serialized `node` matches the pattern.
+    pub fn matches(&self, node: &Map<String, Value>) -> Option<Bindings> {
+        let mut bound = BTreeMap::new();
+        match_object(&self.node, node, &mut bound)
+            .then(|| bound.into_iter().map(|(name, node)| (name, span(node))).collect())
+    }
+}
+
+fn type_name(map: &Map<String, Value>) -> Option<&str> {
+    map.get("type").and_then(Value::as_str)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn span(map: &Map<String, Value>) -> Span {
+    let offset = |key| map.get(key).and_then(Value::as_u64).unwrap_or_default();
+    Span::new(offset("start") as u32, offset("end") as u32)
+}
+
+/// `$MSG` of an identifier node, `$` alone is jQuery rather than a metavariable.
+fn metavariable(map: &Map<String, Value>) -> Option<&str> {
+    if !matches!(
+        type_name(map)?,
+        "IdentifierReference" | "BindingIdentifier" | "IdentifierName" | "LabelIdentifier"
+    ) {
+        return None;
+    }
+    let name = map.get("name")?.as_str()?;
+    let rest = name.strip_prefix('$')?;
+    (!rest.is_empty()
+        && rest.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_'))
+    .then_some(name)
+}
+
+/// Offsets differ between the pattern and the code
+fn is_span_key(key: &str) -> bool {
+    matches!(key, "start" | "end")
+}
+
+fn match_object<'v>(
+    pattern: &Map<String, Value>,
+    map: &'v Map<String, Value>,
+    bound: &mut BTreeMap<String, &'v Map<String, Value>>,
+) -> bool {
+    if let Some(name) = metavariable(pattern) {
+        if name == "$_" {
+            return true;
+        }
+        if let Some(previous) = bound.get(name) {
+            return match_object(previous, map, &mut BTreeMap::new());
+        }
+        bound.insert(name.to_string(), map);
+        return true;
+    }
+    let keys = |map: &Map<String, Value>| map.keys().filter(|key| !is_span_key(key)).count();
+    keys(pattern) == keys(map)
+        && pattern.iter().filter(|(key, _)| !is_span_key(key)).all(|(key, pattern)| {
+            map.get(key).is_some_and(|value| match_value(pattern, value, bound))
+        })
+}
+
+fn match_value<'v>(
+    pattern: &Value,
+    value: &'v Value,
+    bound: &mut BTreeMap<String, &'v Map<String, Value>>,
+) -> bool {
+    match (pattern, value) {
+        (Value::Object(pattern), Value::Object(map)) => match_object(pattern, map, bound),
+        (Value::Array(patterns), Value::Array(values)) => {
+            patterns.len() == values.len()
+                && patterns
+                    .iter()
+                    .zip(values)
+                    .all(|(pattern, value)| match_value(pattern, value, bound))
+        }
+        _ => pattern == value,
+    }
+}
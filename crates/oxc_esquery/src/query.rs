@@ -0,0 +1,347 @@
+use oxc_span::{GetSpan, Span};
+use serde_json::{Map, Value};
+
+use crate::{
+    pattern::{Bindings, Pattern},
+    selector::{AttributeOperator, AttributeValue, NodeClass, Selector},
+};
+
+/// An ESTree-shaped node in a [Query]
+#[derive(Debug, Clone, Copy)]
+pub struct Node<'q, 'v> {
+    query: &'q Query<'v>,
+    id: usize,
+}
+
+impl<'q, 'v> Node<'q, 'v> {
+    pub fn value(&self) -> &'v Map<String, Value> {
+        self.query.entries[self.id].value
+    }
+
+    pub fn type_name(&self) -> &'v str {
+        type_name(self.value()).unwrap_or_default()
+    }
+
+    pub fn parent(&self) -> Option<Self> {
+        self.query.entries[self.id].parent.map(|id| Self { query: self.query, id })
+    }
+}
+
+impl<'q, 'v> GetSpan for Node<'q, 'v> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn span(&self) -> Span {
+        let offset = |key| self.value().get(key).and_then(Value::as_u64).unwrap_or_default();
+        Span::new(offset("start") as u32, offset("end") as u32)
+    }
+}
+
+#[derive(Debug)]
+struct Entry<'v> {
+    value: &'v Map<String, Value>,
+    parent: Option<usize>,
+    /// Keys leading from the parent node to this node, e.g. `["callee"]`
+    field: Vec<&'v str>,
+    /// Index in and length of the array containing this node
+    list: Option<(usize, usize)>,
+}
+
+/// Runs selectors over a serialized AST, i.e. the output of `Program::to_json` or
+/// `serde_json::to_value(&program)`.
+///
+/// Node type names are the ones serialized by oxc, `Identifier`, `Literal`, `MemberExpression` and
+/// `ArrowFunctionExpression` match their oxc counterparts for compatibility with ESLint selectors.
+#[derive(Debug)]
+pub struct Query<'v> {
+    /// All nodes, parents before their children
+    entries: Vec<Entry<'v>>,
+}
+
+impl<'v> Query<'v> {
+    pub fn new(root: &'v Value) -> Self {
+        let mut query = Self { entries: vec![] };
+        if let Value::Object(map) = root {
+            if type_name(map).is_some() {
+                query.entries.push(Entry { value: map, parent: None, field: vec![], list: None });
+                query.collect_children(0, map, &[]);
+            }
+        }
+        query
+    }
+
+    fn collect_children(&mut self, parent: usize, map: &'v Map<String, Value>, path: &[&'v str]) {
+        for (key, value) in map {
+            if key == "type" {
+                continue;
+            }
+            let mut field = path.to_vec();
+            field.push(key.as_str());
+            match value {
+                Value::Object(child) => self.add_child(parent, child, field, None),
+                Value::Array(items) => {
+                    for (index, item) in items.iter().enumerate() {
+                        if let Value::Object(child) = item {
+                            let list = Some((index, items.len()));
+                            self.add_child(parent, child, field.clone(), list);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn add_child(
+        &mut self,
+        parent: usize,
+        map: &'v Map<String, Value>,
+        field: Vec<&'v str>,
+        list: Option<(usize, usize)>,
+    ) {
+        if type_name(map).is_none() {
+            // Not a node, e.g. a template element value, look through it for nodes
+            self.collect_children(parent, map, &field);
+            return;
+        }
+        let id = self.entries.len();
+        self.entries.push(Entry { value: map, parent: Some(parent), field, list });
+        self.collect_children(id, map, &[]);
+    }
+
+    pub fn root(&self) -> Option<Node<'_, 'v>> {
+        (!self.entries.is_empty()).then_some(Node { query: self, id: 0 })
+    }
+
+    /// All nodes matching `selector`, in source order.
+    pub fn select<'q>(&'q self, selector: &Selector) -> impl Iterator<Item = Node<'q, 'v>> {
+        let mut nodes = (0..self.entries.len())
+            .filter(|&id| self.matches(id, selector))
+            .map(|id| Node { query: self, id })
+            .collect::<Vec<_>>();
+        // Object keys are not necessarily serialized in source order
+        nodes.sort_by_key(|node| node.span().start);
+        nodes.into_iter()
+    }
+
+    /// All nodes matching `pattern` with the bindings of its metavariables, in source order.
+    pub fn find<'q>(&'q self, pattern: &Pattern) -> impl Iterator<Item = (Node<'q, 'v>, Bindings)> {
+        let mut matches = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(id, entry)| {
+                pattern.matches(entry.value).map(|bindings| (Node { query: self, id }, bindings))
+            })
+            .collect::<Vec<_>>();
+        matches.sort_by_key(|(node, _)| node.span().start);
+        matches.into_iter()
+    }
+
+    pub fn matches_node(&self, node: Node<'_, 'v>, selector: &Selector) -> bool {
+        self.matches(node.id, selector)
+    }
+
+    fn matches(&self, id: usize, selector: &Selector) -> bool {
+        let entry = &self.entries[id];
+        match selector {
+            Selector::Wildcard => true,
+            Selector::Type(name) => type_name(entry.value).is_some_and(|ty| type_matches(ty, name)),
+            Selector::Attribute { path, test } => {
+                let value = get_path(entry.value, path);
+                match test {
+                    None => value.is_some_and(|value| !value.is_null()),
+                    Some((operator, expected)) => compare(value, *operator, expected),
+                }
+            }
+            Selector::Field(path) => self.field_matches(id, path),
+            Selector::NthChild(nth) => entry.list.is_some_and(|(index, _)| index + 1 == *nth),
+            Selector::NthLastChild(nth) => {
+                entry.list.is_some_and(|(index, len)| len - index == *nth)
+            }
+            Selector::Class(class) => self.class_matches(id, *class),
+            Selector::Matches(selectors) => selectors.iter().any(|s| self.matches(id, s)),
+            Selector::Not(selectors) => !selectors.iter().any(|s| self.matches(id, s)),
+            Selector::Has(selectors) => self
+                .descendants(id)
+                .any(|descendant| selectors.iter().any(|s| self.matches(descendant, s))),
+            Selector::Compound(selectors) => selectors.iter().all(|s| self.matches(id, s)),
+            Selector::Descendant(ancestor, selector) => {
+                self.matches(id, selector)
+                    && self.ancestors(id).any(|ancestor_id| self.matches(ancestor_id, ancestor))
+            }
+            Selector::Child(parent, selector) => {
+                self.matches(id, selector)
+                    && entry.parent.is_some_and(|parent_id| self.matches(parent_id, parent))
+            }
+            Selector::Sibling(sibling, selector) => {
+                self.matches(id, selector)
+                    && self
+                        .preceding_siblings(id)
+                        .any(|sibling_id| self.matches(sibling_id, sibling))
+            }
+            Selector::Adjacent(sibling, selector) => {
+                self.matches(id, selector)
+                    && self
+                        .preceding_siblings(id)
+                        .next()
+                        .is_some_and(|sibling_id| self.matches(sibling_id, sibling))
+            }
+        }
+    }
+
+    fn ancestors(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(self.entries[id].parent, |&id| self.entries[id].parent)
+    }
+
+    /// Children are pushed right after their parent, so descendants are a contiguous range.
+    fn descendants(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        (id + 1..self.entries.len())
+            .take_while(move |&descendant| self.ancestors(descendant).any(|a| a == id))
+    }
+
+    /// Nodes before `id` in the same array, closest first.
+    fn preceding_siblings(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        let entry = &self.entries[id];
+        (0..id).rev().filter(move |&sibling| {
+            let other = &self.entries[sibling];
+            entry.list.is_some()
+                && other.list.is_some()
+                && other.parent == entry.parent
+                && other.field == entry.field
+        })
+    }
+
+    /// `.callee.object` matches a node which is the `object` of the `callee` of its grandparent.
+    fn field_matches(&self, id: usize, path: &[String]) -> bool {
+        let mut keys: Vec<&str> = vec![];
+        let mut current = Some(id);
+        while keys.len() < path.len() {
+            let Some(id) = current else { return false };
+            let entry = &self.entries[id];
+            if entry.field.is_empty() {
+                return false;
+            }
+            keys.extend(entry.field.iter().rev().copied());
+            current = entry.parent;
+        }
+        keys.iter().zip(path.iter().rev()).all(|(key, expected)| *key == expected.as_str())
+    }
+
+    fn class_matches(&self, id: usize, class: NodeClass) -> bool {
+        let Some(ty) = type_name(self.entries[id].value) else { return false };
+        match class {
+            NodeClass::Statement => ty.ends_with("Statement") || ty.ends_with("Declaration"),
+            NodeClass::Declaration => ty.ends_with("Declaration"),
+            NodeClass::Expression => {
+                ty.ends_with("Expression")
+                    || ty.ends_with("Literal")
+                    || ty == "IdentifierReference"
+                    || ty == "ThisExpression"
+            }
+            NodeClass::Function => {
+                matches!(ty, "FunctionDeclaration" | "FunctionExpression" | "ArrowExpression")
+            }
+            NodeClass::Pattern => {
+                ty.ends_with("Pattern")
+                    || ty == "BindingIdentifier"
+                    || self.class_matches(id, NodeClass::Expression)
+            }
+        }
+    }
+}
+
+fn type_name(map: &Map<String, Value>) -> Option<&str> {
+    map.get("type").and_then(Value::as_str)
+}
+
+/// ESTree names which are split into several nodes in oxc
+fn type_matches(ty: &str, name: &str) -> bool {
+    ty == name
+        || match name {
+            "Identifier" => matches!(
+                ty,
+                "IdentifierReference" | "BindingIdentifier" | "IdentifierName" | "LabelIdentifier"
+            ),
+            "Literal" => matches!(
+                ty,
+                "BooleanLiteral"
+                    | "NullLiteral"
+                    | "NumberLiteral"
+                    | "BigintLiteral"
+                    | "RegExpLiteral"
+                    | "StringLiteral"
+            ),
+            "MemberExpression" => matches!(
+                ty,
+                "StaticMemberExpression" | "ComputedMemberExpression" | "PrivateFieldExpression"
+            ),
+            "ArrowFunctionExpression" => ty == "ArrowExpression",
+            _ => false,
+        }
+}
+
+fn get_path<'v>(map: &'v Map<String, Value>, path: &[String]) -> Option<&'v Value> {
+    let (first, rest) = path.split_first()?;
+    rest.iter().try_fold(map.get(first)?, |value, key| value.get(key))
+}
+
+/// `String(value)` in JavaScript
+fn to_js_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.as_f64().map_or_else(|| n.to_string(), |n| n.to_string()),
+        value => value.to_string(),
+    }
+}
+
+/// `typeof value` in JavaScript
+fn js_type_of(value: Option<&Value>) -> &'static str {
+    match value {
+        None => "undefined",
+        Some(Value::Bool(_)) => "boolean",
+        Some(Value::Number(_)) => "number",
+        Some(Value::String(_)) => "string",
+        Some(Value::Null | Value::Array(_) | Value::Object(_)) => "object",
+    }
+}
+
+fn compare(value: Option<&Value>, operator: AttributeOperator, expected: &AttributeValue) -> bool {
+    let result = match (operator, expected) {
+        (AttributeOperator::Equal | AttributeOperator::NotEqual, AttributeValue::Type(ty)) => {
+            js_type_of(value) == ty
+        }
+        (AttributeOperator::Equal | AttributeOperator::NotEqual, AttributeValue::Regex(regex)) => {
+            value.and_then(Value::as_str).is_some_and(|s| regex.is_match(s))
+        }
+        (AttributeOperator::Equal | AttributeOperator::NotEqual, expected) => {
+            let expected = match expected {
+                AttributeValue::String(s) | AttributeValue::Literal(s) => s.clone(),
+                AttributeValue::Number(n) => n.to_string(),
+                AttributeValue::Regex(_) | AttributeValue::Type(_) => unreachable!(),
+            };
+            value.is_some_and(|value| to_js_string(value) == expected)
+        }
+        (operator, expected) => {
+            let Some(value) = value.and_then(Value::as_f64) else { return false };
+            let expected = match expected {
+                AttributeValue::Number(n) => *n,
+                AttributeValue::Literal(s) | AttributeValue::String(s) => {
+                    let Ok(n) = s.parse() else { return false };
+                    n
+                }
+                AttributeValue::Regex(_) | AttributeValue::Type(_) => return false,
+            };
+            match operator {
+                AttributeOperator::LessThan => value < expected,
+                AttributeOperator::LessThanEqual => value <= expected,
+                AttributeOperator::GreaterThan => value > expected,
+                AttributeOperator::GreaterThanEqual => value >= expected,
+                AttributeOperator::Equal | AttributeOperator::NotEqual => unreachable!(),
+            }
+        }
+    };
+    if operator == AttributeOperator::NotEqual {
+        !result
+    } else {
+        result
+    }
+}
@@ -0,0 +1,480 @@
+//! Selector syntax
+//!
+//! References:
+//! * <https://github.com/estools/esquery/blob/master/grammar.pegjs>
+
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_span::Span;
+use regex::Regex;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid selector: {0}")]
+#[diagnostic()]
+pub struct InvalidSelector(pub String, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub enum Selector {
+    /// `*`
+    Wildcard,
+    /// `CallExpression`
+    Type(String),
+    /// `[callee.name]`, `[callee.name='require']`
+    Attribute { path: Vec<String>, test: Option<(AttributeOperator, AttributeValue)> },
+    /// `.callee.object`
+    Field(Vec<String>),
+    /// `:first-child`, `:nth-child(2)`
+    NthChild(usize),
+    /// `:last-child`, `:nth-last-child(2)`
+    NthLastChild(usize),
+    /// `:statement`, `:expression`, `:declaration`, `:function`, `:pattern`
+    Class(NodeClass),
+    /// `:matches(A, B)`, `:is(A, B)`
+    Matches(Vec<Selector>),
+    /// `:not(A, B)`
+    Not(Vec<Selector>),
+    /// `:has(A, B)`
+    Has(Vec<Selector>),
+    /// `A[b]:first-child`, all parts match the same node
+    Compound(Vec<Selector>),
+    /// `A B`
+    Descendant(Box<Selector>, Box<Selector>),
+    /// `A > B`
+    Child(Box<Selector>, Box<Selector>),
+    /// `A ~ B`
+    Sibling(Box<Selector>, Box<Selector>),
+    /// `A + B`
+    Adjacent(Box<Selector>, Box<Selector>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeOperator {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanEqual,
+    GreaterThan,
+    GreaterThanEqual,
+}
+
+#[derive(Debug, Clone)]
+pub enum AttributeValue {
+    /// `'require'`, `"require"`
+    String(String),
+    /// `2`, `1.5`
+    Number(f64),
+    /// `/^foo/i`
+    Regex(Regex),
+    /// `type(string)`
+    Type(String),
+    /// Unquoted names and keywords, e.g. `true`, `null` or `foo`
+    Literal(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeClass {
+    Statement,
+    Expression,
+    Declaration,
+    Function,
+    Pattern,
+}
+
+/// Parse an [esquery](https://github.com/estools/esquery) selector,
+/// e.g. `CallExpression[callee.name='require']`.
+///
+/// # Errors
+///
+/// Returns [InvalidSelector] when the selector is malformed.
+pub fn parse_selector(source: &str) -> Result<Selector, InvalidSelector> {
+    SelectorParser { source, pos: 0 }.parse()
+}
+
+struct SelectorParser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> SelectorParser<'a> {
+    fn parse(mut self) -> Result<Selector, InvalidSelector> {
+        let selector = self.parse_selectors()?;
+        self.skip_whitespace();
+        if self.pos < self.source.len() {
+            return Err(self.unexpected());
+        }
+        Ok(selector)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, ch: char) -> bool {
+        if self.peek() == Some(ch) {
+            self.pos += ch.len_utf8();
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, ch: char) -> Result<(), InvalidSelector> {
+        if self.eat(ch) {
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while let Some(ch) = self.peek().filter(|ch| ch.is_whitespace()) {
+            self.pos += ch.len_utf8();
+        }
+        self.pos > start
+    }
+
+    fn unexpected(&self) -> InvalidSelector {
+        #[allow(clippy::cast_possible_truncation)]
+        let span = Span::new(self.pos as u32, self.pos as u32);
+        match self.peek() {
+            Some(ch) => InvalidSelector(format!("unexpected character `{ch}`"), span),
+            None => InvalidSelector("unexpected end of selector".to_string(), span),
+        }
+    }
+
+    /// `A, B`
+    fn parse_selectors(&mut self) -> Result<Selector, InvalidSelector> {
+        let mut selectors = vec![self.parse_selector()?];
+        loop {
+            self.skip_whitespace();
+            if !self.eat(',') {
+                break;
+            }
+            selectors.push(self.parse_selector()?);
+        }
+        Ok(if selectors.len() == 1 { selectors.remove(0) } else { Selector::Matches(selectors) })
+    }
+
+    /// `A > B ~ C`
+    fn parse_selector(&mut self) -> Result<Selector, InvalidSelector> {
+        self.skip_whitespace();
+        let mut left = self.parse_sequence()?;
+        loop {
+            let start = self.pos;
+            let has_whitespace = self.skip_whitespace();
+            let combinator: fn(Box<Selector>, Box<Selector>) -> Selector = match self.peek() {
+                Some('>') => Selector::Child,
+                Some('~') => Selector::Sibling,
+                Some('+') => Selector::Adjacent,
+                Some(ch) if has_whitespace && ch != ',' && ch != ')' => Selector::Descendant,
+                _ => {
+                    self.pos = start;
+                    return Ok(left);
+                }
+            };
+            if matches!(self.peek(), Some('>' | '~' | '+')) {
+                self.pos += 1;
+                self.skip_whitespace();
+            }
+            let right = self.parse_sequence()?;
+            left = combinator(Box::new(left), Box::new(right));
+        }
+    }
+
+    /// `A[b]:c`
+    fn parse_sequence(&mut self) -> Result<Selector, InvalidSelector> {
+        let mut atoms = vec![];
+        while let Some(atom) = self.parse_atom()? {
+            atoms.push(atom);
+        }
+        match atoms.len() {
+            0 => Err(self.unexpected()),
+            1 => Ok(atoms.remove(0)),
+            _ => Ok(Selector::Compound(atoms)),
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<Option<Selector>, InvalidSelector> {
+        let atom = match self.peek() {
+            Some('*') => {
+                self.pos += 1;
+                Selector::Wildcard
+            }
+            Some('[') => {
+                self.pos += 1;
+                self.parse_attribute()?
+            }
+            Some('.') => {
+                self.pos += 1;
+                Selector::Field(self.parse_path()?)
+            }
+            Some(':') => {
+                self.pos += 1;
+                self.parse_pseudo()?
+            }
+            Some(ch) if is_identifier_char(ch) => Selector::Type(self.parse_identifier()?),
+            _ => return Ok(None),
+        };
+        Ok(Some(atom))
+    }
+
+    fn parse_identifier(&mut self) -> Result<String, InvalidSelector> {
+        let start = self.pos;
+        while self.peek().is_some_and(is_identifier_char) {
+            self.pos += self.peek().map_or(1, char::len_utf8);
+        }
+        if start == self.pos {
+            return Err(self.unexpected());
+        }
+        Ok(self.source[start..self.pos].to_string())
+    }
+
+    /// `callee.object.name`
+    fn parse_path(&mut self) -> Result<Vec<String>, InvalidSelector> {
+        let mut path = vec![self.parse_identifier()?];
+        while self.eat('.') {
+            path.push(self.parse_identifier()?);
+        }
+        Ok(path)
+    }
+
+    /// `[callee.name='require']`, the opening `[` has been consumed
+    fn parse_attribute(&mut self) -> Result<Selector, InvalidSelector> {
+        self.skip_whitespace();
+        let path = self.parse_path()?;
+        self.skip_whitespace();
+        let operator = if self.eat('=') {
+            Some(AttributeOperator::Equal)
+        } else if self.eat('!') {
+            self.expect('=')?;
+            Some(AttributeOperator::NotEqual)
+        } else if self.eat('<') {
+            Some(if self.eat('=') {
+                AttributeOperator::LessThanEqual
+            } else {
+                AttributeOperator::LessThan
+            })
+        } else if self.eat('>') {
+            Some(if self.eat('=') {
+                AttributeOperator::GreaterThanEqual
+            } else {
+                AttributeOperator::GreaterThan
+            })
+        } else {
+            None
+        };
+        let test = match operator {
+            Some(operator) => {
+                self.skip_whitespace();
+                Some((operator, self.parse_attribute_value(operator)?))
+            }
+            None => None,
+        };
+        self.skip_whitespace();
+        self.expect(']')?;
+        Ok(Selector::Attribute { path, test })
+    }
+
+    fn parse_attribute_value(
+        &mut self,
+        operator: AttributeOperator,
+    ) -> Result<AttributeValue, InvalidSelector> {
+        let equality = matches!(operator, AttributeOperator::Equal | AttributeOperator::NotEqual);
+        match self.peek() {
+            Some(quote @ ('\'' | '"')) if equality => {
+                self.pos += 1;
+                self.parse_string(quote).map(AttributeValue::String)
+            }
+            Some('/') if equality => {
+                self.pos += 1;
+                self.parse_regex().map(AttributeValue::Regex)
+            }
+            Some(ch) if ch.is_ascii_digit() || ch == '.' || ch == '-' => {
+                let start = self.pos;
+                self.pos += 1;
+                while self.peek().is_some_and(|ch| ch.is_ascii_digit() || ch == '.') {
+                    self.pos += 1;
+                }
+                #[allow(clippy::cast_possible_truncation)]
+                let span = Span::new(start as u32, self.pos as u32);
+                self.source[start..self.pos]
+                    .parse()
+                    .map(AttributeValue::Number)
+                    .map_err(|_| InvalidSelector("invalid number".to_string(), span))
+            }
+            _ => {
+                let name = self.parse_path()?.join(".");
+                if equality && name == "type" && self.eat('(') {
+                    self.skip_whitespace();
+                    let ty = self.parse_identifier()?;
+                    self.skip_whitespace();
+                    self.expect(')')?;
+                    return Ok(AttributeValue::Type(ty));
+                }
+                Ok(AttributeValue::Literal(name))
+            }
+        }
+    }
+
+    /// The opening quote has been consumed
+    fn parse_string(&mut self, quote: char) -> Result<String, InvalidSelector> {
+        let mut value = String::new();
+        loop {
+            let ch = self.peek().ok_or_else(|| self.unexpected())?;
+            self.pos += ch.len_utf8();
+            match ch {
+                '\\' => {
+                    let escaped = self.peek().ok_or_else(|| self.unexpected())?;
+                    self.pos += escaped.len_utf8();
+                    value.push(match escaped {
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        ch => ch,
+                    });
+                }
+                ch if ch == quote => return Ok(value),
+                ch => value.push(ch),
+            }
+        }
+    }
+
+    /// The opening `/` has been consumed
+    fn parse_regex(&mut self) -> Result<Regex, InvalidSelector> {
+        let start = self.pos;
+        loop {
+            let ch = self.peek().ok_or_else(|| self.unexpected())?;
+            self.pos += ch.len_utf8();
+            match ch {
+                '\\' => {
+                    let escaped = self.peek().ok_or_else(|| self.unexpected())?;
+                    self.pos += escaped.len_utf8();
+                }
+                '/' => break,
+                _ => {}
+            }
+        }
+        let pattern = &self.source[start..self.pos - 1];
+        let flags_start = self.pos;
+        while self.peek().is_some_and(|ch| matches!(ch, 'i' | 'm' | 's' | 'u')) {
+            self.pos += 1;
+        }
+        let flags = &self.source[flags_start..self.pos];
+        let pattern =
+            if flags.is_empty() { pattern.to_string() } else { format!("(?{flags}){pattern}") };
+        #[allow(clippy::cast_possible_truncation)]
+        let span = Span::new(start as u32 - 1, self.pos as u32);
+        Regex::new(&pattern).map_err(|err| InvalidSelector(err.to_string(), span))
+    }
+
+    /// `:not(A)`, `:first-child`, the `:` has been consumed
+    fn parse_pseudo(&mut self) -> Result<Selector, InvalidSelector> {
+        let start = self.pos;
+        let name = self.parse_identifier()?;
+        let selector = match name.as_str() {
+            "not" | "matches" | "is" | "has" => {
+                self.expect('(')?;
+                let mut selectors = vec![self.parse_selector()?];
+                loop {
+                    self.skip_whitespace();
+                    if !self.eat(',') {
+                        break;
+                    }
+                    selectors.push(self.parse_selector()?);
+                }
+                self.skip_whitespace();
+                self.expect(')')?;
+                match name.as_str() {
+                    "not" => Selector::Not(selectors),
+                    "has" => Selector::Has(selectors),
+                    _ => Selector::Matches(selectors),
+                }
+            }
+            "nth-child" | "nth-last-child" => {
+                self.expect('(')?;
+                self.skip_whitespace();
+                let digits = self.pos;
+                while self.peek().is_some_and(|ch| ch.is_ascii_digit()) {
+                    self.pos += 1;
+                }
+                let nth = self.source[digits..self.pos].parse().map_err(|_| self.unexpected())?;
+                self.skip_whitespace();
+                self.expect(')')?;
+                if name == "nth-child" {
+                    Selector::NthChild(nth)
+                } else {
+                    Selector::NthLastChild(nth)
+                }
+            }
+            "first-child" => Selector::NthChild(1),
+            "last-child" => Selector::NthLastChild(1),
+            "statement" => Selector::Class(NodeClass::Statement),
+            "expression" => Selector::Class(NodeClass::Expression),
+            "declaration" => Selector::Class(NodeClass::Declaration),
+            "function" => Selector::Class(NodeClass::Function),
+            "pattern" => Selector::Class(NodeClass::Pattern),
+            _ => {
+                #[allow(clippy::cast_possible_truncation)]
+                let span = Span::new(start as u32, self.pos as u32);
+                return Err(InvalidSelector(format!("unknown pseudo class `:{name}`"), span));
+            }
+        };
+        Ok(selector)
+    }
+}
+
+fn is_identifier_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_' || ch == '$' || ch == '-'
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_selector, AttributeOperator, AttributeValue, Selector};
+
+    #[test]
+    fn attribute() {
+        let Selector::Compound(atoms) =
+            parse_selector("CallExpression[callee.name='require']").unwrap()
+        else {
+            panic!("expected a compound selector");
+        };
+        assert!(matches!(&atoms[0], Selector::Type(name) if name == "CallExpression"));
+        let Selector::Attribute { path, test: Some((operator, AttributeValue::String(value))) } =
+            &atoms[1]
+        else {
+            panic!("expected an attribute selector");
+        };
+        assert_eq!(path, &["callee", "name"]);
+        assert_eq!(*operator, AttributeOperator::Equal);
+        assert_eq!(value, "require");
+    }
+
+    #[test]
+    fn combinators() {
+        let selector = parse_selector("A > B ~ C + D E, F").unwrap();
+        let Selector::Matches(selectors) = selector else { panic!("expected a selector list") };
+        assert_eq!(selectors.len(), 2);
+        assert!(matches!(&selectors[0], Selector::Descendant(left, _)
+            if matches!(left.as_ref(), Selector::Adjacent(..))));
+    }
+
+    #[test]
+    fn unicode_whitespace() {
+        for source in ["A\u{a0}>\u{3000}B", "A\u{3000}B", "\u{a0}A,\u{3000}B\u{a0}"] {
+            assert!(parse_selector(source).is_ok(), "{source}");
+        }
+        let Selector::Child(_, right) = parse_selector("A\u{a0}>\u{3000}B").unwrap() else {
+            panic!("expected a child selector");
+        };
+        assert!(matches!(right.as_ref(), Selector::Type(name) if name == "B"));
+    }
+
+    #[test]
+    fn errors() {
+        for source in ["", "A >", "[name", "A:unknown", "[name=/(/]", "A)"] {
+            assert!(parse_selector(source).is_err(), "{source}");
+        }
+    }
+}
@@ -56,6 +56,8 @@ pub enum FinalEndOfLine {
 #[derive(Debug, Clone)]
 pub struct FormatterOptions {
     pub indentation: u8,
+    /// Indent with a tab per level instead of `indentation` spaces
+    pub use_tabs: bool,
     // <https://prettier.io/docs/en/options#quotes>
     pub single_quote: bool,
     pub end_of_line: EndOfLine,
@@ -63,7 +65,7 @@ pub struct FormatterOptions {
 
 impl Default for FormatterOptions {
     fn default() -> Self {
-        Self { indentation: 4, single_quote: false, end_of_line: EndOfLine::LF }
+        Self { indentation: 4, use_tabs: false, single_quote: false, end_of_line: EndOfLine::LF }
     }
 }
 
@@ -71,6 +73,7 @@ impl Default for FormatterOptions {
 /// processed and reserved for internal use
 pub struct InnerOptions {
     pub indentation: u8,
+    pub use_tabs: bool,
     pub end_of_line: FinalEndOfLine,
     pub single_quote: bool,
 }
@@ -79,6 +82,7 @@ impl From<FormatterOptions> for InnerOptions {
     fn from(options: FormatterOptions) -> Self {
         Self {
             indentation: options.indentation,
+            use_tabs: options.use_tabs,
             single_quote: options.single_quote,
             end_of_line: options.end_of_line.get_final_end_of_line(),
         }
@@ -221,8 +225,14 @@ impl Formatter {
     }
 
     pub fn print_indent(&mut self) {
-        for _ in 0..self.indentation {
-            self.print(b' ');
+        if self.options.use_tabs {
+            for _ in 0..self.indentation / self.options.indentation.max(1) {
+                self.print(b'\t');
+            }
+        } else {
+            for _ in 0..self.indentation {
+                self.print(b' ');
+            }
         }
     }
 
@@ -0,0 +1,102 @@
+use tower_lsp::lsp_types::{
+    ClientCapabilities, CodeActionKind, CodeActionOptions, CodeActionRegistrationOptions,
+    FileOperationFilter, FileOperationPattern, FileOperationRegistrationOptions, Registration,
+    TextDocumentRegistrationOptions, Unregistration, WorkDoneProgressOptions,
+};
+
+use crate::Options;
+
+/// Features of the server which can be turned off in [Options], so that the client does not show
+/// their menus and commands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feature {
+    CodeActions,
+    WorkspaceSymbols,
+    UpdateImportsOnRename,
+}
+
+impl Feature {
+    pub const ALL: [Self; 3] =
+        [Self::CodeActions, Self::WorkspaceSymbols, Self::UpdateImportsOnRename];
+
+    fn method(self) -> &'static str {
+        match self {
+            Self::CodeActions => "textDocument/codeAction",
+            Self::WorkspaceSymbols => "workspace/symbol",
+            Self::UpdateImportsOnRename => "workspace/willRenameFiles",
+        }
+    }
+
+    pub fn is_enabled(self, options: &Options) -> bool {
+        match self {
+            Self::CodeActions => options.code_actions,
+            Self::WorkspaceSymbols => options.workspace_symbols,
+            Self::UpdateImportsOnRename => options.update_imports_on_rename,
+        }
+    }
+
+    /// Whether the client registers the feature with `client/registerCapability`, which can be
+    /// undone when it is turned off. The other features are advertised by the result of
+    /// `initialize` if they are enabled then.
+    pub fn is_dynamic(self, capabilities: &ClientCapabilities) -> bool {
+        let dynamic_registration = match self {
+            Self::CodeActions => capabilities
+                .text_document
+                .as_ref()
+                .and_then(|text_document| text_document.code_action.as_ref())
+                .and_then(|code_action| code_action.dynamic_registration),
+            Self::WorkspaceSymbols => capabilities
+                .workspace
+                .as_ref()
+                .and_then(|workspace| workspace.symbol.as_ref())
+                .and_then(|symbol| symbol.dynamic_registration),
+            Self::UpdateImportsOnRename => capabilities
+                .workspace
+                .as_ref()
+                .and_then(|workspace| workspace.file_operations.as_ref())
+                .and_then(|file_operations| file_operations.dynamic_registration),
+        };
+        dynamic_registration.unwrap_or(false)
+    }
+
+    pub fn registration(self) -> Registration {
+        let register_options = match self {
+            Self::CodeActions => serde_json::to_value(CodeActionRegistrationOptions {
+                text_document_registration_options: TextDocumentRegistrationOptions {
+                    document_selector: None,
+                },
+                code_action_options: code_action_options(),
+            })
+            .ok(),
+            Self::WorkspaceSymbols => None,
+            Self::UpdateImportsOnRename => serde_json::to_value(rename_options()).ok(),
+        };
+        Registration { id: self.id(), method: self.method().into(), register_options }
+    }
+
+    pub fn unregistration(self) -> Unregistration {
+        Unregistration { id: self.id(), method: self.method().into() }
+    }
+
+    fn id(self) -> String {
+        format!("oxc/{}", self.method())
+    }
+}
+
+pub fn code_action_options() -> CodeActionOptions {
+    CodeActionOptions {
+        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX, CodeActionKind::SOURCE_FIX_ALL]),
+        work_done_progress_options: WorkDoneProgressOptions { work_done_progress: None },
+        resolve_provider: None,
+    }
+}
+
+/// Files and directories, the specifiers of the modules in a directory change with it
+pub fn rename_options() -> FileOperationRegistrationOptions {
+    FileOperationRegistrationOptions {
+        filters: vec![FileOperationFilter {
+            scheme: Some("file".into()),
+            pattern: FileOperationPattern { glob: "**/*".into(), matches: None, options: None },
+        }],
+    }
+}
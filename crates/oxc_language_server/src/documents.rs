@@ -0,0 +1,85 @@
+use dashmap::DashMap;
+use oxc_span::{LineIndex, Span};
+use tower_lsp::lsp_types::{Position, TextDocumentContentChangeEvent, Url};
+
+#[derive(Debug)]
+struct Document {
+    text: String,
+    line_index: LineIndex,
+}
+
+impl Document {
+    fn new(text: String) -> Self {
+        let line_index = LineIndex::new(&text);
+        Self { text, line_index }
+    }
+
+    /// Byte offset of a position in UTF-16 code units, positions past the end are the end.
+    fn offset(&self, position: Position) -> u32 {
+        self.line_index
+            .offset_utf16(&self.text, position.line, position.character)
+            .unwrap_or(self.line_index.len())
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn apply(&mut self, change: TextDocumentContentChangeEvent) {
+        let Some(range) = change.range else {
+            *self = Self::new(change.text);
+            return;
+        };
+        let start = self.offset(range.start);
+        let end = self.offset(range.end).max(start);
+        self.text.replace_range(start as usize..end as usize, &change.text);
+        self.line_index.apply_edit(&self.text, Span::new(start, end), change.text.len() as u32);
+    }
+}
+
+/// Text of the open documents, kept up to date with the changes sent by the client with
+/// incremental sync, so that a keystroke sends the edited range instead of the whole document.
+#[derive(Debug, Default)]
+pub struct DocumentStore {
+    documents: DashMap<Url, Document>,
+}
+
+impl DocumentStore {
+    pub fn open(&self, uri: Url, text: String) {
+        self.documents.insert(uri, Document::new(text));
+    }
+
+    pub fn close(&self, uri: &Url) {
+        self.documents.remove(uri);
+    }
+
+    pub fn is_open(&self, uri: &Url) -> bool {
+        self.documents.contains_key(uri)
+    }
+
+    /// Text of the document, `None` when it is not open
+    pub fn text(&self, uri: &Url) -> Option<String> {
+        self.documents.get(uri).map(|document| document.text.clone())
+    }
+
+    /// The open documents with their text
+    pub fn all(&self) -> Vec<(Url, String)> {
+        self.documents.iter().map(|entry| (entry.key().clone(), entry.text.clone())).collect()
+    }
+
+    /// Apply the changes in order and return the new text. `None` for a document which was not
+    /// opened, unless a change replaces its whole text.
+    pub fn change(
+        &self,
+        uri: &Url,
+        mut changes: Vec<TextDocumentContentChangeEvent>,
+    ) -> Option<String> {
+        if !self.documents.contains_key(uri) {
+            let index = changes.iter().rposition(|change| change.range.is_none())?;
+            changes.drain(..index);
+            self.documents.insert(uri.clone(), Document::new(String::new()));
+        }
+        let mut document = self.documents.get_mut(uri)?;
+        for change in changes {
+            document.apply(change);
+        }
+        Some(document.text.clone())
+    }
+}
@@ -0,0 +1,61 @@
+//! Fixes of the rules as edits of the document. The fixes are written for the parsed source text,
+//! so they are adjusted to the byte order mark, the final newline and the line endings of the
+//! document, otherwise a fix touching the first or last line of a file checked out with CRLF line
+//! endings shows up as a change of the whole file.
+
+use oxc_span::Span;
+
+pub const BOM: char = '\u{feff}';
+
+/// The span and content of a fix of `source_text`, adjusted to the document:
+///
+/// * a fix starting at the beginning of the file keeps the byte order mark
+/// * a fix reaching the end of the file keeps the final newline, or the lack of one
+/// * the line breaks of the content are the line endings of the document
+pub fn normalize_fix(source_text: &str, span: Span, content: &str) -> (Span, String) {
+    let (mut start, end) = (span.start as usize, span.end as usize);
+    let mut content = content;
+
+    if source_text.starts_with(BOM) && start < BOM.len_utf8() {
+        start = BOM.len_utf8().min(end);
+        content = content.strip_prefix(BOM).unwrap_or(content);
+    }
+
+    let line_ending = line_ending(source_text);
+    let mut content = match line_ending {
+        Some("\r\n") => to_crlf(content),
+        _ => content.to_string(),
+    };
+
+    if end == source_text.len() && start < end {
+        let replaced = &source_text[start..end];
+        match (replaced.ends_with('\n'), content.ends_with('\n')) {
+            (true, false) => content.push_str(line_ending.unwrap_or("\n")),
+            (false, true) => content.truncate(content.trim_end_matches(['\r', '\n']).len()),
+            _ => {}
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    (Span::new(start as u32, end as u32), content)
+}
+
+/// The line ending of the first line of the document
+pub fn line_ending(source_text: &str) -> Option<&'static str> {
+    let index = source_text.find('\n')?;
+    Some(if source_text[..index].ends_with('\r') { "\r\n" } else { "\n" })
+}
+
+/// `text` with each `\n` not preceded by `\r` replaced by `\r\n`
+fn to_crlf(text: &str) -> String {
+    let mut crlf = String::with_capacity(text.len());
+    let mut previous = None;
+    for c in text.chars() {
+        if c == '\n' && previous != Some('\r') {
+            crlf.push('\r');
+        }
+        crlf.push(c);
+        previous = Some(c);
+    }
+    crlf
+}
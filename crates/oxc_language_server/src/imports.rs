@@ -0,0 +1,220 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Component, Path, PathBuf},
+    sync::RwLock,
+};
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{LineIndex, SourceType, VALID_EXTENSIONS};
+use tower_lsp::lsp_types::{Range, TextEdit, Url};
+
+use crate::{
+    linter::offset_to_position,
+    options::LintOptions,
+    walk::{Extensions, Walk},
+};
+
+/// How a specifier names the imported file, kept when the specifier is rewritten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpecifierForm {
+    /// `./a.js`
+    File,
+    /// `./a` for `./a.js`
+    WithoutExtension,
+    /// `./dir` for `./dir/index.js`
+    Directory,
+}
+
+#[derive(Debug, Clone)]
+struct Import {
+    specifier: String,
+    /// The specifier without its quotes
+    range: Range,
+    /// The imported file
+    resolved: PathBuf,
+    form: SpecifierForm,
+}
+
+/// Relative imports between the modules of the workspace, for `workspace/willRenameFiles`:
+/// the specifiers of the importers of a renamed file are updated, and those of the renamed file
+/// when it moves to another directory. Specifiers of packages and path aliases are left alone.
+///
+/// The graph is built on the first rename and again after each rename, as files have moved.
+#[derive(Debug, Default)]
+pub struct ImportGraph {
+    files: RwLock<Option<HashMap<PathBuf, Vec<Import>>>>,
+}
+
+impl ImportGraph {
+    /// Edits of the specifiers for renaming each `(old, new)` path, a file or a directory.
+    /// The edits are applied before the files are renamed, so they are keyed by the old paths.
+    pub fn rename_edits(
+        &self,
+        root: &Path,
+        renames: &[(PathBuf, PathBuf)],
+    ) -> HashMap<Url, Vec<TextEdit>> {
+        let mut graph = self.files.write().unwrap();
+        let files = graph.get_or_insert_with(|| Self::index_workspace(root));
+
+        let mut edits = HashMap::new();
+        for (importer, imports) in files.iter() {
+            let new_importer = renamed(importer, renames);
+            let Some(new_dir) = new_importer.as_deref().unwrap_or(importer).parent() else {
+                continue;
+            };
+            let file_edits = imports
+                .iter()
+                .filter_map(|import| {
+                    let new_target = renamed(&import.resolved, renames);
+                    if new_target.is_none() && new_importer.is_none() {
+                        return None;
+                    }
+                    let target = new_target.as_deref().unwrap_or(&import.resolved);
+                    let specifier = specifier_of(new_dir, target, import.form)?;
+                    (specifier != import.specifier)
+                        .then(|| TextEdit { range: import.range, new_text: specifier })
+                })
+                .collect::<Vec<_>>();
+            if file_edits.is_empty() {
+                continue;
+            }
+            if let Ok(uri) = Url::from_file_path(importer) {
+                edits.insert(uri, file_edits);
+            }
+        }
+        *graph = None;
+        edits
+    }
+
+    /// Index the imports of the saved file again, unless the graph has not been built yet.
+    pub fn update(&self, path: &Path) {
+        if let Some(files) = self.files.write().unwrap().as_mut() {
+            match Self::index_file(path) {
+                Some(imports) => files.insert(path.to_path_buf(), imports),
+                None => files.remove(path),
+            };
+        }
+    }
+
+    fn index_workspace(root: &Path) -> HashMap<PathBuf, Vec<Import>> {
+        let options = LintOptions {
+            paths: vec![root.to_path_buf()],
+            ignore_path: "node_modules".into(),
+            ignore_pattern: vec!["!**/node_modules/**/*".into()],
+            ..LintOptions::default()
+        };
+        Walk::new(&options)
+            .with_extensions(Extensions(VALID_EXTENSIONS.to_vec()))
+            .iter()
+            .filter_map(|path| Self::index_file(&path).map(|imports| (path.to_path_buf(), imports)))
+            .collect()
+    }
+
+    /// Relative specifiers of the module record of the file at `path` which resolve to a file.
+    fn index_file(path: &Path) -> Option<Vec<Import>> {
+        let source_type = SourceType::from_path(path).ok()?;
+        let source_text = fs::read_to_string(path).ok()?;
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        if ret.panicked {
+            return None;
+        }
+        let program = allocator.alloc(ret.program);
+        let module_record = SemanticBuilder::new(&source_text, source_type)
+            .build_module_record(path.to_path_buf(), program)
+            .module_record();
+
+        let dir = path.parent()?;
+        let line_index = LineIndex::new(&source_text);
+        let imports = module_record
+            .requested_modules
+            .iter()
+            .filter_map(|(specifier, spans)| {
+                let (resolved, form) = resolve(dir, specifier.as_str())?;
+                Some(spans.iter().map(move |span| (specifier, span, resolved.clone(), form)))
+            })
+            .flatten()
+            .map(|(specifier, span, resolved, form)| {
+                // The span of the string literal includes its quotes
+                let range = Range::new(
+                    offset_to_position(span.start as usize + 1, &source_text, &line_index),
+                    offset_to_position(span.end as usize - 1, &source_text, &line_index),
+                );
+                Import { specifier: specifier.to_string(), range, resolved, form }
+            })
+            .collect::<Vec<_>>();
+        (!imports.is_empty()).then_some(imports)
+    }
+}
+
+/// The new path of `path` if it is renamed or in a renamed directory.
+fn renamed(path: &Path, renames: &[(PathBuf, PathBuf)]) -> Option<PathBuf> {
+    renames.iter().find_map(|(old, new)| path.strip_prefix(old).ok().map(|rest| new.join(rest)))
+}
+
+/// The file a relative specifier of a module in `dir` resolves to, as Node.js and bundlers do.
+fn resolve(dir: &Path, specifier: &str) -> Option<(PathBuf, SpecifierForm)> {
+    if !(specifier.starts_with("./") || specifier.starts_with("../")) {
+        return None;
+    }
+    let path = normalize(&dir.join(specifier));
+    if path.is_file() {
+        return Some((path, SpecifierForm::File));
+    }
+    let with_extension = |path: &Path, extension: &str| {
+        let mut path = path.as_os_str().to_os_string();
+        path.push(".");
+        path.push(extension);
+        PathBuf::from(path)
+    };
+    VALID_EXTENSIONS
+        .iter()
+        .map(|extension| with_extension(&path, extension))
+        .find(|path| path.is_file())
+        .map(|path| (path, SpecifierForm::WithoutExtension))
+        .or_else(|| {
+            VALID_EXTENSIONS
+                .iter()
+                .map(|extension| path.join(format!("index.{extension}")))
+                .find(|path| path.is_file())
+                .map(|path| (path, SpecifierForm::Directory))
+        })
+}
+
+/// The specifier of `target` from a module in `dir`, in the given form.
+fn specifier_of(dir: &Path, target: &Path, form: SpecifierForm) -> Option<String> {
+    let target = match form {
+        SpecifierForm::File => target.to_path_buf(),
+        SpecifierForm::WithoutExtension => target.with_extension(""),
+        SpecifierForm::Directory => target.parent()?.to_path_buf(),
+    };
+    let dir = dir.components().collect::<Vec<_>>();
+    let target = target.components().collect::<Vec<_>>();
+    let common = dir.iter().zip(&target).take_while(|(a, b)| a == b).count();
+    let mut parts = vec![".."; dir.len() - common];
+    if parts.is_empty() {
+        parts.push(".");
+    }
+    let rest = target[common..].iter().map(|component| component.as_os_str().to_str());
+    let rest = rest.collect::<Option<Vec<_>>>()?;
+    parts.extend(rest);
+    Some(parts.join("/"))
+}
+
+/// `path` without `.` and `..` components, without following symbolic links.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
@@ -9,7 +9,12 @@ use std::{
 };
 
 use crate::walk::Walk;
-use crate::{options::LintOptions, walk::Extensions};
+use crate::{
+    edits::normalize_fix,
+    options::{DiagnosticSources, LintOptions},
+    walk::Extensions,
+};
+use log::error;
 use miette::NamedSource;
 use oxc_allocator::Allocator;
 use oxc_diagnostics::{miette, Error, Severity};
@@ -17,17 +22,54 @@ use oxc_linter::{
     partial_loader::{
         vue_partial_loader::VuePartialLoader, PartialLoader, LINT_PARTIAL_LOADER_EXT,
     },
-    LintContext, LintSettings, Linter,
+    FixKind, LintContext, LintOptions as LinterOptions, LintSettings, Linter,
 };
 use oxc_linter_plugin::{make_relative_path_parts, LinterPlugin};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
-use oxc_span::{SourceType, VALID_EXTENSIONS};
-use ropey::Rope;
+use oxc_span::{LineIndex, SourceType, VALID_EXTENSIONS};
 use tower_lsp::lsp_types::{
     self, DiagnosticRelatedInformation, DiagnosticSeverity, Position, Range, Url,
 };
 
+/// `source` of the published diagnostics, so that each stream can be told apart and turned off
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiagnosticSource {
+    /// Errors of the parser and the semantic analysis
+    Parse,
+    Lint,
+    /// Diagnostics of the rules of the import plugin
+    Import,
+}
+
+impl DiagnosticSource {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Parse => "oxc-parse",
+            Self::Lint => "oxc-lint",
+            Self::Import => "oxc-import",
+        }
+    }
+
+    /// Source of a diagnostic of a rule, from the plugin prefix of its message,
+    /// e.g. `eslint-plugin-import(no-cycle): ...`
+    fn of_rule_diagnostic(error: &Error) -> Self {
+        if error.to_string().starts_with("eslint-plugin-import(") {
+            Self::Import
+        } else {
+            Self::Lint
+        }
+    }
+
+    fn is_enabled(self, sources: DiagnosticSources) -> bool {
+        match self {
+            Self::Parse => sources.parse,
+            Self::Lint => sources.lint,
+            Self::Import => sources.import,
+        }
+    }
+}
+
 #[derive(Debug)]
 struct ErrorWithPosition {
     pub start_pos: Position,
@@ -35,6 +77,7 @@ struct ErrorWithPosition {
     pub miette_err: Error,
     pub fixed_content: Option<FixedContent>,
     pub labels_with_pos: Vec<LabeledSpanWithPosition>,
+    pub source: DiagnosticSource,
 }
 
 #[derive(Debug)]
@@ -45,14 +88,23 @@ struct LabeledSpanWithPosition {
 }
 
 impl ErrorWithPosition {
-    pub fn new(error: Error, text: &str, fixed_content: Option<FixedContent>) -> Self {
+    pub fn new(
+        error: Error,
+        text: &str,
+        line_index: &LineIndex,
+        fixed_content: Option<FixedContent>,
+        source: DiagnosticSource,
+    ) -> Self {
         let labels = error.labels().map_or(vec![], Iterator::collect);
         let labels_with_pos: Vec<LabeledSpanWithPosition> = labels
             .iter()
             .map(|labeled_span| LabeledSpanWithPosition {
-                start_pos: offset_to_position(labeled_span.offset(), text).unwrap_or_default(),
-                end_pos: offset_to_position(labeled_span.offset() + labeled_span.len(), text)
-                    .unwrap_or_default(),
+                start_pos: offset_to_position(labeled_span.offset(), text, line_index),
+                end_pos: offset_to_position(
+                    labeled_span.offset() + labeled_span.len(),
+                    text,
+                    line_index,
+                ),
                 message: labeled_span.label().map(ToString::to_string),
             })
             .collect();
@@ -60,12 +112,16 @@ impl ErrorWithPosition {
         let start_pos = labels_with_pos[0].start_pos;
         let end_pos = labels_with_pos[labels_with_pos.len() - 1].end_pos;
 
-        Self { miette_err: error, start_pos, end_pos, labels_with_pos, fixed_content }
+        Self { miette_err: error, start_pos, end_pos, labels_with_pos, fixed_content, source }
     }
 
     fn to_lsp_diagnostic(&self, path: &PathBuf) -> lsp_types::Diagnostic {
         let severity = match self.miette_err.severity() {
             Some(Severity::Error) => Some(lsp_types::DiagnosticSeverity::ERROR),
+            // Syntax and semantic errors are errors unless they say otherwise
+            None if self.source == DiagnosticSource::Parse => {
+                Some(lsp_types::DiagnosticSeverity::ERROR)
+            }
             _ => Some(lsp_types::DiagnosticSeverity::WARNING),
         };
         let related_information = Some(
@@ -115,7 +171,7 @@ impl ErrorWithPosition {
             severity,
             code: None,
             message,
-            source: Some("oxc".into()),
+            source: Some(self.source.as_str().into()),
             code_description: None,
             related_information,
             tags: None,
@@ -140,6 +196,7 @@ pub struct DiagnosticReport {
 struct ErrorReport {
     pub error: Error,
     pub fixed_content: Option<FixedContent>,
+    pub source: DiagnosticSource,
 }
 
 #[derive(Debug, Clone)]
@@ -165,12 +222,15 @@ impl IsolatedLintHandler {
     /// # Panics
     ///
     /// * When `mpsc::channel` fails to send.
-    pub fn run_full(&self) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
+    pub fn run_full(
+        &self,
+        on_progress: impl FnMut(usize, Option<usize>),
+    ) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
         let number_of_files = Arc::new(AtomicUsize::new(0));
         let (tx_error, rx_error) = mpsc::channel::<(PathBuf, Vec<ErrorWithPosition>)>();
 
         self.process_paths(&number_of_files, tx_error);
-        Self::process_diagnostics(&rx_error)
+        Self::process_diagnostics(&rx_error, &number_of_files, on_progress)
     }
 
     pub fn run_single(
@@ -179,9 +239,15 @@ impl IsolatedLintHandler {
         content: Option<String>,
     ) -> Option<Vec<DiagnosticReport>> {
         if Self::is_wanted_ext(path) {
-            Some(Self::lint_path(&self.linter, path, Arc::clone(&self.plugin), content).map_or(
-                vec![],
-                |(p, errors)| {
+            Some(
+                Self::lint_path(
+                    &self.linter,
+                    path,
+                    Arc::clone(&self.plugin),
+                    content,
+                    self.options.sources,
+                )
+                .map_or(vec![], |(p, errors)| {
                     let mut diagnostics: Vec<DiagnosticReport> =
                         errors.into_iter().map(|e| e.into_diagnostic_report(&p)).collect();
                     // a diagnostics connected from related_info to original diagnostic
@@ -208,7 +274,7 @@ impl IsolatedLintHandler {
                                     severity: Some(DiagnosticSeverity::HINT),
                                     code: None,
                                     message: r.message.clone(),
-                                    source: Some("oxc".into()),
+                                    source: d.diagnostic.source.clone(),
                                     code_description: None,
                                     related_information: related_information.clone(),
                                     tags: None,
@@ -220,8 +286,8 @@ impl IsolatedLintHandler {
                     }
                     diagnostics.append(&mut inverted_diagnostics);
                     diagnostics
-                },
-            ))
+                }),
+            )
         } else {
             None
         }
@@ -252,36 +318,47 @@ impl IsolatedLintHandler {
 
         let linter = Arc::clone(&self.linter);
         let plugin = Arc::clone(&self.plugin);
+        let sources = self.options.sources;
         rayon::spawn(move || {
             while let Ok(path) = rx_path.recv() {
                 let tx_error = tx_error.clone();
                 let linter = Arc::clone(&linter);
                 let plugin = Arc::clone(&plugin);
                 rayon::spawn(move || {
-                    if let Some(diagnostics) = Self::lint_path(&linter, &path, plugin, None) {
-                        tx_error.send(diagnostics).unwrap();
-                    }
+                    // Clean files are sent too, they count towards the progress
+                    let diagnostics = Self::lint_path(&linter, &path, plugin, None, sources)
+                        .unwrap_or_else(|| (path.to_path_buf(), vec![]));
+                    tx_error.send(diagnostics).unwrap();
                     drop(tx_error);
                 });
             }
         });
     }
 
+    /// `on_progress` is called with the number of linted files after each file, and the number
+    /// of files to lint once the walk is done. Only the files with diagnostics are returned.
     fn process_diagnostics(
         rx_error: &mpsc::Receiver<(PathBuf, Vec<ErrorWithPosition>)>,
+        number_of_files: &AtomicUsize,
+        mut on_progress: impl FnMut(usize, Option<usize>),
     ) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
         rx_error
             .iter()
-            .map(|(path, errors)| {
-                (
-                    path.clone(),
-                    errors.into_iter().map(|e| e.into_diagnostic_report(&path)).collect(),
-                )
+            .enumerate()
+            .filter_map(|(index, (path, errors))| {
+                let total = number_of_files.load(Ordering::Relaxed);
+                on_progress(index + 1, (total > 0).then_some(total));
+                (!errors.is_empty()).then(|| {
+                    let reports =
+                        errors.into_iter().map(|e| e.into_diagnostic_report(&path)).collect();
+                    (path, reports)
+                })
             })
             .collect()
     }
 
     fn get_source_type_and_text(
+        linter: &Linter,
         path: &Path,
         source_text: Option<String>,
     ) -> Option<(SourceType, String)> {
@@ -293,11 +370,10 @@ impl IsolatedLintHandler {
         };
 
         if let Ok(source_type) = SourceType::from_path(path) {
-            return Some((source_type, read_file(path)));
+            return Some((linter.source_type_for(path, source_type), read_file(path)));
         }
         let ext = path.extension().and_then(std::ffi::OsStr::to_str)?;
-        let partial_loader = if ext == "vue" { Some(PartialLoader::Vue) } else { None };
-        let partial_loader = partial_loader?;
+        let partial_loader = PartialLoader::from_extension(ext)?;
 
         let source_text = read_file(path);
         let ret = partial_loader.parse(&source_text);
@@ -309,20 +385,31 @@ impl IsolatedLintHandler {
         path: &Path,
         plugin: Plugin,
         source_text: Option<String>,
+        sources: DiagnosticSources,
     ) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
-        let (source_type, source_text) = Self::get_source_type_and_text(path, source_text)?;
+        let (source_type, source_text) = Self::get_source_type_and_text(linter, path, source_text)?;
         let allocator = Allocator::default();
         let ret = Parser::new(&allocator, &source_text, source_type)
             .allow_return_outside_function(true)
+            .error_recovery(true)
             .parse();
 
-        if !ret.errors.is_empty() {
-            let reports = ret
-                .errors
+        // Keep linting the recovered program while the file is being edited,
+        // syntax errors are reported along with the lint diagnostics.
+        let mut reports = if sources.parse {
+            ret.errors
                 .into_iter()
-                .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
-                .collect();
+                .map(|error| ErrorReport {
+                    error,
+                    fixed_content: None,
+                    source: DiagnosticSource::Parse,
+                })
+                .collect::<Vec<_>>()
+        } else {
+            vec![]
+        };
 
+        if ret.panicked {
             return Some(Self::wrap_diagnostics(path, &source_text, reports));
         };
 
@@ -333,11 +420,13 @@ impl IsolatedLintHandler {
             .build(program);
 
         if !semantic_ret.errors.is_empty() {
-            let reports = semantic_ret
-                .errors
-                .into_iter()
-                .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
-                .collect();
+            if sources.parse {
+                reports.extend(semantic_ret.errors.into_iter().map(|error| ErrorReport {
+                    error,
+                    fixed_content: None,
+                    source: DiagnosticSource::Parse,
+                }));
+            }
             return Some(Self::wrap_diagnostics(path, &source_text, reports));
         };
 
@@ -358,38 +447,43 @@ impl IsolatedLintHandler {
 
         drop(plugin); // explicitly drop plugin so that we consume the plugin in this function's body
 
-        let result = linter.run(lint_ctx);
+        let result = linter
+            .run(lint_ctx)
+            .into_iter()
+            .map(|msg| (DiagnosticSource::of_rule_diagnostic(&msg.error), msg))
+            .filter(|(source, _)| source.is_enabled(sources))
+            .collect::<Vec<_>>();
 
-        if result.is_empty() {
+        if result.is_empty() && reports.is_empty() {
             return None;
         }
 
         if linter.options().fix {
-            let reports = result
-                .into_iter()
-                .map(|msg| {
-                    let fixed_content = msg.fix.map(|f| FixedContent {
-                        code: f.content.to_string(),
-                        range: Range {
-                            start: offset_to_position(f.span.start as usize, &source_text)
-                                .unwrap_or_default(),
-                            end: offset_to_position(f.span.end as usize, &source_text)
-                                .unwrap_or_default(),
-                        },
-                    });
+            let line_index = LineIndex::new(&source_text);
+            reports.extend(result.into_iter().map(|(source, msg)| {
+                let fixed_content = msg.fix.map(|f| {
+                    let (span, code) = normalize_fix(&source_text, f.span, &f.content);
+                    let position = |offset: u32| {
+                        offset_to_position(offset as usize, &source_text, &line_index)
+                    };
+                    FixedContent {
+                        code,
+                        range: Range { start: position(span.start), end: position(span.end) },
+                    }
+                });
 
-                    ErrorReport { error: msg.error, fixed_content }
-                })
-                .collect::<Vec<ErrorReport>>();
+                ErrorReport { error: msg.error, fixed_content, source }
+            }));
 
             return Some(Self::wrap_diagnostics(path, &source_text, reports));
         }
 
-        let errors = result
-            .into_iter()
-            .map(|diagnostic| ErrorReport { error: diagnostic.error, fixed_content: None })
-            .collect();
-        Some(Self::wrap_diagnostics(path, &source_text, errors))
+        reports.extend(result.into_iter().map(|(source, diagnostic)| ErrorReport {
+            error: diagnostic.error,
+            fixed_content: None,
+            source,
+        }));
+        Some(Self::wrap_diagnostics(path, &source_text, reports))
     }
 
     fn wrap_diagnostics(
@@ -398,13 +492,16 @@ impl IsolatedLintHandler {
         reports: Vec<ErrorReport>,
     ) -> (PathBuf, Vec<ErrorWithPosition>) {
         let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text.to_owned()));
+        let line_index = LineIndex::new(source_text);
         let diagnostics = reports
             .into_iter()
             .map(|report| {
                 ErrorWithPosition::new(
                     report.error.with_source_code(Arc::clone(&source)),
                     source_text,
+                    &line_index,
                     report.fixed_content,
+                    report.source,
                 )
             })
             .collect();
@@ -420,24 +517,47 @@ fn get_extensions() -> Vec<&'static str> {
         .collect::<Vec<&'static str>>()
 }
 
+/// Positions are in UTF-16 code units, the default position encoding of the protocol.
 #[allow(clippy::cast_possible_truncation)]
-fn offset_to_position(offset: usize, source_text: &str) -> Option<Position> {
-    let rope = Rope::from_str(source_text);
-    let line = rope.try_char_to_line(offset).ok()?;
-    let first_char_of_line = rope.try_line_to_char(line).ok()?;
-    let column = offset - first_char_of_line;
-    Some(Position::new(line as u32, column as u32))
+pub fn offset_to_position(offset: usize, source_text: &str, line_index: &LineIndex) -> Position {
+    let (line, character) = line_index.line_column_utf16(source_text, offset as u32);
+    Position::new(line, character)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ServerLinter {
     linter: Arc<Linter>,
     plugin: Plugin,
 }
 
+/// Config files looked up in the folder of the workspace, in order
+pub const CONFIG_FILES: [&str; 4] =
+    [".oxlintrc.json", "oxlint.config.json", ".eslintrc.json", ".eslintrc"];
+
 impl ServerLinter {
     pub fn new() -> Self {
-        let linter = Linter::new().with_fix(true);
+        Self::with_linter(Linter::new())
+    }
+
+    /// The linter of the first of [CONFIG_FILES] in `root`, or of the default rules when there is
+    /// none or it is invalid.
+    pub fn from_root(root: &Path) -> Self {
+        let Some(config) = CONFIG_FILES.iter().map(|name| root.join(name)).find(|p| p.is_file())
+        else {
+            return Self::new();
+        };
+        let options = LinterOptions::default().with_config_path(Some(config.clone()));
+        match Linter::from_options(options) {
+            Ok(linter) => Self::with_linter(linter),
+            Err(err) => {
+                error!("Invalid config {}: {err:?}", config.display());
+                Self::new()
+            }
+        }
+    }
+
+    fn with_linter(linter: Linter) -> Self {
+        let linter = linter.with_fix(true).with_fix_kind(FixKind::Suggestion);
         Self { linter: Arc::new(linter), plugin: Arc::new(RwLock::new(None)) }
     }
 
@@ -451,12 +571,19 @@ impl ServerLinter {
         }
     }
 
-    pub fn run_full(&self, root_uri: &Url) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
+    /// Lint all files of the workspace from the disk, blocking until they are linted.
+    pub fn run_full(
+        &self,
+        root_uri: &Url,
+        sources: DiagnosticSources,
+        on_progress: impl FnMut(usize, Option<usize>),
+    ) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
         let options = LintOptions {
             paths: vec![root_uri.to_file_path().unwrap()],
             ignore_path: "node_modules".into(),
             ignore_pattern: vec!["!**/node_modules/**/*".into()],
             fix: true,
+            sources,
             ..LintOptions::default()
         };
 
@@ -465,7 +592,7 @@ impl ServerLinter {
             Arc::clone(&self.linter),
             Arc::clone(&self.plugin),
         )
-        .run_full()
+        .run_full(on_progress)
     }
 
     pub fn run_single(
@@ -473,12 +600,14 @@ impl ServerLinter {
         root_uri: &Url,
         uri: &Url,
         content: Option<String>,
+        sources: DiagnosticSources,
     ) -> Option<Vec<DiagnosticReport>> {
         let options = LintOptions {
             paths: vec![root_uri.to_file_path().unwrap()],
             ignore_path: "node_modules".into(),
             ignore_pattern: vec!["!**/node_modules/**/*".into()],
             fix: true,
+            sources,
             ..LintOptions::default()
         };
 
@@ -1,41 +1,75 @@
 #![allow(unused)]
+mod capabilities;
+mod documents;
+mod edits;
+mod imports;
 mod linter;
 mod options;
+mod suppressions;
+mod symbols;
 mod walk;
-
-use crate::linter::{DiagnosticReport, ServerLinter};
-use globset::Glob;
-use ignore::gitignore::Gitignore;
+mod workspace;
+
+use crate::capabilities::{code_action_options, rename_options, Feature};
+use crate::documents::DocumentStore;
+use crate::linter::{DiagnosticReport, FixedContent, CONFIG_FILES};
+use crate::options::DiagnosticSources;
+use crate::suppressions::{directive_rule_name, disable_for_file, disable_for_line};
+use crate::workspace::WorkspaceRoot;
+use globset::{GlobBuilder, GlobSet};
 use log::{debug, error};
+use oxc_linter::clear_package_json_cache;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::Arc;
 
 use dashmap::DashMap;
 use futures::future::join_all;
-use tokio::sync::{Mutex, OnceCell, SetError};
-use tower_lsp::jsonrpc::{Error, ErrorCode, Result};
+use tokio::sync::{Mutex, OnceCell};
+use tower_lsp::jsonrpc::Result;
+use tower_lsp::lsp_types::notification::Progress;
+use tower_lsp::lsp_types::request::WorkDoneProgressCreate;
 use tower_lsp::lsp_types::{
     CodeAction, CodeActionKind, CodeActionOptions, CodeActionOrCommand, CodeActionParams,
     CodeActionProviderCapability, CodeActionResponse, ConfigurationItem, Diagnostic,
-    DidChangeConfigurationParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
-    DidOpenTextDocumentParams, DidSaveTextDocumentParams, InitializeParams, InitializeResult,
-    InitializedParams, MessageType, OneOf, Registration, ServerCapabilities, ServerInfo,
-    TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Url, WorkDoneProgressOptions,
-    WorkspaceEdit, WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
+    DiagnosticSeverity, DidChangeConfigurationParams, DidChangeTextDocumentParams,
+    DidChangeWatchedFilesParams, DidChangeWatchedFilesRegistrationOptions,
+    DidChangeWorkspaceFoldersParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DidSaveTextDocumentParams, FileOperationFilter, FileOperationPattern,
+    FileOperationRegistrationOptions, FileSystemWatcher, GlobPattern, InitializeParams,
+    InitializeResult, InitializedParams, MessageType, NumberOrString, OneOf, ProgressParams,
+    ProgressParamsValue, Range, Registration, RenameFilesParams, ServerCapabilities, ServerInfo,
+    SymbolInformation, TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Url,
+    WorkDoneProgress, WorkDoneProgressBegin, WorkDoneProgressCreateParams, WorkDoneProgressEnd,
+    WorkDoneProgressOptions, WorkDoneProgressReport, WorkspaceEdit,
+    WorkspaceFileOperationsServerCapabilities, WorkspaceFoldersServerCapabilities,
+    WorkspaceServerCapabilities, WorkspaceSymbolParams,
 };
 use tower_lsp::{Client, LanguageServer, LspService, Server};
 
 #[derive(Debug)]
 struct Backend {
     client: Client,
-    root_uri: OnceCell<Option<Url>>,
-    server_linter: ServerLinter,
+    /// The folders of the workspace, by their URI
+    roots: DashMap<Url, Arc<WorkspaceRoot>>,
     diagnostics_report_map: DashMap<String, Vec<DiagnosticReport>>,
     options: Mutex<Options>,
-    gitignore_glob: Mutex<Option<Gitignore>>,
+    /// [Options::exclude], relative to the folder of each file
+    exclude_glob: Mutex<Option<GlobSet>>,
+    /// Text of the open documents, from the incremental changes of the client
+    documents: DocumentStore,
+    /// Whether the client shows the progress of `window/workDoneProgress/create` tokens
+    work_done_progress: OnceCell<bool>,
+    /// Whether the client registers `workspace/didChangeWatchedFiles` dynamically, to reload the
+    /// linter when a config file changes
+    watch_files: OnceCell<bool>,
+    /// The features which the client registers dynamically, see [Feature::is_dynamic]
+    dynamic_features: OnceCell<Vec<Feature>>,
+    /// The dynamic features currently registered
+    registered_features: Mutex<Vec<Feature>>,
 }
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, PartialOrd, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
@@ -45,18 +79,75 @@ enum Run {
     OnType,
 }
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 struct Options {
     run: Run,
     enable: bool,
+    /// Publish syntax and semantic errors, even when no lint rule reports anything,
+    /// with the source `oxc-parse`
+    #[serde(default = "default_true")]
+    syntax_errors: bool,
+    /// Publish the diagnostics of the rules, with the source `oxc-lint`
+    #[serde(default = "default_true")]
+    lint_diagnostics: bool,
+    /// Publish the diagnostics of the rules of the import plugin, with the source `oxc-import`
+    #[serde(default = "default_true")]
+    import_diagnostics: bool,
+    /// Most diagnostics published per document, the rest are summed up by an informational
+    /// diagnostic. Keeps the editor responsive on generated or vendored files.
+    #[serde(default)]
+    max_diagnostics: Option<usize>,
+    /// Globs of the files which are not linted, relative to the root, e.g. the ones of
+    /// `files.exclude` and `search.exclude` forwarded by the client, such as `**/dist`.
+    /// A matched directory excludes all files in it.
+    #[serde(default)]
+    exclude: Vec<String>,
+    /// Lint all files of the workspace on startup and publish their diagnostics, not only the
+    /// ones of the open documents
+    #[serde(default)]
+    lint_workspace: bool,
+    /// Offer the fixes of the diagnostics as code actions
+    #[serde(default = "default_true")]
+    code_actions: bool,
+    /// Search the exported symbols of the workspace
+    #[serde(default = "default_true")]
+    workspace_symbols: bool,
+    /// Update the import specifiers of the modules referencing a renamed file
+    #[serde(default = "default_true")]
+    update_imports_on_rename: bool,
+}
+
+const fn default_true() -> bool {
+    true
 }
 
 impl Default for Options {
     fn default() -> Self {
-        Self { enable: true, run: Run::default() }
+        Self {
+            enable: true,
+            run: Run::default(),
+            syntax_errors: true,
+            lint_diagnostics: true,
+            import_diagnostics: true,
+            max_diagnostics: None,
+            exclude: vec![],
+            lint_workspace: false,
+            code_actions: true,
+            workspace_symbols: true,
+            update_imports_on_rename: true,
+        }
     }
 }
 
 impl Options {
+    fn diagnostic_sources(&self) -> DiagnosticSources {
+        DiagnosticSources {
+            parse: self.syntax_errors,
+            lint: self.lint_diagnostics,
+            import: self.import_diagnostics,
+        }
+    }
+
     fn get_lint_level(&self) -> SyntheticRunLevel {
         if self.enable {
             match self.run {
@@ -67,6 +158,72 @@ impl Options {
             SyntheticRunLevel::Disable
         }
     }
+
+    /// `None` when nothing is excluded. Invalid globs are logged and skipped.
+    fn exclude_glob_set(&self) -> Option<GlobSet> {
+        if self.exclude.is_empty() {
+            return None;
+        }
+        let mut builder = globset::GlobSetBuilder::new();
+        for pattern in &self.exclude {
+            // `*` does not match `/`, as in the globs of the client
+            match GlobBuilder::new(pattern).literal_separator(true).build() {
+                Ok(glob) => {
+                    builder.add(glob);
+                }
+                Err(err) => error!("Invalid exclude glob {pattern:?}: {err}"),
+            }
+        }
+        builder.build().ok()
+    }
+}
+
+/// Keep the first `max_diagnostics` diagnostics, followed by an informational diagnostic at the
+/// start of the document with the number of the others.
+fn truncate_diagnostics(
+    mut diagnostics: Vec<Diagnostic>,
+    max_diagnostics: Option<usize>,
+) -> Vec<Diagnostic> {
+    let Some(max_diagnostics) = max_diagnostics else { return diagnostics };
+    if diagnostics.len() <= max_diagnostics {
+        return diagnostics;
+    }
+    let more = diagnostics.len() - max_diagnostics;
+    diagnostics.truncate(max_diagnostics);
+    diagnostics.push(Diagnostic {
+        range: Range::default(),
+        severity: Some(DiagnosticSeverity::INFORMATION),
+        source: Some("oxc".into()),
+        message: format!("{more} more problems\u{2026}"),
+        ..Diagnostic::default()
+    });
+    diagnostics
+}
+
+/// Edits of the fixes in document order, without the fixes overlapping an earlier one as the
+/// edits of a `WorkspaceEdit` must not overlap. The skipped problems are fixed by the next run.
+fn non_overlapping_edits(mut fixes: Vec<&FixedContent>) -> Vec<TextEdit> {
+    fixes.sort_by_key(|fixed_content| (fixed_content.range.start, fixed_content.range.end));
+    let mut edits: Vec<TextEdit> = vec![];
+    for fixed_content in fixes {
+        if edits.last().is_some_and(|edit| fixed_content.range.start < edit.range.end) {
+            continue;
+        }
+        edits.push(TextEdit { range: fixed_content.range, new_text: fixed_content.code.clone() });
+    }
+    edits
+}
+
+/// Whether the client asks for code actions of `kind`. `only` may list a parent kind, such as
+/// `source` for `source.fixAll`, and all kinds are asked for without it.
+fn is_requested(only: Option<&[CodeActionKind]>, kind: &CodeActionKind) -> bool {
+    only.map_or(true, |only| {
+        only.iter().any(|requested| {
+            kind.as_str()
+                .strip_prefix(requested.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+        })
+    })
 }
 
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
@@ -79,8 +236,29 @@ enum SyntheticRunLevel {
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
     async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
-        self.init(params.root_uri)?;
-        self.init_ignore_glob().await;
+        // `rootUri` is the first folder of the workspace, or the only one of older clients
+        let folders = match params.workspace_folders {
+            Some(folders) if !folders.is_empty() => {
+                folders.into_iter().map(|folder| folder.uri).collect()
+            }
+            _ => params.root_uri.into_iter().collect::<Vec<_>>(),
+        };
+        self.add_roots(folders);
+        let capabilities = &params.capabilities;
+        let work_done_progress =
+            capabilities.window.as_ref().and_then(|window| window.work_done_progress);
+        let _ = self.work_done_progress.set(work_done_progress.unwrap_or(false));
+        let watch_files = capabilities
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.did_change_watched_files)
+            .and_then(|watched_files| watched_files.dynamic_registration);
+        let _ = self.watch_files.set(watch_files.unwrap_or(false));
+        let dynamic_features = Feature::ALL
+            .into_iter()
+            .filter(|feature| feature.is_dynamic(capabilities))
+            .collect::<Vec<_>>();
+        let _ = self.dynamic_features.set(dynamic_features.clone());
         let options = params.initialization_options.and_then(|mut value| {
             let settings = value.get_mut("settings")?.take();
             serde_json::from_value::<Options>(settings).ok()
@@ -88,31 +266,36 @@ impl LanguageServer for Backend {
 
         if let Some(value) = options {
             debug!("initialize: {:?}", value);
+            *self.exclude_glob.lock().await = value.exclude_glob_set();
             *self.options.lock().await = value;
         }
+        // The dynamic features are registered once initialized, if they are enabled
+        let options = self.options.lock().await;
+        let is_static =
+            |feature: Feature| feature.is_enabled(&options) && !dynamic_features.contains(&feature);
         Ok(InitializeResult {
             server_info: Some(ServerInfo { name: "oxc".into(), version: None }),
             offset_encoding: None,
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                    TextDocumentSyncKind::INCREMENTAL,
                 )),
                 workspace: Some(WorkspaceServerCapabilities {
                     workspace_folders: Some(WorkspaceFoldersServerCapabilities {
                         supported: Some(true),
                         change_notifications: Some(OneOf::Left(true)),
                     }),
-                    file_operations: None,
+                    file_operations: is_static(Feature::UpdateImportsOnRename).then(|| {
+                        WorkspaceFileOperationsServerCapabilities {
+                            will_rename: Some(rename_options()),
+                            ..WorkspaceFileOperationsServerCapabilities::default()
+                        }
+                    }),
                 }),
-                code_action_provider: Some(CodeActionProviderCapability::Options(
-                    CodeActionOptions {
-                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
-                        work_done_progress_options: WorkDoneProgressOptions {
-                            work_done_progress: None,
-                        },
-                        resolve_provider: None,
-                    },
-                )),
+                code_action_provider: is_static(Feature::CodeActions)
+                    .then(|| CodeActionProviderCapability::Options(code_action_options())),
+                workspace_symbol_provider: is_static(Feature::WorkspaceSymbols)
+                    .then_some(OneOf::Left(true)),
                 ..ServerCapabilities::default()
             },
         })
@@ -160,24 +343,103 @@ impl LanguageServer for Backend {
                 .collect::<Vec<_>>();
             self.publish_all_diagnostics(&cleared_diagnostics).await;
         }
+        *self.exclude_glob.lock().await = changed_options.exclude_glob_set();
         *self.options.lock().await = changed_options;
+        self.update_registrations().await;
+
+        // Clear the diagnostics of the opened files which are excluded now
+        let opened_files = self
+            .diagnostics_report_map
+            .iter()
+            .filter_map(|entry| Url::from_str(entry.key()).ok())
+            .collect::<Vec<_>>();
+        for uri in opened_files {
+            if self.is_ignored(&uri).await {
+                self.diagnostics_report_map.remove(&uri.to_string());
+                self.client.publish_diagnostics(uri, vec![], None).await;
+            }
+        }
     }
 
     async fn initialized(&self, params: InitializedParams) {
         debug!("oxc initialized.");
 
-        if let Some(Some(root_uri)) = self.root_uri.get() {
-            self.server_linter.make_plugin(root_uri);
-            // let result = self.server_linter.run_full(root_uri);
+        if self.watch_files.get().copied().unwrap_or(false) {
+            self.watch_config_files().await;
+        }
+        self.update_registrations().await;
 
-            // self.publish_all_diagnostics(
-            // &result
-            // .into_iter()
-            // .map(|(p, d)| (p, d.into_iter().map(|d| d.diagnostic).collect()))
-            // .collect(),
-            // )
-            // .await;
+        let roots = self.roots.iter().map(|root| Arc::clone(root.value())).collect::<Vec<_>>();
+        self.lint_workspaces(&roots).await;
+    }
+
+    async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
+        debug!("oxc workspace folders changed");
+        for folder in params.event.removed {
+            if self.roots.remove(&folder.uri).is_none() {
+                continue;
+            }
+            // Clear the diagnostics of the files of the folder, unless they are in another one
+            let Ok(path) = folder.uri.to_file_path() else { continue };
+            let uris = self
+                .diagnostics_report_map
+                .iter()
+                .filter_map(|entry| Url::from_str(entry.key()).ok())
+                .filter(|uri| {
+                    uri.to_file_path()
+                        .is_ok_and(|file| file.starts_with(&path) && self.root_for(&file).is_none())
+                })
+                .collect::<Vec<_>>();
+            for uri in uris {
+                self.diagnostics_report_map.remove(&uri.to_string());
+                self.client.publish_diagnostics(uri, vec![], None).await;
+            }
+        }
+        let roots = self.add_roots(params.event.added.into_iter().map(|folder| folder.uri));
+        self.lint_workspaces(&roots).await;
+    }
+
+    /// Rebuild the linter of the folders whose config changed and lint the open documents again,
+    /// with the new rules.
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let mut roots: Vec<Arc<WorkspaceRoot>> = vec![];
+        let mut package_json_changed = false;
+        for change in params.changes {
+            let Ok(path) = change.uri.to_file_path() else { continue };
+            let Some(root) = self.root_for(&path) else { continue };
+            // Only the config files at the root of a folder are read, a `package.json` anywhere
+            // changes the dependencies of the files of its package
+            let is_package_json = path.file_name().is_some_and(|name| name == "package.json");
+            package_json_changed |= is_package_json;
+            if (path.parent() == Some(root.path.as_path()) || is_package_json)
+                && !roots.iter().any(|added| added.uri == root.uri)
+            {
+                roots.push(root);
+            }
+        }
+        if package_json_changed {
+            clear_package_json_cache();
+        }
+        if roots.is_empty() {
+            return;
+        }
+        debug!("oxc config changed");
+        for root in &roots {
+            root.reload_linter();
         }
+
+        if self.options.lock().await.get_lint_level() == SyntheticRunLevel::Disable {
+            return;
+        }
+        for (uri, text) in self.documents.all() {
+            let in_roots = uri.to_file_path().is_ok_and(|path| {
+                self.root_for(&path).is_some_and(|root| roots.iter().any(|r| r.uri == root.uri))
+            });
+            if in_roots && !self.is_ignored(&uri).await {
+                self.handle_file_update(uri, Some(text), None).await;
+            }
+        }
+        self.lint_workspaces(&roots).await;
     }
 
     async fn shutdown(&self) -> Result<()> {
@@ -186,6 +448,12 @@ impl LanguageServer for Backend {
 
     async fn did_save(&self, params: DidSaveTextDocumentParams) {
         debug!("oxc server did save");
+        if let Some(root) = self.root_for_uri(&params.text_document.uri) {
+            if let Ok(path) = params.text_document.uri.to_file_path() {
+                root.symbol_index.update(&path);
+                root.import_graph.update(&path);
+            }
+        }
         // drop as fast as possible
         let run_level = { self.options.lock().await.get_lint_level() };
         if run_level < SyntheticRunLevel::OnSave {
@@ -200,6 +468,8 @@ impl LanguageServer for Backend {
     /// When the document changed, it may not be written to disk, so we should
     /// get the file context from the language client
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        // The changes are ranges of the previous text, they are applied even when not linting
+        let content = self.documents.change(&params.text_document.uri, params.content_changes);
         let run_level = { self.options.lock().await.get_lint_level() };
         if run_level < SyntheticRunLevel::OnType {
             return;
@@ -208,7 +478,6 @@ impl LanguageServer for Backend {
         if self.is_ignored(&params.text_document.uri).await {
             return;
         }
-        let content = params.content_changes.first().map(|c| c.text.clone());
         self.handle_file_update(
             params.text_document.uri,
             content,
@@ -218,6 +487,7 @@ impl LanguageServer for Backend {
     }
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        self.documents.open(params.text_document.uri.clone(), params.text_document.text);
         let run_level = { self.options.lock().await.get_lint_level() };
         if run_level < SyntheticRunLevel::OnType {
             return;
@@ -230,93 +500,263 @@ impl LanguageServer for Backend {
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        self.documents.close(&params.text_document.uri);
         let uri = params.text_document.uri.to_string();
         self.diagnostics_report_map.remove(&uri);
     }
 
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        // Clients which do not register the feature dynamically still ask once it is turned off
+        if !self.options.lock().await.code_actions {
+            return Ok(None);
+        }
         let uri = params.text_document.uri;
-
-        if let Some(value) = self.diagnostics_report_map.get(&uri.to_string()) {
-            if let Some(report) = value
+        let Some(value) = self.diagnostics_report_map.get(&uri.to_string()) else {
+            return Ok(None);
+        };
+        let only = params.context.only.as_deref();
+        let mut actions = vec![];
+
+        if let Some(report) = value
+            .iter()
+            .find(|r| r.diagnostic.range == params.range && r.fixed_content.is_some())
+            .filter(|_| is_requested(only, &CodeActionKind::QUICKFIX))
+        {
+            let rule = report.diagnostic.message.split(':').next();
+            let title =
+                rule.map_or_else(|| "Fix this problem".into(), |s| format!("Fix this {s} problem"));
+
+            let fixed_content = report.fixed_content.clone().unwrap();
+
+            actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                title,
+                kind: Some(CodeActionKind::QUICKFIX),
+                is_preferred: Some(true),
+                edit: Some(WorkspaceEdit {
+                    changes: Some(HashMap::from([(
+                        uri.clone(),
+                        vec![TextEdit { range: fixed_content.range, new_text: fixed_content.code }],
+                    )])),
+                    ..WorkspaceEdit::default()
+                }),
+                disabled: None,
+                data: None,
+                diagnostics: None,
+                command: None,
+            }));
+
+            // The fixes of all problems of the same rule in the file, in one edit
+            let fixes = value
                 .iter()
-                .find(|r| r.diagnostic.range == params.range && r.fixed_content.is_some())
-            {
-                let title =
-                    report.diagnostic.message.split(':').next().map_or_else(
-                        || "Fix this problem".into(),
-                        |s| format!("Fix this {s} problem"),
-                    );
-
-                let fixed_content = report.fixed_content.clone().unwrap();
-
-                return Ok(Some(vec![CodeActionOrCommand::CodeAction(CodeAction {
-                    title,
+                .filter(|r| r.diagnostic.message.split(':').next() == rule)
+                .filter_map(|r| r.fixed_content.as_ref())
+                .collect::<Vec<_>>();
+            if let (Some(rule), true) = (rule, fixes.len() > 1) {
+                actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                    title: format!("Fix all {rule} problems in this file"),
                     kind: Some(CodeActionKind::QUICKFIX),
-                    is_preferred: Some(true),
+                    is_preferred: Some(false),
                     edit: Some(WorkspaceEdit {
-                        changes: Some(HashMap::from([(
-                            uri,
-                            vec![TextEdit {
-                                range: fixed_content.range,
-                                new_text: fixed_content.code,
-                            }],
-                        )])),
+                        changes: Some(HashMap::from([(uri.clone(), non_overlapping_edits(fixes))])),
                         ..WorkspaceEdit::default()
                     }),
                     disabled: None,
                     data: None,
                     diagnostics: None,
                     command: None,
-                })]));
+                }));
             }
         }
 
-        Ok(None)
+        // Disable the rules of the problems in the range, once per rule and line
+        if is_requested(only, &CodeActionKind::QUICKFIX) {
+            let mut rules = value
+                .iter()
+                .filter(|r| {
+                    r.diagnostic.severity != Some(DiagnosticSeverity::HINT)
+                        && r.diagnostic.range.start <= params.range.end
+                        && params.range.start <= r.diagnostic.range.end
+                })
+                .filter_map(|r| {
+                    let rule = directive_rule_name(&r.diagnostic.message)?;
+                    Some((r.diagnostic.range.start.line, rule))
+                })
+                .collect::<Vec<_>>();
+            rules.sort_unstable();
+            rules.dedup();
+            let source_text = (!rules.is_empty())
+                .then(|| {
+                    self.documents
+                        .text(&uri)
+                        .or_else(|| std::fs::read_to_string(uri.to_file_path().ok()?).ok())
+                })
+                .flatten();
+            if let Some(source_text) = source_text {
+                for (line, rule) in &rules {
+                    actions.push(disable_for_line(&uri, &source_text, rule, *line));
+                }
+                let mut file_rules = rules.into_iter().map(|(_, rule)| rule).collect::<Vec<_>>();
+                file_rules.sort_unstable();
+                file_rules.dedup();
+                for rule in file_rules {
+                    actions.push(disable_for_file(&uri, &source_text, &rule));
+                }
+            }
+        }
+
+        // The fixes of all problems in the file, for the fix all on save of the editors
+        let fixes = value.iter().filter_map(|r| r.fixed_content.as_ref()).collect::<Vec<_>>();
+        if !fixes.is_empty() && is_requested(only, &CodeActionKind::SOURCE_FIX_ALL) {
+            actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                title: "Fix all oxc problems".into(),
+                kind: Some(CodeActionKind::SOURCE_FIX_ALL),
+                is_preferred: Some(false),
+                edit: Some(WorkspaceEdit {
+                    changes: Some(HashMap::from([(uri, non_overlapping_edits(fixes))])),
+                    ..WorkspaceEdit::default()
+                }),
+                disabled: None,
+                data: None,
+                diagnostics: None,
+                command: None,
+            }));
+        }
+
+        Ok((!actions.is_empty()).then_some(actions))
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> Result<Option<Vec<SymbolInformation>>> {
+        if !self.options.lock().await.workspace_symbols {
+            return Ok(None);
+        }
+        let roots = self.roots.iter().map(|root| Arc::clone(root.value())).collect::<Vec<_>>();
+        let symbols = roots
+            .iter()
+            .flat_map(|root| root.symbol_index.search(&root.path, &params.query))
+            .collect();
+        Ok(Some(symbols))
+    }
+
+    /// Update the import specifiers of the modules referencing the renamed files or directories.
+    async fn will_rename_files(&self, params: RenameFilesParams) -> Result<Option<WorkspaceEdit>> {
+        if !self.options.lock().await.update_imports_on_rename {
+            return Ok(None);
+        }
+        let renames = params
+            .files
+            .iter()
+            .filter_map(|rename| {
+                let old = Url::parse(&rename.old_uri).ok()?.to_file_path().ok()?;
+                let new = Url::parse(&rename.new_uri).ok()?.to_file_path().ok()?;
+                Some((old, new))
+            })
+            .collect::<Vec<_>>();
+        // Imports between the folders are not followed
+        let roots = self.roots.iter().map(|root| Arc::clone(root.value())).collect::<Vec<_>>();
+        let changes = roots
+            .iter()
+            .flat_map(|root| root.import_graph.rename_edits(&root.path, &renames))
+            .collect::<HashMap<_, _>>();
+        if changes.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() }))
     }
 }
 
 impl Backend {
-    fn init(&self, root_uri: Option<Url>) -> Result<()> {
-        self.root_uri.set(root_uri).map_err(|err| {
-            let message = match err {
-                SetError::AlreadyInitializedError(_) => "root uri already initialized".into(),
-                SetError::InitializingError(_) => "initializing error".into(),
-            };
+    /// Add the folders to the workspace, returning the added ones.
+    fn add_roots(&self, uris: impl IntoIterator<Item = Url>) -> Vec<Arc<WorkspaceRoot>> {
+        uris.into_iter()
+            .filter_map(|uri| {
+                let root = Arc::new(WorkspaceRoot::new(uri.clone())?);
+                self.roots.insert(uri, Arc::clone(&root));
+                Some(root)
+            })
+            .collect()
+    }
 
-            Error { code: ErrorCode::ParseError, message, data: None }
-        })?;
+    /// The innermost folder of the workspace containing `path`, for nested folders.
+    fn root_for(&self, path: &Path) -> Option<Arc<WorkspaceRoot>> {
+        self.roots
+            .iter()
+            .filter(|root| root.contains(path))
+            .max_by_key(|root| root.path.components().count())
+            .map(|root| Arc::clone(root.value()))
+    }
 
-        Ok(())
+    fn root_for_uri(&self, uri: &Url) -> Option<Arc<WorkspaceRoot>> {
+        self.root_for(&uri.to_file_path().ok()?)
     }
 
-    async fn init_ignore_glob(&self) {
-        let uri = self
-            .root_uri
-            .get()
-            .expect("The root uri should be initialized already")
-            .as_ref()
-            .expect("should get uri");
-        let mut builder = globset::GlobSetBuilder::new();
-        // Collecting all ignore files
-        builder.add(Glob::new("**/.eslintignore").unwrap());
-        builder.add(Glob::new("**/.gitignore").unwrap());
-
-        let ignore_file_glob_set = builder.build().unwrap();
-
-        let mut gitignore_builder = ignore::gitignore::GitignoreBuilder::new(uri.path());
-        let walk = ignore::WalkBuilder::new(uri.path())
-            .ignore(true)
-            .hidden(false)
-            .git_global(false)
-            .build();
-        for entry in walk.flatten() {
-            if ignore_file_glob_set.is_match(entry.path()) {
-                gitignore_builder.add(entry.path());
+    /// Watch the config files of the folders, and `package.json` files which may configure the
+    /// linter too.
+    async fn watch_config_files(&self) {
+        let watchers = CONFIG_FILES
+            .iter()
+            .chain(&["package.json"])
+            .map(|name| FileSystemWatcher {
+                glob_pattern: GlobPattern::String(format!("**/{name}")),
+                kind: None,
+            })
+            .collect();
+        let registration = Registration {
+            id: "oxc/watchConfigFiles".into(),
+            method: "workspace/didChangeWatchedFiles".into(),
+            register_options: serde_json::to_value(DidChangeWatchedFilesRegistrationOptions {
+                watchers,
+            })
+            .ok(),
+        };
+        if let Err(err) = self.client.register_capability(vec![registration]).await {
+            error!("Failed to watch the config files: {err}");
+        }
+    }
+
+    /// Register the dynamic features which are enabled and unregister the ones turned off.
+    async fn update_registrations(&self) {
+        let Some(dynamic_features) = self.dynamic_features.get() else { return };
+        let options = self.options.lock().await.clone();
+        let mut registered = self.registered_features.lock().await;
+        let (enabled, disabled): (Vec<Feature>, Vec<Feature>) =
+            dynamic_features.iter().copied().partition(|feature| feature.is_enabled(&options));
+
+        let registrations =
+            enabled.into_iter().filter(|feature| !registered.contains(feature)).collect::<Vec<_>>();
+        if !registrations.is_empty() {
+            let params = registrations.iter().map(|feature| feature.registration()).collect();
+            match self.client.register_capability(params).await {
+                Ok(()) => registered.extend(registrations),
+                Err(err) => error!("Failed to register {registrations:?}: {err}"),
             }
         }
 
-        *self.gitignore_glob.lock().await = gitignore_builder.build().ok();
+        let unregistrations =
+            disabled.into_iter().filter(|feature| registered.contains(feature)).collect::<Vec<_>>();
+        if !unregistrations.is_empty() {
+            let params = unregistrations.iter().map(|feature| feature.unregistration()).collect();
+            match self.client.unregister_capability(params).await {
+                Ok(()) => registered.retain(|feature| !unregistrations.contains(feature)),
+                Err(err) => error!("Failed to unregister {unregistrations:?}: {err}"),
+            }
+        }
+    }
+
+    /// Lint the folders with [Options::lint_workspace], one after the other.
+    async fn lint_workspaces(&self, roots: &[Arc<WorkspaceRoot>]) {
+        let lint_workspace = {
+            let options = self.options.lock().await;
+            options.lint_workspace && options.get_lint_level() != SyntheticRunLevel::Disable
+        };
+        if !lint_workspace {
+            return;
+        }
+        for root in roots {
+            self.lint_workspace(root).await;
+        }
     }
 
     #[allow(clippy::ptr_arg)]
@@ -331,14 +771,134 @@ impl Backend {
         .await;
     }
 
+    /// Lint all files of the workspace on a blocking thread and publish the diagnostics of the
+    /// files which are not open, the open documents are linted with the text of the editor.
+    async fn lint_workspace(&self, root: &WorkspaceRoot) {
+        let (sources, max_diagnostics) = {
+            let options = self.options.lock().await;
+            (options.diagnostic_sources(), options.max_diagnostics)
+        };
+
+        let token = NumberOrString::String(format!("oxc/lintWorkspace/{}", root.uri));
+        let progress = self.work_done_progress.get().copied().unwrap_or(false)
+            && self
+                .client
+                .send_request::<WorkDoneProgressCreate>(WorkDoneProgressCreateParams {
+                    token: token.clone(),
+                })
+                .await
+                .is_ok();
+        if progress {
+            let begin = WorkDoneProgressBegin {
+                title: format!("Linting {}", root.path.display()),
+                cancellable: Some(false),
+                message: None,
+                percentage: Some(0),
+            };
+            self.report_progress(&token, WorkDoneProgress::Begin(begin)).await;
+        }
+
+        let (tx_progress, mut rx_progress) = tokio::sync::mpsc::unbounded_channel();
+        let server_linter = root.server_linter();
+        let root_uri = root.uri.clone();
+        let task = tokio::task::spawn_blocking(move || {
+            server_linter.run_full(&root_uri, sources, |done, total| {
+                let _ = tx_progress.send((done, total));
+            })
+        });
+        // The channel is closed when the workspace is linted
+        let mut last_percentage = 0;
+        while let Some((done, total)) = rx_progress.recv().await {
+            let Some(total) = total.filter(|_| progress) else { continue };
+            let percentage = u32::try_from(done * 100 / total.max(done)).unwrap_or(100);
+            if percentage == last_percentage {
+                continue;
+            }
+            last_percentage = percentage;
+            let report = WorkDoneProgressReport {
+                cancellable: Some(false),
+                message: Some(format!("{done}/{total} files")),
+                percentage: Some(percentage),
+            };
+            self.report_progress(&token, WorkDoneProgress::Report(report)).await;
+        }
+        let result = task.await;
+        if progress {
+            let end = WorkDoneProgressEnd { message: None };
+            self.report_progress(&token, WorkDoneProgress::End(end)).await;
+        }
+        let Ok(result) = result else {
+            error!("Failed to lint the workspace");
+            return;
+        };
+
+        let mut reported = HashSet::new();
+        for (path, reports) in result {
+            let Ok(uri) = Url::from_file_path(&path) else { continue };
+            if reports.is_empty() || self.documents.is_open(&uri) || self.is_ignored(&uri).await {
+                continue;
+            }
+            reported.insert(uri.to_string());
+            let diagnostics = reports.iter().map(|report| report.diagnostic.clone()).collect();
+            self.client
+                .publish_diagnostics(
+                    uri.clone(),
+                    truncate_diagnostics(diagnostics, max_diagnostics),
+                    None,
+                )
+                .await;
+            self.diagnostics_report_map.insert(uri.to_string(), reports);
+        }
+
+        // Clear the diagnostics of the files of a previous lint which are clean now, e.g. after
+        // a change of the config file
+        let stale = self
+            .diagnostics_report_map
+            .iter()
+            .filter(|entry| !reported.contains(entry.key()))
+            .filter_map(|entry| Url::from_str(entry.key()).ok())
+            .filter(|uri| {
+                !self.documents.is_open(uri)
+                    && uri.to_file_path().is_ok_and(|file| {
+                        self.root_for(&file).is_some_and(|owner| owner.uri == root.uri)
+                    })
+            })
+            .collect::<Vec<_>>();
+        for uri in stale {
+            self.diagnostics_report_map.remove(&uri.to_string());
+            self.client.publish_diagnostics(uri, vec![], None).await;
+        }
+    }
+
+    async fn report_progress(&self, token: &NumberOrString, progress: WorkDoneProgress) {
+        self.client
+            .send_notification::<Progress>(ProgressParams {
+                token: token.clone(),
+                value: ProgressParamsValue::WorkDone(progress),
+            })
+            .await;
+    }
+
     async fn handle_file_update(&self, uri: Url, content: Option<String>, version: Option<i32>) {
-        if let Some(Some(root_uri)) = self.root_uri.get() {
-            self.server_linter.make_plugin(root_uri);
-            if let Some(diagnostics) = self.server_linter.run_single(root_uri, &uri, content) {
+        // Files outside of the folders, e.g. opened from a terminal, are linted by the first one
+        let root = self
+            .root_for_uri(&uri)
+            .or_else(|| self.roots.iter().next().map(|root| Arc::clone(root.value())));
+        if let Some(root) = root {
+            let (sources, max_diagnostics) = {
+                let options = self.options.lock().await;
+                (options.diagnostic_sources(), options.max_diagnostics)
+            };
+            let server_linter = root.server_linter();
+            server_linter.make_plugin(&root.uri);
+            if let Some(diagnostics) = server_linter.run_single(&root.uri, &uri, content, sources) {
                 self.client
                     .publish_diagnostics(
                         uri.clone(),
-                        diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
+                        truncate_diagnostics(
+                            diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
+                            max_diagnostics,
+                        ),
                         None,
                     )
                     .await;
@@ -349,19 +909,11 @@ impl Backend {
     }
 
     async fn is_ignored(&self, uri: &Url) -> bool {
-        let Some(Some(root_uri)) = self.root_uri.get() else {
-            return false;
-        };
-
-        // The file is not under current workspace
-        if !uri.path().starts_with(root_uri.path()) {
-            return false;
-        }
-        let Some(ref gitignore_globs) = *self.gitignore_glob.lock().await else {
-            return false;
-        };
-        let path = PathBuf::from(uri.path());
-        gitignore_globs.matched_path_or_any_parents(&path, path.is_dir()).is_ignore()
+        let Ok(path) = uri.to_file_path() else { return false };
+        // The file is not under any folder of the workspace
+        let Some(root) = self.root_for(&path) else { return false };
+        let exclude_glob = self.exclude_glob.lock().await;
+        root.is_ignored(&path, exclude_glob.as_ref())
     }
 }
 
@@ -372,16 +924,19 @@ async fn main() {
     let stdin = tokio::io::stdin();
     let stdout = tokio::io::stdout();
 
-    let server_linter = ServerLinter::new();
     let diagnostics_report_map = DashMap::new();
 
     let (service, socket) = LspService::build(|client| Backend {
         client,
-        root_uri: OnceCell::new(),
-        server_linter,
+        roots: DashMap::new(),
         diagnostics_report_map,
         options: Mutex::new(Options::default()),
-        gitignore_glob: Mutex::new(None),
+        exclude_glob: Mutex::new(None),
+        documents: DocumentStore::default(),
+        work_done_progress: OnceCell::new(),
+        watch_files: OnceCell::new(),
+        dynamic_features: OnceCell::new(),
+        registered_features: Mutex::new(vec![]),
     })
     .finish();
 
@@ -7,4 +7,22 @@ pub struct LintOptions {
     pub ignore_path: PathBuf,
     pub no_ignore: bool,
     pub ignore_pattern: Vec<String>,
+    pub sources: DiagnosticSources,
+}
+
+/// Which diagnostics are reported, by their `source`
+#[derive(Debug, Clone, Copy)]
+pub struct DiagnosticSources {
+    /// `oxc-parse`: the errors of the parser and the semantic analysis
+    pub parse: bool,
+    /// `oxc-lint`: the diagnostics of the rules, except the ones of the import plugin
+    pub lint: bool,
+    /// `oxc-import`: the diagnostics of the rules of the import plugin
+    pub import: bool,
+}
+
+impl Default for DiagnosticSources {
+    fn default() -> Self {
+        Self { parse: true, lint: true, import: true }
+    }
 }
@@ -0,0 +1,96 @@
+//! Code actions disabling the rule of a diagnostic with a comment, for the problems which are
+//! intended, as eslint-lsp offers them.
+
+use std::collections::HashMap;
+
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, Position, Range, TextEdit, Url, WorkspaceEdit,
+};
+
+use crate::edits::{line_ending, BOM};
+
+/// The rule of a diagnostic message as written in a directive, from the plugin prefix of the
+/// message, e.g. `import/no-cycle` for `eslint-plugin-import(no-cycle): ...`. `None` for the
+/// diagnostics which are not reported by a rule, such as syntax errors.
+pub fn directive_rule_name(message: &str) -> Option<String> {
+    let (prefix, _) = message.split_once(':')?;
+    let (plugin, rule) = prefix.strip_suffix(')')?.split_once('(')?;
+    if rule.is_empty() || rule.contains(char::is_whitespace) {
+        return None;
+    }
+    Some(match plugin {
+        "eslint" => rule.to_string(),
+        "typescript-eslint" => format!("@typescript-eslint/{rule}"),
+        plugin => {
+            let plugin = plugin.strip_prefix("eslint-plugin-").unwrap_or(plugin);
+            format!("{plugin}/{rule}")
+        }
+    })
+}
+
+/// "Disable `rule` for this line", with an `eslint-disable-next-line` comment above the line of
+/// the problem, indented like it.
+pub fn disable_for_line(
+    uri: &Url,
+    source_text: &str,
+    rule: &str,
+    line: u32,
+) -> CodeActionOrCommand {
+    let line_ending = line_ending(source_text).unwrap_or("\n");
+    let bom = u32::from(source_text.starts_with(BOM));
+    let indent = source_text
+        .trim_start_matches(BOM)
+        .lines()
+        .nth(line as usize)
+        .map_or("", |text| &text[..text.len() - text.trim_start().len()]);
+    // After the byte order mark, of one UTF-16 code unit
+    let start = Position::new(line, if line == 0 { bom } else { 0 });
+    let edit = TextEdit {
+        range: Range::new(start, start),
+        new_text: format!("{indent}// eslint-disable-next-line {rule}{line_ending}"),
+    };
+    action(uri, format!("Disable {rule} for this line"), edit)
+}
+
+/// "Disable `rule` for this file", with an `eslint-disable` comment at the top of the file, after
+/// the hashbang.
+pub fn disable_for_file(uri: &Url, source_text: &str, rule: &str) -> CodeActionOrCommand {
+    let line_ending = line_ending(source_text).unwrap_or("\n");
+    let bom = u32::from(source_text.starts_with(BOM));
+    let text = source_text.trim_start_matches(BOM);
+    let comment = format!("/* eslint-disable {rule} */{line_ending}");
+    let edit = match (text.starts_with("#!"), text.contains('\n')) {
+        (true, true) => {
+            let start = Position::new(1, 0);
+            TextEdit { range: Range::new(start, start), new_text: comment }
+        }
+        // A hashbang without a line break
+        (true, false) => {
+            #[allow(clippy::cast_possible_truncation)]
+            let end = Position::new(0, bom + text.encode_utf16().count() as u32);
+            let new_text = format!("{line_ending}{}", comment.trim_end());
+            TextEdit { range: Range::new(end, end), new_text }
+        }
+        (false, _) => {
+            let start = Position::new(0, bom);
+            TextEdit { range: Range::new(start, start), new_text: comment }
+        }
+    };
+    action(uri, format!("Disable {rule} for this file"), edit)
+}
+
+fn action(uri: &Url, title: String, edit: TextEdit) -> CodeActionOrCommand {
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        is_preferred: Some(false),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), vec![edit])])),
+            ..WorkspaceEdit::default()
+        }),
+        disabled: None,
+        data: None,
+        diagnostics: None,
+        command: None,
+    })
+}
@@ -0,0 +1,212 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{Declaration, ExportDefaultDeclarationKind, ModuleDeclaration, Statement},
+    syntax_directed_operations::BoundNames,
+};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{Atom, LineIndex, SourceType, Span, VALID_EXTENSIONS};
+use oxc_syntax::module_record::{ExportExportName, ExportLocalName};
+use rayon::prelude::*;
+use tower_lsp::lsp_types::{Location, Range, SymbolInformation, SymbolKind, Url};
+
+use crate::{
+    linter::offset_to_position,
+    options::LintOptions,
+    walk::{Extensions, Walk},
+};
+
+/// Most symbols returned for a query, the client asks again while the query is typed.
+const MAX_SYMBOLS: usize = 256;
+
+#[derive(Debug, Clone)]
+struct ExportedSymbol {
+    name: String,
+    kind: SymbolKind,
+    range: Range,
+}
+
+/// Functions, classes and constants exported by the modules of the workspace, for
+/// `workspace/symbol`.
+///
+/// The index is built on the first query and kept up to date with the saved files.
+#[derive(Debug, Default)]
+pub struct SymbolIndex {
+    files: RwLock<Option<HashMap<PathBuf, Vec<ExportedSymbol>>>>,
+}
+
+impl SymbolIndex {
+    /// Symbols whose name contains the characters of `query` in order, ignoring case.
+    #[allow(deprecated)] // `SymbolInformation::deprecated`
+    pub fn search(&self, root: &Path, query: &str) -> Vec<SymbolInformation> {
+        if self.files.read().unwrap().is_none() {
+            let files = Self::index_workspace(root);
+            self.files.write().unwrap().get_or_insert(files);
+        }
+        let files = self.files.read().unwrap();
+        let Some(files) = files.as_ref() else { return vec![] };
+
+        let query = query.to_lowercase();
+        let mut symbols = files
+            .iter()
+            .flat_map(|(path, symbols)| symbols.iter().map(move |symbol| (path, symbol)))
+            .filter(|(_, symbol)| is_fuzzy_match(&symbol.name, &query))
+            .collect::<Vec<_>>();
+        // Shorter names are closer matches
+        symbols.sort_unstable_by(|(a_path, a), (b_path, b)| {
+            (a.name.len(), &a.name, a_path).cmp(&(b.name.len(), &b.name, b_path))
+        });
+        symbols
+            .into_iter()
+            .take(MAX_SYMBOLS)
+            .filter_map(|(path, symbol)| {
+                Some(SymbolInformation {
+                    name: symbol.name.clone(),
+                    kind: symbol.kind,
+                    tags: None,
+                    deprecated: None,
+                    location: Location {
+                        uri: Url::from_file_path(path).ok()?,
+                        range: symbol.range,
+                    },
+                    container_name: path.file_name().map(|name| name.to_string_lossy().to_string()),
+                })
+            })
+            .collect()
+    }
+
+    /// Index the saved file again, unless the index has not been built yet.
+    pub fn update(&self, path: &Path) {
+        if let Some(files) = self.files.write().unwrap().as_mut() {
+            match Self::index_file(path) {
+                Some(symbols) => files.insert(path.to_path_buf(), symbols),
+                None => files.remove(path),
+            };
+        }
+    }
+
+    fn index_workspace(root: &Path) -> HashMap<PathBuf, Vec<ExportedSymbol>> {
+        let options = LintOptions {
+            paths: vec![root.to_path_buf()],
+            ignore_path: "node_modules".into(),
+            ignore_pattern: vec!["!**/node_modules/**/*".into()],
+            ..LintOptions::default()
+        };
+        Walk::new(&options)
+            .with_extensions(Extensions(VALID_EXTENSIONS.to_vec()))
+            .iter()
+            .par_bridge()
+            .filter_map(|path| Self::index_file(&path).map(|symbols| (path.to_path_buf(), symbols)))
+            .collect()
+    }
+
+    /// Exported symbols of the module record of the file at `path`.
+    fn index_file(path: &Path) -> Option<Vec<ExportedSymbol>> {
+        let source_type = SourceType::from_path(path).ok()?;
+        let source_text = fs::read_to_string(path).ok()?;
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        if ret.panicked {
+            return None;
+        }
+        let program = allocator.alloc(ret.program);
+        let module_record = SemanticBuilder::new(&source_text, source_type)
+            .build_module_record(path.to_path_buf(), program)
+            .module_record();
+
+        let declarations = top_level_declarations(&program.body);
+        let line_index = LineIndex::new(&source_text);
+        let symbols = module_record
+            .local_export_entries
+            .iter()
+            .filter_map(|entry| {
+                let ExportLocalName::Name(local_name) = &entry.local_name else { return None };
+                let (kind, span) = declarations.get(local_name.name())?;
+                // `export { foo as bar }` is found as `bar`
+                let name = match &entry.export_name {
+                    ExportExportName::Name(export_name) => export_name.name(),
+                    _ => local_name.name(),
+                };
+                let range = Range::new(
+                    offset_to_position(span.start as usize, &source_text, &line_index),
+                    offset_to_position(span.end as usize, &source_text, &line_index),
+                );
+                Some(ExportedSymbol { name: name.to_string(), kind: *kind, range })
+            })
+            .collect::<Vec<_>>();
+        (!symbols.is_empty()).then_some(symbols)
+    }
+}
+
+/// Functions, classes and constants declared at the top level, by name.
+fn top_level_declarations(body: &[Statement]) -> HashMap<Atom, (SymbolKind, Span)> {
+    let mut declarations = HashMap::new();
+    for statement in body {
+        match statement {
+            Statement::Declaration(declaration) => add_declaration(&mut declarations, declaration),
+            Statement::ModuleDeclaration(module_declaration) => match &**module_declaration {
+                ModuleDeclaration::ExportNamedDeclaration(export) => {
+                    if let Some(declaration) = &export.declaration {
+                        add_declaration(&mut declarations, declaration);
+                    }
+                }
+                ModuleDeclaration::ExportDefaultDeclaration(export) => {
+                    let (id, kind) = match &export.declaration {
+                        ExportDefaultDeclarationKind::FunctionDeclaration(function) => {
+                            (&function.id, SymbolKind::FUNCTION)
+                        }
+                        ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                            (&class.id, SymbolKind::CLASS)
+                        }
+                        _ => continue,
+                    };
+                    if let Some(id) = id {
+                        declarations.insert(id.name.clone(), (kind, id.span));
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    declarations
+}
+
+fn add_declaration(
+    declarations: &mut HashMap<Atom, (SymbolKind, Span)>,
+    declaration: &Declaration,
+) {
+    match declaration {
+        Declaration::FunctionDeclaration(function) => {
+            if let Some(id) = &function.id {
+                declarations.insert(id.name.clone(), (SymbolKind::FUNCTION, id.span));
+            }
+        }
+        Declaration::ClassDeclaration(class) => {
+            if let Some(id) = &class.id {
+                declarations.insert(id.name.clone(), (SymbolKind::CLASS, id.span));
+            }
+        }
+        Declaration::VariableDeclaration(variable) if variable.kind.is_const() => {
+            for declarator in &variable.declarations {
+                declarator.id.bound_names(&mut |id| {
+                    declarations.insert(id.name.clone(), (SymbolKind::CONSTANT, id.span));
+                });
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `query` is lowercase.
+fn is_fuzzy_match(name: &str, query: &str) -> bool {
+    let mut name = name.chars().flat_map(char::to_lowercase);
+    query.chars().all(|c| name.any(|n| n == c))
+}
@@ -0,0 +1,96 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+use globset::{Glob, GlobSet};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use tower_lsp::lsp_types::Url;
+
+use crate::{imports::ImportGraph, linter::ServerLinter, symbols::SymbolIndex};
+
+/// A folder of the workspace, with its own ignore files, linter and indexes, so that each folder
+/// of a multi-root workspace is linted as if it was opened on its own.
+#[derive(Debug)]
+pub struct WorkspaceRoot {
+    pub uri: Url,
+    pub path: PathBuf,
+    /// Rebuilt when a config file of the folder changes
+    server_linter: RwLock<ServerLinter>,
+    /// `.gitignore` and `.eslintignore` files of the folder
+    gitignore_glob: Option<Gitignore>,
+    pub symbol_index: SymbolIndex,
+    pub import_graph: ImportGraph,
+}
+
+impl WorkspaceRoot {
+    /// `None` for folders which are not on the file system.
+    pub fn new(uri: Url) -> Option<Self> {
+        let path = uri.to_file_path().ok()?;
+        let server_linter = RwLock::new(Self::linter(&uri, &path));
+        let gitignore_glob = Self::ignore_glob(&path);
+        Some(Self {
+            uri,
+            path,
+            server_linter,
+            gitignore_glob,
+            symbol_index: SymbolIndex::default(),
+            import_graph: ImportGraph::default(),
+        })
+    }
+
+    fn linter(uri: &Url, path: &Path) -> ServerLinter {
+        let server_linter = ServerLinter::from_root(path);
+        server_linter.make_plugin(uri);
+        server_linter
+    }
+
+    pub fn server_linter(&self) -> ServerLinter {
+        self.server_linter.read().unwrap().clone()
+    }
+
+    /// Rebuild the linter from the config files of the folder, the files being linted keep the
+    /// previous one.
+    pub fn reload_linter(&self) {
+        *self.server_linter.write().unwrap() = Self::linter(&self.uri, &self.path);
+    }
+
+    fn ignore_glob(root: &Path) -> Option<Gitignore> {
+        let mut builder = globset::GlobSetBuilder::new();
+        // Collecting all ignore files
+        builder.add(Glob::new("**/.eslintignore").unwrap());
+        builder.add(Glob::new("**/.gitignore").unwrap());
+
+        let ignore_file_glob_set = builder.build().unwrap();
+
+        let mut gitignore_builder = GitignoreBuilder::new(root);
+        let walk =
+            ignore::WalkBuilder::new(root).ignore(true).hidden(false).git_global(false).build();
+        for entry in walk.flatten() {
+            if ignore_file_glob_set.is_match(entry.path()) {
+                gitignore_builder.add(entry.path());
+            }
+        }
+        gitignore_builder.build().ok()
+    }
+
+    pub fn contains(&self, path: &Path) -> bool {
+        path.starts_with(&self.path)
+    }
+
+    /// Whether `path` in the folder is ignored by the ignore files or by `exclude_glob`, the
+    /// globs of [crate::Options::exclude] relative to the folder.
+    pub fn is_ignored(&self, path: &Path, exclude_glob: Option<&GlobSet>) -> bool {
+        if let Some(exclude_glob) = exclude_glob {
+            let relative_path = path.strip_prefix(&self.path).unwrap_or(path);
+            if relative_path
+                .ancestors()
+                .any(|path| !path.as_os_str().is_empty() && exclude_glob.is_match(path))
+            {
+                return true;
+            }
+        }
+        let Some(gitignore_glob) = &self.gitignore_glob else { return false };
+        gitignore_glob.matched_path_or_any_parents(path, path.is_dir()).is_ignore()
+    }
+}
@@ -0,0 +1,4 @@
+import data from './data.json';
+import { shared } from './shared.js';
+
+export const b = shared(data);
@@ -0,0 +1,3 @@
+export function shared(value) {
+  return value;
+}
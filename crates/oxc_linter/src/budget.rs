@@ -0,0 +1,153 @@
+//! Per-file budget, so that a single pathological file (a 20MB bundle, deeply nested generated
+//! code) cannot dominate a run or exhaust the memory of a worker.
+
+use std::time::{Duration, Instant};
+
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FileBudget {
+    /// Files with more bytes are linted with the correctness rules only.
+    pub reduced_rules_size: Option<usize>,
+    /// Linting a file, from parsing to the last rule, stops after this duration and the file is
+    /// reported as skipped.
+    pub time: Option<Duration>,
+}
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Linted with the correctness rules only: the file size of {0} bytes exceeds the limit of {1} bytes")]
+#[diagnostic(severity(advice))]
+pub struct ReducedRulesAdvice(pub usize, pub usize);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Skipped: linting took longer than the budget of {0} ms")]
+#[diagnostic(severity(advice))]
+pub struct FileSkippedAdvice(pub u128);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Slow rule: {0} took {1} ms on this file, exceeding the timing threshold of {2} ms")]
+#[diagnostic(severity(warning))]
+pub struct SlowRuleWarning(pub &'static str, pub u128, pub u128);
+
+/// Number of AST nodes between two checks of the deadline
+const DEADLINE_CHECK_INTERVAL: usize = 1024;
+
+pub(crate) struct Deadline(Option<Instant>);
+
+impl Deadline {
+    pub fn new(budget: &FileBudget) -> Self {
+        Self(budget.time.map(|time| Instant::now() + time))
+    }
+
+    pub fn exceeded(&self) -> bool {
+        self.0.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Move the deadline by `time` spent outside of the file, e.g. on the files it imports.
+    pub fn postpone(&mut self, time: Duration) {
+        if let Some(deadline) = &mut self.0 {
+            *deadline += time;
+        }
+    }
+
+    /// Reading the clock for every node would slow down the common case.
+    pub fn exceeded_at(&self, node_index: usize) -> bool {
+        node_index % DEADLINE_CHECK_INTERVAL == 0 && self.exceeded()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        path::PathBuf,
+        sync::{mpsc, Arc},
+        time::Duration,
+    };
+
+    use super::{Deadline, FileBudget};
+    use crate::{AllowWarnDeny, LintOptions, LintService, Linter, MemoryFileSystem};
+
+    fn lint(file_budget: FileBudget) -> Vec<String> {
+        lint_with_options(LintOptions::default().with_file_budget(file_budget)).0
+    }
+
+    fn lint_with_options(options: LintOptions) -> (Vec<String>, LintService) {
+        let filter = vec![
+            (AllowWarnDeny::Deny, "correctness".to_string()),
+            (AllowWarnDeny::Deny, "no-console".to_string()),
+        ];
+        let options = options.with_filter(filter);
+        let linter = Linter::from_options(options).unwrap();
+        let path = PathBuf::from("/virtual/a.js");
+        let source_text = "debugger;\nconsole.log(a);".to_string();
+        let file_system = Arc::new(MemoryFileSystem::new([(path.clone(), source_text)]));
+        let cwd = PathBuf::from("/virtual").into_boxed_path();
+        let service =
+            LintService::with_file_system(cwd, &[path.into_boxed_path()], linter, file_system);
+
+        let (tx_error, rx_error) = mpsc::channel();
+        service.run(&tx_error);
+        let mut messages = rx_error
+            .into_iter()
+            .map_while(std::convert::identity)
+            .flat_map(|(_, errors)| errors)
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+        messages.sort();
+        (messages, service)
+    }
+
+    #[test]
+    fn unlimited() {
+        let messages = lint(FileBudget::default());
+        assert_eq!(messages.len(), 2);
+        assert!(messages.iter().any(|message| message.contains("no-console")));
+    }
+
+    #[test]
+    fn reduced_rules() {
+        let messages = lint(FileBudget { reduced_rules_size: Some(10), time: None });
+        assert_eq!(messages.len(), 2);
+        assert!(messages.iter().all(|message| !message.contains("no-console")));
+        assert!(messages.iter().any(|message| message.starts_with("Linted with the correctness")));
+    }
+
+    #[test]
+    fn skipped() {
+        let messages = lint(FileBudget { reduced_rules_size: None, time: Some(Duration::ZERO) });
+        assert_eq!(messages, vec!["Skipped: linting took longer than the budget of 0 ms"]);
+    }
+
+    #[test]
+    fn deadline() {
+        let mut deadline = Deadline::new(&FileBudget { reduced_rules_size: None, time: None });
+        assert!(!deadline.exceeded());
+        deadline =
+            Deadline::new(&FileBudget { reduced_rules_size: None, time: Some(Duration::ZERO) });
+        assert!(deadline.exceeded());
+        assert!(!deadline.exceeded_at(1));
+        deadline.postpone(Duration::from_secs(3600));
+        assert!(!deadline.exceeded());
+    }
+
+    #[test]
+    fn timing_threshold() {
+        let options = LintOptions::default().with_timing_threshold(Some(Duration::ZERO));
+        let (messages, _) = lint_with_options(options);
+        assert!(messages.iter().any(|message| message.starts_with("Slow rule: no-console took")));
+    }
+
+    #[test]
+    fn profile() {
+        let (_, service) = lint_with_options(LintOptions::default().with_profile(true));
+        let mut profile = vec![];
+        service.linter().write_profile(&mut profile).unwrap();
+        let profile = String::from_utf8(profile).unwrap();
+        let line = profile.lines().find(|line| line.contains("no-debugger")).unwrap();
+        assert!(line.starts_with("oxlint;virtual;a.js;no-debugger "));
+        assert!(line.rsplit(' ').next().unwrap().parse::<u128>().is_ok());
+    }
+}
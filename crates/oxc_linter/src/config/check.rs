@@ -0,0 +1,219 @@
+//! Validation of a config file for `oxlint check-config`.
+//!
+//! Unlike loading a config, which stops at the first error and ignores what it does not know,
+//! every rule of the config is checked and all problems are reported:
+//!
+//! * rules which do not exist, with the closest rule name as a suggestion
+//! * severities and rule options which the rules do not accept
+//! * the same rule configured twice with different values, e.g. as `no-console` and
+//!   `eslint/no-console`, in two objects of a flat config with the same `files` and `ignores`,
+//!   or in two `overrides` of an `.eslintrc` with the same `files` and `excludedFiles`
+
+use std::path::Path;
+
+use oxc_diagnostics::{miette::NamedSource, Error};
+use oxc_parser::json::{JsonMode, JsonParser, JsonValue};
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+use super::{
+    errors::{
+        ConflictingRuleConfigError, InvalidRuleOptionsError, InvalidRuleValueError,
+        UnknownRuleError,
+    },
+    parse_rule_name, read_config_file, resolve_rule_value, to_serde_value, ESLintConfig,
+    FlatConfig,
+};
+use crate::{rules::RULES, AllowWarnDeny};
+
+/// A rule configured by a `rules` object
+struct ConfiguredRule {
+    key: String,
+    span: Span,
+    value: (AllowWarnDeny, Option<Value>),
+}
+
+/// All problems of the config file at `path`, empty if the config is valid.
+pub fn check_config(path: &Path) -> Vec<Error> {
+    let Ok(source_text) = std::fs::read_to_string(path) else {
+        return read_config_file(path).err().into_iter().collect();
+    };
+    let Ok(json) = JsonParser::new(&source_text, JsonMode::Jsonc).parse() else {
+        return read_config_file(path).err().into_iter().collect();
+    };
+
+    let mut errors = vec![];
+    // Objects of a flat config with the same `files` and `ignores`, and `overrides` of an
+    // `.eslintrc` with the same `files` and `excludedFiles`, apply to the same files
+    // `Value` is not `Hash`, the patterns are keyed by their JSON text
+    let mut groups = FxHashMap::<(String, String), FxHashMap<_, ConfiguredRule>>::default();
+    let mut check_group = |object: &JsonValue, ignores: &str, errors: &mut Vec<Error>| {
+        let files = object.get("files").map_or(Value::Null, to_serde_value).to_string();
+        let ignores = object.get(ignores).map_or(Value::Null, to_serde_value).to_string();
+        check_rules(object, groups.entry((files, ignores)).or_default(), errors);
+    };
+    if let Some(objects) = json.as_array() {
+        for object in objects {
+            check_group(object, "ignores", &mut errors);
+        }
+    } else {
+        check_group(&json, "excludedFiles", &mut errors);
+        let overrides = json.get("overrides").and_then(JsonValue::as_array).into_iter().flatten();
+        for object in overrides {
+            check_group(object, "excludedFiles", &mut errors);
+        }
+    }
+
+    // Problems outside of `rules`, e.g. in `extends` or in patterns
+    if errors.is_empty() {
+        let loaded = if FlatConfig::is_flat_config(path) {
+            FlatConfig::new(path).err()
+        } else {
+            ESLintConfig::new(&path.to_path_buf()).err()
+        };
+        return loaded.into_iter().collect();
+    }
+
+    errors
+        .into_iter()
+        .map(|error| {
+            error.with_source_code(NamedSource::new(path.to_string_lossy(), source_text.clone()))
+        })
+        .collect()
+}
+
+fn check_rules(
+    config: &JsonValue,
+    configured: &mut FxHashMap<(&'static str, &'static str), ConfiguredRule>,
+    errors: &mut Vec<Error>,
+) {
+    let Some(rules) = config.get("rules").and_then(JsonValue::as_object) else { return };
+    for rule in rules {
+        let key = rule.key.to_string();
+        let (plugin_name, rule_name) = parse_rule_name(&key);
+        let Some(known) = RULES
+            .iter()
+            .find(|known| known.plugin_name() == plugin_name && known.name() == rule_name)
+        else {
+            errors.push(UnknownRuleError(key.clone(), rule.key_span, suggest(&key)).into());
+            continue;
+        };
+
+        let value = match resolve_rule_value(&to_serde_value(&rule.value)) {
+            Ok(value) => value,
+            Err(error) => {
+                errors.push(InvalidRuleValueError(key, error.to_string(), rule.value.span).into());
+                continue;
+            }
+        };
+        if let Some(options) = &value.1 {
+            if let Err(message) = known.validate_json(options) {
+                errors.push(InvalidRuleOptionsError(key, message, rule.value.span).into());
+                continue;
+            }
+        }
+
+        let id = (known.plugin_name(), known.name());
+        match configured.get(&id) {
+            Some(previous) if previous.value != value => {
+                errors.push(
+                    ConflictingRuleConfigError(
+                        previous.key.clone(),
+                        key.clone(),
+                        previous.span,
+                        rule.value.span,
+                    )
+                    .into(),
+                );
+            }
+            Some(_) => {}
+            None => {
+                configured.insert(id, ConfiguredRule { key, span: rule.value.span, value });
+            }
+        }
+    }
+}
+
+/// The name of the known rule closest to `key`, as it would be written in a config
+fn suggest(key: &str) -> Option<String> {
+    let (plugin_name, rule_name) = parse_rule_name(key);
+    RULES
+        .iter()
+        .map(|rule| {
+            let distance = if rule.plugin_name() == plugin_name {
+                levenshtein(rule.name(), rule_name)
+            } else if rule.name() == rule_name {
+                // The rule exists, in another plugin
+                1
+            } else {
+                levenshtein(rule.name(), rule_name) + 2
+            };
+            (distance, rule)
+        })
+        .filter(|(distance, _)| *distance <= rule_name.len() / 3 + 1)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, rule)| {
+            let name = match rule.plugin_name() {
+                "eslint" => rule.name().to_string(),
+                plugin_name => format!("{plugin_name}/{}", rule.name()),
+            };
+            format!("Did you mean {name:?}?")
+        })
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != *b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::{check_config, levenshtein};
+    use std::env;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("no-console", "no-console"), 0);
+        assert_eq!(levenshtein("no-consle", "no-console"), 1);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_check_config() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/check_config/.eslintrc.json");
+        let messages = check_config(&fixture_path)
+            .iter()
+            .map(|error| {
+                let help = error.help().map(|help| format!(" ({help})")).unwrap_or_default();
+                format!("{error}{help}")
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                r#"Unknown rule "no-consle" (Did you mean "no-console"?)"#,
+                r#"Invalid value for rule "no-debugger": Failed to parse rule severity, expected one of "allow", "off", "deny", "error" or "warn", but got "on""#,
+                r#"Invalid options for rule "no-empty": expected "allowEmptyCatch" to be a boolean"#,
+                r#"Rule "eqeqeq" is configured differently by "eslint/eqeqeq" (Only one of the values applies, remove the other)"#,
+                r#"Invalid options for rule "curly": expected "all", "multi", "multi-line" or "multi-or-nest", but got 42"#,
+                r#"Invalid options for rule "yoda": expected "exceptRange" to be a boolean"#,
+                r#"Rule "no-console" is configured differently by "no-console" (Only one of the values applies, remove the other)"#,
+            ]
+        );
+
+        let fixture_path =
+            env::current_dir().unwrap().join("fixtures/flat_config/oxlint.config.json");
+        assert!(check_config(&fixture_path).is_empty());
+    }
+}
@@ -1,8 +1,9 @@
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
-    thiserror::Error,
+    thiserror::{self, Error},
     Report,
 };
+use oxc_span::Span;
 use std::path::PathBuf;
 
 #[derive(Debug, Error, Diagnostic)]
@@ -25,6 +26,11 @@ pub struct FailedToParseConfigPropertyError(pub &'static str, pub &'static str);
 #[diagnostic()]
 pub struct FailedToParseRuleValueError(pub String, pub &'static str);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid value for rule {0:?}: {1}")]
+#[diagnostic()]
+pub struct InvalidRuleValueError(pub String, pub String, #[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error(r#"Failed to parse rule severity, expected one of "allow", "off", "deny", "error" or "warn", but got {0:?}"#)]
 #[diagnostic()]
@@ -39,3 +45,38 @@ pub struct FailedToParseAllowWarnDenyFromNumberError(pub String);
 #[error(r#"Failed to parse rule severity, expected a string or a number, but got {0:?}"#)]
 #[diagnostic()]
 pub struct FailedToParseAllowWarnDenyFromJsonValueError(pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to resolve {0:?} extended by config {1:?}: {2}")]
+#[diagnostic(help("Shareable configs need to be installed in a node_modules directory next to or above the config"))]
+pub struct FailedToResolveExtendsError(pub String, pub PathBuf, pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unknown preset {0:?}")]
+#[diagnostic(help("Presets are oxlint:recommended, oxlint:strict and oxlint:all, optionally pinned to a release such as oxlint:recommended@0.0.22"))]
+pub struct UnknownPresetError(pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Config {0:?} extends itself")]
+#[diagnostic()]
+pub struct CircularExtendsError(pub PathBuf);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unknown rule {0:?}")]
+#[diagnostic()]
+pub struct UnknownRuleError(pub String, #[label] pub Span, #[help] pub Option<String>);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid options for rule {0:?}: {1}")]
+#[diagnostic()]
+pub struct InvalidRuleOptionsError(pub String, pub String, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Rule {0:?} is configured differently by {1:?}")]
+#[diagnostic(help("Only one of the values applies, remove the other"))]
+pub struct ConflictingRuleConfigError(
+    pub String,
+    pub String,
+    #[label("configured here")] pub Span,
+    #[label("and here")] pub Span,
+);
@@ -0,0 +1,417 @@
+//! Flat config, the array of config objects of ESLint's `eslint.config.js`, read from JSON files
+//! such as `oxlint.config.json`.
+//!
+//! ```json
+//! [
+//!   { "rules": { "no-debugger": "error" } },
+//!   { "files": ["**/*.ts"], "rules": { "eqeqeq": "warn" }, "languageOptions": { "globals": { "Deno": "readonly" } } },
+//!   { "ignores": ["dist/**"] }
+//! ]
+//! ```
+//!
+//! Every object whose `files` match a file and whose `ignores` do not applies to it, later objects
+//! override `rules`, `settings` and `languageOptions.globals` of earlier ones. An object with only
+//! `ignores` excludes files from linting altogether.
+//!
+//! `languageOptions.sourceType` and `languageOptions.parserOptions.ecmaFeatures.jsx` change how the
+//! matching files are parsed, e.g. `{ "files": ["**/*.cjs"], "languageOptions": { "sourceType":
+//! "commonjs" } }`. JSX is only enabled or disabled for JavaScript files.
+//!
+//! As the `globals` package cannot be imported from JSON, `env` selects the same presets as in
+//! `.eslintrc`, e.g. `{ "files": ["test/**"], "env": { "mocha": true } }`.
+//!
+//! Patterns of `files` and `ignores` are minimatch patterns relative to the directory of the
+//! config file, as in ESLint: `*.js` only matches JavaScript files next to the config while
+//! `**/*.js` matches them in subdirectories too, `*` does not match `/`, a pattern ending with `/`
+//! matches the files in a directory and a pattern starting with `!` excludes the files matched by
+//! the patterns before it.
+//!
+//! A rule value with `files` or `excludedFiles` becomes an object of its own, right after the
+//! object of the rule, so that a per path exception does not need an object in the config:
+//! `"no-console": { "severity": "error", "excludedFiles": ["scripts/**"] }`. An `.eslintrc`
+//! config with such rules is resolved per file as a flat config of one object, where a pattern
+//! without `/` matches file names in any directory as in the `overrides` of `.eslintrc`.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use globset::{GlobBuilder, GlobMatcher};
+use oxc_diagnostics::Report;
+use serde_json::Value;
+
+use super::{
+    errors::FailedToParseConfigError, invalid, is_scoped_rule_value, load_config_with_base,
+    merge_config, parse_presets, parse_rules, read_config_file, relative_path, split_scoped_rules,
+    ESLintConfig,
+};
+use crate::{rules::RuleEnum, LintSettings};
+
+#[derive(Debug)]
+struct FlatConfigEntry {
+    /// A file must match all of them, the `files` of the object and of a scoped rule
+    files: Vec<FilePatterns>,
+    /// A file matching any of them is excluded
+    ignores: Vec<FilePatterns>,
+    /// `rules`, `settings` and `languageOptions`
+    config: Value,
+}
+
+/// Rules and settings for the files matched by the same config objects
+#[derive(Debug)]
+pub struct ResolvedConfig {
+    pub rules: Vec<(&'static str, RuleEnum)>,
+    pub settings: LintSettings,
+}
+
+#[derive(Debug)]
+pub struct FlatConfig {
+    /// Absolute directory of the config file
+    root: PathBuf,
+    entries: Vec<FlatConfigEntry>,
+    global_ignores: Vec<FilePatterns>,
+    /// Keyed by the indices of the entries matching a file
+    resolved: DashMap<Vec<usize>, Arc<ResolvedConfig>>,
+}
+
+impl FlatConfig {
+    /// Whether the config file at `path` is a flat config, i.e. its root is an array.
+    pub fn is_flat_config(path: &Path) -> bool {
+        std::fs::read_to_string(path).is_ok_and(|file| {
+            // Skip the comments before the root value
+            let mut rest = file.trim_start();
+            loop {
+                if let Some(comment) = rest.strip_prefix("//") {
+                    rest = comment.split_once('\n').map_or("", |(_, rest)| rest).trim_start();
+                } else if let Some(comment) = rest.strip_prefix("/*") {
+                    rest = comment.split_once("*/").map_or("", |(_, rest)| rest).trim_start();
+                } else {
+                    return rest.starts_with('[');
+                }
+            }
+        })
+    }
+
+    /// # Errors
+    ///
+    /// Returns `Err` if the config file cannot be read, is not an array of objects or has invalid
+    /// rule values or patterns.
+    pub fn new(path: &Path) -> Result<Self, Report> {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let root = path.parent().map_or_else(PathBuf::new, Path::to_path_buf);
+        let Value::Array(objects) = read_config_file(&path)? else {
+            return Err(invalid("", "Expected an array of config objects."));
+        };
+        Self::from_objects(root, objects, false)
+    }
+
+    /// The `.eslintrc` config at `path` over the config at `base` as a flat config, when it has
+    /// rules with `files` or `excludedFiles`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the config cannot be read or has invalid rule values or patterns.
+    pub fn from_eslintrc(path: &PathBuf, base: Option<&PathBuf>) -> Result<Option<Self>, Report> {
+        let config = load_config_with_base(path, base)?;
+        let rules = config.get("rules").and_then(Value::as_object);
+        if !rules.is_some_and(|rules| rules.values().any(is_scoped_rule_value)) {
+            return Ok(None);
+        }
+        let path = path.canonicalize().unwrap_or_else(|_| path.clone());
+        let root = path.parent().map_or_else(PathBuf::new, Path::to_path_buf);
+        Self::from_objects(root, vec![config], true).map(Some)
+    }
+
+    /// `match_base` matches the patterns without `/` against file names, for `.eslintrc` configs.
+    fn from_objects(root: PathBuf, objects: Vec<Value>, match_base: bool) -> Result<Self, Report> {
+        let patterns =
+            |property: &'static str, value: &Value| FilePatterns::new(property, value, match_base);
+        let mut entries = vec![];
+        let mut global_ignores = vec![];
+        for object in objects {
+            let Value::Object(mut object) = object else {
+                return Err(invalid("", "Expected an array of config objects."));
+            };
+            let files =
+                object.remove("files").map(|files| patterns("files", &files)).transpose()?;
+            let ignores = object
+                .remove("ignores")
+                .map(|ignores| patterns("ignores", &ignores))
+                .transpose()?;
+            if object.is_empty() && files.is_none() {
+                global_ignores.extend(ignores);
+                continue;
+            }
+            let scoped_rules = split_scoped_rules(&mut object);
+            let config = Value::Object(object);
+            parse_rules(&config).map_err(|error| FailedToParseConfigError(vec![error]))?;
+            parse_presets(&config)?;
+            let (files, ignores) = (Vec::from_iter(files), Vec::from_iter(ignores));
+            let mut scoped_entries = vec![];
+            for scoped_rule in scoped_rules {
+                let Value::Object(mut scoped_rule) = scoped_rule else { continue };
+                let rule_files = scoped_rule
+                    .remove("files")
+                    .map(|files| patterns("files", &files))
+                    .transpose()?;
+                let excluded_files = scoped_rule
+                    .remove("ignores")
+                    .map(|ignores| patterns("excludedFiles", &ignores))
+                    .transpose()?;
+                let config = Value::Object(scoped_rule);
+                parse_rules(&config).map_err(|error| FailedToParseConfigError(vec![error]))?;
+                scoped_entries.push(FlatConfigEntry {
+                    files: files.iter().cloned().chain(rule_files).collect(),
+                    ignores: ignores.iter().cloned().chain(excluded_files).collect(),
+                    config,
+                });
+            }
+            entries.push(FlatConfigEntry { files, ignores, config });
+            entries.extend(scoped_entries);
+        }
+
+        Ok(Self { root, entries, global_ignores, resolved: DashMap::default() })
+    }
+
+    /// Excluded by an object with only `ignores`.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        let Some(path) = relative_path(&self.root, path) else { return false };
+        let path = FilePatterns::normalize(&path);
+        self.global_ignores.iter().any(|ignores| ignores.matches(&path))
+    }
+
+    /// Rules and settings of the config objects matching `path`.
+    pub fn config_for(&self, path: &Path) -> Arc<ResolvedConfig> {
+        let path = relative_path(&self.root, path).map(|path| FilePatterns::normalize(&path));
+        // Patterns never match files outside of the directory of the config
+        let matches =
+            |patterns: &FilePatterns| path.as_ref().is_some_and(|path| patterns.matches(path));
+        let indices = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| {
+                entry.files.iter().all(matches) && !entry.ignores.iter().any(matches)
+            })
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        if let Some(resolved) = self.resolved.get(&indices) {
+            return Arc::clone(&resolved);
+        }
+        let resolved = Arc::new(self.resolve(indices.iter().map(|index| &self.entries[*index])));
+        self.resolved.insert(indices, Arc::clone(&resolved));
+        resolved
+    }
+
+    /// Rules enabled by any config object, for listing the rules of the config.
+    pub fn all_rules(&self) -> Vec<RuleEnum> {
+        let mut rules = self
+            .entries
+            .iter()
+            .flat_map(|entry| self.resolve(std::iter::once(entry)).rules)
+            .map(|(_, rule)| rule)
+            .collect::<Vec<_>>();
+        rules.sort_unstable_by_key(RuleEnum::name);
+        rules.dedup_by_key(|rule| rule.name());
+        rules
+    }
+
+    fn resolve<'e, I>(&self, entries: I) -> ResolvedConfig
+    where
+        I: Iterator<Item = &'e FlatConfigEntry>,
+    {
+        let mut config = Value::Object(serde_json::Map::new());
+        for entry in entries {
+            merge_config(&mut config, entry.config.clone());
+        }
+        // The rule values were checked when loading the config.
+        let (rules, settings) = ESLintConfig::from_value(&config)
+            .map_or_else(|_| (vec![], LintSettings::default()), ESLintConfig::get_config);
+        ResolvedConfig {
+            rules: rules.into_iter().map(|rule| (rule.name(), rule)).collect(),
+            settings,
+        }
+    }
+}
+
+/// `files` or `ignores` of a config object, matched against paths relative to the directory of
+/// the config with `/` separators.
+#[derive(Debug, Clone)]
+struct FilePatterns {
+    patterns: Vec<FilePattern>,
+}
+
+#[derive(Debug, Clone)]
+struct FilePattern {
+    matcher: GlobMatcher,
+    /// `!pattern`, excludes the files matched by the patterns before it
+    negated: bool,
+    /// `dir/`, matches the files in the directory
+    directory: bool,
+}
+
+impl FilePatterns {
+    fn new(property: &'static str, value: &Value, match_base: bool) -> Result<Self, Report> {
+        let Some(values) = value.as_array() else {
+            return Err(invalid(property, "Expected an array of patterns."));
+        };
+        let mut patterns = vec![];
+        for pattern in values {
+            let Some(pattern) = pattern.as_str() else {
+                return Err(invalid(property, "Expected an array of patterns."));
+            };
+            let (negated, pattern) =
+                pattern.strip_prefix('!').map_or((false, pattern), |pattern| (true, pattern));
+            let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+            let (directory, pattern) =
+                pattern.strip_suffix('/').map_or((false, pattern), |pattern| (true, pattern));
+            let pattern = if match_base && !pattern.contains('/') {
+                format!("**/{pattern}")
+            } else {
+                pattern.to_string()
+            };
+            let glob = GlobBuilder::new(&pattern)
+                .literal_separator(true)
+                .build()
+                .map_err(|_| invalid(property, "Invalid pattern."))?;
+            patterns.push(FilePattern { matcher: glob.compile_matcher(), negated, directory });
+        }
+        Ok(Self { patterns })
+    }
+
+    /// `path` with `/` separators, the form the patterns are matched against
+    fn normalize(path: &Path) -> String {
+        path.components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Whether the last pattern matching `path` is not negated.
+    fn matches(&self, path: &str) -> bool {
+        let mut matched = false;
+        for pattern in &self.patterns {
+            let is_match = if pattern.directory {
+                // The directories containing the file
+                path.match_indices('/').any(|(index, _)| pattern.matcher.is_match(&path[..index]))
+            } else {
+                pattern.matcher.is_match(path)
+            };
+            if is_match {
+                matched = !pattern.negated;
+            }
+        }
+        matched
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, path::Path};
+
+    use oxc_span::SourceType;
+    use serde_json::json;
+
+    use super::{FilePatterns, FlatConfig};
+
+    #[test]
+    fn config_per_file() {
+        let fixture_path =
+            env::current_dir().unwrap().join("fixtures/flat_config/oxlint.config.json");
+        assert!(FlatConfig::is_flat_config(&fixture_path));
+        let config = FlatConfig::new(&fixture_path).unwrap();
+        let root = fixture_path.parent().unwrap();
+        let rule_names = |path: &str| {
+            let resolved = config.config_for(&root.join(path));
+            let mut names = resolved.rules.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+            names.sort_unstable();
+            names
+        };
+
+        assert_eq!(rule_names("src/a.js"), vec!["no-debugger"]);
+        assert_eq!(rule_names("src/a.ts"), vec!["eqeqeq"]);
+        assert_eq!(rule_names("test/a.js"), vec!["no-debugger", "no-empty"]);
+        assert_eq!(rule_names("test/generated/a.js"), vec!["no-debugger"]);
+
+        let resolved = config.config_for(&root.join("src/a.ts"));
+        assert!(resolved.settings.globals.contains("Deno"));
+        assert!(!config.config_for(&root.join("src/a.js")).settings.globals.contains("Deno"));
+
+        let source_type = |path: &str| {
+            let path = root.join(path);
+            config.config_for(&path).settings.parser.apply(SourceType::from_path(&path).unwrap())
+        };
+        assert!(source_type("src/a.js").is_module());
+        assert!(source_type("src/a.js").is_jsx());
+        assert!(source_type("src/a.cjs").is_script());
+        assert!(!source_type("legacy/a.js").is_jsx());
+        // TypeScript files keep the JSX of their extension
+        assert!(source_type("legacy/a.tsx").is_jsx());
+        // The globals of the earlier object are kept
+        assert!(config.config_for(&root.join("legacy/a.ts")).settings.globals.contains("Deno"));
+
+        assert!(config.is_ignored(&root.join("dist/a.js")));
+        assert!(!config.is_ignored(&root.join("src/a.js")));
+
+        let all_rules = config.all_rules();
+        let names = all_rules.iter().map(crate::RuleEnum::name).collect::<Vec<_>>();
+        assert_eq!(names, vec!["eqeqeq", "no-debugger", "no-empty"]);
+    }
+
+    #[test]
+    fn scoped_rules() {
+        let fixture_path =
+            env::current_dir().unwrap().join("fixtures/flat_config/scoped_rules.json");
+        let config = FlatConfig::from_eslintrc(&fixture_path, None).unwrap().unwrap();
+        let root = fixture_path.parent().unwrap();
+        let rule_names = |path: &str| {
+            let resolved = config.config_for(&root.join(path));
+            let mut names = resolved.rules.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+            names.sort_unstable();
+            names
+        };
+
+        assert_eq!(rule_names("src/a.js"), vec!["eqeqeq", "no-console", "no-debugger"]);
+        assert_eq!(rule_names("test/a.js"), vec!["no-console", "no-debugger"]);
+        assert_eq!(rule_names("scripts/a.js"), vec!["no-debugger"]);
+
+        let eslintrc_path = env::current_dir().unwrap().join("fixtures/eslint_config.json");
+        assert!(FlatConfig::from_eslintrc(&eslintrc_path, None).unwrap().is_none());
+    }
+
+    #[test]
+    fn eslintrc_is_not_flat() {
+        let fixture_path = Path::new("fixtures/eslint_config.json");
+        assert!(!FlatConfig::is_flat_config(fixture_path));
+    }
+
+    #[test]
+    fn minimatch_patterns() {
+        let matches = |patterns: serde_json::Value, path: &str| {
+            FilePatterns::new("files", &patterns, false).unwrap().matches(path)
+        };
+        assert!(matches(json!(["*.js"]), "a.js"));
+        assert!(!matches(json!(["*.js"]), "src/a.js"));
+        assert!(matches(json!(["**/*.js"]), "src/a.js"));
+        assert!(matches(json!(["**/*.js"]), "a.js"));
+        assert!(!matches(json!(["src/*.js"]), "src/lib/a.js"));
+        assert!(matches(json!(["src/**/*.{js,ts}"]), "src/lib/a.ts"));
+        assert!(matches(json!(["./src/*.js"]), "src/a.js"));
+        assert!(matches(json!([".config/*.js"]), ".config/a.js"));
+        // Patterns ending with `/` match the files in a directory
+        assert!(matches(json!(["dist/"]), "dist/a.js"));
+        assert!(matches(json!(["**/generated/"]), "src/generated/a.js"));
+        assert!(!matches(json!(["dist/"]), "src/dist.js"));
+        // Negated patterns exclude the files matched before them
+        assert!(!matches(json!(["dist/**", "!dist/keep.js"]), "dist/keep.js"));
+        assert!(matches(json!(["dist/**", "!dist/keep.js"]), "dist/other.js"));
+
+        // The `overrides` of `.eslintrc` match file names in any directory
+        let base = FilePatterns::new("files", &json!(["*.test.js"]), true).unwrap();
+        assert!(base.matches("src/a.test.js"));
+        assert!(FilePatterns::new("files", &json!("*.js"), false).is_err());
+        assert!(FilePatterns::new("files", &json!(["a/[b"]), false).is_err());
+    }
+}
@@ -0,0 +1,270 @@
+//! Configuration comments, which configure rules for the file they are written in:
+//!
+//! ```javascript
+//! /* eslint no-console: "off", eqeqeq: ["error", "smart"] */
+//! ```
+//!
+//! `oxlint` can be written instead of `eslint`. As in a config file, a severity other than "off"
+//! enables the rule, and the options replace the configured options of the rule. Later comments
+//! override earlier ones.
+
+use oxc_ast::TriviasMap;
+use oxc_diagnostics::Error;
+use oxc_span::Span;
+use serde_json::Value;
+
+use super::{
+    errors::{InvalidRuleOptionsError, InvalidRuleValueError, UnknownRuleError},
+    parse_rule_name, resolve_rule_value,
+};
+use crate::{
+    rules::{RuleEnum, RULES},
+    AllowWarnDeny,
+};
+
+/// A rule configured by a comment
+struct InlineRule {
+    rule: &'static RuleEnum,
+    severity: AllowWarnDeny,
+    options: Option<Value>,
+}
+
+pub struct InlineConfig {
+    /// Rules configured by the comments, in order
+    rules: Vec<InlineRule>,
+    /// Unknown rules and invalid values, reported at their location in the file
+    errors: Vec<Error>,
+}
+
+impl InlineConfig {
+    pub fn new(source_text: &str, trivias: &TriviasMap) -> Self {
+        let mut config = Self { rules: vec![], errors: vec![] };
+        for (comment, span) in trivias.comments_spans() {
+            // Only block comments, as in ESLint
+            if !comment.is_multi_line() {
+                continue;
+            }
+            let text = span.source_text(source_text);
+            let trimmed = text.trim_start();
+            let Some(entries) =
+                trimmed.strip_prefix("eslint").or_else(|| trimmed.strip_prefix("oxlint"))
+            else {
+                continue;
+            };
+            // e.g. `eslint-disable` and `eslint-env`
+            if !entries.starts_with(char::is_whitespace) {
+                continue;
+            }
+            #[allow(clippy::cast_possible_truncation)]
+            let start = span.end - entries.len() as u32;
+            // `-- description`
+            let entries = entries.split(" -- ").next().unwrap_or_default();
+            for (offset, entry) in split_entries(entries) {
+                config.parse_entry(entry, start + offset);
+            }
+        }
+        config
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// `rules` with the rules of the comments enabled, configured or disabled.
+    pub fn apply(&self, rules: &[(&'static str, RuleEnum)]) -> Vec<(&'static str, RuleEnum)> {
+        let mut rules = rules.to_vec();
+        for inline_rule in &self.rules {
+            let index = rules.iter().position(|(_, rule)| {
+                rule.plugin_name() == inline_rule.rule.plugin_name()
+                    && rule.name() == inline_rule.rule.name()
+            });
+            let options = inline_rule.options.clone();
+            match (inline_rule.severity.is_enabled(), index) {
+                (false, Some(index)) => {
+                    rules.remove(index);
+                }
+                (true, Some(index)) if options.is_some() => {
+                    rules[index].1 = inline_rule.rule.read_json(options);
+                }
+                (true, None) => {
+                    rules.push((inline_rule.rule.name(), inline_rule.rule.read_json(options)));
+                }
+                _ => {}
+            }
+        }
+        rules
+    }
+
+    pub fn take_errors(&mut self) -> Vec<Error> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// `rule-name: value`, starting at `start` in the file
+    #[allow(clippy::cast_possible_truncation)]
+    fn parse_entry(&mut self, entry: &str, start: u32) {
+        let span = Span::new(start, start + entry.len() as u32);
+        let Some((key, value)) = entry.split_once(':') else {
+            let error = InvalidRuleValueError(
+                entry.to_string(),
+                "expected the name of the rule followed by `:` and its value".to_string(),
+                span,
+            );
+            self.errors.push(error.into());
+            return;
+        };
+        let key = key.trim().trim_matches(['"', '\'']);
+        let (plugin_name, rule_name) = parse_rule_name(key);
+        let Some(rule) =
+            RULES.iter().find(|rule| rule.plugin_name() == plugin_name && rule.name() == rule_name)
+        else {
+            self.errors.push(UnknownRuleError(key.to_string(), span, None).into());
+            return;
+        };
+        let resolved = parse_value(value.trim())
+            .and_then(|value| resolve_rule_value(&value).map_err(|error| error.to_string()));
+        let (severity, options) = match resolved {
+            Ok(resolved) => resolved,
+            Err(message) => {
+                self.errors.push(InvalidRuleValueError(key.to_string(), message, span).into());
+                return;
+            }
+        };
+        if let Some(options) = &options {
+            if let Err(message) = rule.validate_json(options) {
+                self.errors.push(InvalidRuleOptionsError(key.to_string(), message, span).into());
+                return;
+            }
+        }
+        self.rules.push(InlineRule { rule, severity, options });
+    }
+}
+
+/// The comma separated entries of a comment with their offsets, commas in arrays, objects and
+/// strings do not separate entries.
+fn split_entries(text: &str) -> Vec<(u32, &str)> {
+    let mut entries = vec![];
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut escaped = false;
+    let mut entry_start = 0;
+    for (index, c) in text.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '[' | '{' => depth += 1,
+            ']' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                entries.push((entry_start, &text[entry_start..index]));
+                entry_start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    entries.push((entry_start, &text[entry_start..]));
+    #[allow(clippy::cast_possible_truncation)]
+    entries
+        .into_iter()
+        .filter(|(_, entry)| !entry.trim().is_empty())
+        .map(|(start, entry)| {
+            let trimmed = entry.trim_start();
+            ((start + entry.len() - trimmed.len()) as u32, trimmed.trim_end())
+        })
+        .collect()
+}
+
+/// JSON, or a bare word or a single quoted string such as `off` or `'warn'`.
+fn parse_value(value: &str) -> Result<Value, String> {
+    if let Ok(value) = serde_json::from_str(value) {
+        return Ok(value);
+    }
+    if let Some(string) = value.strip_prefix('\'').and_then(|value| value.strip_suffix('\'')) {
+        return Ok(Value::String(string.to_string()));
+    }
+    if !value.is_empty() && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Ok(Value::String(value.to_string()));
+    }
+    Err(format!("{value} is not valid JSON"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        path::PathBuf,
+        sync::{mpsc, Arc},
+    };
+
+    use super::split_entries;
+    use crate::{AllowWarnDeny, LintOptions, LintService, Linter, MemoryFileSystem};
+
+    fn lint(source_text: &str) -> Vec<String> {
+        let filter = vec![
+            (AllowWarnDeny::Deny, "correctness".to_string()),
+            (AllowWarnDeny::Deny, "no-console".to_string()),
+        ];
+        let linter = Linter::from_options(LintOptions::default().with_filter(filter)).unwrap();
+        let path = PathBuf::from("/virtual/a.js");
+        let file_system =
+            Arc::new(MemoryFileSystem::new([(path.clone(), source_text.to_string())]));
+        let cwd = PathBuf::from("/virtual").into_boxed_path();
+        let service =
+            LintService::with_file_system(cwd, &[path.into_boxed_path()], linter, file_system);
+
+        let (tx_error, rx_error) = mpsc::channel();
+        service.run(&tx_error);
+        let mut messages = rx_error
+            .into_iter()
+            .map_while(std::convert::identity)
+            .flat_map(|(_, errors)| errors)
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+        messages.sort();
+        messages
+    }
+
+    #[test]
+    fn test_split_entries() {
+        assert_eq!(
+            split_entries(r#" no-console: "off", eqeqeq: ["error", "smart"] "#),
+            [(1, r#"no-console: "off""#), (20, r#"eqeqeq: ["error", "smart"]"#)]
+        );
+        assert!(split_entries("  ").is_empty());
+    }
+
+    #[test]
+    fn disable() {
+        let messages = lint("/* eslint no-console: \"off\" */\ndebugger;\nconsole.log(a);");
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("debugger"));
+
+        let messages =
+            lint("/* oxlint no-console: off, no-debugger: 0 */\ndebugger;\nconsole.log(a);");
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn enable() {
+        let messages = lint("/* eslint eqeqeq: [\"error\", \"smart\"] */\na == b;\na == null;");
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("eqeqeq"));
+    }
+
+    #[test]
+    fn errors() {
+        let messages = lint("/* eslint no-consle: \"off\", eqeqeq: \"on\" */");
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0].starts_with("Invalid value for rule \"eqeqeq\""));
+        assert_eq!(messages[1], "Unknown rule \"no-consle\"");
+
+        // Not configuration comments
+        assert!(lint("/* eslint-env node */\n// eslint no-consle: \"off\"").is_empty());
+    }
+}
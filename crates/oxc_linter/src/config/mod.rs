@@ -1,20 +1,34 @@
-use std::{collections::HashSet, path::PathBuf};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
 
+mod check;
 pub mod errors;
-use oxc_diagnostics::{Error, FailedToOpenFileError, Report};
+mod flat;
+mod inline;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use oxc_diagnostics::{miette::NamedSource, Error, FailedToOpenFileError, Report};
+use oxc_parser::json::{JsonMode, JsonParser, JsonValue, JsonValueKind};
+use oxc_resolver::{ResolveOptions, Resolver};
 use phf::{phf_map, Map};
-use rustc_hash::FxHashMap;
-use serde_json::Value;
+use regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::{Number, Value};
 
 use crate::{
+    globals,
+    presets::Preset,
     rules::{RuleEnum, RULES},
-    AllowWarnDeny, JsxA11y, LintSettings,
+    AllowWarnDeny, JsxA11y, LintSettings, ReactSettings,
 };
 
 use self::errors::{
-    FailedToParseConfigError, FailedToParseConfigJsonError, FailedToParseConfigPropertyError,
-    FailedToParseRuleValueError,
+    CircularExtendsError, FailedToParseConfigError, FailedToParseConfigJsonError,
+    FailedToParseConfigPropertyError, FailedToParseRuleValueError, FailedToResolveExtendsError,
+    InvalidRuleValueError, UnknownPresetError,
 };
+pub use self::{check::check_config, flat::FlatConfig, inline::InlineConfig};
 
 pub struct ESLintConfig {
     rules: std::vec::Vec<RuleEnum>,
@@ -23,42 +37,21 @@ pub struct ESLintConfig {
 
 impl ESLintConfig {
     pub fn new(path: &PathBuf) -> Result<Self, Report> {
-        let file = match std::fs::read_to_string(path) {
-            Ok(file) => file,
-            Err(e) => {
-                return Err(FailedToParseConfigError(vec![Error::new(FailedToOpenFileError(
-                    path.clone(),
-                    e,
-                ))])
-                .into());
-            }
-        };
+        Self::with_base(path, None)
+    }
 
-        let file = match serde_json::from_str::<serde_json::Value>(&file) {
-            Ok(file) => file,
-            Err(e) => {
-                let guess = mime_guess::from_path(path);
-                let err = match guess.first() {
-                    // syntax error
-                    Some(mime) if mime.subtype() == "json" => e.to_string(),
-                    Some(_) => "only json configuration is supported".to_string(),
-                    None => {
-                        format!(
-                            "{e}, if the configuration is not a json file, please use json instead."
-                        )
-                    }
-                };
-                return Err(FailedToParseConfigError(vec![Error::new(
-                    FailedToParseConfigJsonError(path.clone(), err),
-                )])
-                .into());
-            }
-        };
+    /// The config at `path` cascades from the config at `base`, e.g. the config of a workspace
+    /// package from the config of the workspace root.
+    pub fn with_base(path: &PathBuf, base: Option<&PathBuf>) -> Result<Self, Report> {
+        Self::from_value(&load_config_with_base(path, base)?)
+    }
 
+    /// Rules and settings of a config with its `extends` already merged.
+    pub(crate) fn from_value(file: &Value) -> Result<Self, Report> {
         // See https://github.com/oxc-project/oxc/issues/1672
         let extends_hm: HashSet<&str> = HashSet::new();
 
-        let roles_hm = match parse_rules(&file) {
+        let roles_hm = match parse_rules(file) {
             Ok(roles_hm) => roles_hm
                 .into_iter()
                 .map(|(plugin_name, rule_name, allow_warn_deny, config)| {
@@ -70,13 +63,16 @@ impl ESLintConfig {
             }
         };
 
-        let settings = parse_settings_from_root(&file);
+        let preset_rules = parse_presets(file)?;
+
+        let settings = parse_settings_from_root(file);
 
         // `extends` provides the defaults
         // `rules` provides the overrides
         let rules = RULES.clone().into_iter().filter_map(|rule| {
             // Check if the extends set is empty or contains the plugin name
-            let in_extends = extends_hm.contains(rule.plugin_name());
+            let in_extends =
+                extends_hm.contains(rule.plugin_name()) || preset_rules.contains(rule.name());
 
             // Check if there's a custom rule that explicitly handles this rule
             let (is_explicitly_handled, policy, config) =
@@ -110,6 +106,259 @@ impl ESLintConfig {
     }
 }
 
+/// `ignorePatterns` of the config, relative to the directory of the config file and with the
+/// syntax of `.eslintignore`. The patterns of the configs in `extends` are added to them, so the
+/// ignore policy can ship with a shared config.
+#[derive(Debug)]
+pub struct IgnorePatterns {
+    root: PathBuf,
+    patterns: Gitignore,
+}
+
+impl IgnorePatterns {
+    /// `None` without patterns.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` for an invalid pattern.
+    pub fn new(config_path: &Path, patterns: &[String]) -> Result<Option<Self>, Report> {
+        if patterns.is_empty() {
+            return Ok(None);
+        }
+        let config_path = config_path.canonicalize().unwrap_or_else(|_| config_path.to_path_buf());
+        let root = config_path.parent().map_or_else(PathBuf::new, Path::to_path_buf);
+        let patterns = self::patterns(&root, "ignorePatterns", &Value::from(patterns.to_vec()))?;
+        Ok(Some(Self { root, patterns }))
+    }
+
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        relative_path(&self.root, path)
+            .is_some_and(|path| self.patterns.matched_path_or_any_parents(path, false).is_ignore())
+    }
+}
+
+/// Read the config at `path` over the config at `base`, both with the configs they extend.
+fn load_config_with_base(path: &PathBuf, base: Option<&PathBuf>) -> Result<Value, Report> {
+    let mut file = load_config_file(path)?;
+    if let Some(base) = base {
+        let mut base = load_config_file(base)?;
+        merge_config(&mut base, file);
+        file = base;
+    }
+    Ok(file)
+}
+
+/// Read a config file and the configs it extends.
+fn load_config_file(path: &PathBuf) -> Result<Value, Report> {
+    let mut file = read_config_file(path)?;
+    let mut stack = vec![path.canonicalize().unwrap_or_else(|_| path.clone())];
+    merge_extends(path, &mut file, &mut stack)?;
+    Ok(file)
+}
+
+/// Read a JSON config file with comments, reporting invalid rule values in the file.
+fn read_config_file(path: &Path) -> Result<Value, Report> {
+    let file = match std::fs::read_to_string(path) {
+        Ok(file) => file,
+        Err(e) => {
+            return Err(FailedToParseConfigError(vec![Error::new(FailedToOpenFileError(
+                path.to_path_buf(),
+                e,
+            ))])
+            .into());
+        }
+    };
+
+    // Comments and trailing commas are allowed, as in `.eslintrc.json`
+    let json = match JsonParser::new(&file, JsonMode::Jsonc).parse() {
+        Ok(json) => json,
+        Err(e) => {
+            let guess = mime_guess::from_path(path);
+            let err = match guess.first() {
+                // syntax error
+                Some(mime) if mime.subtype() == "json" => {
+                    let source = NamedSource::new(path.to_string_lossy(), file.clone());
+                    return Err(FailedToParseConfigError(vec![e.with_source_code(source)]).into());
+                }
+                Some(_) => "only json configuration is supported".to_string(),
+                None => {
+                    format!(
+                        "{e}, if the configuration is not a json file, please use json instead."
+                    )
+                }
+            };
+            return Err(FailedToParseConfigError(vec![Error::new(FailedToParseConfigJsonError(
+                path.to_path_buf(),
+                err,
+            ))])
+            .into());
+        }
+    };
+
+    if let Err(error) = check_rule_values(&json) {
+        let source = NamedSource::new(path.to_string_lossy(), file.clone());
+        return Err(FailedToParseConfigError(vec![error.with_source_code(source)]).into());
+    }
+    Ok(to_serde_value(&json))
+}
+
+/// Merge the configs of the packages and files in `extends` below the config at `path`.
+///
+/// Entries are resolved from the directory of the config like `require` would, e.g.
+/// `@company/oxlint-config` or `./base.json`. Later entries override earlier ones and the config
+/// itself overrides all of them, per rule and per setting.
+fn merge_extends(path: &Path, config: &mut Value, stack: &mut Vec<PathBuf>) -> Result<(), Report> {
+    let specifiers = match config.get("extends") {
+        Some(Value::String(specifier)) => vec![specifier.clone()],
+        Some(Value::Array(values)) => {
+            values.iter().filter_map(Value::as_str).map(ToString::to_string).collect()
+        }
+        _ => vec![],
+    };
+    let specifiers = specifiers.into_iter().filter(|s| is_shareable_config(s)).collect::<Vec<_>>();
+    if specifiers.is_empty() {
+        return Ok(());
+    }
+
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let resolver = Resolver::new(ResolveOptions {
+        extensions: vec![".json".into()],
+        condition_names: vec!["require".into(), "default".into()],
+        ..ResolveOptions::default()
+    });
+    let mut merged = Value::Object(serde_json::Map::new());
+    for specifier in specifiers {
+        let extended_path = match resolver.resolve(dir, &specifier) {
+            Ok(resolution) => resolution.into_path_buf(),
+            Err(e) => {
+                let error =
+                    FailedToResolveExtendsError(specifier, path.to_path_buf(), e.to_string());
+                return Err(FailedToParseConfigError(vec![Error::new(error)]).into());
+            }
+        };
+        if stack.contains(&extended_path) {
+            let error = CircularExtendsError(extended_path);
+            return Err(FailedToParseConfigError(vec![Error::new(error)]).into());
+        }
+        let mut extended = read_config_file(&extended_path)?;
+        stack.push(extended_path.clone());
+        merge_extends(&extended_path, &mut extended, stack)?;
+        stack.pop();
+        merge_config(&mut merged, extended);
+    }
+    merge_config(&mut merged, std::mem::take(config));
+    *config = merged;
+    Ok(())
+}
+
+fn patterns(root: &Path, property: &'static str, value: &Value) -> Result<Gitignore, Report> {
+    let mut builder = GitignoreBuilder::new(root);
+    for pattern in value.as_array().into_iter().flatten() {
+        let Some(pattern) = pattern.as_str() else {
+            return Err(invalid(property, "Expected an array of patterns."));
+        };
+        if builder.add_line(None, pattern).is_err() {
+            return Err(invalid(property, "Invalid pattern."));
+        }
+    }
+    if !value.is_array() {
+        return Err(invalid(property, "Expected an array of patterns."));
+    }
+    builder.build().map_err(|_| invalid(property, "Invalid pattern."))
+}
+
+fn invalid(property: &'static str, message: &'static str) -> Report {
+    let error = FailedToParseConfigPropertyError(property, message);
+    FailedToParseConfigError(vec![Error::new(error)]).into()
+}
+
+/// `path` relative to `root`, `None` for files outside of it.
+fn relative_path(root: &Path, path: &Path) -> Option<PathBuf> {
+    let path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir().unwrap_or_default().join(path)
+    };
+    // `Path::components` drops the `.` of `./src/a.js`
+    let path = path.components().collect::<PathBuf>();
+    path.strip_prefix(root).ok().map(Path::to_path_buf)
+}
+
+/// `eslint:recommended`, `oxlint:*` and `plugin:*` entries select built in rules instead of
+/// config files.
+fn is_shareable_config(specifier: &str) -> bool {
+    !EXTENDS_MAP.contains_key(specifier)
+        && !specifier.starts_with("eslint:")
+        && !specifier.starts_with("oxlint:")
+        && !specifier.starts_with("plugin:")
+}
+
+/// Names of the rules of the `oxlint:*` presets in `extends`, see [Preset].
+fn parse_presets(config: &Value) -> Result<FxHashSet<&'static str>, Report> {
+    let specifiers = match config.get("extends") {
+        Some(Value::String(specifier)) => vec![specifier.as_str()],
+        Some(Value::Array(values)) => values.iter().filter_map(Value::as_str).collect(),
+        _ => vec![],
+    };
+    let mut rules = FxHashSet::default();
+    for specifier in specifiers {
+        match Preset::from_specifier(specifier) {
+            Some(Ok((preset, version))) => rules.extend(preset.rule_names(version)),
+            Some(Err(specifier)) => {
+                let error = Error::new(UnknownPresetError(specifier));
+                return Err(FailedToParseConfigError(vec![error]).into());
+            }
+            None => {}
+        }
+    }
+    Ok(rules)
+}
+
+fn merge_config(base: &mut Value, config: Value) {
+    let (Value::Object(base), Value::Object(config)) = (base, config) else { return };
+    for (key, value) in config {
+        // The patterns of all configs apply, and the presets of the extended configs
+        if key == "extends" {
+            let as_array = |value: &Value| match value {
+                Value::Array(values) => values.clone(),
+                value => vec![value.clone()],
+            };
+            if let Some(base_value) = base.get(&key) {
+                let mut extends = as_array(base_value);
+                extends.extend(as_array(&value));
+                base.insert(key, Value::Array(extends));
+                continue;
+            }
+        }
+        if key == "ignorePatterns" {
+            if let (Some(Value::Array(base_value)), Value::Array(value)) =
+                (base.get_mut(&key), &value)
+            {
+                base_value.extend(value.iter().cloned());
+                continue;
+            }
+        }
+        // A later object setting the source type keeps the globals of the earlier ones
+        if matches!(key.as_str(), "languageOptions" | "parserOptions" | "ecmaFeatures") {
+            if let Some(base_value @ Value::Object(_)) = base.get_mut(&key) {
+                if value.is_object() {
+                    merge_config(base_value, value);
+                    continue;
+                }
+            }
+        }
+        if matches!(key.as_str(), "rules" | "settings" | "env" | "globals" | "messages") {
+            if let (Some(Value::Object(base_value)), Value::Object(value)) =
+                (base.get_mut(&key), &value)
+            {
+                base_value.extend(value.clone());
+                continue;
+            }
+        }
+        base.insert(key, value);
+    }
+}
+
 #[allow(unused)]
 fn parse_extends(root_json: &Value) -> Result<Option<Vec<&'static str>>, Report> {
     let Some(extends) = root_json.get("extends") else {
@@ -152,6 +401,8 @@ fn parse_rules(
 
     rules_object
         .iter()
+        // Only for the files they match, see `split_scoped_rules`
+        .filter(|(_, value)| !is_scoped_rule_value(value))
         .map(|(key, value)| {
             let (plugin_name, name) = parse_rule_name(key);
 
@@ -162,40 +413,188 @@ fn parse_rules(
         .collect::<Result<Vec<_>, Error>>()
 }
 
+/// A rule value with `files` or `excludedFiles`, which only applies to the files matching them:
+///
+/// ```json
+/// { "no-console": { "severity": "error", "excludedFiles": ["scripts/**"] } }
+/// ```
+fn is_scoped_rule_value(value: &Value) -> bool {
+    value.get("files").is_some() || value.get("excludedFiles").is_some()
+}
+
+/// Move the scoped rule values out of the `rules` of `config`, into config objects of a flat
+/// config which only apply to the `files` of the rule and not to its `excludedFiles`.
+fn split_scoped_rules(config: &mut serde_json::Map<String, Value>) -> Vec<Value> {
+    let Some(Value::Object(rules)) = config.get_mut("rules") else { return vec![] };
+    let names = rules
+        .iter()
+        .filter(|(_, value)| is_scoped_rule_value(value))
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let Value::Object(mut value) = rules.remove(&name)? else { return None };
+            let mut scoped = serde_json::Map::new();
+            if let Some(files) = value.remove("files") {
+                scoped.insert("files".to_string(), files);
+            }
+            if let Some(excluded_files) = value.remove("excludedFiles") {
+                scoped.insert("ignores".to_string(), excluded_files);
+            }
+            let rule = serde_json::Map::from_iter([(name, Value::Object(value))]);
+            scoped.insert("rules".to_string(), Value::Object(rule));
+            Some(Value::Object(scoped))
+        })
+        .collect()
+}
+
+/// Report the first invalid rule value at its location in the config file.
+fn check_rule_values(root_json: &JsonValue) -> Result<(), Error> {
+    let Some(rules) = root_json.get("rules").and_then(JsonValue::as_object) else { return Ok(()) };
+    for rule in rules {
+        if let Err(error) = resolve_rule_value(&to_serde_value(&rule.value)) {
+            let key = rule.key.to_string();
+            return Err(InvalidRuleValueError(key, error.to_string(), rule.value.span).into());
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn to_serde_value(json: &JsonValue) -> Value {
+    match &json.kind {
+        JsonValueKind::Null => Value::Null,
+        JsonValueKind::Boolean(boolean) => Value::Bool(*boolean),
+        #[allow(clippy::cast_possible_truncation, clippy::float_cmp)]
+        JsonValueKind::Number(number) => {
+            if number.fract() == 0.0 && number.abs() < 2f64.powi(53) {
+                Value::Number(Number::from(*number as i64))
+            } else {
+                Number::from_f64(*number).map_or(Value::Null, Value::Number)
+            }
+        }
+        JsonValueKind::String(string) => Value::String(string.to_string()),
+        JsonValueKind::Array(elements) => {
+            Value::Array(elements.iter().map(to_serde_value).collect())
+        }
+        JsonValueKind::Object(properties) => Value::Object(
+            properties
+                .iter()
+                .map(|property| (property.key.to_string(), to_serde_value(&property.value)))
+                .collect(),
+        ),
+    }
+}
+
 fn parse_settings_from_root(root_json: &Value) -> LintSettings {
     let Value::Object(root_object) = root_json else { return LintSettings::default() };
 
-    let Some(settings_value) = root_object.get("settings") else { return LintSettings::default() };
+    let mut settings =
+        root_object.get("settings").map_or_else(LintSettings::default, parse_settings);
+
+    // `env` presets such as `browser` and `node`, overridden by `globals`
+    let env = root_object.get("env").and_then(Value::as_object).into_iter().flatten();
+    for (name, _) in env.filter(|(_, enabled)| enabled.as_bool() == Some(true)) {
+        if let Some(globals) = globals::environment(name) {
+            settings.globals.extend(globals.keys().map(ToString::to_string));
+        }
+    }
+
+    // `globals` of `.eslintrc` and `languageOptions.globals` of flat config
+    let globals = root_object
+        .get("globals")
+        .into_iter()
+        .chain(root_object.get("languageOptions").and_then(|options| options.get("globals")));
+    for globals in globals.filter_map(Value::as_object) {
+        for (name, value) in globals {
+            if value.as_str() == Some("off") {
+                settings.globals.remove(name);
+            } else {
+                settings.globals.insert(name.clone());
+            }
+        }
+    }
+
+    // `parserOptions` of `.eslintrc`, `languageOptions` and its `parserOptions` of flat config
+    let language_options = root_object.get("languageOptions");
+    let parser_options = root_object
+        .get("parserOptions")
+        .into_iter()
+        .chain(language_options)
+        .chain(language_options.and_then(|options| options.get("parserOptions")));
+    for parser_options in parser_options {
+        settings.parser.merge(parser_options);
+    }
+
+    // A single pattern is accepted as in `.eslintrc`
+    match root_object.get("ignorePatterns") {
+        Some(Value::String(pattern)) => settings.ignore_patterns = vec![pattern.clone()],
+        Some(Value::Array(patterns)) => {
+            let patterns = patterns.iter().filter_map(Value::as_str).map(ToString::to_string);
+            settings.ignore_patterns = patterns.collect();
+        }
+        _ => {}
+    }
 
-    parse_settings(settings_value)
+    // Keyed by rule name as in `rules`, e.g. `eslint/no-console` or `no-console`
+    let messages = root_object.get("messages").and_then(Value::as_object).into_iter().flatten();
+    for (key, template) in messages {
+        if let Some(template) = template.as_str() {
+            let (_, name) = parse_rule_name(key);
+            settings.messages.insert(name.to_string(), template.to_string());
+        }
+    }
+
+    settings
 }
 
 pub fn parse_settings(setting_value: &Value) -> LintSettings {
-    if let Value::Object(settings_object) = setting_value {
-        if let Some(Value::Object(jsx_a11y)) = settings_object.get("jsx-a11y") {
-            let mut jsx_a11y_setting =
-                JsxA11y { polymorphic_prop_name: None, components: FxHashMap::default() };
-
-            if let Some(Value::Object(components)) = jsx_a11y.get("components") {
-                let components_map: FxHashMap<String, String> = components
-                    .iter()
-                    .map(|(key, value)| (String::from(key), String::from(value.as_str().unwrap())))
-                    .collect();
-
-                jsx_a11y_setting.set_components(components_map);
-            }
+    let mut settings = LintSettings::default();
+    let Value::Object(settings_object) = setting_value else { return settings };
 
-            if let Some(Value::String(polymorphic_prop_name)) = jsx_a11y.get("polymorphicPropName")
-            {
-                jsx_a11y_setting
-                    .set_polymorphic_prop_name(Some(String::from(polymorphic_prop_name)));
-            }
+    if let Some(Value::Object(jsx_a11y)) = settings_object.get("jsx-a11y") {
+        let mut jsx_a11y_setting =
+            JsxA11y { polymorphic_prop_name: None, components: FxHashMap::default() };
+
+        if let Some(Value::Object(components)) = jsx_a11y.get("components") {
+            let components_map: FxHashMap<String, String> = components
+                .iter()
+                .map(|(key, value)| (String::from(key), String::from(value.as_str().unwrap())))
+                .collect();
 
-            return LintSettings { jsx_a11y: jsx_a11y_setting };
+            jsx_a11y_setting.set_components(components_map);
         }
+
+        if let Some(Value::String(polymorphic_prop_name)) = jsx_a11y.get("polymorphicPropName") {
+            jsx_a11y_setting.set_polymorphic_prop_name(Some(String::from(polymorphic_prop_name)));
+        }
+
+        settings.jsx_a11y = jsx_a11y_setting;
     }
 
-    LintSettings::default()
+    if let Some(react) = settings_object.get("react") {
+        settings.react = ReactSettings::from_value(react);
+    }
+
+    if let Some(Value::String(internal_regex)) = settings_object.get("import/internal-regex") {
+        settings.import.set_internal_regex(Regex::new(internal_regex).ok());
+    }
+
+    // The options of all resolvers, as in `{ "typescript": { "conditionNames": ["worker"] } }`
+    let resolvers = settings_object.get("import/resolver").and_then(Value::as_object);
+    for options in resolvers.into_iter().flat_map(|resolvers| resolvers.values()) {
+        let strings = |key: &str| {
+            options.get(key).and_then(Value::as_array).map(|values| {
+                values.iter().filter_map(Value::as_str).map(ToString::to_string).collect()
+            })
+        };
+        let resolver = &mut settings.import.resolver;
+        resolver.browser |= options.get("browser").and_then(Value::as_bool).unwrap_or_default();
+        resolver.condition_names = strings("conditionNames").or(resolver.condition_names.take());
+        resolver.main_fields = strings("mainFields").or(resolver.main_fields.take());
+    }
+
+    settings
 }
 
 pub const EXTENDS_MAP: Map<&'static str, &'static str> = phf_map! {
@@ -230,17 +629,39 @@ fn parse_rule_name(name: &str) -> (&str, &str) {
 /// {
 ///     "rule": "off",
 ///     "rule": ["off", "config"],
-///     "rule": ["off", "config1", "config2"],
+///     "rule": ["off", "config1", "config2", ...],
 /// }
 /// ```
+///
+/// All options after the severity are passed to the rule, e.g. the module names of
+/// `"no-restricted-imports": ["error", "fs", "os", "path"]`.
+///
+/// The object form takes the same options in `options`, next to the `files` and `excludedFiles`
+/// of a scoped rule: `{ "severity": "error", "options": [{ "allow": ["warn"] }] }`.
 fn resolve_rule_value(value: &serde_json::Value) -> Result<(AllowWarnDeny, Option<Value>), Error> {
     if let Some(v) = value.as_str() {
         return Ok((AllowWarnDeny::try_from(v)?, None));
     }
 
+    if let Some(object) = value.as_object() {
+        let Some(severity) = object.get("severity") else {
+            return Err(FailedToParseRuleValueError(value.to_string(), "Missing severity").into());
+        };
+        let config = match object.get("options") {
+            None => None,
+            Some(Value::Array(options)) if options.is_empty() => None,
+            Some(options @ Value::Array(_)) => Some(options.clone()),
+            Some(_) => {
+                let message = "Expected an array of options";
+                return Err(FailedToParseRuleValueError(value.to_string(), message).into());
+            }
+        };
+        return Ok((AllowWarnDeny::try_from(severity)?, config));
+    }
+
     if let Some(v) = value.as_array() {
         let mut config = Vec::new();
-        for item in v.iter().skip(1).take(2) {
+        for item in v.iter().skip(1) {
             config.push(item.clone());
         }
         let config = if config.is_empty() { None } else { Some(Value::Array(config)) };
@@ -254,8 +675,13 @@ fn resolve_rule_value(value: &serde_json::Value) -> Result<(AllowWarnDeny, Optio
 
 #[cfg(test)]
 mod test {
-    use super::parse_rules;
-    use std::env;
+    use super::{
+        check_rule_values, merge_config, parse_rules, parse_settings_from_root, ESLintConfig,
+        IgnorePatterns, RuleEnum,
+    };
+    use oxc_parser::json::{JsonMode, JsonParser};
+    use oxc_resolver::ResolveOptions;
+    use std::{env, path::Path};
 
     #[test]
     fn test_parse_rules() {
@@ -265,4 +691,113 @@ mod test {
         let rules = parse_rules(&file).unwrap();
         insta::assert_debug_snapshot!(rules);
     }
+
+    #[test]
+    fn test_invalid_rule_value_span() {
+        let source = r#"{ "rules": { /* comment */ "no-debugger": "invalid", } }"#;
+        let json = JsonParser::new(source, JsonMode::Jsonc).parse().unwrap();
+        let error = check_rule_values(&json).unwrap_err();
+        let label = error.labels().unwrap().next().unwrap();
+        assert_eq!(&source[label.offset()..label.offset() + label.len()], r#""invalid""#);
+    }
+
+    #[test]
+    fn test_extends_shareable_config() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/.eslintrc.json");
+        let (rules, _) = ESLintConfig::new(&fixture_path).unwrap().into_rules().get_config();
+        let names = rules.iter().map(|rule| rule.name()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["eqeqeq", "no-empty"]);
+
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/circular.json");
+        let error = ESLintConfig::new(&fixture_path).err().unwrap();
+        assert!(format!("{}", error.related().unwrap().next().unwrap()).contains("extends itself"));
+
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/missing.json");
+        assert!(ESLintConfig::new(&fixture_path).is_err());
+    }
+
+    #[test]
+    fn test_env_presets() {
+        let config = serde_json::json!({
+            "env": { "browser": true, "mocha": true, "es2024": true, "node": false },
+            "globals": { "window": "off" }
+        });
+        let settings = parse_settings_from_root(&config);
+        assert!(settings.globals.contains("document"));
+        assert!(settings.globals.contains("describe"));
+        assert!(settings.globals.contains("Promise"));
+        assert!(!settings.globals.contains("process"));
+        assert!(!settings.globals.contains("window"));
+    }
+
+    #[test]
+    fn test_rule_presets() {
+        let config = serde_json::json!({
+            "extends": ["oxlint:recommended"],
+            "rules": { "no-debugger": "off", "eqeqeq": "warn" }
+        });
+        let (rules, _) = ESLintConfig::from_value(&config).unwrap().get_config();
+        let names = rules.iter().map(RuleEnum::name).collect::<Vec<_>>();
+        assert!(names.contains(&"for-direction"));
+        assert!(names.contains(&"eqeqeq"));
+        assert!(!names.contains(&"no-debugger"));
+
+        let config = serde_json::json!({ "extends": "oxlint:recommended@0.0.22" });
+        assert!(ESLintConfig::from_value(&config).is_ok());
+        let config = serde_json::json!({ "extends": ["oxlint:everything"] });
+        assert!(ESLintConfig::from_value(&config).is_err());
+    }
+
+    #[test]
+    fn test_resolver_settings() {
+        let config = serde_json::json!({
+            "settings": {
+                "import/resolver": {
+                    "node": { "browser": true },
+                    "typescript": { "conditionNames": ["development", "import"] }
+                }
+            }
+        });
+        let settings = parse_settings_from_root(&config);
+        let resolver = settings.resolver();
+        assert!(resolver.browser);
+        assert_eq!(
+            resolver.condition_names,
+            Some(vec!["development".to_string(), "import".to_string()])
+        );
+        assert_eq!(resolver.main_fields, None);
+
+        let options = resolver.resolve_options(ResolveOptions::default());
+        assert_eq!(options.condition_names, ["browser", "development", "import"]);
+        assert_eq!(options.alias_fields, [["browser"]]);
+        assert_eq!(options.main_fields.first().map(String::as_str), Some("browser"));
+    }
+
+    #[test]
+    fn test_messages() {
+        let config = serde_json::json!({
+            "messages": { "no-console": "{message} See the style guide.", "unicorn/no-null": 1 }
+        });
+        let mut base = serde_json::json!({ "messages": { "eslint/eqeqeq": "{message}" } });
+        merge_config(&mut base, config);
+        let settings = parse_settings_from_root(&base);
+        assert_eq!(settings.messages.len(), 2);
+        assert_eq!(settings.messages["no-console"], "{message} See the style guide.");
+        assert_eq!(settings.messages["eqeqeq"], "{message}");
+    }
+
+    #[test]
+    fn test_ignore_patterns() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/.eslintrc.json");
+        let (_, settings) = ESLintConfig::new(&fixture_path).unwrap().get_config();
+        assert_eq!(settings.ignore_patterns, vec!["dist/**", "coverage/**"]);
+
+        let patterns = IgnorePatterns::new(&fixture_path, &settings.ignore_patterns).unwrap();
+        let patterns = patterns.unwrap();
+        let root = fixture_path.parent().unwrap();
+        assert!(patterns.is_ignored(&root.join("dist/a.js")));
+        assert!(patterns.is_ignored(&root.join("coverage/lcov/a.js")));
+        assert!(!patterns.is_ignored(&root.join("src/a.js")));
+        assert!(!patterns.is_ignored(Path::new("/other/dist/a.js")));
+    }
 }
@@ -1,13 +1,22 @@
-use std::{cell::RefCell, path::Path, rc::Rc};
+use std::{
+    cell::{OnceCell, RefCell},
+    path::Path,
+    rc::Rc,
+};
 
 use oxc_diagnostics::Error;
 use oxc_formatter::{Formatter, FormatterOptions};
 use oxc_semantic::{AstNodes, JSDocComment, ScopeTree, Semantic, SymbolTable};
-use oxc_span::SourceType;
+use oxc_span::{SourceType, Span};
+use rustc_hash::FxHashMap;
 
 use crate::{
-    disable_directives::{DisableDirectives, DisableDirectivesBuilder},
+    custom_message::CustomMessage,
+    disable_directives::{DisableDirectives, DisableDirectivesBuilder, SuppressedAdvice},
     fixer::{Fix, Message},
+    globals::BUILTINS,
+    metrics::Metrics,
+    rule::FixKind,
     AstNode, LintSettings,
 };
 
@@ -21,11 +30,31 @@ pub struct LintContext<'a> {
     /// Whether or not to apply code fixes during linting.
     fix: bool,
 
+    /// The least safe kind of fix to apply
+    fix_kind: FixKind,
+
+    /// Report diagnostics silenced by disable directives as [SuppressedAdvice].
+    show_suppressed: bool,
+
     current_rule_name: &'static str,
 
+    current_rule_fix: FixKind,
+
+    /// Rules whose diagnostics are silenced with disable comments instead of being fixed.
+    fix_suppress: Vec<&'static str>,
+
+    /// Indices of the diagnostics to silence with a disable comment, with their rule name.
+    suppressions: RefCell<Vec<(usize, &'static str)>>,
+
     file_path: Box<Path>,
 
     settings: LintSettings,
+
+    /// Indentation, quotes and line endings of the code of fixes
+    formatter_options: FormatterOptions,
+
+    /// Metrics of the functions, computed for the first rule using them
+    metrics: OnceCell<Metrics>,
 }
 
 impl<'a> LintContext<'a> {
@@ -37,9 +66,16 @@ impl<'a> LintContext<'a> {
             diagnostics: RefCell::new(vec![]),
             disable_directives,
             fix: false,
+            fix_kind: FixKind::Safe,
+            show_suppressed: false,
             current_rule_name: "",
+            current_rule_fix: FixKind::None,
+            fix_suppress: vec![],
+            suppressions: RefCell::new(vec![]),
             file_path,
             settings,
+            formatter_options: FormatterOptions::default(),
+            metrics: OnceCell::new(),
         }
     }
 
@@ -49,6 +85,24 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    #[must_use]
+    pub fn with_fix_kind(mut self, fix_kind: FixKind) -> Self {
+        self.fix_kind = fix_kind;
+        self
+    }
+
+    #[must_use]
+    pub fn with_formatter_options(mut self, options: FormatterOptions) -> Self {
+        self.formatter_options = options;
+        self
+    }
+
+    #[must_use]
+    pub fn with_show_suppressed(mut self, yes: bool) -> Self {
+        self.show_suppressed = yes;
+        self
+    }
+
     pub fn semantic(&self) -> &Rc<Semantic<'a>> {
         &self.semantic
     }
@@ -61,6 +115,19 @@ impl<'a> LintContext<'a> {
         self.settings.clone()
     }
 
+    /// Whether `name` is a builtin of the language or a global of the environment, i.e. of the
+    /// `env` presets and `globals` of the config, so unresolved references to it are defined.
+    pub fn is_defined_global(&self, name: &str) -> bool {
+        BUILTINS.contains_key(name) || self.settings.globals.contains(name)
+    }
+
+    /// Complexity and nesting of the functions of the file, and its lines
+    pub fn metrics(&self) -> &Metrics {
+        self.metrics.get_or_init(|| {
+            Metrics::new(self.nodes(), self.source_text(), self.semantic().trivias())
+        })
+    }
+
     pub fn source_text(&self) -> &'a str {
         self.semantic().source_text()
     }
@@ -78,15 +145,72 @@ impl<'a> LintContext<'a> {
         self.current_rule_name = name;
     }
 
+    /// Name of the rule being run, empty before the first rule.
+    pub fn current_rule_name(&self) -> &'static str {
+        self.current_rule_name
+    }
+
+    #[inline]
+    pub fn with_rule_fix(&mut self, fix: FixKind) {
+        self.current_rule_fix = fix;
+    }
+
+    /// Silence the diagnostics of `rules` with disable comments when fixing.
+    pub fn with_fix_suppress(&mut self, rules: Vec<&'static str>) {
+        self.fix_suppress = rules;
+    }
+
     /* Diagnostics */
 
+    #[allow(clippy::cast_possible_truncation)] // for `as u32`
     pub fn into_message(self) -> Vec<Message<'a>> {
-        self.diagnostics.into_inner()
+        let mut messages = self.diagnostics.into_inner();
+        let suppressions = self.suppressions.into_inner();
+        if suppressions.is_empty() {
+            return messages;
+        }
+
+        // A single comment disables all rules reported on the same line.
+        let source_text = self.semantic.source_text();
+        let line_start = |message: &Message| {
+            let start = message.start() as usize;
+            source_text[..start].rfind('\n').map_or(0, |index| index + 1)
+        };
+        let mut rules_by_line = FxHashMap::<usize, Vec<&str>>::default();
+        for (index, rule_name) in &suppressions {
+            let rules = rules_by_line.entry(line_start(&messages[*index])).or_default();
+            if !rules.contains(rule_name) {
+                rules.push(*rule_name);
+            }
+        }
+        let newline = if source_text.contains("\r\n") { "\r\n" } else { "\n" };
+        for (index, _) in suppressions {
+            let start = line_start(&messages[index]);
+            let line = &source_text[start..];
+            let indent = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+            let rules = rules_by_line[&start].join(", ");
+            let comment = format!("{indent}// eslint-disable-next-line {rules} -- TODO{newline}");
+            let span = Span::new(start as u32, start as u32);
+            messages[index].fix = Some(Fix::new(comment, span));
+        }
+        messages
     }
 
-    fn add_diagnostic(&self, message: Message<'a>) {
+    fn add_diagnostic(&self, mut message: Message<'a>) {
+        if let Some(template) = self.settings.messages.get(self.current_rule_name) {
+            let error = CustomMessage::new(template, self.current_rule_name, message.error);
+            message.error = error.into();
+        }
         if !self.disable_directives.contains(self.current_rule_name, message.start()) {
-            self.diagnostics.borrow_mut().push(message);
+            let mut diagnostics = self.diagnostics.borrow_mut();
+            if self.fix && self.fix_suppress.contains(&self.current_rule_name) {
+                self.suppressions.borrow_mut().push((diagnostics.len(), self.current_rule_name));
+            }
+            diagnostics.push(message);
+        } else if self.show_suppressed {
+            let advice =
+                SuppressedAdvice { rule_name: self.current_rule_name, error: message.error };
+            self.diagnostics.borrow_mut().push(Message::new(advice.into(), None));
         }
     }
 
@@ -94,12 +218,16 @@ impl<'a> LintContext<'a> {
         self.add_diagnostic(Message::new(diagnostic.into(), None));
     }
 
+    /// The fix is only kept when the fix kind declared by the rule is allowed.
     pub fn diagnostic_with_fix<T, F>(&self, diagnostic: T, fix: F)
     where
         T: Into<Error>,
         F: FnOnce() -> Fix<'a>,
     {
-        if self.fix {
+        if self.fix
+            && self.current_rule_fix != FixKind::None
+            && self.current_rule_fix <= self.fix_kind
+        {
             self.add_diagnostic(Message::new(diagnostic.into(), Some(fix())));
         } else {
             self.diagnostic(diagnostic);
@@ -118,9 +246,22 @@ impl<'a> LintContext<'a> {
         self.semantic().symbols()
     }
 
-    #[allow(clippy::unused_self)]
+    /// Formatter of the code of fixes, following the `.editorconfig` of the file
     pub fn formatter(&self) -> Formatter {
-        Formatter::new(0, FormatterOptions::default())
+        Formatter::new(0, self.formatter_options.clone())
+    }
+
+    pub fn formatter_options(&self) -> &FormatterOptions {
+        &self.formatter_options
+    }
+
+    /// Quote of the string literals written by fixes
+    pub fn quote(&self) -> char {
+        if self.formatter_options.single_quote {
+            '\''
+        } else {
+            '"'
+        }
     }
 
     /* JSDoc */
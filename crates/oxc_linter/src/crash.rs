@@ -0,0 +1,201 @@
+//! Panics of the linter on a file, reported as internal errors instead of aborting the run.
+//!
+//! With `crash_report`, a report of each panic is appended to a file for bug reports, with the
+//! rule, the panic message and its location, a backtrace and the source of the file reduced to
+//! the lines which still make the rule panic.
+
+use std::{
+    any::Any,
+    backtrace::Backtrace,
+    cell::{Cell, RefCell},
+    fmt::Write as _,
+    fs::OpenOptions,
+    io::{self, Write},
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::Once,
+};
+
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+
+/// Most runs of the rule while minimizing the source of a crash report
+const MAX_MINIMIZE_ATTEMPTS: usize = 500;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Internal error{}: {message}", rule_name.map(|name| format!(" in {name}")).unwrap_or_default())]
+#[diagnostic(
+    severity(error),
+    help("The file was not linted further. This is a bug, please report it with the crash report of `--crash-report <PATH>`.")
+)]
+pub struct InternalError {
+    pub rule_name: Option<&'static str>,
+    pub message: String,
+}
+
+/// Reported with the internal error, the CLI prints it with the diagnostics of the file
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to write crash report {0}: {1}")]
+#[diagnostic(severity(warning))]
+pub struct CrashReportError(pub String, pub String);
+
+impl CrashReportError {
+    pub fn new(report_path: &Path, error: &io::Error) -> Self {
+        Self(report_path.display().to_string(), error.to_string())
+    }
+}
+
+/// A caught panic
+#[derive(Debug)]
+pub struct Panic {
+    pub message: String,
+    /// `file:line:column` of the panic
+    pub location: Option<String>,
+    pub backtrace: String,
+}
+
+impl Panic {
+    pub fn into_internal_error(self, rule_name: Option<&'static str>) -> InternalError {
+        let message = match &self.location {
+            Some(location) => format!("{} at {location}", self.message),
+            None => self.message,
+        };
+        InternalError { rule_name: rule_name.filter(|name| !name.is_empty()), message }
+    }
+}
+
+thread_local! {
+    /// Whether a panic on this thread is caught by [catch_panic]
+    static CATCHING: Cell<bool> = Cell::new(false);
+    /// Location and backtrace of the last caught panic, recorded by the panic hook
+    static LAST_PANIC: RefCell<Option<(Option<String>, String)>> = RefCell::new(None);
+}
+
+/// Run `f`, returning the panic instead of unwinding further.
+///
+/// Caught panics are not printed, other panics are printed by the previous panic hook.
+///
+/// # Errors
+///
+/// Returns `Err` if `f` panicked.
+pub fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, Panic> {
+    static INSTALL_HOOK: Once = Once::new();
+    INSTALL_HOOK.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) {
+                let location = info.location().map(ToString::to_string);
+                let backtrace = Backtrace::force_capture().to_string();
+                LAST_PANIC.with(|last| *last.borrow_mut() = Some((location, backtrace)));
+            } else {
+                previous_hook(info);
+            }
+        }));
+    });
+
+    let was_catching = CATCHING.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(was_catching));
+    result.map_err(|payload| {
+        let (location, backtrace) =
+            LAST_PANIC.with(|last| last.borrow_mut().take()).unwrap_or_default();
+        Panic { message: panic_message(payload.as_ref()), location, backtrace }
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".to_string())
+}
+
+/// Remove chunks of lines from `source_text`, from halves down to single lines, as long as
+/// `crashes` still holds for the rest.
+pub fn minimize(source_text: &str, crashes: impl Fn(&str) -> bool) -> String {
+    let mut lines = source_text.lines().collect::<Vec<_>>();
+    let mut attempts = 0;
+    let mut chunk = lines.len() / 2;
+    while chunk > 0 && attempts < MAX_MINIMIZE_ATTEMPTS {
+        let mut start = 0;
+        while start < lines.len() && attempts < MAX_MINIMIZE_ATTEMPTS {
+            attempts += 1;
+            let end = (start + chunk).min(lines.len());
+            let candidate = lines[..start].iter().chain(&lines[end..]).copied().collect::<Vec<_>>();
+            if crashes(&candidate.join("\n")) {
+                lines = candidate;
+            } else {
+                start = end;
+            }
+        }
+        chunk /= 2;
+    }
+    lines.join("\n")
+}
+
+/// Append the report of a panic of `rule_name` on the file at `path` to the file at
+/// `report_path`, with `source_text`, the source of the file or its lines which reproduce the
+/// panic.
+///
+/// # Errors
+///
+/// Returns `Err` if the report cannot be written.
+pub fn write_crash_report(
+    report_path: &Path,
+    path: &Path,
+    rule_name: Option<&str>,
+    panic: &Panic,
+    source_text: &str,
+) -> io::Result<()> {
+    let mut report = String::new();
+    let _ = writeln!(report, "# Crash report\n");
+    let _ = writeln!(report, "File: {}", path.display());
+    let _ = writeln!(report, "Rule: {}", rule_name.unwrap_or("<none>"));
+    let _ = writeln!(report, "Panic: {}", panic.message);
+    if let Some(location) = &panic.location {
+        let _ = writeln!(report, "Location: {location}");
+    }
+    let extension = path.extension().map_or(String::new(), |ext| ext.to_string_lossy().to_string());
+    let _ = writeln!(report, "\n## Source\n\n```{extension}\n{source_text}\n```");
+    let _ = writeln!(report, "\n## Backtrace\n\n```\n{}```\n", panic.backtrace);
+    // Written at once, reports of several threads do not interleave.
+    OpenOptions::new().create(true).append(true).open(report_path)?.write_all(report.as_bytes())
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{catch_panic, minimize, write_crash_report, CrashReportError};
+
+    #[test]
+    fn catch() {
+        assert_eq!(catch_panic(|| 1).unwrap(), 1);
+        let panic = catch_panic(|| panic!("rule failed on {}", "a.js")).unwrap_err();
+        assert_eq!(panic.message, "rule failed on a.js");
+        assert!(panic.location.unwrap().contains("crash.rs"));
+    }
+
+    #[test]
+    fn minimize_lines() {
+        let source_text = "let a = 1;\nlet b = 2;\nfoo(a);\nlet c = 3;\nbar(b);";
+        let crashes =
+            |source_text: &str| source_text.contains("let b") && source_text.contains("bar");
+        assert_eq!(minimize(source_text, crashes), "let b = 2;\nbar(b);");
+    }
+
+    #[test]
+    fn crash_report_error() {
+        let panic = catch_panic(|| panic!("rule failed")).unwrap_err();
+        // A directory cannot be appended to
+        let report_path = std::env::temp_dir();
+        let error =
+            write_crash_report(&report_path, Path::new("a.js"), None, &panic, "").unwrap_err();
+        let error = CrashReportError::new(&report_path, &error).to_string();
+        let prefix = format!("Failed to write crash report {}: ", report_path.display());
+        assert!(error.starts_with(&prefix));
+    }
+}
@@ -0,0 +1,99 @@
+//! Messages of rules overridden by the `messages` map of the config, e.g. to point developers to
+//! an internal style guide:
+//!
+//! ```json
+//! { "messages": { "no-console": "{message} See https://example.com/style-guide#logging" } }
+//! ```
+//!
+//! `{message}` is the message of the rule and `{rule}` its name, other placeholders are kept as
+//! they are. The `plugin(rule): ` prefix of the message is kept as well.
+
+use std::fmt;
+
+use oxc_diagnostics::{
+    miette::{Diagnostic, LabeledSpan, Severity},
+    Error,
+};
+
+/// A diagnostic of a rule with the message of the config.
+#[derive(Debug)]
+pub struct CustomMessage {
+    pub message: String,
+    pub error: Error,
+}
+
+impl CustomMessage {
+    pub fn new(template: &str, rule_name: &str, error: Error) -> Self {
+        let original = error.to_string();
+        let (prefix, message) = split_prefix(&original);
+        let message = template.replace("{rule}", rule_name).replace("{message}", message);
+        Self { message: format!("{prefix}{message}"), error }
+    }
+}
+
+/// Split `eslint(no-console): Unexpected console statement` after the `plugin(rule): ` prefix.
+fn split_prefix(message: &str) -> (&str, &str) {
+    message
+        .find("): ")
+        .map(|index| index + 3)
+        .filter(|index| {
+            let prefix = &message[..*index];
+            prefix.contains('(') && !prefix.contains(char::is_whitespace)
+        })
+        .map_or(("", message), |index| message.split_at(index))
+}
+
+impl fmt::Display for CustomMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CustomMessage {}
+
+impl Diagnostic for CustomMessage {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.error.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.url()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.error.labels()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::miette::miette;
+
+    use super::CustomMessage;
+
+    #[test]
+    fn placeholders() {
+        let error = miette!("eslint(no-console): Unexpected console statement.");
+        let template = "{message} Use the logger instead, see {rule} in the {styleguide}.";
+        let message = CustomMessage::new(template, "no-console", error).to_string();
+        assert_eq!(
+            message,
+            "eslint(no-console): Unexpected console statement. Use the logger instead, see no-console in the {styleguide}."
+        );
+    }
+
+    #[test]
+    fn without_prefix() {
+        let error = miette!("Unexpected console statement (in a test): remove it");
+        let message = CustomMessage::new("{message}!", "no-console", error).to_string();
+        assert_eq!(message, "Unexpected console statement (in a test): remove it!");
+    }
+}
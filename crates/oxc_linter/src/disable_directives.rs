@@ -1,4 +1,10 @@
+use std::{cell::Cell, fmt};
+
 use oxc_ast::TriviasMap;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic, LabeledSpan, Severity},
+    thiserror::{self, Error},
+};
 use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
 use rustc_hash::FxHashMap;
@@ -9,6 +15,25 @@ enum DisabledRule<'a> {
     Single(&'a str),
 }
 
+impl<'a> DisabledRule<'a> {
+    fn matches(self, rule_name: &str) -> bool {
+        match self {
+            Self::All => true,
+            // Our rule name currently does not contain the prefix.
+            // For example, this will match `@typescript-eslint/no-var-requires` given
+            // our rule_name is `no-var-requires`.
+            Self::Single(name) => name.contains(rule_name),
+        }
+    }
+}
+
+/// A rule disabled over an interval, by the directive at this index
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Disabled<'a> {
+    rule: DisabledRule<'a>,
+    directive: usize,
+}
+
 /// A comment which disables one or more specific rules
 pub struct DisableRuleComment<'a> {
     /// Span of the comment
@@ -17,9 +42,23 @@ pub struct DisableRuleComment<'a> {
     pub rules: Vec<&'a str>,
 }
 
+/// A rule, or all rules, disabled by a comment, e.g. each rule of
+/// `// eslint-disable-next-line no-console, no-debugger`
+#[derive(Debug)]
+struct Directive<'a> {
+    /// Span of the comment
+    span: Span,
+    rule: DisabledRule<'a>,
+    /// `oxlint-disable` instead of `eslint-disable`
+    oxlint: bool,
+    /// Whether a diagnostic was silenced by the directive
+    used: Cell<bool>,
+}
+
 pub struct DisableDirectives<'a> {
     /// All the disabled rules with their corresponding covering spans
-    intervals: Lapper<u32, DisabledRule<'a>>,
+    intervals: Lapper<u32, Disabled<'a>>,
+    directives: Vec<Directive<'a>>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
@@ -27,14 +66,16 @@ pub struct DisableDirectives<'a> {
 }
 
 impl<'a> DisableDirectives<'a> {
+    /// Whether `rule_name` is disabled at `start`, the directives disabling it are marked as used.
     pub fn contains(&self, rule_name: &'static str, start: u32) -> bool {
-        self.intervals.find(start, start + 1).any(|interval| {
-            interval.val == DisabledRule::All
-                // Our rule name currently does not contain the prefix.
-                // For example, this will match `@typescript-eslint/no-var-requires` given
-                // our rule_name is `no-var-requires`.
-                || matches!(interval.val, DisabledRule::Single(name) if name.contains(rule_name))
-        })
+        let mut disabled = false;
+        for interval in self.intervals.find(start, start + 1) {
+            if interval.val.rule.matches(rule_name) {
+                self.directives[interval.val.directive].used.set(true);
+                disabled = true;
+            }
+        }
+        disabled
     }
 
     pub fn disable_all_comments(&self) -> &Vec<Span> {
@@ -44,17 +85,81 @@ impl<'a> DisableDirectives<'a> {
     pub fn disable_rule_comments(&self) -> &Vec<DisableRuleComment<'a>> {
         &self.disable_rule_comments
     }
+
+    /// The directives which silenced no diagnostic. `eslint-` directives are also read by ESLint,
+    /// so they are only reported for the rules in `rule_names`: disabling all rules, or a rule
+    /// which is not run, may silence the diagnostics of ESLint.
+    pub fn unused(&self, rule_names: &[&str]) -> Vec<UnusedDirective> {
+        self.directives
+            .iter()
+            .filter(|directive| !directive.used.get())
+            .filter(|directive| {
+                directive.oxlint
+                    || matches!(directive.rule, DisabledRule::Single(name) if rule_names
+                        .iter()
+                        .any(|rule_name| name.rsplit('/').next() == Some(rule_name)))
+            })
+            .map(|directive| UnusedDirective {
+                prefix: if directive.oxlint { "oxlint" } else { "eslint" },
+                rule: match directive.rule {
+                    DisabledRule::All => String::new(),
+                    DisabledRule::Single(name) => format!(" from '{name}'"),
+                },
+                span: directive.span,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unused {prefix}-disable directive (no problems were reported{rule}).")]
+#[diagnostic(severity(warning), help("Remove the directive or the rule from it."))]
+pub struct UnusedDirective {
+    prefix: &'static str,
+    rule: String,
+    #[label]
+    span: Span,
+}
+
+/// A diagnostic silenced by a disable directive, reported as advice with `show_suppressed`.
+#[derive(Debug)]
+pub struct SuppressedAdvice {
+    pub rule_name: &'static str,
+    pub error: oxc_diagnostics::Error,
+}
+
+impl fmt::Display for SuppressedAdvice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Suppressed: {}", self.error)
+    }
+}
+
+impl std::error::Error for SuppressedAdvice {}
+
+impl Diagnostic for SuppressedAdvice {
+    fn severity(&self) -> Option<Severity> {
+        Some(Severity::Advice)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.help()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.error.labels()
+    }
 }
 
 pub struct DisableDirectivesBuilder<'a, 'b> {
     source_text: &'a str,
     trivias: &'b TriviasMap,
     /// All the disabled rules with their corresponding covering spans
-    intervals: Lapper<u32, DisabledRule<'a>>,
-    /// Start of `eslint-disable`
-    disable_all_start: Option<u32>,
-    /// Start of `eslint-disable rule_name`
-    disable_start_map: FxHashMap<&'a str, u32>,
+    intervals: Lapper<u32, Disabled<'a>>,
+    directives: Vec<Directive<'a>>,
+    /// Start and directive of `eslint-disable`
+    disable_all_start: Option<(u32, usize)>,
+    /// Start and directive of `eslint-disable rule_name`
+    disable_start_map: FxHashMap<&'a str, (u32, usize)>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
@@ -67,6 +172,7 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
             source_text,
             trivias,
             intervals: Lapper::new(vec![]),
+            directives: vec![],
             disable_all_start: None,
             disable_start_map: FxHashMap::default(),
             disable_all_comments: vec![],
@@ -78,13 +184,37 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
         self.build_impl();
         DisableDirectives {
             intervals: self.intervals,
+            directives: self.directives,
             disable_all_comments: self.disable_all_comments,
             disable_rule_comments: self.disable_rule_comments,
         }
     }
 
-    fn add_interval(&mut self, start: u32, stop: u32, val: DisabledRule<'a>) {
-        self.intervals.insert(Interval { start, stop, val });
+    fn add_directive(&mut self, span: Span, rule: DisabledRule<'a>, oxlint: bool) -> usize {
+        self.directives.push(Directive { span, rule, oxlint, used: Cell::new(false) });
+        self.directives.len() - 1
+    }
+
+    fn add_interval(&mut self, start: u32, stop: u32, directive: usize) {
+        let rule = self.directives[directive].rule;
+        self.intervals.insert(Interval { start, stop, val: Disabled { rule, directive } });
+    }
+
+    /// `rule_names` disabled from `start` to `stop`, or all rules without rule names
+    fn disable(&mut self, span: Span, text: &'a str, oxlint: bool, start: u32, stop: u32) {
+        if !Self::has_rule_names(text) {
+            let directive = self.add_directive(span, DisabledRule::All, oxlint);
+            self.add_interval(start, stop, directive);
+            self.disable_all_comments.push(span);
+        } else {
+            let mut rules = vec![];
+            Self::get_rule_names(text, |rule_name| {
+                let directive = self.add_directive(span, DisabledRule::Single(rule_name), oxlint);
+                self.add_interval(start, stop, directive);
+                rules.push(rule_name);
+            });
+            self.disable_rule_comments.push(DisableRuleComment { span, rules });
+        }
     }
 
     #[allow(clippy::cast_possible_truncation)] // for `as u32`
@@ -97,68 +227,55 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
             let span = Span::new(*start, comment.end());
             let text = span.source_text(self.source_text);
             let text = text.trim_start();
+            // `oxlint-disable` and `oxlint-enable` are the same as their `eslint-` counterparts,
+            // but only read by oxlint
+            let (text, oxlint) = if let Some(text) = text.strip_prefix("eslint-") {
+                (text, false)
+            } else if let Some(text) = text.strip_prefix("oxlint-") {
+                (text, true)
+            } else {
+                continue;
+            };
 
-            if let Some(text) = text.strip_prefix("eslint-disable") {
-                // `eslint-disable`
-                if text.trim().is_empty() {
+            if let Some(text) = text.strip_prefix("disable") {
+                // `eslint-disable`, `eslint-disable -- description`
+                if !Self::has_rule_names(text) {
+                    let directive = self.add_directive(span, DisabledRule::All, oxlint);
                     if self.disable_all_start.is_none() {
-                        self.disable_all_start = Some(span.end);
+                        self.disable_all_start = Some((span.end, directive));
                     }
                     self.disable_all_comments.push(span);
                     continue;
                 }
 
-                // `eslint-disable-next-line`
+                // `eslint-disable-next-line`, `eslint-disable-next-line rule_name1, rule_name2`
                 if let Some(text) = text.strip_prefix("-next-line") {
                     // Get the span up to the next new line
                     let stop = self.source_text[span.end as usize..]
                         .lines()
                         .take(2)
                         .fold(span.end, |acc, line| acc + line.len() as u32);
-                    if text.trim().is_empty() {
-                        self.add_interval(span.end, stop, DisabledRule::All);
-                        self.disable_all_comments.push(span);
-                    } else {
-                        // `eslint-disable-next-line rule_name1, rule_name2`
-                        let mut rules = vec![];
-                        Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(span.end, stop, DisabledRule::Single(rule_name));
-                            rules.push(rule_name);
-                        });
-                        self.disable_rule_comments.push(DisableRuleComment { span, rules });
-                    }
+                    self.disable(span, text, oxlint, span.end, stop);
                     continue;
                 }
 
-                // `eslint-disable-line`
+                // `eslint-disable-line`, `eslint-disable-line rule-name1, rule-name2`
                 if let Some(text) = text.strip_prefix("-line") {
                     // Get the span between the preceding newline to this comment
                     let start = self.source_text[..=span.start as usize]
                         .lines()
                         .next_back()
                         .map_or(0, |line| span.start - (line.len() as u32 - 1));
-                    let stop = span.start;
-
-                    // `eslint-disable-line`
-                    if text.trim().is_empty() {
-                        self.add_interval(start, stop, DisabledRule::All);
-                        self.disable_all_comments.push(span);
-                    } else {
-                        // `eslint-disable-line rule-name1, rule-name2`
-                        let mut rules = vec![];
-                        Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(start, stop, DisabledRule::Single(rule_name));
-                            rules.push(rule_name);
-                        });
-                        self.disable_rule_comments.push(DisableRuleComment { span, rules });
-                    }
+                    self.disable(span, text, oxlint, start, span.start);
                     continue;
                 }
 
                 // `eslint-disable rule-name1, rule-name2`
                 let mut rules = vec![];
                 Self::get_rule_names(text, |rule_name| {
-                    self.disable_start_map.entry(rule_name).or_insert(span.end);
+                    let directive =
+                        self.add_directive(span, DisabledRule::Single(rule_name), oxlint);
+                    self.disable_start_map.entry(rule_name).or_insert((span.end, directive));
                     rules.push(rule_name);
                 });
                 self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -166,17 +283,17 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
                 continue;
             }
 
-            if let Some(text) = text.strip_prefix("eslint-enable") {
+            if let Some(text) = text.strip_prefix("enable") {
                 // `eslint-enable`
                 if text.trim().is_empty() {
-                    if let Some(start) = self.disable_all_start.take() {
-                        self.add_interval(start, span.start, DisabledRule::All);
+                    if let Some((start, directive)) = self.disable_all_start.take() {
+                        self.add_interval(start, span.start, directive);
                     }
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
                     Self::get_rule_names(text, |rule_name| {
-                        if let Some(start) = self.disable_start_map.remove(rule_name) {
-                            self.add_interval(start, span.start, DisabledRule::Single(rule_name));
+                        if let Some((start, directive)) = self.disable_start_map.remove(rule_name) {
+                            self.add_interval(start, span.start, directive);
                         }
                     });
                 }
@@ -185,17 +302,22 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
         }
 
         // Lone `eslint-disable`
-        if let Some(start) = self.disable_all_start {
-            self.add_interval(start, source_len, DisabledRule::All);
+        if let Some((start, directive)) = self.disable_all_start {
+            self.add_interval(start, source_len, directive);
         }
 
         // Lone `eslint-disable rule_name`
         let disable_start_map = self.disable_start_map.drain().collect::<Vec<_>>();
-        for (rule_name, start) in disable_start_map {
-            self.add_interval(start, source_len, DisabledRule::Single(rule_name));
+        for (_, (start, directive)) in disable_start_map {
+            self.add_interval(start, source_len, directive);
         }
     }
 
+    /// Whether the text after a directive lists rules, and not only a description
+    fn has_rule_names(text: &str) -> bool {
+        text.split_terminator("--").next().is_some_and(|text| !text.trim().is_empty())
+    }
+
     fn get_rule_names<F: FnMut(&'a str)>(text: &'a str, cb: F) {
         if let Some(text) = text.split_terminator("--").next() {
             text.split(',').map(str::trim).for_each(cb);
@@ -305,7 +427,24 @@ fn test() {
              * along with some additional information
             **/
             debugger;
+        ",
+        // A description without rules disables all rules
+        "
+            // eslint-disable-next-line -- Here's a description
+            debugger;
+        ",
+        // The same directives with the `oxlint-` prefix
+        "
+        /* oxlint-disable */
+            debugger;
+        /* oxlint-enable */
+        ",
         "
+            debugger; // oxlint-disable-line no-debugger
+
+            // oxlint-disable-next-line no-debugger, quotes
+            debugger;
+        ",
     ];
 
     let fail = vec![
@@ -352,7 +491,79 @@ fn test() {
             debugger;
             debugger;
         ",
+        "
+            /* oxlint-disable no-debugger */
+            debugger;
+            /* oxlint-enable no-debugger */
+            debugger;
+        ",
     ];
 
     Tester::new_without_config("no-debugger", pass, fail).test();
 }
+
+#[cfg(test)]
+fn lint(options: crate::LintOptions, source_text: &str) -> (crate::LintService, Vec<String>) {
+    use std::{
+        path::PathBuf,
+        sync::{mpsc, Arc},
+    };
+
+    use crate::{LintService, Linter, MemoryFileSystem};
+
+    let linter = Linter::from_options(options).unwrap();
+    let path = PathBuf::from("/virtual/a.js");
+    let file_system = Arc::new(MemoryFileSystem::new([(path.clone(), source_text.to_string())]));
+    let cwd = PathBuf::from("/virtual").into_boxed_path();
+    let service =
+        LintService::with_file_system(cwd, &[path.into_boxed_path()], linter, file_system);
+
+    let (tx_error, rx_error) = mpsc::channel();
+    service.run(&tx_error);
+    let messages = rx_error
+        .into_iter()
+        .map_while(std::convert::identity)
+        .flat_map(|(_, errors)| errors)
+        .map(|error| error.to_string())
+        .collect::<Vec<_>>();
+    (service, messages)
+}
+
+#[test]
+fn show_suppressed() {
+    let options = crate::LintOptions::default().with_show_suppressed(true);
+    let source_text = "// eslint-disable-next-line no-debugger\ndebugger;\ndebugger; // eslint-disable-line\ndebugger;";
+    let (service, messages) = lint(options, source_text);
+    let suppressed = messages.iter().filter(|message| message.starts_with("Suppressed: ")).count();
+    assert_eq!((messages.len(), suppressed), (3, 2));
+    assert_eq!(service.linter().suppressed_counts(), vec![("no-debugger", 2)]);
+}
+
+#[test]
+fn unused_directives() {
+    let source_text = "
+        // eslint-disable-next-line no-debugger
+        debugger;
+        // eslint-disable-next-line no-debugger, no-console
+        foo();
+        // eslint-disable-next-line
+        foo();
+        // oxlint-disable-next-line
+        foo();
+        /* oxlint-disable no-debugger */
+        debugger;
+    ";
+    let options = crate::LintOptions::default().with_report_unused_directives(true);
+    let (_, mut messages) = lint(options, source_text);
+    messages.sort();
+    assert_eq!(
+        messages,
+        vec![
+            "Unused eslint-disable directive (no problems were reported from 'no-debugger').",
+            "Unused oxlint-disable directive (no problems were reported)."
+        ]
+    );
+
+    let (_, messages) = lint(crate::LintOptions::default(), source_text);
+    assert!(messages.is_empty());
+}
@@ -0,0 +1,227 @@
+//! Formatting of fixes from the `.editorconfig` files of the linted file
+//!
+//! See <https://editorconfig.org>. The `.editorconfig` files of the directories of the file are
+//! read up to the one with `root = true`, the sections of the nearest files take precedence.
+//! Used are `indent_style`, `indent_size`, `end_of_line` and `quote_type`, the quotes of string
+//! literals written by fixes.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use oxc_formatter::{EndOfLine, FormatterOptions};
+use regex::Regex;
+
+#[derive(Debug, Default)]
+struct EditorConfigFile {
+    root: bool,
+    /// Sections in the order of the file, a later section overrides an earlier one
+    sections: Vec<(Regex, Vec<(String, String)>)>,
+}
+
+impl EditorConfigFile {
+    fn parse(source_text: &str) -> Self {
+        let mut file = Self::default();
+        for line in source_text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(['#', ';']) {
+                continue;
+            }
+            if let Some(glob) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                // Sections with an invalid glob are skipped with their properties
+                let pattern = Regex::new(&glob_to_regex(glob)).unwrap_or_else(|_| {
+                    Regex::new("$^").unwrap() // matches nothing
+                });
+                file.sections.push((pattern, vec![]));
+                continue;
+            }
+            let Some((key, value)) = line.split_once(['=', ':']) else { continue };
+            let key = key.trim().to_lowercase();
+            let value = value.trim().to_lowercase();
+            match file.sections.last_mut() {
+                Some((_, properties)) => properties.push((key, value)),
+                None if key == "root" => file.root = value == "true",
+                None => {}
+            }
+        }
+        file
+    }
+
+    /// Properties of the sections matching `path`, relative to the directory of the file.
+    fn properties<'a>(&'a self, path: &'a str) -> impl Iterator<Item = &'a (String, String)> + 'a {
+        self.sections
+            .iter()
+            .filter(move |(pattern, _)| pattern.is_match(path))
+            .flat_map(|(_, properties)| properties)
+    }
+}
+
+/// Regular expression of an editorconfig glob, matching paths relative to the directory of the
+/// `.editorconfig` file. Globs without a `/` match the file name in any directory.
+fn glob_to_regex(glob: &str) -> String {
+    let glob = glob.strip_prefix('/').map_or_else(
+        || if glob.contains('/') { glob.to_string() } else { format!("**/{glob}") },
+        ToString::to_string,
+    );
+    let mut regex = String::from("^");
+    let mut chars = glob.chars().peekable();
+    let mut braces = 0;
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                // `**/` also matches no directory
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            '[' => {
+                regex.push('[');
+                if chars.peek() == Some(&'!') {
+                    chars.next();
+                    regex.push('^');
+                }
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                    if c == '\\' || c == '[' {
+                        regex.push('\\');
+                    }
+                    regex.push(c);
+                }
+                regex.push(']');
+            }
+            '{' => {
+                braces += 1;
+                regex.push_str("(?:");
+            }
+            '}' if braces > 0 => {
+                braces -= 1;
+                regex.push(')');
+            }
+            ',' if braces > 0 => regex.push('|'),
+            '\\' => {
+                if let Some(c) = chars.next() {
+                    regex.push_str(&regex::escape(&c.to_string()));
+                }
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+/// The `.editorconfig` files read so far, by directory.
+#[derive(Debug, Default)]
+pub struct EditorConfigs {
+    files: DashMap<PathBuf, Option<Arc<EditorConfigFile>>>,
+}
+
+impl EditorConfigs {
+    /// Options of the formatter of fixes for the file at `path`, the defaults of the formatter
+    /// for the properties which are not set.
+    pub fn formatter_options(&self, path: &Path) -> FormatterOptions {
+        let path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().unwrap_or_default().join(path)
+        };
+
+        // From the nearest file to the root
+        let mut files = vec![];
+        for dir in path.ancestors().skip(1) {
+            let Some(file) = self.file(dir) else { continue };
+            let root = file.root;
+            files.push((dir, file));
+            if root {
+                break;
+            }
+        }
+
+        let mut options = FormatterOptions::default();
+        let mut indent_size = None;
+        let mut tab_width = None;
+        for (dir, file) in files.iter().rev() {
+            let Ok(relative) = path.strip_prefix(dir) else { continue };
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            for (key, value) in file.properties(&relative) {
+                match (key.as_str(), value.as_str()) {
+                    ("indent_style", "tab") => options.use_tabs = true,
+                    ("indent_style", "space") => options.use_tabs = false,
+                    ("indent_size", "tab") => indent_size = None,
+                    ("indent_size", size) => indent_size = size.parse::<u8>().ok(),
+                    ("tab_width", width) => tab_width = width.parse::<u8>().ok(),
+                    ("end_of_line", "lf") => options.end_of_line = EndOfLine::LF,
+                    ("end_of_line", "crlf") => options.end_of_line = EndOfLine::CRLF,
+                    ("end_of_line", "cr") => options.end_of_line = EndOfLine::CR,
+                    ("quote_type", "single") => options.single_quote = true,
+                    ("quote_type", "double") => options.single_quote = false,
+                    _ => {}
+                }
+            }
+        }
+        if let Some(size) = indent_size.or(tab_width) {
+            options.indentation = size;
+        }
+        options
+    }
+
+    fn file(&self, dir: &Path) -> Option<Arc<EditorConfigFile>> {
+        if let Some(file) = self.files.get(dir) {
+            return file.clone();
+        }
+        let file = fs::read_to_string(dir.join(".editorconfig"))
+            .ok()
+            .map(|source_text| Arc::new(EditorConfigFile::parse(&source_text)));
+        self.files.insert(dir.to_path_buf(), file.clone());
+        file
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use regex::Regex;
+
+    use super::{glob_to_regex, EditorConfigFile};
+
+    fn is_match(glob: &str, path: &str) -> bool {
+        Regex::new(&glob_to_regex(glob)).unwrap().is_match(path)
+    }
+
+    #[test]
+    fn glob() {
+        assert!(is_match("*", "a.js"));
+        assert!(is_match("*", "src/a.js"));
+        assert!(is_match("*.{js,ts}", "src/a.ts"));
+        assert!(!is_match("*.{js,ts}", "src/a.json"));
+        assert!(is_match("lib/**.js", "lib/a/b.js"));
+        assert!(!is_match("lib/*.js", "lib/a/b.js"));
+        assert!(is_match("/src/*.js", "src/a.js"));
+        assert!(!is_match("/src/*.js", "test/src/a.js"));
+        assert!(is_match("[Mm]akefile", "Makefile"));
+    }
+
+    #[test]
+    fn properties() {
+        let file = EditorConfigFile::parse(
+            "root = true\n\n[*]\nindent_style = space\nindent_size = 2\n\n# Tabs for Go\n[*.go]\nindent_style = tab\n",
+        );
+        assert!(file.root);
+        let properties =
+            file.properties("cmd/main.go").map(|(key, value)| (key.as_str(), value.as_str()));
+        assert_eq!(
+            properties.collect::<Vec<_>>(),
+            [("indent_style", "space"), ("indent_size", "2"), ("indent_style", "tab")]
+        );
+    }
+}
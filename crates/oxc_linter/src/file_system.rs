@@ -0,0 +1,308 @@
+//! File systems the [crate::LintService] reads source files from and writes fixes to.
+//!
+//! Bundlers and test runners can lint generated or transformed code without writing it to disk
+//! with a [MemoryFileSystem]. Module resolution of the import plugin still probes the disk,
+//! so imports between in-memory files are only followed when the files also exist on disk.
+
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use dashmap::DashMap;
+
+/// Files of at least this many bytes are memory mapped instead of read into a buffer.
+const MMAP_THRESHOLD: u64 = 256 * 1024;
+
+pub trait LintFileSystem: Send + Sync {
+    /// # Errors
+    ///
+    /// * When the file does not exist or is not valid in its [Encoding]
+    fn read_to_string(&self, path: &Path) -> io::Result<String>;
+
+    /// Called with the fixed source text when fixing is enabled.
+    ///
+    /// # Errors
+    ///
+    /// * When the file cannot be written
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()>;
+}
+
+/// The file system of the operating system.
+///
+/// Large files are memory mapped and decoded from the map, smaller files or files which cannot be
+/// mapped are read into a buffer which becomes the source text when it is UTF-8.
+///
+/// Files starting with a byte order mark are decoded from UTF-8 or UTF-16 and fixes are written
+/// back in the encoding of the file, with its byte order mark.
+#[derive(Debug, Default)]
+pub struct OsFileSystem {
+    /// Files read with an encoding other than UTF-8 without a byte order mark
+    encodings: DashMap<PathBuf, Encoding>,
+}
+
+impl LintFileSystem for OsFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        let mut file = fs::File::open(path)?;
+        let len = file.metadata()?.len();
+        let map = if len >= MMAP_THRESHOLD { map(&file) } else { None };
+        let (encoding, source_text) = match map {
+            Some(map) => Encoding::decode(&map)?,
+            None => Encoding::decode_owned(read_bytes(&mut file, len)?)?,
+        };
+        if encoding != Encoding::Utf8 {
+            self.encodings.insert(path.to_path_buf(), encoding);
+        }
+        Ok(source_text)
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()> {
+        let encoding = self.encodings.get(path).map_or(Encoding::Utf8, |encoding| *encoding);
+        fs::write(path, encoding.encode(contents))
+    }
+}
+
+/// `None` when the file cannot be mapped, e.g. on some network file systems.
+fn map(file: &fs::File) -> Option<memmap2::Mmap> {
+    // SAFETY: the map is undefined behavior if another process truncates or writes the file while
+    // it is mapped. As with ripgrep, which also maps large files, changing the files while they
+    // are linted is not supported: the map only lives while the file is decoded into a `String`.
+    unsafe { memmap2::Mmap::map(file) }.ok()
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn read_bytes(file: &mut fs::File, len: u64) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(len as usize);
+    file.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Encoding of a source file, told by its byte order mark.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    #[default]
+    Utf8,
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+}
+
+impl Encoding {
+    fn detect(bytes: &[u8]) -> Self {
+        match bytes {
+            [0xEF, 0xBB, 0xBF, ..] => Self::Utf8Bom,
+            [0xFF, 0xFE, ..] => Self::Utf16Le,
+            [0xFE, 0xFF, ..] => Self::Utf16Be,
+            _ => Self::Utf8,
+        }
+    }
+
+    fn bom(self) -> &'static [u8] {
+        match self {
+            Self::Utf8 => &[],
+            Self::Utf8Bom => &[0xEF, 0xBB, 0xBF],
+            Self::Utf16Le => &[0xFF, 0xFE],
+            Self::Utf16Be => &[0xFE, 0xFF],
+        }
+    }
+
+    /// The encoding of `bytes` and their text without the byte order mark.
+    ///
+    /// # Errors
+    ///
+    /// * When the bytes are not valid in the detected encoding, with the offset of the first
+    ///   invalid byte
+    pub fn decode(bytes: &[u8]) -> io::Result<(Self, String)> {
+        let encoding = Self::detect(bytes);
+        let bytes = &bytes[encoding.bom().len()..];
+        let text = match encoding {
+            Self::Utf8 | Self::Utf8Bom => match std::str::from_utf8(bytes) {
+                Ok(text) => text.to_string(),
+                Err(error) => {
+                    let offset = encoding.bom().len() + error.valid_up_to();
+                    return Err(invalid_data(format!("invalid UTF-8 at byte {offset}")));
+                }
+            },
+            Self::Utf16Le | Self::Utf16Be => {
+                if bytes.len() % 2 != 0 {
+                    return Err(invalid_data("UTF-16 with an odd number of bytes".to_string()));
+                }
+                let units = bytes.chunks_exact(2).map(|unit| match encoding {
+                    Self::Utf16Le => u16::from_le_bytes([unit[0], unit[1]]),
+                    _ => u16::from_be_bytes([unit[0], unit[1]]),
+                });
+                let mut text = String::with_capacity(bytes.len() / 2);
+                for (index, c) in char::decode_utf16(units).enumerate() {
+                    let Ok(c) = c else {
+                        let offset = encoding.bom().len() + index * 2;
+                        return Err(invalid_data(format!(
+                            "unpaired UTF-16 surrogate at byte {offset}"
+                        )));
+                    };
+                    text.push(c);
+                }
+                text
+            }
+        };
+        Ok((encoding, text))
+    }
+
+    /// Like [Encoding::decode], without copying UTF-8 bytes without a byte order mark.
+    fn decode_owned(bytes: Vec<u8>) -> io::Result<(Self, String)> {
+        if Self::detect(&bytes) != Self::Utf8 {
+            return Self::decode(&bytes);
+        }
+        match String::from_utf8(bytes) {
+            Ok(text) => Ok((Self::Utf8, text)),
+            // Reported with the offset of the first invalid byte
+            Err(error) => Self::decode(error.as_bytes()),
+        }
+    }
+
+    /// `text` in this encoding, with the byte order mark.
+    pub fn encode(self, text: &str) -> Vec<u8> {
+        let mut bytes = self.bom().to_vec();
+        match self {
+            Self::Utf8 | Self::Utf8Bom => bytes.extend_from_slice(text.as_bytes()),
+            Self::Utf16Le => bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes)),
+            Self::Utf16Be => bytes.extend(text.encode_utf16().flat_map(u16::to_be_bytes)),
+        }
+        bytes
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Files kept in memory, fixes are written back to the map.
+#[derive(Debug, Default)]
+pub struct MemoryFileSystem {
+    files: DashMap<PathBuf, String>,
+}
+
+impl MemoryFileSystem {
+    pub fn new<I>(files: I) -> Self
+    where
+        I: IntoIterator<Item = (PathBuf, String)>,
+    {
+        Self { files: files.into_iter().collect() }
+    }
+
+    /// Paths of all files, sorted.
+    pub fn paths(&self) -> Vec<Box<Path>> {
+        let mut paths =
+            self.files.iter().map(|file| file.key().clone().into_boxed_path()).collect::<Vec<_>>();
+        paths.sort();
+        paths
+    }
+
+    /// Current contents of the file at `path`, including the fixes applied to it.
+    pub fn get(&self, path: &Path) -> Option<String> {
+        self.files.get(path).map(|file| file.value().clone())
+    }
+}
+
+impl LintFileSystem for MemoryFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.get(path).ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()> {
+        self.files.insert(path.to_path_buf(), contents.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+        sync::{mpsc, Arc},
+    };
+
+    use super::{Encoding, LintFileSystem, MemoryFileSystem, OsFileSystem, MMAP_THRESHOLD};
+    use crate::{LintOptions, LintService, Linter};
+
+    fn lint(file_system: &Arc<MemoryFileSystem>, options: LintOptions) -> Vec<(PathBuf, usize)> {
+        let linter = Linter::from_options(options).unwrap();
+        let cwd = PathBuf::from("/virtual").into_boxed_path();
+        let paths = file_system.paths();
+        let file_system: Arc<dyn LintFileSystem> = Arc::<MemoryFileSystem>::clone(file_system);
+        let service = LintService::with_file_system(cwd, &paths, linter, file_system);
+
+        let (tx_error, rx_error) = mpsc::channel();
+        service.run(&tx_error);
+        let mut diagnostics = rx_error
+            .into_iter()
+            .map_while(|message| message.map(|(path, errors)| (path, errors.len())))
+            .collect::<Vec<_>>();
+        diagnostics.sort();
+        diagnostics
+    }
+
+    #[test]
+    fn lint_in_memory() {
+        let file_system = Arc::new(MemoryFileSystem::new([
+            (PathBuf::from("/virtual/a.js"), "debugger; debugger;".to_string()),
+            (PathBuf::from("/virtual/b.ts"), "let b: number = 1;".to_string()),
+            (PathBuf::from("/virtual/c.js"), "let c = (".to_string()),
+        ]));
+        let diagnostics = lint(&file_system, LintOptions::default());
+        assert_eq!(diagnostics, vec![(PathBuf::from("a.js"), 2), (PathBuf::from("c.js"), 1)]);
+    }
+
+    #[test]
+    fn fix_in_memory() {
+        let path = Path::new("/virtual/a.js");
+        let file_system = Arc::new(MemoryFileSystem::new([(
+            path.to_path_buf(),
+            "debugger;\nlet a;".to_string(),
+        )]));
+        let diagnostics = lint(&file_system, LintOptions::default().with_fix(true));
+        assert!(diagnostics.is_empty());
+        assert_eq!(file_system.get(path).unwrap(), "\nlet a;");
+    }
+
+    #[test]
+    fn decode() {
+        let (encoding, text) = Encoding::decode(b"\xEF\xBB\xBFlet a;").unwrap();
+        assert_eq!((encoding, text.as_str()), (Encoding::Utf8Bom, "let a;"));
+
+        let utf16 = [0xFF, 0xFE, b'a', 0, b';', 0];
+        let (encoding, text) = Encoding::decode(&utf16).unwrap();
+        assert_eq!((encoding, text.as_str()), (Encoding::Utf16Le, "a;"));
+        assert_eq!(encoding.encode("a;"), utf16);
+
+        let utf16 = [0xFE, 0xFF, 0, b'a', 0xD8, 0];
+        let error = Encoding::decode(&utf16).unwrap_err();
+        assert_eq!(error.to_string(), "unpaired UTF-16 surrogate at byte 4");
+
+        let error = Encoding::decode(b"let a = '\xFF';").unwrap_err();
+        assert_eq!(error.to_string(), "invalid UTF-8 at byte 9");
+        let error = Encoding::decode_owned(b"let a = '\xFF';".to_vec()).unwrap_err();
+        assert_eq!(error.to_string(), "invalid UTF-8 at byte 9");
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn read_mapped_and_buffered() {
+        let dir = env::temp_dir().join(format!("oxc-file-system-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file_system = OsFileSystem::default();
+        let large = "let a = 1;\n".repeat(MMAP_THRESHOLD as usize / 10);
+        for (name, text) in [("small.js", "let a = 1;\n"), ("large.js", large.as_str())] {
+            let path = dir.join(name);
+            fs::write(&path, text).unwrap();
+            assert_eq!(file_system.read_to_string(&path).unwrap(), text);
+
+            let mut bom = vec![0xEF, 0xBB, 0xBF];
+            bom.extend_from_slice(text.as_bytes());
+            fs::write(&path, bom).unwrap();
+            assert_eq!(file_system.read_to_string(&path).unwrap(), text);
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,9 +1,9 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, str::FromStr};
 
 use oxc_diagnostics::Error;
 use oxc_span::Span;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Fix<'a> {
     pub content: Cow<'a, str>,
     pub span: Span,
@@ -17,6 +17,32 @@ impl<'a> Fix<'a> {
     pub fn new<T: Into<Cow<'a, str>>>(content: T, span: Span) -> Self {
         Self { content: content.into(), span }
     }
+
+    /// A fix made of several edits of `source_text`, e.g. replacing the head and the tail of a
+    /// statement while keeping its body, applied together or not at all.
+    ///
+    /// The edits are sorted by their span, an edit overlapping a previous one is dropped.
+    pub fn from_edits<I, T>(source_text: &str, edits: I) -> Self
+    where
+        I: IntoIterator<Item = (Span, T)>,
+        T: Into<Cow<'a, str>>,
+    {
+        let mut edits =
+            edits.into_iter().map(|(span, content)| (span, content.into())).collect::<Vec<_>>();
+        edits.sort_by_key(|(span, _)| *span);
+        let Some(start) = edits.first().map(|(span, _)| span.start) else { return Self::default() };
+        let mut content = String::new();
+        let mut end = start;
+        for (span, edit) in edits {
+            if span.start < end || span.start > span.end {
+                continue;
+            }
+            content.push_str(&source_text[end as usize..span.start as usize]);
+            content.push_str(&edit);
+            end = span.end;
+        }
+        Self { content: Cow::Owned(content), span: Span::new(start, end) }
+    }
 }
 
 #[derive(Debug)]
@@ -57,16 +83,73 @@ impl<'a> Message<'a> {
     }
 }
 
+/// Line endings of the fixed code.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Newline {
+    /// Line breaks of the fixes are the line ending of the first line of the file, the other
+    /// lines are kept as they are
+    #[default]
+    Auto,
+    /// All line breaks of a fixed file are `\n`
+    Lf,
+    /// All line breaks of a fixed file are `\r\n`
+    Crlf,
+}
+
+impl Newline {
+    /// The line ending of the first line of `source_text`
+    fn detect(source_text: &str) -> Option<Self> {
+        let index = source_text.find('\n')?;
+        Some(if source_text[..index].ends_with('\r') { Self::Crlf } else { Self::Lf })
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Auto | Self::Lf => "\n",
+            Self::Crlf => "\r\n",
+        }
+    }
+
+    /// `text` with all line breaks replaced by this line ending
+    fn apply(self, text: &str) -> Cow<'_, str> {
+        if self == Self::Auto || !text.contains('\n') {
+            return Cow::Borrowed(text);
+        }
+        Cow::Owned(text.replace("\r\n", "\n").replace('\n', self.as_str()))
+    }
+}
+
+impl FromStr for Newline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "lf" => Ok(Self::Lf),
+            "crlf" => Ok(Self::Crlf),
+            _ => Err(format!("expected `lf`, `crlf` or `auto`, received `{s}`")),
+        }
+    }
+}
+
 /// The fixer of the code.
 /// Note that our parser has handled the BOM, so we don't need to port the BOM test cases from `ESLint`.
 pub struct Fixer<'a> {
     source_text: &'a str,
     messages: Vec<Message<'a>>,
+    newline: Newline,
 }
 
 impl<'a> Fixer<'a> {
     pub fn new(source_text: &'a str, messages: Vec<Message<'a>>) -> Self {
-        Self { source_text, messages }
+        Self { source_text, messages, newline: Newline::Auto }
+    }
+
+    /// Line endings of the fixed code. Whether the file ends with a line break is kept either way.
+    #[must_use]
+    pub fn with_newline(mut self, newline: Newline) -> Self {
+        self.newline = newline;
+        self
     }
 
     /// # Panics
@@ -81,17 +164,28 @@ impl<'a> Fixer<'a> {
         }
 
         self.messages.sort_by_key(|m| m.fix.as_ref().unwrap_or(&Fix::default()).span);
+        // The fixes are written with `\n`, as are most files
+        let fix_newline = match self.newline {
+            Newline::Auto => Newline::detect(source_text).unwrap_or(Newline::Auto),
+            newline => newline,
+        };
         let mut fixed = false;
         let mut output = String::with_capacity(source_text.len());
         let mut last_pos: i64 = -1;
+        let mut last_fix: Option<Fix> = None;
         self.messages.iter_mut().filter(|m| m.fix.is_some()).for_each(|m| {
-            let Fix { content, span } = m.fix.as_ref().unwrap();
+            let fix = m.fix.as_ref().unwrap();
+            let Fix { content, span } = fix;
             let start = span.start;
             let end = span.end;
             if start > end {
                 return;
             }
             if i64::from(start) <= last_pos {
+                // The same fix of several diagnostics, e.g. a comment disabling several rules
+                if last_fix.as_ref() == Some(fix) {
+                    m.fixed = true;
+                }
                 return;
             }
 
@@ -99,13 +193,23 @@ impl<'a> Fixer<'a> {
             fixed = true;
             let offset = usize::try_from(last_pos.max(0)).ok().unwrap();
             output.push_str(&source_text[offset..start as usize]);
-            output.push_str(content);
+            output.push_str(&fix_newline.apply(content));
             last_pos = i64::from(end);
+            last_fix = Some(fix.clone());
         });
 
         let offset = usize::try_from(last_pos.max(0)).ok().unwrap();
         output.push_str(&source_text[offset..]);
 
+        if fixed {
+            output = self.newline.apply(&output).into_owned();
+            match (source_text.ends_with('\n'), output.ends_with('\n')) {
+                (true, false) if !output.is_empty() => output.push_str(fix_newline.as_str()),
+                (false, true) => output.truncate(output.trim_end_matches(['\r', '\n']).len()),
+                _ => {}
+            }
+        }
+
         let mut messages = self.messages.into_iter().filter(|m| !m.fixed).collect::<Vec<_>>();
         messages.sort_by_key(|m| (m.start, m.end));
         return FixResult { fixed, fixed_code: Cow::Owned(output), messages };
@@ -120,7 +224,7 @@ mod test {
     use oxc_diagnostics::{thiserror::Error, Error};
     use oxc_span::Span;
 
-    use super::{Fix, FixResult, Fixer, Message};
+    use super::{Fix, FixResult, Fixer, Message, Newline};
 
     const TEST_CODE: &str = "var answer = 6 * 7;";
 
@@ -193,6 +297,22 @@ mod test {
         Message::new(error.into(), fix)
     }
 
+    #[test]
+    fn from_edits() {
+        let edits = [
+            (Span::new(13, 18), "6"),
+            (Span::new(0, 3), "let"),
+            (Span::new(14, 16), "overlap"),
+            (Span::new(4, 10), "product"),
+        ];
+        let fix = Fix::from_edits(TEST_CODE, edits);
+        assert_eq!(fix.span, Span::new(0, 18));
+        assert_eq!(fix.content, "let product = 6");
+
+        let result = get_fix_result(vec![create_message(InsertAtEnd, Some(fix))]);
+        assert_eq!(result.fixed_code, "let product = 6;");
+    }
+
     #[test]
     fn insert_at_the_end() {
         let result = get_fix_result(vec![create_message(InsertAtEnd, Some(INSERT_AT_END))]);
@@ -357,6 +477,17 @@ mod test {
         assert!(result.fixed);
     }
 
+    #[test]
+    fn apply_identical_fixes_once() {
+        let result = get_fix_result(vec![
+            create_message(InsertAtStart, Some(INSERT_AT_START)),
+            create_message(InsertAtStart, Some(INSERT_AT_START)),
+        ]);
+        assert_eq!(result.fixed_code, format!("// start{TEST_CODE}"));
+        assert_eq!(result.messages.len(), 0);
+        assert!(result.fixed);
+    }
+
     #[test]
     fn apply_same_fix_when_span_overlap_regardless_of_order() {
         let result1 = get_fix_result(vec![
@@ -392,4 +523,31 @@ mod test {
         assert_eq!(result.messages[1].error.to_string(), "nofix2");
         assert!(result.fixed);
     }
+
+    #[test]
+    fn keep_line_endings() {
+        let source_text = "var a;\r\nvar b;\r\n";
+        let fix = Fix::new("let c;\nlet d;", Span::new(8, 16));
+        let result = Fixer::new(source_text, vec![create_message(ReplaceVar, Some(fix))]).fix();
+        assert_eq!(result.fixed_code, "var a;\r\nlet c;\r\nlet d;\r\n");
+
+        let fix = Fix::new("let a;\n", Span::new(0, 6));
+        let result = Fixer::new("var a;", vec![create_message(ReplaceVar, Some(fix))]).fix();
+        assert_eq!(result.fixed_code, "let a;");
+    }
+
+    #[test]
+    fn newline() {
+        let source_text = "var a;\r\nvar b;\n";
+        let messages = vec![create_message(ReplaceVar, Some(REPLACE_VAR))];
+        let result = Fixer::new(source_text, messages).with_newline(Newline::Lf).fix();
+        assert_eq!(result.fixed_code, "let a;\nvar b;\n");
+
+        let messages = vec![create_message(ReplaceVar, Some(REPLACE_VAR))];
+        let result = Fixer::new(source_text, messages).with_newline(Newline::Crlf).fix();
+        assert_eq!(result.fixed_code, "let a;\r\nvar b;\r\n");
+
+        assert_eq!("crlf".parse::<Newline>(), Ok(Newline::Crlf));
+        assert!("cr".parse::<Newline>().is_err());
+    }
 }
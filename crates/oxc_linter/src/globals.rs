@@ -82,6 +82,278 @@ pub const PRE_DEFINE_VAR: Map<&'static str, bool> = phf_map! {
     "arguments" => false,
 };
 
+/// Globals of the `jest` environment, defined in test files
+pub const JEST: Map<&'static str, bool> = phf_map! {
+    "afterAll" => false,
+    "afterEach" => false,
+    "beforeAll" => false,
+    "beforeEach" => false,
+    "describe" => false,
+    "expect" => false,
+    "fdescribe" => false,
+    "fit" => false,
+    "it" => false,
+    "jest" => false,
+    "pit" => false,
+    "require" => false,
+    "test" => false,
+    "xdescribe" => false,
+    "xit" => false,
+    "xtest" => false,
+};
+
+/// Globals of the `browser` environment
+pub const BROWSER: Map<&'static str, bool> = phf_map! {
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "CustomEvent" => false,
+    "DOMParser" => false,
+    "Document" => false,
+    "DocumentFragment" => false,
+    "Element" => false,
+    "Event" => false,
+    "EventTarget" => false,
+    "File" => false,
+    "FileReader" => false,
+    "FormData" => false,
+    "HTMLCanvasElement" => false,
+    "HTMLElement" => false,
+    "HTMLInputElement" => false,
+    "Headers" => false,
+    "Image" => false,
+    "IntersectionObserver" => false,
+    "KeyboardEvent" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "MouseEvent" => false,
+    "MutationObserver" => false,
+    "Node" => false,
+    "NodeList" => false,
+    "Notification" => false,
+    "ReadableStream" => false,
+    "Request" => false,
+    "ResizeObserver" => false,
+    "Response" => false,
+    "SharedWorker" => false,
+    "TextDecoder" => false,
+    "TextEncoder" => false,
+    "TransformStream" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+    "WebSocket" => false,
+    "Worker" => false,
+    "WritableStream" => false,
+    "XMLHttpRequest" => false,
+    "addEventListener" => false,
+    "alert" => false,
+    "atob" => false,
+    "btoa" => false,
+    "caches" => false,
+    "cancelAnimationFrame" => false,
+    "cancelIdleCallback" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "close" => false,
+    "closed" => false,
+    "confirm" => false,
+    "console" => false,
+    "crypto" => false,
+    "customElements" => false,
+    "devicePixelRatio" => false,
+    "dispatchEvent" => false,
+    "document" => false,
+    "fetch" => false,
+    "frames" => false,
+    "getComputedStyle" => false,
+    "getSelection" => false,
+    "history" => false,
+    "indexedDB" => false,
+    "innerHeight" => false,
+    "innerWidth" => false,
+    "localStorage" => false,
+    "location" => false,
+    "matchMedia" => false,
+    "name" => true,
+    "navigator" => false,
+    "onerror" => true,
+    "onload" => true,
+    "onmessage" => true,
+    "onresize" => true,
+    "onscroll" => true,
+    "open" => false,
+    "opener" => false,
+    "outerHeight" => false,
+    "outerWidth" => false,
+    "parent" => false,
+    "performance" => false,
+    "postMessage" => false,
+    "print" => false,
+    "prompt" => false,
+    "queueMicrotask" => false,
+    "removeEventListener" => false,
+    "requestAnimationFrame" => false,
+    "requestIdleCallback" => false,
+    "screen" => false,
+    "scroll" => false,
+    "scrollTo" => false,
+    "scrollX" => false,
+    "scrollY" => false,
+    "self" => false,
+    "sessionStorage" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "structuredClone" => false,
+    "top" => false,
+    "window" => false,
+};
+
+/// Globals of the `node` environment
+pub const NODE: Map<&'static str, bool> = phf_map! {
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "Buffer" => false,
+    "CustomEvent" => false,
+    "Event" => false,
+    "EventTarget" => false,
+    "FormData" => false,
+    "Headers" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "ReadableStream" => false,
+    "Request" => false,
+    "Response" => false,
+    "TextDecoder" => false,
+    "TextEncoder" => false,
+    "TransformStream" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+    "WritableStream" => false,
+    "__dirname" => false,
+    "__filename" => false,
+    "atob" => false,
+    "btoa" => false,
+    "clearImmediate" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "console" => false,
+    "crypto" => false,
+    "exports" => true,
+    "fetch" => false,
+    "global" => false,
+    "module" => false,
+    "performance" => false,
+    "process" => false,
+    "queueMicrotask" => false,
+    "require" => false,
+    "setImmediate" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "structuredClone" => false,
+};
+
+/// Globals of the `worker` environment, web workers and service workers
+pub const WORKER: Map<&'static str, bool> = phf_map! {
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "CustomEvent" => false,
+    "Event" => false,
+    "EventTarget" => false,
+    "File" => false,
+    "FileReader" => false,
+    "FormData" => false,
+    "Headers" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "ReadableStream" => false,
+    "Request" => false,
+    "Response" => false,
+    "TextDecoder" => false,
+    "TextEncoder" => false,
+    "TransformStream" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+    "WebSocket" => false,
+    "WritableStream" => false,
+    "addEventListener" => false,
+    "atob" => false,
+    "btoa" => false,
+    "caches" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "close" => false,
+    "console" => false,
+    "crypto" => false,
+    "dispatchEvent" => false,
+    "fetch" => false,
+    "importScripts" => false,
+    "indexedDB" => false,
+    "location" => false,
+    "navigator" => false,
+    "onerror" => true,
+    "onmessage" => true,
+    "performance" => false,
+    "postMessage" => false,
+    "queueMicrotask" => false,
+    "removeEventListener" => false,
+    "self" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "structuredClone" => false,
+};
+
+/// Globals of the `mocha` environment
+pub const MOCHA: Map<&'static str, bool> = phf_map! {
+    "after" => false,
+    "afterEach" => false,
+    "before" => false,
+    "beforeEach" => false,
+    "context" => false,
+    "describe" => false,
+    "it" => false,
+    "mocha" => false,
+    "run" => false,
+    "setup" => false,
+    "specify" => false,
+    "suite" => false,
+    "suiteSetup" => false,
+    "suiteTeardown" => false,
+    "teardown" => false,
+    "test" => false,
+    "xcontext" => false,
+    "xdescribe" => false,
+    "xit" => false,
+    "xspecify" => false,
+};
+
+/// Globals of an `env` preset of ESLint, e.g. `"env": { "browser": true, "node": true }`.
+/// The `es*` presets, e.g. `es2024`, are the builtins of the language.
+pub fn environment(name: &str) -> Option<&'static Map<&'static str, bool>> {
+    match name {
+        "browser" => Some(&BROWSER),
+        "node" => Some(&NODE),
+        "worker" | "serviceworker" => Some(&WORKER),
+        "jest" => Some(&JEST),
+        "mocha" => Some(&MOCHA),
+        "builtin" | "es6" => Some(&BUILTINS),
+        _ => name
+            .strip_prefix("es20")
+            .is_some_and(|year| year.len() == 2 && year.bytes().all(|b| b.is_ascii_digit()))
+            .then_some(&BUILTINS),
+    }
+}
+
 pub const GLOBAL_OBJECT_NAMES: phf::Set<&'static str> = phf_set! {
     "global",
     "globalThis",
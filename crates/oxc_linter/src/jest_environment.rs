@@ -0,0 +1,166 @@
+//! Test files of Jest, read from `testMatch` and `testRegex` of `jest.config.json` or the `jest`
+//! key of `package.json`.
+//!
+//! The rules of the jest plugin only run on test files, and the globals of jest such as
+//! `describe` and `expect` are only defined in them.
+//!
+//! `testMatch` uses micromatch globs. The extglobs `?(..)`, `+(..)`, `@(..)` and `*(..)` of the
+//! default patterns are expanded into gitignore patterns, matching each alternative once.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use regex::Regex;
+use serde_json::Value;
+
+/// `testMatch` of jest when neither `testMatch` nor `testRegex` is configured
+const DEFAULT_TEST_MATCH: [&str; 2] =
+    ["**/__tests__/**/*.[jt]s?(x)", "**/?(*.)+(spec|test).[jt]s?(x)"];
+
+#[derive(Debug)]
+pub struct JestEnvironment {
+    /// `rootDir`, the directory of the config by default
+    root: PathBuf,
+    test_match: Option<Gitignore>,
+    /// Matched against the absolute path, replaces `testMatch` when set
+    test_regex: Vec<Regex>,
+}
+
+impl JestEnvironment {
+    /// The jest config of `dir`, or the defaults of jest when there is none.
+    pub fn load(dir: &Path) -> Self {
+        let read_json = |path: PathBuf| fs::read_to_string(path).ok()?.parse::<Value>().ok();
+        let config = read_json(dir.join("jest.config.json"))
+            .or_else(|| read_json(dir.join("package.json"))?.get("jest").cloned());
+        Self::from_config(dir, config.as_ref())
+    }
+
+    pub fn from_config(dir: &Path, config: Option<&Value>) -> Self {
+        let root = config
+            .and_then(|config| config.get("rootDir")?.as_str())
+            .map_or_else(|| dir.to_path_buf(), |root_dir| dir.join(root_dir));
+        let strings = |key: &str| -> Vec<String> {
+            match config.and_then(|config| config.get(key)) {
+                Some(Value::String(value)) => vec![value.clone()],
+                Some(Value::Array(values)) => {
+                    values.iter().filter_map(Value::as_str).map(ToString::to_string).collect()
+                }
+                _ => vec![],
+            }
+        };
+
+        let test_regex = strings("testRegex")
+            .iter()
+            .filter_map(|regex| Regex::new(regex).ok())
+            .collect::<Vec<_>>();
+        let test_match = if test_regex.is_empty() {
+            let mut test_match = strings("testMatch");
+            if test_match.is_empty() {
+                test_match = DEFAULT_TEST_MATCH.iter().map(ToString::to_string).collect();
+            }
+            let mut builder = GitignoreBuilder::new(&root);
+            for pattern in test_match.iter().flat_map(|pattern| expand_extglob(pattern)) {
+                // `<rootDir>/src/**` is anchored to the root like `/src/**` in gitignore.
+                let pattern = pattern.replace("<rootDir>", "");
+                let _ = builder.add_line(None, &pattern);
+            }
+            builder.build().ok()
+        } else {
+            None
+        };
+
+        Self { root, test_match, test_regex }
+    }
+
+    /// Relative paths are resolved against the working directory.
+    pub fn is_test_file(&self, path: &Path) -> bool {
+        let path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().unwrap_or_default().join(path)
+        };
+        // `Path::components` drops the `.` of `./src/a.test.js`
+        let path = path.components().collect::<PathBuf>();
+        if !self.test_regex.is_empty() {
+            let path = path.to_string_lossy();
+            return self.test_regex.iter().any(|regex| regex.is_match(&path));
+        }
+        let Some(test_match) = &self.test_match else { return false };
+        path.strip_prefix(&self.root).is_ok_and(|path| test_match.matched(path, false).is_ignore())
+    }
+}
+
+/// Expand the first extglob of `pattern` into its alternatives, recursively.
+fn expand_extglob(pattern: &str) -> Vec<String> {
+    let extglob = pattern.char_indices().find(|(index, c)| {
+        matches!(c, '?' | '+' | '@' | '*') && pattern[index + 1..].starts_with('(')
+    });
+    let Some((start, kind)) = extglob else { return vec![pattern.to_string()] };
+    let Some(end) = pattern[start..].find(')').map(|end| start + end) else {
+        return vec![pattern.to_string()];
+    };
+    let mut alternatives = pattern[start + 2..end].split('|').collect::<Vec<_>>();
+    // `?(..)` and `*(..)` also match nothing
+    if matches!(kind, '?' | '*') {
+        alternatives.insert(0, "");
+    }
+    let (prefix, suffix) = (&pattern[..start], &pattern[end + 1..]);
+    alternatives
+        .into_iter()
+        .flat_map(|alternative| expand_extglob(&format!("{prefix}{alternative}{suffix}")))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, path::Path};
+
+    use serde_json::json;
+
+    use super::{expand_extglob, JestEnvironment};
+
+    #[test]
+    fn extglob() {
+        assert_eq!(expand_extglob("*.js"), vec!["*.js"]);
+        assert_eq!(
+            expand_extglob("?(*.)+(spec|test).js"),
+            vec!["spec.js", "test.js", "*.spec.js", "*.test.js"]
+        );
+    }
+
+    #[test]
+    fn default_test_match() {
+        let root = Path::new("/project");
+        let jest = JestEnvironment::from_config(root, None);
+        assert!(jest.is_test_file(&root.join("src/__tests__/a.js")));
+        assert!(jest.is_test_file(&root.join("src/__tests__/nested/a.tsx")));
+        assert!(jest.is_test_file(&root.join("src/a.test.ts")));
+        assert!(jest.is_test_file(&root.join("src/a.spec.jsx")));
+        assert!(jest.is_test_file(&root.join("test.js")));
+        assert!(!jest.is_test_file(&root.join("src/a.js")));
+        assert!(!jest.is_test_file(&root.join("src/contest.js")));
+        assert!(!jest.is_test_file(Path::new("/other/a.test.js")));
+    }
+
+    #[test]
+    fn test_regex() {
+        let root = Path::new("/project");
+        let config = json!({ "testRegex": "/tests/.*\\.js$", "testMatch": ["**/*.js"] });
+        let jest = JestEnvironment::from_config(root, Some(&config));
+        assert!(jest.is_test_file(&root.join("tests/a.js")));
+        assert!(!jest.is_test_file(&root.join("src/a.js")));
+    }
+
+    #[test]
+    fn package_json() {
+        let dir = env::current_dir().unwrap().join("fixtures/jest_environment");
+        let jest = JestEnvironment::load(&dir);
+        assert!(jest.is_test_file(&dir.join("src/a.check.js")));
+        assert!(jest.is_test_file(&dir.join("src/nested/a.check.mjs")));
+        assert!(!jest.is_test_file(&dir.join("src/a.test.js")));
+        assert!(!jest.is_test_file(&dir.join("a.check.js")));
+    }
+}
@@ -0,0 +1,153 @@
+//! JSX options of the linted files, which decide whether JSX reads `React`: `settings.react` of
+//! the config, over the `jsx`, `jsxFactory`, `jsxFragmentFactory` and `jsxImportSource` compiler
+//! options of the tsconfig, over the classic runtime. The pragma comments of a file, such as
+//! `@jsxRuntime automatic`, override all of them.
+//!
+//! Parsed tsconfig files are read again when their modification time changes, e.g. in the
+//! language server. `extends` is not followed.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use dashmap::DashMap;
+use lazy_static::lazy_static;
+use oxc_parser::json::{JsonMode, JsonParser};
+use oxc_semantic::{JsxOptions, JsxRuntime};
+use serde_json::Value;
+
+use crate::config::to_serde_value;
+
+lazy_static! {
+    static ref PARSED: DashMap<PathBuf, (Option<SystemTime>, JsxOptions)> = DashMap::default();
+}
+
+/// `settings.react` of the config, as in eslint-plugin-react:
+///
+/// ```json
+/// { "settings": { "react": { "runtime": "automatic" } } }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ReactSettings {
+    /// `runtime`, `classic` or `automatic`
+    runtime: Option<JsxRuntime>,
+    /// `pragma`, the variable of the classic runtime, e.g. `h` of Preact
+    pragma: Option<String>,
+    /// `fragment`, the property of the pragma variable for fragments, e.g. `Fragment`
+    fragment: Option<String>,
+    /// `importSource`, e.g. `preact`
+    import_source: Option<String>,
+}
+
+impl ReactSettings {
+    pub fn from_value(value: &Value) -> Self {
+        let string = |key: &str| value.get(key).and_then(Value::as_str).map(ToString::to_string);
+        let runtime = value.get("runtime").and_then(Value::as_str).and_then(parse_runtime);
+        Self {
+            runtime,
+            pragma: string("pragma"),
+            fragment: string("fragment"),
+            import_source: string("importSource"),
+        }
+    }
+
+    /// `options` overridden by these settings
+    pub fn apply(&self, mut options: JsxOptions) -> JsxOptions {
+        if let Some(runtime) = self.runtime {
+            options.runtime = runtime;
+        }
+        if let Some(pragma) = &self.pragma {
+            options.pragma = pragma.clone();
+        }
+        if let Some(fragment) = &self.fragment {
+            // A property of the pragma variable, as in eslint-plugin-react
+            let pragma = options.pragma.split('.').next().unwrap_or_default();
+            options.pragma_frag = format!("{pragma}.{fragment}");
+        }
+        if let Some(import_source) = &self.import_source {
+            options.import_source = import_source.clone();
+        }
+        options
+    }
+}
+
+/// JSX options of the compiler options of the tsconfig at `path`, the defaults when it cannot be
+/// read or has none.
+pub fn tsconfig_jsx_options(path: &Path) -> JsxOptions {
+    let modified = fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+    if let Some(entry) = PARSED.get(path) {
+        if entry.0 == modified {
+            return entry.1.clone();
+        }
+    }
+    let options = fs::read_to_string(path)
+        .ok()
+        .and_then(|source_text| {
+            // Comments and trailing commas are allowed in tsconfig files
+            let json = JsonParser::new(&source_text, JsonMode::Jsonc).parse().ok()?;
+            Some(parse_compiler_options(to_serde_value(&json).get("compilerOptions")?))
+        })
+        .unwrap_or_default();
+    PARSED.insert(path.to_path_buf(), (modified, options.clone()));
+    options
+}
+
+fn parse_compiler_options(compiler_options: &Value) -> JsxOptions {
+    let string = |key: &str| compiler_options.get(key).and_then(Value::as_str);
+    let mut options = JsxOptions::default();
+    // `preserve` and `react-native` leave JSX to another compiler, which is classic by default
+    if let Some("react-jsx" | "react-jsxdev") = string("jsx") {
+        options.runtime = JsxRuntime::Automatic;
+    }
+    if let Some(pragma) = string("jsxFactory") {
+        options.pragma = pragma.to_string();
+    }
+    if let Some(pragma_frag) = string("jsxFragmentFactory") {
+        options.pragma_frag = pragma_frag.to_string();
+    }
+    if let Some(import_source) = string("jsxImportSource") {
+        options.import_source = import_source.to_string();
+    }
+    options
+}
+
+fn parse_runtime(runtime: &str) -> Option<JsxRuntime> {
+    match runtime {
+        "classic" => Some(JsxRuntime::Classic),
+        "automatic" => Some(JsxRuntime::Automatic),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_semantic::{JsxOptions, JsxRuntime};
+    use serde_json::json;
+
+    use super::{parse_compiler_options, ReactSettings};
+
+    #[test]
+    fn compiler_options() {
+        let options = parse_compiler_options(&json!({ "jsx": "react-jsx" }));
+        assert_eq!(options.runtime, JsxRuntime::Automatic);
+
+        let options = parse_compiler_options(&json!({ "jsx": "react", "jsxFactory": "h" }));
+        assert_eq!(options.runtime, JsxRuntime::Classic);
+        assert_eq!(options.pragma_variable(), Some("h"));
+    }
+
+    #[test]
+    fn react_settings() {
+        let settings = ReactSettings::from_value(&json!({ "runtime": "automatic" }));
+        let tsconfig = JsxOptions::default();
+        assert_eq!(settings.apply(tsconfig).runtime, JsxRuntime::Automatic);
+
+        let settings = ReactSettings::from_value(&json!({ "pragma": "h", "fragment": "Fragment" }));
+        let options = settings.apply(JsxOptions::default());
+        assert_eq!(options.pragma, "h");
+        assert_eq!(options.pragma_frag, "h.Fragment");
+        assert_eq!(options.pragma_variable(), Some("h"));
+    }
+}
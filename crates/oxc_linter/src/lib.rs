@@ -5,35 +5,93 @@
 mod tester;
 
 mod ast_util;
+mod budget;
 mod config;
 mod context;
+mod crash;
+mod custom_message;
 mod disable_directives;
+mod editorconfig;
+mod file_system;
 mod fixer;
 mod globals;
+mod jest_environment;
+mod jsx_options;
+mod lint_cache;
+mod metrics;
 mod options;
+mod package_json;
 pub mod partial_loader;
+mod pnp;
+mod presets;
+mod resolve_cache;
 pub mod rule;
 mod rule_timer;
 mod rules;
 mod service;
+mod skipped;
 mod utils;
 
-use std::{self, fs, io::Write, rc::Rc, time::Duration};
+use std::{
+    self, fs,
+    io::{self, Write},
+    path::{Component, Path},
+    rc::Rc,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
+use dashmap::DashMap;
+use oxc_allocator::Allocator;
 use oxc_diagnostics::Report;
+use oxc_formatter::FormatterOptions;
+use oxc_parser::Parser;
+use oxc_resolver::ResolveOptions;
 pub(crate) use oxc_semantic::AstNode;
-use rustc_hash::FxHashMap;
+use oxc_semantic::{JsxOptions, SemanticBuilder};
+use oxc_span::SourceType;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::Value;
 
 pub use crate::{
+    budget::FileBudget,
+    config::{check_config, FlatConfig},
     context::LintContext,
+    crash::InternalError,
+    disable_directives::SuppressedAdvice,
+    file_system::{Encoding, LintFileSystem, MemoryFileSystem, OsFileSystem},
     fixer::Fix,
-    fixer::{FixResult, Fixer, Message},
+    fixer::{FixResult, Fixer, Message, Newline},
+    jest_environment::JestEnvironment,
+    jsx_options::ReactSettings,
     options::{AllowWarnDeny, LintOptions},
-    rule::RuleCategory,
+    package_json::clear_package_json_cache,
+    presets::Preset,
+    rule::{FixKind, RuleCategory},
     service::LintService,
+    skipped::{is_binary, SkipReason, SkippedFiles, BINARY_SNIFF_LEN},
 };
 pub(crate) use rules::{RuleEnum, RULES};
 
+use crate::{
+    budget::{Deadline, FileSkippedAdvice, ReducedRulesAdvice, SlowRuleWarning},
+    config::{IgnorePatterns, InlineConfig},
+    crash::{write_crash_report, CrashReportError, Panic},
+    editorconfig::EditorConfigs,
+    rule_timer::FileProfile,
+};
+
+/// Rules about runtime code which report false positives on ambient declarations, e.g. overloads
+/// and merged declarations look like redeclarations, and declared types refer to undefined globals.
+const DECLARATION_FILE_DISABLED_RULES: [&str; 6] = [
+    "no-redeclare",
+    "no-undef",
+    "no-inner-declarations",
+    "no-dupe-class-members",
+    "no-empty-interface",
+    "no-unsafe-declaration-merging",
+];
+
 #[cfg(target_pointer_width = "64")]
 #[test]
 fn size_asserts() {
@@ -48,11 +106,134 @@ fn size_asserts() {
 #[derive(Debug, Clone)]
 pub struct LintSettings {
     jsx_a11y: JsxA11y,
+    /// `react`, the JSX runtime of the project
+    react: ReactSettings,
+    import: ImportSettings,
+    /// Names of globals defined by the environment, e.g. `languageOptions.globals`
+    globals: FxHashSet<String>,
+    /// `ignorePatterns` of the config
+    ignore_patterns: Vec<String>,
+    /// `messages` of the config, templates of the messages keyed by rule name
+    messages: FxHashMap<String, String>,
+    /// `sourceType` and `ecmaFeatures.jsx` of the parser options
+    parser: ParserSettings,
 }
 
 impl Default for LintSettings {
     fn default() -> Self {
-        Self { jsx_a11y: JsxA11y { polymorphic_prop_name: None, components: FxHashMap::default() } }
+        Self {
+            jsx_a11y: JsxA11y { polymorphic_prop_name: None, components: FxHashMap::default() },
+            react: ReactSettings::default(),
+            import: ImportSettings::default(),
+            globals: FxHashSet::default(),
+            ignore_patterns: vec![],
+            messages: FxHashMap::default(),
+            parser: ParserSettings::default(),
+        }
+    }
+}
+
+impl LintSettings {
+    /// Options of the module resolution, e.g. for the rules of the import plugin
+    pub fn resolver(&self) -> &ResolverSettings {
+        &self.import.resolver
+    }
+
+    /// JSX options of a file of the project of the tsconfig with `tsconfig_options`
+    pub fn jsx_options(&self, tsconfig_options: JsxOptions) -> JsxOptions {
+        self.react.apply(tsconfig_options)
+    }
+}
+
+/// Parser options of the config, `None` keeps the source type of the file extension
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ParserSettings {
+    /// `sourceType`, `module` or `script` and `commonjs`
+    module: Option<bool>,
+    /// `ecmaFeatures.jsx`
+    jsx: Option<bool>,
+}
+
+impl ParserSettings {
+    /// Parse `sourceType` and `ecmaFeatures` of the parser options, overriding the set ones.
+    pub(crate) fn merge(&mut self, parser_options: &Value) {
+        match parser_options.get("sourceType").and_then(Value::as_str) {
+            Some("module") => self.module = Some(true),
+            Some("script" | "commonjs") => self.module = Some(false),
+            _ => {}
+        }
+        let jsx = parser_options.get("ecmaFeatures").and_then(|features| features.get("jsx"));
+        if let Some(jsx) = jsx.and_then(Value::as_bool) {
+            self.jsx = Some(jsx);
+        }
+    }
+
+    /// The source type of a file with these options. TypeScript files keep their JSX variant,
+    /// which is decided by the `.tsx` extension.
+    pub fn apply(self, mut source_type: SourceType) -> SourceType {
+        if let Some(module) = self.module {
+            source_type = source_type.with_module(module);
+        }
+        if let Some(jsx) = self.jsx {
+            if !source_type.is_typescript() {
+                source_type = source_type.with_jsx(jsx);
+            }
+        }
+        source_type
+    }
+}
+
+/// `import/*` settings of eslint-plugin-import
+#[derive(Debug, Default, Clone)]
+pub struct ImportSettings {
+    /// `import/internal-regex`, bare module specifiers which are internal to the project
+    internal_regex: Option<regex::Regex>,
+    /// `import/resolver`, how the import plugin resolves modules
+    resolver: ResolverSettings,
+}
+
+impl ImportSettings {
+    pub fn set_internal_regex(&mut self, internal_regex: Option<regex::Regex>) {
+        self.internal_regex = internal_regex;
+    }
+}
+
+/// Options of the module resolution, read from the options of the resolvers of `import/resolver`
+/// so that they are shared with `eslint-import-resolver-typescript`:
+///
+/// ```json
+/// { "import/resolver": { "typescript": { "conditionNames": ["development", "import"] } } }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ResolverSettings {
+    /// `browser`, resolve the `browser` field of package.json and the `browser` condition of
+    /// `exports` as bundlers targeting browsers do
+    pub browser: bool,
+    /// `conditionNames`, the conditions of `exports` and `imports`, e.g. `development` or `worker`
+    pub condition_names: Option<Vec<String>>,
+    /// `mainFields`, the fields of package.json for the entry of a package, in order
+    pub main_fields: Option<Vec<String>>,
+}
+
+impl ResolverSettings {
+    /// `options` with the module resolution of these settings
+    pub fn resolve_options(&self, mut options: ResolveOptions) -> ResolveOptions {
+        if let Some(condition_names) = &self.condition_names {
+            options.condition_names = condition_names.clone();
+        }
+        if let Some(main_fields) = &self.main_fields {
+            options.main_fields = main_fields.clone();
+        }
+        if self.browser {
+            options.alias_fields = vec![vec!["browser".into()]];
+            if !options.condition_names.iter().any(|name| name == "browser") {
+                options.condition_names.insert(0, "browser".into());
+            }
+            if self.main_fields.is_none() {
+                options.main_fields.insert(0, "browser".into());
+            }
+        }
+        options
     }
 }
 
@@ -77,6 +258,20 @@ pub struct Linter {
     rules: Vec<(/* rule name */ &'static str, RuleEnum)>,
     options: LintOptions,
     settings: LintSettings,
+    /// Rules and settings per file, replacing `rules` and `settings` when the config is a flat config
+    flat_config: Option<Arc<FlatConfig>>,
+    /// Test files of jest with the jest plugin, other files are linted without the jest rules
+    jest: Option<JestEnvironment>,
+    /// `ignorePatterns` of the config, files matching them are not linted
+    ignore_patterns: Option<IgnorePatterns>,
+    /// Number of diagnostics silenced by disable directives per rule, with `show_suppressed`
+    suppressed: DashMap<&'static str, usize>,
+    /// Time spent by each rule on each file, with `profile`
+    profile: Option<Mutex<Vec<FileProfile>>>,
+    /// Files which were not linted, by cause
+    skipped: SkippedFiles,
+    /// Formatting of the fixes of each file, with `fix`
+    editorconfigs: EditorConfigs,
 }
 
 impl Default for Linter {
@@ -93,16 +288,82 @@ impl Linter {
             .cloned()
             .map(|rule| (rule.name(), rule))
             .collect::<Vec<_>>();
-        Self { rules, options: LintOptions::default(), settings: LintSettings::default() }
+        Self {
+            rules,
+            options: LintOptions::default(),
+            settings: LintSettings::default(),
+            flat_config: None,
+            jest: None,
+            ignore_patterns: None,
+            suppressed: DashMap::default(),
+            profile: None,
+            skipped: SkippedFiles::default(),
+            editorconfigs: EditorConfigs::default(),
+        }
     }
 
     /// # Errors
     ///
     /// Returns `Err` if there are any errors parsing the configuration file.
     pub fn from_options(options: LintOptions) -> Result<Self, Report> {
+        let jest = options
+            .jest_plugin
+            .then(|| JestEnvironment::load(&std::env::current_dir().unwrap_or_default()));
+        if let Some(path) =
+            options.config_path.as_ref().filter(|path| FlatConfig::is_flat_config(path))
+        {
+            let flat_config = FlatConfig::new(path)?;
+            let rules =
+                flat_config.all_rules().into_iter().map(|rule| (rule.name(), rule)).collect();
+            let settings = LintSettings::default();
+            let flat_config = Some(Arc::new(flat_config));
+            let (suppressed, profile) = (DashMap::default(), Self::profile(&options));
+            return Ok(Self {
+                rules,
+                options,
+                settings,
+                flat_config,
+                jest,
+                ignore_patterns: None,
+                suppressed,
+                profile,
+                skipped: SkippedFiles::default(),
+                editorconfigs: EditorConfigs::default(),
+            });
+        }
         let (rules, settings) = options.derive_rules_and_settings()?;
+        let ignore_patterns = match &options.config_path {
+            Some(path) => IgnorePatterns::new(path, &settings.ignore_patterns)?,
+            None => None,
+        };
+        // Rules with `files` or `excludedFiles` are resolved per file like a flat config
+        let flat_config = match &options.config_path {
+            Some(path) => FlatConfig::from_eslintrc(path, options.base_config_path.as_ref())?,
+            None => None,
+        };
+        let rules = match &flat_config {
+            Some(flat_config) => flat_config.all_rules(),
+            None => rules,
+        };
         let rules = rules.into_iter().map(|rule| (rule.name(), rule)).collect();
-        Ok(Self { rules, options, settings })
+        let flat_config = flat_config.map(Arc::new);
+        let (suppressed, profile) = (DashMap::default(), Self::profile(&options));
+        Ok(Self {
+            rules,
+            options,
+            settings,
+            flat_config,
+            jest,
+            ignore_patterns,
+            suppressed,
+            profile,
+            skipped: SkippedFiles::default(),
+            editorconfigs: EditorConfigs::default(),
+        })
+    }
+
+    fn profile(options: &LintOptions) -> Option<Mutex<Vec<FileProfile>>> {
+        options.profile.then(|| Mutex::new(vec![]))
     }
 
     #[must_use]
@@ -117,20 +378,61 @@ impl Linter {
         self
     }
 
+    /// Run the jest rules on all files instead of the test files of `jest`.
+    #[must_use]
+    pub fn with_jest_environment(mut self, jest: Option<JestEnvironment>) -> Self {
+        self.jest = jest;
+        self
+    }
+
     pub fn options(&self) -> &LintOptions {
         &self.options
     }
 
+    /// Whether `path` is excluded from linting by the `ignores` of a flat config or the
+    /// `ignorePatterns` of a config, unless `no_ignore` is set.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        if self.options.no_ignore {
+            return false;
+        }
+        self.flat_config.as_ref().is_some_and(|flat_config| flat_config.is_ignored(path))
+            || self.ignore_patterns.as_ref().is_some_and(|patterns| patterns.is_ignored(path))
+    }
+
     pub fn number_of_rules(&self) -> usize {
         self.rules.len()
     }
 
+    /// `(plugin name, rule name)` of the enabled rules
+    pub fn rule_names(&self) -> impl Iterator<Item = (&str, &'static str)> + '_ {
+        self.rules.iter().map(|(name, rule)| (rule.plugin_name(), *name))
+    }
+
+    /// `(plugin name, rule name)` of the rules which run on the file at `path`: the rules of the
+    /// config objects matching it, without the jest rules outside of test files.
+    pub fn rule_names_for(&self, path: &Path) -> Vec<(String, &'static str)> {
+        let flat_config = self.flat_config.as_ref().map(|flat_config| flat_config.config_for(path));
+        let rules = flat_config.as_ref().map_or(&self.rules, |flat_config| &flat_config.rules);
+        let is_test_file = self.is_test_file(path);
+        rules
+            .iter()
+            .filter(|(_, rule)| is_test_file || rule.plugin_name() != "jest")
+            .map(|(name, rule)| (rule.plugin_name().to_string(), *name))
+            .collect()
+    }
+
     #[must_use]
     pub fn with_fix(mut self, yes: bool) -> Self {
         self.options.fix = yes;
         self
     }
 
+    #[must_use]
+    pub fn with_fix_kind(mut self, fix_kind: FixKind) -> Self {
+        self.options.fix_kind = fix_kind;
+        self
+    }
+
     #[must_use]
     pub fn with_print_execution_times(mut self, yes: bool) -> Self {
         self.options.timing = yes;
@@ -138,35 +440,269 @@ impl Linter {
     }
 
     pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
-        let timing = self.options.timing;
+        self.run_with_deadline(ctx, &Deadline::new(&self.options.file_budget))
+    }
+
+    /// `run` within the time budget of the file, which started before the file was parsed.
+    pub(crate) fn run_with_deadline<'a>(
+        &self,
+        ctx: LintContext<'a>,
+        deadline: &Deadline,
+    ) -> Vec<Message<'a>> {
+        let timing_threshold = self.options.timing_threshold;
+        let timing = self.options.timing || timing_threshold.is_some() || self.profile.is_some();
         let semantic = Rc::clone(ctx.semantic());
-        let mut ctx = ctx.with_fix(self.options.fix);
+        // Fixes are written for runtime code, their effect on declarations is not checked.
+        let is_declaration_file = ctx.source_type().is_typescript_definition();
+        let formatter_options = if self.options.fix {
+            self.editorconfigs.formatter_options(ctx.file_path())
+        } else {
+            FormatterOptions::default()
+        };
+        let mut ctx = ctx
+            .with_formatter_options(formatter_options)
+            .with_fix(self.options.fix && !is_declaration_file)
+            .with_fix_kind(self.options.fix_kind)
+            .with_show_suppressed(self.options.show_suppressed);
+
+        let flat_config =
+            self.flat_config.as_ref().map(|flat_config| flat_config.config_for(ctx.file_path()));
+        let rules = flat_config.as_ref().map_or(&self.rules, |flat_config| &flat_config.rules);
+        // Comments such as `/* eslint no-console: "off" */` configure the rules of this file
+        let mut inline_config = InlineConfig::new(ctx.source_text(), ctx.semantic().trivias());
+        let inline_rules;
+        let rules = if inline_config.is_empty() {
+            rules
+        } else {
+            inline_rules = inline_config.apply(rules);
+            &inline_rules
+        };
+        let is_test_file = self.is_test_file(ctx.file_path());
+
+        let budget = &self.options.file_budget;
+        let size = ctx.source_text().len();
+        let reduced_rules_size = budget.reduced_rules_size.filter(|limit| size > *limit);
+        let rules = rules
+            .iter()
+            .filter(|(_, rule)| {
+                reduced_rules_size.is_none() || rule.category() == RuleCategory::Correctness
+            })
+            .filter(|(name, _)| {
+                !is_declaration_file || !DECLARATION_FILE_DISABLED_RULES.contains(name)
+            })
+            .filter(|(_, rule)| is_test_file || rule.plugin_name() != "jest")
+            .collect::<Vec<_>>();
+        ctx.with_fix_suppress(
+            rules
+                .iter()
+                .map(|(name, _)| *name)
+                .filter(|name| self.options.fix_suppress.iter().any(|rule| rule == name))
+                .collect(),
+        );
+        // Time spent by each rule on this file
+        let mut times = vec![Duration::ZERO; if timing { rules.len() } else { 0 }];
+        let mut add_time = |index: usize, time: Option<Duration>| {
+            if let Some(time) = time {
+                times[index] += time;
+            }
+        };
+
+        // A panic of a rule stops the linting of this file only.
+        let completed = crash::catch_panic(|| {
+            for (index, (rule_name, rule)) in rules.iter().enumerate() {
+                if deadline.exceeded() {
+                    return false;
+                }
+                ctx.with_rule_name(rule_name);
+                ctx.with_rule_fix(rule.fix());
+                add_time(index, rule.run_once(&ctx, timing));
+            }
 
-        for (rule_name, rule) in &self.rules {
-            ctx.with_rule_name(rule_name);
-            rule.run_once(&ctx, timing);
-        }
+            for (symbol_index, symbol) in semantic.symbols().iter().enumerate() {
+                if deadline.exceeded_at(symbol_index) {
+                    return false;
+                }
+                for (index, (rule_name, rule)) in rules.iter().enumerate() {
+                    ctx.with_rule_name(rule_name);
+                    ctx.with_rule_fix(rule.fix());
+                    add_time(index, rule.run_on_symbol(symbol, &ctx, timing));
+                }
+            }
 
-        for symbol in semantic.symbols().iter() {
-            for (rule_name, rule) in &self.rules {
-                ctx.with_rule_name(rule_name);
-                rule.run_on_symbol(symbol, &ctx, timing);
+            for (node_index, node) in semantic.nodes().iter().enumerate() {
+                if deadline.exceeded_at(node_index) {
+                    return false;
+                }
+                for (index, (rule_name, rule)) in rules.iter().enumerate() {
+                    ctx.with_rule_name(rule_name);
+                    ctx.with_rule_fix(rule.fix());
+                    add_time(index, rule.run(node, &ctx, timing));
+                }
+            }
+            true
+        });
+        match completed {
+            Ok(true) => {}
+            Ok(false) => {
+                self.skipped.add(SkipReason::TimeBudget);
+                return vec![self.time_budget_exceeded()];
             }
+            Err(panic) => {
+                self.skipped.add(SkipReason::Panic);
+                return self.internal_error(&ctx, &rules, panic);
+            }
+        }
+        let rule_times = rules.iter().map(|(rule_name, _)| *rule_name).zip(times);
+        if let Some(profile) = &self.profile {
+            let rules = rule_times.clone().filter(|(_, time)| !time.is_zero()).collect();
+            let path = ctx.file_path().to_path_buf().into_boxed_path();
+            profile.lock().unwrap().push(FileProfile { path, rules });
         }
+        let slow_rules = rule_times
+            .filter(|(_, time)| timing_threshold.is_some_and(|threshold| *time > threshold))
+            .map(|(rule_name, time)| {
+                let threshold = timing_threshold.unwrap_or_default().as_millis();
+                Message::new(SlowRuleWarning(rule_name, time.as_millis(), threshold).into(), None)
+            })
+            .collect::<Vec<_>>();
 
-        for node in semantic.nodes().iter() {
-            for (rule_name, rule) in &self.rules {
-                ctx.with_rule_name(rule_name);
-                rule.run(node, &ctx, timing);
+        let unused_directives = if self.options.report_unused_directives {
+            let rule_names = rules.iter().map(|(rule_name, _)| *rule_name).collect::<Vec<_>>();
+            ctx.disable_directives().unused(&rule_names)
+        } else {
+            vec![]
+        };
+
+        let mut messages = ctx.into_message();
+        for message in &messages {
+            if let Some(advice) = message.error.downcast_ref::<SuppressedAdvice>() {
+                *self.suppressed.entry(advice.rule_name).or_default() += 1;
             }
         }
+        if let Some(limit) = reduced_rules_size {
+            messages.push(Message::new(ReducedRulesAdvice(size, limit).into(), None));
+        }
+        messages.extend(slow_rules);
+        messages.extend(
+            unused_directives.into_iter().map(|directive| Message::new(directive.into(), None)),
+        );
+        messages
+            .extend(inline_config.take_errors().into_iter().map(|error| Message::new(error, None)));
+        messages
+    }
 
-        ctx.into_message()
+    /// The advice of a file skipped because its time budget ran out.
+    pub(crate) fn time_budget_exceeded<'a>(&self) -> Message<'a> {
+        let time = self.options.file_budget.time.unwrap_or_default().as_millis();
+        Message::new(FileSkippedAdvice(time).into(), None)
+    }
+
+    /// Report the panic of the current rule of `ctx` as an internal error, and append a crash
+    /// report with `crash_report`, followed by an error if the report cannot be written.
+    fn internal_error<'a>(
+        &self,
+        ctx: &LintContext<'a>,
+        rules: &[&(&'static str, RuleEnum)],
+        panic: Panic,
+    ) -> Vec<Message<'a>> {
+        let rule_name = ctx.current_rule_name();
+        let mut crash_report_error = None;
+        if let Some(report_path) = &self.options.crash_report {
+            let source_text = match rules.iter().find(|(name, _)| *name == rule_name) {
+                Some((_, rule)) => crash::minimize(ctx.source_text(), |source_text| {
+                    Self::rule_panics(rule, ctx, source_text)
+                }),
+                None => ctx.source_text().to_string(),
+            };
+            let path = ctx.file_path();
+            crash_report_error =
+                write_crash_report(report_path, path, Some(rule_name), &panic, &source_text)
+                    .err()
+                    .map(|error| CrashReportError::new(report_path, &error));
+        }
+        std::iter::once(Message::new(panic.into_internal_error(Some(rule_name)).into(), None))
+            .chain(crash_report_error.map(|error| Message::new(error.into(), None)))
+            .collect()
+    }
+
+    /// Whether `rule` panics on `source_text` in place of the file of `ctx`.
+    fn rule_panics(rule: &RuleEnum, ctx: &LintContext, source_text: &str) -> bool {
+        let allocator = Allocator::default();
+        let source_type = *ctx.source_type();
+        let ret = Parser::new(&allocator, source_text, source_type)
+            .allow_return_outside_function(true)
+            .parse();
+        if !ret.errors.is_empty() {
+            return false;
+        }
+        let program = allocator.alloc(ret.program);
+        let semantic_ret = SemanticBuilder::new(source_text, source_type)
+            .with_trivias(ret.trivias)
+            .with_jsx_options(ctx.semantic().jsx().clone())
+            .build_module_record(ctx.file_path().to_path_buf(), program)
+            .build(program);
+        if !semantic_ret.errors.is_empty() {
+            return false;
+        }
+        let semantic = Rc::new(semantic_ret.semantic);
+        let mut rule_ctx = LintContext::new(ctx.file_path().into(), &semantic, ctx.settings());
+        rule_ctx.with_rule_name(rule.name());
+        crash::catch_panic(|| {
+            rule.run_once(&rule_ctx, false);
+            for symbol in semantic.symbols().iter() {
+                rule.run_on_symbol(symbol, &rule_ctx, false);
+            }
+            for node in semantic.nodes().iter() {
+                rule.run(node, &rule_ctx, false);
+            }
+        })
+        .is_err()
     }
 
     pub fn get_settings(&self) -> LintSettings {
         self.settings.clone()
     }
+
+    /// Settings for the file at `path`, which differ between files with a flat config.
+    /// Test files of jest also define the globals of jest.
+    pub fn settings_for(&self, path: &Path) -> LintSettings {
+        let mut settings = self.flat_config.as_ref().map_or_else(
+            || self.settings.clone(),
+            |flat_config| flat_config.config_for(path).settings.clone(),
+        );
+        if self.jest.is_some() && self.is_test_file(path) {
+            settings.globals.extend(globals::JEST.keys().map(ToString::to_string));
+        }
+        settings
+    }
+
+    /// Source type of the file at `path`, with the parser options of its config applied to
+    /// `source_type` of its extension.
+    pub fn source_type_for(&self, path: &Path, source_type: SourceType) -> SourceType {
+        let parser = self.flat_config.as_ref().map_or(self.settings.parser, |flat_config| {
+            flat_config.config_for(path).settings.parser
+        });
+        parser.apply(source_type)
+    }
+
+    /// Number of diagnostics silenced by disable directives per rule, most suppressed first.
+    /// Only counted with `show_suppressed`.
+    pub fn suppressed_counts(&self) -> Vec<(&'static str, usize)> {
+        let mut counts =
+            self.suppressed.iter().map(|entry| (*entry.key(), *entry.value())).collect::<Vec<_>>();
+        counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        counts
+    }
+
+    /// Files which were not linted, by cause. Files skipped by the walk are not counted here.
+    pub fn skipped(&self) -> &SkippedFiles {
+        &self.skipped
+    }
+
+    /// All files are test files without the jest environment.
+    fn is_test_file(&self, path: &Path) -> bool {
+        self.jest.as_ref().map_or(true, |jest| jest.is_test_file(path))
+    }
     #[allow(unused)]
     fn read_rules_configuration() -> Option<serde_json::Map<String, serde_json::Value>> {
         fs::read_to_string(".eslintrc.json")
@@ -190,12 +726,67 @@ impl Linter {
             for rule in rules {
                 // Separate the category and rule name so people don't copy the combination as a whole for `--allow` and `--deny`,
                 // resulting invalid rule names.
-                writeln!(writer, "• {}: {}", rule.plugin_name(), rule.name()).unwrap();
+                if rule.fix() == FixKind::None {
+                    writeln!(writer, "• {}: {}", rule.plugin_name(), rule.name()).unwrap();
+                } else {
+                    let (plugin_name, name, fix) = (rule.plugin_name(), rule.name(), rule.fix());
+                    writeln!(writer, "• {plugin_name}: {name} ({fix})").unwrap();
+                }
             }
         }
         writeln!(writer, "Total: {}", RULES.len()).unwrap();
     }
 
+    /// The rules of `preset`, grouped by the release which added them to the preset.
+    pub fn print_preset_rules<W: Write>(writer: &mut W, preset: Preset) {
+        let rules = preset.rules();
+        writeln!(writer, "{preset} ({}):", rules.len()).unwrap();
+        let mut current_version = None;
+        for (version, rule) in &rules {
+            if current_version != Some(version) {
+                writeln!(writer, "Since {version}:").unwrap();
+                current_version = Some(version);
+            }
+            writeln!(writer, "• {}: {}", rule.plugin_name(), rule.name()).unwrap();
+        }
+        writeln!(writer, "Total: {}", rules.len()).unwrap();
+    }
+
+    /// Write the profile of the run in the collapsed stack format of flamegraph tools such as
+    /// `inferno-flamegraph`: a frame for each directory, the file and the rule, with the time
+    /// spent in microseconds.
+    ///
+    /// # Errors
+    ///
+    /// * When `writer` fails to write
+    pub fn write_profile<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let Some(profile) = &self.profile else { return Ok(()) };
+        let files = profile.lock().unwrap();
+        let mut lines = files
+            .iter()
+            .flat_map(|file| {
+                let frames = file
+                    .path
+                    .components()
+                    .filter(|component| {
+                        !matches!(component, Component::RootDir | Component::CurDir)
+                    })
+                    .map(|component| component.as_os_str().to_string_lossy().replace(';', ":"))
+                    .collect::<Vec<_>>()
+                    .join(";");
+                file.rules.iter().map(move |(rule_name, time)| {
+                    format!("oxlint;{frames};{rule_name} {}", time.as_micros())
+                })
+            })
+            .collect::<Vec<_>>();
+        drop(files);
+        lines.sort_unstable();
+        for line in lines {
+            writeln!(writer, "{line}")?;
+        }
+        Ok(())
+    }
+
     #[allow(clippy::print_stdout)]
     pub fn print_execution_times_if_enable(&self) {
         if !self.options.timing {
@@ -0,0 +1,181 @@
+//! On-disk cache of the files without problems, for `--cache`
+//!
+//! Like the `.eslintcache` of ESLint, a file is skipped by the next run as long as its content
+//! and the configuration it was linted with are the same. The configuration is hashed from the
+//! rules with their options, the settings and the lint options, and a new version of the linter
+//! discards the whole cache.
+//!
+//! Files with diagnostics are not cached, they are linted again to report them. Several linters,
+//! e.g. one per workspace package, share a cache file: each one only writes the files it linted.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+use dashmap::DashMap;
+use serde_json::{json, Map, Value};
+
+use crate::Linter;
+
+/// Bumped when the format changes, discarding existing caches.
+const VERSION: u64 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Entry {
+    content: u64,
+    config: u64,
+}
+
+#[derive(Debug)]
+pub struct LintCache {
+    path: PathBuf,
+    /// Hash of the configuration of the linter
+    config: u64,
+    entries: DashMap<Box<Path>, Entry>,
+    /// Files linted by this run, `None` for the files with diagnostics
+    changes: DashMap<Box<Path>, Option<Entry>>,
+}
+
+impl LintCache {
+    /// Load the cache from `path` for `linter`, starting empty when it is missing, written by
+    /// another version or corrupt.
+    pub fn load(path: PathBuf, linter: &Linter) -> Self {
+        let entries = read_entries(&path).unwrap_or_default();
+        Self { path, config: config_hash(linter), entries, changes: DashMap::default() }
+    }
+
+    /// Whether `path` had no diagnostics with the same `source_text` and configuration.
+    pub fn is_clean(&self, path: &Path, source_text: &str) -> bool {
+        self.entries.get(path).is_some_and(|entry| *entry == self.entry(source_text))
+    }
+
+    /// Record the result of linting `path`, whose content is now `source_text`.
+    pub fn update(&self, path: &Path, source_text: &str, clean: bool) {
+        let entry = clean.then(|| self.entry(source_text));
+        self.changes.insert(path.to_path_buf().into_boxed_path(), entry);
+    }
+
+    fn entry(&self, source_text: &str) -> Entry {
+        Entry { content: hash(source_text), config: self.config }
+    }
+
+    /// Write the files linted by this run over the current content of the cache file, creating
+    /// its directory.
+    ///
+    /// # Errors
+    ///
+    /// * When the cache file cannot be written
+    pub fn save(&self) -> std::io::Result<()> {
+        if self.changes.is_empty() {
+            return Ok(());
+        }
+        // Another linter sharing the file may have saved it since it was loaded.
+        let entries = read_entries(&self.path).unwrap_or_default();
+        for item in &self.changes {
+            let (path, entry) = item.pair();
+            if let Some(entry) = entry {
+                entries.insert(path.clone(), *entry);
+            } else {
+                entries.remove(path);
+            }
+        }
+        let files = entries
+            .iter()
+            .map(|item| {
+                let (path, entry) = item.pair();
+                let entry = json!([format!("{:x}", entry.content), format!("{:x}", entry.config)]);
+                (path.to_string_lossy().to_string(), entry)
+            })
+            .collect::<Map<_, _>>();
+        let cache = json!({
+            "version": VERSION,
+            "linter": env!("CARGO_PKG_VERSION"),
+            "files": files,
+        });
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, cache.to_string())?;
+        self.changes.clear();
+        Ok(())
+    }
+}
+
+fn read_entries(path: &Path) -> Option<DashMap<Box<Path>, Entry>> {
+    let cache = serde_json::from_str::<Value>(&fs::read_to_string(path).ok()?).ok()?;
+    if cache.get("version").and_then(Value::as_u64) != Some(VERSION)
+        || cache.get("linter").and_then(Value::as_str) != Some(env!("CARGO_PKG_VERSION"))
+    {
+        return None;
+    }
+    let entries = cache
+        .get("files")?
+        .as_object()?
+        .iter()
+        .filter_map(|(path, entry)| {
+            let hash = |index: usize| u64::from_str_radix(entry.get(index)?.as_str()?, 16).ok();
+            let entry = Entry { content: hash(0)?, config: hash(1)? };
+            Some((PathBuf::from(path).into_boxed_path(), entry))
+        })
+        .collect();
+    Some(entries)
+}
+
+/// The rules with their options, the settings and the options of `linter`, which decide its
+/// diagnostics for a given file.
+fn config_hash(linter: &Linter) -> u64 {
+    let config = format!(
+        "{:?}",
+        (&linter.rules, &linter.settings, &linter.flat_config, &linter.jest, &linter.options)
+    );
+    hash(&config)
+}
+
+fn hash(text: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::Path};
+
+    use super::LintCache;
+    use crate::{AllowWarnDeny, LintOptions, Linter};
+
+    fn linter(filter: &str) -> Linter {
+        let filter = vec![(AllowWarnDeny::Deny, filter.to_string())];
+        Linter::from_options(LintOptions::default().with_filter(filter)).unwrap()
+    }
+
+    #[test]
+    fn round_trip_and_invalidate() {
+        let dir = env::temp_dir().join(format!("oxc-lint-cache-{}", std::process::id()));
+        let cache_path = dir.join("cache/.oxlintcache");
+        let (foo, bar) = (Path::new("foo.js"), Path::new("bar.js"));
+
+        let cache = LintCache::load(cache_path.clone(), &linter("correctness"));
+        assert!(!cache.is_clean(foo, "debugger"));
+        cache.update(foo, "let a = 1", true);
+        cache.update(bar, "debugger", false);
+        cache.save().unwrap();
+
+        let cache = LintCache::load(cache_path.clone(), &linter("correctness"));
+        assert!(cache.is_clean(foo, "let a = 1"));
+        assert!(!cache.is_clean(foo, "let a = 2"));
+        assert!(!cache.is_clean(bar, "debugger"));
+
+        // Linted with other rules
+        let cache = LintCache::load(cache_path.clone(), &linter("suspicious"));
+        assert!(!cache.is_clean(foo, "let a = 1"));
+
+        fs::write(&cache_path, "not json").unwrap();
+        let cache = LintCache::load(cache_path, &linter("correctness"));
+        assert!(!cache.is_clean(foo, "let a = 1"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
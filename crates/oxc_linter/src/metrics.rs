@@ -0,0 +1,321 @@
+//! Metrics of the functions of a file, for the rules `complexity`, `max-depth`,
+//! `max-lines-per-function` and `max-params`.
+//!
+//! They are computed once per file, on the first use by one of these rules, in a single pass
+//! over the nodes: the nodes are in source order, so the function and nesting depth of a node are
+//! known from its parent. The lines of the file are classified in a single pass too, so that the
+//! lines of a function are counted from its first and last line.
+
+use oxc_ast::{
+    ast::{MethodDefinition, MethodDefinitionKind, ObjectProperty, PropertyKey, PropertyKind},
+    AstKind, TriviasMap,
+};
+use oxc_semantic::{AstNode, AstNodeId, AstNodes};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::LintContext,
+    utils::{check_option, check_properties, OptionType},
+};
+
+#[derive(Debug, Default)]
+pub struct FunctionMetrics {
+    /// Cyclomatic complexity, one plus the number of branches of the function
+    pub complexity: usize,
+    /// Statements nesting blocks, such as `if` and loops, with their depth in the function
+    pub blocks: Vec<(Span, usize)>,
+}
+
+/// Metrics of the functions and of the top level of the program
+#[derive(Debug, Default)]
+pub struct Metrics {
+    functions: FxHashMap<AstNodeId, FunctionMetrics>,
+    lines: Lines,
+}
+
+impl Metrics {
+    pub fn new(nodes: &AstNodes, source_text: &str, trivias: &TriviasMap) -> Self {
+        let mut functions = FxHashMap::default();
+        // The function and the nesting depth of each node, by node id
+        let mut owners = Vec::<(AstNodeId, usize)>::new();
+        for node in nodes.iter() {
+            let kind = node.kind();
+            let parent = nodes.parent_id(node.id());
+            let (owner, mut depth) = match parent {
+                Some(parent) if !is_function(kind) => owners[parent.index()],
+                _ => {
+                    functions.insert(node.id(), FunctionMetrics { complexity: 1, blocks: vec![] });
+                    (node.id(), 0)
+                }
+            };
+            let metrics = functions.get_mut(&owner).unwrap();
+            if is_branch(kind) {
+                metrics.complexity += 1;
+            }
+            // `else if` is at the depth of its `if`
+            let is_else_if = matches!(kind, AstKind::IfStatement(_))
+                && matches!(parent.map(|parent| nodes.kind(parent)), Some(AstKind::IfStatement(_)));
+            if is_block(kind) && !is_else_if {
+                depth += 1;
+                metrics.blocks.push((kind.span(), depth));
+            }
+            owners.push((owner, depth));
+        }
+        Self { functions, lines: Lines::new(source_text, trivias) }
+    }
+
+    /// Metrics of a function, or of the top level code with the id of the program
+    pub fn get(&self, node_id: AstNodeId) -> Option<&FunctionMetrics> {
+        self.functions.get(&node_id)
+    }
+
+    pub fn lines(&self) -> &Lines {
+        &self.lines
+    }
+}
+
+/// The lines of the file, with the number of blank lines and of lines with only comments before
+/// each line
+#[derive(Debug, Default)]
+pub struct Lines {
+    /// Offset of the start of each line
+    starts: Vec<u32>,
+    /// Blank lines before each line, and in the file as the last item
+    blank: Vec<usize>,
+    /// Lines with only comments before each line, and in the file as the last item
+    comment: Vec<usize>,
+}
+
+impl Lines {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(source_text: &str, trivias: &TriviasMap) -> Self {
+        let comments = trivias.comments();
+        let mut lines = Self { starts: vec![], blank: vec![0], comment: vec![0] };
+        let mut offset = 0;
+        for line in source_text.split('\n') {
+            lines.starts.push(offset as u32);
+            let start = offset + (line.len() - line.trim_start().len());
+            let end = offset + line.trim_end().len();
+            offset += line.len() + 1;
+            let is_blank = start >= end;
+            // Comments are keyed by the start of their text, after `//` or `/*`
+            let is_comment = !is_blank
+                && comments.range(..=start as u32 + 2).next_back().is_some_and(|(key, comment)| {
+                    let comment_end =
+                        if comment.is_multi_line() { comment.end() + 2 } else { comment.end() };
+                    *key as usize - 2 <= start && end <= comment_end as usize
+                });
+            lines.blank.push(lines.blank.last().unwrap() + usize::from(is_blank));
+            lines.comment.push(lines.comment.last().unwrap() + usize::from(is_comment));
+        }
+        lines
+    }
+
+    /// Index of the line containing `offset`
+    fn line_of(&self, offset: u32) -> usize {
+        self.starts.partition_point(|start| *start <= offset).saturating_sub(1)
+    }
+
+    /// Lines from the first to the last one of `span`, without the blank lines and the lines of
+    /// comments if they are skipped
+    pub fn count(&self, span: Span, skip_blank_lines: bool, skip_comments: bool) -> usize {
+        let (first, last) = (self.line_of(span.start), self.line_of(span.end) + 1);
+        let mut count = last - first;
+        if skip_blank_lines {
+            count -= self.blank[last] - self.blank[first];
+        }
+        if skip_comments {
+            count -= self.comment[last] - self.comment[first];
+        }
+        count
+    }
+}
+
+fn is_function(kind: AstKind) -> bool {
+    matches!(kind, AstKind::Function(_) | AstKind::ArrowExpression(_))
+}
+
+fn is_branch(kind: AstKind) -> bool {
+    match kind {
+        AstKind::IfStatement(_)
+        | AstKind::ConditionalExpression(_)
+        | AstKind::LogicalExpression(_)
+        | AstKind::ForStatement(_)
+        | AstKind::ForInStatement(_)
+        | AstKind::ForOfStatement(_)
+        | AstKind::WhileStatement(_)
+        | AstKind::DoWhileStatement(_)
+        | AstKind::CatchClause(_) => true,
+        // `default` is not a branch
+        AstKind::SwitchCase(case) => case.test.is_some(),
+        // `a ||= b`
+        AstKind::AssignmentExpression(expr) => expr.operator.is_logical(),
+        _ => false,
+    }
+}
+
+fn is_block(kind: AstKind) -> bool {
+    matches!(
+        kind,
+        AstKind::IfStatement(_)
+            | AstKind::SwitchStatement(_)
+            | AstKind::TryStatement(_)
+            | AstKind::DoWhileStatement(_)
+            | AstKind::WhileStatement(_)
+            | AstKind::WithStatement(_)
+            | AstKind::ForStatement(_)
+            | AstKind::ForInStatement(_)
+            | AstKind::ForOfStatement(_)
+    )
+}
+
+/// The method or property of a function which is the method of a class or an object, e.g.
+/// `foo() {}` or `get foo() {}`
+fn embedding_method<'a, 'b>(
+    node: &AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    let AstKind::Function(_) = node.kind() else { return None };
+    let parent = ctx.nodes().parent_node(node.id())?;
+    match parent.kind() {
+        AstKind::MethodDefinition(_) => Some(parent),
+        AstKind::ObjectProperty(prop) if prop.method || prop.kind != PropertyKind::Init => {
+            Some(parent)
+        }
+        _ => None,
+    }
+}
+
+/// Span of a function with its method, e.g. `get foo() {}` instead of `() {}`
+pub fn function_span<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Span {
+    embedding_method(node, ctx).unwrap_or(node).kind().span()
+}
+
+/// Span of the head of a function, where its problems are reported: before its parameters, e.g.
+/// `async function foo` or `static foo`, and up to the arrow of an arrow function, e.g. `(a) =>`.
+#[allow(clippy::cast_possible_truncation)]
+pub fn function_head<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Span {
+    let source_text = ctx.source_text();
+    let head = match node.kind() {
+        AstKind::Function(func) => {
+            Span::new(function_span(node, ctx).start, func.params.span.start)
+        }
+        AstKind::ArrowExpression(arrow) => {
+            let params_end = arrow.params.span.end;
+            let arrow_end = source_text[params_end as usize..arrow.span.end as usize]
+                .find("=>")
+                .map_or(params_end, |index| params_end + index as u32 + 2);
+            Span::new(arrow.span.start, arrow_end)
+        }
+        kind => return kind.span(),
+    };
+    let text = head.source_text(source_text).trim_end();
+    Span::new(head.start, head.start + text.len() as u32)
+}
+
+/// Whether the function is called where it is defined, e.g. `(function () {})()`
+pub fn is_iife<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    ctx.nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .find(|parent| !matches!(parent.kind(), AstKind::ParenthesizedExpression(_)))
+        .is_some_and(|parent| matches!(parent.kind(), AstKind::CallExpression(_)))
+}
+
+/// Kind and name of a function as ESLint words them in messages, e.g. `Function 'foo'`,
+/// `Async arrow function` or `Static method 'bar'`.
+pub fn function_name_with_kind<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> String {
+    let (id, is_async, is_generator, is_arrow) = match node.kind() {
+        AstKind::Function(func) => {
+            (func.id.as_ref().map(|id| id.name.to_string()), func.r#async, func.generator, false)
+        }
+        AstKind::ArrowExpression(arrow) => (None, arrow.r#async, false, true),
+        _ => return "Function".into(),
+    };
+    let parent = ctx.nodes().parent_node(node.id()).map(AstNode::kind);
+
+    let mut words = vec![];
+    let key = match parent {
+        Some(AstKind::MethodDefinition(method)) => {
+            if method.kind == MethodDefinitionKind::Constructor {
+                return "Constructor".into();
+            }
+            if method.r#static {
+                words.push("static");
+            }
+            if method.key.is_private_identifier() {
+                words.push("private");
+            }
+            Some(&method.key)
+        }
+        Some(AstKind::PropertyDefinition(prop)) => {
+            if prop.r#static {
+                words.push("static");
+            }
+            if prop.key.is_private_identifier() {
+                words.push("private");
+            }
+            Some(&prop.key)
+        }
+        Some(AstKind::ObjectProperty(prop)) => Some(&prop.key),
+        _ => None,
+    };
+    if is_async {
+        words.push("async");
+    }
+    if is_generator {
+        words.push("generator");
+    }
+    words.push(match parent {
+        Some(
+            AstKind::MethodDefinition(MethodDefinition { kind: MethodDefinitionKind::Get, .. })
+            | AstKind::ObjectProperty(ObjectProperty { kind: PropertyKind::Get, .. }),
+        ) => "getter",
+        Some(
+            AstKind::MethodDefinition(MethodDefinition { kind: MethodDefinitionKind::Set, .. })
+            | AstKind::ObjectProperty(ObjectProperty { kind: PropertyKind::Set, .. }),
+        ) => "setter",
+        Some(
+            AstKind::MethodDefinition(_)
+            | AstKind::ObjectProperty(_)
+            | AstKind::PropertyDefinition(_),
+        ) => "method",
+        _ if is_arrow => "arrow function",
+        _ => "function",
+    });
+
+    let name = match key {
+        Some(PropertyKey::PrivateIdentifier(ident)) => Some(format!("#{}", ident.name)),
+        Some(key) => key.static_name().map(|name| name.to_string()).or(id),
+        None => id,
+    };
+    let mut text = words.join(" ");
+    if let Some(name) = name {
+        text = format!("{text} '{name}'");
+    }
+    let mut chars = text.chars();
+    chars.next().map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+}
+
+/// The threshold of a metrics rule, configured as ESLint accepts it: `[5]`, `[{ "max": 5 }]` or
+/// the deprecated `[{ "maximum": 5 }]`.
+pub fn max_option(value: &serde_json::Value, default: usize) -> usize {
+    let Some(option) = value.get(0) else { return default };
+    option
+        .as_u64()
+        .or_else(|| option.get("max").and_then(serde_json::Value::as_u64))
+        .or_else(|| option.get("maximum").and_then(serde_json::Value::as_u64))
+        .map_or(default, |max| usize::try_from(max).unwrap_or(default))
+}
+
+/// Checks the threshold read by [`max_option`].
+pub fn check_max_option(value: &serde_json::Value) -> Result<(), String> {
+    match value.get(0) {
+        Some(option) if option.is_object() => check_properties(
+            Some(option),
+            &[("max", OptionType::Count), ("maximum", OptionType::Count)],
+        ),
+        option => check_option(option, OptionType::Count),
+    }
+}
@@ -1,6 +1,7 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
 use crate::{
+    budget::FileBudget,
     config::{
         errors::{
             FailedToParseAllowWarnDenyFromJsonValueError,
@@ -8,6 +9,8 @@ use crate::{
         },
         ESLintConfig,
     },
+    fixer::Newline,
+    rule::FixKind,
     rules::RULES,
     LintSettings, RuleCategory, RuleEnum,
 };
@@ -21,11 +24,50 @@ pub struct LintOptions {
     /// Defaults to [("deny", "correctness")]
     pub filter: Vec<(AllowWarnDeny, String)>,
     pub config_path: Option<PathBuf>,
+    /// Config which `config_path` cascades from, e.g. the config of the workspace root for the
+    /// config of a workspace package.
+    pub base_config_path: Option<PathBuf>,
     pub fix: bool,
+    /// The least safe kind of fix applied with `fix`, defaults to [FixKind::Safe].
+    pub fix_kind: FixKind,
+    /// Rules whose diagnostics are silenced by `fix` with `// eslint-disable-next-line <rule> -- TODO`
+    /// comments instead of being fixed.
+    pub fix_suppress: Vec<String>,
+    /// Line endings of the files written by `fix`, those of each file by default.
+    pub newline: Newline,
     pub timing: bool,
     pub import_plugin: bool,
+    /// tsconfig used to resolve `baseUrl` and `paths` aliases in the import plugin.
+    /// Defaults to the nearest `tsconfig.json` of each file.
+    pub tsconfig: Option<PathBuf>,
+    /// File to keep the module resolutions of the import plugin in between runs.
+    pub resolve_cache: Option<PathBuf>,
+    /// File to keep the files without diagnostics in between runs, which are skipped as long as
+    /// they and the configuration do not change. Not used with the import plugin, whose
+    /// diagnostics depend on other files.
+    pub cache: Option<PathBuf>,
     pub jest_plugin: bool,
     pub jsx_a11y_plugin: bool,
+    /// Hard-coded credentials and high-entropy strings
+    pub secrets_plugin: bool,
+    /// Only report the syntax errors of the parser, without semantic analysis and rules.
+    pub syntax_only: bool,
+    pub file_budget: FileBudget,
+    /// Report the diagnostics silenced by disable directives as advice and count them per rule.
+    pub show_suppressed: bool,
+    /// Report the disable directives which silence no diagnostic.
+    pub report_unused_directives: bool,
+    /// Warn about rules which take longer than this on a single file.
+    pub timing_threshold: Option<Duration>,
+    /// Record the time of each rule on each file for [crate::Linter::write_profile].
+    pub profile: bool,
+    /// Lint the files excluded by the `ignorePatterns` or `ignores` of the config.
+    pub no_ignore: bool,
+    /// Lint the given files with an unsupported extension as JavaScript instead of skipping them.
+    pub force: bool,
+    /// Append a report of each panic of the linter to this file, with the source of the file
+    /// reduced to the lines which reproduce it.
+    pub crash_report: Option<PathBuf>,
 }
 
 impl Default for LintOptions {
@@ -33,11 +75,28 @@ impl Default for LintOptions {
         Self {
             filter: vec![(AllowWarnDeny::Deny, String::from("correctness"))],
             config_path: None,
+            base_config_path: None,
             fix: false,
+            fix_kind: FixKind::Safe,
+            fix_suppress: vec![],
+            newline: Newline::Auto,
             timing: false,
             import_plugin: false,
+            tsconfig: None,
+            resolve_cache: None,
+            cache: None,
             jest_plugin: false,
             jsx_a11y_plugin: false,
+            secrets_plugin: false,
+            syntax_only: false,
+            file_budget: FileBudget::default(),
+            show_suppressed: false,
+            report_unused_directives: false,
+            timing_threshold: None,
+            profile: false,
+            no_ignore: false,
+            force: false,
+            crash_report: None,
         }
     }
 }
@@ -57,12 +116,36 @@ impl LintOptions {
         self
     }
 
+    #[must_use]
+    pub fn with_base_config_path(mut self, path: Option<PathBuf>) -> Self {
+        self.base_config_path = path;
+        self
+    }
+
     #[must_use]
     pub fn with_fix(mut self, yes: bool) -> Self {
         self.fix = yes;
         self
     }
 
+    #[must_use]
+    pub fn with_fix_kind(mut self, fix_kind: FixKind) -> Self {
+        self.fix_kind = fix_kind;
+        self
+    }
+
+    #[must_use]
+    pub fn with_fix_suppress(mut self, rules: Vec<String>) -> Self {
+        self.fix_suppress = rules;
+        self
+    }
+
+    #[must_use]
+    pub fn with_newline(mut self, newline: Newline) -> Self {
+        self.newline = newline;
+        self
+    }
+
     #[must_use]
     pub fn with_timing(mut self, yes: bool) -> Self {
         self.timing = yes;
@@ -75,6 +158,24 @@ impl LintOptions {
         self
     }
 
+    #[must_use]
+    pub fn with_tsconfig(mut self, tsconfig: Option<PathBuf>) -> Self {
+        self.tsconfig = tsconfig;
+        self
+    }
+
+    #[must_use]
+    pub fn with_resolve_cache(mut self, path: Option<PathBuf>) -> Self {
+        self.resolve_cache = path;
+        self
+    }
+
+    #[must_use]
+    pub fn with_cache(mut self, path: Option<PathBuf>) -> Self {
+        self.cache = path;
+        self
+    }
+
     #[must_use]
     pub fn with_jest_plugin(mut self, yes: bool) -> Self {
         self.jest_plugin = yes;
@@ -86,6 +187,66 @@ impl LintOptions {
         self.jsx_a11y_plugin = yes;
         self
     }
+
+    #[must_use]
+    pub fn with_secrets_plugin(mut self, yes: bool) -> Self {
+        self.secrets_plugin = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_file_budget(mut self, file_budget: FileBudget) -> Self {
+        self.file_budget = file_budget;
+        self
+    }
+
+    #[must_use]
+    pub fn with_syntax_only(mut self, yes: bool) -> Self {
+        self.syntax_only = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_show_suppressed(mut self, yes: bool) -> Self {
+        self.show_suppressed = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_unused_directives(mut self, yes: bool) -> Self {
+        self.report_unused_directives = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_timing_threshold(mut self, threshold: Option<Duration>) -> Self {
+        self.timing_threshold = threshold;
+        self
+    }
+
+    #[must_use]
+    pub fn with_profile(mut self, yes: bool) -> Self {
+        self.profile = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_no_ignore(mut self, yes: bool) -> Self {
+        self.no_ignore = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_force(mut self, yes: bool) -> Self {
+        self.force = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_crash_report(mut self, path: Option<PathBuf>) -> Self {
+        self.crash_report = path;
+        self
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -139,8 +300,64 @@ impl TryFrom<&Number> for AllowWarnDeny {
     }
 }
 
+/// Whether the `-A` / `-D` / `-W` filter `name` selects `rule`, with `name` either:
+/// * `all`
+/// * `plugin:<plugin>` for the rules of a plugin, e.g. `plugin:import`
+/// * `<plugin>/<rule>` for the rules of a plugin, e.g. `import/*` or `jest/no-*`
+/// * `<rule>`, e.g. `no-debugger` or `no-*`
+///
+/// Rule names may contain `*` and `?` wildcards.
+fn is_rule_selected(rule: &RuleEnum, name: &str) -> bool {
+    if name == "all" {
+        return true;
+    }
+    if let Some(plugin) = name.strip_prefix("plugin:") {
+        return is_plugin(rule, plugin);
+    }
+    match name.split_once('/') {
+        Some((plugin, rule_name)) => {
+            is_plugin(rule, plugin) && is_glob_match(rule_name, rule.name())
+        }
+        None => is_glob_match(name, rule.name()),
+    }
+}
+
+/// Plugins are named after their module, e.g. `jsx_a11y` for `jsx-a11y` and `typescript` for
+/// `@typescript-eslint`.
+fn is_plugin(rule: &RuleEnum, plugin: &str) -> bool {
+    let plugin = plugin.trim_start_matches('@');
+    let plugin = if plugin == "typescript-eslint" { "typescript" } else { plugin };
+    rule.plugin_name() == plugin.replace('-', "_")
+}
+
+/// Match `name` against `pattern`, where `*` matches any characters and `?` one character.
+fn is_glob_match(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let name = name.as_bytes();
+    let (mut p, mut n) = (0, 0);
+    // Position of the last `*` in the pattern and of the name where it started to match
+    let mut star = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == b'?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == b'*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
 const JEST_PLUGIN_NAME: &str = "jest";
 const JSX_A11Y_PLUGIN_NAME: &str = "jsx_a11y";
+const SECRETS_PLUGIN_NAME: &str = "secrets";
 
 impl LintOptions {
     /// # Errors
@@ -149,7 +366,8 @@ impl LintOptions {
         let mut rules: FxHashSet<RuleEnum> = FxHashSet::default();
 
         if let Some(path) = &self.config_path {
-            let (rules, settings) = ESLintConfig::new(path)?.into_rules().get_config();
+            let base = self.base_config_path.as_ref();
+            let (rules, settings) = ESLintConfig::with_base(path, base)?.into_rules().get_config();
             return Ok((rules, settings));
         }
 
@@ -157,38 +375,15 @@ impl LintOptions {
 
         for (allow_warn_deny, name_or_category) in &self.filter {
             let maybe_category = RuleCategory::from(name_or_category.as_str());
+            let is_selected = |rule: &RuleEnum| match maybe_category {
+                Some(category) => rule.category() == category,
+                None => is_rule_selected(rule, name_or_category),
+            };
             match allow_warn_deny {
                 AllowWarnDeny::Deny | AllowWarnDeny::Warn => {
-                    match maybe_category {
-                        Some(category) => rules.extend(
-                            all_rules.iter().filter(|rule| rule.category() == category).cloned(),
-                        ),
-                        None => {
-                            if name_or_category == "all" {
-                                rules.extend(all_rules.iter().cloned());
-                            } else {
-                                rules.extend(
-                                    all_rules
-                                        .iter()
-                                        .filter(|rule| rule.name() == name_or_category)
-                                        .cloned(),
-                                );
-                            }
-                        }
-                    };
-                }
-                AllowWarnDeny::Allow => {
-                    match maybe_category {
-                        Some(category) => rules.retain(|rule| rule.category() != category),
-                        None => {
-                            if name_or_category == "all" {
-                                rules.clear();
-                            } else {
-                                rules.retain(|rule| rule.name() != name_or_category);
-                            }
-                        }
-                    };
+                    rules.extend(all_rules.iter().filter(|rule| is_selected(rule)).cloned());
                 }
+                AllowWarnDeny::Allow => rules.retain(|rule| !is_selected(rule)),
             }
         }
 
@@ -198,7 +393,8 @@ impl LintOptions {
         Ok((rules, LintSettings::default()))
     }
 
-    // get final filtered rules by reading `self.jest_plugin` and `self.jsx_a11y_plugin`
+    // get final filtered rules by reading `self.jest_plugin`, `self.jsx_a11y_plugin` and
+    // `self.secrets_plugin`
     fn get_filtered_rules(&self) -> Vec<RuleEnum> {
         let mut rules = RULES.clone();
 
@@ -210,7 +406,57 @@ impl LintOptions {
 
         may_exclude_plugin_rules(self.jest_plugin, JEST_PLUGIN_NAME);
         may_exclude_plugin_rules(self.jsx_a11y_plugin, JSX_A11Y_PLUGIN_NAME);
+        may_exclude_plugin_rules(self.secrets_plugin, SECRETS_PLUGIN_NAME);
 
         rules
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{is_glob_match, LintOptions};
+    use crate::AllowWarnDeny;
+
+    #[test]
+    fn glob() {
+        assert!(is_glob_match("no-debugger", "no-debugger"));
+        assert!(is_glob_match("no-*", "no-debugger"));
+        assert!(is_glob_match("*", "no-debugger"));
+        assert!(is_glob_match("no-*-vars", "no-unused-vars"));
+        assert!(is_glob_match("no-?ar", "no-var"));
+        assert!(!is_glob_match("no-*", "eqeqeq"));
+        assert!(!is_glob_match("no-debugger", "no-debugger-foo"));
+    }
+
+    fn rule_names(filter: &[(AllowWarnDeny, &str)]) -> Vec<(String, &'static str)> {
+        let filter = filter.iter().map(|(level, name)| (*level, (*name).to_string())).collect();
+        let options = LintOptions::default().with_filter(filter).with_jest_plugin(true);
+        let (rules, _) = options.derive_rules_and_settings().unwrap();
+        rules.iter().map(|rule| (rule.plugin_name().to_string(), rule.name())).collect()
+    }
+
+    #[test]
+    fn filter_plugin() {
+        let rules = rule_names(&[(AllowWarnDeny::Allow, "all"), (AllowWarnDeny::Deny, "jest/*")]);
+        assert!(!rules.is_empty());
+        assert!(rules.iter().all(|(plugin, _)| plugin == "jest"));
+        assert_eq!(
+            rules,
+            rule_names(&[(AllowWarnDeny::Allow, "all"), (AllowWarnDeny::Deny, "plugin:jest")])
+        );
+
+        let rules = rule_names(&[(AllowWarnDeny::Deny, "all"), (AllowWarnDeny::Allow, "jest/*")]);
+        assert!(rules.iter().all(|(plugin, _)| plugin != "jest"));
+    }
+
+    #[test]
+    fn filter_glob() {
+        let rules =
+            rule_names(&[(AllowWarnDeny::Allow, "all"), (AllowWarnDeny::Warn, "jest/no-*")]);
+        assert!(rules.iter().all(|(plugin, name)| plugin == "jest" && name.starts_with("no-")));
+        assert!(rules.iter().any(|(_, name)| *name == "no-disabled-tests"));
+
+        let rules = rule_names(&[(AllowWarnDeny::Deny, "all"), (AllowWarnDeny::Allow, "no-*")]);
+        assert!(rules.iter().all(|(_, name)| !name.starts_with("no-")));
+    }
+}
@@ -0,0 +1,174 @@
+//! `package.json` files of the linted files, shared by the resolve cache and the rules of the
+//! import plugin
+//!
+//! The nearest `package.json` of each directory is looked up once, until
+//! [clear_package_json_cache] is called by long running processes, the language server and the
+//! watch mode, when a `package.json` is created, moved or removed. Parsed files are read again
+//! when their modification time changes.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
+
+use dashmap::DashMap;
+use lazy_static::lazy_static;
+use rustc_hash::FxHashSet;
+use serde_json::Value;
+
+lazy_static! {
+    /// The nearest `package.json` of each directory
+    static ref NEAREST: DashMap<Box<Path>, Option<PathBuf>> = DashMap::default();
+    static ref PARSED: DashMap<PathBuf, (Option<SystemTime>, Arc<PackageJson>)> =
+        DashMap::default();
+}
+
+#[derive(Debug, Default)]
+pub struct PackageJson {
+    pub path: PathBuf,
+    pub name: Option<String>,
+    pub dependencies: FxHashSet<String>,
+    pub dev_dependencies: FxHashSet<String>,
+    pub peer_dependencies: FxHashSet<String>,
+    pub optional_dependencies: FxHashSet<String>,
+    pub bundled_dependencies: FxHashSet<String>,
+    /// Whether this is the root of a npm or yarn workspace, with `workspaces`, or of a pnpm
+    /// workspace, next to `pnpm-workspace.yaml`
+    pub is_workspace_root: bool,
+    /// Whether the subpaths of the package are restricted to its `exports`
+    pub has_exports: bool,
+}
+
+impl PackageJson {
+    fn parse(path: &Path, source_text: &str) -> Option<Self> {
+        let json = serde_json::from_str::<Value>(source_text).ok()?;
+        let names = |key: &str| match json.get(key) {
+            Some(Value::Object(dependencies)) => dependencies.keys().cloned().collect(),
+            Some(Value::Array(dependencies)) => {
+                dependencies.iter().filter_map(Value::as_str).map(ToString::to_string).collect()
+            }
+            _ => FxHashSet::default(),
+        };
+        let mut bundled_dependencies = names("bundledDependencies");
+        bundled_dependencies.extend(names("bundleDependencies"));
+        Some(Self {
+            path: path.to_path_buf(),
+            name: json.get("name").and_then(Value::as_str).map(ToString::to_string),
+            dependencies: names("dependencies"),
+            dev_dependencies: names("devDependencies"),
+            peer_dependencies: names("peerDependencies"),
+            optional_dependencies: names("optionalDependencies"),
+            bundled_dependencies,
+            is_workspace_root: json.get("workspaces").is_some()
+                || path.with_file_name("pnpm-workspace.yaml").is_file(),
+            has_exports: json.get("exports").is_some(),
+        })
+    }
+
+    /// The parsed `package.json` at `path`, `None` if it cannot be read or is not valid JSON.
+    pub fn load(path: &Path) -> Option<Arc<Self>> {
+        let modified = fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+        if let Some(entry) = PARSED.get(path) {
+            if entry.0 == modified {
+                return Some(Arc::clone(&entry.1));
+            }
+        }
+        let package_json = Arc::new(Self::parse(path, &fs::read_to_string(path).ok()?)?);
+        PARSED.insert(path.to_path_buf(), (modified, Arc::clone(&package_json)));
+        Some(package_json)
+    }
+
+    /// The `package.json` of the root of the workspace of the package of this `package.json`.
+    pub fn workspace_root(&self) -> Option<Arc<Self>> {
+        let dir = self.path.parent()?.parent()?;
+        let mut path = nearest_package_json(dir);
+        while let Some(package_json) = path.as_deref().and_then(Self::load) {
+            if package_json.is_workspace_root {
+                return Some(package_json);
+            }
+            path = package_json.path.parent()?.parent().and_then(nearest_package_json);
+        }
+        None
+    }
+
+    /// Whether the package, e.g. `lodash` or `@scope/name`, is in `dependencies`.
+    pub fn has_dependency(&self, name: &str) -> bool {
+        self.dependencies.contains(name)
+    }
+}
+
+/// The `package.json` in the nearest ancestor of `path` which has one.
+pub fn nearest_package_json(path: &Path) -> Option<PathBuf> {
+    if let Some(nearest) = NEAREST.get(path) {
+        return nearest.clone();
+    }
+    let package_json = path.join("package.json");
+    let nearest = if package_json.is_file() {
+        Some(package_json)
+    } else {
+        path.parent().and_then(nearest_package_json)
+    };
+    NEAREST.insert(path.into(), nearest.clone());
+    nearest
+}
+
+/// Forget the nearest `package.json` of the directories, which changes when a `package.json` is
+/// created, moved or removed.
+pub fn clear_package_json_cache() {
+    NEAREST.clear();
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::Path};
+
+    use super::{clear_package_json_cache, nearest_package_json, PackageJson};
+
+    #[test]
+    fn parse() {
+        let source_text = r#"{
+            "name": "app",
+            "dependencies": { "react": "^18.0.0" },
+            "devDependencies": { "jest": "*" },
+            "bundledDependencies": ["@generated/foo"]
+        }"#;
+        let package_json = PackageJson::parse(Path::new("package.json"), source_text).unwrap();
+        assert_eq!(package_json.name.as_deref(), Some("app"));
+        assert!(package_json.has_dependency("react"));
+        assert!(package_json.dev_dependencies.contains("jest"));
+        assert!(package_json.bundled_dependencies.contains("@generated/foo"));
+        assert!(!package_json.is_workspace_root);
+    }
+
+    #[test]
+    fn workspace_root() {
+        let fixtures = env::current_dir().unwrap().join("fixtures/import/workspaces");
+        let path = nearest_package_json(&fixtures.join("packages/app/src")).unwrap();
+        let package_json = PackageJson::load(&path).unwrap();
+        assert_eq!(package_json.name.as_deref(), Some("app"));
+        let root = package_json.workspace_root().unwrap();
+        assert_eq!(root.path, fixtures.join("package.json"));
+    }
+
+    #[test]
+    fn created_package_json() {
+        let dir = env::temp_dir().join(format!("oxc-package-json-{}", std::process::id()));
+        let src = dir.join("packages/app/src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(dir.join("package.json"), "{}").unwrap();
+        assert_eq!(nearest_package_json(&src), Some(dir.join("package.json")));
+
+        let package_json = dir.join("packages/app/package.json");
+        fs::write(&package_json, r#"{ "name": "app" }"#).unwrap();
+        clear_package_json_cache();
+        assert_eq!(nearest_package_json(&src), Some(package_json.clone()));
+        assert_eq!(PackageJson::load(&package_json).unwrap().name.as_deref(), Some("app"));
+
+        fs::remove_file(&package_json).unwrap();
+        clear_package_json_cache();
+        assert_eq!(nearest_package_json(&src), Some(dir.join("package.json")));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
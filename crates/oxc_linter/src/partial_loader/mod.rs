@@ -4,10 +4,17 @@ use self::vue_partial_loader::VuePartialLoader;
 
 pub mod vue_partial_loader;
 
-pub const LINT_PARTIAL_LOADER_EXT: &[&str] = &["vue"];
+pub const LINT_PARTIAL_LOADER_EXT: &[&str] = &["vue", "svelte"];
 
+/// Extracts the script of a single file component.
+///
+/// Everything outside of the script is blanked out, so spans and line numbers of the extracted
+/// script are valid in the original file. Diagnostics, fixes and source maps produced from the
+/// script can be applied to the component directly.
 pub enum PartialLoader {
     Vue,
+    /// Svelte components have no `<template>` wrapper, the vue loader reads their top level script.
+    Svelte,
 }
 
 #[derive(Default)]
@@ -26,10 +33,17 @@ impl PartialLoaderValue {
 }
 
 impl PartialLoader {
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext {
+            "vue" => Some(Self::Vue),
+            "svelte" => Some(Self::Svelte),
+            _ => None,
+        }
+    }
+
     pub fn parse(&self, source_text: &str) -> PartialLoaderValue {
-        if matches!(self, Self::Vue) {
-            return VuePartialLoader::from(source_text).build();
+        match self {
+            Self::Vue | Self::Svelte => VuePartialLoader::from(source_text).build(),
         }
-        PartialLoaderValue::default()
     }
 }
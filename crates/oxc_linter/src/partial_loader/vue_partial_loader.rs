@@ -7,7 +7,8 @@ use super::PartialLoaderValue;
 pub struct VuePartialLoader<'a> {
     source_text: &'a str,
     chars: Chars<'a>,
-    code: Vec<u8>,
+    /// Extracted script, everything else is blanked out so that offsets match the source text
+    code: String,
     is_ts: bool,
     is_jsx: bool,
     is_reading_js: bool,
@@ -20,7 +21,7 @@ impl<'a> VuePartialLoader<'a> {
         Self {
             source_text,
             chars: source_text.chars(),
-            code: vec![],
+            code: String::new(),
             is_ts: false,
             is_jsx: false,
             is_reading_js: false,
@@ -29,9 +30,7 @@ impl<'a> VuePartialLoader<'a> {
     }
     pub fn build(mut self) -> PartialLoaderValue {
         self.parse();
-        // SAFETY: criteria of `from_utf8_unchecked`.are met.
-        let js_content = unsafe { String::from_utf8_unchecked(self.code) };
-        PartialLoaderValue::from(js_content, self.is_ts, self.is_jsx)
+        PartialLoaderValue::from(self.code, self.is_ts, self.is_jsx)
     }
     fn parse(&mut self) {
         while let Some(ch) = self.advance() {
@@ -114,10 +113,10 @@ impl<'a> VuePartialLoader<'a> {
     }
     fn skip_to_end_of_template_literal(&mut self) {
         let mut last_is_escape = false;
-        let mut chars = vec![];
+        let mut chars = String::new();
 
         while let Some(c) = self.advance() {
-            chars.push(c as u8);
+            chars.push(c);
             if last_is_escape {
                 last_is_escape = false;
                 continue;
@@ -130,23 +129,23 @@ impl<'a> VuePartialLoader<'a> {
                 '$' => {
                     if self.peek() == Some('{') {
                         let sub_chars = self.skip_to_end_dollar_brace();
-                        chars.extend(sub_chars);
+                        chars.push_str(&sub_chars);
                     }
                 }
                 _ => last_is_escape = false,
             }
         }
 
-        self.push_str_or_multi_space(chars);
+        self.push_str_or_multi_space(&chars);
     }
-    fn skip_to_end_dollar_brace(&mut self) -> Vec<u8> {
+    fn skip_to_end_dollar_brace(&mut self) -> String {
         self.advance();
-        let mut chars = vec![b'{'];
+        let mut chars = String::from('{');
         let mut brace_depth = 0;
         let mut last_is_escape = false;
 
         while let Some(c) = self.advance() {
-            chars.push(c as u8);
+            chars.push(c);
             if last_is_escape {
                 last_is_escape = false;
                 continue;
@@ -171,10 +170,10 @@ impl<'a> VuePartialLoader<'a> {
     }
     fn skip_until_next_delimiter(&mut self, delimiter: char) {
         let mut last_is_escape = false;
-        let mut chars = vec![];
+        let mut chars = String::new();
 
         for c in self.chars.by_ref() {
-            chars.push(c as u8);
+            chars.push(c);
             if last_is_escape {
                 last_is_escape = false;
                 continue;
@@ -190,7 +189,7 @@ impl<'a> VuePartialLoader<'a> {
             }
         }
 
-        self.push_str_or_multi_space(chars);
+        self.push_str_or_multi_space(&chars);
     }
     #[allow(clippy::cast_possible_truncation)]
     fn offset(&self) -> u32 {
@@ -198,9 +197,9 @@ impl<'a> VuePartialLoader<'a> {
     }
     fn eat(&mut self, target: &str) -> bool {
         let mut chars = self.chars.clone();
-        let mut code = vec![];
+        let mut code = String::new();
         for ch in target.chars() {
-            code.push(ch as u8);
+            code.push(ch);
             if let Some(c) = chars.next() {
                 if c != ch {
                     return false;
@@ -210,7 +209,7 @@ impl<'a> VuePartialLoader<'a> {
             }
         }
 
-        self.push_str_or_multi_space(code);
+        self.push_str_or_multi_space(&code);
         self.chars = chars;
         true
     }
@@ -229,16 +228,16 @@ impl<'a> VuePartialLoader<'a> {
         true
     }
     fn eat_to(&mut self, target: char) -> bool {
-        let mut chars = vec![];
+        let mut chars = String::new();
         for ch in self.chars.by_ref() {
-            chars.push(ch as u8);
+            chars.push(ch);
             if target == ch {
-                self.push_str_or_multi_space(chars);
+                self.push_str_or_multi_space(&chars);
                 return true;
             }
         }
 
-        self.push_str_or_multi_space(chars);
+        self.push_str_or_multi_space(&chars);
         false
     }
 
@@ -254,17 +253,21 @@ impl<'a> VuePartialLoader<'a> {
             .any(|flag| s.contains(flag))
     }
 
-    fn push_str_or_multi_space(&mut self, s: Vec<u8>) {
-        for byte in s {
-            self.push_ch_or_space(byte as char);
+    fn push_str_or_multi_space(&mut self, s: &str) {
+        for ch in s.chars() {
+            self.push_ch_or_space(ch);
         }
     }
 
+    /// Characters outside of the script are replaced by as many spaces as they take bytes,
+    /// so spans and line numbers of the extracted script point into the original file.
     fn push_ch_or_space(&mut self, ch: char) {
         if self.is_reading_js {
-            self.code.push(ch as u8);
+            self.code.push(ch);
+        } else if ch == '\n' {
+            self.code.push('\n');
         } else {
-            self.code.push(if ch == '\n' { b'\n' } else { b' ' });
+            self.code.extend(std::iter::repeat(' ').take(ch.len_utf8()));
         }
     }
 }
@@ -492,4 +495,22 @@ mod test {
         assert!(!loader_value.source_type.is_typescript());
         assert_eq!(loader_value.source_text.trim(), "console.log('error')");
     }
+
+    #[test]
+    fn test_offsets_match_source_text() {
+        let source_text = r#"
+        <template>
+          <h1>héllo wörld 👋</h1>
+        </template>
+        <script>
+            console.log("héllo", `👋 ${"wörld"}`)
+        </script>
+        "#;
+
+        let loader_value = VuePartialLoader::from(source_text).build();
+        let start = source_text.find("console").unwrap();
+        let end = source_text.find("</script>").unwrap();
+        assert_eq!(&loader_value.source_text[start..end], &source_text[start..end]);
+        assert_eq!(loader_value.source_text.len(), end);
+    }
 }
@@ -0,0 +1,339 @@
+//! Yarn Plug'n'Play
+//!
+//! Yarn Berry does not write a `node_modules` tree, it instead records where every package lives
+//! in `.pnp.data.json` (or inlined into `.pnp.cjs`). This module reads that manifest and maps a
+//! bare specifier to the directory of the dependency as seen from the importing package. The
+//! resolver takes over from there: the subpath is resolved from the package directory as a
+//! self-reference of the package when it has `exports`, so that they apply as for packages in
+//! `node_modules`.
+//!
+//! Packages stored inside zip archives are not readable by the resolver and will fail to resolve.
+//!
+//! References:
+//! * <https://yarnpkg.com/advanced/pnp-spec>
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use dashmap::DashMap;
+use oxc_resolver::{Resolution, ResolveError, Resolver};
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+use crate::package_json::PackageJson;
+
+const PNP_DATA_FILE: &str = ".pnp.data.json";
+const PNP_RUNTIME_FILE: &str = ".pnp.cjs";
+
+/// `(name, reference)`, both `None` for the top-level locator.
+type Locator = (Option<String>, Option<String>);
+
+#[derive(Debug)]
+struct PnpPackage {
+    /// Absolute path of the package directory
+    location: PathBuf,
+    /// Dependency name to the locator it resolves to, `None` for missing peer dependencies
+    dependencies: FxHashMap<String, Option<Locator>>,
+}
+
+#[derive(Debug)]
+pub struct PnpManifest {
+    packages: FxHashMap<Locator, PnpPackage>,
+    /// Location of each package to its locator, without the top-level locator
+    locations: FxHashMap<PathBuf, Locator>,
+    /// Issuer of each directory imported from, so that it is canonicalized and looked up once
+    issuers: DashMap<Box<Path>, Option<Locator>>,
+    enable_top_level_fallback: bool,
+}
+
+impl PnpManifest {
+    /// Look for a PnP manifest in `cwd` and its ancestors.
+    pub fn find(cwd: &Path) -> Option<Self> {
+        cwd.ancestors().find_map(|dir| {
+            if let Ok(json) = fs::read_to_string(dir.join(PNP_DATA_FILE)) {
+                return Self::from_json(dir, &json);
+            }
+            let runtime = fs::read_to_string(dir.join(PNP_RUNTIME_FILE)).ok()?;
+            Self::from_json(dir, &extract_runtime_state(&runtime)?)
+        })
+    }
+
+    pub fn from_json(root: &Path, json: &str) -> Option<Self> {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        let data: Value = serde_json::from_str(json).ok()?;
+
+        let mut packages = FxHashMap::default();
+        let mut locations = FxHashMap::default();
+        for entry in data.get("packageRegistryData")?.as_array()? {
+            let name = entry.get(0)?.as_str().map(String::from);
+            for store in entry.get(1)?.as_array()? {
+                let reference = store.get(0)?.as_str().map(String::from);
+                let info = store.get(1)?;
+                let location = info.get("packageLocation")?.as_str()?;
+                let dependencies = info
+                    .get("packageDependencies")
+                    .and_then(Value::as_array)
+                    .map(|deps| deps.iter().filter_map(parse_dependency).collect())
+                    .unwrap_or_default();
+                let location = normalize(&root.join(location));
+                let locator = (name.clone(), reference);
+                // A workspace shares its location with the top-level locator
+                if name.is_some() {
+                    locations.insert(location.clone(), locator.clone());
+                }
+                packages.insert(locator, PnpPackage { location, dependencies });
+            }
+        }
+
+        let enable_top_level_fallback =
+            data.get("enableTopLevelFallback").and_then(Value::as_bool).unwrap_or(false);
+        let issuers = DashMap::default();
+        Some(Self { packages, locations, issuers, enable_top_level_fallback })
+    }
+
+    /// Resolve the bare `specifier` imported from `dir` with `resolver`, from the directory of the
+    /// package it refers to. `None` when the manifest does not know that package.
+    pub fn resolve(
+        &self,
+        resolver: &Resolver,
+        dir: &Path,
+        specifier: &str,
+    ) -> Option<Result<Resolution, ResolveError>> {
+        let (package_dir, subpath) = self.resolve_package(dir, specifier)?;
+        Some(resolver.resolve(package_dir, &package_request(package_dir, subpath)))
+    }
+
+    /// Returns the directory of the package `specifier` refers to when imported from `dir`,
+    /// together with the remaining subpath (e.g. `fp/map` for `lodash/fp/map`).
+    ///
+    /// Returns `None` for relative or absolute specifiers, and for files outside of any package
+    /// known to the manifest.
+    fn resolve_package<'s>(&self, dir: &Path, specifier: &'s str) -> Option<(&Path, &'s str)> {
+        let (name, subpath) = parse_bare_specifier(specifier)?;
+        let issuer = self.find_issuer(dir)?;
+
+        let locator = match issuer.dependencies.get(name) {
+            Some(locator) => locator.as_ref()?,
+            None if self.enable_top_level_fallback => {
+                self.packages.get(&(None, None))?.dependencies.get(name)?.as_ref()?
+            }
+            None => return None,
+        };
+        let package = self.packages.get(locator)?;
+        Some((package.location.as_path(), subpath))
+    }
+
+    /// The package with the longest location containing `dir`.
+    /// The top-level locator is only used as a fallback.
+    fn find_issuer(&self, dir: &Path) -> Option<&PnpPackage> {
+        // The entry is not locked while inserting the issuer of a new directory
+        let cached = self.issuers.get(dir).map(|locator| locator.clone());
+        let locator = match cached {
+            Some(locator) => locator,
+            None => {
+                let locator = dir.canonicalize().ok().and_then(|canonical| {
+                    canonical.ancestors().find_map(|dir| self.locations.get(dir)).cloned()
+                });
+                self.issuers.insert(dir.into(), locator.clone());
+                locator
+            }
+        };
+        self.packages.get(&locator?)
+    }
+}
+
+/// `["name", "npm:1.0.0"]`, `["alias", ["name", "npm:1.0.0"]]` or `["peer", null]`
+fn parse_dependency(dep: &Value) -> Option<(String, Option<Locator>)> {
+    let name = dep.get(0)?.as_str()?;
+    let locator = match dep.get(1)? {
+        Value::Null => None,
+        Value::String(reference) => Some((Some(name.to_string()), Some(reference.clone()))),
+        Value::Array(alias) => Some((
+            alias.first()?.as_str().map(String::from),
+            alias.get(1)?.as_str().map(String::from),
+        )),
+        _ => return None,
+    };
+    Some((name.to_string(), locator))
+}
+
+/// The request of `subpath` from `package_dir`: a self-reference, e.g. `name/subpath`, for a
+/// package with `exports`, or a relative path.
+fn package_request(package_dir: &Path, subpath: &str) -> String {
+    let package_json = PackageJson::load(&package_dir.join("package.json"));
+    match package_json.as_deref() {
+        Some(PackageJson { name: Some(name), has_exports: true, .. }) if subpath.is_empty() => {
+            name.clone()
+        }
+        Some(PackageJson { name: Some(name), has_exports: true, .. }) => {
+            format!("{name}/{subpath}")
+        }
+        _ if subpath.is_empty() => ".".to_string(),
+        _ => format!("./{subpath}"),
+    }
+}
+
+/// Split `@scope/name/sub/path` into `("@scope/name", "sub/path")`.
+fn parse_bare_specifier(specifier: &str) -> Option<(&str, &str)> {
+    if specifier.is_empty()
+        || specifier.starts_with('.')
+        || specifier.starts_with('/')
+        || specifier.starts_with('#')
+        || specifier.contains(':')
+    {
+        return None;
+    }
+    let mut separators = specifier.match_indices('/').map(|(i, _)| i);
+    let end = if specifier.starts_with('@') { separators.nth(1) } else { separators.next() };
+    Some(match end {
+        Some(end) => (&specifier[..end], &specifier[end + 1..]),
+        None => (specifier, ""),
+    })
+}
+
+/// Remove `.` components and trailing slashes from package locations.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// `.pnp.cjs` inlines the manifest as a single quoted string assigned to `RAW_RUNTIME_STATE`.
+fn extract_runtime_state(source: &str) -> Option<String> {
+    let start = source.find("RAW_RUNTIME_STATE")?;
+    let quote = start + source[start..].find('\'')?;
+    let mut chars = source[quote + 1..].chars();
+    let mut state = String::new();
+    loop {
+        match chars.next()? {
+            '\'' => return Some(state),
+            '\\' => match chars.next()? {
+                // Line continuation
+                '\n' => {}
+                'n' => state.push('\n'),
+                't' => state.push('\t'),
+                c => state.push(c),
+            },
+            c => state.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_resolver::Resolver;
+
+    use super::{extract_runtime_state, parse_bare_specifier, PnpManifest};
+
+    #[test]
+    fn bare_specifier() {
+        assert_eq!(parse_bare_specifier("lodash"), Some(("lodash", "")));
+        assert_eq!(parse_bare_specifier("lodash/fp/map"), Some(("lodash", "fp/map")));
+        assert_eq!(parse_bare_specifier("@babel/core"), Some(("@babel/core", "")));
+        assert_eq!(
+            parse_bare_specifier("@babel/core/lib/index"),
+            Some(("@babel/core", "lib/index"))
+        );
+        assert_eq!(parse_bare_specifier("./foo"), None);
+        assert_eq!(parse_bare_specifier("/foo"), None);
+        assert_eq!(parse_bare_specifier("node:fs"), None);
+    }
+
+    #[test]
+    fn runtime_state() {
+        let source = "const RAW_RUNTIME_STATE =\n'{\\\n  \"a\": \"it\\'s\"\\\n}';\n";
+        assert_eq!(extract_runtime_state(source).as_deref(), Some("{  \"a\": \"it's\"}"));
+    }
+
+    #[test]
+    fn resolve_package() {
+        let root = std::env::current_dir().unwrap();
+        let json = r#"{
+            "enableTopLevelFallback": true,
+            "packageRegistryData": [
+                [null, [[null, {
+                    "packageLocation": "./",
+                    "packageDependencies": [["app", "workspace:."], ["shared", "npm:1.0.0"]]
+                }]]],
+                ["app", [["workspace:.", {
+                    "packageLocation": "./",
+                    "packageDependencies": [["utils", "workspace:src"], ["peer", null]]
+                }]]],
+                ["utils", [["workspace:src", {
+                    "packageLocation": "./src/",
+                    "packageDependencies": [["alias", ["shared", "npm:1.0.0"]]]
+                }]]],
+                ["shared", [["npm:1.0.0", {
+                    "packageLocation": "./.yarn/unplugged/shared-npm-1.0.0/node_modules/shared/",
+                    "packageDependencies": []
+                }]]]
+            ]
+        }"#;
+        let manifest = PnpManifest::from_json(&root, json).unwrap();
+        let root = root.canonicalize().unwrap();
+        let shared = root.join(".yarn/unplugged/shared-npm-1.0.0/node_modules/shared");
+
+        assert_eq!(
+            manifest.resolve_package(&root, "utils/rules"),
+            Some((root.join("src").as_path(), "rules"))
+        );
+        assert_eq!(
+            manifest.resolve_package(&root.join("src"), "alias"),
+            Some((shared.as_path(), ""))
+        );
+        assert_eq!(manifest.resolve_package(&root, "shared"), Some((shared.as_path(), "")));
+        assert_eq!(manifest.resolve_package(&root, "peer"), None);
+        assert_eq!(manifest.resolve_package(&root, "missing"), None);
+        assert_eq!(manifest.resolve_package(&root, "./utils"), None);
+        assert_eq!(manifest.resolve_package(Path::new("/"), "utils"), None);
+    }
+
+    #[test]
+    fn resolve_exports() {
+        let root = std::env::current_dir().unwrap().join("fixtures/pnp");
+        let json = r#"{
+            "packageRegistryData": [
+                ["app", [["workspace:.", {
+                    "packageLocation": "./",
+                    "packageDependencies": [["pkg", "npm:1.0.0"], ["plain", "npm:1.0.0"]]
+                }]]],
+                ["pkg", [["npm:1.0.0", {
+                    "packageLocation": "./.yarn/unplugged/pkg/",
+                    "packageDependencies": []
+                }]]],
+                ["plain", [["npm:1.0.0", {
+                    "packageLocation": "./.yarn/unplugged/plain/",
+                    "packageDependencies": []
+                }]]]
+            ]
+        }"#;
+        let manifest = PnpManifest::from_json(&root, json).unwrap();
+        let resolver = Resolver::default();
+        let resolve = |specifier: &str| {
+            let resolution = manifest.resolve(&resolver, &root, specifier)?;
+            resolution.ok().map(|resolution| resolution.path().to_path_buf())
+        };
+        let root = root.canonicalize().unwrap();
+
+        let pkg = root.join(".yarn/unplugged/pkg");
+        assert_eq!(resolve("pkg"), Some(pkg.join("main.js")));
+        // `./feature` is exported as `./lib/feature.js`
+        assert_eq!(resolve("pkg/feature"), Some(pkg.join("lib/feature.js")));
+        assert_eq!(resolve("pkg/internal.js"), None);
+        // Without `exports`, every file of the package can be imported
+        assert_eq!(resolve("plain/lib/util"), Some(root.join(".yarn/unplugged/plain/lib/util.js")));
+        assert_eq!(resolve("missing"), None);
+    }
+}
@@ -0,0 +1,174 @@
+//! Presets of rules enabled with `extends` in the config, e.g. `"extends": ["oxlint:recommended"]`,
+//! and listed with `--rules --preset recommended`.
+//!
+//! Rules join a preset through their category. The contents of the presets are versioned, so that
+//! a release can tell which rules it adds to each preset, and a config can stay on the rules of a
+//! release with `oxlint:recommended@0.0.22` until it is ready for the new ones.
+
+use std::{fmt, str::FromStr};
+
+use rustc_hash::FxHashSet;
+
+use crate::{rules::RULES, RuleCategory, RuleEnum};
+
+/// Release with the first version of the presets, the version of the rules not in [`ADDITIONS`].
+pub const FIRST_VERSION: &str = "0.0.22";
+
+/// Rules added to a preset after its first version, with the release adding them, oldest first.
+/// A rule added to a preset is also added to the presets containing it.
+const ADDITIONS: &[(&str, Preset, &[&str])] = &[];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Preset {
+    /// Code that is outright wrong or useless, the rules enabled without a config
+    Recommended,
+    /// `recommended` and the rules for code that is most likely wrong or needlessly slow
+    Strict,
+    /// All rules except the nursery rules still under development
+    All,
+}
+
+impl Preset {
+    fn contains(self, other: Self) -> bool {
+        match self {
+            Self::Recommended => other == Self::Recommended,
+            Self::Strict => other != Self::All,
+            Self::All => true,
+        }
+    }
+
+    fn contains_category(self, category: RuleCategory) -> bool {
+        match self {
+            Self::Recommended => category == RuleCategory::Correctness,
+            Self::Strict => matches!(
+                category,
+                RuleCategory::Correctness | RuleCategory::Suspicious | RuleCategory::Perf
+            ),
+            Self::All => category != RuleCategory::Nursery,
+        }
+    }
+
+    /// The rules of the latest version of the preset with the release which added each of them,
+    /// oldest first.
+    pub fn rules(self) -> Vec<(&'static str, &'static RuleEnum)> {
+        let mut rules = RULES
+            .iter()
+            .filter(|rule| self.contains_category(rule.category()))
+            .map(|rule| (self.since(ADDITIONS, rule.name()), rule))
+            .collect::<Vec<_>>();
+        rules.sort_by(|(a, rule_a), (b, rule_b)| {
+            version_key(a)
+                .cmp(&version_key(b))
+                .then_with(|| rule_a.plugin_name().cmp(rule_b.plugin_name()))
+                .then_with(|| rule_a.name().cmp(rule_b.name()))
+        });
+        rules
+    }
+
+    /// Names of the rules of the preset in release `version`, the latest one without it.
+    pub fn rule_names(self, version: Option<&str>) -> FxHashSet<&'static str> {
+        self.rules()
+            .into_iter()
+            .filter(|(since, _)| version.map_or(true, |v| version_key(since) <= version_key(v)))
+            .map(|(_, rule)| rule.name())
+            .collect()
+    }
+
+    fn since(self, additions: &[(&'static str, Self, &[&str])], name: &str) -> &'static str {
+        additions
+            .iter()
+            .find(|(_, preset, names)| self.contains(*preset) && names.contains(&name))
+            .map_or(FIRST_VERSION, |(version, _, _)| *version)
+    }
+
+    /// `oxlint:recommended` or `oxlint:recommended@0.0.22` of `extends`, `None` for other configs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` with the specifier for unknown presets and versions which are not a release.
+    pub fn from_specifier(specifier: &str) -> Option<Result<(Self, Option<&str>), String>> {
+        let preset = specifier.strip_prefix("oxlint:")?;
+        let (name, version) = match preset.split_once('@') {
+            Some((name, version)) => (name, Some(version)),
+            None => (preset, None),
+        };
+        let is_valid_version = |version: &str| {
+            version
+                .split('.')
+                .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+        };
+        Some(match name.parse::<Self>() {
+            Ok(preset) if version.map_or(true, is_valid_version) => Ok((preset, version)),
+            _ => Err(specifier.to_string()),
+        })
+    }
+}
+
+impl FromStr for Preset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "recommended" => Ok(Self::Recommended),
+            "strict" => Ok(Self::Strict),
+            "all" => Ok(Self::All),
+            _ => Err(format!("expected `recommended`, `strict` or `all`, received `{s}`")),
+        }
+    }
+}
+
+impl fmt::Display for Preset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Recommended => write!(f, "oxlint:recommended"),
+            Self::Strict => write!(f, "oxlint:strict"),
+            Self::All => write!(f, "oxlint:all"),
+        }
+    }
+}
+
+/// `0.0.22` as `[0, 0, 22]`, to compare releases
+fn version_key(version: &str) -> Vec<u64> {
+    version.split('.').map(|part| part.parse().unwrap_or_default()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{version_key, Preset, FIRST_VERSION};
+
+    #[test]
+    fn presets_contain_each_other() {
+        let recommended = Preset::Recommended.rule_names(None);
+        let strict = Preset::Strict.rule_names(None);
+        let all = Preset::All.rule_names(None);
+        assert!(recommended.contains("no-debugger"));
+        assert!(recommended.is_subset(&strict));
+        assert!(strict.is_subset(&all));
+        assert!(strict.len() < all.len());
+    }
+
+    #[test]
+    fn since() {
+        let additions: &[(&str, Preset, &[&str])] = &[
+            ("0.1.0", Preset::Strict, &["no-debugger"]),
+            ("0.2.0", Preset::Recommended, &["no-debugger"]),
+        ];
+        assert_eq!(Preset::Recommended.since(additions, "no-debugger"), "0.2.0");
+        assert_eq!(Preset::Strict.since(additions, "no-debugger"), "0.1.0");
+        assert_eq!(Preset::All.since(additions, "no-debugger"), "0.1.0");
+        assert_eq!(Preset::All.since(additions, "eqeqeq"), FIRST_VERSION);
+        assert!(version_key("0.0.22") < version_key("0.1.0"));
+    }
+
+    #[test]
+    fn from_specifier() {
+        assert_eq!(Preset::from_specifier("oxlint:strict"), Some(Ok((Preset::Strict, None))));
+        assert_eq!(
+            Preset::from_specifier("oxlint:all@0.0.22"),
+            Some(Ok((Preset::All, Some("0.0.22"))))
+        );
+        assert!(Preset::from_specifier("oxlint:pedantic").unwrap().is_err());
+        assert!(Preset::from_specifier("oxlint:all@latest").unwrap().is_err());
+        assert_eq!(Preset::from_specifier("eslint:recommended"), None);
+    }
+}
@@ -0,0 +1,239 @@
+//! On-disk cache of module resolutions for the import plugin
+//!
+//! Resolving the requested modules of a file probes the file system for every extension,
+//! `index` file, `package.json` and tsconfig on the way, which adds up to tens of thousands of
+//! probes in large repositories. The resolutions are written to a JSON file after a run and
+//! reused by the next run as long as the paths they were stamped with keep their modification
+//! time:
+//! * the directories probed on the way from the importer to the resolved file, whose
+//!   modification time changes when a file is added, e.g. a `foo.ts` next to the resolved
+//!   `foo.js`, or removed, e.g. the resolved file itself;
+//! * the nearest `package.json` of the resolved file and of the importer, and the tsconfig of
+//!   the importer, whose content changes do not show in their directory.
+//!
+//! A hit is validated against these stamps only. Each stamped path is read at most once per run,
+//! so the hits of the files of a directory share the same few reads.
+//!
+//! Failed resolutions are not cached, the missing file may exist by the next run.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+    time::UNIX_EPOCH,
+};
+
+use dashmap::DashMap;
+use serde_json::{json, Value};
+
+/// Bumped when the format or the resolve options change, discarding existing caches.
+const VERSION: u64 = 3;
+
+#[derive(Debug, Clone)]
+struct Entry {
+    resolved: PathBuf,
+    /// Paths the resolution depends on with their modification time in nanoseconds
+    stamps: Vec<(PathBuf, u64)>,
+}
+
+#[derive(Debug)]
+pub struct ResolveCache {
+    path: PathBuf,
+    /// Keyed by the directory of the importer and the specifier
+    entries: DashMap<(Box<Path>, String), Entry>,
+    /// Modification times read in this run, `None` for missing paths
+    mtimes: DashMap<Box<Path>, Option<u64>>,
+    dirty: AtomicBool,
+}
+
+impl ResolveCache {
+    /// Load the cache from `path`, starting empty when it is missing, outdated or corrupt.
+    pub fn load(path: PathBuf) -> Self {
+        let entries = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| serde_json::from_str::<Value>(&json).ok())
+            .filter(|cache| cache.get("version").and_then(Value::as_u64) == Some(VERSION))
+            .and_then(|cache| Some(parse_entries(cache.get("entries")?.as_array()?)))
+            .unwrap_or_default();
+        Self { path, entries, mtimes: DashMap::default(), dirty: AtomicBool::new(false) }
+    }
+
+    /// The cached resolution of `specifier` imported from `dir`, dropped when one of the paths it
+    /// was stamped with was modified or removed.
+    pub fn get(&self, dir: &Path, specifier: &str) -> Option<PathBuf> {
+        let key = (dir.to_path_buf().into_boxed_path(), specifier.to_string());
+        let entry = self.entries.get(&key)?;
+        if entry.stamps.iter().all(|(path, mtime)| self.modified(path) == Some(*mtime)) {
+            return Some(entry.resolved.clone());
+        }
+        drop(entry);
+        self.entries.remove(&key);
+        self.dirty.store(true, Ordering::Relaxed);
+        None
+    }
+
+    /// Cache the resolution of `specifier` imported from `dir`, stamped with the directories probed
+    /// for it and with `files`, the configs the resolution depends on.
+    pub fn insert<I>(&self, dir: &Path, specifier: &str, resolved: &Path, files: I)
+    where
+        I: IntoIterator<Item = PathBuf>,
+    {
+        let stamps = probed_directories(dir, specifier, resolved)
+            .into_iter()
+            .chain(files)
+            .filter_map(|path| self.modified(&path).map(|mtime| (path, mtime)))
+            .collect();
+        let entry = Entry { resolved: resolved.to_path_buf(), stamps };
+        self.entries.insert((dir.to_path_buf().into_boxed_path(), specifier.to_string()), entry);
+        self.dirty.store(true, Ordering::Relaxed);
+    }
+
+    /// Write the cache if it changed, creating the directory of the cache file.
+    ///
+    /// # Errors
+    ///
+    /// * When the cache file cannot be written
+    pub fn save(&self) -> std::io::Result<()> {
+        if !self.dirty.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        let entries = self
+            .entries
+            .iter()
+            .map(|item| {
+                let ((dir, specifier), entry) = item.pair();
+                json!({
+                    "dir": dir,
+                    "specifier": specifier,
+                    "resolved": entry.resolved,
+                    "stamps": entry.stamps,
+                })
+            })
+            .collect::<Vec<_>>();
+        let cache = json!({ "version": VERSION, "entries": entries });
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, cache.to_string())?;
+        self.dirty.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn modified(&self, path: &Path) -> Option<u64> {
+        if let Some(mtime) = self.mtimes.get(path) {
+            return *mtime;
+        }
+        let mtime = modified(path);
+        self.mtimes.insert(path.to_path_buf().into_boxed_path(), mtime);
+        mtime
+    }
+}
+
+/// The directories probed when resolving `specifier` imported from `dir` to `resolved`: the
+/// directories from `dir` and from the directory of `resolved` up to their common ancestor, and
+/// for a bare specifier the `node_modules` directories looked up on the way up from `dir`.
+fn probed_directories(dir: &Path, specifier: &str, resolved: &Path) -> Vec<PathBuf> {
+    let is_bare = !specifier.starts_with('.') && !Path::new(specifier).is_absolute();
+    let Some(resolved_dir) = resolved.parent() else { return vec![] };
+    let common = resolved_dir.ancestors().find(|ancestor| dir.starts_with(ancestor));
+    let below_common = |ancestor: &&Path| common.map_or(true, |common| *ancestor != common);
+    let mut directories = vec![];
+    for ancestor in resolved_dir.ancestors().take_while(below_common) {
+        directories.push(ancestor.to_path_buf());
+    }
+    for ancestor in dir.ancestors().take_while(below_common).chain(common) {
+        directories.push(ancestor.to_path_buf());
+        if is_bare {
+            directories.push(ancestor.join("node_modules"));
+        }
+    }
+    directories.sort_unstable();
+    directories.dedup();
+    directories
+}
+
+fn parse_entries(entries: &[Value]) -> DashMap<(Box<Path>, String), Entry> {
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let dir = PathBuf::from(entry.get("dir")?.as_str()?).into_boxed_path();
+            let specifier = entry.get("specifier")?.as_str()?.to_string();
+            let resolved = PathBuf::from(entry.get("resolved")?.as_str()?);
+            let stamps = entry
+                .get("stamps")?
+                .as_array()?
+                .iter()
+                .map(|stamp| {
+                    let path = PathBuf::from(stamp.get(0)?.as_str()?);
+                    Some((path, stamp.get(1)?.as_u64()?))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(((dir, specifier), Entry { resolved, stamps }))
+        })
+        .collect()
+}
+
+fn modified(path: &Path) -> Option<u64> {
+    let mtime = fs::metadata(path).ok()?.modified().ok()?;
+    mtime.duration_since(UNIX_EPOCH).ok()?.as_nanos().try_into().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+    };
+
+    use super::ResolveCache;
+
+    #[test]
+    fn round_trip_and_invalidate() {
+        let dir = env::temp_dir().join(format!("oxc-resolve-cache-{}", std::process::id()));
+        let resolved = dir.join("foo.js");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&resolved, "export default 1").unwrap();
+        let cache_path = dir.join("cache/resolve.json");
+        // Written before stamping `dir`, the cache directory is created in it
+        fs::create_dir_all(cache_path.parent().unwrap()).unwrap();
+
+        let cache = ResolveCache::load(cache_path.clone());
+        assert_eq!(cache.get(&dir, "./foo"), None);
+        cache.insert(&dir, "./foo", &resolved, [dir.join("package.json")]);
+        cache.save().unwrap();
+
+        let cache = ResolveCache::load(cache_path.clone());
+        assert_eq!(cache.get(&dir, "./foo"), Some(resolved.clone()));
+        assert_eq!(cache.get(&dir, "./bar"), None);
+
+        // A file which may take precedence over the resolved one changes the directory
+        fs::write(dir.join("foo.ts"), "export default 1").unwrap();
+        let cache = ResolveCache::load(cache_path.clone());
+        assert_eq!(cache.get(&dir, "./foo"), None);
+
+        fs::write(&cache_path, "not json").unwrap();
+        assert_eq!(ResolveCache::load(cache_path).get(&dir, "./foo"), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn probed_directories() {
+        let dirs = |dir: &str, specifier: &str, resolved: &str| {
+            super::probed_directories(Path::new(dir), specifier, Path::new(resolved))
+        };
+        assert_eq!(
+            dirs("/repo/src", "./lib/foo", "/repo/src/lib/foo.js"),
+            vec![PathBuf::from("/repo/src"), PathBuf::from("/repo/src/lib")]
+        );
+        assert_eq!(
+            dirs("/repo/src", "pkg", "/repo/node_modules/pkg/index.js"),
+            vec![
+                PathBuf::from("/repo"),
+                PathBuf::from("/repo/node_modules"),
+                PathBuf::from("/repo/node_modules/pkg"),
+                PathBuf::from("/repo/src"),
+                PathBuf::from("/repo/src/node_modules"),
+            ]
+        );
+    }
+}
@@ -10,6 +10,11 @@ pub trait Rule: Sized + Default + fmt::Debug {
         Self::default()
     }
 
+    /// Check the shape of the eslint json configuration, for `oxlint check-config`
+    fn validate_configuration(_value: &serde_json::Value) -> Result<(), String> {
+        Ok(())
+    }
+
     /// Visit each AST Node
     fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {}
 
@@ -25,6 +30,9 @@ pub trait RuleMeta {
 
     const CATEGORY: RuleCategory;
 
+    /// Declared with `fix = safe`, `fix = unsafe` or `fix = suggestion` in `declare_oxc_lint!`
+    const FIX: FixKind = FixKind::None;
+
     fn documentation() -> Option<&'static str> {
         None
     }
@@ -82,8 +90,38 @@ impl fmt::Display for RuleCategory {
     }
 }
 
+/// How safe the fixes of a rule are to apply without review, ordered from safest to least safe.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FixKind {
+    /// The rule does not produce fixes
+    #[default]
+    None,
+    /// Fixes keep the behavior of the code, they are applied by `--fix`
+    Safe,
+    /// Fixes may change the behavior of the code, e.g. for values of an unexpected type,
+    /// they are only applied by `--fix-unsafe`
+    Unsafe,
+    /// Fixes are one of several ways to resolve the problem, they are never applied
+    /// automatically and only offered by editors
+    Suggestion,
+}
+
+impl fmt::Display for FixKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "no fix"),
+            Self::Safe => write!(f, "safe fix"),
+            Self::Unsafe => write!(f, "unsafe fix"),
+            Self::Suggestion => write!(f, "suggestion"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use std::{fs, path::Path};
+
+    use super::FixKind;
     use crate::RULES;
 
     #[test]
@@ -93,4 +131,34 @@ mod test {
             assert!(rule.documentation().is_some_and(|s| !s.is_empty()), "{}", rule.name());
         }
     }
+
+    /// Fixes of rules without a declared fix kind are never applied, so every rule producing
+    /// fixes must declare one.
+    #[test]
+    fn ensure_fix_kind() {
+        for plugin in fs::read_dir(Path::new("src/rules")).unwrap() {
+            let plugin = plugin.unwrap().path();
+            if !plugin.is_dir() {
+                continue;
+            }
+            let plugin_name = plugin.file_name().unwrap().to_string_lossy().to_string();
+            for file in fs::read_dir(&plugin).unwrap() {
+                let file = file.unwrap().path();
+                let Ok(source_text) = fs::read_to_string(&file) else { continue };
+                if !source_text.contains("diagnostic_with_fix") {
+                    continue;
+                }
+                let rule_name = file.file_stem().unwrap().to_string_lossy().replace('_', "-");
+                let rule = RULES
+                    .iter()
+                    .find(|rule| rule.plugin_name() == plugin_name && rule.name() == rule_name)
+                    .unwrap_or_else(|| panic!("no rule for {}", file.display()));
+                assert_ne!(
+                    rule.fix(),
+                    FixKind::None,
+                    "{plugin_name}/{rule_name} declares no fix kind"
+                );
+            }
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use std::{
+    path::Path,
     sync::atomic::{AtomicU32, AtomicU64, Ordering},
     time::Duration,
 };
@@ -25,3 +26,10 @@ impl RuleTimer {
         Duration::new(secs, nanos)
     }
 }
+
+/// Time spent by each rule on a file
+#[derive(Debug)]
+pub struct FileProfile {
+    pub path: Box<Path>,
+    pub rules: Vec<(&'static str, Duration)>,
+}
@@ -9,10 +9,14 @@
 mod import {
     pub mod default;
     pub mod export;
+    pub mod extensions;
     pub mod named;
     pub mod no_amd;
     pub mod no_cycle;
+    pub mod no_extraneous_dependencies;
     pub mod no_self_import;
+    pub mod no_unresolved;
+    pub mod order;
 }
 
 mod deepscan {
@@ -30,11 +34,17 @@ mod deepscan {
 
 mod eslint {
     pub mod array_callback_return;
+    pub mod complexity;
     pub mod constructor_super;
+    pub mod curly;
     pub mod default_case_last;
+    pub mod dot_notation;
     pub mod eqeqeq;
     pub mod for_direction;
     pub mod getter_return;
+    pub mod max_depth;
+    pub mod max_lines_per_function;
+    pub mod max_params;
     pub mod no_array_constructor;
     pub mod no_async_promise_executor;
     pub mod no_bitwise;
@@ -73,6 +83,7 @@ mod eslint {
     pub mod no_prototype_builtins;
     pub mod no_redeclare;
     pub mod no_regex_spaces;
+    pub mod no_restricted_imports;
     pub mod no_return_await;
     pub mod no_self_assign;
     pub mod no_self_compare;
@@ -90,6 +101,7 @@ mod eslint {
     pub mod require_yield;
     pub mod use_isnan;
     pub mod valid_typeof;
+    pub mod yoda;
 }
 
 mod typescript {
@@ -262,9 +274,16 @@ mod oxc {
     pub mod erasing_op;
     pub mod misrefactored_assign_op;
     pub mod no_accumulating_spread;
+    pub mod no_implementation_in_declaration_file;
     pub mod only_used_in_recursion;
 }
 
+/// Hard-coded credentials and secrets, opt-in with the secrets plugin
+mod secrets {
+    pub mod no_hardcoded_credentials;
+    pub mod no_high_entropy_strings;
+}
+
 oxc_macros::declare_all_lint_rules! {
     deepscan::bad_array_method_on_arguments,
     deepscan::bad_bitwise_operator,
@@ -277,11 +296,17 @@ oxc_macros::declare_all_lint_rules! {
     deepscan::number_arg_out_of_range,
     deepscan::uninvoked_array_callback,
     eslint::array_callback_return,
+    eslint::complexity,
     eslint::constructor_super,
+    eslint::curly,
     eslint::default_case_last,
+    eslint::dot_notation,
     eslint::eqeqeq,
     eslint::for_direction,
     eslint::getter_return,
+    eslint::max_depth,
+    eslint::max_lines_per_function,
+    eslint::max_params,
     eslint::no_array_constructor,
     eslint::no_async_promise_executor,
     eslint::no_bitwise,
@@ -320,6 +345,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_prototype_builtins,
     eslint::no_redeclare,
     eslint::no_regex_spaces,
+    eslint::no_restricted_imports,
     eslint::no_return_await,
     eslint::no_self_assign,
     eslint::no_self_compare,
@@ -337,6 +363,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::require_yield,
     eslint::use_isnan,
     eslint::valid_typeof,
+    eslint::yoda,
     typescript::adjacent_overload_signatures,
     typescript::ban_ts_comment,
     typescript::ban_types,
@@ -468,9 +495,13 @@ oxc_macros::declare_all_lint_rules! {
     import::default,
     import::named,
     import::no_cycle,
+    import::no_extraneous_dependencies,
     import::no_self_import,
+    import::no_unresolved,
     import::no_amd,
     import::export,
+    import::extensions,
+    import::order,
     jsx_a11y::alt_text,
     jsx_a11y::anchor_has_content,
     jsx_a11y::anchor_is_valid,
@@ -497,5 +528,8 @@ oxc_macros::declare_all_lint_rules! {
     oxc::erasing_op,
     oxc::misrefactored_assign_op,
     oxc::no_accumulating_spread,
+    oxc::no_implementation_in_declaration_file,
     oxc::only_used_in_recursion,
+    secrets::no_hardcoded_credentials,
+    secrets::no_high_entropy_strings,
 }
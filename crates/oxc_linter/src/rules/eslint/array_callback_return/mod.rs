@@ -18,6 +18,7 @@ use crate::{
     ast_util::{get_enclosing_function, is_nth_argument, outermost_paren},
     context::LintContext,
     rule::Rule,
+    utils::{check_properties, OptionType},
     AstNode,
 };
 
@@ -81,6 +82,13 @@ impl Rule for ArrayCallbackReturn {
         Self { check_for_each, allow_implicit_return }
     }
 
+    fn validate_configuration(value: &Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[("checkForEach", OptionType::Boolean), ("allowImplicit", OptionType::Boolean)],
+        )
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let (function_body, always_explicit_return) = match node.kind() {
             // Async, generator, and single expression arrow functions
@@ -0,0 +1,147 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    metrics::{check_max_option, function_head, function_name_with_kind, max_option},
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(complexity): {0} has a complexity of {1}. Maximum allowed is {2}.")]
+#[diagnostic(severity(warning), help("Split the function or simplify its conditions."))]
+struct ComplexityDiagnostic(String, usize, usize, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct Complexity {
+    max: usize,
+}
+
+impl Default for Complexity {
+    fn default() -> Self {
+        Self { max: 20 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a maximum cyclomatic complexity of functions, the number of paths through a
+    /// function: one plus its conditions, loops, `case` clauses, `catch` clauses and logical
+    /// operators. The maximum is 20 by default, `[10]` or `[{ "max": 10 }]` change it.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Functions with many paths are hard to follow and to test.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // With a maximum of 2
+    /// function foo(a, b) {
+    ///   if (a) {
+    ///     return 1;
+    ///   } else if (b) {
+    ///     return 2;
+    ///   }
+    ///   return 3;
+    /// }
+    /// ```
+    Complexity,
+    pedantic
+);
+
+impl Rule for Complexity {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self { max: max_option(&value, Self::default().max) }
+    }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_max_option(value)
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowExpression(_)) {
+            return;
+        }
+        let Some(metrics) = ctx.metrics().get(node.id()) else { return };
+        if metrics.complexity > self.max {
+            ctx.diagnostic(ComplexityDiagnostic(
+                function_name_with_kind(node, ctx),
+                metrics.complexity,
+                self.max,
+                function_head(node, ctx),
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function a(x) {}", None),
+        ("function b(x) {}", Some(json!([1]))),
+        ("function a(x) {if (true) {return x;}}", Some(json!([2]))),
+        ("function a(x) {if (true) {return x;} else {return x+1;}}", Some(json!([2]))),
+        ("function a(x) {if (true) {return x;} else if (false) {return x+1;} else {return 4;}}", Some(json!([3]))),
+        ("function a(x) {for(var i = 0; i < 5; i ++) {x ++;} return x;}", Some(json!([2]))),
+        ("function a(obj) {for(var i in obj) {obj[i] = 3;}}", Some(json!([2]))),
+        ("function a(x) {for(var i = 0; i < 5; i ++) {if(i % 2 === 0) {x ++;}} return x;}", Some(json!([3]))),
+        ("function a(obj) {if(obj){ for(var x in obj) {try {x.getThis();} catch (e) {x.getThat();}}} else {return false;}}", Some(json!([4]))),
+        ("function a(x) {try {x.getThis();} catch (e) {x.getThat();}}", Some(json!([2]))),
+        ("function a(x) {return x === 4 ? 3 : 5;}", Some(json!([2]))),
+        ("function a(x) {return x === 4 ? 3 : (x === 3 ? 2 : 1);}", Some(json!([3]))),
+        ("function a(x) {return x || 4;}", Some(json!([2]))),
+        ("function a(x) {x && 4;}", Some(json!([2]))),
+        ("function a(x) {x ?? 4;}", Some(json!([2]))),
+        ("function a(x) {x ||= 4;}", Some(json!([2]))),
+        ("function a(x) {x = 4;}", Some(json!([1]))),
+        ("function a(x) {x |= 4;}", Some(json!([1]))),
+        ("function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: 3;}}", Some(json!([3]))),
+        ("function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: if(x == 'foo') {5;};}}", Some(json!([4]))),
+        ("function a(x) {while(true) {'foo';}}", Some(json!([2]))),
+        ("function a(x) {do {'foo';} while (true)}", Some(json!([2]))),
+        ("if (foo) { bar(); }", Some(json!([1]))),
+        ("var a = (x) => {do {'foo';} while (true)}", Some(json!([2]))),
+        // The functions inside a function have their own complexity
+        ("function a(x) {if (x) {} (function() {if (x) {}})();}", Some(json!([2]))),
+        ("function foo() { if (a) {} }", Some(json!([{ "max": 2 }]))),
+        ("function foo() { if (a) {} }", Some(json!([{ "maximum": 2 }]))),
+    ];
+
+    let fail = vec![
+        ("function a(x) {}", Some(json!([0]))),
+        ("var func = function () {}", Some(json!([0]))),
+        ("var obj = { a(x) {} }", Some(json!([0]))),
+        ("class Test { a(x) {} }", Some(json!([0]))),
+        ("var a = (x) => {if (true) {return x;}}", Some(json!([1]))),
+        ("function a(x) {if (true) {return x;}}", Some(json!([1]))),
+        ("function a(x) {if (true) {return x;} else {return x+1;}}", Some(json!([1]))),
+        ("function a(x) {if (true) {return x;} else if (false) {return x+1;} else {return 4;}}", Some(json!([2]))),
+        ("function a(x) {for(var i = 0; i < 5; i ++) {x ++;} return x;}", Some(json!([1]))),
+        ("function a(obj) {for(var i in obj) {obj[i] = 3;}}", Some(json!([1]))),
+        ("function a(obj) {for(var i of obj) {obj[i] = 3;}}", Some(json!([1]))),
+        ("function a(x) {try {x.getThis();} catch (e) {x.getThat();}}", Some(json!([1]))),
+        ("function a(x) {return x === 4 ? 3 : 5;}", Some(json!([1]))),
+        ("function a(x) {return x || 4;}", Some(json!([1]))),
+        ("function a(x) {x ??= 4;}", Some(json!([1]))),
+        ("function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: 3;}}", Some(json!([2]))),
+        ("function a(x) {while(true) {'foo';}}", Some(json!([1]))),
+        ("function a(x) {do {'foo';} while (true)}", Some(json!([1]))),
+        ("function a(x) {(function() {while(true){'foo';}})(); (function() {while(true){'bar';}})();}", Some(json!([1]))),
+        ("async function a(x) {if (x) {}}", Some(json!([{ "max": 1 }]))),
+        ("class C { static get x() { return a ? 1 : 2; } }", Some(json!([1]))),
+    ];
+
+    Tester::new(Complexity::NAME, pass, fail).test_and_snapshot();
+}
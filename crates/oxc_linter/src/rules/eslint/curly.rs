@@ -0,0 +1,365 @@
+use oxc_ast::{
+    ast::{BlockStatement, Declaration, IfStatement, Statement, VariableDeclarationKind},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    fixer::Fix,
+    rule::Rule,
+    utils::{check_option, OptionType},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum CurlyDiagnostic {
+    #[error("eslint(curly): Expected {{ after {0}.")]
+    #[diagnostic(severity(warning))]
+    Expected(&'static str, #[label] Span),
+    #[error("eslint(curly): Unnecessary {{ after {0}.")]
+    #[diagnostic(severity(warning))]
+    Unnecessary(&'static str, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum CurlyOption {
+    #[default]
+    All,
+    Multi,
+    MultiLine,
+    MultiOrNest,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Curly {
+    option: CurlyOption,
+    /// All bodies of an `if` / `else if` / `else` chain either have braces or none has.
+    consistent: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce consistent brace style for all control statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Omitting the braces of a body makes it easy to add a statement which looks like it is
+    /// part of the body but is not.
+    ///
+    /// ### Options
+    ///
+    /// * `"all"` (default) requires braces for all bodies
+    /// * `"multi"` disallows braces around a body with a single statement
+    /// * `"multi-line"` allows a body without braces on the same line as the statement
+    /// * `"multi-or-nest"` requires braces around bodies spanning multiple lines and disallows
+    ///   them around single-line bodies
+    /// * `"consistent"` as the second option requires the same style for all bodies of an
+    ///   `if` / `else if` / `else` chain
+    ///
+    /// ### Example
+    /// ```javascript
+    /// if (foo) foo++;
+    /// while (bar)
+    ///     baz();
+    /// ```
+    Curly,
+    style,
+    fix = safe
+);
+
+struct Check<'a, 'b> {
+    body: &'b Statement<'a>,
+    /// `'if' condition`, `'else'`, ...
+    name: &'static str,
+    /// `None` when both styles are allowed
+    expected: Option<bool>,
+}
+
+impl<'a, 'b> Check<'a, 'b> {
+    fn has_braces(&self) -> bool {
+        matches!(self.body, Statement::BlockStatement(_))
+    }
+}
+
+impl Rule for Curly {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let option = match value.get(0).and_then(serde_json::Value::as_str) {
+            Some("multi") => CurlyOption::Multi,
+            Some("multi-line") => CurlyOption::MultiLine,
+            Some("multi-or-nest") => CurlyOption::MultiOrNest,
+            _ => CurlyOption::All,
+        };
+        let consistent = value.get(1).and_then(serde_json::Value::as_str) == Some("consistent");
+        Self { option, consistent }
+    }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_option(
+            value.get(0),
+            OptionType::OneOf(&["all", "multi", "multi-line", "multi-or-nest"]),
+        )?;
+        check_option(value.get(1), OptionType::OneOf(&["consistent"]))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let checks = match node.kind() {
+            AstKind::IfStatement(if_statement) => {
+                // An `else if` is checked with the chain of its first `if`
+                let is_else_if =
+                    ctx.nodes().parent_node(node.id()).is_some_and(|parent| match parent.kind() {
+                        AstKind::IfStatement(parent) => parent
+                            .alternate
+                            .as_ref()
+                            .is_some_and(|alternate| alternate.span() == if_statement.span),
+                        _ => false,
+                    });
+                if is_else_if {
+                    return;
+                }
+                self.if_chain_checks(if_statement, ctx)
+            }
+            AstKind::WhileStatement(statement) => {
+                vec![self.check(&statement.body, "'while' condition", false, ctx)]
+            }
+            AstKind::DoWhileStatement(statement) => {
+                vec![self.check(&statement.body, "'do'", false, ctx)]
+            }
+            AstKind::ForStatement(statement) => {
+                vec![self.check(&statement.body, "'for' condition", false, ctx)]
+            }
+            AstKind::ForInStatement(statement) => {
+                vec![self.check(&statement.body, "'for-in'", false, ctx)]
+            }
+            AstKind::ForOfStatement(statement) => {
+                vec![self.check(&statement.body, "'for-of'", false, ctx)]
+            }
+            _ => return,
+        };
+        for check in checks {
+            report(&check, ctx);
+        }
+    }
+}
+
+impl Curly {
+    fn if_chain_checks<'a, 'b>(
+        &self,
+        if_statement: &'b IfStatement<'a>,
+        ctx: &LintContext<'a>,
+    ) -> Vec<Check<'a, 'b>> {
+        let mut checks = vec![];
+        let mut current = if_statement;
+        loop {
+            let has_else = current.alternate.is_some();
+            checks.push(self.check(&current.consequent, "'if' condition", has_else, ctx));
+            match &current.alternate {
+                Some(Statement::IfStatement(alternate)) => current = alternate,
+                Some(alternate) => {
+                    checks.push(self.check(alternate, "'else'", false, ctx));
+                    break;
+                }
+                None => break,
+            }
+        }
+        if self.consistent {
+            let expected = checks.iter().any(|check| check.expected.unwrap_or(check.has_braces()));
+            for check in &mut checks {
+                check.expected = Some(expected);
+            }
+        }
+        checks
+    }
+
+    /// `followed_by_else` for the consequent of an `if` with an `else`.
+    fn check<'a, 'b>(
+        &self,
+        body: &'b Statement<'a>,
+        name: &'static str,
+        followed_by_else: bool,
+        ctx: &LintContext<'a>,
+    ) -> Check<'a, 'b> {
+        let block = match body {
+            Statement::BlockStatement(block) => Some(block),
+            _ => None,
+        };
+        let expected = if block.is_some_and(|block| {
+            block.body.len() != 1 || are_braces_necessary(block, followed_by_else)
+        }) {
+            Some(true)
+        } else {
+            match self.option {
+                CurlyOption::All => Some(true),
+                CurlyOption::Multi => Some(false),
+                CurlyOption::MultiLine => (!is_collapsed_one_liner(body, ctx)).then_some(true),
+                CurlyOption::MultiOrNest => Some(block.map_or_else(
+                    || !is_one_liner(body.span(), ctx),
+                    |block| {
+                        let statement = &block.body[0];
+                        let before_statement = Span::new(block.span.start, statement.span().start);
+                        !is_one_liner(statement.span(), ctx)
+                            || ctx.semantic().trivias().has_comments_between(before_statement)
+                    },
+                )),
+            }
+        };
+        Check { body, name, expected }
+    }
+}
+
+fn report(check: &Check, ctx: &LintContext) {
+    let Some(expected) = check.expected else { return };
+    if expected == check.has_braces() {
+        return;
+    }
+    let span = check.body.span();
+    if expected {
+        ctx.diagnostic_with_fix(CurlyDiagnostic::Expected(check.name, span), || {
+            Fix::new(format!("{{{}}}", span.source_text(ctx.source_text())), span)
+        });
+        return;
+    }
+    let Statement::BlockStatement(block) = check.body else { return };
+    let diagnostic = CurlyDiagnostic::Unnecessary(check.name, span);
+    match remove_braces(block, ctx) {
+        Some(text) => ctx.diagnostic_with_fix(diagnostic, || Fix::new(text, span)),
+        None => ctx.diagnostic(diagnostic),
+    }
+}
+
+/// The body without braces, `None` when the statement could join with the code after it.
+fn remove_braces(block: &BlockStatement, ctx: &LintContext) -> Option<String> {
+    let source_text = ctx.source_text();
+    let inner = Span::new(block.span.start + 1, block.span.end - 1).source_text(source_text);
+    let inner = inner.trim();
+    if !inner.ends_with(';') && !inner.ends_with('}') {
+        return None;
+    }
+    // `else{foo();}` and `do{foo();}while (a)`
+    let is_word = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    let needs_space =
+        source_text[..block.span.start as usize].ends_with(is_word) && inner.starts_with(is_word);
+    Some(if needs_space { format!(" {inner}") } else { inner.to_string() })
+}
+
+/// A lexical declaration, or an `if` without `else` which would take the `else` of the parent.
+fn are_braces_necessary(block: &BlockStatement, followed_by_else: bool) -> bool {
+    let statement = &block.body[0];
+    is_lexical_declaration(statement) || (followed_by_else && has_unsafe_if(statement))
+}
+
+fn is_lexical_declaration(statement: &Statement) -> bool {
+    match statement {
+        Statement::Declaration(Declaration::VariableDeclaration(declaration)) => {
+            declaration.kind != VariableDeclarationKind::Var
+        }
+        Statement::Declaration(
+            Declaration::FunctionDeclaration(_)
+            | Declaration::ClassDeclaration(_)
+            | Declaration::UsingDeclaration(_),
+        ) => true,
+        _ => false,
+    }
+}
+
+fn has_unsafe_if(statement: &Statement) -> bool {
+    match statement {
+        Statement::IfStatement(if_statement) => {
+            if_statement.alternate.as_ref().map_or(true, has_unsafe_if)
+        }
+        Statement::ForStatement(statement) => has_unsafe_if(&statement.body),
+        Statement::ForInStatement(statement) => has_unsafe_if(&statement.body),
+        Statement::ForOfStatement(statement) => has_unsafe_if(&statement.body),
+        Statement::LabeledStatement(statement) => has_unsafe_if(&statement.body),
+        Statement::WithStatement(statement) => has_unsafe_if(&statement.body),
+        Statement::WhileStatement(statement) => has_unsafe_if(&statement.body),
+        _ => false,
+    }
+}
+
+fn is_one_liner(span: Span, ctx: &LintContext) -> bool {
+    !span.source_text(ctx.source_text()).contains('\n')
+}
+
+/// The body starts on the line of the token before it and ends on the same line.
+fn is_collapsed_one_liner(body: &Statement, ctx: &LintContext) -> bool {
+    let source_text = ctx.source_text();
+    let span = body.span();
+    let before = source_text[..span.start as usize].trim_end();
+    #[allow(clippy::cast_possible_truncation)]
+    let start = before.len() as u32;
+    let text = Span::new(start, span.end).source_text(source_text);
+    !text.trim_end_matches(';').trim_end().contains('\n')
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("if (foo) { bar() }", None),
+        ("if (foo) { bar() } else if (foo2) { baz() }", None),
+        ("while (foo) { bar() }", None),
+        ("do { bar(); } while (foo)", None),
+        ("for (;foo;) { bar() }", None),
+        ("for (var foo in bar) { console.log(foo) }", None),
+        ("for (var foo of bar) { console.log(foo) }", None),
+        ("if (foo) bar()", Some(json!(["multi"]))),
+        ("if (foo) { bar(); baz(); }", Some(json!(["multi"]))),
+        ("if (foo) { let bar; }", Some(json!(["multi"]))),
+        ("if (foo) { if (bar) baz(); } else qux();", Some(json!(["multi"]))),
+        ("if (foo) bar(); else { baz(); qux(); }", Some(json!(["multi"]))),
+        ("if (foo) bar()", Some(json!(["multi-line"]))),
+        ("if (foo) bar(); else baz()", Some(json!(["multi-line"]))),
+        ("if (foo) {\n bar();\n}", Some(json!(["multi-line"]))),
+        ("if (foo) { bar() }", Some(json!(["multi-line"]))),
+        ("while (foo) bar()", Some(json!(["multi-line"]))),
+        ("if (foo) bar()", Some(json!(["multi-or-nest"]))),
+        ("if (foo) {\n bar(a,\n b);\n}", Some(json!(["multi-or-nest"]))),
+        ("if (foo) {\n // comment\n bar();\n}", Some(json!(["multi-or-nest"]))),
+        ("if (foo) bar(); else baz();", Some(json!(["multi", "consistent"]))),
+        ("if (foo) { bar() } else { baz(); qux(); }", Some(json!(["multi", "consistent"]))),
+        ("if (a) b(); else if (c) d(); else e();", Some(json!(["multi-line", "consistent"]))),
+    ];
+
+    let fail = vec![
+        ("if (foo) bar()", None),
+        ("if (foo) { bar() } else baz()", None),
+        ("if (foo) { bar() } else if (faa) baz()", None),
+        ("while (foo) bar()", None),
+        ("do bar(); while (foo)", None),
+        ("for (;foo;) bar()", None),
+        ("for (var foo in bar) console.log(foo)", None),
+        ("for (var foo of bar) console.log(foo)", None),
+        ("if (foo) { bar(); }", Some(json!(["multi"]))),
+        ("while (foo) { bar(); }", Some(json!(["multi"]))),
+        ("if (foo) { bar(); } else { baz(); }", Some(json!(["multi"]))),
+        ("if (foo)\n bar()", Some(json!(["multi-line"]))),
+        ("while (foo)\n bar()", Some(json!(["multi-line"]))),
+        ("if (foo) { bar(); }", Some(json!(["multi-or-nest"]))),
+        ("if (foo)\n bar(a,\n b);", Some(json!(["multi-or-nest"]))),
+        ("if (foo) { bar() } else { baz() }", Some(json!(["multi", "consistent"]))),
+        ("if (foo) { bar(); baz(); } else qux();", Some(json!(["multi", "consistent"]))),
+        ("if (a) b(); else if (c) {\n d();\n}", Some(json!(["multi-line", "consistent"]))),
+    ];
+
+    let fix = vec![
+        ("if (foo) bar()", "if (foo) {bar()}", None),
+        ("do bar(); while (foo)", "do {bar();} while (foo)", None),
+        ("if (foo) { bar(); }", "if (foo) bar();", Some(json!(["multi"]))),
+        ("if (foo) { bar(); } else{baz();}", "if (foo) bar(); else baz();", Some(json!(["multi"]))),
+        ("do{bar();} while (foo)", "do bar(); while (foo)", Some(json!(["multi"]))),
+        ("if (foo) { bar() }\nbaz()", "if (foo) { bar() }\nbaz()", Some(json!(["multi"]))),
+        ("if (foo)\n bar()", "if (foo)\n {bar()}", Some(json!(["multi-line"]))),
+    ];
+
+    Tester::new(Curly::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,273 @@
+use oxc_ast::{
+    ast::{Expression, MemberExpression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::identifier::is_identifier_name;
+use regex::Regex;
+
+use crate::{
+    context::LintContext,
+    fixer::Fix,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum DotNotationDiagnostic {
+    #[error("eslint(dot-notation): [{0}] is better written in dot notation.")]
+    #[diagnostic(severity(warning))]
+    UseDot(String, #[label] Span),
+    #[error("eslint(dot-notation): .{0} is a syntax error.")]
+    #[diagnostic(severity(warning), help("Keywords cannot be used as property names in ES3"))]
+    UseBrackets(String, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DotNotation(Box<DotNotationConfig>);
+
+#[derive(Debug, Clone)]
+pub struct DotNotationConfig {
+    /// Keywords are allowed as property names after a dot, as since ES5
+    allow_keywords: bool,
+    /// Property names which may be written with brackets
+    allow_pattern: Option<Regex>,
+}
+
+impl Default for DotNotationConfig {
+    fn default() -> Self {
+        Self { allow_keywords: true, allow_pattern: None }
+    }
+}
+
+impl std::ops::Deref for DotNotation {
+    type Target = DotNotationConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce dot notation whenever possible, i.e. `foo.bar` instead of `foo["bar"]`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Dot notation is easier to read and less verbose, and lets minifiers shorten the names.
+    ///
+    /// ### Options
+    ///
+    /// * `allowKeywords: false` requires brackets for the keywords of ES3, e.g. `foo["class"]`
+    /// * `allowPattern` is a regular expression of property names which may use brackets,
+    ///   e.g. `"^[a-z]+(_[a-z]+)+$"` for snake case names of an API
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var x = foo["bar"];
+    /// ```
+    DotNotation,
+    style,
+    fix = safe
+);
+
+/// Reserved words of ES3, which are not allowed after a dot.
+const ES3_KEYWORDS: [&str; 59] = [
+    "abstract",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "function",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "volatile",
+    "while",
+    "with",
+];
+
+impl Rule for DotNotation {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let options = value.get(0);
+        let allow_keywords = options
+            .and_then(|options| options.get("allowKeywords"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(true);
+        let allow_pattern = options
+            .and_then(|options| options.get("allowPattern"))
+            .and_then(serde_json::Value::as_str)
+            .and_then(|pattern| Regex::new(pattern).ok());
+        Self(Box::new(DotNotationConfig { allow_keywords, allow_pattern }))
+    }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[("allowKeywords", OptionType::Boolean), ("allowPattern", OptionType::Pattern)],
+        )
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::MemberExpression(member) = node.kind() else { return };
+        match member {
+            MemberExpression::ComputedMemberExpression(computed) => {
+                let (name, raw) = match &computed.expression {
+                    Expression::StringLiteral(string) => {
+                        (string.value.as_str(), string.span.source_text(ctx.source_text()))
+                    }
+                    Expression::TemplateLiteral(template) if template.expressions.is_empty() => {
+                        let Some(quasi) = template.quasis.first() else { return };
+                        (quasi.value.raw.as_str(), template.span.source_text(ctx.source_text()))
+                    }
+                    _ => return,
+                };
+                if !is_identifier_name(name)
+                    || (!self.allow_keywords && is_keyword(name))
+                    || self.allow_pattern.as_ref().is_some_and(|pattern| pattern.is_match(name))
+                {
+                    return;
+                }
+                let diagnostic = DotNotationDiagnostic::UseDot(raw.to_string(), computed.span);
+                let span = Span::new(computed.object.span().end, computed.span.end);
+                // `1.foo` would be a number, comments would be lost
+                if matches!(computed.object, Expression::NumberLiteral(_))
+                    || ctx.semantic().trivias().has_comments_between(span)
+                {
+                    ctx.diagnostic(diagnostic);
+                    return;
+                }
+                let dot = if computed.optional { "?." } else { "." };
+                ctx.diagnostic_with_fix(diagnostic, || Fix::new(format!("{dot}{name}"), span));
+            }
+            MemberExpression::StaticMemberExpression(member) => {
+                let name = member.property.name.as_str();
+                if self.allow_keywords || !is_keyword(name) {
+                    return;
+                }
+                let span = Span::new(member.object.span().end, member.span.end);
+                let diagnostic = DotNotationDiagnostic::UseBrackets(name.to_string(), member.span);
+                if ctx.semantic().trivias().has_comments_between(span) {
+                    ctx.diagnostic(diagnostic);
+                    return;
+                }
+                let dot = if member.optional { "?." } else { "" };
+                let quote = ctx.quote();
+                ctx.diagnostic_with_fix(diagnostic, || {
+                    Fix::new(format!("{dot}[{quote}{name}{quote}]"), span)
+                });
+            }
+            MemberExpression::PrivateFieldExpression(_) => {}
+        }
+    }
+}
+
+fn is_keyword(name: &str) -> bool {
+    ES3_KEYWORDS.contains(&name)
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("a.b;", None),
+        ("a.b.c;", None),
+        ("a['12'];", None),
+        ("a[b];", None),
+        ("a[0];", None),
+        ("a['foo-bar'];", None),
+        ("a[`with${interpolation}`];", None),
+        ("a.true;", None),
+        ("a.null;", None),
+        ("a['while'];", Some(json!([{ "allowKeywords": false }]))),
+        ("a.foo;", Some(json!([{ "allowKeywords": false }]))),
+        ("a['snake_case'];", Some(json!([{ "allowPattern": "^[a-z]+(_[a-z]+)+$" }]))),
+        ("a['lots_of_snake_case'];", Some(json!([{ "allowPattern": "^[a-z]+(_[a-z]+)+$" }]))),
+        ("a[`time${range}`];", Some(json!([{ "allowPattern": "^[a-z]+(_[a-z]+)+$" }]))),
+        ("this.#foo;", None),
+    ];
+
+    let fail = vec![
+        ("a.true;", Some(json!([{ "allowKeywords": false }]))),
+        ("a['true'];", None),
+        ("a[`time`];", None),
+        ("a['b'];", None),
+        ("a.b['c'];", None),
+        ("a['_dangle'];", Some(json!([{ "allowPattern": "^[a-z]+(_[a-z]+)+$" }]))),
+        ("a['SHOUT_CASE'];", Some(json!([{ "allowPattern": "^[a-z]+(_[a-z]+)+$" }]))),
+        ("a?.['b'];", None),
+        ("1['toString'];", None),
+        ("a[/* comment */ 'b'];", None),
+    ];
+
+    let fix = vec![
+        ("a['b'];", "a.b;", None),
+        ("a.b['c'];", "a.b.c;", None),
+        ("a [ 'b' ];", "a.b;", None),
+        ("a[`time`];", "a.time;", None),
+        ("a?.['b'];", "a?.b;", None),
+        ("a.true;", "a[\"true\"];", Some(json!([{ "allowKeywords": false }]))),
+        ("a?.while;", "a?.[\"while\"];", Some(json!([{ "allowKeywords": false }]))),
+        ("1['toString'];", "1['toString'];", None),
+        ("a[/* comment */ 'b'];", "a[/* comment */ 'b'];", None),
+    ];
+
+    Tester::new(DotNotation::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
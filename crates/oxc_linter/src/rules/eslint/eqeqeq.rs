@@ -36,8 +36,18 @@ declare_oxc_lint!(
     /// let b = false
     /// a == b
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `"always"` (default) enforces `===` and `!==` everywhere. With `{ "null": "ignore" }`
+    ///   comparisons with `null` are not checked, with `{ "null": "never" }` they must use `==`
+    ///   and `!=`.
+    /// * `"smart"` allows `==` and `!=` for comparing two literals, the result of `typeof`
+    ///   and `null`.
+    /// * `"allow-null"` is the deprecated form of `["always", { "null": "ignore" }]`.
     Eqeqeq,
-    pedantic
+    pedantic,
+    fix = unsafe
 );
 
 impl Rule for Eqeqeq {
@@ -45,6 +55,10 @@ impl Rule for Eqeqeq {
         let obj1 = value.get(0);
         let obj2 = value.get(1);
 
+        if obj1.and_then(serde_json::Value::as_str) == Some("allow-null") {
+            return Self { compare_type: CompareType::Always, null_type: NullType::Ignore };
+        }
+
         Self {
             compare_type: obj1
                 .and_then(serde_json::Value::as_str)
@@ -58,6 +72,30 @@ impl Rule for Eqeqeq {
         }
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        match value.get(0) {
+            None => {}
+            Some(serde_json::Value::String(mode))
+                if matches!(mode.as_str(), "always" | "smart" | "allow-null") => {}
+            Some(mode) => {
+                return Err(format!(
+                    r#"expected "always", "smart" or "allow-null", but got {mode}"#
+                ))
+            }
+        }
+        match value.get(1).map(|v| v.get("null")) {
+            None | Some(None) => Ok(()),
+            Some(Some(serde_json::Value::String(null)))
+                if matches!(null.as_str(), "always" | "never" | "ignore") =>
+            {
+                Ok(())
+            }
+            Some(Some(null)) => Err(format!(
+                r#"expected "null" to be "always", "never" or "ignore", but got {null}"#
+            )),
+        }
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::BinaryExpression(binary_expr) = node.kind() else { return };
         let is_null = is_null_check(binary_expr);
@@ -210,6 +248,8 @@ fn test() {
         ("null == null", Some(json!(["always", {"null": "never"}]))),
         // Do not apply this rule to `null`.
         ("null == null", Some(json!(["smart", {"null": "ignore"}]))),
+        ("foo == null", Some(json!(["allow-null"]))),
+        ("null != foo", Some(json!(["allow-null"]))),
     ];
 
     let fail = vec![
@@ -225,6 +265,7 @@ fn test() {
         ("bananas != 1", None),
         ("value == undefined", None),
         ("null == null", Some(json!(["always", {"null": "always"}]))),
+        ("foo == undefined", Some(json!(["allow-null"]))),
     ];
 
     let fix = vec![
@@ -13,7 +13,12 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
 use super::array_callback_return::return_checker;
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(getter-return): Expected to always return a value in getter.")]
@@ -190,6 +195,10 @@ impl Rule for GetterReturn {
 
         Self { allow_implicit }
     }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("allowImplicit", OptionType::Boolean)])
+    }
 }
 
 #[test]
@@ -0,0 +1,116 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    metrics::{check_max_option, max_option},
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(max-depth): Blocks are nested too deeply ({0}). Maximum allowed is {1}.")]
+#[diagnostic(severity(warning), help("Return early or move the nested code to a function."))]
+struct MaxDepthDiagnostic(usize, usize, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct MaxDepth {
+    max: usize,
+}
+
+impl Default for MaxDepth {
+    fn default() -> Self {
+        Self { max: 4 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a maximum depth of nested blocks, i.e. of `if`, `switch`, `try`, `with` and loop
+    /// statements, in functions and at the top level. `else if` is at the depth of its `if`, and
+    /// the blocks of a nested function start over. The maximum is 4 by default, `[2]` or
+    /// `[{ "max": 2 }]` change it.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deeply nested code is hard to read.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // With a maximum of 2
+    /// function foo() {
+    ///   for (;;) {
+    ///     while (true) {
+    ///       if (true) {}
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    MaxDepth,
+    pedantic
+);
+
+impl Rule for MaxDepth {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self { max: max_option(&value, Self::default().max) }
+    }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_max_option(value)
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !matches!(
+            node.kind(),
+            AstKind::Program(_) | AstKind::Function(_) | AstKind::ArrowExpression(_)
+        ) {
+            return;
+        }
+        let Some(metrics) = ctx.metrics().get(node.id()) else { return };
+        for (span, depth) in &metrics.blocks {
+            if *depth > self.max {
+                ctx.diagnostic(MaxDepthDiagnostic(*depth, self.max, *span));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(json!([3]))),
+        ("function foo() { if (true) { } else if (false) { } else if (true) { } else if (false) {} }", Some(json!([3]))),
+        ("var foo = () => { if (true) { if (false) { if (true) { } } } }", Some(json!([3]))),
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", None),
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(json!([{ "max": 3 }]))),
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(json!([{ "maximum": 3 }]))),
+        // A nested function starts over
+        ("function foo() { if (true) { function bar() { if (true) { } } } }", Some(json!([1]))),
+        ("if (true) { if (false) { } }", Some(json!([2]))),
+    ];
+
+    let fail = vec![
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(json!([2]))),
+        ("var foo = () => { if (true) { if (false) { if (true) { } } } }", Some(json!([2]))),
+        ("function foo() { if (true) {} else { for(;;) {} } }", Some(json!([1]))),
+        ("function foo() { while (true) { if (true) {} } }", Some(json!([1]))),
+        ("function foo() { for (let x of foo) { if (true) {} } }", Some(json!([1]))),
+        ("function foo() { while (true) { if (true) { if (false) { } } } }", Some(json!([1]))),
+        ("function foo() { if (true) { if (false) { if (true) { if (false) { if (true) { } } } } } }", None),
+        ("function foo() { switch (x) { case 1: try { a(); } catch (e) {} } }", Some(json!([{ "max": 1 }]))),
+        ("if (true) { if (false) { } }", Some(json!([1]))),
+    ];
+
+    Tester::new(MaxDepth::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,185 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    metrics::{
+        check_max_option, function_head, function_name_with_kind, function_span, is_iife,
+        max_option,
+    },
+    rule::Rule,
+    utils::{check_properties, OptionType},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(max-lines-per-function): {0} has too many lines ({1}). Maximum allowed is {2}.")]
+#[diagnostic(severity(warning), help("Split the function into smaller functions."))]
+struct MaxLinesPerFunctionDiagnostic(String, usize, usize, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct MaxLinesPerFunction(Box<MaxLinesPerFunctionConfig>);
+
+#[derive(Debug, Clone)]
+pub struct MaxLinesPerFunctionConfig {
+    max: usize,
+    skip_blank_lines: bool,
+    skip_comments: bool,
+    /// Check the functions which are called where they are defined
+    iifes: bool,
+}
+
+impl Default for MaxLinesPerFunctionConfig {
+    fn default() -> Self {
+        Self { max: 50, skip_blank_lines: false, skip_comments: false, iifes: false }
+    }
+}
+
+impl std::ops::Deref for MaxLinesPerFunction {
+    type Target = MaxLinesPerFunctionConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a maximum number of lines of code in a function, 50 by default. The options are
+    /// `max`, `skipBlankLines` and `skipComments` to count only the lines with code, and `IIFEs`
+    /// to also check the functions which are called where they are defined, e.g.
+    /// `[{ "max": 30, "skipBlankLines": true, "skipComments": true }]`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Long functions tend to do several things, which makes them hard to understand and to
+    /// reuse.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // With a maximum of 2
+    /// function foo() {
+    ///   const x = 0;
+    /// }
+    /// ```
+    MaxLinesPerFunction,
+    pedantic
+);
+
+impl Rule for MaxLinesPerFunction {
+    fn from_configuration(value: Value) -> Self {
+        let default = MaxLinesPerFunctionConfig::default();
+        let option = value.get(0);
+        let flag = |name: &str, default: bool| {
+            option.and_then(|option| option.get(name)).and_then(Value::as_bool).unwrap_or(default)
+        };
+        Self(Box::new(MaxLinesPerFunctionConfig {
+            max: max_option(&value, default.max),
+            skip_blank_lines: flag("skipBlankLines", default.skip_blank_lines),
+            skip_comments: flag("skipComments", default.skip_comments),
+            iifes: flag("IIFEs", default.iifes),
+        }))
+    }
+
+    fn validate_configuration(value: &Value) -> Result<(), String> {
+        check_max_option(value)?;
+        check_properties(
+            value.get(0).filter(|option| option.is_object()),
+            &[
+                ("skipBlankLines", OptionType::Boolean),
+                ("skipComments", OptionType::Boolean),
+                ("IIFEs", OptionType::Boolean),
+            ],
+        )
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let is_function = match node.kind() {
+            AstKind::Function(func) => func.body.is_some(),
+            kind => matches!(kind, AstKind::ArrowExpression(_)),
+        };
+        if !is_function || (!self.iifes && is_iife(node, ctx)) {
+            return;
+        }
+        let span = function_span(node, ctx);
+        let lines = ctx.metrics().lines().count(span, self.skip_blank_lines, self.skip_comments);
+        if lines > self.max {
+            ctx.diagnostic(MaxLinesPerFunctionDiagnostic(
+                function_name_with_kind(node, ctx),
+                lines,
+                self.max,
+                function_head(node, ctx),
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var x = 5;\nvar x = 2;\n", Some(json!([1]))),
+        ("function name() {}", Some(json!([1]))),
+        ("function name() {\nvar x = 5;\nvar x = 2;\n}", Some(json!([4]))),
+        ("const bar = () => 2", Some(json!([1]))),
+        ("const bar = () => {\nconst x = 2 + 1;\nreturn x;\n}", Some(json!([4]))),
+        (
+            "function name() {\nvar x = 5;\n\t\n \n\nvar x = 2;\n}",
+            Some(json!([{ "max": 4, "skipBlankLines": true }])),
+        ),
+        (
+            "function name() {\nvar x = 5;\n\t\n \n\nvar x = 2;\n}",
+            Some(json!([{ "max": 7, "skipBlankLines": false }])),
+        ),
+        (
+            "function name() {\nvar x = 5;\n// a comment\nvar x = 2;\n}",
+            Some(json!([{ "max": 4, "skipComments": true }])),
+        ),
+        (
+            "function name() {\nvar x = 5;\n/* a\n multi line\n comment */\nvar x = 2;\n}",
+            Some(json!([{ "max": 4, "skipComments": true }])),
+        ),
+        (
+            "function name() {\nvar x = 5; // a comment\n}",
+            Some(json!([{ "max": 3, "skipComments": true }])),
+        ),
+        ("(function() {\nvar x = 5;\nvar x = 2;\n})();", Some(json!([{ "max": 2 }]))),
+        ("(() => {\nvar x = 5;\nvar x = 2;\n})();", Some(json!([{ "max": 2, "IIFEs": false }]))),
+        ("function name() {\nvar x = 5;\nvar x = 2;\n}", Some(json!([{ "maximum": 4 }]))),
+    ];
+
+    let fail = vec![
+        ("function name() {\n}", Some(json!([1]))),
+        ("var func = function() {\n}", Some(json!([1]))),
+        ("const bar = () => {\nconst x = 2 + 1;\nreturn x;\n}", Some(json!([3]))),
+        ("const bar = () =>\n 2", Some(json!([1]))),
+        ("function name() {\nvar x = 5;\n\t\n \n\nvar x = 2;\n}", Some(json!([{ "max": 6 }]))),
+        (
+            "function name() {\nvar x = 5;\n\t\n \n\nvar x = 2;\n}",
+            Some(json!([{ "max": 2, "skipBlankLines": true }])),
+        ),
+        ("function name() {\nvar x = 5;\n// a comment\nvar x = 2;\n}", Some(json!([{ "max": 4 }]))),
+        (
+            "function name() {\nvar x = 5; // a comment\n}",
+            Some(json!([{ "max": 2, "skipComments": true }])),
+        ),
+        ("class C {\n  method() {\n    return 1;\n  }\n}", Some(json!([2]))),
+        (
+            "(function() {\nvar x = 5;\nvar x = 2;\n})();",
+            Some(json!([{ "max": 2, "IIFEs": true }])),
+        ),
+    ];
+
+    Tester::new(MaxLinesPerFunction::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,112 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    metrics::{check_max_option, function_head, function_name_with_kind, max_option},
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(max-params): {0} has too many parameters ({1}). Maximum allowed is {2}.")]
+#[diagnostic(severity(warning), help("Group the parameters in an object."))]
+struct MaxParamsDiagnostic(String, usize, usize, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct MaxParams {
+    max: usize,
+}
+
+impl Default for MaxParams {
+    fn default() -> Self {
+        Self { max: 3 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a maximum number of parameters of functions, 3 by default, which `[4]` or
+    /// `[{ "max": 4 }]` change. The `this` parameter of TypeScript is not counted, and neither are
+    /// functions without a body, such as overloads.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Functions with many parameters are hard to call correctly, the arguments are easily mixed
+    /// up.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// function foo(bar, baz, qux, qxx) {
+    ///   doSomething();
+    /// }
+    /// ```
+    MaxParams,
+    pedantic
+);
+
+impl Rule for MaxParams {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self { max: max_option(&value, Self::default().max) }
+    }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_max_option(value)
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let params = match node.kind() {
+            AstKind::Function(func) if func.body.is_some() => &func.params,
+            AstKind::ArrowExpression(arrow) => &arrow.params,
+            _ => return,
+        };
+        let count = params.parameters_count();
+        if count > self.max {
+            ctx.diagnostic(MaxParamsDiagnostic(
+                function_name_with_kind(node, ctx),
+                count,
+                self.max,
+                function_head(node, ctx),
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function test(d, e, f) {}", None),
+        ("var test = function(a, b, c) {};", Some(json!([3]))),
+        ("var test = (a, b, c) => {};", Some(json!([3]))),
+        ("var test = function test(a, b, c) {};", Some(json!([3]))),
+        ("var test = function(a, b, c) {};", Some(json!([{ "max": 3 }]))),
+        ("var test = function(a, b, c) {};", Some(json!([{ "maximum": 3 }]))),
+        ("function test(this: Foo, a, b, c) {}", None),
+        ("declare function test(a, b, c, d): void;", None),
+    ];
+
+    let fail = vec![
+        ("function test(a, b, c) {}", Some(json!([2]))),
+        ("function test(a, b, c, d) {}", None),
+        ("var test = function(a, b, c, d) {};", Some(json!([3]))),
+        ("var test = (a, b, c, d) => {};", Some(json!([3]))),
+        ("(function(a, b, c, d) {});", Some(json!([3]))),
+        ("var test = function test(a, b, c) {};", Some(json!([1]))),
+        ("function test(a, b, ...c) {}", Some(json!([{ "max": 2 }]))),
+        ("class Foo { bar(a, b) {} }", Some(json!([1]))),
+        ("var foo = { async *bar(a, b) {} }", Some(json!([1]))),
+    ];
+
+    Tester::new(MaxParams::NAME, pass, fail).test_and_snapshot();
+}
@@ -7,7 +7,12 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::BinaryOperator;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-bitwise): Unexpected use of {0:?}")]
@@ -75,6 +80,13 @@ impl Rule for NoBitwise {
         }))
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[("allow", OptionType::Strings), ("int32Hint", OptionType::Boolean)],
+        )
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::BinaryExpression(bin_expr) => {
@@ -9,7 +9,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_option, OptionType},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-cond-assign): Expected a conditional expression and instead saw an assignment")]
@@ -54,6 +59,10 @@ impl Rule for NoCondAssign {
         Self { config }
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_option(value.get(0), OptionType::OneOf(&["except-parens", "always"]))
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::IfStatement(stmt) => self.check_expression(ctx, &stmt.test),
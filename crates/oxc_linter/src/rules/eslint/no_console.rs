@@ -6,7 +6,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-console): Unexpected console statement.")]
@@ -71,6 +76,10 @@ impl Rule for NoConsole {
         }))
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("allow", OptionType::Strings)])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::CallExpression(call_expr) = node.kind() {
             if let Expression::MemberExpression(mem) = &call_expr.callee {
@@ -6,7 +6,13 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{ast_util::IsConstant, context::LintContext, rule::Rule, AstNode};
+use crate::{
+    ast_util::IsConstant,
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-constant-condition): Unexpected constant condition")]
@@ -50,6 +56,10 @@ impl Rule for NoConstantCondition {
         }
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("checkLoops", OptionType::Boolean)])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::IfStatement(if_stmt) => {
@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// debugger;
     /// ```
     NoDebugger,
-    correctness
+    correctness,
+    fix = safe
 );
 
 impl Rule for NoDebugger {
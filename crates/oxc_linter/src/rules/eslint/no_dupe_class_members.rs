@@ -1,18 +1,15 @@
 use std::hash::BuildHasherDefault;
 
-use oxc_ast::{
-    ast::{ClassElement, MethodDefinitionKind},
-    AstKind,
-};
+use oxc_ast::ast::MethodDefinitionKind;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{Atom, GetSpan, Span};
+use oxc_span::{Atom, Span};
 use rustc_hash::FxHashMap;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-dupe-class-members): Duplicate class member: {0:?}")]
@@ -53,54 +50,58 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDupeClassMembers {
-    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::Class(class) = node.kind() else {
-            return;
-        };
-
-        let num_element = class.body.body.len();
-        let mut property_table = PropertyTable::with_capacity(num_element);
-        for element in &class.body.body {
-            if ctx.source_type().is_typescript() && element.is_ts_empty_body_function() {
-                // Skip functions with no function bodies, which are Typescript's overload signatures
-                continue;
-            }
-
-            if let Some(dup_span) = property_table.insert(element) {
-                let property_name = element.property_key().unwrap().static_name().unwrap();
-                let cur_span = element.property_key().unwrap().span();
-                ctx.diagnostic(NoDupeClassMembersDiagnostic(property_name, dup_span, cur_span));
+    fn run_once(&self, ctx: &LintContext) {
+        let classes = ctx.semantic().classes();
+        for (class_id, _) in classes.iter_enumerated() {
+            // Properties and methods in source order
+            let mut members = classes.properties[class_id]
+                .iter()
+                .map(|property| {
+                    (property.is_private, property.r#static, &property.name, None, property.span)
+                })
+                .chain(classes.methods[class_id].iter().map(|method| {
+                    (
+                        method.is_private,
+                        method.r#static,
+                        &method.name,
+                        Some(method.kind),
+                        method.span,
+                    )
+                }))
+                .collect::<Vec<_>>();
+            members.sort_unstable_by_key(|(_, _, _, _, span)| span.start);
+
+            let mut property_table = PropertyTable::with_capacity(members.len());
+            for (is_private, r#static, name, kind, span) in members {
+                let key = (is_private, r#static, name.clone());
+                if let Some(dup_span) = property_table.insert(key, kind, span) {
+                    let name =
+                        if is_private { Atom::from(format!("#{name}")) } else { name.clone() };
+                    ctx.diagnostic(NoDupeClassMembersDiagnostic(name, dup_span, span));
+                }
             }
         }
     }
 }
 
-/// (static, name)
-type PropertyTableKey = (bool, Atom);
+/// (private, static, name)
+type PropertyTableKey = (bool, bool, Atom);
 /// (Definition kind, span of last declaration)
 type PropertyTableEntry = (Option<MethodDefinitionKind>, Span);
 /// Table to track whether a name is defined in static/non-static context as a getter/setter/normal class members
-/// Maps (static, name) -> (kind -> span of last declaration)
+/// Maps (private, static, name) -> (kind -> span of last declaration)
 #[derive(Debug, Clone, Default)]
 struct PropertyTable(FxHashMap<PropertyTableKey, Vec<PropertyTableEntry>>);
 
 impl PropertyTable {
-    /// Return the last duplicate span if element's property key is duplicate,
-    /// otherwise return None and insert the property key into the table.
-    pub fn insert(&mut self, element: &ClassElement) -> Option<Span> {
-        // It is valid to have a normal method named 'constructor'
-        if element.method_definition_kind() == Some(MethodDefinitionKind::Constructor) {
-            return None;
-        }
-
-        let Some((property_name, property_span)) =
-            element.property_key().and_then(|key| key.static_name().map(|name| (name, key.span())))
-        else {
-            return None;
-        };
-        let property_kind = element.method_definition_kind();
-
-        let key = (element.r#static(), property_name);
+    /// Return the last duplicate span if the key is duplicate,
+    /// otherwise return None and insert the key into the table.
+    pub fn insert(
+        &mut self,
+        key: PropertyTableKey,
+        property_kind: Option<MethodDefinitionKind>,
+        property_span: Span,
+    ) -> Option<Span> {
         let entry = self.0.entry(key).or_default();
         for (kind, span) in &*entry {
             if Self::conflict(*kind, property_kind) {
@@ -165,6 +166,7 @@ fn test() {
         "class A { static foo() {}; foo() {}; }",
         "class A { foo; #foo; }",
         "class A { '#foo'; #foo; }",
+        "class A { get #foo() {} set #foo(value) {} }",
         // typescript-eslint
         "class A { foo() {} bar() {} }",
         "class A { static foo() {} foo() {} }",
@@ -6,7 +6,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-empty): Disallow empty block statements")]
@@ -47,6 +52,10 @@ impl Rule for NoEmpty {
         }
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("allowEmptyCatch", OptionType::Boolean)])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::BlockStatement(block) if block.body.is_empty() => {
@@ -9,7 +9,11 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNode;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-eval): eval can be harmful.")]
@@ -61,6 +65,10 @@ impl Rule for NoEval {
         Self { allow_indirect }
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("allowIndirect", OptionType::Boolean)])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let kind = node.kind();
 
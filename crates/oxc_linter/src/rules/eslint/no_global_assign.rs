@@ -5,7 +5,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
 
-use crate::{context::LintContext, globals::BUILTINS, rule::Rule};
+use crate::{
+    context::LintContext,
+    globals::BUILTINS,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-global-assign): Read-only global '{0}' should not be modified.")]
@@ -63,6 +68,10 @@ impl Rule for NoGlobalAssign {
         }))
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("exceptions", OptionType::Strings)])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let symbol_table = ctx.symbols();
         for reference_id_list in ctx.scopes().root_unresolved_references().values() {
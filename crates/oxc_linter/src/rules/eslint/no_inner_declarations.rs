@@ -6,7 +6,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_option, OptionType},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error(
@@ -63,6 +68,10 @@ impl Rule for NoInnerDeclarations {
         Self { config }
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_option(value.get(0), OptionType::OneOf(&["functions", "both"]))
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let span = match node.kind() {
             AstKind::VariableDeclaration(decl)
@@ -7,7 +7,11 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNode;
 use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-mixed-operators): Unexpected mix of {0} with {1}")]
@@ -94,6 +98,25 @@ impl Rule for NoMixedOperators {
     fn from_configuration(value: serde_json::Value) -> Self {
         Self::try_from_configuration(&value).unwrap_or_default()
     }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("allowSamePrecedence", OptionType::Boolean)])?;
+        let is_group = |group: &serde_json::Value| {
+            group.as_array().is_some_and(|operators| {
+                operators
+                    .iter()
+                    .all(|operator| operator.as_str().and_then(operator_and_precedence).is_some())
+            })
+        };
+        match value.get(0).and_then(|config| config.get("groups")) {
+            Some(groups)
+                if !groups.as_array().is_some_and(|groups| groups.iter().all(is_group)) =>
+            {
+                Err(r#"expected "groups" to be an array of arrays of operators"#.to_string())
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 impl NoMixedOperators {
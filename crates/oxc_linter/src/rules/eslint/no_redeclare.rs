@@ -10,7 +10,12 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::VariableInfo;
 use oxc_span::{Atom, Span};
 
-use crate::{context::LintContext, globals::BUILTINS, rule::Rule};
+use crate::{
+    context::LintContext,
+    globals::BUILTINS,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-redeclare): '{0}' is already defined.")]
@@ -72,6 +77,10 @@ impl Rule for NoRedeclare {
         Self { built_in_globals }
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("builtinGlobals", OptionType::Boolean)])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let redeclare_variables = ctx.semantic().redeclare_variables();
         let symbol_table = ctx.semantic().symbols();
@@ -0,0 +1,413 @@
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use oxc_ast::{
+    ast::{ImportDeclarationSpecifier, ModuleDeclaration},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, utils::OptionType, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-restricted-imports): {0}")]
+#[diagnostic(severity(warning))]
+struct NoRestrictedImportsDiagnostic(String, #[help] Option<String>, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedImports(Box<NoRestrictedImportsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedImportsConfig {
+    paths: Vec<RestrictedPath>,
+    patterns: Vec<RestrictedPattern>,
+}
+
+impl std::ops::Deref for NoRestrictedImports {
+    type Target = NoRestrictedImportsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A module restricted by its exact name
+#[derive(Debug, Clone)]
+struct RestrictedPath {
+    name: String,
+    message: Option<String>,
+    /// Only these names are restricted when set, the whole module otherwise
+    import_names: Option<Vec<String>>,
+}
+
+/// Modules restricted by gitignore-style patterns, e.g. `lodash/*` and `!lodash/pick`
+#[derive(Debug, Clone)]
+struct RestrictedPattern {
+    matcher: Gitignore,
+    message: Option<String>,
+    import_names: Option<Vec<String>>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows specified modules when loaded by `import` or re-exported by `export ... from`.
+    ///
+    /// ### Why is this bad?
+    /// Some modules should not be used in a project, e.g. a library which is too large for
+    /// the browser, internal modules of a package or a legacy module which is being replaced.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // ["error", { "paths": [{ "name": "lodash", "message": "Use lodash-es instead." }] }]
+    /// import _ from 'lodash';
+    ///
+    /// // ["error", { "paths": [{ "name": "react", "importNames": ["default"] }] }]
+    /// import React from 'react';
+    ///
+    /// // ["error", { "patterns": ["@company/*/internal", "!@company/ui/internal"] }]
+    /// import { helper } from '@company/auth/internal';
+    /// ```
+    NoRestrictedImports,
+    restriction
+);
+
+impl Rule for NoRestrictedImports {
+    /// Both the object form `[{ "paths": [...], "patterns": [...] }]` and the list form
+    /// `["lodash", { "name": "react", "importNames": ["default"] }]` are supported.
+    fn from_configuration(value: Value) -> Self {
+        let mut config = NoRestrictedImportsConfig::default();
+        let options = value.as_array().cloned().unwrap_or_default();
+        match options.first() {
+            Some(Value::Object(object))
+                if object.contains_key("paths") || object.contains_key("patterns") =>
+            {
+                if let Some(paths) = object.get("paths").and_then(Value::as_array) {
+                    config.paths = paths.iter().filter_map(RestrictedPath::from_json).collect();
+                }
+                if let Some(patterns) = object.get("patterns").and_then(Value::as_array) {
+                    config.patterns = RestrictedPattern::from_json(patterns);
+                }
+            }
+            _ => config.paths = options.iter().filter_map(RestrictedPath::from_json).collect(),
+        }
+        Self(Box::new(config))
+    }
+
+    fn validate_configuration(value: &Value) -> Result<(), String> {
+        let options = value.as_array().map_or(&[][..], Vec::as_slice);
+        let paths = match options.first() {
+            Some(Value::Object(object))
+                if object.contains_key("paths") || object.contains_key("patterns") =>
+            {
+                let is_pattern = |pattern: &Value| {
+                    pattern.is_string()
+                        || pattern
+                            .get("group")
+                            .is_some_and(|group| OptionType::Strings.accepts(group))
+                };
+                if let Some(patterns) = object.get("patterns") {
+                    if !patterns.as_array().is_some_and(|patterns| patterns.iter().all(is_pattern))
+                    {
+                        return Err(
+                            r#"expected "patterns" to be an array of patterns or of objects with a "group""#
+                                .to_string(),
+                        );
+                    }
+                }
+                match object.get("paths") {
+                    None => return Ok(()),
+                    Some(Value::Array(paths)) => paths.as_slice(),
+                    Some(_) => return Err(r#"expected "paths" to be an array"#.to_string()),
+                }
+            }
+            _ => options,
+        };
+        if paths.iter().all(|path| RestrictedPath::from_json(path).is_some()) {
+            Ok(())
+        } else {
+            Err(r#"expected the restricted paths to be strings or objects with a "name""#
+                .to_string())
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ModuleDeclaration(decl) = node.kind() else { return };
+        // `(imported name, span)`, `*` for namespace imports and `export *`
+        let (source, names) = match decl {
+            ModuleDeclaration::ImportDeclaration(decl) => {
+                let names = decl
+                    .specifiers
+                    .iter()
+                    .flatten()
+                    .map(|specifier| match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(s) => {
+                            (s.imported.name().as_str(), s.span)
+                        }
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => {
+                            ("default", s.span)
+                        }
+                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => ("*", s.span),
+                    })
+                    .collect::<Vec<_>>();
+                (&decl.source, names)
+            }
+            ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                let Some(source) = &decl.source else { return };
+                let names = decl
+                    .specifiers
+                    .iter()
+                    .map(|specifier| (specifier.local.name().as_str(), specifier.span))
+                    .collect::<Vec<_>>();
+                (source, names)
+            }
+            ModuleDeclaration::ExportAllDeclaration(decl) => {
+                (&decl.source, vec![("*", decl.source.span)])
+            }
+            _ => return,
+        };
+        let source_name = source.value.as_str();
+
+        for path in self.paths.iter().filter(|path| path.name == source_name) {
+            let (message, import_names) = (path.message.as_deref(), path.import_names.as_deref());
+            report(ctx, source_name, source.span, &names, message, import_names, "");
+        }
+        for pattern in self.patterns.iter().filter(|pattern| pattern.matches(source_name)) {
+            let (message, import_names) =
+                (pattern.message.as_deref(), pattern.import_names.as_deref());
+            let suffix = " from being used by a pattern";
+            report(ctx, source_name, source.span, &names, message, import_names, suffix);
+        }
+    }
+}
+
+fn report(
+    ctx: &LintContext<'_>,
+    source: &str,
+    source_span: Span,
+    names: &[(&str, Span)],
+    message: Option<&str>,
+    import_names: Option<&[String]>,
+    suffix: &str,
+) {
+    let Some(import_names) = import_names else {
+        let error = if suffix.is_empty() {
+            format!("'{source}' import is restricted from being used.")
+        } else {
+            format!("'{source}' import is restricted{suffix}.")
+        };
+        ctx.diagnostic(NoRestrictedImportsDiagnostic(
+            error,
+            message.map(ToString::to_string),
+            source_span,
+        ));
+        return;
+    };
+    for &(name, span) in names {
+        let error = if name == "*" {
+            let restricted = import_names.join(", ");
+            format!(
+                "* import is invalid because '{restricted}' from '{source}' is restricted{suffix}."
+            )
+        } else if import_names.iter().any(|import_name| import_name == name) {
+            format!("'{name}' import from '{source}' is restricted{suffix}.")
+        } else {
+            continue;
+        };
+        ctx.diagnostic(NoRestrictedImportsDiagnostic(
+            error,
+            message.map(ToString::to_string),
+            span,
+        ));
+    }
+}
+
+impl RestrictedPath {
+    fn from_json(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(name) => {
+                Some(Self { name: name.clone(), message: None, import_names: None })
+            }
+            Value::Object(object) => Some(Self {
+                name: object.get("name")?.as_str()?.to_string(),
+                message: message(value),
+                import_names: import_names(value),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl RestrictedPattern {
+    /// Plain strings form a single group, objects are groups with their own message.
+    fn from_json(values: &[Value]) -> Vec<Self> {
+        let strings = values.iter().filter_map(Value::as_str).collect::<Vec<_>>();
+        let mut patterns = vec![];
+        if !strings.is_empty() {
+            patterns.extend(Self::new(&strings, false, None, None));
+        }
+        for value in values.iter().filter(|value| value.is_object()) {
+            let Some(group) = value.get("group").and_then(Value::as_array) else { continue };
+            let group = group.iter().filter_map(Value::as_str).collect::<Vec<_>>();
+            let case_sensitive =
+                value.get("caseSensitive").and_then(Value::as_bool).unwrap_or_default();
+            patterns.extend(Self::new(&group, case_sensitive, message(value), import_names(value)));
+        }
+        patterns
+    }
+
+    fn new(
+        group: &[&str],
+        case_sensitive: bool,
+        message: Option<String>,
+        import_names: Option<Vec<String>>,
+    ) -> Option<Self> {
+        let mut builder = GitignoreBuilder::new("");
+        builder.case_insensitive(!case_sensitive).ok()?;
+        for pattern in group {
+            builder.add_line(None, pattern).ok()?;
+        }
+        let matcher = builder.build().ok()?;
+        Some(Self { matcher, message, import_names })
+    }
+
+    /// Like gitignore, `lodash` also matches `lodash/fp`.
+    fn matches(&self, source: &str) -> bool {
+        let source = source.trim_start_matches('/');
+        !source.is_empty() && self.matcher.matched_path_or_any_parents(source, false).is_ignore()
+    }
+}
+
+fn message(value: &Value) -> Option<String> {
+    value.get("message").and_then(Value::as_str).map(ToString::to_string)
+}
+
+fn import_names(value: &Value) -> Option<Vec<String>> {
+    let names = value.get("importNames")?.as_array()?;
+    Some(names.iter().filter_map(Value::as_str).map(ToString::to_string).collect())
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import os from 'os';", None),
+        ("import os from 'os';", Some(json!(["osx"]))),
+        ("import fs from 'fs';", Some(json!(["crypto"]))),
+        ("import path from 'path';", Some(json!(["crypto", "stream", "os"]))),
+        ("import async from 'async';", None),
+        ("import 'foo'", Some(json!(["crypto"]))),
+        ("import 'foo/bar';", Some(json!(["foo"]))),
+        ("import withPaths from 'foo/bar';", Some(json!([{ "paths": ["foo", "bar"] }]))),
+        ("import withPatterns from 'foo/bar';", Some(json!([{ "patterns": ["foo/c*"] }]))),
+        ("import foo from 'foo';", Some(json!(["../foo"]))),
+        ("import foo from 'foo';", Some(json!([{ "paths": ["../foo"] }]))),
+        ("import foo from 'foo';", Some(json!([{ "patterns": ["../foo"] }]))),
+        (
+            "import withGitignores from 'foo/bar';",
+            Some(json!([{ "patterns": ["foo/*", "!foo/bar"] }])),
+        ),
+        (
+            "import withPatterns from 'foo/bar';",
+            Some(
+                json!([{ "patterns": [{ "group": ["foo/*", "!foo/bar"], "message": "foo is forbidden, use bar instead" }] }]),
+            ),
+        ),
+        (
+            "import withPatternsCaseSensitive from 'foo';",
+            Some(json!([{ "patterns": [{ "group": ["FOO"], "caseSensitive": true }] }])),
+        ),
+        (
+            "import AllowedObject from 'foo';",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import { AllowedObject } from 'foo';",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import { 'AllowedObject' as bar } from 'foo';",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "export { bar } from 'foo';",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import { bar } from 'foo';",
+            Some(
+                json!([{ "patterns": [{ "group": ["foo"], "importNames": ["DisallowedObject"] }] }]),
+            ),
+        ),
+        ("export { foo } from './foo';", Some(json!(["foo"]))),
+        ("const fs = require('fs');", Some(json!(["fs"]))),
+    ];
+
+    let fail = vec![
+        ("import \"fs\"", Some(json!(["fs"]))),
+        ("import os from \"os\";", Some(json!(["fs", "crypto ", "stream", "os"]))),
+        ("import \"foo/bar\";", Some(json!(["foo/bar"]))),
+        ("import withPaths from \"foo/bar\";", Some(json!([{ "paths": ["foo/bar"] }]))),
+        ("import withPatterns from \"foo/bar\";", Some(json!([{ "patterns": ["foo"] }]))),
+        ("import withPatterns from \"foo/bar\";", Some(json!([{ "patterns": ["bar"] }]))),
+        (
+            "import withPatterns from \"foo/baz\";",
+            Some(
+                json!([{ "patterns": [{ "group": ["foo/*", "!foo/bar"], "message": "foo is forbidden, use foo/bar instead" }] }]),
+            ),
+        ),
+        (
+            "import withPatterns from \"FOO\";",
+            Some(json!([{ "patterns": [{ "group": ["foo"] }] }])),
+        ),
+        (
+            "import withGitignores from \"foo/bar\";",
+            Some(json!([{ "patterns": ["foo/*", "!foo/baz"] }])),
+        ),
+        ("export * from \"fs\";", Some(json!(["fs"]))),
+        ("export {a} from \"fs\";", Some(json!(["fs"]))),
+        (
+            "import withGitignores from \"foo\";",
+            Some(json!([{ "name": "foo", "message": "Please import from 'bar' instead." }])),
+        ),
+        (
+            "import { DisallowedObject } from \"foo\";",
+            Some(
+                json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"], "message": "Please import 'DisallowedObject' from /bar/ instead." }] }]),
+            ),
+        ),
+        (
+            "import { 'DisallowedObject' as bar } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import * as All from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "export * from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import DisallowedObject, { AllowedObject } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["default"] }] }])),
+        ),
+        (
+            "export { DisallowedObject as Allowed } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import { DisallowedObject } from \"@company/ui/internal\";",
+            Some(
+                json!([{ "patterns": [{ "group": ["@company/*/internal"], "importNames": ["DisallowedObject"], "message": "Use the public API." }] }]),
+            ),
+        ),
+    ];
+
+    Tester::new(NoRestrictedImports::NAME, pass, fail).test_and_snapshot();
+}
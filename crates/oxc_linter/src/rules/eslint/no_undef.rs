@@ -1,4 +1,8 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    ast::{Statement, TSModuleDeclarationBody, TSModuleDeclarationName},
+    syntax_directed_operations::BoundNames,
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -6,8 +10,15 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
 use oxc_syntax::operator::UnaryOperator;
+use rustc_hash::FxHashSet;
 
-use crate::{context::LintContext, globals::BUILTINS, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    globals,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-undef): Disallow the use of undeclared variables")]
@@ -29,6 +40,10 @@ declare_oxc_lint!(
     ///
     /// It is most likely a potential ReferenceError caused by a misspelling of a variable or parameter name.
     ///
+    /// Besides the builtins of the language, the `env` presets and `globals` of the config,
+    /// `/* global foo, bar:writable */` and `/* eslint-env browser */` comments and the ambient
+    /// declarations of `declare global { ... }` define globals. References to types are ignored.
+    ///
     /// ### Example
     /// ```javascript
     /// var foo = someFunction();
@@ -47,19 +62,33 @@ impl Rule for NoUndef {
             .unwrap_or_default();
         Self { type_of }
     }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("typeof", OptionType::Boolean)])
+    }
     fn run_once(&self, ctx: &LintContext) {
         let symbol_table = ctx.symbols();
+        let comment_globals = comment_globals(ctx);
+        let ambient_globals = ambient_globals(ctx);
 
         for reference_id_list in ctx.scopes().root_unresolved_references().values() {
             for &reference_id in reference_id_list {
                 let reference = symbol_table.get_reference(reference_id);
-                if BUILTINS.contains_key(reference.name().as_str()) {
-                    return;
+                let name = reference.name().as_str();
+                if ctx.is_defined_global(name)
+                    || comment_globals.contains(name)
+                    || ambient_globals.contains(name)
+                {
+                    continue;
                 }
 
                 let node = ctx.nodes().get_node(reference.node_id());
                 if !self.type_of && has_typeof_operator(node, ctx) {
-                    return;
+                    continue;
+                }
+                // Types are not values, e.g. `Foo` of `let a: Foo` or `class A implements Foo {}`
+                if reference.is_type() {
+                    continue;
                 }
 
                 ctx.diagnostic(NoUndefDiagnostic(reference.name().clone(), reference.span()));
@@ -68,6 +97,77 @@ impl Rule for NoUndef {
     }
 }
 
+/// Globals of `/* global a, b:writable */`, `/* globals ... */` and `/* eslint-env node */`.
+fn comment_globals<'a>(ctx: &LintContext<'a>) -> FxHashSet<&'a str> {
+    let mut names = FxHashSet::default();
+    for (start, comment) in ctx.semantic().trivias().comments() {
+        if !comment.is_multi_line() {
+            continue;
+        }
+        let text = Span::new(*start, comment.end()).source_text(ctx.source_text()).trim_start();
+        let tokens = |text: &'a str| {
+            text.split(|c: char| c == ',' || c.is_whitespace()).filter(|token| !token.is_empty())
+        };
+        if let Some(text) = text.strip_prefix("eslint-env") {
+            for name in tokens(text) {
+                names.extend(
+                    globals::environment(name).into_iter().flat_map(|env| env.keys().copied()),
+                );
+            }
+            continue;
+        }
+        let Some(text) = text.strip_prefix("globals").or_else(|| text.strip_prefix("global"))
+        else {
+            continue;
+        };
+        if !text.starts_with(char::is_whitespace) {
+            continue;
+        }
+        // `a:readonly`, `a: readonly`, `a : readonly`, the value of `off` removes the global
+        let mut value_pending = false;
+        for token in tokens(text) {
+            if value_pending {
+                value_pending = false;
+                continue;
+            }
+            match token.split_once(':') {
+                Some(("", value)) => value_pending = value.is_empty(),
+                Some((name, value)) => {
+                    if value != "off" {
+                        names.insert(name);
+                    }
+                    value_pending = value.is_empty();
+                }
+                None => {
+                    names.insert(token);
+                }
+            }
+        }
+    }
+    names
+}
+
+/// Values declared in `declare global { ... }`.
+fn ambient_globals(ctx: &LintContext) -> FxHashSet<String> {
+    let mut names = FxHashSet::default();
+    for node in ctx.nodes().iter() {
+        let AstKind::TSModuleDeclaration(module) = node.kind() else { continue };
+        let TSModuleDeclarationName::Identifier(id) = &module.id else { continue };
+        let TSModuleDeclarationBody::TSModuleBlock(block) = &module.body else { continue };
+        if id.name != "global" {
+            continue;
+        }
+        for statement in &block.body {
+            if let Statement::Declaration(declaration) = statement {
+                declaration.bound_names(&mut |ident| {
+                    names.insert(ident.name.to_string());
+                });
+            }
+        }
+    }
+    names
+}
+
 fn has_typeof_operator(node: &AstNode<'_>, ctx: &LintContext<'_>) -> bool {
     ctx.nodes().parent_node(node.id()).map_or(false, |parent| match parent.kind() {
         AstKind::UnaryExpression(expr) => expr.operator == UnaryOperator::Typeof,
@@ -82,16 +182,16 @@ fn test() {
 
     let pass = vec![
         ("var a = 1, b = 2; a;", None),
-        // ("/*global b*/ function f() { b; }", None),
+        ("/*global b*/ function f() { b; }", None),
         // { code: "function f() { b; }", globals: { b: false } },
-        // ("/*global b a:false*/  a;  function f() { b; a; }", None),
+        ("/*global b a:false*/  a;  function f() { b; a; }", None),
         ("function a(){}  a();", None),
         ("function f(b) { b; }", None),
         ("var a; a = 1; a++;", None),
         ("var a; function f() { a = 1; }", None),
-        // ("/*global b:true*/ b++;", None),
-        // ("/*eslint-env browser*/ window;", None),
-        // ("/*eslint-env node*/ require(\"a\");", None),
+        ("/*global b:true*/ b++;", None),
+        ("/*eslint-env browser*/ window;", None),
+        ("/*eslint-env node*/ require(\"a\");", None),
         ("Object; isNaN();", None),
         ("toString()", None),
         ("hasOwnProperty()", None),
@@ -121,11 +221,12 @@ fn test() {
         // ("customElements;", None),
         // ("PromiseRejectionEvent;", None),
         ("(foo, bar) => { foo ||= WeakRef; bar ??= FinalizationRegistry; }", None),
-        // ("/*global b:false*/ function f() { b = 1; }", None),
+        ("/*global b:false*/ function f() { b = 1; }", None),
         // { code: "function f() { b = 1; }", globals: { b: false } },
-        // ("/*global b:false*/ function f() { b++; }", None),
-        // ("/*global b*/ b = 1;", None),
-        // ("/*global b:false*/ var b = 1;", None),
+        ("/*global b:false*/ function f() { b++; }", None),
+        ("/*global b*/ b = 1;", None),
+        ("/*global b:false*/ var b = 1;", None),
+        ("/* globals a, b : readonly, c: writable */ a; b; c;", None),
         ("Array = 1;", None),
         ("class A { constructor() { new.target; } }", None),
         // {
@@ -150,7 +251,13 @@ fn test() {
         ("class C { static { let a; a; } }", None),
         ("class C { static { a; let a; } }", None),
         ("class C { static { function a() {} a; } }", None),
-        ("class C { static { a; function a() {} } }", None)
+        ("class C { static { a; function a() {} } }", None),
+        ("declare global { var foo: string; function bar(): void; } foo; bar();", None),
+        ("declare const a: number; a;", None),
+        ("let a: Foo<Bar> = 1; a;", None),
+        ("let a: Foo.Bar = 1; a;", None),
+        ("class A implements Foo.Bar {} new A();", None),
+        ("interface A extends Foo.Bar {}", None),
     ];
 
     let fail = vec![
@@ -183,6 +290,11 @@ fn test() {
         ("class C { static { let a; } [a]; }", None),
         ("class C { static { function a() {} } [a]; }", None),
         ("class C { static { var a; } } a;", None),
+        ("/*global a:off*/ a;", None),
+        ("// global a\na;", None),
+        ("/*eslint-env browser*/ process;", None),
+        ("declare global { interface Foo {} } Foo;", None),
+        ("declare module 'foo' { var foo: string; } foo;", None),
     ];
 
     Tester::new(NoUndef::NAME, pass, fail).test_and_snapshot();
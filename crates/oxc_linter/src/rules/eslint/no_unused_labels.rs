@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoUnusedLabels,
-    correctness
+    correctness,
+    fix = safe
 );
 
 impl Rule for NoUnusedLabels {
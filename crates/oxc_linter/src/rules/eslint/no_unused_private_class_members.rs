@@ -1,11 +1,8 @@
-use itertools::Itertools;
-use oxc_ast::AstKind;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::{AstNode, AstNodeId, AstNodes};
 use oxc_span::{Atom, Span};
 
 use crate::{context::LintContext, rule::Rule};
@@ -91,13 +88,12 @@ declare_oxc_lint!(
 
 impl Rule for NoUnusedPrivateClassMembers {
     fn run_once(&self, ctx: &LintContext) {
-        ctx.semantic().classes().iter_enumerated().for_each(|(class_id, _)| {
-            for (property_id, property) in
-                ctx.semantic().classes().properties[class_id].iter_enumerated()
-            {
+        let classes = ctx.semantic().classes();
+        classes.iter_enumerated().for_each(|(class_id, _)| {
+            for (property_id, property) in classes.properties[class_id].iter_enumerated() {
                 if property.is_private
-                    && !ctx.semantic().classes().iter_private_identifiers(class_id).any(|ident| {
-                        is_read(ident.id, ctx.semantic().nodes())
+                    && !classes.iter_private_identifiers(class_id).any(|ident| {
+                        ident.flag.is_read()
                             && ident.property_id.is_some_and(|id| id == property_id)
                     })
                 {
@@ -108,12 +104,9 @@ impl Rule for NoUnusedPrivateClassMembers {
                 }
             }
 
-            for (method_id, method) in ctx.semantic().classes().methods[class_id].iter_enumerated()
-            {
+            for (method_id, method) in classes.methods[class_id].iter_enumerated() {
                 if method.is_private
-                    && !ctx
-                        .semantic()
-                        .classes()
+                    && !classes
                         .iter_private_identifiers(class_id)
                         .any(|ident| ident.method_ids.contains(&method_id))
                 {
@@ -127,42 +120,6 @@ impl Rule for NoUnusedPrivateClassMembers {
     }
 }
 
-fn is_read(current_node_id: AstNodeId, nodes: &AstNodes) -> bool {
-    for (curr, parent) in nodes
-        .iter_parents(nodes.parent_id(current_node_id).unwrap_or(current_node_id))
-        .tuple_windows::<(&AstNode<'_>, &AstNode<'_>)>()
-    {
-        match (curr.kind(), parent.kind()) {
-            (
-                AstKind::SimpleAssignmentTarget(_) | AstKind::MemberExpression(_),
-                AstKind::AssignmentTarget(_) | AstKind::SimpleAssignmentTarget(_),
-            ) => {
-                continue;
-            }
-            (
-                AstKind::AssignmentTarget(_),
-                AstKind::ForInStatement(_)
-                | AstKind::ForOfStatement(_)
-                | AstKind::AssignmentTargetWithDefault(_)
-                | AstKind::AssignmentTarget(_),
-            )
-            | (AstKind::SimpleAssignmentTarget(_), AstKind::AssignmentExpression(_)) => {
-                return false
-            }
-            (AstKind::AssignmentTarget(_), AstKind::AssignmentExpression(_))
-            | (_, AstKind::UpdateExpression(_)) => {
-                return !matches!(
-                    nodes.parent_kind(parent.id()),
-                    Some(AstKind::ExpressionStatement(_))
-                );
-            }
-            _ => return true,
-        }
-    }
-
-    true
-}
-
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -309,6 +266,25 @@ fn test() {
                 this.#x = 1;
             }
         }",
+        r"class C {
+            #usedInNestedClass = 1;
+
+            foo() {
+                return class {
+                    bar(c) {
+                        return c.#usedInNestedClass;
+                    }
+                };
+            }
+        }",
+        r"class C {
+            usedWithPublicName = 1;
+            #usedWithPublicName = 2;
+
+            foo() {
+                return this.#usedWithPublicName;
+            }
+        }",
     ];
 
     let fail = vec![
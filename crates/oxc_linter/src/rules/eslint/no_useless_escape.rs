@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoUselessEscape,
-    correctness
+    correctness,
+    fix = suggestion
 );
 
 impl Rule for NoUselessEscape {
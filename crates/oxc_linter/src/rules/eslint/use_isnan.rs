@@ -9,7 +9,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 enum UseIsnanDiagnostic {
@@ -135,6 +140,16 @@ impl Rule for UseIsnan {
 
         Self { enforce_for_switch_case, enforce_for_index_of }
     }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[
+                ("enforceForSwitchCase", OptionType::Boolean),
+                ("enforceForIndexOf", OptionType::Boolean),
+            ],
+        )
+    }
 }
 
 fn is_nan_identifier<'a>(expr: &'a Expression<'a>) -> bool {
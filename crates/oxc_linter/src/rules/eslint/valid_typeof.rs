@@ -8,7 +8,13 @@ use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::UnaryOperator;
 use phf::{phf_set, Set};
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::Fix,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 enum ValidTypeofDiagnostic {
@@ -46,6 +52,7 @@ declare_oxc_lint!(
     /// ```
     ValidTypeof,
     correctness,
+    fix = suggestion
 );
 
 impl Rule for ValidTypeof {
@@ -126,6 +133,10 @@ impl Rule for ValidTypeof {
 
         Self { require_string_literals }
     }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("requireStringLiterals", OptionType::Boolean)])
+    }
 }
 
 const VALID_TYPES: Set<&'static str> = phf_set! {
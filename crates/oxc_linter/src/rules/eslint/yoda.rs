@@ -0,0 +1,290 @@
+use oxc_ast::{
+    ast::{BinaryExpression, Expression, LogicalExpression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+
+use crate::{
+    context::LintContext,
+    fixer::Fix,
+    rule::Rule,
+    utils::{check_option, check_properties, is_same_reference, OptionType},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(yoda): Expected literal to be on the {0} side of {1}.")]
+#[diagnostic(severity(warning))]
+struct YodaDiagnostic(&'static str, &'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct Yoda {
+    /// Literals on the left side of comparisons
+    always: bool,
+    /// Allow range tests such as `(0 <= x && x < 10)` in parentheses
+    except_range: bool,
+    /// Only check `==`, `===`, `!=` and `!==`
+    only_equality: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require or disallow "Yoda" conditions, i.e. comparisons with the literal on the left side.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `if ("red" === color)` reads backwards compared to `if (color === "red")`.
+    ///
+    /// ### Options
+    ///
+    /// * `"never"` (default) or `"always"` for the side of the literal
+    /// * `exceptRange: true` allows range tests in parentheses, e.g. `if (0 <= x && x < 10)`
+    /// * `onlyEquality: true` only checks `==`, `===`, `!=` and `!==`
+    ///
+    /// ### Example
+    /// ```javascript
+    /// if ("red" === color) {}
+    /// if (5 < value) {}
+    /// ```
+    Yoda,
+    style,
+    fix = safe
+);
+
+impl Rule for Yoda {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let always = value.get(0).and_then(serde_json::Value::as_str) == Some("always");
+        let option = |name: &str| {
+            value
+                .get(1)
+                .and_then(|options| options.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default()
+        };
+        Self { always, except_range: option("exceptRange"), only_equality: option("onlyEquality") }
+    }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_option(value.get(0), OptionType::OneOf(&["always", "never"]))?;
+        check_properties(
+            value.get(1),
+            &[("exceptRange", OptionType::Boolean), ("onlyEquality", OptionType::Boolean)],
+        )
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::BinaryExpression(binary) = node.kind() else { return };
+        let operator = binary.operator;
+        if !operator.is_compare() && !operator.is_equality() {
+            return;
+        }
+        if self.only_equality && !operator.is_equality() {
+            return;
+        }
+        let (expected_literal, expected_non_literal) =
+            if self.always { (&binary.left, &binary.right) } else { (&binary.right, &binary.left) };
+        if !looks_like_literal(expected_non_literal) || looks_like_literal(expected_literal) {
+            return;
+        }
+        if self.except_range && is_range_test(node, ctx) {
+            return;
+        }
+
+        let side = if self.always { "left" } else { "right" };
+        ctx.diagnostic_with_fix(YodaDiagnostic(side, operator.as_str(), binary.span), || {
+            Fix::new(flip_comparison(binary, ctx), binary.span)
+        });
+    }
+}
+
+/// Literals, negative numbers and template literals without expressions.
+fn looks_like_literal(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumberLiteral(_)
+        | Expression::BigintLiteral(_)
+        | Expression::RegExpLiteral(_)
+        | Expression::StringLiteral(_) => true,
+        Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+        Expression::UnaryExpression(unary) => {
+            unary.operator == UnaryOperator::UnaryNegation
+                && matches!(unary.argument, Expression::NumberLiteral(_))
+        }
+        _ => false,
+    }
+}
+
+/// `b < a` for `a > b`, keeping the whitespace, parentheses and comments around the operator.
+fn flip_comparison(binary: &BinaryExpression, ctx: &LintContext) -> String {
+    let source_text = ctx.source_text();
+    let text = |span: Span| &source_text[span.start as usize..span.end as usize];
+    let (left, right) = (binary.left.span(), binary.right.span());
+    let operator = operator_offset(text(Span::new(left.end, right.start))) + left.end as usize;
+    let operator_end = operator + binary.operator.as_str().len();
+    let flipped = match binary.operator {
+        BinaryOperator::LessThan => ">",
+        BinaryOperator::LessEqualThan => ">=",
+        BinaryOperator::GreaterThan => "<",
+        BinaryOperator::GreaterEqualThan => "<=",
+        operator => operator.as_str(),
+    };
+    format!(
+        "{}{}{flipped}{}{}",
+        text(right),
+        &source_text[left.end as usize..operator],
+        &source_text[operator_end..right.start as usize],
+        text(left)
+    )
+}
+
+/// Offset of the operator between the operands, after the closing parentheses and the comments
+/// of the left one
+fn operator_offset(between: &str) -> usize {
+    let mut offset = 0;
+    loop {
+        let rest = &between[offset..];
+        if rest.starts_with("//") {
+            offset += rest.find('\n').unwrap_or(rest.len());
+        } else if rest.starts_with("/*") {
+            offset += rest.find("*/").map_or(rest.len(), |end| end + 2);
+        } else if let Some(c) = rest.chars().next().filter(|c| c.is_whitespace() || *c == ')') {
+            offset += c.len_utf8();
+        } else {
+            return offset;
+        }
+    }
+}
+
+/// `(0 <= x && x < 10)` or `(x < 0 || 10 <= x)`
+fn is_range_test(node: &AstNode, ctx: &LintContext) -> bool {
+    let Some(parent) = ctx.nodes().parent_node(node.id()) else { return false };
+    let AstKind::LogicalExpression(logical) = parent.kind() else { return false };
+    // Also the parentheses of `if (...)` and calls
+    let source_text = ctx.source_text();
+    let is_parenthesized = source_text[..logical.span.start as usize].trim_end().ends_with('(')
+        && source_text[logical.span.end as usize..].trim_start().starts_with(')');
+    is_parenthesized && (is_between_test(logical, ctx) || is_outside_test(logical, ctx))
+}
+
+fn range_operands<'a, 'b>(
+    logical: &'b LogicalExpression<'a>,
+) -> Option<(&'b BinaryExpression<'a>, &'b BinaryExpression<'a>)> {
+    let is_range_operator = |operator: BinaryOperator| {
+        matches!(operator, BinaryOperator::LessThan | BinaryOperator::LessEqualThan)
+    };
+    match (&logical.left, &logical.right) {
+        (Expression::BinaryExpression(left), Expression::BinaryExpression(right))
+            if is_range_operator(left.operator) && is_range_operator(right.operator) =>
+        {
+            Some((left, right))
+        }
+        _ => None,
+    }
+}
+
+fn is_between_test(logical: &LogicalExpression, ctx: &LintContext) -> bool {
+    let Some((left, right)) = range_operands(logical) else { return false };
+    logical.operator == LogicalOperator::And
+        && is_same_reference(&left.right, &right.left, ctx)
+        && is_ordered(&left.left, &right.right)
+}
+
+fn is_outside_test(logical: &LogicalExpression, ctx: &LintContext) -> bool {
+    let Some((left, right)) = range_operands(logical) else { return false };
+    logical.operator == LogicalOperator::Or
+        && is_same_reference(&left.left, &right.right, ctx)
+        && is_ordered(&left.right, &right.left)
+}
+
+/// Bounds in increasing order, an unknown bound is accepted unless both are unknown.
+fn is_ordered(lower: &Expression, upper: &Expression) -> bool {
+    match (bound(lower), bound(upper)) {
+        (None, None) => false,
+        (Some(Bound::Number(lower)), Some(Bound::Number(upper))) => lower <= upper,
+        (Some(Bound::String(lower)), Some(Bound::String(upper))) => lower <= upper,
+        (Some(_), Some(_)) => false,
+        _ => true,
+    }
+}
+
+enum Bound<'a> {
+    Number(f64),
+    String(&'a str),
+}
+
+fn bound<'a>(expr: &'a Expression) -> Option<Bound<'a>> {
+    match expr.without_parenthesized() {
+        Expression::NumberLiteral(number) => Some(Bound::Number(number.value)),
+        Expression::StringLiteral(string) => Some(Bound::String(string.value.as_str())),
+        Expression::TemplateLiteral(template) if template.expressions.is_empty() => {
+            template.quasis.first().map(|quasi| Bound::String(quasi.value.raw.as_str()))
+        }
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::UnaryNegation => {
+            match bound(&unary.argument) {
+                Some(Bound::Number(value)) => Some(Bound::Number(-value)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("if (value === \"red\") {}", None),
+        ("if (value === value) {}", None),
+        ("if (value != 5) {}", None),
+        ("if (5 & foo) {}", None),
+        ("if (5 === 4) {}", None),
+        ("if (value < -1) {}", None),
+        ("if (value <= `red`) {}", None),
+        ("if (\"red\" == value) {}", Some(json!(["always"]))),
+        ("if (`red` === value) {}", Some(json!(["always"]))),
+        ("if (-1 < str.indexOf(substr)) {}", Some(json!(["always"]))),
+        ("if (0 < x && x <= 1) {}", Some(json!(["never", { "exceptRange": true }]))),
+        ("if (x < 0 || 1 <= x) {}", Some(json!(["never", { "exceptRange": true }]))),
+        ("if ('a' <= x && x < 'b') {}", Some(json!(["never", { "exceptRange": true }]))),
+        ("if (0 <= a.b && a.b < 1) {}", Some(json!(["never", { "exceptRange": true }]))),
+        ("if (x < -5) {}", Some(json!(["never", { "onlyEquality": true }]))),
+        ("if (5 < x) {}", Some(json!(["never", { "onlyEquality": true }]))),
+    ];
+
+    let fail = vec![
+        ("if (\"red\" == value) {}", None),
+        ("if (true === value) {}", None),
+        ("if (5 != value) {}", None),
+        ("if (null !== value) {}", None),
+        ("if (-1 < str.indexOf(substr)) {}", None),
+        ("if (`red` <= value) {}", None),
+        ("if (value == \"red\") {}", Some(json!(["always"]))),
+        ("if (0 < x && x <= 1) {}", None),
+        ("if (1 < x && x <= 0) {}", Some(json!(["never", { "exceptRange": true }]))),
+        ("if (0 <= x && x < 1) {}", Some(json!(["never", { "exceptRange": false }]))),
+        ("if (5 === x) {}", Some(json!(["never", { "onlyEquality": true }]))),
+    ];
+
+    let fix = vec![
+        ("if (\"red\" == value) {}", "if (value == \"red\") {}", None),
+        ("if (5 < value) {}", "if (value > 5) {}", None),
+        ("if (-1>=value) {}", "if (value<=-1) {}", None),
+        ("if (0 /* < */ < value) {}", "if (value /* < */ > 0) {}", None),
+        ("if (0 // <\n< value) {}", "if (value // <\n> 0) {}", None),
+        ("if (value <= 5) {}", "if (5 >= value) {}", Some(json!(["always"]))),
+        ("if (5 /* five */ === value) {}", "if (value /* five */ === 5) {}", None),
+    ];
+
+    Tester::new(Yoda::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,376 @@
+use std::path::Path;
+
+use oxc_ast::{
+    ast::{ModuleDeclaration, StringLiteral},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_option, check_properties, require_specifier, OptionType},
+    AstNode, Fix,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ExtensionsDiagnostic {
+    #[error("eslint-plugin-import(extensions): Missing file extension {0:?} for {1:?}")]
+    #[diagnostic(severity(warning))]
+    Missing(String, String, #[label] Span),
+    #[error("eslint-plugin-import(extensions): Unexpected use of file extension {0:?} for {1:?}")]
+    #[diagnostic(severity(warning))]
+    Unexpected(String, String, #[label] Span),
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/extensions.md>
+#[derive(Debug, Default, Clone)]
+pub struct Extensions(Box<ExtensionsConfig>);
+
+#[derive(Debug, Clone)]
+pub struct ExtensionsConfig {
+    /// For the extensions missing from `extensions`
+    default: Modifier,
+    /// Per extension of the resolved file, without the dot
+    extensions: FxHashMap<String, Modifier>,
+    /// Extensions are not required in the specifiers of packages
+    ignore_packages: bool,
+}
+
+impl Default for ExtensionsConfig {
+    fn default() -> Self {
+        Self { default: Modifier::Never, extensions: FxHashMap::default(), ignore_packages: false }
+    }
+}
+
+impl std::ops::Deref for Extensions {
+    type Target = ExtensionsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Modifier {
+    Always,
+    Never,
+    Ignore,
+}
+
+impl Modifier {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value.as_str()? {
+            "always" => Some(Self::Always),
+            "never" => Some(Self::Never),
+            "ignore" => Some(Self::Ignore),
+            _ => None,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures consistent use of file extensions in the specifiers of imports, exports and
+    /// `require` calls, e.g. the `.js` extensions which Node.js requires for ES modules.
+    ///
+    /// The extension is the one of the file found by the resolver of the import plugin.
+    /// Specifiers which do not resolve, built-in modules of Node.js, type-only imports and the
+    /// root of packages, e.g. `lodash`, are not checked. A `.js` specifier of a TypeScript file,
+    /// which TypeScript resolves to the `.ts` file, counts as the extension of the `.ts` file.
+    ///
+    /// ### Options
+    ///
+    /// * `"never"` (default), `"always"` or `"ignorePackages"`, which is `"always"` except for
+    ///   the specifiers of packages
+    /// * an object of modifiers per extension, e.g. `{ "js": "always", "json": "never" }`,
+    ///   alone or after the string. `"ignorePackages": true` can be given in the object too.
+    ///
+    /// The fix appends the extension of the resolved file, or `/index` and the extension for a
+    /// directory, and strips a forbidden extension. In TypeScript files, the appended extension
+    /// is the JavaScript one, `.js` for a `.ts` file. Only relative specifiers are fixed.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // with "always"
+    /// import foo from './foo'
+    /// // with "never"
+    /// import foo from './foo.js'
+    /// ```
+    Extensions,
+    nursery,
+    fix = unsafe
+);
+
+impl Rule for Extensions {
+    fn from_configuration(value: Value) -> Self {
+        let mut config = ExtensionsConfig::default();
+        let Some(options) = value.as_array() else { return Self(Box::new(config)) };
+        for option in options {
+            match option {
+                Value::String(modifier) if modifier == "ignorePackages" => {
+                    config.default = Modifier::Always;
+                    config.ignore_packages = true;
+                }
+                Value::String(_) => {
+                    config.default = Modifier::from_value(option).unwrap_or(Modifier::Never);
+                }
+                Value::Object(map) => {
+                    // `{ "pattern": { ... }, "ignorePackages": true }` or the pattern alone
+                    let pattern = map.get("pattern").and_then(Value::as_object).unwrap_or(map);
+                    for (extension, modifier) in pattern {
+                        if let Some(modifier) = Modifier::from_value(modifier) {
+                            config.extensions.insert(extension.clone(), modifier);
+                        }
+                    }
+                    if let Some(ignore_packages) =
+                        map.get("ignorePackages").and_then(Value::as_bool)
+                    {
+                        config.ignore_packages = ignore_packages;
+                    }
+                }
+                _ => {}
+            }
+        }
+        Self(Box::new(config))
+    }
+
+    fn validate_configuration(value: &Value) -> Result<(), String> {
+        for option in value.as_array().into_iter().flatten() {
+            match option {
+                Value::String(_) => check_option(
+                    Some(option),
+                    OptionType::OneOf(&["always", "never", "ignore", "ignorePackages"]),
+                )?,
+                Value::Object(map) => {
+                    check_properties(Some(option), &[("ignorePackages", OptionType::Boolean)])?;
+                    let pattern = map.get("pattern").and_then(Value::as_object).unwrap_or(map);
+                    for (extension, modifier) in pattern {
+                        if extension != "ignorePackages" && Modifier::from_value(modifier).is_none()
+                        {
+                            return Err(format!(
+                                r#"expected "{extension}" to be "always", "never" or "ignore""#
+                            ));
+                        }
+                    }
+                }
+                _ => return Err(format!("expected a string or an object, but got {option}")),
+            }
+        }
+        Ok(())
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let source = match node.kind() {
+            AstKind::ModuleDeclaration(declaration) => match declaration {
+                ModuleDeclaration::ImportDeclaration(import) if !import.import_kind.is_type() => {
+                    &import.source
+                }
+                ModuleDeclaration::ExportNamedDeclaration(export)
+                    if !export.export_kind.is_type() =>
+                {
+                    let Some(source) = &export.source else { return };
+                    source
+                }
+                ModuleDeclaration::ExportAllDeclaration(export)
+                    if !export.export_kind.is_type() =>
+                {
+                    &export.source
+                }
+                _ => return,
+            },
+            AstKind::CallExpression(call) => {
+                let Some(source) = require_specifier(call) else { return };
+                source
+            }
+            _ => return,
+        };
+        self.check(source, ctx);
+    }
+}
+
+impl Extensions {
+    fn modifier(&self, extension: &str) -> Modifier {
+        self.extensions.get(extension).copied().unwrap_or(self.default)
+    }
+
+    fn check(&self, source: &StringLiteral, ctx: &LintContext<'_>) {
+        let specifier = source.value.as_str();
+        // Query strings and loaders of bundlers, e.g. `./icon.svg?raw`
+        if specifier.contains(['?', '!']) || is_package_root(specifier) {
+            return;
+        }
+        let module_record = ctx.semantic().module_record();
+        // Built-in modules of Node.js are not recorded
+        let Some(resolved) = module_record.resolved_modules.get(specifier) else { return };
+        let Some(path) = resolved.value().clone() else { return };
+        let Some(extension) = path.extension().and_then(|ext| ext.to_str()) else { return };
+        let is_typescript = ctx.source_type().is_typescript();
+        let written = written_extension(specifier, extension);
+        let is_relative = specifier.starts_with('.') || specifier.starts_with('/');
+        // Inside of the quotes
+        let span = Span::new(source.span.start + 1, source.span.end - 1);
+
+        match (written, self.modifier(extension)) {
+            (None, Modifier::Always) if is_relative || !self.ignore_packages => {
+                let diagnostic = ExtensionsDiagnostic::Missing(
+                    extension.to_string(),
+                    specifier.to_string(),
+                    source.span,
+                );
+                let appended = if is_typescript { output_extension(extension) } else { extension };
+                match with_extension(specifier, &path, extension, appended) {
+                    Some(fixed) if is_relative => {
+                        ctx.diagnostic_with_fix(diagnostic, || Fix::new(fixed, span));
+                    }
+                    _ => ctx.diagnostic(diagnostic),
+                }
+            }
+            (Some(written), Modifier::Never) => {
+                let diagnostic = ExtensionsDiagnostic::Unexpected(
+                    extension.to_string(),
+                    specifier.to_string(),
+                    source.span,
+                );
+                if is_relative {
+                    let fixed = specifier[..specifier.len() - written.len() - 1].to_string();
+                    ctx.diagnostic_with_fix(diagnostic, || Fix::new(fixed, span));
+                } else {
+                    ctx.diagnostic(diagnostic);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `lodash` or `@scope/pkg`, the entry of a package which has no extension to write.
+fn is_package_root(specifier: &str) -> bool {
+    if specifier.starts_with('.') || specifier.starts_with('/') {
+        return false;
+    }
+    let segments = specifier.split('/').count();
+    segments == 1 || (specifier.starts_with('@') && segments == 2)
+}
+
+/// The extension written at the end of `specifier` for a file with `extension`, counting
+/// `./foo.js` as the extension of `foo.ts` like TypeScript does.
+fn written_extension<'s>(specifier: &'s str, extension: &str) -> Option<&'s str> {
+    let (_, written) = specifier.rsplit_once('.')?;
+    (written == extension || written == output_extension(extension)).then_some(written)
+}
+
+/// The extension of the JavaScript emitted by TypeScript for a file with `extension`
+fn output_extension(extension: &str) -> &str {
+    match extension {
+        "ts" | "tsx" => "js",
+        "mts" => "mjs",
+        "cts" => "cjs",
+        _ => extension,
+    }
+}
+
+/// `specifier` naming the resolved file at `path`, either the file itself or the index of a
+/// directory.
+fn with_extension(specifier: &str, path: &Path, extension: &str, appended: &str) -> Option<String> {
+    let last = specifier.rsplit('/').next().unwrap_or(specifier);
+    let file_name = path.file_name()?.to_str()?;
+    if !last.is_empty() && !matches!(last, "." | "..") && file_name == format!("{last}.{extension}")
+    {
+        return Some(format!("{specifier}.{appended}"));
+    }
+    if file_name == format!("index.{extension}") {
+        return Some(format!("{}/index.{appended}", specifier.trim_end_matches('/')));
+    }
+    None
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import foo from './bar'", None),
+        ("import fs from 'fs'", None),
+        ("import jquery from 'jquery'", None),
+        ("import foo from './bar.js'", Some(json!(["always"]))),
+        ("import bar from './bar/index.js'", Some(json!(["always"]))),
+        ("import chai from 'chai'", Some(json!(["always"]))),
+        ("import { Org } from '@org/package'", Some(json!(["always"]))),
+        ("import foo from './does-not-exist'", Some(json!(["always"]))),
+        ("import type { Foo } from './bar'", Some(json!(["always"]))),
+        ("import operators from 'rxjs/operators'", Some(json!(["ignorePackages"]))),
+        ("import foo from './bar.js'", Some(json!(["never", { "js": "always" }]))),
+        ("import foo from './bar'", Some(json!([{ "js": "never" }]))),
+        ("import foo from './bar.js'", Some(json!([{ "js": "ignore" }]))),
+        ("const foo = require('./bar.js')", Some(json!(["always"]))),
+        ("const foo = require(bar)", Some(json!(["always"]))),
+    ];
+
+    let fail = vec![
+        ("import foo from './bar.js'", None),
+        ("import foo from './bar'", Some(json!(["always"]))),
+        ("import constants from './constants'", Some(json!(["always"]))),
+        ("import operators from 'rxjs/operators'", Some(json!(["always"]))),
+        ("import foo from './bar'", Some(json!(["ignorePackages"]))),
+        ("export { foo } from './bar'", Some(json!(["always"]))),
+        ("export * from './bar.js'", Some(json!(["never"]))),
+        ("const foo = require('./bar')", Some(json!(["always"]))),
+        ("import foo from './bar.js'", Some(json!([{ "js": "never" }]))),
+        ("import foo from './bar'", Some(json!([{ "pattern": { "js": "always" } }]))),
+    ];
+
+    let fix = vec![
+        ("import foo from './bar'", "import foo from './bar.js'", Some(json!(["always"]))),
+        (
+            "import constants from './constants'",
+            "import constants from './constants/index.js'",
+            Some(json!(["always"])),
+        ),
+        ("import foo from './bar.js'", "import foo from './bar'", None),
+        ("import bar from './bar/index.js'", "import bar from './bar/index'", None),
+    ];
+
+    Tester::new(Extensions::NAME, pass, fail)
+        .with_import_plugin(true)
+        .change_rule_path("index.js")
+        .expect_fix(fix)
+        .test_and_snapshot();
+
+    // `.js` specifiers of TypeScript files
+    let pass = vec![
+        ("import foo from './typescript.js'", Some(json!(["always"]))),
+        ("import foo from './typescript'", None),
+    ];
+
+    let fail = vec![
+        ("import foo from './typescript'", Some(json!(["always"]))),
+        ("import foo from './typescript.js'", None),
+    ];
+
+    let fix = vec![
+        (
+            "import foo from './typescript'",
+            "import foo from './typescript.js'",
+            Some(json!(["always"])),
+        ),
+        ("import foo from './typescript.js'", "import foo from './typescript'", None),
+    ];
+
+    Tester::new(Extensions::NAME, pass, fail)
+        .with_import_plugin(true)
+        .change_rule_path("index.ts")
+        .expect_fix(fix)
+        .test();
+}
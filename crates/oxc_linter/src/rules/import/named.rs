@@ -6,7 +6,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
 use oxc_syntax::module_record::{ExportImportName, ImportImportName};
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::has_export};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-import(named): named import {0:?} not found")]
@@ -50,9 +50,8 @@ impl Rule for Named {
             let Some(remote_module_record_ref) = module_record.loaded_modules.get(specifier) else {
                 continue;
             };
-            let remote_module_record = remote_module_record_ref.value();
-            // Check remote bindings
-            if remote_module_record.exported_bindings.contains_key(import_name.name()) {
+            // Check remote bindings, and the bindings of the modules re-exported with `export *`
+            if has_export(remote_module_record_ref.value(), import_name.name()) != Some(false) {
                 continue;
             }
             ctx.diagnostic(NamedDiagnostic(
@@ -74,9 +73,8 @@ impl Rule for Named {
             let Some(remote_module_record_ref) = module_record.loaded_modules.get(specifier) else {
                 continue;
             };
-            let remote_module_record = remote_module_record_ref.value();
-            // Check remote bindings
-            if remote_module_record.exported_bindings.contains_key(import_name.name()) {
+            // Check remote bindings, and the bindings of the modules re-exported with `export *`
+            if has_export(remote_module_record_ref.value(), import_name.name()) != Some(false) {
                 continue;
             }
             ctx.diagnostic(NamedDiagnostic(
@@ -103,11 +101,11 @@ fn test() {
         "import { destructingAssign } from './named-exports'",
         "import { destructingRenamedAssign } from './named-exports'",
         "import { ActionTypes } from './qc'",
-        // TODO: export *
-        // "import {a, b, c, d} from './re-export'",
+        "import {a, b, c, d} from './re-export'",
+        // TODO: module.exports
         // "import {a, b, c} from './re-export-common-star'",
-        // "import {RuleTester} from './re-export-node_modules'",
-        // "import { jsxFoo } from './jsx/AnotherComponent'",
+        "import {RuleTester} from './re-export-node_modules'",
+        "import { jsxFoo } from './jsx/AnotherComponent'",
         "import {a, b, d} from './common'; // eslint-disable-line named",
         "import { foo, bar } from './re-export-names'",
         // TODO: module.exports
@@ -161,7 +159,7 @@ fn test() {
         "import { 'foo' as foo } from './bar'",
         "import { 'foo' as foo } from './empty-module'",
         // export all
-        // "import { foo } from './export-all'",
+        "import { foo } from './export-all'",
         // TypeScript export assignment
         "import x from './typescript-export-assign-object'",
     ];
@@ -0,0 +1,403 @@
+use std::{
+    env,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use oxc_ast::{
+    ast::{Expression, ImportOrExportKind, ModuleDeclaration, StringLiteral},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    package_json::{nearest_package_json, PackageJson},
+    rule::Rule,
+    utils::{check_properties, OptionType, NODE_BUILTINS_MODULE},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoExtraneousDependenciesDiagnostic {
+    #[error("eslint-plugin-import(no-extraneous-dependencies): '{1}' should be listed in the project's dependencies. Run 'npm i -S {1}' to add it")]
+    #[diagnostic(severity(warning))]
+    Missing(#[label] Span, String),
+    #[error("eslint-plugin-import(no-extraneous-dependencies): '{1}' should be listed in the project's dependencies, not {2}.")]
+    #[diagnostic(severity(warning))]
+    WrongKind(#[label] Span, String, &'static str),
+}
+
+/// Whether imports of a kind of dependencies are allowed in a file
+#[derive(Debug, Clone)]
+enum Allowed {
+    Always(bool),
+    /// In the files matching these globs, relative to the working directory
+    Globs(Gitignore),
+}
+
+impl Allowed {
+    fn from_value(value: Option<&Value>) -> Self {
+        match value {
+            Some(Value::Bool(allowed)) => Self::Always(*allowed),
+            Some(Value::String(glob)) => Self::globs([glob.as_str()]),
+            Some(Value::Array(globs)) => Self::globs(globs.iter().filter_map(Value::as_str)),
+            _ => Self::Always(true),
+        }
+    }
+
+    fn globs<'a>(globs: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut builder = GitignoreBuilder::new(env::current_dir().unwrap_or_default());
+        for glob in globs {
+            let _ = builder.add_line(None, glob);
+        }
+        builder.build().map_or(Self::Always(false), Self::Globs)
+    }
+
+    fn allows(&self, path: &Path) -> bool {
+        match self {
+            Self::Always(allowed) => *allowed,
+            Self::Globs(globs) => {
+                let path = if path.is_absolute() {
+                    path.to_path_buf()
+                } else {
+                    env::current_dir().unwrap_or_default().join(path)
+                };
+                globs.matched_path_or_any_parents(&path, false).is_ignore()
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtraneousDependencies(Box<NoExtraneousDependenciesConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoExtraneousDependenciesConfig {
+    dev_dependencies: Allowed,
+    optional_dependencies: Allowed,
+    peer_dependencies: Allowed,
+    bundled_dependencies: bool,
+    /// Check `import type` and `export type` too
+    include_types: bool,
+    /// Directories of the `package.json` files to read instead of the nearest one
+    package_dirs: Vec<PathBuf>,
+    /// Dependencies of the root of the workspace are declared for its packages too
+    workspace_root: bool,
+}
+
+impl Default for NoExtraneousDependenciesConfig {
+    fn default() -> Self {
+        Self {
+            dev_dependencies: Allowed::Always(true),
+            optional_dependencies: Allowed::Always(true),
+            peer_dependencies: Allowed::Always(true),
+            bundled_dependencies: true,
+            include_types: false,
+            package_dirs: vec![],
+            workspace_root: false,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbid the import of external modules that are not declared in the `dependencies`,
+    /// `devDependencies`, `optionalDependencies`, `peerDependencies` or `bundledDependencies` of
+    /// the nearest `package.json`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Undeclared dependencies work as long as another package happens to install them, and
+    /// break for the users of the package. Imports of `devDependencies` in published code break
+    /// once installed without them.
+    ///
+    /// ### Options
+    ///
+    /// * `devDependencies`, `optionalDependencies` and `peerDependencies`: `false` to report
+    ///   imports of these dependencies, or globs of the files which may import them,
+    ///   e.g. `["**/*.test.js", "scripts/**"]`
+    /// * `bundledDependencies`: `false` to report imports of these dependencies
+    /// * `includeTypes`: check type-only imports too
+    /// * `packageDir`: directory or directories of the `package.json` files to read instead
+    /// * `workspaceRoot`: also accept the dependencies of the root `package.json` of the npm,
+    ///   yarn or pnpm workspace, e.g. tools hoisted to the root of a monorepo
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // package.json: { "dependencies": { "react": "*" }, "devDependencies": { "jest": "*" } }
+    /// import _ from "lodash"; // not declared
+    /// import { test } from "jest"; // with `devDependencies: false`
+    /// ```
+    NoExtraneousDependencies,
+    nursery
+);
+
+impl Rule for NoExtraneousDependencies {
+    fn from_configuration(value: Value) -> Self {
+        let Some(options) = value.get(0) else { return Self::default() };
+        let package_dirs = match options.get("packageDir") {
+            Some(Value::String(dir)) => vec![PathBuf::from(dir)],
+            Some(Value::Array(dirs)) => {
+                dirs.iter().filter_map(Value::as_str).map(PathBuf::from).collect()
+            }
+            _ => vec![],
+        };
+        let flag =
+            |key: &str, default: bool| options.get(key).and_then(Value::as_bool).unwrap_or(default);
+        Self(Box::new(NoExtraneousDependenciesConfig {
+            dev_dependencies: Allowed::from_value(options.get("devDependencies")),
+            optional_dependencies: Allowed::from_value(options.get("optionalDependencies")),
+            peer_dependencies: Allowed::from_value(options.get("peerDependencies")),
+            bundled_dependencies: flag("bundledDependencies", true),
+            include_types: flag("includeTypes", false),
+            package_dirs,
+            workspace_root: flag("workspaceRoot", false),
+        }))
+    }
+
+    fn validate_configuration(value: &Value) -> Result<(), String> {
+        let options = value.get(0);
+        check_properties(
+            options,
+            &[
+                ("packageDir", OptionType::StringOrStrings),
+                ("bundledDependencies", OptionType::Boolean),
+                ("includeTypes", OptionType::Boolean),
+                ("workspaceRoot", OptionType::Boolean),
+            ],
+        )?;
+        for key in ["devDependencies", "optionalDependencies", "peerDependencies"] {
+            match options.and_then(|options| options.get(key)) {
+                None | Some(Value::Bool(_)) => {}
+                Some(globs) if OptionType::StringOrStrings.accepts(globs) => {}
+                Some(_) => {
+                    return Err(format!(r#"expected "{key}" to be a boolean or globs"#));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (source, kind) = match node.kind() {
+            AstKind::ModuleDeclaration(declaration) => match declaration {
+                ModuleDeclaration::ImportDeclaration(import) => {
+                    (&import.source, import.import_kind)
+                }
+                ModuleDeclaration::ExportNamedDeclaration(export) => {
+                    let Some(source) = &export.source else { return };
+                    (source, export.export_kind)
+                }
+                ModuleDeclaration::ExportAllDeclaration(export) => {
+                    (&export.source, export.export_kind)
+                }
+                _ => return,
+            },
+            AstKind::CallExpression(call) => {
+                let Some(source) = call.common_js_require() else { return };
+                (source, ImportOrExportKind::Value)
+            }
+            AstKind::ImportExpression(import) => {
+                let Expression::StringLiteral(source) = &import.source else { return };
+                (&**source, ImportOrExportKind::Value)
+            }
+            _ => return,
+        };
+        if kind.is_type() && !self.0.include_types {
+            return;
+        }
+        self.check(source, kind, ctx);
+    }
+}
+
+impl NoExtraneousDependencies {
+    fn check(&self, source: &StringLiteral, kind: ImportOrExportKind, ctx: &LintContext) {
+        let Some(name) = package_name(&source.value) else { return };
+        // Specifiers resolved to files of the project are aliases, e.g. `paths` of tsconfig
+        let module_record = ctx.semantic().module_record();
+        if let Some(loaded) = module_record.loaded_modules.get(&source.value) {
+            let path = &loaded.value().resolved_absolute_path;
+            if !path.components().any(|c| c == Component::Normal("node_modules".as_ref())) {
+                return;
+            }
+        }
+
+        let package_jsons = self.package_jsons(ctx.file_path());
+        if package_jsons.is_empty()
+            || package_jsons.iter().any(|package_json| package_json.name.as_deref() == Some(name))
+        {
+            return;
+        }
+        let types_name = types_package_name(name);
+        let names = if kind.is_type() { vec![name, types_name.as_str()] } else { vec![name] };
+        let declared = |dependencies: fn(&PackageJson) -> &FxHashSet<String>| {
+            package_jsons.iter().any(|package_json| {
+                names.iter().any(|name| dependencies(package_json).contains(*name))
+            })
+        };
+
+        if declared(|package_json| &package_json.dependencies)
+            || (self.0.bundled_dependencies
+                && declared(|package_json| &package_json.bundled_dependencies))
+        {
+            return;
+        }
+        let path = ctx.file_path();
+        let kinds: [(fn(&PackageJson) -> &FxHashSet<String>, &Allowed, &'static str); 3] = [
+            (
+                |package_json| &package_json.dev_dependencies,
+                &self.0.dev_dependencies,
+                "devDependencies",
+            ),
+            (
+                |package_json| &package_json.optional_dependencies,
+                &self.0.optional_dependencies,
+                "optionalDependencies",
+            ),
+            (
+                |package_json| &package_json.peer_dependencies,
+                &self.0.peer_dependencies,
+                "peerDependencies",
+            ),
+        ];
+        let mut wrong_kind = None;
+        for (dependencies, allowed, key) in kinds {
+            if declared(dependencies) {
+                if allowed.allows(path) {
+                    return;
+                }
+                wrong_kind = wrong_kind.or(Some(key));
+            }
+        }
+        let span = source.span;
+        ctx.diagnostic(match wrong_kind {
+            Some(key) => NoExtraneousDependenciesDiagnostic::WrongKind(span, name.to_string(), key),
+            None => NoExtraneousDependenciesDiagnostic::Missing(span, name.to_string()),
+        });
+    }
+
+    /// The `package.json` files declaring the dependencies of the file at `path`.
+    fn package_jsons(&self, path: &Path) -> Vec<Arc<PackageJson>> {
+        if !self.0.package_dirs.is_empty() {
+            let cwd = env::current_dir().unwrap_or_default();
+            return self
+                .0
+                .package_dirs
+                .iter()
+                .filter_map(|dir| PackageJson::load(&cwd.join(dir).join("package.json")))
+                .collect();
+        }
+        let Some(package_json) =
+            path.parent().and_then(nearest_package_json).and_then(|path| PackageJson::load(&path))
+        else {
+            return vec![];
+        };
+        let workspace_root = self.0.workspace_root.then(|| package_json.workspace_root()).flatten();
+        std::iter::once(package_json).chain(workspace_root).collect()
+    }
+}
+
+/// Name of the package of an external module, e.g. `lodash` for `lodash/map` and `@scope/name`
+/// for `@scope/name/sub`. `None` for relative and absolute paths, builtins of node, protocols
+/// such as `node:` and subpath imports such as `#internal`.
+fn package_name(specifier: &str) -> Option<&str> {
+    if specifier.starts_with(['.', '/', '#']) || specifier.contains(':') {
+        return None;
+    }
+    let mut segments = specifier.splitn(3, '/');
+    let first = segments.next()?;
+    let name = if first.starts_with('@') {
+        let second = segments.next().filter(|second| !second.is_empty())?;
+        // `@/components` is an alias of a directory of the project
+        if first.len() == 1 {
+            return None;
+        }
+        &specifier[..first.len() + 1 + second.len()]
+    } else {
+        first
+    };
+    (!NODE_BUILTINS_MODULE.contains(name)).then_some(name)
+}
+
+/// `@types/node` for `node` and `@types/scope__name` for `@scope/name`
+fn types_package_name(name: &str) -> String {
+    match name.strip_prefix('@') {
+        Some(scoped) => format!("@types/{}", scoped.replacen('/', "__", 1)),
+        None => format!("@types/{name}"),
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import 'lodash.cond'", None),
+        (
+            "import 'package-named'",
+            Some(json!([{ "packageDir": "fixtures/import/package-named" }])),
+        ),
+        ("import fs from 'fs'", None),
+        ("import fs from 'node:fs'", None),
+        ("import { readFile } from 'fs/promises'", None),
+        ("import foo from './foo'", None),
+        ("import foo from '../foo'", None),
+        ("import foo from '#internal/foo'", None),
+        ("import foo from '@/components/foo'", None),
+        ("import { cond } from 'lodash.cond'", None),
+        ("import '@org/package'", None),
+        ("import '@org/package/sub'", None),
+        ("import 'eslint'", None),
+        ("import 'glob/sync'", None),
+        ("import 'lodash.isarray'", None),
+        ("import '@generated/foo'", None),
+        ("import type { Foo } from 'not-declared'", None),
+        ("export type { Foo } from 'not-declared'", None),
+        ("var jquery = require('jquery')", None),
+        ("var foo = require(name)", None),
+        ("import('jquery')", None),
+        ("export { foo } from 'jquery'", None),
+        ("export * from 'jquery'", None),
+        ("import 'glob'", Some(json!([{ "devDependencies": ["**/*.tsx"] }]))),
+    ];
+
+    let fail = vec![
+        ("import 'not-a-dependency'", None),
+        ("import '@scope/not-a-dependency/sub'", None),
+        ("var foo = require('not-a-dependency')", None),
+        ("import('not-a-dependency')", None),
+        ("export * from 'not-a-dependency'", None),
+        ("import type { Foo } from 'not-a-dependency'", Some(json!([{ "includeTypes": true }]))),
+        ("import 'glob'", Some(json!([{ "devDependencies": false }]))),
+        ("import 'glob'", Some(json!([{ "devDependencies": ["**/*.test.js"] }]))),
+        ("import 'lodash.isarray'", Some(json!([{ "optionalDependencies": false }]))),
+        ("import '@generated/foo'", Some(json!([{ "bundledDependencies": false }]))),
+        ("import 'jquery'", Some(json!([{ "packageDir": "fixtures/import/package-named" }]))),
+    ];
+
+    Tester::new(NoExtraneousDependencies::NAME, pass, fail)
+        .with_import_plugin(true)
+        .test_and_snapshot();
+
+    let pass = vec![
+        ("import 'react'", None),
+        ("import 'left-pad'", Some(json!([{ "workspaceRoot": true }]))),
+    ];
+    let fail = vec![("import 'left-pad'", None)];
+    Tester::new(NoExtraneousDependencies::NAME, pass, fail)
+        .with_import_plugin(true)
+        .change_rule_path("workspaces/packages/app/index.js")
+        .test();
+}
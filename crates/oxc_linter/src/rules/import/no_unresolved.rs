@@ -0,0 +1,255 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use oxc_ast::{
+    ast::{ModuleDeclaration, StringLiteral},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+use regex::Regex;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, require_specifier, OptionType},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoUnresolvedDiagnostic {
+    #[error("eslint-plugin-import(no-unresolved): Unable to resolve path to module {0:?}")]
+    #[diagnostic(severity(warning))]
+    Unresolved(Atom, #[label] Span),
+    #[error(
+        "eslint-plugin-import(no-unresolved): Casing of {0:?} does not match the underlying filesystem"
+    )]
+    #[diagnostic(
+        severity(warning),
+        help("It resolves to {2}, a case-sensitive filesystem would fail to resolve it")
+    )]
+    Casing(Atom, #[label] Span, String),
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-unresolved.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolved(Box<NoUnresolvedConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolvedConfig {
+    /// Check the specifiers of `require` calls
+    commonjs: bool,
+    /// Report specifiers which only resolve on a case-insensitive filesystem
+    case_sensitive: bool,
+    /// Specifiers matching one of the patterns are not checked
+    ignore: Vec<Regex>,
+}
+
+impl std::ops::Deref for NoUnresolved {
+    type Target = NoUnresolvedConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures an imported module can be resolved to a module on the local filesystem,
+    /// with the resolver of the import plugin: the `import/resolver` settings, `paths` of
+    /// `tsconfig.json` and Yarn Plug'n'Play. Built-in modules of Node.js are not checked,
+    /// nor are type-only imports, which may resolve to declaration files of `@types` packages.
+    ///
+    /// ### Options
+    ///
+    /// * `commonjs`: also check `require('./foo')` calls, `false` by default
+    /// * `caseSensitive`: report specifiers whose casing differs from the files on disk,
+    ///   `true` by default. They resolve on the case-insensitive filesystems of macOS and
+    ///   Windows, and break the build on Linux, e.g. in CI.
+    /// * `ignore`: regular expressions of specifiers not to check, e.g. `["\\.svg$"]`
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// import x from './does-not-exist'
+    /// import Foo from './foo' // for `Foo.js`
+    /// ```
+    NoUnresolved,
+    nursery
+);
+
+impl Rule for NoUnresolved {
+    fn from_configuration(value: Value) -> Self {
+        let options = value.get(0);
+        let flag = |key: &str, default: bool| {
+            options.and_then(|options| options.get(key)).and_then(Value::as_bool).unwrap_or(default)
+        };
+        let ignore = options
+            .and_then(|options| options.get("ignore"))
+            .and_then(Value::as_array)
+            .map(|patterns| {
+                patterns
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .filter_map(|p| Regex::new(p).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self(Box::new(NoUnresolvedConfig {
+            commonjs: flag("commonjs", false),
+            case_sensitive: flag("caseSensitive", true),
+            ignore,
+        }))
+    }
+
+    fn validate_configuration(value: &Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[
+                ("commonjs", OptionType::Boolean),
+                ("caseSensitive", OptionType::Boolean),
+                ("ignore", OptionType::Patterns),
+            ],
+        )
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let source = match node.kind() {
+            AstKind::ModuleDeclaration(declaration) => match declaration {
+                ModuleDeclaration::ImportDeclaration(import) if !import.import_kind.is_type() => {
+                    &import.source
+                }
+                ModuleDeclaration::ExportNamedDeclaration(export)
+                    if !export.export_kind.is_type() =>
+                {
+                    let Some(source) = &export.source else { return };
+                    source
+                }
+                ModuleDeclaration::ExportAllDeclaration(export)
+                    if !export.export_kind.is_type() =>
+                {
+                    &export.source
+                }
+                _ => return,
+            },
+            AstKind::CallExpression(call) if self.commonjs => {
+                let Some(source) = require_specifier(call) else { return };
+                source
+            }
+            _ => return,
+        };
+        self.check(source, ctx);
+    }
+}
+
+impl NoUnresolved {
+    fn check(&self, source: &StringLiteral, ctx: &LintContext<'_>) {
+        let specifier = &source.value;
+        if self.ignore.iter().any(|pattern| pattern.is_match(specifier)) {
+            return;
+        }
+        let module_record = ctx.semantic().module_record();
+        // Built-in modules of Node.js are not recorded
+        let Some(resolved) = module_record.resolved_modules.get(specifier) else { return };
+        match resolved.value() {
+            None => {
+                ctx.diagnostic(NoUnresolvedDiagnostic::Unresolved(specifier.clone(), source.span));
+            }
+            Some(path) if self.case_sensitive => {
+                let Some(dir) = module_record.resolved_absolute_path.parent() else { return };
+                if !has_exact_case(dir, path) {
+                    ctx.diagnostic(NoUnresolvedDiagnostic::Casing(
+                        specifier.clone(),
+                        source.span,
+                        path.to_string_lossy().to_string(),
+                    ));
+                }
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+/// Whether each component of `path` which is not an ancestor of `dir` is named as the entry of
+/// its directory. The resolver takes the names of the specifier, which a case-insensitive
+/// filesystem finds whatever their casing.
+fn has_exact_case(dir: &Path, path: &Path) -> bool {
+    let mut current = PathBuf::from(path);
+    while !dir.starts_with(&current) {
+        let (Some(parent), Some(name)) = (current.parent(), current.file_name()) else {
+            break;
+        };
+        let Ok(entries) = fs::read_dir(parent) else { return true };
+        if !entries.filter_map(Result::ok).any(|entry| entry.file_name() == name) {
+            return false;
+        }
+        current = parent.to_path_buf();
+    }
+    true
+}
+
+#[test]
+fn test_has_exact_case() {
+    let dir = std::env::current_dir().unwrap().join("fixtures/import");
+    assert!(has_exact_case(&dir, &dir.join("CaseyKasem.js")));
+    assert!(has_exact_case(&dir.join("bar"), &dir.join("deep/a.js")));
+    assert!(!has_exact_case(&dir, &dir.join("caseykasem.js")));
+    assert!(!has_exact_case(&dir, &dir.join("Deep/a.js")));
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import foo from './bar'", None),
+        ("import foo from './bar.js'", None),
+        ("import { foo } from './deep/a.js'", None),
+        ("import CaseyKasem from './CaseyKasem.js'", None),
+        ("export { foo } from './bar'", None),
+        ("export * from './bar'", None),
+        ("export { foo }; const foo = 1", None),
+        ("import fs from 'fs'", None),
+        ("import fs from 'node:fs'", None),
+        ("import { readFile } from 'fs/promises'", None),
+        ("import jquery from 'jquery'", None),
+        ("import type { Foo } from './does-not-exist'", None),
+        ("export type { Foo } from './does-not-exist'", None),
+        ("const foo = require('./does-not-exist')", None),
+        ("const foo = require('./bar')", Some(json!([{ "commonjs": true }]))),
+        ("function f() { return require('fs') }", Some(json!([{ "commonjs": true }]))),
+        ("require(foo)", Some(json!([{ "commonjs": true }]))),
+        ("import icon from './icon.svg'", Some(json!([{ "ignore": ["\\.svg$"] }]))),
+        ("import foo from 'virtual:foo'", Some(json!([{ "ignore": ["^virtual:"] }]))),
+    ];
+
+    let fail = vec![
+        ("import foo from './does-not-exist'", None),
+        ("import foo from 'does-not-exist'", None),
+        ("import { foo } from './deep/does-not-exist.js'", None),
+        ("export { foo } from './does-not-exist'", None),
+        ("export * from './does-not-exist'", None),
+        ("import './does-not-exist'", None),
+        ("const foo = require('./does-not-exist')", Some(json!([{ "commonjs": true }]))),
+        (
+            "function f() { return require('./does-not-exist') }",
+            Some(json!([{ "commonjs": true }])),
+        ),
+        ("import icon from './icon.svg'", Some(json!([{ "ignore": ["\\.png$"] }]))),
+    ];
+
+    Tester::new(NoUnresolved::NAME, pass, fail)
+        .with_import_plugin(true)
+        .change_rule_path("index.ts")
+        .test_and_snapshot();
+}
@@ -0,0 +1,681 @@
+use std::cmp::Ordering;
+
+use oxc_ast::{
+    ast::{ImportDeclaration, ModuleDeclaration, Statement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, OptionType, NODE_BUILTINS_MODULE},
+    Fix,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum OrderDiagnostic {
+    #[error("eslint-plugin-import(order): `{1}` import should occur before import of `{2}`")]
+    #[diagnostic(severity(warning))]
+    OutOfOrder(#[label] Span, String, String),
+    #[error("eslint-plugin-import(order): There should be at least one empty line between import groups")]
+    #[diagnostic(severity(warning))]
+    MissingEmptyLine(#[label] Span),
+    #[error("eslint-plugin-import(order): There should be no empty line between import groups")]
+    #[diagnostic(severity(warning))]
+    EmptyLineBetweenGroups(#[label] Span),
+    #[error("eslint-plugin-import(order): There should be no empty line within import group")]
+    #[diagnostic(severity(warning))]
+    EmptyLineWithinGroup(#[label] Span),
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/order.md>
+#[derive(Debug, Default, Clone)]
+pub struct Order(Box<OrderConfig>);
+
+#[derive(Debug, Clone)]
+pub struct OrderConfig {
+    /// Rank of each [ImportType], types missing from `groups` share the last rank
+    ranks: [usize; ImportType::COUNT],
+    /// `import type` declarations are only ranked as `type` when it is in `groups`
+    type_group: bool,
+    newlines_between: NewlinesBetween,
+    alphabetize: Alphabetize,
+    case_insensitive: bool,
+}
+
+impl Default for OrderConfig {
+    fn default() -> Self {
+        let groups = ["builtin", "external", "parent", "sibling", "index"].map(Value::from);
+        let (ranks, type_group) = ranks(&groups);
+        Self {
+            ranks,
+            type_group,
+            newlines_between: NewlinesBetween::Ignore,
+            alphabetize: Alphabetize::Ignore,
+            case_insensitive: false,
+        }
+    }
+}
+
+impl std::ops::Deref for Order {
+    type Target = OrderConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportType {
+    Builtin,
+    External,
+    Internal,
+    Unknown,
+    Parent,
+    Sibling,
+    Index,
+    Object,
+    Type,
+}
+
+impl ImportType {
+    const COUNT: usize = 9;
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "builtin" => Some(Self::Builtin),
+            "external" => Some(Self::External),
+            "internal" => Some(Self::Internal),
+            "unknown" => Some(Self::Unknown),
+            "parent" => Some(Self::Parent),
+            "sibling" => Some(Self::Sibling),
+            "index" => Some(Self::Index),
+            "object" => Some(Self::Object),
+            "type" => Some(Self::Type),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NewlinesBetween {
+    Ignore,
+    Always,
+    AlwaysAndInsideGroups,
+    Never,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Alphabetize {
+    Ignore,
+    Asc,
+    Desc,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a convention in the order of `import` declarations.
+    ///
+    /// Imports are ranked by the group of their module specifier:
+    /// `builtin` (`fs`, `node:path`), `external` (`lodash`), `internal` (bare specifiers matching
+    /// the `import/internal-regex` setting or resolved outside of `node_modules`, e.g. through
+    /// tsconfig `paths` aliases), `parent` (`../foo`), `sibling` (`./foo`), `index` (`./`),
+    /// `unknown` and `type` (`import type`, only when listed in `groups`).
+    ///
+    /// ### Options
+    ///
+    /// * `groups`, `["builtin", "external", "parent", "sibling", "index"]` by default, nested arrays
+    ///   share a rank and omitted groups share the last rank
+    /// * `newlines-between`: `ignore` (default), `always`, `always-and-inside-groups` or `never`
+    /// * `alphabetize`: `{ "order": "ignore" | "asc" | "desc", "caseInsensitive": false }`
+    ///
+    /// The fix moves whole import declarations, with the comments on the lines above them and
+    /// after them on the same line, when nothing but imports is between the first and last import.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// import _ from 'lodash';
+    /// import path from 'path';
+    ///
+    /// // pass
+    /// import path from 'path';
+    /// import _ from 'lodash';
+    /// ```
+    Order,
+    nursery,
+    fix = unsafe
+);
+
+impl Rule for Order {
+    fn from_configuration(value: Value) -> Self {
+        let mut config = OrderConfig::default();
+        let Some(options) = value.get(0) else { return Self(Box::new(config)) };
+
+        if let Some(groups) = options.get("groups").and_then(Value::as_array) {
+            (config.ranks, config.type_group) = ranks(groups);
+        }
+        config.newlines_between = match options.get("newlines-between").and_then(Value::as_str) {
+            Some("always") => NewlinesBetween::Always,
+            Some("always-and-inside-groups") => NewlinesBetween::AlwaysAndInsideGroups,
+            Some("never") => NewlinesBetween::Never,
+            _ => NewlinesBetween::Ignore,
+        };
+        if let Some(alphabetize) = options.get("alphabetize") {
+            config.alphabetize = match alphabetize.get("order").and_then(Value::as_str) {
+                Some("asc") => Alphabetize::Asc,
+                Some("desc") => Alphabetize::Desc,
+                _ => Alphabetize::Ignore,
+            };
+            config.case_insensitive =
+                alphabetize.get("caseInsensitive").and_then(Value::as_bool).unwrap_or_default();
+        }
+
+        Self(Box::new(config))
+    }
+
+    fn validate_configuration(value: &Value) -> Result<(), String> {
+        let options = value.get(0);
+        check_properties(
+            options,
+            &[(
+                "newlines-between",
+                OptionType::OneOf(&["ignore", "always", "always-and-inside-groups", "never"]),
+            )],
+        )?;
+        let is_group = |group: &Value| match group {
+            Value::String(name) => ImportType::from_name(name).is_some(),
+            Value::Array(names) => {
+                names.iter().all(|name| name.as_str().and_then(ImportType::from_name).is_some())
+            }
+            _ => false,
+        };
+        if let Some(groups) = options.and_then(|options| options.get("groups")) {
+            if !groups.as_array().is_some_and(|groups| groups.iter().all(is_group)) {
+                return Err(
+                    r#"expected "groups" to be an array of import types or of arrays of them"#
+                        .to_string(),
+                );
+            }
+        }
+        check_properties(
+            options.and_then(|options| options.get("alphabetize")),
+            &[
+                ("order", OptionType::OneOf(&["asc", "desc", "ignore"])),
+                ("caseInsensitive", OptionType::Boolean),
+            ],
+        )
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let Some(root) = ctx.nodes().iter().next() else { return };
+        let AstKind::Program(program) = root.kind() else { return };
+
+        // Top level import declarations with their index in the program body
+        let declarations = program
+            .body
+            .iter()
+            .enumerate()
+            .filter_map(|(index, statement)| match statement {
+                Statement::ModuleDeclaration(decl) => match &**decl {
+                    ModuleDeclaration::ImportDeclaration(decl) => Some((index, &**decl)),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if declarations.len() < 2 {
+            return;
+        }
+        let imports = declarations
+            .iter()
+            .map(|(_, decl)| Import {
+                name: decl.source.value.as_str(),
+                source_span: decl.source.span,
+                span: decl.span,
+                rank: self.rank(decl, ctx),
+            })
+            .collect::<Vec<_>>();
+
+        // Only whole blocks of imports are moved, so that no code runs in a different order
+        let contiguous = declarations.windows(2).all(|pair| pair[0].0 + 1 == pair[1].0);
+        let next_statement_start = declarations
+            .last()
+            .and_then(|(index, _)| program.body.get(index + 1))
+            .map_or(program.span.end, |statement| statement.span().start);
+        let mut reorder_fix = None;
+
+        for (index, import) in imports.iter().enumerate() {
+            let Some(previous) =
+                imports[..index].iter().find(|previous| self.compare(previous, import).is_gt())
+            else {
+                continue;
+            };
+            let diagnostic = OrderDiagnostic::OutOfOrder(
+                import.source_span,
+                import.name.to_string(),
+                previous.name.to_string(),
+            );
+            if !contiguous {
+                ctx.diagnostic(diagnostic);
+                continue;
+            }
+            let fix = reorder_fix
+                .get_or_insert_with(|| self.reorder(&imports, next_statement_start, ctx))
+                .clone();
+            ctx.diagnostic_with_fix(diagnostic, || fix);
+        }
+
+        if self.newlines_between != NewlinesBetween::Ignore {
+            self.check_newlines_between(&imports, ctx);
+        }
+    }
+}
+
+struct Import<'a> {
+    name: &'a str,
+    source_span: Span,
+    span: Span,
+    rank: usize,
+}
+
+impl Order {
+    fn rank(&self, decl: &ImportDeclaration<'_>, ctx: &LintContext<'_>) -> usize {
+        let import_type = if self.type_group && decl.import_kind.is_type() {
+            ImportType::Type
+        } else {
+            import_type(decl.source.value.as_str(), ctx)
+        };
+        self.ranks[import_type as usize]
+    }
+
+    fn compare(&self, a: &Import<'_>, b: &Import<'_>) -> Ordering {
+        let compare_names = |a: &str, b: &str| {
+            if self.case_insensitive {
+                a.to_lowercase().cmp(&b.to_lowercase())
+            } else {
+                a.cmp(b)
+            }
+        };
+        a.rank.cmp(&b.rank).then_with(|| match self.alphabetize {
+            Alphabetize::Ignore => Ordering::Equal,
+            Alphabetize::Asc => compare_names(a.name, b.name),
+            Alphabetize::Desc => compare_names(b.name, a.name),
+        })
+    }
+
+    /// Replace all imports with the sorted imports, each with its comments. The comments right
+    /// above the first import move with it, a file header separated by an empty line stays.
+    #[allow(clippy::cast_possible_truncation)]
+    fn reorder<'a>(
+        &self,
+        imports: &[Import<'_>],
+        next_statement_start: u32,
+        ctx: &LintContext<'a>,
+    ) -> Fix<'a> {
+        let source_text = ctx.source_text();
+        // A chunk ends at the first line break after its import, the comments on the lines
+        // between two imports belong to the second one
+        let mut ends = imports
+            .windows(2)
+            .map(|pair| line_break_after(ctx, pair[0].span.end, pair[1].span.start))
+            .collect::<Vec<_>>();
+        let last = imports.last().unwrap().span;
+        ends.push(line_break_after(ctx, last.end, next_statement_start));
+        let start = leading_comments_start(ctx, imports[0].span.start);
+        let starts = std::iter::once(start).chain(ends.iter().copied());
+
+        let mut chunks = imports
+            .iter()
+            .zip(starts.zip(ends.iter().copied()))
+            .map(|(import, (start, end))| {
+                (import, source_text[start as usize..end as usize].trim())
+            })
+            .collect::<Vec<_>>();
+        chunks.sort_by(|(a, _), (b, _)| self.compare(a, b));
+
+        let mut content = String::new();
+        for (index, (import, chunk)) in chunks.iter().enumerate() {
+            if let Some((previous, _)) = index.checked_sub(1).map(|index| &chunks[index]) {
+                let empty_line = matches!(
+                    self.newlines_between,
+                    NewlinesBetween::Always | NewlinesBetween::AlwaysAndInsideGroups
+                ) && previous.rank != import.rank;
+                content.push_str(if empty_line { "\n\n" } else { "\n" });
+            }
+            content.push_str(chunk);
+        }
+
+        let region = &source_text[start as usize..ends[ends.len() - 1] as usize];
+        let end = start + region.trim_end().len() as u32;
+        Fix::new(content, Span::new(start, end))
+    }
+
+    fn check_newlines_between(&self, imports: &[Import<'_>], ctx: &LintContext<'_>) {
+        let source_text = ctx.source_text();
+        for pair in imports.windows(2) {
+            let (previous, import) = (&pair[0], &pair[1]);
+            let gap = Span::new(previous.span.end, import.span.start);
+            let empty_line = has_empty_line(gap.source_text(source_text));
+            let same_group = previous.rank == import.rank;
+            let diagnostic = match (self.newlines_between, same_group, empty_line) {
+                (
+                    NewlinesBetween::Always | NewlinesBetween::AlwaysAndInsideGroups,
+                    false,
+                    false,
+                ) => OrderDiagnostic::MissingEmptyLine(previous.source_span),
+                (NewlinesBetween::Always, true, true) => {
+                    OrderDiagnostic::EmptyLineWithinGroup(previous.source_span)
+                }
+                (NewlinesBetween::Never, false, true) => {
+                    OrderDiagnostic::EmptyLineBetweenGroups(previous.source_span)
+                }
+                (NewlinesBetween::Never, true, true) => {
+                    OrderDiagnostic::EmptyLineWithinGroup(previous.source_span)
+                }
+                _ => continue,
+            };
+            // Gaps with comments are left for the developer
+            if !gap.source_text(source_text).trim().is_empty() {
+                ctx.diagnostic(diagnostic);
+                continue;
+            }
+            let content = if empty_line { "\n" } else { "\n\n" };
+            ctx.diagnostic_with_fix(diagnostic, || Fix::new(content, gap));
+        }
+    }
+}
+
+/// Types and ranks of `groups`, e.g. `["builtin", ["parent", "sibling"]]`.
+fn ranks(groups: &[Value]) -> ([usize; ImportType::COUNT], bool) {
+    let mut ranks = [groups.len(); ImportType::COUNT];
+    let mut type_group = false;
+    for (rank, group) in groups.iter().enumerate() {
+        let names = match group {
+            Value::String(name) => vec![name.as_str()],
+            Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+            _ => vec![],
+        };
+        for import_type in names.into_iter().filter_map(ImportType::from_name) {
+            ranks[import_type as usize] = rank;
+            type_group |= import_type == ImportType::Type;
+        }
+    }
+    (ranks, type_group)
+}
+
+fn import_type(name: &str, ctx: &LintContext<'_>) -> ImportType {
+    if name.starts_with("node:") || NODE_BUILTINS_MODULE.contains(name) {
+        return ImportType::Builtin;
+    }
+    if matches!(name, "." | "./") || is_index(name) {
+        return ImportType::Index;
+    }
+    if name == ".." || name.starts_with("../") {
+        return ImportType::Parent;
+    }
+    if name.starts_with("./") {
+        return ImportType::Sibling;
+    }
+    if name.starts_with('/') {
+        return ImportType::Unknown;
+    }
+    if ctx.settings().import.internal_regex.is_some_and(|regex| regex.is_match(name)) {
+        return ImportType::Internal;
+    }
+    // Bare specifiers resolved to a file of the project are aliases, e.g. tsconfig `paths`
+    let module_record = ctx.semantic().module_record();
+    if let Some(remote) = module_record.loaded_modules.get(name) {
+        let path = &remote.value().resolved_absolute_path;
+        if !path.components().any(|component| component.as_os_str() == "node_modules") {
+            return ImportType::Internal;
+        }
+    }
+    ImportType::External
+}
+
+/// `./index` and `./index.js`
+fn is_index(name: &str) -> bool {
+    name.strip_prefix("./index")
+        .is_some_and(|rest| rest.is_empty() || (rest.starts_with('.') && !rest.contains('/')))
+}
+
+/// Offset of the first line break between `start` and `end` outside of comments, `end` if there
+/// is none.
+#[allow(clippy::cast_possible_truncation)]
+fn line_break_after(ctx: &LintContext<'_>, start: u32, end: u32) -> u32 {
+    let source_text = ctx.source_text();
+    let comments = ctx.semantic().trivias().comments();
+    let mut offset = start;
+    while offset < end {
+        // Skip over comments, multi line comments contain line breaks
+        if let Some(comment) = comments.get(&offset) {
+            offset = comment.end().max(offset + 1);
+            continue;
+        }
+        let Some(ch) = source_text[offset as usize..].chars().next() else { break };
+        if matches!(ch, '\n' | '\r' | '\u{2028}' | '\u{2029}') {
+            return offset;
+        }
+        offset += ch.len_utf8() as u32;
+    }
+    end
+}
+
+/// Whether the whitespace and comments between two imports contain an empty line.
+/// Start of the comments on the lines right above `start`, without an empty line between them.
+/// A comment after code on its line belongs to that code.
+fn leading_comments_start(ctx: &LintContext<'_>, start: u32) -> u32 {
+    let source_text = ctx.source_text();
+    let comments = ctx.semantic().trivias().comments();
+    let mut leading_start = start;
+    for (&comment_start, comment) in comments.range(..start).rev() {
+        let gap = &source_text[comment.end() as usize..leading_start as usize];
+        if !gap.trim().is_empty() || gap.matches('\n').count() > 1 {
+            break;
+        }
+        let line_start = source_text[..comment_start as usize].rfind('\n').map_or(0, |i| i + 1);
+        if !source_text[line_start..comment_start as usize].trim().is_empty() {
+            break;
+        }
+        leading_start = comment_start;
+    }
+    leading_start
+}
+
+fn has_empty_line(gap: &str) -> bool {
+    let lines = gap.split('\n').collect::<Vec<_>>();
+    lines.len() > 2 && lines[1..lines.len() - 1].iter().any(|line| line.trim().is_empty())
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (
+            r"
+            import fs from 'fs';
+            import async from 'async';
+            import sibling from './foo';
+            import index from './';
+            ",
+            None,
+        ),
+        (
+            r"
+            import fs from 'fs';
+            import { readFile } from 'node:fs/promises';
+            import _ from 'lodash';
+            import parent from '../foo';
+            import sibling from './foo';
+            import index from './index.js';
+            ",
+            None,
+        ),
+        // Omitted groups are ranked last
+        (
+            r"
+            import sibling from './foo';
+            import fs from 'fs';
+            ",
+            Some(json!([{ "groups": ["sibling", "builtin"] }])),
+        ),
+        (
+            r"
+            import parent from '../foo';
+            import sibling from './foo';
+            import fs from 'fs';
+            ",
+            Some(json!([{ "groups": [["parent", "sibling"], "builtin"] }])),
+        ),
+        (
+            r"
+            import fs from 'fs';
+            import path from 'path';
+
+            import _ from 'lodash';
+
+            import sibling from './foo';
+            ",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"
+            import fs from 'fs';
+
+            import path from 'path';
+
+            import _ from 'lodash';
+            ",
+            Some(json!([{ "newlines-between": "always-and-inside-groups" }])),
+        ),
+        (
+            r"
+            import fs from 'fs';
+            import _ from 'lodash';
+            import sibling from './foo';
+            ",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            r"
+            import a from 'a';
+            import B from 'B';
+            import c from 'c';
+            ",
+            Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": true } }])),
+        ),
+        (
+            r"
+            import c from 'c';
+            import b from 'b';
+            import a from 'a';
+            ",
+            Some(json!([{ "alphabetize": { "order": "desc" } }])),
+        ),
+        // Imports separated by code are still ranked
+        (
+            r"
+            import fs from 'fs';
+            foo();
+            import _ from 'lodash';
+            ",
+            None,
+        ),
+        ("import _ from 'lodash';\nconst fs = require('fs');", None),
+    ];
+
+    let fail = vec![
+        ("import _ from 'lodash';\nimport fs from 'fs';", None),
+        ("import sibling from './foo';\nimport parent from '../foo';", None),
+        ("import _ from 'lodash';\nfoo();\nimport fs from 'fs';", None),
+        (
+            "import b from 'b';\nimport a from 'a';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            "import fs from 'fs';\nimport _ from 'lodash';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport path from 'path';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport _ from 'lodash';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            "import type { Foo } from 'fs';\nimport _ from 'lodash';",
+            Some(json!([{ "groups": ["external", "type"] }])),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "import _ from 'lodash';\nimport fs from 'fs';\n",
+            "import fs from 'fs';\nimport _ from 'lodash';\n",
+            None,
+        ),
+        (
+            "import _ from 'lodash'; // utilities\n// the file system\nimport fs from 'fs';\n\nfoo();",
+            "// the file system\nimport fs from 'fs';\nimport _ from 'lodash'; // utilities\n\nfoo();",
+            None,
+        ),
+        (
+            "// lodash helpers\nimport _ from 'lodash';\nimport fs from 'fs';\n",
+            "import fs from 'fs';\n// lodash helpers\nimport _ from 'lodash';\n",
+            None,
+        ),
+        (
+            "/* Copyright 2024 */\n\nimport _ from 'lodash';\nimport fs from 'fs';\n",
+            "/* Copyright 2024 */\n\nimport fs from 'fs';\nimport _ from 'lodash';\n",
+            None,
+        ),
+        (
+            "// header\n\n// lodash helpers\nimport _ from 'lodash';\nimport fs from 'fs';",
+            "// header\n\nimport fs from 'fs';\n// lodash helpers\nimport _ from 'lodash';",
+            None,
+        ),
+        (
+            "import b from 'b';\nimport sibling from './foo';\nimport a from 'a';\nimport fs from 'fs';",
+            "import fs from 'fs';\n\nimport a from 'a';\nimport b from 'b';\n\nimport sibling from './foo';",
+            Some(json!([{ "newlines-between": "always", "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            "import fs from 'fs';\nimport _ from 'lodash';",
+            "import fs from 'fs';\n\nimport _ from 'lodash';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport _ from 'lodash';",
+            "import fs from 'fs';\nimport _ from 'lodash';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+    ];
+
+    Tester::new(Order::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+
+    let settings = json!({ "import/internal-regex": "^@app/" });
+    let pass = vec![(
+        "import _ from 'lodash';\nimport utils from '@app/utils';",
+        Some(json!([{ "groups": ["external", "internal"] }])),
+        Some(settings.clone()),
+    )];
+    let fail = vec![(
+        "import utils from '@app/utils';\nimport _ from 'lodash';",
+        Some(json!([{ "groups": ["external", "internal"] }])),
+        Some(settings),
+    )];
+    Tester::new_with_settings(Order::NAME, pass, fail).test();
+}
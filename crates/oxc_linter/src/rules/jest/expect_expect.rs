@@ -15,8 +15,8 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, get_node_name, is_type_of_jest_fn_call, JestFnKind,
-        JestGeneralFnKind, PossibleJestNode,
+        check_properties, collect_possible_jest_call_node, get_node_name, is_type_of_jest_fn_call,
+        JestFnKind, JestGeneralFnKind, OptionType, PossibleJestNode,
     },
 };
 
@@ -97,6 +97,16 @@ impl Rule for ExpectExpect {
             additional_test_block_functions,
         }))
     }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[
+                ("assertFunctionNames", OptionType::Strings),
+                ("additionalTestBlockFunctions", OptionType::Strings),
+            ],
+        )
+    }
     fn run_once(&self, ctx: &LintContext) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
             run(self, possible_jest_node, ctx);
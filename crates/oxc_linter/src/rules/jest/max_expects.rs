@@ -3,7 +3,7 @@ use std::{collections::HashMap, hash::BuildHasherDefault};
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{collect_possible_jest_call_node, PossibleJestNode},
+    utils::{check_properties, collect_possible_jest_call_node, OptionType, PossibleJestNode},
 };
 use oxc_ast::{ast::Expression, AstKind};
 use oxc_diagnostics::{
@@ -83,6 +83,10 @@ impl Rule for MaxExpects {
         Self { max }
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("max", OptionType::Count)])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let mut count_map: HashMap<usize, usize, BuildHasherDefault<FxHasher>> =
             FxHashMap::default();
@@ -8,7 +8,12 @@ use oxc_span::{GetSpan, Span};
 use phf::{phf_map, Map};
 use std::borrow::Cow;
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule};
+use crate::{
+    context::LintContext,
+    fixer::Fix,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-jest(no-deprecated-functions): Disallow use of deprecated functions")]
@@ -76,6 +81,7 @@ declare_oxc_lint!(
     /// ```
     NoDeprecatedFunctions,
     style,
+    fix = safe
 );
 
 const DEPRECATED_FUNCTIONS_MAP: Map<&'static str, (usize, &'static str)> = phf_map! {
@@ -107,6 +113,14 @@ impl Rule for NoDeprecatedFunctions {
         }))
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[])?;
+        check_properties(
+            value.get(0).and_then(|config| config.get("jest")),
+            &[("version", OptionType::String)],
+        )
+    }
+
     fn run<'a>(&self, node: &oxc_semantic::AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::MemberExpression(mem_expr) = node.kind() else {
             return;
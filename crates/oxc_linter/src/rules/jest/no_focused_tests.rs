@@ -53,7 +53,8 @@ declare_oxc_lint!(
     /// `();
     /// ```
     NoFocusedTests,
-    correctness
+    correctness,
+    fix = suggestion
 );
 
 impl Rule for NoFocusedTests {
@@ -10,8 +10,8 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, is_type_of_jest_fn_call, JestFnKind, JestGeneralFnKind,
-        PossibleJestNode,
+        check_properties, collect_possible_jest_call_node, is_type_of_jest_fn_call, JestFnKind,
+        JestGeneralFnKind, OptionType, PossibleJestNode,
     },
 };
 
@@ -98,6 +98,10 @@ impl Rule for NoHooks {
         Self(Box::new(NoHooksConfig { allow }))
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("allow", OptionType::Strings)])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for possible_jest_node in collect_possible_jest_call_node(ctx) {
             self.run(&possible_jest_node, ctx);
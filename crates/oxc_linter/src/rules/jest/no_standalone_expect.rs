@@ -13,9 +13,9 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, get_node_name, parse_expect_jest_fn_call,
-        parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind, KnownMemberExpressionParentKind,
-        ParsedExpectFnCall, PossibleJestNode,
+        check_properties, collect_possible_jest_call_node, get_node_name,
+        parse_expect_jest_fn_call, parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind,
+        KnownMemberExpressionParentKind, OptionType, ParsedExpectFnCall, PossibleJestNode,
     },
     AstNode,
 };
@@ -75,6 +75,10 @@ impl Rule for NoStandaloneExpect {
 
         Self(Box::new(NoStandaloneExpectConfig { additional_test_block_functions }))
     }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("additionalTestBlockFunctions", OptionType::Strings)])
+    }
     fn run_once(&self, ctx: &LintContext<'_>) {
         let possible_jest_nodes = collect_possible_jest_call_node(ctx);
         let id_nodes_mapping = possible_jest_nodes.iter().fold(HashMap::new(), |mut acc, cur| {
@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// xdescribe('foo'); // invalid
     /// ```
     NoTestPrefixes,
-    style
+    style,
+    fix = safe
 );
 
 impl Rule for NoTestPrefixes {
@@ -52,6 +52,7 @@ declare_oxc_lint!(
     /// ```
     PreferTodo,
     style,
+    fix = safe
 );
 
 impl Rule for PreferTodo {
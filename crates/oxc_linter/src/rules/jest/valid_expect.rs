@@ -13,7 +13,8 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, parse_expect_jest_fn_call, ExpectError, PossibleJestNode,
+        check_properties, collect_possible_jest_call_node, parse_expect_jest_fn_call, ExpectError,
+        OptionType, PossibleJestNode,
     },
     AstNode,
 };
@@ -101,6 +102,18 @@ impl Rule for ValidExpect {
 
         Self(Box::new(ValidExpectConfig { async_matchers, min_args, max_args, always_await }))
     }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[
+                ("asyncMatchers", OptionType::Strings),
+                ("minArgs", OptionType::Count),
+                ("maxArgs", OptionType::Count),
+                ("alwaysAwait", OptionType::Boolean),
+            ],
+        )
+    }
     fn run_once(&self, ctx: &LintContext) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
             self.run(possible_jest_node, ctx);
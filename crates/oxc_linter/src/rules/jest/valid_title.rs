@@ -16,8 +16,8 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind,
-        PossibleJestNode,
+        check_properties, collect_possible_jest_call_node, parse_general_jest_fn_call, JestFnKind,
+        JestGeneralFnKind, OptionType, PossibleJestNode,
     },
 };
 
@@ -108,6 +108,29 @@ impl Rule for ValidTitle {
         }))
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        let config = value.get(0);
+        check_properties(
+            config,
+            &[
+                ("ignoreTypeOfDescribeName", OptionType::Boolean),
+                ("ignoreSpaces", OptionType::Boolean),
+                ("disallowedWords", OptionType::Strings),
+            ],
+        )?;
+        for key in ["mustMatch", "mustNotMatch"] {
+            match config.and_then(|config| config.get(key)) {
+                Some(patterns) if compile_matcher_patterns(patterns).is_none() => {
+                    return Err(format!(
+                        r#"expected "{key}" to be a pattern, a pattern and a message, or an object of them"#
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for node in &collect_possible_jest_call_node(ctx) {
             self.run(node, ctx);
@@ -12,7 +12,9 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::utils::{get_literal_prop_value, get_prop_value, has_jsx_prop_lowercase};
+use crate::utils::{
+    check_properties, get_literal_prop_value, get_prop_value, has_jsx_prop_lowercase, OptionType,
+};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
@@ -161,6 +163,19 @@ impl Rule for AltText {
         Self(Box::new(alt_text))
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[
+                ("elements", OptionType::Strings),
+                ("img", OptionType::Strings),
+                ("object", OptionType::Strings),
+                ("area", OptionType::Strings),
+                ("input[type=\"image\"]", OptionType::Strings),
+            ],
+        )
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
         let JSXElementName::Identifier(iden) = &jsx_el.name else { return };
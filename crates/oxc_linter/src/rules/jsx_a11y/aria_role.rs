@@ -13,7 +13,7 @@ use crate::{
     context::LintContext,
     globals::{HTML_TAG, VALID_ARIA_ROLES},
     rule::Rule,
-    utils::{get_element_type, get_prop_value, has_jsx_prop},
+    utils::{check_properties, get_element_type, get_prop_value, has_jsx_prop, OptionType},
     AstNode,
 };
 
@@ -118,6 +118,13 @@ impl Rule for AriaRole {
         Self(Box::new(AriaRoleConfig { ignore_non_dom, allowed_invalid_roles }))
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[("ignoreNonDOM", OptionType::Boolean), ("allowedInvalidRoles", OptionType::Strings)],
+        )
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXElement(jsx_el) = node.kind() {
             if let Option::Some(aria_role) = has_jsx_prop(&jsx_el.opening_element, "role") {
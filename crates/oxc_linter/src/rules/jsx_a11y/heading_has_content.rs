@@ -9,7 +9,9 @@ use oxc_span::Span;
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{is_hidden_from_screen_reader, object_has_accessible_child},
+    utils::{
+        check_properties, is_hidden_from_screen_reader, object_has_accessible_child, OptionType,
+    },
     AstNode,
 };
 
@@ -82,6 +84,10 @@ impl Rule for HeadingHasContent {
         }))
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("components", OptionType::Strings)])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
             return;
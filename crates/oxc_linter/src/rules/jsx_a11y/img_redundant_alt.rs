@@ -14,7 +14,10 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::utils::{get_prop_value, has_jsx_prop_lowercase, is_hidden_from_screen_reader};
+use crate::utils::{
+    check_properties, get_prop_value, has_jsx_prop_lowercase, is_hidden_from_screen_reader,
+    OptionType,
+};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
@@ -105,6 +108,13 @@ impl Rule for ImgRedundantAlt {
 
         Self(Box::new(img_redundant_alt))
     }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[("components", OptionType::Strings), ("words", OptionType::Strings)],
+        )
+    }
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
         let JSXElementName::Identifier(iden) = &jsx_el.name else { return };
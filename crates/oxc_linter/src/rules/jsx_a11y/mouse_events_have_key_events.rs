@@ -13,7 +13,7 @@ use crate::{
     context::LintContext,
     globals::HTML_TAG,
     rule::Rule,
-    utils::{get_element_type, get_prop_value, has_jsx_prop},
+    utils::{check_properties, get_element_type, get_prop_value, has_jsx_prop, OptionType},
     AstNode,
 };
 
@@ -99,6 +99,13 @@ impl Rule for MouseEventsHaveKeyEvents {
         Self(Box::new(config))
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[("hoverInHandlers", OptionType::Strings), ("hoverOutHandlers", OptionType::Strings)],
+        )
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_el) = node.kind() else {
             return;
@@ -0,0 +1,130 @@
+use oxc_ast::{
+    ast::{Expression, VariableDeclarationKind},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoImplementationInDeclarationFileDiagnostic {
+    #[error("oxc(no-implementation-in-declaration-file): Implementations are not allowed in declaration files")]
+    #[diagnostic(
+        severity(warning),
+        help("Remove the body, declaration files only describe types")
+    )]
+    Body(#[label] Span),
+    #[error("oxc(no-implementation-in-declaration-file): Initializers are not allowed in declaration files")]
+    #[diagnostic(
+        severity(warning),
+        help(
+            "Only a `const` can be initialized, with a string or numeric literal or an enum member"
+        )
+    )]
+    Initializer(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoImplementationInDeclarationFile;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows function bodies and initializers in `.d.ts` files.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Declaration files only describe the types of code implemented elsewhere. TypeScript rejects
+    /// implementations in them, while the parser accepts them, so handwritten declaration files
+    /// can silently carry code that is never type checked.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// // index.d.ts
+    /// export function foo() {}
+    /// export let bar = createBar();
+    /// ```
+    NoImplementationInDeclarationFile,
+    correctness
+);
+
+impl Rule for NoImplementationInDeclarationFile {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !ctx.source_type().is_typescript_definition() {
+            return;
+        }
+        match node.kind() {
+            AstKind::Function(function) => {
+                if let Some(body) = &function.body {
+                    ctx.diagnostic(NoImplementationInDeclarationFileDiagnostic::Body(body.span));
+                }
+            }
+            AstKind::VariableDeclarator(declarator) => {
+                let Some(init) = &declarator.init else { return };
+                if declarator.kind != VariableDeclarationKind::Const
+                    || !is_constant_initializer(init)
+                {
+                    ctx.diagnostic(NoImplementationInDeclarationFileDiagnostic::Initializer(
+                        init.span(),
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Initializers TypeScript accepts for an ambient `const`.
+fn is_constant_initializer(init: &Expression) -> bool {
+    match init.without_parenthesized() {
+        Expression::StringLiteral(_)
+        | Expression::NumberLiteral(_)
+        | Expression::BigintLiteral(_)
+        | Expression::Identifier(_)
+        | Expression::MemberExpression(_) => true,
+        Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+        Expression::UnaryExpression(unary) => {
+            unary.operator == UnaryOperator::UnaryNegation
+                && matches!(
+                    unary.argument,
+                    Expression::NumberLiteral(_) | Expression::BigintLiteral(_)
+                )
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("declare function foo(): void;", None),
+        ("export function foo(a: string): void;", None),
+        ("export class A { m(): void; }", None),
+        ("declare const a = 1;", None),
+        ("export const b = 'b';", None),
+        ("export const c = -1;", None),
+        ("export const d = E.A;", None),
+        ("export const e = `e`;", None),
+        ("declare let f: string;", None),
+    ];
+
+    let fail = vec![
+        ("export function foo() {}", None),
+        ("export class A { m() {} }", None),
+        ("export let a = 1;", None),
+        ("export const b = foo();", None),
+        ("export const c = () => 1;", None),
+    ];
+
+    Tester::new(NoImplementationInDeclarationFile::NAME, pass, fail)
+        .change_rule_path_extension("d.ts")
+        .test_and_snapshot();
+}
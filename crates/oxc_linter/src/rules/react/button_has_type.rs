@@ -15,7 +15,10 @@ use oxc_span::{GetSpan, Span};
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_prop_value, has_jsx_prop_lowercase, is_create_element_call},
+    utils::{
+        check_properties, get_prop_value, has_jsx_prop_lowercase, is_create_element_call,
+        OptionType,
+    },
     AstNode,
 };
 
@@ -158,6 +161,17 @@ impl Rule for ButtonHasType {
                 .unwrap_or(true),
         }
     }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[
+                ("button", OptionType::Boolean),
+                ("submit", OptionType::Boolean),
+                ("reset", OptionType::Boolean),
+            ],
+        )
+    }
 }
 
 impl ButtonHasType {
@@ -15,7 +15,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_parent_es5_component, get_parent_es6_component},
+    utils::{check_properties, get_parent_es5_component, get_parent_es6_component, OptionType},
     AstNode,
 };
 
@@ -110,6 +110,10 @@ impl Rule for NoStringRefs {
 
         Self { no_template_literals }
     }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("noTemplateLiterals", OptionType::Boolean)])
+    }
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr)) => {
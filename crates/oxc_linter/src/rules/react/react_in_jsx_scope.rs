@@ -1,7 +1,7 @@
 use oxc_ast::AstKind;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
-    thiserror::Error,
+    thiserror::{self, Error},
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, GetSpan, Span};
@@ -9,9 +9,9 @@ use oxc_span::{Atom, GetSpan, Span};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint-plugin-react(react-in-jsx-scope): 'React' must be in scope when using JSX")]
+#[error("eslint-plugin-react(react-in-jsx-scope): '{1}' must be in scope when using JSX")]
 #[diagnostic(severity(warning), help("When using JSX, `<a />` expands to `React.createElement(\"a\")`. Therefore the `React` variable must be in scope."))]
-struct ReactInJsxScopeDiagnostic(#[label] pub Span);
+struct ReactInJsxScopeDiagnostic(#[label] pub Span, Atom);
 
 #[derive(Debug, Default, Clone)]
 pub struct ReactInJsxScope;
@@ -35,6 +35,11 @@ declare_oxc_lint!(
     /// var a = <a />;
     ///
     /// ```
+    ///
+    /// The rule does not report anything with the automatic runtime, configured with
+    /// `"settings": { "react": { "runtime": "automatic" } }`, `"jsx": "react-jsx"` in the
+    /// tsconfig or a `/** @jsxRuntime automatic */` comment. `settings.react.pragma`,
+    /// `jsxFactory` and `/** @jsx h */` replace `React`.
     ReactInJsxScope,
     suspicious
 );
@@ -46,8 +51,10 @@ impl Rule for ReactInJsxScope {
             AstKind::JSXFragment(v) => v.opening_fragment.span,
             _ => return,
         };
+        // Nothing to be in scope with the automatic runtime
+        let Some(pragma) = ctx.semantic().jsx().pragma_variable() else { return };
         let scope = ctx.scopes();
-        let react_name: &Atom = &Atom::from("React");
+        let react_name = &Atom::from(pragma);
         if scope.get_binding(scope.root_scope_id(), react_name).is_some() {
             return;
         }
@@ -56,7 +63,7 @@ impl Rule for ReactInJsxScope {
             .ancestors(node.scope_id())
             .any(|v| scope.get_bindings(v).iter().any(|(k, _)| k == react_name))
         {
-            ctx.diagnostic(ReactInJsxScopeDiagnostic(node_span));
+            ctx.diagnostic(ReactInJsxScopeDiagnostic(node_span, react_name.clone()));
         }
     }
 }
@@ -64,15 +71,18 @@ impl Rule for ReactInJsxScope {
 #[test]
 fn test() {
     use crate::tester::Tester;
+    fn settings(key: &str, value: &str) -> serde_json::Value {
+        serde_json::json!({ "react": { key: value } })
+    }
 
     let pass = vec![
-        ("var React, App; <App />;", None),
-        ("var React; <img />;", None),
-        ("var React; <>fragment</>;", None),
-        ("var React; <x-gif />;", None),
-        ("var React, App, a=1; <App attr={a} />;", None),
-        ("var React, App, a=1; function elem() { return <App attr={a} />; }", None),
-        ("var React, App; <App />;", None),
+        ("var React, App; <App />;", None, None),
+        ("var React; <img />;", None, None),
+        ("var React; <>fragment</>;", None, None),
+        ("var React; <x-gif />;", None, None),
+        ("var React, App, a=1; <App attr={a} />;", None, None),
+        ("var React, App, a=1; function elem() { return <App attr={a} />; }", None, None),
+        ("var React, App; <App />;", None, None),
         (
             "
 			        import React from 'react/addons';
@@ -86,17 +96,31 @@ fn test() {
 			        export default Button;
 			      ",
             None,
+            None,
         ),
-        ("var React, a = <img />;", None),
+        ("var React, a = <img />;", None, None),
+        ("/** @jsxRuntime automatic */\nvar a = <img />;", None, None),
+        ("/** @jsxImportSource preact */\nvar a = <>fragment</>;", None, None),
+        ("/** @jsx h */\nimport { h } from 'preact';\nvar a = <img />;", None, None),
+        ("var a = <App />;", None, Some(settings("runtime", "automatic"))),
+        ("var h, a = <img />;", None, Some(settings("pragma", "h"))),
     ];
 
     let fail = vec![
-        ("var App, a = <App />;", None),
-        ("var a = <App />;", None),
-        ("var a = <img />;", None),
-        ("var a = <>fragment</>;", None),
-        ("var Foo, a = <img />;", None),
+        ("var App, a = <App />;", None, None),
+        ("var a = <App />;", None, None),
+        ("var a = <img />;", None, None),
+        ("var a = <>fragment</>;", None, None),
+        ("var Foo, a = <img />;", None, None),
+        ("/** @jsx h */\nvar React, a = <img />;", None, None),
+        ("/** @jsxRuntime classic */\nvar a = <img />;", None, None),
+        ("var React, a = <img />;", None, Some(settings("pragma", "h"))),
+        (
+            "/** @jsxRuntime classic */\nvar a = <img />;",
+            None,
+            Some(settings("runtime", "automatic")),
+        ),
     ];
 
-    Tester::new(ReactInJsxScope::NAME, pass, fail).test_and_snapshot();
+    Tester::new_with_settings(ReactInJsxScope::NAME, pass, fail).test_and_snapshot();
 }
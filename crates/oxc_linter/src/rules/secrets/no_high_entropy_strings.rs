@@ -0,0 +1,178 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use regex::Regex;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, parse_secrets_allowlist, string_values, OptionType},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("secrets(no-high-entropy-strings): String with an entropy of {0:.2} bits per character looks like a secret")]
+#[diagnostic(
+    severity(warning),
+    help("Load secrets from the environment or a secret manager, or add the value to the `allowlist`")
+)]
+struct NoHighEntropyStringsDiagnostic(f64, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct NoHighEntropyStrings(Box<NoHighEntropyStringsConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoHighEntropyStringsConfig {
+    /// Shorter strings are not checked, defaults to 20.
+    min_length: usize,
+    /// Shannon entropy in bits per character above which a string is reported, defaults to 4.
+    threshold: f64,
+    /// Regular expressions of string values which are not secrets.
+    allowlist: Vec<Regex>,
+}
+
+impl Default for NoHighEntropyStrings {
+    fn default() -> Self {
+        Self(Box::new(NoHighEntropyStringsConfig {
+            min_length: 20,
+            threshold: 4.0,
+            allowlist: vec![],
+        }))
+    }
+}
+
+impl std::ops::Deref for NoHighEntropyStrings {
+    type Target = NoHighEntropyStringsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows string literals which look like random tokens, i.e. long strings of base64 or
+    /// hex characters with a high Shannon entropy.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// API keys, passwords and signing secrets committed to a repository are readable by everyone
+    /// with access to it. Secrets without a known format are only recognizable by their
+    /// randomness.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// const stripe = new Stripe("9fQ2kLx7ZpR4tVb8NwYc3HdJ6mAe1Su0");
+    /// ```
+    NoHighEntropyStrings,
+    suspicious
+);
+
+impl Rule for NoHighEntropyStrings {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let default = Self::default();
+        let min_length = config
+            .and_then(|config| config.get("minLength"))
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|min_length| usize::try_from(min_length).ok())
+            .unwrap_or(default.min_length);
+        let threshold = config
+            .and_then(|config| config.get("threshold"))
+            .and_then(serde_json::Value::as_f64)
+            .unwrap_or(default.threshold);
+        Self(Box::new(NoHighEntropyStringsConfig {
+            min_length,
+            threshold,
+            allowlist: parse_secrets_allowlist(&value),
+        }))
+    }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[
+                ("minLength", OptionType::Count),
+                ("threshold", OptionType::Number),
+                ("allowlist", OptionType::Patterns),
+            ],
+        )
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        for (value, span) in string_values(node) {
+            if value.len() < self.min_length || !is_token(value) {
+                continue;
+            }
+            let entropy = shannon_entropy(value);
+            if entropy <= self.threshold || self.allowlist.iter().any(|regex| regex.is_match(value))
+            {
+                continue;
+            }
+            ctx.diagnostic(NoHighEntropyStringsDiagnostic(entropy, span));
+        }
+    }
+}
+
+/// Only the characters of base64, base64url and hex encoded values, so that sentences, URLs and
+/// paths are not reported.
+fn is_token(value: &str) -> bool {
+    !value.contains("://")
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'=' | b'_' | b'-'))
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn shannon_entropy(value: &str) -> f64 {
+    let mut counts = [0usize; 256];
+    for b in value.bytes() {
+        counts[b as usize] += 1;
+    }
+    let len = value.len() as f64;
+    counts
+        .iter()
+        .filter(|count| **count > 0)
+        .map(|count| {
+            let p = *count as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("const name = 'short';", None),
+        ("const message = 'The quick brown fox jumps over the lazy dog';", None),
+        ("const url = 'https://example.com/9fQ2kLx7ZpR4tVb8NwYc3HdJ';", None),
+        ("const repeated = 'aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa';", None),
+        ("const name = 'use-sync-external-store-shim';", None),
+        (
+            "const key = '9fQ2kLx7ZpR4tVb8NwYc3HdJ6mAe1Su0';",
+            Some(serde_json::json!([{ "allowlist": ["^9fQ2"] }])),
+        ),
+        (
+            "const key = '9fQ2kLx7ZpR4tVb8NwYc3HdJ6mAe1Su0';",
+            Some(serde_json::json!([{ "threshold": 5.5 }])),
+        ),
+        ("const key = 'kLx7ZpR4tVb8';", None),
+    ];
+
+    let fail = vec![
+        ("const key = '9fQ2kLx7ZpR4tVb8NwYc3HdJ6mAe1Su0';", None),
+        ("fetch(url, { headers: { Authorization: `Bearer ${'x'}` + 'c2VjcmV0LXRva2VuLTk4NzY1NDMyMQ==' } });", None),
+        (
+            "const key = 'kLx7ZpR4tVb8';",
+            Some(serde_json::json!([{ "minLength": 8, "threshold": 3.5 }])),
+        ),
+    ];
+
+    Tester::new(NoHighEntropyStrings::NAME, pass, fail).test_and_snapshot();
+}
@@ -6,7 +6,11 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use regex::Regex;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 pub enum BanTsCommentDiagnostic {
@@ -135,6 +139,25 @@ impl Rule for BanTsComment {
         }))
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        let config = value.get(0);
+        check_properties(config, &[("minimumDescriptionLength", OptionType::Count)])?;
+        for directive in ["ts-expect-error", "ts-ignore", "ts-nocheck", "ts-check"] {
+            match config.and_then(|config| config.get(directive)) {
+                Some(object @ serde_json::Value::Object(_)) => {
+                    check_properties(Some(object), &[("descriptionFormat", OptionType::Pattern)])?
+                }
+                Some(value) if DirectiveConfig::from_json(value).is_none() => {
+                    return Err(format!(
+                        r#"expected "{directive}" to be a boolean, "allow-with-description" or an object"#
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let comments = ctx.semantic().trivias().comments();
         for (start, comment) in comments {
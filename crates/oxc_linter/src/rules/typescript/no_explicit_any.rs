@@ -1,4 +1,7 @@
-use crate::Fix;
+use crate::{
+    utils::{check_properties, OptionType},
+    Fix,
+};
 use oxc_ast::AstKind;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -85,7 +88,8 @@ declare_oxc_lint!(
     /// Whether to enable auto-fixing in which the `any` type is converted to the `unknown` type.
     /// `false` by default.
     NoExplicitAny,
-    restriction
+    restriction,
+    fix = unsafe
 );
 
 impl Rule for NoExplicitAny {
@@ -111,6 +115,13 @@ impl Rule for NoExplicitAny {
 
         Self { fix_to_unknown, ignore_rest_args }
     }
+
+    fn validate_configuration(value: &Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[("fixToUnknown", OptionType::Boolean), ("ignoreRestArgs", OptionType::Boolean)],
+        )
+    }
 }
 
 impl NoExplicitAny {
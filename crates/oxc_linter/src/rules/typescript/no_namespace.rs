@@ -9,7 +9,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("typescript-eslint(no-namespace): ES2015 module syntax is preferred over namespaces.")]
@@ -61,6 +66,16 @@ impl Rule for NoNamespace {
         }
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[
+                ("allowDeclarations", OptionType::Boolean),
+                ("allowDefinitionFiles", OptionType::Boolean),
+            ],
+        )
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::TSModuleDeclaration(declaration) = node.kind() else { return };
         let TSModuleDeclarationName::Identifier(ident) = &declaration.id else { return };
@@ -36,7 +36,8 @@ declare_oxc_lint!(
     /// let foo = { bar: 'baz' as 'baz' };
     /// ```
     PreferAsConst,
-    correctness
+    correctness,
+    fix = safe
 );
 
 impl Rule for PreferAsConst {
@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// const foo = '\cA';
     /// ```
     EscapeCase,
-    pedantic
+    pedantic,
+    fix = safe
 );
 
 fn is_hex_char(c: char) -> bool {
@@ -16,7 +16,7 @@ use oxc_syntax::operator::{BinaryOperator, LogicalOperator};
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_boolean_ancestor, is_boolean_node},
+    utils::{check_properties, get_boolean_ancestor, is_boolean_node, OptionType},
     AstNode, Fix,
 };
 
@@ -75,7 +75,8 @@ declare_oxc_lint!(
     /// const isEmpty = foo.length === 0;
     /// ```
     ExplicitLengthCheck,
-    pedantic
+    pedantic,
+    fix = unsafe
 );
 fn is_literal(expr: &Expression, value: f64) -> bool {
     matches!(expr, Expression::NumberLiteral(lit) if (lit.value - value).abs() < f64::EPSILON)
@@ -298,6 +299,13 @@ impl Rule for ExplicitLengthCheck {
                 .unwrap_or_default(),
         }
     }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(
+            value.get(0),
+            &[("non-zero", OptionType::OneOf(&["greater-than", "not-equal"]))],
+        )
+    }
 }
 
 #[test]
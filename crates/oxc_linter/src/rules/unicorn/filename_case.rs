@@ -7,7 +7,11 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use serde_json::Value;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(filename-case): Filename should not be in {1} case")]
@@ -78,6 +82,27 @@ impl Rule for FilenameCase {
         }
     }
 
+    fn validate_configuration(value: &Value) -> Result<(), String> {
+        const CASES: &[&str] =
+            &["kebabCase", "camelCase", "snakeCase", "pascalCase", "underscoreCase"];
+        check_properties(value.get(0), &[])?;
+        match value.get(0).and_then(|config| config.get("cases")) {
+            None => Ok(()),
+            Some(case) if OptionType::OneOf(CASES).accepts(case) => Ok(()),
+            Some(Value::Object(cases))
+                if cases.iter().all(|(case, enabled)| {
+                    CASES.contains(&case.as_str()) && enabled.is_boolean()
+                }) =>
+            {
+                Ok(())
+            }
+            Some(_) => Err(format!(
+                r#"expected "cases" to be {} or an object of them with booleans"#,
+                OptionType::OneOf(CASES).expected()
+            )),
+        }
+    }
+
     fn run_once<'a>(&self, ctx: &LintContext<'_>) {
         let Some(filename) = ctx.file_path().file_stem().and_then(|s| s.to_str()) else { return };
 
@@ -1,12 +1,19 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{
+    ast::{
+        Argument, ArrowExpression, BindingPatternKind, CallExpression, Expression, Function,
+        IdentifierReference, ReturnStatement,
+    },
+    AstKind, Visit,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
 
-use crate::{ast_util::is_method_call, context::LintContext, rule::Rule, AstNode};
+use crate::{ast_util::is_method_call, context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 use phf::phf_set;
 
@@ -43,8 +50,13 @@ declare_oxc_lint!(
     /// const foo = [1, 2, 3];
     /// for (const element of foo) { /* ... */ }
     /// ```
+    ///
+    /// The fix rewrites a `forEach` statement with an arrow function of a single parameter and
+    /// without `return`. It is unsafe as the receiver may not be an array, e.g. `Map#forEach`
+    /// iterates the values while `for…of` iterates the entries of a `Map`.
     NoArrayForEach,
     restriction,
+    fix = unsafe
 );
 
 impl Rule for NoArrayForEach {
@@ -86,7 +98,112 @@ impl Rule for NoArrayForEach {
                 return;
             };
 
-            ctx.diagnostic(NoArrayForEachDiagnostic(span));
+            let diagnostic = NoArrayForEachDiagnostic(span);
+            match for_of_fix(node, call_expr, object, ctx) {
+                Some(fix) => ctx.diagnostic_with_fix(diagnostic, || fix),
+                None => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+}
+
+/// `array.forEach((element) => { ... });` to `for (const element of array) { ... }`
+fn for_of_fix<'a>(
+    node: &AstNode<'a>,
+    call_expr: &CallExpression<'a>,
+    object: &Expression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<Fix<'a>> {
+    let AstKind::ExpressionStatement(stmt) = ctx.nodes().parent_kind(node.id())? else {
+        return None;
+    };
+    if call_expr.optional || call_expr.callee.get_member_expr()?.optional() {
+        return None;
+    }
+    let [Argument::Expression(Expression::ArrowExpression(arrow))] = call_expr.arguments.as_slice()
+    else {
+        return None;
+    };
+    if arrow.r#async || arrow.params.rest.is_some() || arrow.return_type.is_some() {
+        return None;
+    }
+    let [param] = arrow.params.items.as_slice() else { return None };
+    let BindingPatternKind::BindingIdentifier(ident) = &param.pattern.kind else { return None };
+    if param.pattern.type_annotation.is_some() || contains_return(arrow) {
+        return None;
+    }
+    if !matches!(
+        object,
+        Expression::Identifier(_)
+            | Expression::MemberExpression(_)
+            | Expression::CallExpression(_)
+            | Expression::ArrayExpression(_)
+            | Expression::ThisExpression(_)
+    ) || references_name(object, ident.name.as_str())
+    {
+        return None;
+    }
+
+    let symbol_id = ident.symbol_id.get()?;
+    let is_reassigned =
+        ctx.semantic().symbol_references(symbol_id).any(oxc_semantic::Reference::is_write);
+    let kind = if is_reassigned { "let" } else { "const" };
+    let head =
+        format!("for ({kind} {} of {}) ", ident.name, object.span().source_text(ctx.source_text()));
+    let body = arrow.body.span;
+    let edits = if arrow.expression {
+        let expr = arrow.body.statements.first()?.span();
+        vec![
+            (Span::new(stmt.span.start, expr.start), format!("{head}{{ ")),
+            (Span::new(expr.end, stmt.span.end), "; }".to_string()),
+        ]
+    } else {
+        vec![
+            (Span::new(stmt.span.start, body.start), head),
+            (Span::new(body.end, stmt.span.end), String::new()),
+        ]
+    };
+    Some(Fix::from_edits(ctx.source_text(), edits))
+}
+
+/// `return` in the callback, which cannot be moved into a loop.
+fn contains_return(arrow: &ArrowExpression) -> bool {
+    let mut finder = ReturnFinder { found: false };
+    finder.visit_function_body(&arrow.body);
+    finder.found
+}
+
+struct ReturnFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for ReturnFinder {
+    fn visit_return_statement(&mut self, _stmt: &ReturnStatement<'a>) {
+        self.found = true;
+    }
+
+    // `return` of nested functions stays in them.
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowExpression<'a>) {}
+}
+
+/// `array.forEach((array) => {})` cannot become `for (const array of array) {}`.
+fn references_name(expr: &Expression, name: &str) -> bool {
+    let mut finder = NameFinder { name, found: false };
+    finder.visit_expression(expr);
+    finder.found
+}
+
+struct NameFinder<'n> {
+    name: &'n str,
+    found: bool,
+}
+
+impl<'a, 'n> Visit<'a> for NameFinder<'n> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference) {
+        if ident.name == self.name {
+            self.found = true;
         }
     }
 }
@@ -124,5 +241,45 @@ fn test() {
         r"return foo.forEach(element => {bar(element)});",
     ];
 
-    Tester::new_without_config(NoArrayForEach::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (
+            r"foo.forEach((element) => { bar(element); });",
+            r"for (const element of foo) { bar(element); }",
+            None,
+        ),
+        (
+            r"foo.bar.forEach(element => bar(element))",
+            r"for (const element of foo.bar) { bar(element); }",
+            None,
+        ),
+        (
+            r"foo().forEach((element) => { element = 1; });",
+            r"for (let element of foo()) { element = 1; }",
+            None,
+        ),
+        (
+            r"foo.forEach((element) => { bar(() => { return element; }); });",
+            r"for (const element of foo) { bar(() => { return element; }); }",
+            None,
+        ),
+        (
+            r"foo.forEach((element) => { if (element) return; bar(element); });",
+            r"foo.forEach((element) => { if (element) return; bar(element); });",
+            None,
+        ),
+        (r"foo.forEach((element, index) => {});", r"foo.forEach((element, index) => {});", None),
+        (r"foo.forEach(function(element) {});", r"foo.forEach(function(element) {});", None),
+        (r"foo.forEach(async (element) => {});", r"foo.forEach(async (element) => {});", None),
+        (r"foo.forEach?.((element) => {});", r"foo.forEach?.((element) => {});", None),
+        (
+            r"const a = foo.forEach((element) => {});",
+            r"const a = foo.forEach((element) => {});",
+            None,
+        ),
+        (r"foo[foo].forEach((foo) => {});", r"foo[foo].forEach((foo) => {});", None),
+    ];
+
+    Tester::new_without_config(NoArrayForEach::NAME, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
@@ -10,7 +10,10 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
 use crate::{
-    ast_util::is_method_call, context::LintContext, rule::Rule, utils::is_prototype_property,
+    ast_util::is_method_call,
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, is_prototype_property, OptionType},
     AstNode,
 };
 
@@ -59,6 +62,10 @@ impl Rule for NoArrayReduce {
         Self { allow_simple_operations }
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("allowSimpleOperations", OptionType::Boolean)])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
@@ -46,7 +46,8 @@ declare_oxc_lint!(
     ///
     /// ```
     NoConsoleSpaces,
-    style
+    style,
+    fix = unsafe
 );
 
 impl Rule for NoConsoleSpaces {
@@ -32,7 +32,8 @@ declare_oxc_lint!(
     /// [1,2,3] instanceof Array;
     /// ```
     NoInstanceofArray,
-    pedantic
+    pedantic,
+    fix = unsafe
 );
 
 impl Rule for NoInstanceofArray {
@@ -12,7 +12,13 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::BinaryOperator;
 
-use crate::{ast_util::is_method_call, context::LintContext, rule::Rule, AstNode, Fix};
+use crate::{
+    ast_util::is_method_call,
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+    AstNode, Fix,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(no-null): Disallow the use of the `null` literal")]
@@ -50,7 +56,8 @@ declare_oxc_lint!(
     /// let foo
     /// ```
     NoNull,
-    style
+    style,
+    fix = unsafe
 );
 
 fn match_null_arg(call_expr: &CallExpression, index: usize, span: Span) -> bool {
@@ -170,6 +177,10 @@ impl Rule for NoNull {
         }
     }
 
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("checkStrictEquality", OptionType::Boolean)])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NullLiteral(null_literal) = node.kind() else {
             return;
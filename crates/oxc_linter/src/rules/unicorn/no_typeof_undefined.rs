@@ -7,7 +7,13 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 
-use crate::{ast_util::get_declaration_of_variable, context::LintContext, rule::Rule, AstNode};
+use crate::{
+    ast_util::get_declaration_of_variable,
+    context::LintContext,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(no-typeof-undefined): Compare with `undefined` directly instead of using `typeof`.")]
@@ -78,6 +84,10 @@ impl Rule for NoTypeofUndefined {
 
         Self { check_global_variables }
     }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        check_properties(value.get(0), &[("checkGlobalVariables", OptionType::Boolean)])
+    }
 }
 
 fn is_global_variable(ident: &Expression, ctx: &LintContext) -> bool {
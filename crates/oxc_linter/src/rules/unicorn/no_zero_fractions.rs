@@ -44,7 +44,8 @@ declare_oxc_lint!(
     /// const foo = 1.1;
     /// ```
     NoZeroFractions,
-    style
+    style,
+    fix = safe
 );
 
 impl Rule for NoZeroFractions {
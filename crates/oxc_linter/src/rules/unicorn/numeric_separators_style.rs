@@ -11,7 +11,13 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use regex::Regex;
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::Fix,
+    rule::Rule,
+    utils::{check_properties, OptionType},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(numeric-separators-style): Invalid group length in numeric value.")]
@@ -84,7 +90,8 @@ declare_oxc_lint!(
     /// ];
     /// ```
     NumericSeparatorsStyle,
-    style
+    style,
+    fix = safe
 );
 
 impl Rule for NumericSeparatorsStyle {
@@ -148,6 +155,18 @@ impl Rule for NumericSeparatorsStyle {
 
         Self(Box::new(cfg))
     }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        let config = value.get(0);
+        check_properties(config, &[("onlyIfContainsSeparator", OptionType::Boolean)])?;
+        for base in ["binary", "hexadecimal", "number", "octal"] {
+            check_properties(
+                config.and_then(|config| config.get(base)),
+                &[("minimumDigits", OptionType::Count), ("groupLength", OptionType::Count)],
+            )?;
+        }
+        Ok(())
+    }
 }
 
 impl NumericSeparatorsStyle {
@@ -9,7 +9,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
 
-use crate::{context::LintContext, rule::Rule, utils::NODE_BUILTINS_MODULE, AstNode};
+use crate::{context::LintContext, fixer::Fix, rule::Rule, utils::NODE_BUILTINS_MODULE, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(prefer-node-protocol): Prefer using the `node:` protocol when importing Node.js builtin modules.")]
@@ -32,7 +32,8 @@ declare_oxc_lint!(
     /// import fs from "node:fs";
     /// ```
     PreferNodeProtocol,
-    style
+    style,
+    fix = safe
 );
 
 impl Rule for PreferNodeProtocol {
@@ -70,7 +71,11 @@ impl Rule for PreferNodeProtocol {
             return;
         }
 
-        ctx.diagnostic(PreferNodeProtocolDiagnostic(span, string_lit_value.to_string()));
+        ctx.diagnostic_with_fix(
+            PreferNodeProtocolDiagnostic(span, string_lit_value.to_string()),
+            // after the opening quote
+            || Fix::new("node:", Span::new(span.start + 1, span.start + 1)),
+        );
     }
 }
 
@@ -131,5 +136,14 @@ fn test() {
         r"await import('assert/strict')",
     ];
 
-    Tester::new_without_config(PreferNodeProtocol::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r#"import fs from "fs";"#, r#"import fs from "node:fs";"#, None),
+        (r"const fs = require('fs/promises')", r"const fs = require('node:fs/promises')", None),
+        (r#"export {promises} from "fs";"#, r#"export {promises} from "node:fs";"#, None),
+        (r"await import('assert/strict')", r"await import('node:assert/strict')", None),
+    ];
+
+    Tester::new_without_config(PreferNodeProtocol::NAME, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
@@ -9,7 +9,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(prefer-optional-catch-binding): Prefer omitting the catch binding parameter if it is unused")]
@@ -41,7 +41,8 @@ declare_oxc_lint!(
     /// } catch { }
     /// ```
     PreferOptionalCatchBinding,
-    style
+    style,
+    fix = safe
 );
 
 impl Rule for PreferOptionalCatchBinding {
@@ -56,7 +57,11 @@ impl Rule for PreferOptionalCatchBinding {
             return;
         }
 
-        ctx.diagnostic(PreferOptionalCatchBindingDiagnostic(catch_param.span()));
+        // `catch (e) {` to `catch {`
+        let binding = Span::new(catch_clause.span.start + 5, catch_clause.body.span.start);
+        ctx.diagnostic_with_fix(PreferOptionalCatchBindingDiagnostic(catch_param.span()), || {
+            Fix::new(" ", binding)
+        });
     }
 }
 
@@ -115,5 +120,14 @@ fn test() {
         r"try {} catch ({cause: {message}}) {}",
     ];
 
-    Tester::new_without_config(PreferOptionalCatchBinding::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r"try {} catch (e) {}", r"try {} catch {}", None),
+        (r"try {} catch(e){}", r"try {} catch {}", None),
+        (r"try {} catch ({message}) {}", r"try {} catch {}", None),
+        (r"try {} catch (e) {} finally {}", r"try {} catch {} finally {}", None),
+    ];
+
+    Tester::new_without_config(PreferOptionalCatchBinding::NAME, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
@@ -51,7 +51,8 @@ declare_oxc_lint!(
     /// const maxValue = Math.max.apply(Math, numbers);
     /// ```
     PreferPrototypeMethods,
-    pedantic
+    pedantic,
+    fix = safe
 );
 
 impl Rule for PreferPrototypeMethods {
@@ -1,4 +1,7 @@
-use oxc_ast::{ast::MemberExpression, AstKind};
+use oxc_ast::{
+    ast::{Argument, CallExpression, Expression, MemberExpression},
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -6,7 +9,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(prefer-string-slice): Prefer String#slice() over String#{1}()")]
@@ -29,7 +32,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     PreferStringSlice,
-    pedantic
+    pedantic,
+    fix = safe
 );
 
 impl Rule for PreferStringSlice {
@@ -50,7 +54,42 @@ impl Rule for PreferStringSlice {
             _ => return,
         };
 
-        ctx.diagnostic(PreferStringSliceDiagnostic(span, name.clone()));
+        let diagnostic = PreferStringSliceDiagnostic(span, name.clone());
+        match slice_arguments(name.as_str(), call_expr) {
+            Some(arguments) => ctx.diagnostic_with_fix(diagnostic, || {
+                let rename = (span, "slice".to_string());
+                Fix::from_edits(ctx.source_text(), std::iter::once(rename).chain(arguments))
+            }),
+            None => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// Edits of the arguments for `slice`, only for arguments which are non-negative integer
+/// literals, where the result of `slice` is known to be the same.
+fn slice_arguments(name: &str, call_expr: &CallExpression) -> Option<Vec<(Span, String)>> {
+    let mut arguments = vec![];
+    for argument in &call_expr.arguments {
+        let Argument::Expression(Expression::NumberLiteral(literal)) = argument else {
+            return None;
+        };
+        if literal.value < 0.0 || literal.value.fract() != 0.0 {
+            return None;
+        }
+        arguments.push((literal.span, literal.value));
+    }
+    match (name, arguments.as_slice()) {
+        (_, [] | [_]) => Some(vec![]),
+        // `substr(start, length)` is `slice(start, start + length)`
+        ("substr", [(_, start), (span, length)]) => {
+            Some(vec![(*span, format!("{}", start + length))])
+        }
+        // `substring` swaps its arguments when the start is after the end
+        ("substring", [(_, start), (_, end)]) if start <= end => Some(vec![]),
+        ("substring", [(start_span, start), (end_span, end)]) => {
+            Some(vec![(*start_span, format!("{end}")), (*end_span, format!("{start}"))])
+        }
+        _ => None,
     }
 }
 
@@ -123,5 +162,18 @@ fn test() {
         r"foo.substring((10, bar))",
     ];
 
-    Tester::new_without_config(PreferStringSlice::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r"foo.substr()", r"foo.slice()", None),
+        (r#""foo".substr(1)"#, r#""foo".slice(1)"#, None),
+        (r#""foo".substr(1, 2)"#, r#""foo".slice(1, 3)"#, None),
+        (r#""foo".substring(1, 2)"#, r#""foo".slice(1, 2)"#, None),
+        (r#""foo".substring(2, 1)"#, r#""foo".slice(1, 2)"#, None),
+        (r"foo.substr(start)", r"foo.substr(start)", None),
+        (r#""foo".substr(0, -1)"#, r#""foo".substr(0, -1)"#, None),
+        (r"foo.substring(1, 2, 3)", r"foo.substring(1, 2, 3)", None),
+    ];
+
+    Tester::new_without_config(PreferStringSlice::NAME, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
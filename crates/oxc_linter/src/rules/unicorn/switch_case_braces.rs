@@ -39,7 +39,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     SwitchCaseBraces,
-    style
+    style,
+    fix = safe
 );
 
 impl Rule for SwitchCaseBraces {
@@ -1,9 +1,10 @@
 use std::{
     collections::HashMap,
-    fs,
-    path::Path,
+    io,
+    path::{Path, PathBuf},
     rc::Rc,
     sync::{Arc, Condvar, Mutex},
+    time::Instant,
 };
 
 use dashmap::DashMap;
@@ -11,13 +12,27 @@ use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
 use rustc_hash::FxHashSet;
 
 use oxc_allocator::Allocator;
+use oxc_ast::{ast::CallExpression, Visit};
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, FailedToOpenFileError};
 use oxc_parser::Parser;
-use oxc_resolver::{ResolveOptions, Resolver};
-use oxc_semantic::{ModuleRecord, SemanticBuilder};
-use oxc_span::{SourceType, VALID_EXTENSIONS};
-
-use crate::{partial_loader::PartialLoader, Fixer, LintContext, Linter, Message};
+use oxc_resolver::{ResolveOptions, Resolver, TsconfigOptions, TsconfigReferences};
+use oxc_semantic::{JsxOptions, ModuleRecord, SemanticBuilder};
+use oxc_span::{Atom, SourceType, VALID_EXTENSIONS};
+
+use crate::{
+    budget::Deadline,
+    crash::{catch_panic, write_crash_report, CrashReportError},
+    file_system::{LintFileSystem, OsFileSystem},
+    jsx_options::tsconfig_jsx_options,
+    lint_cache::LintCache,
+    package_json::nearest_package_json,
+    partial_loader::PartialLoader,
+    pnp::PnpManifest,
+    resolve_cache::ResolveCache,
+    skipped::is_binary,
+    utils::{require_specifier, NODE_BUILTINS_MODULE},
+    Fixer, LintContext, LintSettings, Linter, Message, ResolverSettings, SkipReason,
+};
 
 #[derive(Clone)]
 pub struct LintService {
@@ -26,13 +41,24 @@ pub struct LintService {
 
 impl LintService {
     pub fn new(cwd: Box<Path>, paths: &[Box<Path>], linter: Linter) -> Self {
-        let runtime = Arc::new(Runtime::new(cwd, paths, linter));
+        Self::with_file_system(cwd, paths, linter, Arc::new(OsFileSystem::default()))
+    }
+
+    /// Read the files to lint from `file_system` instead of the disk,
+    /// e.g. a [crate::MemoryFileSystem] of generated code.
+    pub fn with_file_system(
+        cwd: Box<Path>,
+        paths: &[Box<Path>],
+        linter: Linter,
+        file_system: Arc<dyn LintFileSystem>,
+    ) -> Self {
+        let runtime = Arc::new(Runtime::new(cwd, paths, linter, file_system));
         Self { runtime }
     }
 
     #[cfg(test)]
     pub(crate) fn from_linter(cwd: Box<Path>, paths: &[Box<Path>], linter: Linter) -> Self {
-        let runtime = Arc::new(Runtime::new(cwd, paths, linter));
+        let runtime = Arc::new(Runtime::new(cwd, paths, linter, Arc::new(OsFileSystem::default())));
         Self { runtime }
     }
 
@@ -51,7 +77,36 @@ impl LintService {
             .iter()
             .par_bridge()
             .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
+        if let Some(resolve_cache) = &self.runtime.resolve_cache {
+            // The cache only saves work, a run does not fail because it cannot be written.
+            let _ = resolve_cache.save();
+        }
+        self.runtime.save_lint_cache();
+        tx_error.send(None).unwrap();
+    }
+
+    /// Lint `paths` while they are still being produced, e.g. by a directory walk, and return the
+    /// number of linted files.
+    ///
+    /// Only for linters without the import plugin, which needs all paths upfront to tell the
+    /// linted modules apart from their dependencies.
+    ///
+    /// # Panics
+    pub fn run_stream<I>(&self, paths: I, tx_error: &DiagnosticSender) -> usize
+    where
+        I: IntoIterator<Item = Box<Path>>,
+        I::IntoIter: Send,
+    {
+        debug_assert!(!self.runtime.linter.options().import_plugin);
+        let number_of_files = paths
+            .into_iter()
+            .filter(|path| !self.runtime.linter.is_ignored(path))
+            .par_bridge()
+            .map(|path| self.runtime.process_path(&path, tx_error))
+            .count();
+        self.runtime.save_lint_cache();
         tx_error.send(None).unwrap();
+        number_of_files
     }
 
     /// For tests
@@ -68,6 +123,7 @@ impl LintService {
             .iter()
             .flat_map(|path| {
                 let source_type = SourceType::from_path(path).unwrap();
+                let source_type = self.runtime.linter.source_type_for(path, source_type);
                 self.runtime.init_cache_state(path);
                 self.runtime.process_source(
                     path,
@@ -107,55 +163,212 @@ pub struct Runtime {
     /// All paths to lint
     paths: FxHashSet<Box<Path>>,
     linter: Linter,
-    resolver: Resolver,
+    file_system: Arc<dyn LintFileSystem>,
+    /// Resolver used when there is no tsconfig
+    resolver: Arc<Resolver>,
+    /// `import/resolver` of the settings, for all resolvers
+    resolver_settings: ResolverSettings,
+    /// Resolvers keyed by the tsconfig they load `paths` from
+    tsconfig_resolvers: DashMap<Box<Path>, Arc<Resolver>>,
+    /// Yarn Plug'n'Play manifest, for repositories without `node_modules`
+    pnp: Option<PnpManifest>,
+    /// Resolutions of previous runs
+    resolve_cache: Option<ResolveCache>,
+    /// Files without diagnostics in previous runs
+    lint_cache: Option<LintCache>,
     module_map: ModuleMap,
     cache_state: CacheState,
-    partial_vue_loader: PartialLoader,
 }
 
 impl Runtime {
-    fn new(cwd: Box<Path>, paths: &[Box<Path>], linter: Linter) -> Self {
+    fn new(
+        cwd: Box<Path>,
+        paths: &[Box<Path>],
+        linter: Linter,
+        file_system: Arc<dyn LintFileSystem>,
+    ) -> Self {
+        let pnp = if linter.options().import_plugin { PnpManifest::find(&cwd) } else { None };
+        let resolve_cache = linter
+            .options()
+            .resolve_cache
+            .as_ref()
+            .filter(|_| linter.options().import_plugin)
+            .map(|path| ResolveCache::load(cwd.join(path)));
+        let lint_cache = linter
+            .options()
+            .cache
+            .as_ref()
+            .filter(|_| !linter.options().import_plugin)
+            .map(|path| LintCache::load(cwd.join(path), &linter));
+        // The settings of the config for the whole project, resolvers are not per file
+        let resolver_settings = linter.settings_for(&cwd).resolver().clone();
         Self {
             cwd,
-            paths: paths.iter().cloned().collect(),
+            paths: paths.iter().filter(|path| !linter.is_ignored(path)).cloned().collect(),
             linter,
-            resolver: Self::resolver(),
+            file_system,
+            resolver: Arc::new(Self::resolver(&resolver_settings, None)),
+            resolver_settings,
+            tsconfig_resolvers: DashMap::default(),
+            pnp,
+            resolve_cache,
+            lint_cache,
             module_map: ModuleMap::default(),
             cache_state: CacheState::default(),
-            partial_vue_loader: PartialLoader::Vue,
         }
     }
 
-    fn resolver() -> Resolver {
-        Resolver::new(ResolveOptions {
+    /// `browser`, `conditionNames` and `mainFields` are read from `import/resolver` of the settings
+    fn resolver(settings: &ResolverSettings, tsconfig: Option<PathBuf>) -> Resolver {
+        Resolver::new(settings.resolve_options(ResolveOptions {
             extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+            // `./foo.js` names `foo.ts` in TypeScript files, as written for ES modules
+            extension_alias: vec![
+                (".js".into(), vec![".ts".into(), ".tsx".into(), ".js".into()]),
+                (".mjs".into(), vec![".mts".into(), ".mjs".into()]),
+                (".cjs".into(), vec![".cts".into(), ".cjs".into()]),
+            ],
+            tsconfig: tsconfig.map(|config_file| TsconfigOptions {
+                config_file,
+                references: TsconfigReferences::Auto,
+            }),
             ..ResolveOptions::default()
-        })
+        }))
+    }
+
+    /// The resolver for files in `dir`, loading `baseUrl` and `paths` from the configured tsconfig
+    /// or else from the nearest `tsconfig.json`. `extends` chains are followed by the resolver.
+    fn resolver_for(&self, dir: &Path) -> Arc<Resolver> {
+        let Some(tsconfig) = self.tsconfig_for(dir) else { return Arc::clone(&self.resolver) };
+        let resolver = self
+            .tsconfig_resolvers
+            .entry(tsconfig.clone().into_boxed_path())
+            .or_insert_with(|| Arc::new(Self::resolver(&self.resolver_settings, Some(tsconfig))));
+        Arc::clone(&resolver)
+    }
+
+    fn tsconfig_for(&self, dir: &Path) -> Option<PathBuf> {
+        self.linter.options().tsconfig.as_ref().map_or_else(
+            || dir.ancestors().map(|dir| dir.join("tsconfig.json")).find(|path| path.is_file()),
+            |tsconfig| Some(self.cwd.join(tsconfig)),
+        )
+    }
+
+    /// JSX options of the file at `path`, from the settings or else from its tsconfig.
+    fn jsx_options_for(
+        &self,
+        path: &Path,
+        source_type: SourceType,
+        settings: &LintSettings,
+    ) -> JsxOptions {
+        if !source_type.is_jsx() {
+            return JsxOptions::default();
+        }
+        let tsconfig = path.parent().and_then(|dir| self.tsconfig_for(dir));
+        settings.jsx_options(
+            tsconfig.map_or_else(JsxOptions::default, |tsconfig| tsconfig_jsx_options(&tsconfig)),
+        )
     }
 
-    fn get_source_type_and_text(&self, path: &Path) -> Option<Result<(SourceType, String), Error>> {
+    /// Resolve `specifier` imported from a file in `dir`, from the resolve cache when possible.
+    fn resolve(&self, resolver: &Resolver, dir: &Path, specifier: &str) -> Option<PathBuf> {
+        let Some(resolve_cache) = &self.resolve_cache else {
+            return self.resolve_uncached(resolver, dir, specifier);
+        };
+        if let Some(path) = resolve_cache.get(dir, specifier) {
+            return Some(path);
+        }
+        let path = self.resolve_uncached(resolver, dir, specifier)?;
+        // The probed directories are stamped by the cache, the configs are stamped on their own
+        let files =
+            [nearest_package_json(&path), nearest_package_json(dir), self.tsconfig_for(dir)];
+        resolve_cache.insert(dir, specifier, &path, files.into_iter().flatten());
+        Some(path)
+    }
+
+    /// Bare specifiers go through the PnP manifest first when there is one.
+    fn resolve_uncached(
+        &self,
+        resolver: &Resolver,
+        dir: &Path,
+        specifier: &str,
+    ) -> Option<PathBuf> {
+        if let Some(resolution) =
+            self.pnp.as_ref().and_then(|pnp| pnp.resolve(resolver, dir, specifier))
+        {
+            return resolution.ok().map(|r| r.path().to_path_buf());
+        }
+        resolver.resolve(dir, specifier).ok().map(|r| r.path().to_path_buf())
+    }
+
+    /// Returns the source type with the parser options of the config, the file content and, for
+    /// single file components, the script extracted from it.
+    #[allow(clippy::type_complexity)]
+    fn get_source_type_and_text(
+        &self,
+        path: &Path,
+    ) -> Option<Result<(SourceType, String, Option<String>), Error>> {
         let read_file = |path: &Path| -> Result<String, Error> {
-            fs::read_to_string(path)
-                .map_err(|e| Error::new(FailedToOpenFileError(path.to_path_buf(), e)))
+            self.file_system.read_to_string(path).map_err(|e| {
+                let reason = if e.kind() == io::ErrorKind::InvalidData {
+                    SkipReason::Encoding
+                } else {
+                    SkipReason::Unreadable
+                };
+                self.skip(path, reason);
+                Error::new(FailedToOpenFileError(path.to_path_buf(), e))
+            })
         };
 
         if let Ok(source_type) = SourceType::from_path(path) {
+            let source_type = self.linter.source_type_for(path, source_type);
             match read_file(path) {
-                Ok(source_text) => Some(Ok((source_type, source_text))),
+                Ok(source_text) => Some(Ok((source_type, source_text, None))),
                 Err(e) => Some(Err(e)),
             }
-        } else {
-            let ext = path.extension().and_then(std::ffi::OsStr::to_str)?;
-            let partial_loader = if ext == "vue" { Some(&self.partial_vue_loader) } else { None };
-            let partial_loader = partial_loader?;
-
+        } else if let Some(partial_loader) = path
+            .extension()
+            .and_then(std::ffi::OsStr::to_str)
+            .and_then(PartialLoader::from_extension)
+        {
             let source_text = match read_file(path) {
                 Ok(source_text) => source_text,
                 Err(e) => return Some(Err(e)),
             };
 
             let ret = partial_loader.parse(&source_text);
-            Some(Ok((ret.source_type, ret.source_text)))
+            Some(Ok((ret.source_type, source_text, Some(ret.source_text))))
+        } else if self.is_forced(path) {
+            // The same as a `.js` file
+            let source_type = SourceType::default().with_module(true).with_jsx(true);
+            let source_type = self.linter.source_type_for(path, source_type);
+            match read_file(path) {
+                Ok(source_text) => Some(Ok((source_type, source_text, None))),
+                Err(e) => Some(Err(e)),
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Whether `path` with an unsupported extension is linted because of `force`. Dependencies of
+    /// the import plugin are not, they are skipped as before.
+    fn is_forced(&self, path: &Path) -> bool {
+        let options = self.linter.options();
+        options.force && (!options.import_plugin || self.paths.contains(path))
+    }
+
+    fn save_lint_cache(&self) {
+        if let Some(lint_cache) = &self.lint_cache {
+            // Like the resolve cache, a run does not fail because it cannot be written.
+            let _ = lint_cache.save();
+        }
+    }
+
+    /// Count a file which is not linted, dependencies of the import plugin are not linted anyway.
+    fn skip(&self, path: &Path, reason: SkipReason) {
+        if !self.linter.options().import_plugin || self.paths.contains(path) {
+            self.linter.skipped().add(reason);
         }
     }
 
@@ -163,30 +376,78 @@ impl Runtime {
         if self.init_cache_state(path) {
             return;
         }
+        self.lint_path(path, tx_error);
+        // Files without a module record, e.g. JSON files, files which cannot be read or have
+        // syntax errors, release the importers waiting for them, which find no record.
+        if self.linter.options().import_plugin && !self.module_map.contains_key(path) {
+            self.update_cache_state(path);
+        }
+    }
+
+    fn lint_path(&self, path: &Path, tx_error: &DiagnosticSender) {
         let Some(source_type_and_text) = self.get_source_type_and_text(path) else { return };
-        let (source_type, source_text) = match source_type_and_text {
+        let (source_type, source_text, script_text) = match source_type_and_text {
             Ok(source_text) => source_text,
             Err(e) => {
                 tx_error.send(Some((path.to_path_buf(), vec![e]))).unwrap();
                 return;
             }
         };
+        // Sniffed here rather than in the walk, so that each file is read once
+        if is_binary(source_text.as_bytes()) {
+            self.skip(path, SkipReason::Binary);
+            return;
+        }
+        // Spans of an extracted script are valid in the whole file,
+        // fixes and diagnostics are applied to the component itself.
+        let script_text = script_text.as_deref().unwrap_or(&source_text);
+        if self.lint_cache.as_ref().is_some_and(|cache| cache.is_clean(path, &source_text)) {
+            let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+            tx_error.send(Some((path.to_path_buf(), vec![]))).unwrap();
+            return;
+        }
         let allocator = Allocator::default();
-        let mut messages =
-            self.process_source(path, &allocator, &source_text, source_type, true, tx_error);
+        // Panics of the rules are caught by the linter, these are panics of the parser or the
+        // semantic analysis.
+        let messages = catch_panic(|| {
+            self.process_source(path, &allocator, script_text, source_type, true, tx_error)
+        });
+        let mut messages = match messages {
+            Ok(messages) => messages,
+            Err(panic) => {
+                self.skip(path, SkipReason::Panic);
+                let crash_report_error: Option<Error> =
+                    self.linter.options().crash_report.as_ref().and_then(|report_path| {
+                        write_crash_report(report_path, path, None, &panic, script_text)
+                            .err()
+                            .map(|error| CrashReportError::new(report_path, &error).into())
+                    });
+                let errors: Vec<Error> = std::iter::once(panic.into_internal_error(None).into())
+                    .chain(crash_report_error)
+                    .collect();
+                tx_error.send(Some((path.to_path_buf(), errors))).unwrap();
+                return;
+            }
+        };
 
         if self.linter.options().fix {
-            let fix_result = Fixer::new(&source_text, messages).fix();
-            fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
+            let fix_result = Fixer::new(&source_text, messages)
+                .with_newline(self.linter.options().newline)
+                .fix();
+            self.file_system.write(path, &fix_result.fixed_code).unwrap();
             messages = fix_result.messages;
+            if let Some(lint_cache) = &self.lint_cache {
+                lint_cache.update(path, &fix_result.fixed_code, messages.is_empty());
+            }
+        } else if let Some(lint_cache) = &self.lint_cache {
+            lint_cache.update(path, &source_text, messages.is_empty());
         }
 
-        if !messages.is_empty() {
-            let errors = messages.into_iter().map(|m| m.error).collect();
-            let path = path.strip_prefix(&self.cwd).unwrap_or(path);
-            let diagnostics = DiagnosticService::wrap_diagnostics(path, &source_text, errors);
-            tx_error.send(Some(diagnostics)).unwrap();
-        }
+        // Files without diagnostics are sent too, some output formats list every linted file
+        let errors = messages.into_iter().map(|m| m.error).collect();
+        let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+        let diagnostics = DiagnosticService::wrap_diagnostics(path, &source_text, errors);
+        tx_error.send(Some(diagnostics)).unwrap();
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -199,67 +460,100 @@ impl Runtime {
         check_syntax_errors: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
+        // Parsing and the semantic analysis count towards the time budget of the file too
+        let mut deadline = Deadline::new(&self.linter.options().file_budget);
         let ret = Parser::new(allocator, source_text, source_type)
             .allow_return_outside_function(true)
             .parse();
 
         if !ret.errors.is_empty() {
+            self.skip(path, SkipReason::ParseError);
+        }
+        if !ret.errors.is_empty() || self.linter.options().syntax_only {
             return ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
         };
+        if deadline.exceeded() {
+            self.skip(path, SkipReason::TimeBudget);
+            return vec![self.linter.time_budget_exceeded()];
+        }
 
         let program = allocator.alloc(ret.program);
+        let settings = self.linter.settings_for(path);
 
         // Build the module record to unblock other threads from waiting for too long.
         // The semantic model is not built at this stage.
         let semantic_builder = SemanticBuilder::new(source_text, source_type)
             .with_trivias(ret.trivias)
             .with_check_syntax_error(check_syntax_errors)
+            .with_jsx_options(self.jsx_options_for(path, source_type, &settings))
             .build_module_record(path.to_path_buf(), program);
         let module_record = semantic_builder.module_record();
 
         if self.linter.options().import_plugin {
+            // Linting the dependencies does not count towards the budget of this file
+            let dependencies_start = Instant::now();
             self.module_map
                 .insert(path.to_path_buf().into_boxed_path(), Arc::clone(&module_record));
             self.update_cache_state(path);
 
             // Retrieve all dependency modules from this module.
             let dir = path.parent().unwrap();
+            let resolver = self.resolver_for(dir);
             module_record
                 .requested_modules
                 .keys()
                 .par_bridge()
-                .map_with(&self.resolver, |resolver, specifier| {
-                    resolver.resolve(dir, specifier).ok().map(|r| (specifier, r))
-                })
-                .flatten()
-                .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
-                    let path = resolution.path();
-                    self.process_path(path, tx_error);
-                    if let Some(target_module_record) = self.module_map.get(path) {
+                .map(|specifier| (specifier, self.resolve(&resolver, dir, specifier)))
+                .for_each_with(tx_error, |tx_error, (specifier, path)| {
+                    if path.is_some() || !is_node_builtin(specifier) {
+                        module_record.resolved_modules.insert(specifier.clone(), path.clone());
+                    }
+                    let Some(path) = path else { return };
+                    self.process_path(&path, tx_error);
+                    if let Some(target_module_record) = self.module_map.get(path.as_path()) {
                         module_record
                             .loaded_modules
                             .insert(specifier.clone(), Arc::clone(&target_module_record));
                     }
                 });
 
+            // `require` calls in functions and blocks, which are not requested modules.
+            let mut requires = RequireCollector::default();
+            requires.visit_program(program);
+            for specifier in requires.specifiers {
+                if module_record.resolved_modules.contains_key(&specifier) {
+                    continue;
+                }
+                let path = self.resolve(&resolver, dir, &specifier);
+                if path.is_some() || !is_node_builtin(&specifier) {
+                    module_record.resolved_modules.insert(specifier, path);
+                }
+            }
+
             // Stop if the current module is not marked for lint.
             if !self.paths.contains(path) {
                 return vec![];
             }
+            deadline.postpone(dependencies_start.elapsed());
         }
 
         let semantic_ret = semantic_builder.build(program);
 
         if !semantic_ret.errors.is_empty() {
+            self.skip(path, SkipReason::ParseError);
             return semantic_ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
         };
+        if deadline.exceeded() {
+            self.skip(path, SkipReason::TimeBudget);
+            return vec![self.linter.time_budget_exceeded()];
+        }
 
         let lint_ctx = LintContext::new(
             path.to_path_buf().into_boxed_path(),
             &Rc::new(semantic_ret.semantic),
-            self.linter.get_settings(),
+            settings,
         );
-        self.linter.run(lint_ctx)
+        self.linter.run_with_deadline(lint_ctx, &deadline)
     }
 
     fn init_cache_state(&self, path: &Path) -> bool {
@@ -317,3 +611,70 @@ impl Runtime {
         }
     }
 }
+
+fn is_node_builtin(specifier: &str) -> bool {
+    specifier.starts_with("node:") || NODE_BUILTINS_MODULE.contains(specifier)
+}
+
+/// Specifiers of the `require` calls of a module
+#[derive(Default)]
+struct RequireCollector {
+    specifiers: Vec<Atom>,
+}
+
+impl<'a> Visit<'a> for RequireCollector {
+    fn visit_call_expression(&mut self, expr: &CallExpression<'a>) {
+        if let Some(specifier) = require_specifier(expr) {
+            self.specifiers.push(specifier.value.clone());
+        }
+        for arg in &expr.arguments {
+            self.visit_argument(arg);
+        }
+        self.visit_expression(&expr.callee);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env,
+        path::{Path, PathBuf},
+        sync::mpsc,
+    };
+
+    use super::LintService;
+    use crate::{LintOptions, Linter};
+
+    fn lint(paths: &[Box<Path>], cache: &Path) -> Vec<(PathBuf, usize)> {
+        let options =
+            LintOptions::default().with_import_plugin(true).with_cache(Some(cache.to_path_buf()));
+        let linter = Linter::from_options(options).unwrap();
+        let service =
+            LintService::new(env::current_dir().unwrap().into_boxed_path(), paths, linter);
+        let (tx_error, rx_error) = mpsc::channel();
+        service.run(&tx_error);
+        let mut diagnostics = rx_error
+            .into_iter()
+            .map_while(|message| message.map(|(path, errors)| (path, errors.len())))
+            .filter(|(path, _)| path.ends_with("a.js") || path.ends_with("b.js"))
+            .collect::<Vec<_>>();
+        diagnostics.sort();
+        diagnostics
+    }
+
+    /// Both files import the same JSON file, which has no module record: the second importer
+    /// must not wait for it forever.
+    #[test]
+    fn shared_imports_with_cache() {
+        let dir = env::current_dir().unwrap().join("fixtures/cache");
+        let paths = [dir.join("a.js").into_boxed_path(), dir.join("b.js").into_boxed_path()];
+        let cache = env::temp_dir().join(format!("oxc-service-cache-{}", std::process::id()));
+        let expected = vec![
+            (PathBuf::from("fixtures/cache/a.js"), 0),
+            (PathBuf::from("fixtures/cache/b.js"), 0),
+        ];
+        assert_eq!(lint(&paths, &cache), expected);
+        assert_eq!(lint(&paths, &cache), expected);
+        let _ = std::fs::remove_file(&cache);
+    }
+}
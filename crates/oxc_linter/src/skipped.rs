@@ -0,0 +1,113 @@
+//! Files which were walked but not linted, counted by cause for the summary of a run, so that a
+//! drop of the number of linted files is explained without the verbose logs.
+
+use std::fmt;
+
+use dashmap::DashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SkipReason {
+    /// Larger than `--max-file-size`
+    SizeLimit,
+    /// Binary content with the extension of a source file
+    Binary,
+    /// The file could not be read
+    Unreadable,
+    /// Neither UTF-8 nor UTF-16 with a byte order mark
+    Encoding,
+    /// Syntax or semantic errors, the rules only run on files which parse
+    ParseError,
+    /// A panic of the parser, the semantic analysis or a rule
+    Panic,
+    /// Linting took longer than the time budget of a file
+    TimeBudget,
+}
+
+impl SkipReason {
+    fn description(self) -> &'static str {
+        match self {
+            Self::SizeLimit => "above the size limit",
+            Self::Binary => "with binary content",
+            Self::Unreadable => "which could not be read",
+            Self::Encoding => "with an unsupported encoding",
+            Self::ParseError => "with syntax errors",
+            Self::Panic => "which crashed the linter",
+            Self::TimeBudget => "over the time budget",
+        }
+    }
+}
+
+/// Number of bytes at the start of a file searched for binary content
+pub const BINARY_SNIFF_LEN: usize = 8 * 1024;
+
+/// Same heuristic as git: a NUL byte in the first few kilobytes.
+pub fn is_binary(content: &[u8]) -> bool {
+    content[..content.len().min(BINARY_SNIFF_LEN)].contains(&0)
+}
+
+/// Number of skipped files per [SkipReason]
+#[derive(Debug, Default)]
+pub struct SkippedFiles(DashMap<SkipReason, usize>);
+
+impl SkippedFiles {
+    pub fn add(&self, reason: SkipReason) {
+        *self.0.entry(reason).or_default() += 1;
+    }
+
+    pub fn extend(&self, other: &Self) {
+        for entry in &other.0 {
+            *self.0.entry(*entry.key()).or_default() += *entry.value();
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.0.iter().map(|entry| *entry.value()).sum()
+    }
+
+    /// Counts of the causes with skipped files, in the order of [SkipReason]
+    pub fn counts(&self) -> Vec<(SkipReason, usize)> {
+        let mut counts =
+            self.0.iter().map(|entry| (*entry.key(), *entry.value())).collect::<Vec<_>>();
+        counts.sort_unstable();
+        counts
+    }
+}
+
+/// `1 above the size limit, 2 with syntax errors`
+impl fmt::Display for SkippedFiles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = self
+            .counts()
+            .into_iter()
+            .map(|(reason, count)| format!("{count} {}", reason.description()))
+            .collect::<Vec<_>>();
+        write!(f, "{}", counts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_binary, SkipReason, SkippedFiles, BINARY_SNIFF_LEN};
+
+    #[test]
+    fn summary() {
+        let skipped = SkippedFiles::default();
+        assert_eq!(skipped.total(), 0);
+        skipped.add(SkipReason::ParseError);
+        skipped.add(SkipReason::ParseError);
+        let walk = SkippedFiles::default();
+        walk.add(SkipReason::SizeLimit);
+        skipped.extend(&walk);
+        assert_eq!(skipped.total(), 3);
+        assert_eq!(skipped.to_string(), "1 above the size limit, 2 with syntax errors");
+    }
+
+    #[test]
+    fn binary() {
+        assert!(!is_binary(b"let a = 1;"));
+        assert!(is_binary(b"let a\0 = 1;"));
+        let mut content = vec![b' '; BINARY_SNIFF_LEN];
+        content.push(0);
+        assert!(!is_binary(&content));
+    }
+}
@@ -9,8 +9,8 @@ use oxc_diagnostics::DiagnosticService;
 use serde_json::Value;
 
 use crate::{
-    config::parse_settings, rules::RULES, Fixer, LintOptions, LintService, LintSettings, Linter,
-    RuleEnum,
+    config::parse_settings, rule::FixKind, rules::RULES, Fixer, LintOptions, LintService,
+    LintSettings, Linter, RuleEnum,
 };
 
 #[derive(Eq, PartialEq)]
@@ -106,6 +106,12 @@ impl Tester {
         self
     }
 
+    /// Change the extension of the path, e.g. to `d.ts` for declaration files
+    pub fn change_rule_path_extension(mut self, extension: &str) -> Self {
+        self.rule_path = self.rule_path.with_extension(extension);
+        self
+    }
+
     pub fn with_import_plugin(mut self, yes: bool) -> Self {
         self.import_plugin = yes;
         self
@@ -185,12 +191,15 @@ impl Tester {
             settings.as_ref().map_or_else(LintSettings::default, parse_settings);
         let options = LintOptions::default()
             .with_fix(is_fix)
+            .with_fix_kind(FixKind::Suggestion)
             .with_import_plugin(self.import_plugin)
             .with_jest_plugin(self.jest_plugin)
             .with_jsx_a11y_plugin(self.jsx_a11y_plugin);
         let linter = Linter::from_options(options)
             .unwrap()
             .with_rules(vec![rule])
+            // Every snippet is a test file for the rules of the jest plugin.
+            .with_jest_environment(None)
             .with_settings(lint_settings);
         let path_to_lint = if self.import_plugin {
             self.current_working_directory.join(&self.rule_path)
@@ -0,0 +1,60 @@
+use std::path::PathBuf;
+
+use oxc_ast::ast::{Argument, CallExpression, Expression, StringLiteral};
+use oxc_semantic::ModuleRecord;
+use rustc_hash::FxHashSet;
+
+/// Whether the module exports `name`, including the names of the modules of its
+/// `export * from` declarations, which re-export all names but `default`.
+///
+/// `None` when it is unknown, as a module of an `export *` was not loaded, e.g. a package which
+/// could not be resolved.
+pub fn has_export(module_record: &ModuleRecord, name: &str) -> Option<bool> {
+    has_export_recursive(module_record, name, &mut FxHashSet::default())
+}
+
+fn has_export_recursive(
+    module_record: &ModuleRecord,
+    name: &str,
+    visited: &mut FxHashSet<PathBuf>,
+) -> Option<bool> {
+    // Modules exporting each other with `export *`
+    if !visited.insert(module_record.resolved_absolute_path.clone()) {
+        return Some(false);
+    }
+    if module_record.exported_bindings.contains_key(name) {
+        return Some(true);
+    }
+    if name == "default" {
+        return Some(false);
+    }
+
+    let mut known = true;
+    for export_entry in &module_record.star_export_entries {
+        let remote_module_record = export_entry
+            .module_request
+            .as_ref()
+            .and_then(|module_request| module_record.loaded_modules.get(module_request.name()));
+        let Some(remote_module_record) = remote_module_record else {
+            known = false;
+            continue;
+        };
+        match has_export_recursive(remote_module_record.value(), name, visited) {
+            Some(true) => return Some(true),
+            Some(false) => {}
+            None => known = false,
+        }
+    }
+    known.then_some(false)
+}
+
+/// The specifier of a CommonJS `require('./foo')` call, with a single string literal argument.
+pub fn require_specifier<'a, 'b>(call: &'b CallExpression<'a>) -> Option<&'b StringLiteral> {
+    if !call.callee.is_specific_id("require") || call.arguments.len() != 1 {
+        return None;
+    }
+    match &call.arguments[0] {
+        Argument::Expression(Expression::StringLiteral(specifier)) => Some(specifier),
+        _ => None,
+    }
+}
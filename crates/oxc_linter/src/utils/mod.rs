@@ -1,7 +1,12 @@
+mod import;
 mod jest;
 mod jsx_a11y;
 mod node;
+mod options;
 mod react;
+mod secrets;
 mod unicorn;
 
-pub use self::{jest::*, jsx_a11y::*, node::*, react::*, unicorn::*};
+pub use self::{
+    import::*, jest::*, jsx_a11y::*, node::*, options::*, react::*, secrets::*, unicorn::*,
+};
@@ -0,0 +1,97 @@
+//! Checks for `Rule::validate_configuration`, which reject the options a rule would silently
+//! ignore when reading them, e.g. `curly: ["error", 42]`.
+
+use regex::Regex;
+use serde_json::Value;
+
+/// The type an option is expected to have.
+#[derive(Debug, Clone, Copy)]
+pub enum OptionType {
+    Boolean,
+    /// A non-negative integer
+    Count,
+    Number,
+    String,
+    /// An array of strings
+    Strings,
+    /// A string or an array of strings
+    StringOrStrings,
+    /// A regular expression
+    Pattern,
+    /// An array of regular expressions
+    Patterns,
+    /// One of the listed strings
+    OneOf(&'static [&'static str]),
+}
+
+impl OptionType {
+    pub fn accepts(self, value: &Value) -> bool {
+        match self {
+            Self::Boolean => value.is_boolean(),
+            Self::Count => value.is_u64(),
+            Self::Number => value.is_number(),
+            Self::String => value.is_string(),
+            Self::Strings => {
+                value.as_array().is_some_and(|values| values.iter().all(Value::is_string))
+            }
+            Self::StringOrStrings => value.is_string() || Self::Strings.accepts(value),
+            Self::Pattern => value.as_str().is_some_and(|pattern| Regex::new(pattern).is_ok()),
+            Self::Patterns => value
+                .as_array()
+                .is_some_and(|values| values.iter().all(|v| Self::Pattern.accepts(v))),
+            Self::OneOf(names) => value.as_str().is_some_and(|value| names.contains(&value)),
+        }
+    }
+
+    pub fn expected(self) -> String {
+        match self {
+            Self::Boolean => "a boolean".to_string(),
+            Self::Count => "a non-negative integer".to_string(),
+            Self::Number => "a number".to_string(),
+            Self::String => "a string".to_string(),
+            Self::Strings => "an array of strings".to_string(),
+            Self::StringOrStrings => "a string or an array of strings".to_string(),
+            Self::Pattern => "a regular expression".to_string(),
+            Self::Patterns => "an array of regular expressions".to_string(),
+            Self::OneOf(names) => {
+                let names = names.iter().map(|name| format!("{name:?}")).collect::<Vec<_>>();
+                match names.split_last() {
+                    Some((last, [])) => last.clone(),
+                    Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+                    None => String::new(),
+                }
+            }
+        }
+    }
+}
+
+/// Checks a positional option, e.g. the `"always"` of `yoda: ["error", "always"]`, if it is given.
+pub fn check_option(option: Option<&Value>, expected: OptionType) -> Result<(), String> {
+    match option {
+        Some(option) if !expected.accepts(option) => {
+            Err(format!("expected {}, but got {option}", expected.expected()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks the properties of an object option, if it is given. Properties which are not listed
+/// are left to the rule, as ESLint accepts options which oxlint does not implement yet.
+pub fn check_properties(
+    option: Option<&Value>,
+    properties: &[(&str, OptionType)],
+) -> Result<(), String> {
+    let Some(option) = option else { return Ok(()) };
+    let Some(object) = option.as_object() else {
+        return Err(format!("expected an object, but got {option}"));
+    };
+    for (name, expected) in properties {
+        match object.get(*name) {
+            Some(value) if !expected.accepts(value) => {
+                return Err(format!(r#"expected "{name}" to be {}"#, expected.expected()));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use oxc_ast::AstKind;
+use oxc_span::Span;
+use regex::Regex;
+
+use crate::AstNode;
+
+/// `allowlist` of the rules of the secrets plugin, regular expressions matching string values
+/// which are not secrets, e.g. the example keys of a documentation.
+pub fn parse_secrets_allowlist(value: &serde_json::Value) -> Vec<Regex> {
+    value
+        .get(0)
+        .and_then(|config| config.get("allowlist"))
+        .and_then(serde_json::Value::as_array)
+        .map(|patterns| {
+            patterns
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .filter_map(|pattern| Regex::new(pattern).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Values and spans of string literals and of the parts of template literals.
+pub fn string_values<'a>(node: &AstNode<'a>) -> Vec<(&'a str, Span)> {
+    match node.kind() {
+        AstKind::StringLiteral(literal) => vec![(literal.value.as_str(), literal.span)],
+        AstKind::TemplateLiteral(template) => {
+            template.quasis.iter().map(|quasi| (quasi.value.raw.as_str(), quasi.span)).collect()
+        }
+        _ => vec![],
+    }
+}
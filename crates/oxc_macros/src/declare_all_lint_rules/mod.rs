@@ -67,7 +67,7 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
         #(#use_stmts)*
 
         use std::time::{Instant, Duration};
-        use crate::{context::LintContext, rule::{Rule, RuleCategory, RuleMeta}, rule_timer:: RuleTimer, AstNode};
+        use crate::{context::LintContext, rule::{FixKind, Rule, RuleCategory, RuleMeta}, rule_timer:: RuleTimer, AstNode};
         use oxc_semantic::SymbolId;
 
         #[derive(Debug, Clone)]
@@ -89,6 +89,12 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn fix(&self) -> FixKind {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::FIX),*
+                }
+            }
+
             pub fn documentation(&self) -> Option<&'static str> {
                 match self {
                     #(Self::#struct_names(_) => #struct_names::documentation()),*
@@ -109,37 +115,50 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
-            pub fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>, print_execution_times: bool) {
+            /// Check the options of the rule in a configuration, without building the rule
+            pub fn validate_json(&self, value: &serde_json::Value) -> Result<(), String> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::validate_configuration(value)),*
+                }
+            }
+
+            /// The execution time of the rule with `print_execution_times`
+            pub fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>, print_execution_times: bool) -> Option<Duration> {
                 let start = print_execution_times.then(|| Instant::now());
-                let result = match self {
+                match self {
                     #(Self::#struct_names(rule) => rule.run(node, ctx)),*
                 };
-                if let Some(start) = start {
-                    RULE_TIMERS.get(self.name()).unwrap().update(&start.elapsed());
-                }
-                result
+                start.map(|start| {
+                    let elapsed = start.elapsed();
+                    RULE_TIMERS.get(self.name()).unwrap().update(&elapsed);
+                    elapsed
+                })
             }
 
-            pub fn run_on_symbol<'a>(&self, symbol_id: SymbolId, ctx: &LintContext<'a>, print_execution_times: bool) {
+            /// The execution time of the rule with `print_execution_times`
+            pub fn run_on_symbol<'a>(&self, symbol_id: SymbolId, ctx: &LintContext<'a>, print_execution_times: bool) -> Option<Duration> {
                 let start = print_execution_times.then(|| Instant::now());
-                let result = match self {
+                match self {
                     #(Self::#struct_names(rule) => rule.run_on_symbol(symbol_id, ctx)),*
                 };
-                if let Some(start) = start {
-                    RULE_TIMERS.get(self.name()).unwrap().update(&start.elapsed());
-                }
-                result
+                start.map(|start| {
+                    let elapsed = start.elapsed();
+                    RULE_TIMERS.get(self.name()).unwrap().update(&elapsed);
+                    elapsed
+                })
             }
 
-            pub fn run_once<'a>(&self, ctx: &LintContext<'a>, print_execution_times: bool) {
+            /// The execution time of the rule with `print_execution_times`
+            pub fn run_once<'a>(&self, ctx: &LintContext<'a>, print_execution_times: bool) -> Option<Duration> {
                 let start = print_execution_times.then(|| Instant::now());
-                let result = match self {
+                match self {
                     #(Self::#struct_names(rule) => rule.run_once(ctx)),*
                 };
-                if let Some(start) = start {
-                    RULE_TIMERS.get(self.name()).unwrap().update(&start.elapsed());
-                }
-                result
+                start.map(|start| {
+                    let elapsed = start.elapsed();
+                    RULE_TIMERS.get(self.name()).unwrap().update(&elapsed);
+                    elapsed
+                })
             }
 
             pub fn execute_time(&self) -> Duration {
@@ -2,6 +2,7 @@ use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{
+    ext::IdentExt,
     parse::{Parse, ParseStream},
     Attribute, Error, Ident, Lit, LitStr, Meta, Result, Token,
 };
@@ -9,6 +10,7 @@ use syn::{
 pub struct LintRuleMeta {
     name: Ident,
     category: Ident,
+    fix: Option<Ident>,
     documentation: String,
     pub used_in_test: bool,
 }
@@ -34,15 +36,27 @@ impl Parse for LintRuleMeta {
         input.parse::<Token!(,)>()?;
         let category = input.parse()?;
 
+        // `fix = safe`, `unsafe` is a keyword
+        let fix = if input.parse::<Token!(,)>().is_ok() && input.peek(Ident) {
+            let key = input.parse::<Ident>()?;
+            if key != "fix" {
+                return Err(Error::new_spanned(key, "expected `fix`"));
+            }
+            input.parse::<Token!(=)>()?;
+            Some(Ident::parse_any(input)?)
+        } else {
+            None
+        };
+
         // Ignore the rest
         input.parse::<TokenStream>()?;
 
-        Ok(Self { name: struct_name, category, documentation, used_in_test: false })
+        Ok(Self { name: struct_name, category, fix, documentation, used_in_test: false })
     }
 }
 
 pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
-    let LintRuleMeta { name, category, documentation, used_in_test } = metadata;
+    let LintRuleMeta { name, category, fix, documentation, used_in_test } = metadata;
     let canonical_name = name.to_string().to_case(Case::Kebab);
     let category = match category.to_string().as_str() {
         "correctness" => quote! { RuleCategory::Correctness },
@@ -54,6 +68,14 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         "nursery" => quote! { RuleCategory::Nursery },
         _ => panic!("invalid rule category"),
     };
+    let fix = fix.map(|fix| match fix.to_string().as_str() {
+        "safe" => quote! { const FIX: crate::rule::FixKind = crate::rule::FixKind::Safe; },
+        "unsafe" => quote! { const FIX: crate::rule::FixKind = crate::rule::FixKind::Unsafe; },
+        "suggestion" => {
+            quote! { const FIX: crate::rule::FixKind = crate::rule::FixKind::Suggestion; }
+        }
+        _ => panic!("invalid fix kind, expected `safe`, `unsafe` or `suggestion`"),
+    });
 
     let import_statement = if used_in_test {
         None
@@ -69,6 +91,8 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             const CATEGORY: RuleCategory = #category;
 
+            #fix
+
             fn documentation() -> Option<&'static str> {
                 Some(#documentation)
             }
@@ -90,3 +114,21 @@ fn parse_attr<const LEN: usize>(path: [&'static str; LEN], attr: &Attribute) ->
     }
     unreachable!()
 }
+
+#[cfg(test)]
+mod test {
+    use super::LintRuleMeta;
+
+    #[test]
+    fn fix_kinds() {
+        for kind in ["safe", "unsafe", "suggestion"] {
+            let meta = syn::parse_str::<LintRuleMeta>(&format!(
+                "/// Documentation\nRuleName, correctness, fix = {kind}"
+            ))
+            .unwrap();
+            assert_eq!(meta.fix.unwrap().to_string(), kind);
+        }
+        let meta = syn::parse_str::<LintRuleMeta>("RuleName, correctness").unwrap();
+        assert!(meta.fix.is_none());
+    }
+}
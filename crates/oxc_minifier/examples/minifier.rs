@@ -1,8 +1,12 @@
 use std::path::Path;
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_minifier::{Minifier, MinifierOptions};
+use oxc_ast::TriviasMap;
+use oxc_codegen::{Codegen, CodegenOptions, CodegenReturn, PreserveComments, SourceMap};
+use oxc_minifier::{
+    ManglePropsOptions, Minifier, MinifierOptions, NameCache, PropertyMangler,
+    ReplaceGlobalDefines, ReplaceGlobalDefinesConfig,
+};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -20,6 +24,12 @@ static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 // create a `test.js`,
 // run `cargo run -p oxc_minifier --example minifier`
 // or `just watch "run -p oxc_minifier --example minifier"`
+// Globals can be replaced with `--define process.env.NODE_ENV='"production"'`
+// License comments are kept with `--legal-comments`
+// The source map of all passes is printed with `--sourcemap`, and composed with the source map of
+// a previous build step with `--input-source-map test.js.map`
+// Properties are mangled with `--mangle-props '^_'`, except the ones of `--reserve-props _keep`,
+// and the mangled names are kept in between runs with `--name-cache names.json`
 
 fn main() {
     let mut args = Arguments::from_env();
@@ -28,29 +38,145 @@ fn main() {
     let mangle = args.contains("--mangle");
     let whitespace = args.contains("--whitespace");
     let twice = args.contains("--twice");
+    let sourcemap = args.contains("--sourcemap");
+    let comments = if args.contains("--legal-comments") {
+        PreserveComments::Legal
+    } else {
+        PreserveComments::None
+    };
+    let defines: Vec<String> = args.values_from_str("--define").unwrap();
+    let mangle_props: Option<String> = args.opt_value_from_str("--mangle-props").unwrap();
+    let reserve_props: Vec<String> = args.values_from_str("--reserve-props").unwrap();
+    let name_cache_path: Option<String> = args.opt_value_from_str("--name-cache").unwrap();
+    let input_source_map_path: Option<String> =
+        args.opt_value_from_str("--input-source-map").unwrap();
+    let defines = defines
+        .iter()
+        .map(|define| define.split_once('=').unwrap_or_else(|| panic!("{define} is not KEY=VALUE")))
+        .collect::<Vec<_>>();
+    let config = ReplaceGlobalDefinesConfig::new(&defines).unwrap();
 
     let path = Path::new(&name);
     let source_text = std::fs::read_to_string(path).unwrap_or_else(|_| panic!("{name} not found"));
     let source_type = SourceType::from_path(path).unwrap();
 
-    let printed = minify(&source_text, source_type, mangle, whitespace);
-    println!("{printed}");
+    let mut property_mangler = mangle_props.map(|regex| {
+        let regex = regex::Regex::new(&regex).unwrap_or_else(|err| panic!("{err}"));
+        let options = ManglePropsOptions::new(regex).with_reserved(reserve_props);
+        let name_cache = name_cache_path.as_deref().map(read_name_cache).unwrap_or_default();
+        PropertyMangler::new(options).with_name_cache(name_cache)
+    });
+
+    let input_source_map = input_source_map_path.as_deref().map(read_source_map);
+
+    let ret = minify(
+        &name,
+        &source_text,
+        source_type,
+        &config,
+        property_mangler.as_mut(),
+        input_source_map,
+        mangle,
+        whitespace,
+        comments,
+    );
+    println!("{}", ret.source_text);
+    let mut source_map = ret.source_map;
 
     if twice {
-        let printed = minify(&printed, source_type, mangle, whitespace);
-        println!("{printed}");
+        // The second pass maps back through the source map of the first pass.
+        let ret = minify(
+            &name,
+            &ret.source_text,
+            source_type,
+            &config,
+            property_mangler.as_mut(),
+            source_map,
+            mangle,
+            whitespace,
+            comments,
+        );
+        println!("{}", ret.source_text);
+        source_map = ret.source_map;
+    }
+
+    if sourcemap {
+        if let Some(source_map) = source_map {
+            println!("{}", source_map.to_json_string());
+        }
+    }
+
+    if let (Some(path), Some(property_mangler)) = (name_cache_path, property_mangler) {
+        write_name_cache(&path, property_mangler.name_cache());
     }
 }
 
-fn minify(source_text: &str, source_type: SourceType, mangle: bool, whitespace: bool) -> String {
+fn read_source_map(path: &str) -> SourceMap {
+    let json = std::fs::read_to_string(path).unwrap_or_else(|_| panic!("{path} not found"));
+    serde_json::from_str(&json).unwrap_or_else(|err| panic!("{path}: {err}"))
+}
+
+/// `{ "props": { "_name": "a" } }`, an empty cache when the file does not exist yet
+fn read_name_cache(path: &str) -> NameCache {
+    let Ok(json) = std::fs::read_to_string(path) else { return NameCache::default() };
+    let value: serde_json::Value =
+        serde_json::from_str(&json).unwrap_or_else(|err| panic!("{path}: {err}"));
+    let props = value.get("props").and_then(serde_json::Value::as_object).into_iter().flatten();
+    props
+        .filter_map(|(name, mangled)| Some((name.clone(), mangled.as_str()?.to_string())))
+        .collect()
+}
+
+fn write_name_cache(path: &str, name_cache: &NameCache) {
+    let props = name_cache
+        .iter()
+        .map(|(name, mangled)| (name.to_string(), serde_json::Value::from(mangled)))
+        .collect::<serde_json::Map<_, _>>();
+    let json = serde_json::json!({ "props": props });
+    std::fs::write(path, serde_json::to_string_pretty(&json).unwrap())
+        .unwrap_or_else(|err| panic!("{path}: {err}"));
+}
+
+#[allow(clippy::too_many_arguments)]
+fn minify(
+    source_name: &str,
+    source_text: &str,
+    source_type: SourceType,
+    config: &ReplaceGlobalDefinesConfig,
+    property_mangler: Option<&mut PropertyMangler>,
+    input_source_map: Option<SourceMap>,
+    mangle: bool,
+    whitespace: bool,
+    comments: PreserveComments,
+) -> CodegenReturn {
     let allocator = Allocator::default();
-    let program = Parser::new(&allocator, source_text, source_type).parse().program;
-    let program = allocator.alloc(program);
-    let options = MinifierOptions { mangle, ..MinifierOptions::default() };
-    Minifier::new(options).build(&allocator, program);
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let trivias = TriviasMap::from(ret.trivias);
+    let program = allocator.alloc(ret.program);
+    ReplaceGlobalDefines::new(&allocator, config.clone()).build(program);
+    if let Some(property_mangler) = property_mangler {
+        property_mangler.build(program);
+    }
+    let mut options = MinifierOptions { mangle, ..MinifierOptions::default() };
+    options.compress.dead_code = true;
+    Minifier::new(options).with_pure_annotations(source_text, &trivias).build(&allocator, program);
     if whitespace {
-        Codegen::<true>::new(source_text.len(), CodegenOptions).build(program)
+        let codegen = Codegen::<true>::new(source_text.len(), CodegenOptions)
+            .with_comments(source_text, &trivias, comments)
+            .with_source_map(source_name, source_text);
+        match input_source_map {
+            Some(input_source_map) => codegen.with_input_source_map(input_source_map),
+            None => codegen,
+        }
+        .build_with_source_map(program)
     } else {
-        Codegen::<false>::new(source_text.len(), CodegenOptions).build(program)
+        let codegen = Codegen::<false>::new(source_text.len(), CodegenOptions)
+            .with_comments(source_text, &trivias, comments)
+            .with_source_map(source_name, source_text);
+        match input_source_map {
+            Some(input_source_map) => codegen.with_input_source_map(input_source_map),
+            None => codegen,
+        }
+        .build_with_source_map(program)
     }
 }
@@ -1,6 +1,6 @@
 #![allow(clippy::unused_self)]
 
-mod ast_util;
+pub(crate) mod ast_util;
 mod fold;
 mod options;
 mod prepass;
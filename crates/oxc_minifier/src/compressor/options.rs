@@ -10,6 +10,12 @@ pub struct CompressOptions {
     /// Default `true`
     pub drop_debugger: bool,
 
+    /// Remove unreachable code, constant branches and unused declarations without side effects.
+    /// See [crate::RemoveDeadCode].
+    ///
+    /// Default `false`
+    pub dead_code: bool,
+
     /// Remove `console.*` statements.
     ///
     /// Default `false`
@@ -41,6 +47,7 @@ impl Default for CompressOptions {
         Self {
             booleans: true,
             drop_debugger: true,
+            dead_code: false,
             drop_console: false,
             evaluate: true,
             join_vars: true,
@@ -55,6 +62,7 @@ impl CompressOptions {
         Self {
             booleans: true,
             drop_debugger: true,
+            dead_code: true,
             drop_console: true,
             evaluate: true,
             join_vars: true,
@@ -67,6 +75,7 @@ impl CompressOptions {
         Self {
             booleans: false,
             drop_debugger: false,
+            dead_code: false,
             drop_console: false,
             evaluate: false,
             join_vars: false,
@@ -2,41 +2,82 @@
 
 mod compressor;
 mod mangler;
+mod plugins;
 
 use oxc_allocator::Allocator;
-use oxc_ast::ast::Program;
+use oxc_ast::{ast::Program, TriviasMap};
+use rustc_hash::FxHashSet;
 
 pub use crate::{
     compressor::{CompressOptions, Compressor},
-    mangler::ManglerBuilder,
+    mangler::{ManglePropsOptions, Mangler, ManglerBuilder, NameCache, PropertyMangler},
+    plugins::{
+        InvalidDefineKey, InvalidDefineValue, RemoveDeadCode, ReplaceGlobalDefines,
+        ReplaceGlobalDefinesConfig,
+    },
 };
 
 #[derive(Debug, Clone, Copy)]
 pub struct MinifierOptions {
     pub mangle: bool,
+    /// Keep the original names of functions and classes when mangling.
+    pub keep_names: bool,
     pub compress: CompressOptions,
 }
 
 impl Default for MinifierOptions {
     fn default() -> Self {
-        Self { mangle: true, compress: CompressOptions::default() }
+        Self { mangle: true, keep_names: false, compress: CompressOptions::default() }
     }
 }
 
 pub struct Minifier {
     options: MinifierOptions,
+    /// Starts of the calls annotated with `/*#__PURE__*/`
+    pure_calls: FxHashSet<u32>,
+    side_effect_free_modules: FxHashSet<String>,
 }
 
 impl Minifier {
     pub fn new(options: MinifierOptions) -> Self {
-        Self { options }
+        Self {
+            options,
+            pure_calls: FxHashSet::default(),
+            side_effect_free_modules: FxHashSet::default(),
+        }
+    }
+
+    /// Remove unused calls annotated with `/*#__PURE__*/` with `compress.dead_code`.
+    /// See [RemoveDeadCode::with_pure_annotations].
+    #[must_use]
+    pub fn with_pure_annotations(mut self, source_text: &str, trivias: &TriviasMap) -> Self {
+        self.pure_calls = plugins::pure_annotations(source_text, trivias);
+        self
+    }
+
+    /// Remove unused imports of these modules with `compress.dead_code`.
+    /// See [RemoveDeadCode::with_side_effect_free_modules].
+    #[must_use]
+    pub fn with_side_effect_free_modules<I: IntoIterator<Item = String>>(
+        mut self,
+        modules: I,
+    ) -> Self {
+        self.side_effect_free_modules = modules.into_iter().collect();
+        self
     }
 
     pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) {
+        if self.options.compress.dead_code {
+            RemoveDeadCode::new(allocator)
+                .with_pure_calls(self.pure_calls)
+                .with_side_effect_free_modules(self.side_effect_free_modules)
+                .build(program);
+        }
         Compressor::new(allocator, self.options.compress).build(program);
-        // if self.options.mangle {
-        // let mangler = ManglerBuilder.build(program);
-        // printer.with_mangler(mangler);
-        // }
+        if self.options.mangle {
+            let mut mangler =
+                ManglerBuilder::default().with_keep_names(self.options.keep_names).build(program);
+            mangler.rename(program);
+        }
     }
 }
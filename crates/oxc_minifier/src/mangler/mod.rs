@@ -1,9 +1,16 @@
+mod properties;
+
 use itertools::Itertools;
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::ast::*;
+use oxc_ast::{ast::*, AstKind, Visit, VisitMut};
 use oxc_index::{index_vec, IndexVec};
-use oxc_semantic::{ReferenceId, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_semantic::{
+    ReferenceId, ScopeId, Semantic, SemanticBuilder, SymbolFlags, SymbolId, SymbolTable,
+};
 use oxc_span::Atom;
+use rustc_hash::FxHashSet;
+
+pub use self::properties::{ManglePropsOptions, NameCache, PropertyMangler};
 
 type Slot = usize;
 
@@ -21,6 +28,30 @@ impl Mangler {
         let symbol_id = self.symbol_table.get_reference(reference_id).symbol_id()?;
         Some(self.symbol_table.get_name(symbol_id))
     }
+
+    /// Write the mangled names back into the AST.
+    ///
+    /// `program` must be the same program this mangler was built from,
+    /// so the symbol and reference ids recorded on the identifiers are still valid.
+    pub fn rename<'a>(&mut self, program: &mut Program<'a>) {
+        self.visit_program(program);
+    }
+}
+
+impl<'a> VisitMut<'a> for Mangler {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier) {
+        if let Some(symbol_id) = ident.symbol_id.get() {
+            ident.name = self.get_symbol_name(symbol_id).clone();
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference) {
+        if let Some(reference_id) = ident.reference_id.get() {
+            if let Some(name) = self.get_reference_name(reference_id) {
+                ident.name = name.clone();
+            }
+        }
+    }
 }
 
 /// # Name Mangler / Symbol Minification
@@ -64,14 +95,36 @@ impl Mangler {
 ///     }
 /// }
 /// ```
-pub struct ManglerBuilder;
+///
+/// Some symbols must keep their original names:
+/// - bindings visible from a scope containing a direct `eval` or a `with` statement,
+///   because they can be looked up by name at runtime
+/// - top level bindings of scripts, which are globals
+/// - top level bindings exported from modules
+/// - bindings referenced from a shorthand assignment pattern (`({ a } = b)`)
+/// - function and class names when `keep_names` is enabled
+///
+/// The names of these symbols are reserved so that no mangled name can shadow them.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ManglerBuilder {
+    keep_names: bool,
+}
 
 impl ManglerBuilder {
+    /// Do not rename functions and classes, so `Function.prototype.name` is preserved.
+    #[must_use]
+    pub fn with_keep_names(mut self, yes: bool) -> Self {
+        self.keep_names = yes;
+        self
+    }
+
     #[must_use]
-    pub fn build<'a>(self, program: &'a Program<'a>) -> Mangler {
+    pub fn build(self, program: &Program<'_>) -> Mangler {
         let semantic_ret = SemanticBuilder::new("", program.source_type).build(program);
         let semantic = semantic_ret.semantic;
 
+        let kept_symbols = self.collect_kept_symbols(&semantic, program);
+
         // Mangle the symbol table by computing slots from the scope tree.
         // A slot is the occurrence index of a binding identifier inside a scope.
         let (mut symbol_table, scope_tree) = semantic.into_symbol_table_and_scope_tree();
@@ -97,6 +150,9 @@ impl ManglerBuilder {
 
             // `bindings` are stored in order, traverse and increment slot
             for symbol_id in bindings.values() {
+                if kept_symbols[*symbol_id] {
+                    continue;
+                }
                 slots[*symbol_id] = slot;
                 slot += 1;
             }
@@ -108,15 +164,26 @@ impl ManglerBuilder {
             }
         }
 
-        let frequencies =
-            Self::tally_slot_frequencies(&symbol_table, total_number_of_slots, &slots);
+        let frequencies = Self::tally_slot_frequencies(
+            &symbol_table,
+            total_number_of_slots,
+            &slots,
+            &kept_symbols,
+        );
 
-        let unresolved_references = scope_tree
+        // Unresolved references and kept symbols are looked up by their original names,
+        // a mangled name must never shadow them.
+        let reserved_names = scope_tree
             .root_unresolved_references()
             .keys()
-            // It is unlike to get a 5 letter mangled identifier, which is a lot of slots.
-            // .filter(|name| name.len() < 5)
-            .collect::<Vec<_>>();
+            .cloned()
+            .chain(
+                kept_symbols
+                    .iter_enumerated()
+                    .filter(|(_, kept)| **kept)
+                    .map(|(symbol_id, _)| symbol_table.get_name(symbol_id).clone()),
+            )
+            .collect::<FxHashSet<_>>();
 
         let mut names = Vec::with_capacity(total_number_of_slots);
 
@@ -125,8 +192,8 @@ impl ManglerBuilder {
             names.push(loop {
                 let name = Atom::base54(count);
                 count += 1;
-                // Do not mangle keywords and unresolved references
-                if !is_keyword(&name) && !unresolved_references.iter().any(|n| **n == name) {
+                // Do not mangle keywords and reserved names
+                if !is_keyword(&name) && !reserved_names.contains(&name) {
                     break name;
                 }
             });
@@ -179,14 +246,54 @@ impl ManglerBuilder {
         Mangler { symbol_table }
     }
 
+    /// Compute which symbols must keep their original names.
+    fn collect_kept_symbols(
+        self,
+        semantic: &Semantic<'_>,
+        program: &Program<'_>,
+    ) -> IndexVec<SymbolId, bool> {
+        let symbols = semantic.symbols();
+        let scopes = semantic.scopes();
+
+        let dynamic_scopes = dynamic_scopes(semantic);
+
+        let mut collector = KeptNamesCollector::default();
+        collector.visit_program(program);
+
+        let root_scope_id = scopes.root_scope_id();
+        let is_script = !program.source_type.is_module();
+
+        let mut kept_symbols = index_vec![false; symbols.len()];
+        for symbol_id in symbols.iter() {
+            let flag = symbols.get_flag(symbol_id);
+            let scope_id = symbols.get_scope_id(symbol_id);
+            let is_top_level = scope_id == root_scope_id;
+            kept_symbols[symbol_id] = dynamic_scopes[scope_id]
+                || (is_top_level
+                    && (is_script
+                        || flag.contains(SymbolFlags::Export)
+                        || collector.exported_names.contains(symbols.get_name(symbol_id))))
+                || (self.keep_names && flag.intersects(SymbolFlags::Function | SymbolFlags::Class));
+        }
+
+        for reference_id in collector.shorthand_references {
+            if let Some(symbol_id) = symbols.get_reference(reference_id).symbol_id() {
+                kept_symbols[symbol_id] = true;
+            }
+        }
+
+        kept_symbols
+    }
+
     fn tally_slot_frequencies(
         symbol_table: &SymbolTable,
         total_number_of_slots: usize,
         slots: &IndexVec<SymbolId, Slot>,
+        kept_symbols: &IndexVec<SymbolId, bool>,
     ) -> Vec<SlotFrequency> {
         let mut frequencies = vec![SlotFrequency::default(); total_number_of_slots];
         for (symbol_id, slot) in slots.iter_enumerated() {
-            if !symbol_table.get_flag(symbol_id).is_variable() {
+            if kept_symbols[symbol_id] || !is_mangleable(symbol_table.get_flag(symbol_id)) {
                 continue;
             }
             let index = *slot;
@@ -200,6 +307,31 @@ impl ManglerBuilder {
     }
 }
 
+/// Scopes whose bindings can be observed by name through a direct `eval` or `with`: the scope of
+/// the `eval` call or `with` statement and all its ancestors, since both can reach every binding
+/// in the enclosing scope chain.
+pub(crate) fn dynamic_scopes(semantic: &Semantic<'_>) -> IndexVec<ScopeId, bool> {
+    let scopes = semantic.scopes();
+    let mut dynamic_scopes: IndexVec<ScopeId, bool> = index_vec![false; scopes.len()];
+    for node in semantic.nodes().iter() {
+        let is_dynamic = match node.kind() {
+            AstKind::WithStatement(_) => true,
+            AstKind::CallExpression(call) => matches!(
+                &call.callee,
+                Expression::Identifier(ident)
+                    if ident.name == "eval" && semantic.is_reference_to_global_variable(ident)
+            ),
+            _ => false,
+        };
+        if is_dynamic {
+            for scope_id in scopes.ancestors(node.scope_id()) {
+                dynamic_scopes[scope_id] = true;
+            }
+        }
+    }
+    dynamic_scopes
+}
+
 #[derive(Debug, Default, Clone)]
 struct SlotFrequency {
     pub slot: Slot,
@@ -207,6 +339,45 @@ struct SlotFrequency {
     pub symbol_ids: Vec<SymbolId>,
 }
 
+/// Names referenced without a binding identifier or reference of their own.
+#[derive(Default)]
+struct KeptNamesCollector {
+    /// Local names exported by `export { a }`
+    exported_names: FxHashSet<Atom>,
+    /// References inside `({ a } = b)`, printing them under a new name would change the property key
+    shorthand_references: Vec<ReferenceId>,
+}
+
+impl<'a> Visit<'a> for KeptNamesCollector {
+    fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
+        if decl.source.is_none() {
+            for specifier in &decl.specifiers {
+                self.exported_names.insert(specifier.local.name().clone());
+            }
+        }
+        if let Some(decl) = &decl.declaration {
+            self.visit_declaration(decl);
+        }
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        ident: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        if let Some(reference_id) = ident.binding.reference_id.get() {
+            self.shorthand_references.push(reference_id);
+        }
+        if let Some(expr) = &ident.init {
+            self.visit_expression(expr);
+        }
+    }
+}
+
+fn is_mangleable(flag: SymbolFlags) -> bool {
+    flag.is_variable()
+        || flag.intersects(SymbolFlags::Function | SymbolFlags::Class | SymbolFlags::CatchVariable)
+}
+
 #[rustfmt::skip]
 fn is_keyword(s: &str) -> bool {
     matches!(s, "as" | "do" | "if" | "in" | "is" | "of" | "any" | "for" | "get"
@@ -0,0 +1,283 @@
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, Visit, VisitMut};
+use oxc_span::Atom;
+use regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Options of [PropertyMangler].
+#[derive(Debug, Clone)]
+pub struct ManglePropsOptions {
+    /// Only the properties whose names match are mangled, e.g. `^_` for "private" properties.
+    pub regex: Regex,
+    /// Properties which are never mangled, even when they match `regex`.
+    pub reserved: FxHashSet<String>,
+}
+
+impl ManglePropsOptions {
+    pub fn new(regex: Regex) -> Self {
+        Self { regex, reserved: FxHashSet::default() }
+    }
+
+    #[must_use]
+    pub fn with_reserved<I: IntoIterator<Item = String>>(mut self, reserved: I) -> Self {
+        self.reserved.extend(reserved);
+        self
+    }
+}
+
+/// Mangled names of properties, kept in between runs so that separately minified files which
+/// share objects agree on their property names.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NameCache {
+    props: FxHashMap<String, String>,
+}
+
+impl NameCache {
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.props.get(name).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, name: String, mangled: String) {
+        self.props.insert(name, mangled);
+    }
+
+    /// `(name, mangled name)` of all properties
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.props.iter().map(|(name, mangled)| (name.as_str(), mangled.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.props.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.props.is_empty()
+    }
+}
+
+impl FromIterator<(String, String)> for NameCache {
+    fn from_iter<T: IntoIterator<Item = (String, String)>>(iter: T) -> Self {
+        Self { props: iter.into_iter().collect() }
+    }
+}
+
+/// # Property Mangler
+///
+/// Renames the properties matching [ManglePropsOptions::regex] to short names, in member
+/// expressions (`a.foo`), object literals, destructuring patterns and classes:
+///
+/// ```javascript
+/// obj._count = 1; const { _count } = obj;
+/// // becomes
+/// obj.a = 1; const { a: _count } = obj;
+/// ```
+///
+/// This is unsafe unless the matched properties are only used by the minified code, e.g. they are
+/// not read by `JSON.stringify` or other code. The following properties keep their names:
+/// - properties of [ManglePropsOptions::reserved]
+/// - properties written as strings anywhere, `obj["foo"]` or `{ "foo": 1 }`, as they are likely
+///   accessed dynamically
+/// - properties of shorthand assignment patterns, `({ foo } = obj)`, which cannot be renamed
+///   without renaming the variable
+///
+/// The same mangler should build all programs which share properties, and [NameCache] keeps the
+/// names across runs. The names kept in a program are reserved first, so a mangled name never
+/// collides with them: a property whose cached name is kept in the program gets a new name, which
+/// replaces the cached one and differs from the name in the programs built before. Properties
+/// kept in other programs are not known.
+#[derive(Debug)]
+pub struct PropertyMangler {
+    options: ManglePropsOptions,
+    name_cache: NameCache,
+}
+
+impl PropertyMangler {
+    pub fn new(options: ManglePropsOptions) -> Self {
+        Self { options, name_cache: NameCache::default() }
+    }
+
+    /// Start from the mangled names of an earlier run.
+    #[must_use]
+    pub fn with_name_cache(mut self, name_cache: NameCache) -> Self {
+        self.name_cache = name_cache;
+        self
+    }
+
+    /// The mangled names of all programs built so far, and of the initial name cache.
+    pub fn name_cache(&self) -> &NameCache {
+        &self.name_cache
+    }
+
+    pub fn into_name_cache(self) -> NameCache {
+        self.name_cache
+    }
+
+    pub fn build(&mut self, program: &mut Program<'_>) {
+        let mut collector = PropertyCollector::default();
+        collector.visit_program(program);
+
+        let is_mangled = |name: &Atom| {
+            self.options.regex.is_match(name.as_str())
+                && !self.options.reserved.contains(name.as_str())
+                && !collector.kept.contains(name)
+        };
+
+        // Names kept in this program, which no property may be renamed to
+        let kept = collector
+            .names
+            .keys()
+            .filter(|&name| !is_mangled(name))
+            .map(ToString::to_string)
+            .chain(self.options.reserved.iter().cloned())
+            .collect::<FxHashSet<_>>();
+        // Properties without a cached name, or whose cached name is kept in this program
+        let is_new = |name: &Atom| {
+            self.name_cache.get(name.as_str()).map_or(true, |mangled| kept.contains(mangled))
+        };
+
+        // The most frequent properties get the shortest names
+        let mut new_names = collector
+            .names
+            .iter()
+            .filter(|&(name, _)| is_mangled(name) && is_new(name))
+            .map(|(name, (frequency, first))| (name.clone(), *frequency, *first))
+            .collect::<Vec<_>>();
+        new_names.sort_by_key(|(_, frequency, first)| (std::cmp::Reverse(*frequency), *first));
+
+        // Names which must not be given to another property
+        let mut used = kept;
+        used.extend(self.name_cache.iter().map(|(_, mangled)| mangled.to_string()));
+
+        let mut count = 0;
+        for (name, _, _) in new_names {
+            let mangled = loop {
+                let mangled = Atom::base54(count);
+                count += 1;
+                if !used.contains(mangled.as_str()) {
+                    break mangled;
+                }
+            };
+            used.insert(mangled.to_string());
+            self.name_cache.insert(name.to_string(), mangled.to_string());
+        }
+
+        let names = collector
+            .names
+            .keys()
+            .filter(|&name| is_mangled(name))
+            .filter_map(|name| {
+                let mangled = self.name_cache.get(name.as_str())?;
+                Some((name.clone(), Atom::from(mangled)))
+            })
+            .collect::<FxHashMap<_, _>>();
+        if !names.is_empty() {
+            PropertyRenamer { names }.visit_program(program);
+        }
+    }
+}
+
+/// Property names of a program, with their number of occurrences and first occurrence.
+#[derive(Default)]
+struct PropertyCollector {
+    names: FxHashMap<Atom, (usize, usize)>,
+    /// Properties written as strings or in shorthand assignment patterns
+    kept: FxHashSet<Atom>,
+}
+
+impl PropertyCollector {
+    fn add(&mut self, name: &Atom) {
+        let next = self.names.len();
+        self.names.entry(name.clone()).or_insert((0, next)).0 += 1;
+    }
+
+    fn keep(&mut self, name: &Atom) {
+        self.add(name);
+        self.kept.insert(name.clone());
+    }
+}
+
+impl<'a> Visit<'a> for PropertyCollector {
+    fn visit_static_member_expression(&mut self, expr: &StaticMemberExpression<'a>) {
+        self.add(&expr.property.name);
+        self.visit_expression(&expr.object);
+    }
+
+    fn visit_computed_member_expression(&mut self, expr: &ComputedMemberExpression<'a>) {
+        if let Expression::StringLiteral(lit) = &expr.expression {
+            self.keep(&lit.value);
+        }
+        self.visit_expression(&expr.object);
+        self.visit_expression(&expr.expression);
+    }
+
+    fn visit_property_key(&mut self, key: &PropertyKey<'a>) {
+        match key {
+            PropertyKey::Identifier(ident) => self.add(&ident.name),
+            PropertyKey::PrivateIdentifier(_) => {}
+            PropertyKey::Expression(expr) => {
+                if let Expression::StringLiteral(lit) = expr {
+                    self.keep(&lit.value);
+                }
+                self.visit_expression(expr);
+            }
+        }
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        ident: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.keep(&ident.binding.name);
+        if let Some(expr) = &ident.init {
+            self.visit_expression(expr);
+        }
+    }
+}
+
+struct PropertyRenamer {
+    names: FxHashMap<Atom, Atom>,
+}
+
+impl PropertyRenamer {
+    fn is_renamed(&self, key: &PropertyKey) -> bool {
+        matches!(key, PropertyKey::Identifier(ident) if self.names.contains_key(&ident.name))
+    }
+}
+
+impl<'a> VisitMut<'a> for PropertyRenamer {
+    fn visit_static_member_expression(&mut self, expr: &mut StaticMemberExpression<'a>) {
+        if let Some(name) = self.names.get(&expr.property.name) {
+            expr.property.name = name.clone();
+        }
+        self.visit_expression(&mut expr.object);
+    }
+
+    fn visit_property_key(&mut self, key: &mut PropertyKey<'a>) {
+        match key {
+            PropertyKey::Identifier(ident) => {
+                if let Some(name) = self.names.get(&ident.name) {
+                    ident.name = name.clone();
+                }
+            }
+            PropertyKey::PrivateIdentifier(_) => {}
+            PropertyKey::Expression(expr) => self.visit_expression(expr),
+        }
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        // `{ foo }` becomes `{ a: foo }`
+        if prop.shorthand && self.is_renamed(&prop.key) {
+            prop.shorthand = false;
+        }
+        self.visit_property_key(&mut prop.key);
+        self.visit_expression(&mut prop.value);
+    }
+
+    fn visit_binding_property(&mut self, prop: &mut BindingProperty<'a>) {
+        if prop.shorthand && self.is_renamed(&prop.key) {
+            prop.shorthand = false;
+        }
+        self.visit_property_key(&mut prop.key);
+        self.visit_binding_pattern(&mut prop.value);
+    }
+}
@@ -0,0 +1,10 @@
+mod remove_dead_code;
+mod replace_global_defines;
+
+pub(crate) use self::remove_dead_code::pure_annotations;
+pub use self::{
+    remove_dead_code::RemoveDeadCode,
+    replace_global_defines::{
+        InvalidDefineKey, InvalidDefineValue, ReplaceGlobalDefines, ReplaceGlobalDefinesConfig,
+    },
+};
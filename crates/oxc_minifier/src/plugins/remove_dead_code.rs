@@ -0,0 +1,417 @@
+use oxc_allocator::{Allocator, Vec};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, TriviasMap, Visit, VisitMut};
+use oxc_semantic::{ScopeFlags, SemanticBuilder, SymbolFlags, SymbolId};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    compressor::ast_util::{get_boolean_value, IsLiteralValue, MayHaveSideEffects},
+    mangler::dynamic_scopes,
+};
+
+/// Remove Dead Code
+///
+/// * Folds constant conditions of `if` statements, conditional and logical expressions.
+/// * Removes unreachable statements after `return`, `throw`, `break` and `continue`.
+/// * Removes unused declarations with side effect free initializers.
+/// * Removes unused calls annotated with `/*#__PURE__*/` whose arguments are side effect free,
+///   see [RemoveDeadCode::with_pure_annotations].
+/// * Removes unused imports of modules without side effects,
+///   see [RemoveDeadCode::with_side_effect_free_modules].
+///
+/// Usually paired with [crate::ReplaceGlobalDefines] to remove branches guarded by
+/// `process.env.NODE_ENV`.
+pub struct RemoveDeadCode<'a> {
+    ast: AstBuilder<'a>,
+    unused_symbols: FxHashSet<SymbolId>,
+    /// Starts of the calls annotated with `/*#__PURE__*/`
+    pure_calls: FxHashSet<u32>,
+    side_effect_free_modules: FxHashSet<String>,
+}
+
+impl<'a> RemoveDeadCode<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            unused_symbols: FxHashSet::default(),
+            pure_calls: FxHashSet::default(),
+            side_effect_free_modules: FxHashSet::default(),
+        }
+    }
+
+    /// Read the `/*#__PURE__*/` and `/*@__PURE__*/` annotations of calls and `new` expressions
+    /// from the comments of the source text, written by bundlers and compilers for calls whose
+    /// result can be removed when it is not used.
+    #[must_use]
+    pub fn with_pure_annotations(self, source_text: &str, trivias: &TriviasMap) -> Self {
+        self.with_pure_calls(pure_annotations(source_text, trivias))
+    }
+
+    pub(crate) fn with_pure_calls(mut self, pure_calls: FxHashSet<u32>) -> Self {
+        self.pure_calls = pure_calls;
+        self
+    }
+
+    /// Module specifiers of the imports which can be removed when none of their bindings are
+    /// used, e.g. the packages with `"sideEffects": false` in their `package.json`.
+    #[must_use]
+    pub fn with_side_effect_free_modules<I: IntoIterator<Item = String>>(
+        mut self,
+        modules: I,
+    ) -> Self {
+        self.side_effect_free_modules = modules.into_iter().collect();
+        self
+    }
+
+    pub fn build(mut self, program: &mut Program<'a>) {
+        // Fold branches first, they are the main source of unused declarations.
+        self.visit_program(program);
+        self.unused_symbols = Self::collect_unused_symbols(program);
+        if !self.unused_symbols.is_empty() {
+            UnusedDeclarationRemover {
+                unused_symbols: &self.unused_symbols,
+                pure_calls: &self.pure_calls,
+            }
+            .visit_program(program);
+        }
+        // After the declarations, which may have been the only users of the imports.
+        if !self.side_effect_free_modules.is_empty() {
+            self.remove_unused_imports(program);
+        }
+    }
+
+    /// Remove the unused specifiers of imports of side effect free modules, and the imports
+    /// without used specifiers.
+    fn remove_unused_imports(&self, program: &mut Program<'a>) {
+        let unused_imports = {
+            let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+            let symbols = semantic.symbols();
+            symbols
+                .iter()
+                .filter(|symbol_id| {
+                    symbols.get_flag(*symbol_id).intersects(SymbolFlags::Import)
+                        && symbols.get_resolved_reference_ids(*symbol_id).is_empty()
+                })
+                .collect::<FxHashSet<_>>()
+        };
+        let is_unused = |specifier: &ImportDeclarationSpecifier| {
+            let local = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => &specifier.local,
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => &specifier.local,
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => &specifier.local,
+            };
+            local.symbol_id.get().is_some_and(|symbol_id| unused_imports.contains(&symbol_id))
+        };
+        let is_side_effect_free = |import: &ImportDeclaration| {
+            self.side_effect_free_modules.contains(import.source.value.as_str())
+        };
+
+        for stmt in program.body.iter_mut() {
+            let Statement::ModuleDeclaration(decl) = stmt else { continue };
+            let ModuleDeclaration::ImportDeclaration(import) = &mut **decl else { continue };
+            if is_side_effect_free(import) {
+                if let Some(specifiers) = &mut import.specifiers {
+                    specifiers.retain(|specifier| !is_unused(specifier));
+                }
+            }
+        }
+        program.body.retain(|stmt| {
+            let Statement::ModuleDeclaration(decl) = stmt else { return true };
+            let ModuleDeclaration::ImportDeclaration(import) = &**decl else { return true };
+            // `import "foo"` only runs the side effects of the module
+            !is_side_effect_free(import)
+                || import.specifiers.as_ref().is_some_and(|specifiers| !specifiers.is_empty())
+        });
+    }
+
+    /// Symbols that are never referenced and are safe to remove.
+    ///
+    /// Top level bindings of scripts are globals, and top level bindings of modules may be exported.
+    /// Bindings of scopes containing a direct `eval` or a `with` statement may be read by name.
+    fn collect_unused_symbols(program: &Program<'a>) -> FxHashSet<SymbolId> {
+        let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+        let symbols = semantic.symbols();
+        let scopes = semantic.scopes();
+        let dynamic_scopes = dynamic_scopes(&semantic);
+
+        let mut exported_names = FxHashSet::default();
+        for stmt in &program.body {
+            if let Statement::ModuleDeclaration(decl) = stmt {
+                if let ModuleDeclaration::ExportNamedDeclaration(decl) = &**decl {
+                    for specifier in &decl.specifiers {
+                        exported_names.insert(specifier.local.name().clone());
+                    }
+                }
+            }
+        }
+
+        let is_module = program.source_type.is_module();
+        symbols
+            .iter()
+            .filter(|symbol_id| {
+                let flag = symbols.get_flag(*symbol_id);
+                if !(flag.is_variable() || flag.intersects(SymbolFlags::Function))
+                    || flag.intersects(SymbolFlags::Export | SymbolFlags::Import)
+                {
+                    return false;
+                }
+                let scope_id = symbols.get_scope_id(*symbol_id);
+                if dynamic_scopes[scope_id] {
+                    return false;
+                }
+                if scope_id == scopes.root_scope_id()
+                    && (!is_module || exported_names.contains(symbols.get_name(*symbol_id)))
+                {
+                    return false;
+                }
+                symbols.get_resolved_reference_ids(*symbol_id).is_empty()
+            })
+            .collect()
+    }
+
+    /// `if (true) { a } else { b }` -> `{ a }`
+    fn fold_if_statement(&mut self, stmt: &mut Statement<'a>) {
+        let Statement::IfStatement(if_stmt) = stmt else { return };
+        let Some(boolean) = get_boolean_value(&if_stmt.test) else { return };
+        if if_stmt.test.may_have_side_effects() {
+            return;
+        }
+        let removed_branch =
+            if boolean { if_stmt.alternate.as_ref() } else { Some(&if_stmt.consequent) };
+        // `var` declarations are hoisted out of the removed branch.
+        if removed_branch.is_some_and(has_var_declaration) {
+            return;
+        }
+        *stmt = if boolean {
+            self.ast.move_statement(&mut if_stmt.consequent)
+        } else if let Some(alternate) = &mut if_stmt.alternate {
+            self.ast.move_statement(alternate)
+        } else {
+            self.ast.empty_statement(if_stmt.span)
+        };
+    }
+
+    /// `true ? a : b` -> `a`
+    /// `false && a` -> `false`
+    /// `"a" === "a"` -> `true`
+    fn fold_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ConditionalExpression(cond_expr) => {
+                if cond_expr.test.may_have_side_effects() {
+                    return;
+                }
+                if let Some(boolean) = get_boolean_value(&cond_expr.test) {
+                    *expr = if boolean {
+                        self.ast.move_expression(&mut cond_expr.consequent)
+                    } else {
+                        self.ast.move_expression(&mut cond_expr.alternate)
+                    };
+                }
+            }
+            Expression::LogicalExpression(logical_expr) => {
+                if logical_expr.left.may_have_side_effects() {
+                    return;
+                }
+                let Some(boolean) = get_boolean_value(&logical_expr.left) else { return };
+                let keep_left = match logical_expr.operator {
+                    LogicalOperator::And => !boolean,
+                    LogicalOperator::Or => boolean,
+                    LogicalOperator::Coalesce => return,
+                };
+                *expr = if keep_left {
+                    self.ast.move_expression(&mut logical_expr.left)
+                } else {
+                    self.ast.move_expression(&mut logical_expr.right)
+                };
+            }
+            Expression::UnaryExpression(unary_expr)
+                if unary_expr.operator == UnaryOperator::LogicalNot =>
+            {
+                if unary_expr.argument.may_have_side_effects() {
+                    return;
+                }
+                if let Some(boolean) = get_boolean_value(&unary_expr.argument) {
+                    let literal = self.ast.boolean_literal(unary_expr.span, !boolean);
+                    *expr = self.ast.literal_boolean_expression(literal);
+                }
+            }
+            Expression::BinaryExpression(binary_expr) => {
+                let Some(equal) = literal_equality(&binary_expr.left, &binary_expr.right) else {
+                    return;
+                };
+                let value = match binary_expr.operator {
+                    BinaryOperator::Equality | BinaryOperator::StrictEquality => equal,
+                    BinaryOperator::Inequality | BinaryOperator::StrictInequality => !equal,
+                    _ => return,
+                };
+                let literal = self.ast.boolean_literal(binary_expr.span, value);
+                *expr = self.ast.literal_boolean_expression(literal);
+            }
+            _ => {}
+        }
+    }
+
+    /// Remove statements following a `return`, `throw`, `break` or `continue`.
+    /// Function declarations and `var` declarations are kept because they are hoisted.
+    fn remove_unreachable_statements(stmts: &mut Vec<'a, Statement<'a>>) {
+        let Some(index) = stmts.iter().position(|stmt| {
+            matches!(
+                stmt,
+                Statement::ReturnStatement(_)
+                    | Statement::ThrowStatement(_)
+                    | Statement::BreakStatement(_)
+                    | Statement::ContinueStatement(_)
+            )
+        }) else {
+            return;
+        };
+        let mut i = 0;
+        stmts.retain(|stmt| {
+            i += 1;
+            i <= index + 1
+                || matches!(stmt, Statement::Declaration(Declaration::FunctionDeclaration(_)))
+                || has_var_declaration(stmt)
+        });
+    }
+}
+
+impl<'a> VisitMut<'a> for RemoveDeadCode<'a> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        for stmt in stmts.iter_mut() {
+            self.visit_statement(stmt);
+        }
+        stmts.retain(|stmt| match stmt {
+            Statement::EmptyStatement(_) => false,
+            Statement::ExpressionStatement(stmt) => {
+                !is_pure_call(&stmt.expression, &self.pure_calls)
+            }
+            _ => true,
+        });
+        Self::remove_unreachable_statements(stmts);
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        self.visit_statement_match(stmt);
+        self.fold_if_statement(stmt);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.visit_expression_match(expr);
+        self.fold_expression(expr);
+    }
+}
+
+/// Compare two literals of the same type, `None` if the result is unknown.
+#[allow(clippy::float_cmp)]
+fn literal_equality(left: &Expression, right: &Expression) -> Option<bool> {
+    match (left, right) {
+        (Expression::StringLiteral(left), Expression::StringLiteral(right)) => {
+            Some(left.value == right.value)
+        }
+        (Expression::NumberLiteral(left), Expression::NumberLiteral(right)) => {
+            Some(left.value == right.value)
+        }
+        (Expression::BooleanLiteral(left), Expression::BooleanLiteral(right)) => {
+            Some(left.value == right.value)
+        }
+        (Expression::NullLiteral(_), Expression::NullLiteral(_)) => Some(true),
+        _ => None,
+    }
+}
+
+/// Starts of the expressions following `/*#__PURE__*/` or `/*@__PURE__*/` comments.
+pub(crate) fn pure_annotations(source_text: &str, trivias: &TriviasMap) -> FxHashSet<u32> {
+    trivias
+        .comments_spans()
+        .filter(|(comment, span)| {
+            comment.is_multi_line()
+                && matches!(span.source_text(source_text).trim(), "#__PURE__" | "@__PURE__")
+        })
+        .map(|(_, span)| {
+            // After `*/`
+            let rest = &source_text[span.end as usize + 2..];
+            span.end + 2 + u32::try_from(rest.len() - rest.trim_start().len()).unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Whether the expression is a call or `new` expression annotated as pure, whose arguments are
+/// side effect free.
+fn is_pure_call(expr: &Expression, pure_calls: &FxHashSet<u32>) -> bool {
+    let (span, arguments) = match expr {
+        Expression::CallExpression(call_expr) => (call_expr.span, &call_expr.arguments),
+        Expression::NewExpression(new_expr) => (new_expr.span, &new_expr.arguments),
+        _ => return false,
+    };
+    pure_calls.contains(&span.start)
+        && arguments.iter().all(|argument| match argument {
+            Argument::Expression(expr) => {
+                !expr.may_have_side_effects() || is_pure_call(expr, pure_calls)
+            }
+            // Spreading runs the iterator of the argument.
+            Argument::SpreadElement(_) => false,
+        })
+}
+
+fn has_var_declaration(stmt: &Statement) -> bool {
+    let mut finder = VarDeclarationFinder { found: false };
+    finder.visit_statement(stmt);
+    finder.found
+}
+
+struct VarDeclarationFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for VarDeclarationFinder {
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if decl.kind.is_var() {
+            self.found = true;
+        }
+    }
+
+    // `var` declarations do not escape function boundaries.
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+}
+
+struct UnusedDeclarationRemover<'b> {
+    unused_symbols: &'b FxHashSet<SymbolId>,
+    pure_calls: &'b FxHashSet<u32>,
+}
+
+impl<'b> UnusedDeclarationRemover<'b> {
+    fn is_unused(&self, ident: &BindingIdentifier) -> bool {
+        ident.symbol_id.get().is_some_and(|symbol_id| self.unused_symbols.contains(&symbol_id))
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for UnusedDeclarationRemover<'b> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        for stmt in stmts.iter_mut() {
+            if let Statement::Declaration(Declaration::VariableDeclaration(decl)) = stmt {
+                decl.declarations.retain(|declarator| {
+                    let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                        return true;
+                    };
+                    let is_pure = declarator.init.as_ref().map_or(true, |init| {
+                        init.is_literal_value(true) || is_pure_call(init, self.pure_calls)
+                    });
+                    !(is_pure && self.is_unused(ident))
+                });
+            }
+        }
+        stmts.retain(|stmt| match stmt {
+            Statement::Declaration(Declaration::VariableDeclaration(decl)) => {
+                !decl.declarations.is_empty()
+            }
+            Statement::Declaration(Declaration::FunctionDeclaration(func)) => {
+                !func.id.as_ref().is_some_and(|ident| self.is_unused(ident))
+            }
+            _ => true,
+        });
+        for stmt in stmts.iter_mut() {
+            self.visit_statement(stmt);
+        }
+    }
+}
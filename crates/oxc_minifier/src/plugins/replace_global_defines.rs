@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use oxc_allocator::Allocator;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_parser::Parser;
+use oxc_semantic::{SemanticBuilder, SymbolTable};
+use oxc_span::SourceType;
+use oxc_syntax::identifier::is_identifier_name;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The define key `{0}` is not an identifier or a member expression of identifiers")]
+#[diagnostic(help("Use a key such as `DEBUG` or `process.env.NODE_ENV`"))]
+pub struct InvalidDefineKey(pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The define value `{0}` is not a single expression")]
+#[diagnostic(help("Strings need to be quoted, e.g. `\"production\"`"))]
+pub struct InvalidDefineValue(pub String);
+
+/// Configuration for [ReplaceGlobalDefines].
+///
+/// Keys are identifiers or member expressions of identifiers, e.g. `DEBUG` or `process.env.NODE_ENV`.
+/// Values are JavaScript expressions, e.g. `"production"`, `false` or `{}`.
+#[derive(Debug, Clone)]
+pub struct ReplaceGlobalDefinesConfig(Arc<Vec<(Vec<String>, String)>>);
+
+impl ReplaceGlobalDefinesConfig {
+    /// # Errors
+    ///
+    /// * key is not an identifier or a member expression of identifiers
+    /// * value is not a valid JavaScript expression
+    pub fn new<S: AsRef<str>>(defines: &[(S, S)]) -> Result<Self, Vec<oxc_diagnostics::Error>> {
+        let allocator = Allocator::default();
+        let mut errors = vec![];
+        let mut entries = Vec::with_capacity(defines.len());
+        for (key, value) in defines {
+            let (key, value) = (key.as_ref(), value.as_ref());
+            let parts = key.split('.').map(String::from).collect::<Vec<_>>();
+            if !parts.iter().all(|part| is_identifier_name(part)) {
+                errors.push(InvalidDefineKey(key.to_string()).into());
+                continue;
+            }
+            if let Err(errs) = parse_value(&allocator, value) {
+                errors.extend(errs);
+                continue;
+            }
+            entries.push((parts, value.to_string()));
+        }
+        if errors.is_empty() {
+            Ok(Self(Arc::new(entries)))
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Replace Global Defines
+///
+/// Replaces global identifiers and member expressions with the configured values,
+/// `process.env.NODE_ENV` -> `"production"`.
+/// Bindings that shadow a define are left untouched.
+pub struct ReplaceGlobalDefines<'a> {
+    ast: AstBuilder<'a>,
+    config: ReplaceGlobalDefinesConfig,
+    symbols: SymbolTable,
+}
+
+impl<'a> ReplaceGlobalDefines<'a> {
+    pub fn new(allocator: &'a Allocator, config: ReplaceGlobalDefinesConfig) -> Self {
+        Self { ast: AstBuilder::new(allocator), config, symbols: SymbolTable::default() }
+    }
+
+    pub fn build(mut self, program: &mut Program<'a>) {
+        let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+        (self.symbols, _) = semantic.into_symbol_table_and_scope_tree();
+        self.visit_program(program);
+    }
+
+    fn replace_define(&mut self, expr: &mut Expression<'a>) -> bool {
+        let config = Arc::clone(&self.config.0);
+        for (parts, value) in config.iter() {
+            if self.is_define(expr, parts) {
+                if let Ok(value) = parse_value(self.ast.allocator, value) {
+                    *expr = value;
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn is_define(&self, expr: &Expression<'a>, parts: &[String]) -> bool {
+        match expr {
+            Expression::Identifier(ident) => {
+                parts.len() == 1 && ident.name == parts[0].as_str() && self.is_global(ident)
+            }
+            Expression::MemberExpression(member_expr) => {
+                let MemberExpression::StaticMemberExpression(member_expr) = &**member_expr else {
+                    return false;
+                };
+                let Some((last, rest)) = parts.split_last() else { return false };
+                !rest.is_empty()
+                    && !member_expr.optional
+                    && member_expr.property.name == last.as_str()
+                    && self.is_define(&member_expr.object, rest)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_global(&self, ident: &IdentifierReference) -> bool {
+        ident
+            .reference_id
+            .get()
+            .map_or(true, |reference_id| self.symbols.is_global_reference(reference_id))
+    }
+}
+
+impl<'a> VisitMut<'a> for ReplaceGlobalDefines<'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if !self.replace_define(expr) {
+            self.visit_expression_match(expr);
+        }
+    }
+}
+
+/// Parse a define value into an expression allocated in `allocator`.
+fn parse_value<'a>(
+    allocator: &'a Allocator,
+    value: &str,
+) -> Result<Expression<'a>, Vec<oxc_diagnostics::Error>> {
+    let ast = AstBuilder::new(allocator);
+    // Wrap in parentheses so a string literal is not parsed as a directive.
+    let source_text = ast.new_str(&format!("({value})"));
+    let ret =
+        Parser::new(allocator, source_text, SourceType::default()).preserve_parens(false).parse();
+    if !ret.errors.is_empty() {
+        return Err(ret.errors);
+    }
+    let mut body = ret.program.body;
+    match body.as_mut_slice() {
+        [Statement::ExpressionStatement(stmt)] => Ok(ast.move_expression(&mut stmt.expression)),
+        _ => Err(vec![InvalidDefineValue(value.to_string()).into()]),
+    }
+}
@@ -48,7 +48,8 @@ pub(crate) fn test_reparse(source_text: &str) {
 pub(crate) fn test_without_compress_booleans(source_text: &str, expected: &str) {
     let source_type = SourceType::default();
     let compress_options = CompressOptions { booleans: false, ..CompressOptions::default() };
-    let options = MinifierOptions { mangle: false, compress: compress_options };
+    let options =
+        MinifierOptions { mangle: false, compress: compress_options, ..MinifierOptions::default() };
     let minified = minify(source_text, source_type, options);
     assert_eq!(expected, minified, "for source {source_text}");
 }
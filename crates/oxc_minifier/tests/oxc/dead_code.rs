@@ -0,0 +1,130 @@
+use oxc_allocator::Allocator;
+use oxc_ast::TriviasMap;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_minifier::{Minifier, ReplaceGlobalDefines, ReplaceGlobalDefinesConfig};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+use crate::{test_with_options, CompressOptions, MinifierOptions};
+
+fn dead_code_options() -> MinifierOptions {
+    MinifierOptions {
+        mangle: false,
+        compress: CompressOptions { dead_code: true, ..CompressOptions::all_false() },
+        ..MinifierOptions::default()
+    }
+}
+
+fn test(source_text: &str, expected: &str) {
+    test_with_options(source_text, expected, dead_code_options());
+}
+
+fn test_define(defines: &[(&str, &str)], source_text: &str, expected: &str) {
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+    let program = allocator.alloc(program);
+    let config = ReplaceGlobalDefinesConfig::new(defines).unwrap();
+    ReplaceGlobalDefines::new(&allocator, config).build(program);
+    Minifier::new(dead_code_options()).build(&allocator, program);
+    let minified = Codegen::<true>::new(source_text.len(), CodegenOptions).build(program);
+    assert_eq!(expected, minified, "for source {source_text}");
+}
+
+fn test_tree_shaking(source_text: &str, expected: &str) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let trivias = TriviasMap::from(ret.trivias);
+    let program = allocator.alloc(ret.program);
+    Minifier::new(dead_code_options())
+        .with_pure_annotations(source_text, &trivias)
+        .with_side_effect_free_modules(["pure".to_string()])
+        .build(&allocator, program);
+    let minified = Codegen::<true>::new(source_text.len(), CodegenOptions).build(program);
+    assert_eq!(expected, minified, "for source {source_text}");
+}
+
+#[test]
+fn constant_branches() {
+    test("if (true) { foo() } else { bar() }", "foo();");
+    test("if (false) foo(); bar()", "bar();");
+    test("true ? foo() : bar()", "foo();");
+    test("'a' === 'b' ? foo() : bar()", "bar();");
+    test("!0 && foo()", "foo();");
+}
+
+#[test]
+fn unreachable_statements() {
+    test("function foo() { return 1; bar() }", "function foo(){return 1}");
+    test(
+        "function foo() { return bar(); baz(); function bar() {} }",
+        "function foo(){return bar();function bar(){}}",
+    );
+}
+
+#[test]
+fn unused_declarations() {
+    test(
+        "function foo() { let a = 1; let b = bar(); return 2 }",
+        "function foo(){let b=bar();return 2}",
+    );
+    test("function foo() { function bar() {} return 1 }", "function foo(){return 1}");
+    test("let a = 1", "let a=1;");
+    // Read by name through a direct `eval` or `with`
+    test(
+        "function foo() { let a = 1; return eval('a') }",
+        "function foo(){let a=1;return eval('a')}",
+    );
+    test(
+        "function foo() { let a = 1; return () => eval('a') }",
+        "function foo(){let a=1;return ()=>eval('a')}",
+    );
+    test(
+        "function foo(o) { let a = 1; with (o) { return a } }",
+        "function foo(o){let a=1;with(o){return a}}",
+    );
+}
+
+#[test]
+fn pure_annotations() {
+    test_tree_shaking("/*#__PURE__*/ foo(); bar()", "bar();");
+    test_tree_shaking("/* @__PURE__ */ new Foo(1, 'a'); bar()", "bar();");
+    test_tree_shaking("let a = /*#__PURE__*/ foo(/*#__PURE__*/ bar())", "");
+    // The arguments may have side effects
+    test_tree_shaking("/*#__PURE__*/ foo(bar())", "foo(bar());");
+    test_tree_shaking("/*#__PURE__*/ foo(...a)", "foo(...a);");
+    test_tree_shaking("/* PURE */ foo()", "foo();");
+}
+
+#[test]
+fn side_effect_free_modules() {
+    test_tree_shaking(
+        "import { a, b } from 'pure'; import c from 'impure'; a()",
+        "import {a} from 'pure';import c from 'impure';a();",
+    );
+    test_tree_shaking("import 'pure'; import 'impure'", "import 'impure'");
+    // Only used by a removed declaration
+    test_tree_shaking("import { a } from 'pure'; let b = /*#__PURE__*/ a()", "");
+}
+
+#[test]
+fn define() {
+    let defines = [("process.env.NODE_ENV", "\"production\"")];
+    test_define(
+        &defines,
+        "if (process.env.NODE_ENV !== 'production') { console.log('dev') } foo()",
+        "foo();",
+    );
+    test_define(
+        &defines,
+        "function foo(process) { return process.env.NODE_ENV }",
+        "function foo(process){return process.env.NODE_ENV}",
+    );
+    test_define(&[("DEBUG", "false")], "DEBUG && log()", "false;");
+}
+
+#[test]
+fn invalid_define() {
+    assert!(ReplaceGlobalDefinesConfig::new(&[("1DEBUG", "true")]).is_err());
+    assert!(ReplaceGlobalDefinesConfig::new(&[("DEBUG", "1); foo(")]).is_err());
+}
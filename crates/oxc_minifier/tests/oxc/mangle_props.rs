@@ -0,0 +1,82 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_minifier::{ManglePropsOptions, NameCache, PropertyMangler};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use regex::Regex;
+
+fn mangle(property_mangler: &mut PropertyMangler, source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+    let program = allocator.alloc(program);
+    property_mangler.build(program);
+    Codegen::<true>::new(source_text.len(), CodegenOptions).build(program)
+}
+
+fn property_mangler(reserved: &[&str]) -> PropertyMangler {
+    let options = ManglePropsOptions::new(Regex::new("^_").unwrap())
+        .with_reserved(reserved.iter().map(ToString::to_string));
+    PropertyMangler::new(options)
+}
+
+fn test(source_text: &str, expected: &str) {
+    let minified = mangle(&mut property_mangler(&[]), source_text);
+    assert_eq!(expected, minified, "for source {source_text}");
+}
+
+#[test]
+fn mangle_matching_properties() {
+    test("obj._foo = obj.bar + obj._foo", "obj.a=obj.bar+obj.a;");
+    test("class A { _foo() {} }", "class A{a(){}};");
+    test("const { _foo, _bar: x } = { _foo: 1, _bar }", "const {a:_foo,b:x}={a:1,b:_bar};");
+    // The most frequent property gets the shortest name
+    test("x._a; x._b; x._b", "x.b;x.a;x.a;");
+}
+
+#[test]
+fn keep_quoted_properties() {
+    test("obj._foo = obj['_foo']; obj._bar", "obj._foo=obj['_foo'];obj.a;");
+    test("({ '_foo': 1 })._foo", "({'_foo':1})._foo;");
+}
+
+#[test]
+fn keep_shorthand_assignment_targets() {
+    test("({ _foo } = obj); obj._foo", "({_foo}=obj);obj._foo;");
+}
+
+#[test]
+fn no_collision_with_kept_properties() {
+    test("x.a; x._foo", "x.a;x.b;");
+}
+
+#[test]
+fn reserved() {
+    let minified = mangle(&mut property_mangler(&["_foo"]), "x._foo; x._bar");
+    assert_eq!(minified, "x._foo;x.a;");
+}
+
+#[test]
+fn name_cache() {
+    let mut mangler = property_mangler(&[]);
+    assert_eq!(mangle(&mut mangler, "x._foo; x._bar; x._bar"), "x.b;x.a;x.a;");
+    // Consistent across programs
+    assert_eq!(mangle(&mut mangler, "x._baz; x._foo"), "x.c;x.b;");
+
+    let name_cache = mangler.into_name_cache();
+    assert_eq!(name_cache.get("_foo"), Some("b"));
+    let cache =
+        name_cache.iter().map(|(a, b)| (a.to_string(), b.to_string())).collect::<NameCache>();
+    let mut mangler = property_mangler(&[]).with_name_cache(cache);
+    assert_eq!(mangle(&mut mangler, "x._qux; x._foo"), "x.d;x.b;");
+}
+
+#[test]
+fn name_cache_collision_with_kept_properties() {
+    let cache = [("_foo".to_string(), "a".to_string())].into_iter().collect::<NameCache>();
+    let mut mangler = property_mangler(&[]).with_name_cache(cache);
+    // `a` is kept in this program, `_foo` gets a new name
+    assert_eq!(mangle(&mut mangler, "x.a; x._foo"), "x.a;x.b;");
+    assert_eq!(mangler.name_cache().get("_foo"), Some("b"));
+    // Cached names which are not kept are reused
+    assert_eq!(mangle(&mut mangler, "x._foo; x._bar"), "x.b;x.a;");
+}
@@ -0,0 +1,68 @@
+use oxc_span::SourceType;
+
+use crate::{minify, test_with_options, CompressOptions, MinifierOptions};
+
+fn mangle_options() -> MinifierOptions {
+    MinifierOptions { compress: CompressOptions::all_false(), ..MinifierOptions::default() }
+}
+
+fn test(source_text: &str, expected: &str) {
+    test_with_options(source_text, expected, mangle_options());
+}
+
+fn test_module(source_text: &str, expected: &str) {
+    let source_type = SourceType::default().with_module(true);
+    let minified = minify(source_text, source_type, mangle_options());
+    assert_eq!(expected, minified, "for source {source_text}");
+}
+
+#[test]
+fn mangle_local_bindings() {
+    test("function foo(bar) { return bar }", "function foo(a){return a}");
+    test(
+        "function foo() { function bar(baz) { return baz } return bar }",
+        "function foo(){function a(b){return b}return a}",
+    );
+}
+
+#[test]
+fn keep_unresolved_references() {
+    test("function foo(bar) { return a + bar }", "function foo(b){return a+b}");
+}
+
+#[test]
+fn keep_top_level_script_bindings() {
+    test("var foo = 1; function bar() {}", "var foo=1;function bar(){}");
+}
+
+#[test]
+fn bailout_on_direct_eval() {
+    test("function foo(bar) { eval(x); return bar }", "function foo(bar){eval(x);return bar}");
+    test(
+        "function foo(bar) { function baz(qux) { return qux } eval(x); return bar }",
+        "function foo(bar){function baz(a){return a}eval(x);return bar}",
+    );
+}
+
+#[test]
+fn bailout_on_with() {
+    test(
+        "function foo(bar) { with (obj) bar; function baz(qux) { return qux } }",
+        "function foo(bar){with(obj)bar;function baz(a){return a}}",
+    );
+}
+
+#[test]
+fn keep_exported_names() {
+    test_module("export let foo = 1; let bar = 2; foo(bar)", "export let foo=1;let a=2;foo(a);");
+}
+
+#[test]
+fn keep_names() {
+    let options = MinifierOptions { keep_names: true, ..mangle_options() };
+    test_with_options(
+        "function foo() { function bar(baz) { return baz } return bar }",
+        "function foo(){function bar(a){return a}return bar}",
+        options,
+    );
+}
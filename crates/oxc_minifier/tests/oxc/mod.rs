@@ -1,3 +1,6 @@
 mod code_removal;
+mod dead_code;
 mod folding;
+mod mangle_props;
+mod mangler;
 mod precedence;
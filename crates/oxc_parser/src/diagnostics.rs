@@ -14,6 +14,11 @@ pub struct OverlongSource;
 #[diagnostic()]
 pub struct Flow(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("{0} are a stage 3 proposal")]
+#[diagnostic(help("Stage 3 proposals are disabled in the parser options"))]
+pub struct Stage3Proposal(pub &'static str, #[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Unexpected token")]
 #[diagnostic()]
@@ -60,6 +65,10 @@ pub struct RegExpFlagTwice(pub char, #[label] pub Span);
 #[error("Unexpected end of file")]
 pub struct UnexpectedEnd(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Trailing comma is not allowed")]
+pub struct TrailingComma(#[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Unterminated regular expression")]
 pub struct UnterminatedRegExp(#[label] pub Span);
@@ -167,6 +167,8 @@ impl<'a> Parser<'a> {
             }
         }
 
-        Ok(self.ast.using_declaration(self.end_span(span), declarations, is_await))
+        let span = self.end_span(span);
+        self.check_stage3_proposal("Using declarations", span);
+        Ok(self.ast.using_declaration(span, declarations, is_await))
     }
 }
@@ -1089,7 +1089,12 @@ impl<'a> Parser<'a> {
             let lhs = self.parse_decorator_member_expression()?;
             self.parse_decorator_call_expression(span, lhs)?
         };
-        Ok(self.ast.decorator(self.end_span(span), expr))
+        let span = self.end_span(span);
+        // TypeScript has its own decorators
+        if !self.ts_enabled() {
+            self.check_stage3_proposal("Decorators", span);
+        }
+        Ok(self.ast.decorator(span, expr))
     }
 
     fn is_await_expression(&mut self) -> bool {
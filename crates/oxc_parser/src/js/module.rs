@@ -21,7 +21,9 @@ impl<'a> Parser<'a> {
         let expression = self.parse_assignment_expression_base()?;
         let mut arguments = self.ast.new_vec();
         if self.eat(Kind::Comma) && !self.at(Kind::RParen) {
+            let span = self.start_span();
             arguments.push(self.parse_assignment_expression_base()?);
+            self.check_stage3_proposal("Import attributes", self.end_span(span));
         }
 
         self.ctx = self.ctx.and_in(has_in);
@@ -150,7 +152,9 @@ impl<'a> Parser<'a> {
         let with_entries = AssertEntries::parse(self)?.elements;
         self.ctx = ctx;
 
-        Ok(Some(WithClause { span: self.end_span(span), attributes_keyword, with_entries }))
+        let span = self.end_span(span);
+        self.check_stage3_proposal("Import attributes", span);
+        Ok(Some(WithClause { span, attributes_keyword, with_entries }))
     }
 
     pub(crate) fn parse_ts_export_assignment_declaration(
@@ -42,11 +42,15 @@ impl<'a> Parser<'a> {
             match self.cur_kind() {
                 Kind::RCurly if !is_top_level => break,
                 Kind::Import if !matches!(self.peek_kind(), Kind::Dot | Kind::LParen) => {
-                    let stmt = self.parse_import_declaration()?;
+                    let span = self.start_span();
+                    let stmt = self.parse_import_declaration();
+                    let stmt = self.recover_statement(span, stmt)?;
                     statements.push(stmt);
                 }
                 Kind::Export => {
-                    let stmt = self.parse_export_declaration()?;
+                    let span = self.start_span();
+                    let stmt = self.parse_export_declaration();
+                    let stmt = self.recover_statement(span, stmt)?;
                     statements.push(stmt);
                 }
                 Kind::At => {
@@ -54,7 +58,9 @@ impl<'a> Parser<'a> {
                     continue;
                 }
                 _ => {
-                    let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+                    let span = self.start_span();
+                    let stmt = self.parse_statement_list_item(StatementContext::StatementList);
+                    let stmt = self.recover_statement(span, stmt)?;
 
                     // Section 11.2.1 Directive Prologue
                     // The only way to get a correct directive is to parse the statement first and check if it is a string literal.
@@ -87,6 +93,58 @@ impl<'a> Parser<'a> {
         Ok((directives, statements))
     }
 
+    /// With error recovery enabled, a statement starting at `span` which failed to parse is
+    /// replaced by an `EmptyStatement` and the parser skips to the start of the next statement.
+    fn recover_statement(
+        &mut self,
+        span: Span,
+        result: Result<Statement<'a>>,
+    ) -> Result<Statement<'a>> {
+        match result {
+            Err(error) if self.error_recovery => {
+                self.error(error);
+                self.skip_to_next_statement(span.start);
+                Ok(self.ast.empty_statement(self.end_span(span)))
+            }
+            result => result,
+        }
+    }
+
+    /// Skip to after the next `;`, before a statement keyword on a new line,
+    /// or before the `}` closing the enclosing block.
+    /// Always advances past the token at `start` so that parsing makes progress.
+    fn skip_to_next_statement(&mut self, start: u32) {
+        let mut depth = 0u32;
+        loop {
+            let token = self.cur_token();
+            let at_start = token.start == start;
+            match token.kind {
+                Kind::Eof => break,
+                Kind::Semicolon if depth == 0 => {
+                    self.bump_any();
+                    break;
+                }
+                Kind::RCurly if depth == 0 => {
+                    if at_start {
+                        self.bump_any();
+                    }
+                    break;
+                }
+                kind if depth == 0
+                    && !at_start
+                    && token.is_on_new_line
+                    && kind.is_statement_start() =>
+                {
+                    break;
+                }
+                Kind::LCurly => depth += 1,
+                Kind::RCurly => depth -= 1,
+                _ => {}
+            }
+            self.bump_any();
+        }
+    }
+
     /// `StatementListItem`[Yield, Await, Return] :
     ///     Statement[?Yield, ?Await, ?Return]
     ///     Declaration[?Yield, ?Await]
@@ -160,8 +218,9 @@ impl<'a> Parser<'a> {
         self.expect(Kind::LCurly)?;
         let mut body = self.ast.new_vec();
         while !self.at(Kind::RCurly) && !self.at(Kind::Eof) {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
-            body.push(stmt);
+            let span = self.start_span();
+            let stmt = self.parse_statement_list_item(StatementContext::StatementList);
+            body.push(self.recover_statement(span, stmt)?);
         }
         self.expect(Kind::RCurly)?;
         Ok(self.ast.block(self.end_span(span), body))
@@ -0,0 +1,583 @@
+//! JSON, JSONC and JSON5 parser
+//!
+//! Produces a value tree with spans, for reporting errors at the exact location of a value in
+//! configuration files such as `tsconfig.json`, `package.json` and `.eslintrc.json`.
+//!
+//! ```rust
+//! let value = JsonParser::new(source_text, JsonMode::Jsonc).parse()?;
+//! let span = value.get("compilerOptions").map(|value| value.span);
+//! ```
+//!
+//! References:
+//! * <https://www.json.org/json-en.html>
+//! * <https://code.visualstudio.com/docs/languages/json#_json-with-comments>
+//! * <https://spec.json5.org>
+
+use std::borrow::Cow;
+
+use oxc_diagnostics::{Error, Result};
+use oxc_span::Span;
+
+use crate::diagnostics;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum JsonMode {
+    /// RFC 8259
+    #[default]
+    Json,
+    /// JSON with comments and trailing commas
+    Jsonc,
+    /// JSON5: comments, trailing commas, single quoted strings, unquoted keys,
+    /// hexadecimal numbers, `Infinity` and `NaN`
+    Json5,
+}
+
+impl JsonMode {
+    fn allows_comments_and_trailing_commas(self) -> bool {
+        matches!(self, Self::Jsonc | Self::Json5)
+    }
+
+    fn is_json5(self) -> bool {
+        self == Self::Json5
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonValue<'a> {
+    pub span: Span,
+    pub kind: JsonValueKind<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValueKind<'a> {
+    Null,
+    Boolean(bool),
+    Number(f64),
+    String(Cow<'a, str>),
+    Array(Vec<JsonValue<'a>>),
+    /// Properties in source order, duplicate keys are kept.
+    Object(Vec<JsonProperty<'a>>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonProperty<'a> {
+    pub key: Cow<'a, str>,
+    pub key_span: Span,
+    pub value: JsonValue<'a>,
+}
+
+impl<'a> JsonValue<'a> {
+    /// Get the value of property `key`, the last one wins for duplicate keys as in `JSON.parse`.
+    pub fn get(&self, key: &str) -> Option<&JsonValue<'a>> {
+        self.as_object()?.iter().rev().find(|property| property.key == key).map(|p| &p.value)
+    }
+
+    pub fn as_object(&self) -> Option<&[JsonProperty<'a>]> {
+        match &self.kind {
+            JsonValueKind::Object(properties) => Some(properties),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[JsonValue<'a>]> {
+        match &self.kind {
+            JsonValueKind::Array(elements) => Some(elements),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.kind {
+            JsonValueKind::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self.kind {
+            JsonValueKind::Number(number) => Some(number),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.kind {
+            JsonValueKind::Boolean(boolean) => Some(boolean),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.kind == JsonValueKind::Null
+    }
+}
+
+pub struct JsonParser<'a> {
+    source_text: &'a str,
+    mode: JsonMode,
+    /// Byte offset of the current character
+    pos: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    pub fn new(source_text: &'a str, mode: JsonMode) -> Self {
+        // A leading byte order mark is ignored
+        let pos = if source_text.starts_with('\u{feff}') { 3 } else { 0 };
+        Self { source_text, mode, pos }
+    }
+
+    /// Main entry point
+    ///
+    /// # Errors
+    ///
+    /// Returns the first syntax error.
+    pub fn parse(mut self) -> Result<JsonValue<'a>> {
+        self.skip_trivia()?;
+        let value = self.parse_value()?;
+        self.skip_trivia()?;
+        if self.peek().is_some() {
+            return Err(self.unexpected());
+        }
+        Ok(value)
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue<'a>> {
+        let start = self.pos;
+        let kind = match self.peek() {
+            Some('{') => self.parse_object()?,
+            Some('[') => self.parse_array()?,
+            Some('"') => JsonValueKind::String(self.parse_string('"')?),
+            Some('\'') if self.mode.is_json5() => JsonValueKind::String(self.parse_string('\'')?),
+            Some('-' | '0'..='9') => JsonValueKind::Number(self.parse_number()?),
+            Some('+' | '.') if self.mode.is_json5() => JsonValueKind::Number(self.parse_number()?),
+            Some(c) if is_identifier_start(c) => match self.parse_identifier() {
+                "null" => JsonValueKind::Null,
+                "true" => JsonValueKind::Boolean(true),
+                "false" => JsonValueKind::Boolean(false),
+                "Infinity" if self.mode.is_json5() => JsonValueKind::Number(f64::INFINITY),
+                "NaN" if self.mode.is_json5() => JsonValueKind::Number(f64::NAN),
+                _ => return Err(diagnostics::UnexpectedToken(self.span(start)).into()),
+            },
+            _ => return Err(self.unexpected()),
+        };
+        Ok(JsonValue { span: self.span(start), kind })
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValueKind<'a>> {
+        self.bump(); // bump `{`
+        let mut properties = vec![];
+        loop {
+            self.skip_trivia()?;
+            if self.eat('}') {
+                break;
+            }
+            let key_start = self.pos;
+            let key = match self.peek() {
+                Some('"') => self.parse_string('"')?,
+                Some('\'') if self.mode.is_json5() => self.parse_string('\'')?,
+                Some(c) if self.mode.is_json5() && is_identifier_start(c) => {
+                    Cow::Borrowed(self.parse_identifier())
+                }
+                _ => return Err(self.unexpected()),
+            };
+            let key_span = self.span(key_start);
+            self.skip_trivia()?;
+            self.expect(':')?;
+            self.skip_trivia()?;
+            let value = self.parse_value()?;
+            properties.push(JsonProperty { key, key_span, value });
+            if !self.parse_separator('}')? {
+                break;
+            }
+        }
+        Ok(JsonValueKind::Object(properties))
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValueKind<'a>> {
+        self.bump(); // bump `[`
+        let mut elements = vec![];
+        loop {
+            self.skip_trivia()?;
+            if self.eat(']') {
+                break;
+            }
+            elements.push(self.parse_value()?);
+            if !self.parse_separator(']')? {
+                break;
+            }
+        }
+        Ok(JsonValueKind::Array(elements))
+    }
+
+    /// Eat `,` or the `close` bracket. Returns `true` if there are more elements.
+    fn parse_separator(&mut self, close: char) -> Result<bool> {
+        self.skip_trivia()?;
+        if self.eat(close) {
+            return Ok(false);
+        }
+        let comma = self.pos;
+        self.expect(',')?;
+        if !self.mode.allows_comments_and_trailing_commas() {
+            self.skip_trivia()?;
+            if self.peek() == Some(close) {
+                return Err(diagnostics::TrailingComma(self.span(comma)).into());
+            }
+        }
+        Ok(true)
+    }
+
+    fn parse_string(&mut self, quote: char) -> Result<Cow<'a, str>> {
+        let start = self.pos;
+        self.bump(); // bump quote
+        let content_start = self.pos;
+        let mut value: Option<String> = None;
+        loop {
+            let Some(c) = self.peek() else {
+                return Err(diagnostics::UnterminatedString(self.span(start)).into());
+            };
+            match c {
+                c if c == quote => {
+                    let raw = &self.source_text[content_start..self.pos];
+                    self.bump();
+                    return Ok(value.map_or(Cow::Borrowed(raw), Cow::Owned));
+                }
+                '\\' => {
+                    let value = value.get_or_insert_with(|| {
+                        self.source_text[content_start..self.pos].to_string()
+                    });
+                    let escape_start = self.pos;
+                    self.bump();
+                    self.parse_escape(escape_start, value)?;
+                }
+                '\n' | '\r' => {
+                    return Err(diagnostics::UnterminatedString(self.span(start)).into());
+                }
+                c if c < ' ' && !self.mode.is_json5() => {
+                    return Err(diagnostics::InvalidCharacter(c, self.span(self.pos)).into());
+                }
+                c => {
+                    if let Some(value) = &mut value {
+                        value.push(c);
+                    }
+                    self.bump();
+                }
+            }
+        }
+    }
+
+    fn parse_escape(&mut self, start: usize, value: &mut String) -> Result<()> {
+        let Some(c) = self.peek() else {
+            return Err(diagnostics::InvalidEscapeSequence(self.span(start)).into());
+        };
+        self.bump();
+        let escaped = match c {
+            '"' | '\\' | '/' => c,
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let high = self.parse_hex(start, 4)?;
+                // Surrogate pairs are written as two escapes
+                let code_point = if (0xD800..0xDC00).contains(&high)
+                    && self.source_text[self.pos..].starts_with("\\u")
+                {
+                    let checkpoint = self.pos;
+                    self.pos += 2;
+                    let low = self.parse_hex(start, 4)?;
+                    if (0xDC00..0xE000).contains(&low) {
+                        0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                    } else {
+                        self.pos = checkpoint;
+                        high
+                    }
+                } else {
+                    high
+                };
+                char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER)
+            }
+            '\'' if self.mode.is_json5() => c,
+            'v' if self.mode.is_json5() => '\u{b}',
+            '0' if self.mode.is_json5() => '\0',
+            'x' if self.mode.is_json5() => {
+                let code_point = self.parse_hex(start, 2)?;
+                char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER)
+            }
+            // Line continuation
+            '\n' | '\u{2028}' | '\u{2029}' if self.mode.is_json5() => return Ok(()),
+            '\r' if self.mode.is_json5() => {
+                self.eat('\n');
+                return Ok(());
+            }
+            _ => return Err(diagnostics::InvalidEscapeSequence(self.span(start)).into()),
+        };
+        value.push(escaped);
+        Ok(())
+    }
+
+    fn parse_hex(&mut self, start: usize, len: usize) -> Result<u32> {
+        let digits = self.source_text.get(self.pos..self.pos + len);
+        let Some(code_point) = digits
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit()))
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+        else {
+            return Err(diagnostics::InvalidEscapeSequence(self.span(start)).into());
+        };
+        self.pos += len;
+        Ok(code_point)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn parse_number(&mut self) -> Result<f64> {
+        let start = self.pos;
+        let negative = match self.peek() {
+            Some('-') => {
+                self.bump();
+                true
+            }
+            Some('+') => {
+                self.bump();
+                false
+            }
+            _ => false,
+        };
+        let sign = if negative { -1.0 } else { 1.0 };
+
+        if self.mode.is_json5() {
+            if self.peek().is_some_and(is_identifier_start) {
+                return match self.parse_identifier() {
+                    "Infinity" => Ok(sign * f64::INFINITY),
+                    "NaN" => Ok(f64::NAN),
+                    _ => Err(diagnostics::InvalidNumber(
+                        "expected Infinity or NaN",
+                        self.span(start),
+                    )
+                    .into()),
+                };
+            }
+            if self.source_text[self.pos..].starts_with("0x")
+                || self.source_text[self.pos..].starts_with("0X")
+            {
+                self.pos += 2;
+                let digits_start = self.pos;
+                self.eat_while(|c| c.is_ascii_hexdigit());
+                let digits = &self.source_text[digits_start..self.pos];
+                return u64::from_str_radix(digits, 16).map(|value| sign * value as f64).map_err(
+                    |_| diagnostics::InvalidNumber("invalid hexadecimal", self.span(start)).into(),
+                );
+            }
+        }
+
+        let invalid = |p: &Self, reason: &'static str| -> Error {
+            diagnostics::InvalidNumber(reason, p.span(start)).into()
+        };
+
+        // Integer part: `0` or `[1-9][0-9]*`, optional in JSON5 when followed by a fraction
+        let integer_start = self.pos;
+        self.eat_while(|c| c.is_ascii_digit());
+        let integer = &self.source_text[integer_start..self.pos];
+        if integer.is_empty() && !(self.mode.is_json5() && self.peek() == Some('.')) {
+            return Err(invalid(self, "missing digits"));
+        }
+        if integer.len() > 1 && integer.starts_with('0') {
+            return Err(invalid(self, "leading zeros are not allowed"));
+        }
+
+        // Fraction, may be empty in JSON5
+        if self.eat('.') {
+            let fraction_start = self.pos;
+            self.eat_while(|c| c.is_ascii_digit());
+            let fraction_is_empty = fraction_start == self.pos;
+            if fraction_is_empty && (!self.mode.is_json5() || integer.is_empty()) {
+                return Err(invalid(self, "missing digits after decimal point"));
+            }
+        }
+
+        // Exponent
+        if self.eat('e') || self.eat('E') {
+            if !self.eat('+') {
+                self.eat('-');
+            }
+            let exponent_start = self.pos;
+            self.eat_while(|c| c.is_ascii_digit());
+            if exponent_start == self.pos {
+                return Err(invalid(self, "missing exponent"));
+            }
+        }
+
+        if self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == '.') {
+            return Err(diagnostics::InvalidNumberEnd(self.span(self.pos)).into());
+        }
+
+        let digits = &self.source_text[start..self.pos];
+        let digits = digits.strip_prefix('+').unwrap_or(digits);
+        digits.parse::<f64>().map_err(|_| invalid(self, "out of range"))
+    }
+
+    fn parse_identifier(&mut self) -> &'a str {
+        let start = self.pos;
+        self.eat_while(is_identifier_part);
+        &self.source_text[start..self.pos]
+    }
+
+    /// Whitespace, and comments in JSONC and JSON5
+    fn skip_trivia(&mut self) -> Result<()> {
+        loop {
+            match self.peek() {
+                Some(' ' | '\t' | '\n' | '\r') => self.bump(),
+                Some('\u{b}' | '\u{c}' | '\u{a0}' | '\u{feff}' | '\u{2028}' | '\u{2029}')
+                    if self.mode.is_json5() =>
+                {
+                    self.bump();
+                }
+                Some('/') if self.mode.allows_comments_and_trailing_commas() => {
+                    let rest = &self.source_text[self.pos..];
+                    if rest.starts_with("//") {
+                        self.eat_while(|c| c != '\n' && c != '\r');
+                    } else if rest.starts_with("/*") {
+                        let start = self.pos;
+                        let Some(end) = rest[2..].find("*/") else {
+                            self.pos = self.source_text.len();
+                            return Err(diagnostics::UnterminatedMultiLineComment(
+                                self.span(start),
+                            )
+                            .into());
+                        };
+                        self.pos += end + 4;
+                    } else {
+                        return Err(self.unexpected());
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source_text[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.bump();
+            return true;
+        }
+        false
+    }
+
+    fn eat_while<F: Fn(char) -> bool>(&mut self, predicate: F) {
+        while self.peek().is_some_and(&predicate) {
+            self.bump();
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        if self.eat(c) {
+            return Ok(());
+        }
+        Err(self.unexpected())
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn unexpected(&self) -> Error {
+        match self.peek() {
+            Some(c) => {
+                let span = Span::new(self.pos as u32, (self.pos + c.len_utf8()) as u32);
+                diagnostics::UnexpectedToken(span).into()
+            }
+            None => diagnostics::UnexpectedEnd(self.span(self.pos)).into(),
+        }
+    }
+
+    /// From `start` to the current position
+    #[allow(clippy::cast_possible_truncation)]
+    fn span(&self, start: usize) -> Span {
+        Span::new(start as u32, self.pos as u32)
+    }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$'
+}
+
+fn is_identifier_part(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use oxc_span::Span;
+
+    use super::{JsonMode, JsonParser, JsonValueKind};
+
+    #[test]
+    fn json() {
+        let source = r#"{ "a": [1, -2.5e3, true, null], "b": { "c": "d\né😀" } }"#;
+        let value = JsonParser::new(source, JsonMode::Json).parse().unwrap();
+        let a = value.get("a").unwrap();
+        assert_eq!(a.span, Span::new(7, 30));
+        let elements = a.as_array().unwrap();
+        assert_eq!(elements[1].as_f64(), Some(-2500.0));
+        assert_eq!(elements[2].as_bool(), Some(true));
+        assert!(elements[3].is_null());
+        let c = value.get("b").and_then(|b| b.get("c")).unwrap();
+        assert_eq!(c.as_str(), Some("d\né😀"));
+
+        let value = JsonParser::new(r#""plain""#, JsonMode::Json).parse().unwrap();
+        assert_eq!(value.kind, JsonValueKind::String(Cow::Borrowed("plain")));
+    }
+
+    #[test]
+    fn jsonc() {
+        let source = "{\n  // comment\n  \"a\": 1, /* block */\n  \"b\": [2,],\n}";
+        assert!(JsonParser::new(source, JsonMode::Json).parse().is_err());
+        let value = JsonParser::new(source, JsonMode::Jsonc).parse().unwrap();
+        assert_eq!(value.get("a").and_then(|a| a.as_f64()), Some(1.0));
+        assert_eq!(value.get("b").and_then(|b| b.as_array()).map(<[_]>::len), Some(1));
+    }
+
+    #[test]
+    fn json5() {
+        let source =
+            "{ unquoted: 'single', hex: 0xFF, lead: .5, trail: 5., plus: +1, inf: -Infinity, }";
+        assert!(JsonParser::new(source, JsonMode::Jsonc).parse().is_err());
+        let value = JsonParser::new(source, JsonMode::Json5).parse().unwrap();
+        assert_eq!(value.get("unquoted").and_then(|v| v.as_str()), Some("single"));
+        assert_eq!(value.get("hex").and_then(|v| v.as_f64()), Some(255.0));
+        assert_eq!(value.get("lead").and_then(|v| v.as_f64()), Some(0.5));
+        assert_eq!(value.get("trail").and_then(|v| v.as_f64()), Some(5.0));
+        assert_eq!(value.get("plus").and_then(|v| v.as_f64()), Some(1.0));
+        assert_eq!(value.get("inf").and_then(|v| v.as_f64()), Some(f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn errors() {
+        let cases = [
+            ("", "Unexpected end of file"),
+            ("{ \"a\": 1 ", "Unexpected end of file"),
+            ("[1, 2,]", "Trailing comma is not allowed"),
+            ("{ \"a\" 1 }", "Unexpected token"),
+            ("\"unterminated", "Unterminated string"),
+            ("01", "Invalid Number leading zeros are not allowed"),
+            ("[1] 2", "Unexpected token"),
+            ("undefined", "Unexpected token"),
+        ];
+        for (source, message) in cases {
+            let error = JsonParser::new(source, JsonMode::Json).parse().unwrap_err();
+            assert_eq!(error.to_string(), message, "{source}");
+        }
+
+        let error = JsonParser::new("{\n  \"a\": tru\n}", JsonMode::Json).parse().unwrap_err();
+        let label = error.labels().unwrap().next().unwrap();
+        assert_eq!((label.offset(), label.len()), (9, 3));
+    }
+}
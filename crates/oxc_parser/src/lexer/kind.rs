@@ -364,6 +364,14 @@ impl Kind {
         matches!(self, NoSubstitutionTemplate | TemplateHead)
     }
 
+    /// Keywords which start a statement, used to find where to resume after a syntax error.
+    #[rustfmt::skip]
+    pub fn is_statement_start(self) -> bool {
+        matches!(self, Var | Let | Const | Function | Class | If | For | While | Do | Return | Try
+            | Switch | Throw | Break | Continue | Import | Export | Debugger | With | Interface
+            | Type | Enum | Namespace | Module | Declare | Abstract)
+    }
+
     #[rustfmt::skip]
     pub fn is_modifier_kind(self) -> bool {
         matches!(self, Abstract | Accessor | Async | Const | Declare | Default
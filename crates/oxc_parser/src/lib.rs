@@ -73,6 +73,8 @@ mod ts;
 mod diagnostics;
 mod lexer;
 
+pub mod json;
+
 use context::{Context, StatementContext};
 use oxc_allocator::Allocator;
 use oxc_ast::{ast::Program, AstBuilder, Trivias};
@@ -134,6 +136,15 @@ pub struct Parser<'a> {
     /// Emit `ParenthesizedExpression` in AST.
     /// Default: `true`
     preserve_parens: bool,
+
+    /// Parse stage 3 proposals: import attributes, explicit resource management (`using`)
+    /// and decorators in JavaScript.
+    /// Default: `true`
+    stage3_proposals: bool,
+
+    /// Skip over statements with syntax errors instead of bailing on the first fatal error.
+    /// Default: `false`
+    error_recovery: bool,
 }
 
 impl<'a> Parser<'a> {
@@ -154,6 +165,8 @@ impl<'a> Parser<'a> {
             ctx: Self::default_context(source_type),
             ast: AstBuilder::new(allocator),
             preserve_parens: true,
+            stage3_proposals: true,
+            error_recovery: false,
         }
     }
 
@@ -177,6 +190,28 @@ impl<'a> Parser<'a> {
         self
     }
 
+    /// Allow stage 3 proposals.
+    ///
+    /// If this option is false, import attributes (`with { type: "json" }`), `using` declarations
+    /// and decorators in JavaScript files are reported as syntax errors.
+    #[must_use]
+    pub fn allow_stage3_proposals(mut self, allow: bool) -> Self {
+        self.stage3_proposals = allow;
+        self
+    }
+
+    /// Recover from fatal syntax errors.
+    ///
+    /// If this option is true, a statement which fails to parse is replaced by an `EmptyStatement`
+    /// spanning the skipped source, and parsing resumes at the next statement.
+    /// The returned program is a best-effort AST and `errors` contains all syntax errors,
+    /// which is useful for editors where the source is often incomplete.
+    #[must_use]
+    pub fn error_recovery(mut self, yes: bool) -> Self {
+        self.error_recovery = yes;
+        self
+    }
+
     /// Main entry point
     ///
     /// Returns an empty `Program` on unrecoverable error,
@@ -268,6 +303,13 @@ impl<'a> Parser<'a> {
     fn ts_enabled(&self) -> bool {
         self.source_type.is_typescript()
     }
+
+    /// Report `name` when stage 3 proposals are disabled.
+    fn check_stage3_proposal(&mut self, name: &'static str, span: Span) {
+        if !self.stage3_proposals {
+            self.error(diagnostics::Stage3Proposal(name, span));
+        }
+    }
 }
 
 #[cfg(test)]
@@ -300,6 +342,48 @@ mod test {
         assert_eq!(ret.errors.first().unwrap().to_string(), "Flow is not supported");
     }
 
+    #[test]
+    fn error_recovery() {
+        use oxc_ast::ast::Statement;
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "let a = ;\nfunction foo() {\n  return a +;\n}\nconst b = 2;";
+
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+
+        let ret = Parser::new(&allocator, source, source_type).error_recovery(true).parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 2);
+        assert_eq!(ret.program.body.len(), 3);
+        assert!(matches!(ret.program.body[0], Statement::EmptyStatement(_)));
+        assert!(matches!(ret.program.body[1], Statement::Declaration(_)));
+        assert!(matches!(ret.program.body[2], Statement::Declaration(_)));
+    }
+
+    #[test]
+    fn stage3_proposals() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let sources = [
+            "import json from './foo.json' with { type: 'json' };",
+            "import('./foo.json', { with: { type: 'json' } });",
+            "{ using x = foo(); }",
+            "@dec class Foo {}",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+
+            let ret =
+                Parser::new(&allocator, source, source_type).allow_stage3_proposals(false).parse();
+            assert_eq!(ret.errors.len(), 1, "{source}");
+            assert!(ret.errors[0].to_string().ends_with("are a stage 3 proposal"));
+        }
+    }
+
     // Source with length u32::MAX + 1 fails to parse
     #[test]
     fn overlong_source() {
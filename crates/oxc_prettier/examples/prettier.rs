@@ -22,7 +22,10 @@ fn main() {
     let source_text = std::fs::read_to_string(path).unwrap_or_else(|_| panic!("{name} not found"));
     let allocator = Allocator::default();
     let source_type = SourceType::from_path(path).unwrap();
-    let ret = Parser::new(&allocator, &source_text, source_type).preserve_parens(false).parse();
+    let ret = Parser::new(&allocator, &source_text, source_type)
+        .preserve_parens(false)
+        .error_recovery(true)
+        .parse();
     let output = Prettier::new(
         &allocator,
         &source_text,
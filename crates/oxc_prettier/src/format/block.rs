@@ -57,7 +57,7 @@ pub(super) fn print_block_body<'a>(
     is_root: bool,
 ) -> Option<Doc<'a>> {
     let has_directives = directives.is_some_and(|directives| !directives.is_empty());
-    let has_body = stmts.iter().any(|stmt| !matches!(stmt, Statement::EmptyStatement(_)));
+    let has_body = stmts.iter().any(|stmt| !p.is_empty_statement(stmt));
 
     if !has_body && !has_directives {
         return None;
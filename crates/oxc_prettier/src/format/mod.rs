@@ -140,7 +140,13 @@ impl<'a> Format<'a> for ExpressionStatement<'a> {
 
 impl<'a> Format<'a> for EmptyStatement {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Str("")
+        if !p.is_recovered(self.span) {
+            return Doc::Str("");
+        }
+        // A statement with a syntax error is printed as written, with the comments inside of it
+        let leading = p.print_leading_comments(self.span);
+        while p.trivias.next_if(|(_, end, _)| *end <= self.span.end).is_some() {}
+        p.print_comments(leading, Doc::Str(self.span.source_text(p.source_text)), None)
     }
 }
 
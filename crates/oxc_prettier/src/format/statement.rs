@@ -19,7 +19,7 @@ pub(super) fn print_statement_sequence<'a>(
     let len = stmts.len();
 
     for (i, stmt) in stmts.iter().enumerate() {
-        if skip_empty_statement && matches!(stmt, Statement::EmptyStatement(_)) {
+        if skip_empty_statement && p.is_empty_statement(stmt) {
             continue;
         }
 
@@ -40,7 +40,7 @@ pub(super) fn print_statement_sequence<'a>(
 
         parts.push(docs);
 
-        if i < len - 1 && !matches!(stmts[i + 1], Statement::EmptyStatement(_)) {
+        if i < len - 1 && !p.is_empty_statement(&stmts[i + 1]) {
             parts.extend(hardline!());
 
             if p.is_next_line_empty(stmt.span()) {
@@ -17,7 +17,10 @@ mod utils;
 use std::{iter::Peekable, vec};
 
 use oxc_allocator::Allocator;
-use oxc_ast::{ast::Program, AstKind, CommentKind, Trivias};
+use oxc_ast::{
+    ast::{Program, Statement},
+    AstKind, CommentKind, Trivias,
+};
 use oxc_span::Span;
 use oxc_syntax::identifier::is_line_terminator;
 
@@ -132,6 +135,18 @@ impl<'a> Prettier<'a> {
         unsafe { std::mem::transmute(t) }
     }
 
+    /// Whether `stmt` is an empty statement written as `;`, and not in place of a statement skipped
+    /// by the error recovery of the parser.
+    fn is_empty_statement(&self, stmt: &Statement<'a>) -> bool {
+        matches!(stmt, Statement::EmptyStatement(stmt) if !self.is_recovered(stmt.span))
+    }
+
+    /// The error recovery of the parser replaces a statement with a syntax error by an empty
+    /// statement spanning its source, which is never just `;`.
+    fn is_recovered(&self, span: Span) -> bool {
+        span.source_text(self.source_text) != ";"
+    }
+
     pub fn semi(&self) -> Option<Doc<'a>> {
         self.options.semi.then(|| Doc::Str(";"))
     }
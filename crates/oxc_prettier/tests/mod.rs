@@ -0,0 +1,31 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_prettier::{Prettier, PrettierOptions};
+use oxc_span::SourceType;
+
+fn format_with_errors(source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default();
+    let ret = Parser::new(&allocator, source_text, source_type)
+        .preserve_parens(false)
+        .error_recovery(true)
+        .parse();
+    assert!(!ret.errors.is_empty());
+    Prettier::new(&allocator, source_text, ret.trivias, PrettierOptions::default())
+        .build(&ret.program)
+}
+
+#[test]
+fn error_recovery() {
+    // Statements with syntax errors are printed as written
+    assert_eq!(format_with_errors("let a = ;\nconst   b=2"), "let a = ;\nconst b = 2;\n");
+    assert_eq!(
+        format_with_errors("function foo( ) {\n  return a +;\n  let   c=1\n}"),
+        "function foo() {\n  return a +;\n  let c = 1;\n}\n"
+    );
+    // Comments inside of them are not printed twice
+    assert_eq!(
+        format_with_errors("// leading\nlet a = /* inner */ ;\nb"),
+        "// leading\nlet a = /* inner */ ;\nb;\n"
+    );
+}
@@ -1,14 +1,19 @@
 //! Semantic Builder
 
-use std::{cell::RefCell, path::PathBuf, rc::Rc, sync::Arc};
+use std::{
+    cell::{OnceCell, RefCell},
+    path::PathBuf,
+    rc::Rc,
+    sync::Arc,
+};
 
 use itertools::Itertools;
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::{ast::*, AstKind, Trivias, TriviasMap, Visit};
 use oxc_diagnostics::Error;
-use oxc_span::{Atom, SourceType, Span};
+use oxc_span::{Atom, GetSpan, SourceType, Span};
 use oxc_syntax::{module_record::ModuleRecord, operator::AssignmentOperator};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     binder::Binder,
@@ -16,6 +21,7 @@ use crate::{
     class::ClassTableBuilder,
     diagnostics::Redeclaration,
     jsdoc::JSDocBuilder,
+    jsx::JsxOptions,
     module_record::ModuleRecordBuilder,
     node::{AstNode, AstNodeId, AstNodes, NodeFlags},
     reference::{Reference, ReferenceFlag, ReferenceId},
@@ -84,6 +90,11 @@ pub struct SemanticBuilder<'a> {
 
     check_syntax_error: bool,
 
+    /// JSX options of the project, then of the file once its pragma comments are read
+    jsx: JsxOptions,
+    /// References to the pragma variables implied by JSX elements and fragments
+    jsx_references: FxHashSet<ReferenceId>,
+
     redeclare_variables: RedeclareVariables,
     pub class_table_builder: ClassTableBuilder,
 }
@@ -117,6 +128,8 @@ impl<'a> SemanticBuilder<'a> {
             unused_labels: UnusedLabels { scopes: vec![], curr_scope: 0, labels: vec![] },
             jsdoc: JSDocBuilder::new(source_text, &trivias),
             check_syntax_error: false,
+            jsx: JsxOptions::default(),
+            jsx_references: FxHashSet::default(),
             redeclare_variables: RedeclareVariables { variables: vec![] },
             class_table_builder: ClassTableBuilder::new(),
         }
@@ -129,12 +142,28 @@ impl<'a> SemanticBuilder<'a> {
         self
     }
 
+    /// Reuse the comments of a previous analysis of the same source text, for a program which
+    /// was changed after it was parsed.
+    #[must_use]
+    pub fn with_trivias_of(mut self, semantic: &Semantic<'a>) -> Self {
+        self.trivias = Rc::clone(&semantic.trivias);
+        self.jsdoc = JSDocBuilder::new(self.source_text, &self.trivias);
+        self
+    }
+
     #[must_use]
     pub fn with_check_syntax_error(mut self, yes: bool) -> Self {
         self.check_syntax_error = yes;
         self
     }
 
+    /// JSX options of the project, overridden by the pragma comments of the file.
+    #[must_use]
+    pub fn with_jsx_options(mut self, jsx: JsxOptions) -> Self {
+        self.jsx = jsx;
+        self
+    }
+
     /// Get the built module record from `build_module_record`
     pub fn module_record(&self) -> Arc<ModuleRecord> {
         Arc::clone(&self.module_record)
@@ -157,7 +186,9 @@ impl<'a> SemanticBuilder<'a> {
         if self.source_type.is_typescript_definition() {
             self.scope.add_scope(None, ScopeFlags::Top);
         } else {
+            self.jsx = self.jsx.with_pragmas(self.source_text, &self.trivias);
             self.visit_program(program);
+            self.remove_unresolved_jsx_references();
 
             // Checking syntax error on module record requires scope information from the previous AST pass
             if self.check_syntax_error {
@@ -169,12 +200,14 @@ impl<'a> SemanticBuilder<'a> {
             source_text: self.source_text,
             source_type: self.source_type,
             trivias: self.trivias,
+            comments: OnceCell::new(),
             nodes: self.nodes,
             scopes: self.scope,
             symbols: self.symbols,
             classes: self.class_table_builder.build(),
             module_record: Arc::clone(&self.module_record),
             jsdoc: self.jsdoc.build(),
+            jsx: self.jsx,
             unused_labels: self.unused_labels.labels,
             redeclare_variables: self.redeclare_variables.variables,
         };
@@ -186,12 +219,14 @@ impl<'a> SemanticBuilder<'a> {
             source_text: self.source_text,
             source_type: self.source_type,
             trivias: self.trivias,
+            comments: OnceCell::new(),
             nodes: self.nodes,
             scopes: self.scope,
             symbols: self.symbols,
             classes: self.class_table_builder.build(),
             module_record: Arc::new(ModuleRecord::default()),
             jsdoc: self.jsdoc.build(),
+            jsx: self.jsx,
             unused_labels: self.unused_labels.labels,
             redeclare_variables: self.redeclare_variables.variables,
         }
@@ -479,6 +514,14 @@ impl<'a> SemanticBuilder<'a> {
             AstKind::JSXElementName(elem) => {
                 self.reference_jsx_element_name(elem);
             }
+            AstKind::JSXFragment(fragment) => {
+                let pragma = self.jsx.pragma_variable().map(Atom::from);
+                let pragma_frag = self.jsx.pragma_frag_variable().map(Atom::from);
+                let pragma_frag = pragma_frag.filter(|name| Some(name) != pragma.as_ref());
+                for name in pragma.into_iter().chain(pragma_frag) {
+                    self.reference_jsx_pragma(name, fragment.opening_fragment.span);
+                }
+            }
             AstKind::LabeledStatement(stmt) => {
                 self.unused_labels.scopes.push(LabeledScope {
                     name: stmt.label.name.as_str(),
@@ -560,6 +603,23 @@ impl<'a> SemanticBuilder<'a> {
         ident.reference_id.set(Some(reference_id));
     }
 
+    /// Whether the current identifier reference names a type, e.g. the `A` of `let a: A.B`,
+    /// `let a: typeof A`, `class C implements A {}` or `interface I extends A.B {}`.
+    /// The ancestors up to the type position are the names it is qualifying.
+    fn is_type_position(&self) -> bool {
+        for kind in self.nodes.iter_parents(self.current_node_id).skip(1).map(AstNode::kind) {
+            match kind {
+                AstKind::TSQualifiedName(_) | AstKind::MemberExpression(_) => {}
+                AstKind::TSTypeReference(_)
+                | AstKind::TSTypeQuery(_)
+                | AstKind::TSClassImplements(_)
+                | AstKind::TSInterfaceHeritage(_) => return true,
+                _ => return false,
+            }
+        }
+        false
+    }
+
     /// Resolve reference flags for the current ast node.
     fn resolve_reference_usages(&self) -> ReferenceFlag {
         let mut flags = ReferenceFlag::None;
@@ -568,6 +628,10 @@ impl<'a> SemanticBuilder<'a> {
             return ReferenceFlag::Read;
         }
 
+        if self.is_type_position() {
+            return ReferenceFlag::Type;
+        }
+
         // This func should only get called when an IdentifierReference is
         // reached
         debug_assert!(matches!(
@@ -648,7 +712,33 @@ impl<'a> SemanticBuilder<'a> {
                 );
                 self.declare_reference(reference);
             }
+            if let Some(pragma) = self.jsx.pragma_variable().map(Atom::from) {
+                self.reference_jsx_pragma(pragma, elem.span());
+            }
+        }
+    }
+
+    /// With the classic runtime, JSX reads the root of the pragma, e.g. `React` of
+    /// `React.createElement`, so that an import of `React` is used.
+    fn reference_jsx_pragma(&mut self, name: Atom, span: Span) {
+        let reference = Reference::new(span, name, self.current_node_id, ReferenceFlag::read());
+        let reference_id = self.declare_reference(reference);
+        self.jsx_references.insert(reference_id);
+    }
+
+    /// A pragma variable which is not declared is not an undefined global such as a typo, as
+    /// `no-undef` would report, `react-in-jsx-scope` reports it instead.
+    fn remove_unresolved_jsx_references(&mut self) {
+        if self.jsx_references.is_empty() {
+            return;
         }
+        let jsx_references = &self.jsx_references;
+        self.scope.unresolved_references_mut(self.scope.root_scope_id()).retain(
+            |_, reference_ids| {
+                reference_ids.retain(|reference_id| !jsx_references.contains(reference_id));
+                !reference_ids.is_empty()
+            },
+        );
     }
 
     fn symbol_flag_from_module_declaration(module: &ModuleDeclaration) -> SymbolFlags {
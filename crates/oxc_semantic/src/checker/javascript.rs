@@ -294,14 +294,8 @@ fn check_private_identifier_outside_class(ident: &PrivateIdentifier, ctx: &Seman
 fn check_private_identifier(ctx: &SemanticBuilder<'_>) {
     if let Some(class_id) = ctx.class_table_builder.current_class_id {
         ctx.class_table_builder.classes.iter_private_identifiers(class_id).for_each(|reference| {
-            if reference.property_id.is_none()
-                && reference.method_ids.is_empty()
-                && !ctx.class_table_builder.classes.ancestors(class_id).skip(1).any(|class_id| {
-                    ctx.class_table_builder
-                        .classes
-                        .has_private_definition(class_id, &reference.name)
-                })
-            {
+            // References to the members of enclosing classes are kept by those classes
+            if reference.property_id.is_none() && reference.method_ids.is_empty() {
                 #[derive(Debug, Error, Diagnostic)]
                 #[error("Private field '{0}' must be declared in an enclosing class")]
                 #[diagnostic()]
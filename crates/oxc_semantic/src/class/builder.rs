@@ -1,11 +1,12 @@
+use itertools::Itertools;
 use oxc_ast::{
-    ast::{ClassBody, ClassElement, MethodDefinition, PrivateIdentifier, PropertyDefinition},
+    ast::{ClassBody, ClassElement, MethodDefinition, PrivateIdentifier, PropertyKey},
     AstKind,
 };
 use oxc_span::GetSpan;
-use oxc_syntax::class::ClassId;
+use oxc_syntax::{class::ClassId, reference::ReferenceFlag};
 
-use crate::{AstNodeId, AstNodes};
+use crate::{AstNode, AstNodeId, AstNodes};
 
 use super::{
     table::{Method, PrivateIdentifierReference, Property},
@@ -39,7 +40,10 @@ impl ClassTableBuilder {
         for element in &class.body {
             match element {
                 ClassElement::PropertyDefinition(definition) => {
-                    self.declare_class_property(definition.0);
+                    self.declare_class_property(&definition.key, definition.r#static);
+                }
+                ClassElement::AccessorProperty(definition) => {
+                    self.declare_class_property(&definition.key, definition.r#static);
                 }
                 ClassElement::MethodDefinition(definition) => {
                     self.declare_class_method(definition.0);
@@ -49,15 +53,14 @@ impl ClassTableBuilder {
         }
     }
 
-    pub fn declare_class_property(&mut self, property: &PropertyDefinition) {
-        let is_private = property.key.is_private_identifier();
-        let name =
-            if is_private { property.key.private_name() } else { property.key.static_name() };
+    pub fn declare_class_property(&mut self, key: &PropertyKey, r#static: bool) {
+        let is_private = key.is_private_identifier();
+        let name = if is_private { key.private_name() } else { key.static_name() };
 
         if let Some(name) = name {
             if let Some(class_id) = self.current_class_id {
                 self.classes
-                    .add_property(class_id, Property::new(name, property.key.span(), is_private));
+                    .add_property(class_id, Property::new(name, key.span(), is_private, r#static));
             }
         }
     }
@@ -72,7 +75,12 @@ impl ClassTableBuilder {
         if let Some(parent_kind) = parent_kind {
             if matches!(parent_kind, AstKind::PrivateInExpression(_) | AstKind::MemberExpression(_))
             {
-                if let Some(class_id) = self.current_class_id {
+                if let Some(current_class_id) = self.current_class_id {
+                    // `#a` may be a member of an enclosing class
+                    let class_id = self
+                        .classes
+                        .get_private_declaring_class(current_class_id, &ident.name)
+                        .unwrap_or(current_class_id);
                     let property_id = self.classes.get_property_id(class_id, &ident.name);
                     let method_ids = if property_id.is_some() {
                         Vec::default()
@@ -86,6 +94,7 @@ impl ClassTableBuilder {
                         ident.span,
                         property_id,
                         method_ids,
+                        Self::resolve_reference_usages(current_node_id, nodes),
                     );
                     self.classes.add_private_identifier_reference(class_id, reference);
                 }
@@ -93,8 +102,51 @@ impl ClassTableBuilder {
         }
     }
 
+    /// Whether the private member of `obj.#a` or `#a in obj` is read, written or both.
+    ///
+    /// The value of an assignment or update of a member is not a read of it when the value is
+    /// unused, e.g. `this.#a += 1;` only updates `#a`.
+    fn resolve_reference_usages(current_node_id: AstNodeId, nodes: &AstNodes) -> ReferenceFlag {
+        for (curr, parent) in nodes
+            .iter_parents(nodes.parent_id(current_node_id).unwrap_or(current_node_id))
+            .tuple_windows::<(&AstNode<'_>, &AstNode<'_>)>()
+        {
+            match (curr.kind(), parent.kind()) {
+                (
+                    AstKind::SimpleAssignmentTarget(_) | AstKind::MemberExpression(_),
+                    AstKind::AssignmentTarget(_) | AstKind::SimpleAssignmentTarget(_),
+                ) => {}
+                (
+                    AstKind::AssignmentTarget(_),
+                    AstKind::ForInStatement(_)
+                    | AstKind::ForOfStatement(_)
+                    | AstKind::AssignmentTargetWithDefault(_)
+                    | AstKind::AssignmentTarget(_),
+                )
+                | (AstKind::SimpleAssignmentTarget(_), AstKind::AssignmentExpression(_)) => {
+                    return ReferenceFlag::write();
+                }
+                (AstKind::AssignmentTarget(_), AstKind::AssignmentExpression(_))
+                | (_, AstKind::UpdateExpression(_)) => {
+                    return if matches!(
+                        nodes.parent_kind(parent.id()),
+                        Some(AstKind::ExpressionStatement(_))
+                    ) {
+                        ReferenceFlag::write()
+                    } else {
+                        ReferenceFlag::read_write()
+                    };
+                }
+                _ => return ReferenceFlag::read(),
+            }
+        }
+
+        ReferenceFlag::read()
+    }
+
     pub fn declare_class_method(&mut self, method: &MethodDefinition) {
-        if method.kind.is_constructor() {
+        // Overload signatures of TypeScript are not members
+        if method.kind.is_constructor() || method.value.body.is_none() {
             return;
         }
         let is_private = method.key.is_private_identifier();
@@ -104,11 +156,12 @@ impl ClassTableBuilder {
             if let Some(class_id) = self.current_class_id {
                 self.classes.add_method(
                     class_id,
-                    Method::new(name, method.key.span(), is_private, method.kind),
+                    Method::new(name, method.key.span(), is_private, method.r#static, method.kind),
                 );
             }
         }
     }
+
     pub fn pop_class(&mut self) {
         self.current_class_id = self
             .current_class_id
@@ -2,4 +2,4 @@ mod builder;
 mod table;
 
 pub use builder::ClassTableBuilder;
-pub use table::ClassTable;
+pub use table::{ClassTable, Method, PrivateIdentifierReference, Property};
@@ -1,35 +1,50 @@
 use oxc_ast::ast::MethodDefinitionKind;
 use oxc_index::IndexVec;
 use oxc_span::{Atom, Span};
-use oxc_syntax::class::{ClassId, MethodId, PropertyId};
+use oxc_syntax::{
+    class::{ClassId, MethodId, PropertyId},
+    reference::ReferenceFlag,
+};
 use rustc_hash::FxHashMap;
 
 use crate::node::AstNodeId;
 
+/// PropertyDefinition or AccessorProperty
 #[derive(Debug)]
 pub struct Property {
     pub name: Atom,
+    /// Span of the key
     pub span: Span,
     pub is_private: bool,
+    pub r#static: bool,
 }
 
 impl Property {
-    pub fn new(name: Atom, span: Span, is_private: bool) -> Self {
-        Self { name, span, is_private }
+    pub fn new(name: Atom, span: Span, is_private: bool, r#static: bool) -> Self {
+        Self { name, span, is_private, r#static }
     }
 }
 
+/// MethodDefinition with a body, except the constructor
 #[derive(Debug)]
 pub struct Method {
     pub name: Atom,
+    /// Span of the key
     pub span: Span,
     pub is_private: bool,
+    pub r#static: bool,
     pub kind: MethodDefinitionKind,
 }
 
 impl Method {
-    pub fn new(name: Atom, span: Span, is_private: bool, kind: MethodDefinitionKind) -> Self {
-        Self { name, span, is_private, kind }
+    pub fn new(
+        name: Atom,
+        span: Span,
+        is_private: bool,
+        r#static: bool,
+        kind: MethodDefinitionKind,
+    ) -> Self {
+        Self { name, span, is_private, r#static, kind }
     }
 }
 
@@ -41,6 +56,9 @@ pub struct PrivateIdentifierReference {
     pub property_id: Option<PropertyId>,
     /// If the private identifier is used in a get/set method, this will be has 2 method ids
     pub method_ids: Vec<MethodId>,
+    /// Whether the member is read, written or both, e.g. `this.#a = 1` only writes `#a` and
+    /// `this.#a++` as a statement only updates it
+    pub flag: ReferenceFlag,
 }
 
 impl PrivateIdentifierReference {
@@ -50,8 +68,9 @@ impl PrivateIdentifierReference {
         span: Span,
         property_id: Option<PropertyId>,
         method_ids: Vec<MethodId>,
+        flag: ReferenceFlag,
     ) -> Self {
-        Self { id, name, span, property_id, method_ids }
+        Self { id, name, span, property_id, method_ids, flag }
     }
 }
 
@@ -66,7 +85,8 @@ pub struct ClassTable {
     pub properties: IndexVec<ClassId, IndexVec<PropertyId, Property>>,
     // MethodDefinition
     pub methods: IndexVec<ClassId, IndexVec<MethodId, Method>>,
-    // PrivateIdentifier reference
+    // PrivateIdentifier reference, in the class declaring the private member, or in the class of
+    // the reference if no enclosing class declares it
     pub private_identifiers: IndexVec<ClassId, Vec<PrivateIdentifierReference>>,
 }
 
@@ -90,9 +110,10 @@ impl ClassTable {
         self.declarations[class_id]
     }
 
+    /// The private property `#name` of the class
     pub fn get_property_id(&self, class_id: ClassId, name: &Atom) -> Option<PropertyId> {
         self.properties[class_id].iter_enumerated().find_map(|(property_id, property)| {
-            if property.name == *name {
+            if property.is_private && property.name == *name {
                 Some(property_id)
             } else {
                 None
@@ -100,10 +121,11 @@ impl ClassTable {
         })
     }
 
+    /// The private method `#name` of the class, or its getter and setter
     pub fn get_method_ids(&self, class_id: ClassId, name: &Atom) -> Vec<MethodId> {
         let mut method_ids = vec![];
         for (method_id, method) in self.methods[class_id].iter_enumerated() {
-            if method.name == *name {
+            if method.is_private && method.name == *name {
                 method_ids.push(method_id);
                 // Only have 1 method id for MethodDefinition::Method
                 if method.kind.is_method() {
@@ -119,6 +141,12 @@ impl ClassTable {
         method_ids
     }
 
+    /// The closest enclosing class of `class_id`, itself included, which declares the private
+    /// member `name`
+    pub fn get_private_declaring_class(&self, class_id: ClassId, name: &Atom) -> Option<ClassId> {
+        self.ancestors(class_id).find(|class_id| self.has_private_definition(*class_id, name))
+    }
+
     pub fn has_private_definition(&self, class_id: ClassId, name: &Atom) -> bool {
         self.properties[class_id].iter().any(|p| p.is_private && p.name == *name)
             || self.methods[class_id].iter().any(|m| m.is_private && m.name == *name)
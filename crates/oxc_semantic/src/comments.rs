@@ -0,0 +1,148 @@
+//! Comments of the source text attached to the nodes of the AST
+//!
+//! A comment is attached to the node it documents, for codegen which preserves comments, the
+//! formatter and rules which read directives or JSDoc, instead of each of them scanning the
+//! source text again:
+//!
+//! * a comment on the line of the end of a node is trailing of it: `a(); // c`
+//! * otherwise a comment is leading of the next node: `/** c */ function a() {}`
+//! * a comment at the end of a node, on a line of its own, is trailing of the previous node
+//! * a comment without nodes around it is inner of the node containing it: `a(/* c */)`
+
+use std::cmp::Reverse;
+
+use oxc_ast::{CommentKind, TriviasMap};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::node::{AstNodeId, AstNodes};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentPosition {
+    Leading,
+    Trailing,
+    Inner,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AttachedComment {
+    /// Span of the text of the comment, without `//` or `/*` and `*/`
+    pub span: Span,
+    pub kind: CommentKind,
+    /// The node the comment is attached to
+    pub node: AstNodeId,
+    pub position: CommentPosition,
+}
+
+impl AttachedComment {
+    /// Text of the comment without `//` or `/*` and `*/`
+    pub fn text<'a>(&self, source_text: &'a str) -> &'a str {
+        self.span.source_text(source_text)
+    }
+}
+
+/// All comments of a file, in source order, with the nodes they are attached to
+#[derive(Debug, Default)]
+pub struct CommentTable {
+    comments: Vec<AttachedComment>,
+    /// Indexes into `comments` by node
+    attached: FxHashMap<AstNodeId, Vec<usize>>,
+}
+
+impl CommentTable {
+    pub fn new(source_text: &str, nodes: &AstNodes, trivias: &TriviasMap) -> Self {
+        let Some(root) = nodes.iter().next().map(crate::AstNode::id) else {
+            return Self::default();
+        };
+        let span = |node_id: AstNodeId| nodes.kind(node_id).span();
+
+        // Nodes by start, an ancestor before its descendants with the same start
+        let mut starts =
+            nodes.iter().map(|node| (node.kind().span().start, node.id())).collect::<Vec<_>>();
+        starts.sort_by_key(|(start, _)| *start);
+        // Nodes by end, an ancestor after its descendants with the same end, so that the last node
+        // ending before a comment is the outermost one
+        let mut ends = nodes.iter().map(|node| (node.kind().span(), node.id())).collect::<Vec<_>>();
+        ends.sort_by_key(|(span, node_id)| (span.end, Reverse(span.start), Reverse(*node_id)));
+
+        let mut table = Self::default();
+        for (comment, comment_span) in trivias.comments_spans() {
+            let contains = |node_id: AstNodeId| {
+                span(node_id).start <= comment_span.start && comment_span.end <= span(node_id).end
+            };
+
+            // The innermost node containing the comment is an ancestor of the last node starting
+            // before the comment.
+            let last_started = starts.partition_point(|(start, _)| *start <= comment_span.start);
+            let enclosing = last_started
+                .checked_sub(1)
+                .and_then(|i| nodes.ancestors(starts[i].1).find(|node_id| contains(*node_id)))
+                .unwrap_or(root);
+            let enclosing_span = span(enclosing);
+
+            // The outermost node inside the enclosing node which ends before the comment
+            let preceding = ends
+                .partition_point(|(span, _)| span.end <= comment_span.start)
+                .checked_sub(1)
+                .map(|i| ends[i])
+                .filter(|(span, _)| enclosing_span.start <= span.start)
+                .map(|(_, node_id)| node_id);
+            // The outermost node inside the enclosing node which starts after the comment
+            let following = starts
+                .get(starts.partition_point(|(start, _)| *start < comment_span.end))
+                .filter(|(start, _)| *start < enclosing_span.end)
+                .map(|(_, node_id)| *node_id);
+
+            let (node, position) = match (preceding, following) {
+                (Some(node_id), _)
+                    if !source_text[span(node_id).end as usize..comment_span.start as usize]
+                        .contains(['\n', '\r', '\u{2028}', '\u{2029}']) =>
+                {
+                    (node_id, CommentPosition::Trailing)
+                }
+                (_, Some(node_id)) => (node_id, CommentPosition::Leading),
+                (Some(node_id), None) => (node_id, CommentPosition::Trailing),
+                (None, None) => (enclosing, CommentPosition::Inner),
+            };
+            table.attached.entry(node).or_default().push(table.comments.len());
+            table.comments.push(AttachedComment {
+                span: comment_span,
+                kind: comment.kind(),
+                node,
+                position,
+            });
+        }
+        table
+    }
+
+    /// All comments, in source order
+    pub fn iter(&self) -> impl Iterator<Item = &AttachedComment> + '_ {
+        self.comments.iter()
+    }
+
+    /// Comments attached to the node, in source order
+    pub fn comments_of(&self, node_id: AstNodeId) -> impl Iterator<Item = &AttachedComment> + '_ {
+        self.attached.get(&node_id).into_iter().flatten().map(|i| &self.comments[*i])
+    }
+
+    pub fn leading_comments(
+        &self,
+        node_id: AstNodeId,
+    ) -> impl Iterator<Item = &AttachedComment> + '_ {
+        self.comments_of(node_id).filter(|comment| comment.position == CommentPosition::Leading)
+    }
+
+    pub fn trailing_comments(
+        &self,
+        node_id: AstNodeId,
+    ) -> impl Iterator<Item = &AttachedComment> + '_ {
+        self.comments_of(node_id).filter(|comment| comment.position == CommentPosition::Trailing)
+    }
+
+    pub fn inner_comments(
+        &self,
+        node_id: AstNodeId,
+    ) -> impl Iterator<Item = &AttachedComment> + '_ {
+        self.comments_of(node_id).filter(|comment| comment.position == CommentPosition::Inner)
+    }
+}
@@ -0,0 +1,115 @@
+//! How the JSX of a file is compiled, which decides the variables JSX refers to:
+//!
+//! * the classic runtime compiles `<a />` to `React.createElement("a")`, so `React`, the root of
+//!   the pragma, must be in scope
+//! * the automatic runtime imports `jsx` from `react/jsx-runtime` by itself
+//!
+//! The options of the project are overridden by the pragma comments of the file, as in Babel and
+//! TypeScript: `@jsxRuntime classic`, `@jsx h`, `@jsxFrag Fragment` and `@jsxImportSource preact`.
+
+use oxc_ast::TriviasMap;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum JsxRuntime {
+    /// `React.createElement` calls
+    #[default]
+    Classic,
+    /// `jsx` calls imported from `{import_source}/jsx-runtime`
+    Automatic,
+}
+
+impl JsxRuntime {
+    pub fn is_classic(self) -> bool {
+        self == Self::Classic
+    }
+
+    pub fn is_automatic(self) -> bool {
+        self == Self::Automatic
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsxOptions {
+    pub runtime: JsxRuntime,
+    /// Function called for elements by the classic runtime, `React.createElement` by default
+    pub pragma: String,
+    /// Component of fragments of the classic runtime, `React.Fragment` by default
+    pub pragma_frag: String,
+    /// Module the automatic runtime imports from, `react` by default
+    pub import_source: String,
+}
+
+impl Default for JsxOptions {
+    fn default() -> Self {
+        Self {
+            runtime: JsxRuntime::default(),
+            pragma: "React.createElement".to_string(),
+            pragma_frag: "React.Fragment".to_string(),
+            import_source: "react".to_string(),
+        }
+    }
+}
+
+impl JsxOptions {
+    /// These options overridden by the pragma comments of a file. `@jsxRuntime` wins, otherwise
+    /// `@jsxImportSource` selects the automatic runtime and `@jsx` or `@jsxFrag` the classic one.
+    #[must_use]
+    pub fn with_pragmas(&self, source_text: &str, trivias: &TriviasMap) -> Self {
+        let mut options = self.clone();
+        let mut runtime = None;
+        let mut implied_runtime = None;
+        for (_, span) in trivias.comments_spans() {
+            let mut text = span.source_text(source_text);
+            while let Some(index) = text.find("@jsx") {
+                text = &text[index + "@jsx".len()..];
+                let name_end = text.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(text.len());
+                let (name, rest) = text.split_at(name_end);
+                if !rest.starts_with(char::is_whitespace) {
+                    continue;
+                }
+                let Some(value) = rest.split_whitespace().next() else { continue };
+                match name {
+                    "" => {
+                        options.pragma = value.to_string();
+                        implied_runtime.get_or_insert(JsxRuntime::Classic);
+                    }
+                    "Frag" => {
+                        options.pragma_frag = value.to_string();
+                        implied_runtime.get_or_insert(JsxRuntime::Classic);
+                    }
+                    "ImportSource" => {
+                        options.import_source = value.to_string();
+                        implied_runtime = Some(JsxRuntime::Automatic);
+                    }
+                    "Runtime" => match value {
+                        "classic" => runtime = Some(JsxRuntime::Classic),
+                        "automatic" => runtime = Some(JsxRuntime::Automatic),
+                        _ => {}
+                    },
+                    _ => {}
+                }
+            }
+        }
+        if let Some(runtime) = runtime.or(implied_runtime) {
+            options.runtime = runtime;
+        }
+        options
+    }
+
+    /// The variable which must be in scope for elements, `React` of `React.createElement`.
+    /// `None` with the automatic runtime.
+    pub fn pragma_variable(&self) -> Option<&str> {
+        self.runtime.is_classic().then(|| root_name(&self.pragma))
+    }
+
+    /// The variable which must be in scope for fragments, `React` of `React.Fragment`.
+    /// `None` with the automatic runtime.
+    pub fn pragma_frag_variable(&self) -> Option<&str> {
+        self.runtime.is_classic().then(|| root_name(&self.pragma_frag))
+    }
+}
+
+/// `React` of `React.createElement`
+fn root_name(pragma: &str) -> &str {
+    pragma.split('.').next().unwrap_or(pragma)
+}
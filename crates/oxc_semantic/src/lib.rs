@@ -2,21 +2,25 @@ mod binder;
 mod builder;
 mod checker;
 mod class;
+mod comments;
 mod diagnostics;
 mod jsdoc;
+mod jsx;
 mod module_record;
 mod node;
 mod reference;
 mod scope;
 mod symbol;
 
-use std::{rc::Rc, sync::Arc};
+use std::{cell::OnceCell, rc::Rc, sync::Arc};
 
 pub use builder::{SemanticBuilder, SemanticBuilderReturn};
-use class::ClassTable;
+pub use class::{ClassTable, Method, PrivateIdentifierReference, Property};
+pub use comments::{AttachedComment, CommentPosition, CommentTable};
 pub use jsdoc::{JSDoc, JSDocComment, JSDocTag};
+pub use jsx::{JsxOptions, JsxRuntime};
 use oxc_ast::{ast::IdentifierReference, AstKind, TriviasMap};
-use oxc_span::SourceType;
+use oxc_span::{Atom, SourceType};
 pub use oxc_syntax::{
     module_record::ModuleRecord,
     scope::{ScopeFlags, ScopeId},
@@ -46,10 +50,16 @@ pub struct Semantic<'a> {
 
     trivias: Rc<TriviasMap>,
 
+    /// Built on first use
+    comments: OnceCell<CommentTable>,
+
     module_record: Arc<ModuleRecord>,
 
     jsdoc: JSDoc<'a>,
 
+    /// JSX options of the file, with its pragma comments applied
+    jsx: JsxOptions,
+
     unused_labels: Vec<AstNodeId>,
 
     redeclare_variables: Vec<VariableInfo>,
@@ -88,10 +98,20 @@ impl<'a> Semantic<'a> {
         &self.trivias
     }
 
+    /// The comments with the nodes they are attached to
+    pub fn comments(&self) -> &CommentTable {
+        self.comments
+            .get_or_init(|| CommentTable::new(self.source_text, &self.nodes, &self.trivias))
+    }
+
     pub fn jsdoc(&self) -> &JSDoc<'a> {
         &self.jsdoc
     }
 
+    pub fn jsx(&self) -> &JsxOptions {
+        &self.jsx
+    }
+
     pub fn module_record(&self) -> &Arc<ModuleRecord> {
         &self.module_record
     }
@@ -126,6 +146,30 @@ impl<'a> Semantic<'a> {
         self.nodes.get_node(self.symbols.get_declaration(symbol_id))
     }
 
+    /// Find which scope a node belongs to
+    pub fn node_scope(&self, node_id: AstNodeId) -> ScopeId {
+        self.nodes.get_node(node_id).scope_id()
+    }
+
+    /// Get the bindings declared directly in a scope, in declaration order
+    pub fn scope_bindings(
+        &self,
+        scope_id: ScopeId,
+    ) -> impl Iterator<Item = (&Atom, SymbolId)> + '_ {
+        self.scopes.get_bindings(scope_id).iter().map(|(name, symbol_id)| (name, *symbol_id))
+    }
+
+    /// Get the symbol an identifier refers to, `None` for globals and unresolved identifiers
+    pub fn identifier_symbol(&self, ident: &IdentifierReference) -> Option<SymbolId> {
+        let reference_id = ident.reference_id.get()?;
+        self.symbols.get_reference(reference_id).symbol_id()
+    }
+
+    /// Get the declaration an identifier refers to
+    pub fn identifier_declaration(&self, ident: &IdentifierReference) -> Option<&AstNode<'a>> {
+        self.identifier_symbol(ident).map(|symbol_id| self.symbol_declaration(symbol_id))
+    }
+
     pub fn is_reference_to_global_variable(&self, ident: &IdentifierReference) -> bool {
         self.scopes().root_unresolved_references().contains_key(&ident.name)
     }
@@ -227,6 +271,69 @@ mod tests {
         assert!(semantic.symbols().references.len() == 1);
     }
 
+    #[test]
+    fn test_scope_and_reference_queries() {
+        let source = "
+            type T = number;
+            let a: T = 1;
+            function foo(b: T) {
+                a = b;
+            }";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_typescript(true);
+        let semantic = get_semantic(&allocator, source, source_type);
+        let root_scope_id = semantic.scopes().root_scope_id();
+
+        let names: Vec<_> =
+            semantic.scope_bindings(root_scope_id).map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["T", "a", "foo"]);
+
+        let t = semantic.scopes().get_root_binding(&Atom::from("T")).unwrap();
+        assert!(semantic.symbol_references(t).all(Reference::is_type));
+        assert_eq!(semantic.symbol_references(t).count(), 2);
+
+        let a = semantic.scopes().get_root_binding(&Atom::from("a")).unwrap();
+        let reference = semantic.symbol_references(a).next().unwrap();
+        assert!(reference.is_write() && !reference.is_read() && !reference.is_type());
+        assert_ne!(semantic.node_scope(reference.node_id()), root_scope_id);
+
+        let AstKind::IdentifierReference(ident) = semantic.nodes().kind(reference.node_id()) else {
+            panic!("expected an identifier reference");
+        };
+        let declaration = semantic.identifier_declaration(ident).unwrap();
+        assert!(matches!(declaration.kind(), AstKind::VariableDeclarator(_)));
+    }
+
+    #[test]
+    fn test_type_position_references() {
+        let alloc = Allocator::default();
+        let source_type = SourceType::default().with_typescript(true).with_module(true);
+        let sources = [
+            ("import a from 'a'; let b: a;", true),
+            ("import a from 'a'; let b: a.B.C;", true),
+            ("import a from 'a'; let b: typeof a;", true),
+            ("import a from 'a'; let b: typeof a.b;", true),
+            ("import a from 'a'; class B implements a {}", true),
+            ("import a from 'a'; class B implements a.C<a> {}", true),
+            ("import a from 'a'; interface B extends a {}", true),
+            ("import a from 'a'; interface B extends a.C {}", true),
+            ("import a from 'a'; class B extends a {}", false),
+            ("import a from 'a'; class B extends a.C {}", false),
+            ("import a from 'a'; let b = a.c;", false),
+        ];
+
+        for (source, is_type) in sources {
+            let semantic = get_semantic(&alloc, source, source_type);
+            let a = semantic.scopes().get_root_binding(&Atom::from("a")).unwrap();
+            let references = semantic.symbol_references(a).collect::<Vec<_>>();
+            assert!(!references.is_empty(), "expected references to 'a'\n\nsource:\n{source}");
+            for reference in references {
+                assert_eq!(reference.is_type(), is_type, "source:\n{source}");
+                assert_eq!(reference.is_read(), !is_type, "source:\n{source}");
+            }
+        }
+    }
+
     #[test]
     fn test_reference_resolutions_simple_read_write() {
         let alloc = Allocator::default();
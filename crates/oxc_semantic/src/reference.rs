@@ -37,6 +37,10 @@ impl Reference {
         self.symbol_id
     }
 
+    pub fn flag(&self) -> ReferenceFlag {
+        self.flag
+    }
+
     pub(crate) fn set_symbol_id(&mut self, symbol_id: SymbolId) {
         self.symbol_id = Some(symbol_id);
     }
@@ -52,4 +56,9 @@ impl Reference {
     pub fn is_write(&self) -> bool {
         self.flag.is_write()
     }
+
+    /// Returns `true` if the identifier is used as a type, e.g. `let a: A`
+    pub fn is_type(&self) -> bool {
+        self.flag.is_type()
+    }
 }
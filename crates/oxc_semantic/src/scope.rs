@@ -184,7 +184,7 @@ impl ScopeTree {
         let name = parts.join("$");
         let name = name.trim_start_matches('_');
         for i in 0.. {
-            let name = Self::generate_uid(name, i);
+            let name = Self::uid_name(name, i);
             if !self.has_binding(ScopeId::new(0), &name) {
                 return name;
             }
@@ -192,7 +192,22 @@ impl ScopeTree {
         unreachable!()
     }
 
-    fn generate_uid(name: &str, i: i32) -> Atom {
+    /// Generate `_{name}`, `_{name}2`, ... which is neither bound in any scope nor referenced as a
+    /// global, so it is safe to declare anywhere in the program.
+    pub fn generate_uid(&self, name: &str) -> Atom {
+        let name = name.trim_start_matches('_');
+        for i in 0.. {
+            let name = Self::uid_name(name, i);
+            if !self.bindings.iter().any(|bindings| bindings.contains_key(&name))
+                && !self.root_unresolved_references().contains_key(&name)
+            {
+                return name;
+            }
+        }
+        unreachable!()
+    }
+
+    fn uid_name(name: &str, i: i32) -> Atom {
         Atom::from(if i > 1 { format!("_{name}{i}") } else { format!("_{name}") })
     }
 }
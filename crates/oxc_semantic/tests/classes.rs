@@ -1,5 +1,6 @@
 mod util;
 
+use oxc_semantic::ReferenceFlag;
 use util::SemanticTester;
 
 #[test]
@@ -24,3 +25,42 @@ fn test_class_simple() {
     .has_method("a")
     .has_property("privateProperty");
 }
+
+#[test]
+fn test_private_identifier_references() {
+    let tester = SemanticTester::js(
+        "
+      class Foo {
+        #written = 1;
+        #read = 2;
+        #updated = 3;
+
+        method() {
+          this.#written = 1;
+          this.#updated++;
+          return class {
+            inner(foo) {
+              return foo.#read;
+            }
+          };
+        }
+      }
+    ",
+    );
+    let semantic = tester.build();
+    let (class_id, _) = semantic.classes().iter_enumerated().next().unwrap();
+    // The reference in the nested class is kept by the class declaring `#read`
+    let references = semantic
+        .classes()
+        .iter_private_identifiers(class_id)
+        .map(|reference| (reference.name.as_str(), reference.flag, reference.property_id.is_some()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        references,
+        [
+            ("written", ReferenceFlag::write(), true),
+            ("updated", ReferenceFlag::write(), true),
+            ("read", ReferenceFlag::read(), true),
+        ]
+    );
+}
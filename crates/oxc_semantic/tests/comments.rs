@@ -0,0 +1,68 @@
+mod util;
+
+use oxc_ast::AstKind;
+use oxc_semantic::{CommentPosition, Semantic};
+use util::SemanticTester;
+
+/// Texts of the comments with the kind of the node they are attached to and their position
+fn attachments<'a>(semantic: &'a Semantic) -> Vec<(&'a str, &'static str, CommentPosition)> {
+    semantic
+        .comments()
+        .iter()
+        .map(|comment| {
+            let kind = match semantic.nodes().kind(comment.node) {
+                AstKind::Program(_) => "Program",
+                AstKind::Function(_) => "Function",
+                AstKind::FunctionBody(_) => "FunctionBody",
+                AstKind::VariableDeclaration(_) => "VariableDeclaration",
+                AstKind::ExpressionStatement(_) => "ExpressionStatement",
+                AstKind::ReturnStatement(_) => "ReturnStatement",
+                AstKind::ModuleDeclaration(_) => "ModuleDeclaration",
+                _ => "other",
+            };
+            (comment.text(semantic.source_text()).trim(), kind, comment.position)
+        })
+        .collect()
+}
+
+#[test]
+fn test_comment_attachment() {
+    let tester = SemanticTester::js(
+        "
+        /** leading */
+        export function foo() {
+            // leading of return
+            return 1; // trailing of return
+            // trailing at the end of the body
+        }
+        const a = 1; /* trailing */
+        function bar(/* inner */) {}
+        ",
+    );
+    let semantic = tester.build();
+    assert_eq!(
+        attachments(&semantic),
+        [
+            ("* leading", "ModuleDeclaration", CommentPosition::Leading),
+            ("leading of return", "ReturnStatement", CommentPosition::Leading),
+            ("trailing of return", "ReturnStatement", CommentPosition::Trailing),
+            ("trailing at the end of the body", "ReturnStatement", CommentPosition::Trailing),
+            ("trailing", "VariableDeclaration", CommentPosition::Trailing),
+            ("inner", "other", CommentPosition::Inner),
+        ]
+    );
+}
+
+#[test]
+fn test_leading_comments() {
+    let tester = SemanticTester::js("// a\n// b\nfoo();\n");
+    let semantic = tester.build();
+    let statement = semantic
+        .nodes()
+        .iter()
+        .find(|node| matches!(node.kind(), AstKind::ExpressionStatement(_)))
+        .unwrap();
+    let comments = semantic.comments().leading_comments(statement.id()).collect::<Vec<_>>();
+    assert_eq!(comments.len(), 2);
+    assert_eq!(semantic.comments().trailing_comments(statement.id()).count(), 0);
+}
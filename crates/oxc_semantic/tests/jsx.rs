@@ -0,0 +1,47 @@
+mod util;
+
+use oxc_semantic::JsxRuntime;
+use util::SemanticTester;
+
+#[test]
+fn test_pragma_comments() {
+    let tester = SemanticTester::js("<div />").with_jsx(true);
+    let semantic = tester.build();
+    assert_eq!(semantic.jsx().runtime, JsxRuntime::Classic);
+    assert_eq!(semantic.jsx().pragma_variable(), Some("React"));
+
+    let tester = SemanticTester::js("/** @jsx h */\n<div />").with_jsx(true);
+    let semantic = tester.build();
+    assert_eq!(semantic.jsx().runtime, JsxRuntime::Classic);
+    assert_eq!(semantic.jsx().pragma_variable(), Some("h"));
+    assert_eq!(semantic.jsx().pragma_frag_variable(), Some("React"));
+
+    let tester = SemanticTester::js("/** @jsxImportSource preact */\n<div />").with_jsx(true);
+    let semantic = tester.build();
+    assert_eq!(semantic.jsx().runtime, JsxRuntime::Automatic);
+    assert_eq!(semantic.jsx().import_source, "preact");
+    assert_eq!(semantic.jsx().pragma_variable(), None);
+
+    let tester = SemanticTester::js("/* @jsxRuntime classic @jsxImportSource preact */\n<div />")
+        .with_jsx(true);
+    assert_eq!(tester.build().jsx().runtime, JsxRuntime::Classic);
+}
+
+#[test]
+fn test_pragma_references() {
+    SemanticTester::js("import React from 'react';\n<div><>a</></div>")
+        .with_jsx(true)
+        .has_root_symbol("React")
+        .has_number_of_reads(2)
+        .test();
+
+    SemanticTester::js("/** @jsxRuntime automatic */\nimport React from 'react';\n<div />")
+        .with_jsx(true)
+        .has_root_symbol("React")
+        .has_number_of_references(0)
+        .test();
+
+    // An undeclared pragma variable is not an unresolved reference
+    let tester = SemanticTester::js("<div />").with_jsx(true);
+    assert!(tester.build().scopes().root_unresolved_references().is_empty());
+}
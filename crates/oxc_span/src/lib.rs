@@ -3,11 +3,13 @@
 //! <https://doc.rust-lang.org/beta/nightly-rustc/rustc_span>
 
 mod atom;
+mod line_index;
 mod source_type;
 mod span;
 
 pub use crate::{
     atom::Atom,
+    line_index::LineIndex,
     source_type::{Language, LanguageVariant, ModuleKind, SourceType, VALID_EXTENSIONS},
     span::{GetSpan, Span, SPAN},
 };
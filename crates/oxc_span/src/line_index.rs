@@ -0,0 +1,216 @@
+use crate::Span;
+
+/// Line starts of a source text, for converting byte offsets to line and column positions.
+///
+/// Line terminators are `\n`, `\r\n`, `\r`, `<LS>` and `<PS>`. Lines are zero based,
+/// columns are byte offsets from the start of the line unless stated otherwise.
+///
+/// The index does not keep a copy of the text, methods that need to look at the characters of a
+/// line (UTF-16 columns and edits) take the current text as an argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineIndex {
+    /// Byte offsets of the start of each line, the first line always starts at `0`
+    line_starts: Vec<u32>,
+    len: u32,
+}
+
+impl LineIndex {
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn new(text: &str) -> Self {
+        let mut line_starts = vec![0];
+        scan_line_starts(text, 0, text.len() + 1, &mut line_starts);
+        Self { line_starts, len: text.len() as u32 }
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Length of the indexed text in bytes
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Byte offset of the start of `line`
+    pub fn line_start(&self, line: u32) -> Option<u32> {
+        self.line_starts.get(line as usize).copied()
+    }
+
+    /// Span of `line`, including its line terminator
+    pub fn line_span(&self, line: u32) -> Option<Span> {
+        let start = self.line_start(line)?;
+        let end = self.line_start(line + 1).unwrap_or(self.len);
+        Some(Span::new(start, end))
+    }
+
+    /// Zero based line containing the byte `offset`, offsets past the end map to the last line.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn line(&self, offset: u32) -> u32 {
+        (self.line_starts.partition_point(|&start| start <= offset) - 1) as u32
+    }
+
+    /// `(line, column)` of the byte `offset`, with the column in bytes.
+    pub fn line_column(&self, offset: u32) -> (u32, u32) {
+        let offset = offset.min(self.len);
+        let line = self.line(offset);
+        (line, offset - self.line_starts[line as usize])
+    }
+
+    /// `(line, column)` of the byte `offset`, with the column in UTF-16 code units as used by the
+    /// language server protocol and source maps.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn line_column_utf16(&self, text: &str, offset: u32) -> (u32, u32) {
+        let (line, column) = self.line_column(offset);
+        let start = self.line_starts[line as usize] as usize;
+        let line_text = text.get(start..start + column as usize).unwrap_or_default();
+        if line_text.is_ascii() {
+            return (line, column);
+        }
+        (line, line_text.encode_utf16().count() as u32)
+    }
+
+    /// Byte offset of a `line` and byte `column`, `None` if the position is outside of the text.
+    pub fn offset(&self, line: u32, column: u32) -> Option<u32> {
+        let span = self.line_span(line)?;
+        let offset = span.start.checked_add(column)?;
+        (offset <= span.end).then_some(offset)
+    }
+
+    /// Byte offset of a `line` and UTF-16 `column`.
+    /// Columns past the end of the line are clamped to the end of the line, before its line
+    /// terminator.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn offset_utf16(&self, text: &str, line: u32, column: u32) -> Option<u32> {
+        let span = self.line_span(line)?;
+        let line_text = text.get(span.start as usize..span.end as usize)?;
+        let line_text = line_text.strip_suffix("\r\n").unwrap_or_else(|| {
+            line_text
+                .strip_suffix(|c: char| matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}'))
+                .unwrap_or(line_text)
+        });
+        let mut utf16_column = 0;
+        for (byte_column, ch) in line_text.char_indices() {
+            if utf16_column >= column {
+                return Some(span.start + byte_column as u32);
+            }
+            utf16_column += ch.len_utf16() as u32;
+        }
+        Some(span.start + line_text.len() as u32)
+    }
+
+    /// Update the index after the bytes in `replaced` were replaced with `inserted_len` bytes.
+    ///
+    /// `text` is the text after the edit. Only the lines touched by the edit are rescanned,
+    /// the starts of all following lines are shifted.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn apply_edit(&mut self, text: &str, replaced: Span, inserted_len: u32) {
+        debug_assert!(replaced.start <= replaced.end);
+        debug_assert!(replaced.end <= self.len);
+        let delta = i64::from(inserted_len) - i64::from(replaced.size());
+
+        // A line starting right at the edit may be merged into the previous line, e.g. when
+        // inserting `\n` after `\r`, so rescan from the start of the line before it.
+        let first_changed =
+            self.line_starts.partition_point(|&start| start < replaced.start).max(1);
+        let scan_from = self.line_starts[first_changed - 1];
+        // Lines starting after the edit are preceded by an unchanged line terminator.
+        let first_unchanged = self.line_starts.partition_point(|&start| start <= replaced.end);
+        // Lines starting after the edit start at least `replaced.end`, so shifting them by the
+        // difference in length stays within the new text.
+        let shift = |start: u32| u32::try_from(i64::from(start) + delta).unwrap();
+        let scan_to = self
+            .line_starts
+            .get(first_unchanged)
+            .map_or(text.len() + 1, |&start| shift(start) as usize);
+
+        let mut rescanned = vec![];
+        scan_line_starts(text, scan_from as usize, scan_to, &mut rescanned);
+        let unchanged = self.line_starts[first_unchanged..].iter().map(|&start| shift(start));
+        let tail = rescanned.into_iter().chain(unchanged).collect::<Vec<_>>();
+        self.line_starts.truncate(first_changed);
+        self.line_starts.extend(tail);
+        self.len = text.len() as u32;
+    }
+}
+
+/// Push the starts of lines beginning after `from` and before `to`.
+#[allow(clippy::cast_possible_truncation)]
+fn scan_line_starts(text: &str, from: usize, to: usize, line_starts: &mut Vec<u32>) {
+    let bytes = text.as_bytes();
+    for (i, ch) in text[from..].char_indices() {
+        let start = from + i + ch.len_utf8();
+        if start >= to {
+            break;
+        }
+        match ch {
+            '\r' if bytes.get(start) == Some(&b'\n') => {}
+            '\n' | '\r' | '\u{2028}' | '\u{2029}' => line_starts.push(start as u32),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::cast_possible_truncation)]
+mod test {
+    use super::LineIndex;
+    use crate::Span;
+
+    #[test]
+    fn line_column() {
+        let text = "a\nbc\r\nd\re\u{2028}f";
+        let index = LineIndex::new(text);
+        assert_eq!(index.line_count(), 5);
+        assert_eq!(index.line_column(0), (0, 0));
+        assert_eq!(index.line_column(3), (1, 1));
+        assert_eq!(index.line_column(4), (1, 2));
+        assert_eq!(index.line_column(6), (2, 0));
+        assert_eq!(index.line_column(8), (3, 0));
+        assert_eq!(index.line_column(12), (4, 0));
+        assert_eq!(index.offset(1, 1), Some(3));
+        assert_eq!(index.offset(9, 0), None);
+    }
+
+    #[test]
+    fn utf16() {
+        let text = "const 𝒜 = 'é';\nx";
+        let index = LineIndex::new(text);
+        let offset = text.find('=').unwrap() as u32;
+        assert_eq!(index.line_column(offset), (0, 11));
+        assert_eq!(index.line_column_utf16(text, offset), (0, 9));
+        assert_eq!(index.offset_utf16(text, 0, 9), Some(offset));
+        assert_eq!(index.offset_utf16(text, 0, 100), Some(text.find('\n').unwrap() as u32));
+        assert_eq!(index.offset_utf16(text, 1, 0), Some(text.len() as u32 - 1));
+        assert_eq!(index.offset_utf16(text, 1, 100), Some(text.len() as u32));
+
+        // Clamped before the `\r\n`, not between `\r` and `\n`
+        let text = "ab\r\ncd\u{2028}";
+        let index = LineIndex::new(text);
+        assert_eq!(index.offset_utf16(text, 0, 3), Some(2));
+        assert_eq!(index.offset_utf16(text, 1, 3), Some(6));
+    }
+
+    #[test]
+    fn apply_edit() {
+        let cases = [
+            ("a\nb\nc", Span::new(2, 3), "x\ny\n"),
+            ("a\nb\nc", Span::new(1, 4), ""),
+            ("a\r", Span::new(2, 2), "\nb"),
+            ("a\r\nb", Span::new(2, 3), ""),
+            ("a\nb", Span::new(0, 0), "\n\n"),
+            ("a\nb", Span::new(3, 3), "\r"),
+            ("", Span::new(0, 0), "a\u{2029}b"),
+        ];
+        for (before, replaced, inserted) in cases {
+            let mut index = LineIndex::new(before);
+            let mut after = before.to_string();
+            after.replace_range(replaced.start as usize..replaced.end as usize, inserted);
+            index.apply_edit(&after, replaced, inserted.len() as u32);
+            assert_eq!(index, LineIndex::new(&after), "{before:?} {replaced:?} {inserted:?}");
+        }
+    }
+}
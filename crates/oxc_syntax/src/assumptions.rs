@@ -12,4 +12,10 @@ pub struct CompilerAssumptions {
     /// See <https://babeljs.io/docs/assumptions#nodocumentall>.
     #[cfg_attr(feature = "serde", serde(default))]
     pub no_document_all: bool,
+
+    /// When using public class fields, assume that they don't shadow any getter in the current class, in its subclasses or in its superclass.
+    /// Thus, it's safe to assign them rather than using `Object.defineProperty`.
+    /// See <https://babeljs.io/docs/assumptions#setpublicclassfields>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub set_public_class_fields: bool,
 }
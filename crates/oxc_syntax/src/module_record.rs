@@ -34,6 +34,12 @@ pub struct ModuleRecord {
     /// The list does not contain two different Records with the same `[[Specifier]]`.
     pub loaded_modules: DashMap<Atom, Arc<ModuleRecord>, BuildHasherDefault<FxHasher>>,
 
+    /// The files the specifiers of `requested_modules` and of `require` calls resolve to,
+    /// `None` for specifiers which failed to resolve.
+    ///
+    /// Filled by the linter with the import plugin, without the built-in modules of Node.js.
+    pub resolved_modules: DashMap<Atom, Option<PathBuf>, BuildHasherDefault<FxHasher>>,
+
     /// `[[ImportEntries]]`
     ///
     /// A List of ImportEntry records derived from the code of this module
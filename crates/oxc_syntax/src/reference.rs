@@ -12,6 +12,8 @@ bitflags! {
         const Read = 1 << 0;
         const Write = 1 << 1;
         const ReadWrite = Self::Read.bits() | Self::Write.bits();
+        /// Used in a type position, e.g. `let a: A`
+        const Type = 1 << 2;
     }
 }
 
@@ -52,4 +54,9 @@ impl ReferenceFlag {
     pub const fn is_read_write(&self) -> bool {
         self.contains(Self::ReadWrite)
     }
+
+    /// The identifier is used as a type, e.g `let a: A`. Type references are neither reads nor writes.
+    pub const fn is_type(&self) -> bool {
+        self.contains(Self::Type)
+    }
 }
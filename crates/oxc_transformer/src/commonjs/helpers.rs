@@ -0,0 +1,77 @@
+/// Runtime helpers inserted at the top of the program when used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Helper {
+    InteropRequireDefault,
+    InteropRequireWildcard,
+    Export,
+    ExportStar,
+    ReadOnlyError,
+}
+
+impl Helper {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::InteropRequireDefault => "_interopRequireDefault",
+            Self::InteropRequireWildcard => "_interopRequireWildcard",
+            Self::Export => "_export",
+            Self::ExportStar => "_exportStar",
+            Self::ReadOnlyError => "_readOnlyError",
+        }
+    }
+
+    pub fn source(self) -> &'static str {
+        match self {
+            Self::InteropRequireDefault => INTEROP_REQUIRE_DEFAULT,
+            Self::InteropRequireWildcard => INTEROP_REQUIRE_WILDCARD,
+            Self::Export => EXPORT,
+            Self::ExportStar => EXPORT_STAR,
+            Self::ReadOnlyError => READ_ONLY_ERROR,
+        }
+    }
+}
+
+// A CommonJS module is the default export of a module without `__esModule`.
+pub const INTEROP_REQUIRE_DEFAULT: &str = r#"function _interopRequireDefault(obj) {
+    return obj && obj.__esModule ? obj : { default: obj };
+}
+"#;
+
+// The namespace object of a CommonJS module, with its own properties and `module.exports` as the
+// default export.
+pub const INTEROP_REQUIRE_WILDCARD: &str = r#"function _interopRequireWildcard(obj) {
+    if (obj && obj.__esModule) return obj;
+    var newObj = { default: obj };
+    if (obj != null && (typeof obj === "object" || typeof obj === "function")) {
+        for (var key in obj) {
+            if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+                var desc = Object.getOwnPropertyDescriptor(obj, key);
+                if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
+                else newObj[key] = obj[key];
+            }
+        }
+    }
+    return newObj;
+}
+"#;
+
+// Exports are getters, so that the importers see the bindings updated by the module.
+pub const EXPORT: &str = r#"function _export(target, all) {
+    for (var name in all) Object.defineProperty(target, name, { enumerable: true, get: all[name] });
+}
+"#;
+
+// `export * from` does not override the exports of the module or re-export `default`.
+pub const EXPORT_STAR: &str = r#"function _exportStar(from, to) {
+    Object.keys(from).forEach(function (key) {
+        if (key === "default" || key === "__esModule" || Object.prototype.hasOwnProperty.call(to, key)) return;
+        Object.defineProperty(to, key, { enumerable: true, get: function () { return from[key]; } });
+    });
+    return from;
+}
+"#;
+
+// Imported bindings are immutable, assigning to them throws like in an ES module.
+pub const READ_ONLY_ERROR: &str = r#"function _readOnlyError(name) {
+    throw new TypeError("\"" + name + "\" is read-only");
+}
+"#;
@@ -0,0 +1,758 @@
+mod helpers;
+
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, AstBuilder, VisitMut};
+use oxc_parser::Parser;
+use oxc_span::{Atom, SourceType, SPAN};
+use oxc_syntax::{identifier::is_identifier_name, operator::AssignmentOperator, NumberBase};
+use rustc_hash::FxHashMap;
+
+use self::helpers::Helper;
+use crate::{context::TransformerCtx, options::ModuleFormat, TransformOptions};
+
+const ES_MODULE_FLAG: &str = "Object.defineProperty(exports, \"__esModule\", { value: true });\n";
+
+/// ES Modules to CommonJS
+///
+/// ```js
+/// import foo, { bar } from "foo";
+/// export const baz = bar(foo);
+/// ```
+///
+/// ```js
+/// "use strict";
+/// Object.defineProperty(exports, "__esModule", { value: true });
+/// _export(exports, { baz: function () { return baz; } });
+/// var _foo = _interopRequireWildcard(require("foo"));
+/// const baz = (0, _foo.bar)(_foo.default);
+/// ```
+///
+/// Imports are hoisted and read from the namespace object on every use, exports are getters, so
+/// bindings stay live in both directions. Imported functions are called and tagged without the
+/// namespace object as `this`, and assigning to an import throws, as in an ES module. `import.meta`
+/// is shimmed with `__filename` and `__dirname`, `import()` is lowered to a `require` in a promise.
+///
+/// Not supported: imports assigned by destructuring, e.g. `[foo] = []`, or as the left side of
+/// `for (foo of [])`, are not rejected.
+///
+/// References:
+/// * <https://babeljs.io/docs/babel-plugin-transform-modules-commonjs>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-modules-commonjs>
+pub struct CommonJs<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    helpers: std::vec::Vec<Helper>,
+    /// Imported bindings by local name
+    imports: FxHashMap<Atom, ImportBinding>,
+}
+
+struct ImportBinding {
+    /// The variable holding the result of `require`
+    namespace: Atom,
+    /// `None` for `import * as local`
+    imported: Option<Atom>,
+}
+
+#[derive(Default)]
+struct RequiredModule {
+    default: bool,
+    named: bool,
+    namespace_object: bool,
+    /// The variable holding the result of `require`, once declared
+    namespace: Option<Atom>,
+}
+
+impl RequiredModule {
+    fn interop(&self) -> Interop {
+        if self.namespace_object || (self.default && self.named) {
+            Interop::Wildcard
+        } else if self.default {
+            Interop::Default
+        } else {
+            Interop::None
+        }
+    }
+}
+
+/// How the exports of a required module are read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Interop {
+    /// Only named imports, read from `module.exports`
+    None,
+    /// Only the default import, `module.exports` itself for CommonJS modules
+    Default,
+    /// The namespace object or the default import with named imports
+    Wildcard,
+}
+
+impl<'a> CommonJs<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.module == ModuleFormat::CommonJs).then(|| Self {
+            ast,
+            ctx,
+            helpers: vec![],
+            imports: FxHashMap::default(),
+        })
+    }
+
+    pub fn transform_program(&mut self, program: &mut Program<'a>) {
+        if !program.source_type.is_module() {
+            return;
+        }
+        let has_exports = program.body.iter().any(|stmt| {
+            matches!(stmt, Statement::ModuleDeclaration(decl) if decl.is_export()
+                && !is_typescript_syntax(decl))
+        });
+
+        // The requires are hoisted like the imports, in the order of the first import of each
+        // module: var _foo = _interopRequireWildcard(require("foo"));
+        let mut modules = Self::required_modules(&program.body);
+        let mut requires = self.ast.new_vec();
+        let mut exports = vec![];
+        let mut body = self.ast.new_vec_with_capacity(program.body.len());
+        for stmt in self.ast.move_statement_vec(&mut program.body) {
+            let Statement::ModuleDeclaration(decl) = stmt else {
+                body.push(stmt);
+                continue;
+            };
+            if is_typescript_syntax(&decl) {
+                body.push(Statement::ModuleDeclaration(decl));
+                continue;
+            }
+            match decl.unbox() {
+                ModuleDeclaration::ImportDeclaration(decl) => {
+                    let decl = decl.unbox();
+                    let specifiers = decl.specifiers.unwrap_or_else(|| self.ast.new_vec());
+                    if specifiers.is_empty() {
+                        // import "foo";
+                        let require = self.require(decl.source.value.clone());
+                        requires.push(self.ast.expression_statement(SPAN, require));
+                        continue;
+                    }
+                    let namespace = self.namespace(&decl.source.value, &mut modules, &mut requires);
+                    for specifier in specifiers {
+                        let (local, imported) = match specifier {
+                            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                                if specifier.import_kind.is_type() {
+                                    continue;
+                                }
+                                (specifier.local, Some(specifier.imported.name().clone()))
+                            }
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                                (specifier.local, Some(Atom::from("default")))
+                            }
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                                (specifier.local, None)
+                            }
+                        };
+                        self.imports.insert(
+                            local.name,
+                            ImportBinding { namespace: namespace.clone(), imported },
+                        );
+                    }
+                }
+                ModuleDeclaration::ExportAllDeclaration(decl) => {
+                    let decl = decl.unbox();
+                    if let Some(exported) = decl.exported {
+                        // export * as foo from "foo";
+                        let namespace =
+                            self.namespace(&decl.source.value, &mut modules, &mut requires);
+                        exports.push((exported.name().clone(), self.identifier(namespace)));
+                    } else {
+                        // _exportStar(require("foo"), exports);
+                        let require = self.require(decl.source.value.clone());
+                        let callee = self.helper(Helper::ExportStar);
+                        let call = self.call(callee, [require, self.identifier("exports".into())]);
+                        requires.push(self.ast.expression_statement(SPAN, call));
+                    }
+                }
+                ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                    let decl = decl.unbox();
+                    let namespace = decl
+                        .source
+                        .map(|source| self.namespace(&source.value, &mut modules, &mut requires));
+                    for specifier in decl.specifiers {
+                        if specifier.export_kind.is_type() {
+                            continue;
+                        }
+                        let value = match &namespace {
+                            // export { foo as bar } from "foo";
+                            Some(namespace) => self.ast.static_member_expression(
+                                SPAN,
+                                self.identifier(namespace.clone()),
+                                IdentifierName::new(SPAN, specifier.local.name().clone()),
+                                false,
+                            ),
+                            // export { foo as bar };
+                            None => self.identifier(specifier.local.name().clone()),
+                        };
+                        exports.push((specifier.exported.name().clone(), value));
+                    }
+                    if let Some(declaration) = decl.declaration {
+                        // export const foo = 1;
+                        declaration.bound_names(&mut |ident| {
+                            exports.push((ident.name.clone(), self.identifier(ident.name.clone())));
+                        });
+                        body.push(Statement::Declaration(declaration));
+                    }
+                }
+                ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                    let default = Atom::from("default");
+                    match decl.unbox().declaration {
+                        // exports.default = foo;
+                        ExportDefaultDeclarationKind::Expression(expr) => {
+                            let target = self.ast.simple_assignment_target_member_expression(
+                                self.ast.static_member(
+                                    SPAN,
+                                    self.identifier("exports".into()),
+                                    IdentifierName::new(SPAN, default),
+                                    false,
+                                ),
+                            );
+                            let assignment = self.ast.assignment_expression(
+                                SPAN,
+                                AssignmentOperator::Assign,
+                                AssignmentTarget::SimpleAssignmentTarget(target),
+                                expr,
+                            );
+                            body.push(self.ast.expression_statement(SPAN, assignment));
+                        }
+                        ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                            let name = self.default_export_name(&mut func.id);
+                            exports.push((default, self.identifier(name)));
+                            body.push(self.ast.function_declaration(func));
+                        }
+                        ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                            let name = self.default_export_name(&mut class.id);
+                            exports.push((default, self.identifier(name)));
+                            body.push(Statement::Declaration(Declaration::ClassDeclaration(class)));
+                        }
+                        _ => {}
+                    }
+                }
+                decl => body.push(Statement::ModuleDeclaration(self.ast.alloc(decl))),
+            }
+        }
+
+        let mut stmts = self.ast.new_vec();
+        if has_exports {
+            // Object.defineProperty(exports, "__esModule", { value: true });
+            stmts.extend(
+                Parser::new(self.ast.allocator, ES_MODULE_FLAG, SourceType::default())
+                    .parse()
+                    .program
+                    .body,
+            );
+        }
+        if !exports.is_empty() {
+            // _export(exports, { foo: function () { return foo; } });
+            let mut properties = self.ast.new_vec_with_capacity(exports.len());
+            for (exported, value) in exports {
+                let key = if is_identifier_name(&exported) {
+                    self.ast.property_key_identifier(IdentifierName::new(SPAN, exported))
+                } else {
+                    self.ast.property_key_expression(
+                        self.ast.literal_string_expression(StringLiteral::new(SPAN, exported)),
+                    )
+                };
+                let getter = self.getter(value);
+                properties.push(ObjectPropertyKind::ObjectProperty(self.ast.object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    key,
+                    getter,
+                    None,
+                    false,
+                    false,
+                    false,
+                )));
+            }
+            let callee = self.helper(Helper::Export);
+            let all = self.ast.object_expression(SPAN, properties, None);
+            let call = self.call(callee, [self.identifier("exports".into()), all]);
+            stmts.push(self.ast.expression_statement(SPAN, call));
+        }
+        stmts.extend(requires);
+        stmts.extend(body);
+        program.body = stmts;
+
+        // Read the imports from the namespace objects
+        self.visit_statements(&mut program.body);
+
+        self.add_helpers(program);
+        if !program.directives.iter().any(|directive| directive.directive == "use strict") {
+            let use_strict = Atom::from("use strict");
+            let expression = StringLiteral::new(SPAN, use_strict.clone());
+            program.directives.insert(0, self.ast.directive(SPAN, expression, use_strict));
+        }
+    }
+
+    /// The variable holding the required module, declared on first use.
+    fn namespace(
+        &mut self,
+        source: &Atom,
+        modules: &mut FxHashMap<Atom, RequiredModule>,
+        requires: &mut Vec<'a, Statement<'a>>,
+    ) -> Atom {
+        let module = modules.entry(source.clone()).or_default();
+        if let Some(namespace) = &module.namespace {
+            return namespace.clone();
+        }
+        let namespace = self.create_binding(&source_name(source));
+        module.namespace = Some(namespace.clone());
+        let interop = module.interop();
+        let require = self.require(source.clone());
+        let init = match interop {
+            Interop::None => require,
+            Interop::Default => self.call_helper(Helper::InteropRequireDefault, require),
+            Interop::Wildcard => self.call_helper(Helper::InteropRequireWildcard, require),
+        };
+        requires.push(self.var(namespace.clone(), init));
+        namespace
+    }
+
+    /// The modules imported or re-exported, with what is read from them.
+    fn required_modules(stmts: &Vec<'a, Statement<'a>>) -> FxHashMap<Atom, RequiredModule> {
+        let mut modules = FxHashMap::<Atom, RequiredModule>::default();
+        let mut add = |source: &Atom, default: bool, named: bool, namespace: bool| {
+            let module = modules.entry(source.clone()).or_default();
+            module.default |= default;
+            module.named |= named;
+            module.namespace_object |= namespace;
+        };
+        for stmt in stmts.iter() {
+            let Statement::ModuleDeclaration(decl) = stmt else { continue };
+            if is_typescript_syntax(decl) {
+                continue;
+            }
+            match &**decl {
+                ModuleDeclaration::ImportDeclaration(decl) => {
+                    let Some(specifiers) = &decl.specifiers else { continue };
+                    for specifier in specifiers.iter() {
+                        match specifier {
+                            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                                if !specifier.import_kind.is_type() {
+                                    let default = specifier.imported.name().as_str() == "default";
+                                    add(&decl.source.value, default, !default, false);
+                                }
+                            }
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                                add(&decl.source.value, true, false, false);
+                            }
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => {
+                                add(&decl.source.value, false, false, true);
+                            }
+                        }
+                    }
+                }
+                ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                    let Some(source) = &decl.source else { continue };
+                    for specifier in decl.specifiers.iter() {
+                        if !specifier.export_kind.is_type() {
+                            let default = specifier.local.name().as_str() == "default";
+                            add(&source.value, default, !default, false);
+                        }
+                    }
+                }
+                ModuleDeclaration::ExportAllDeclaration(decl) if decl.exported.is_some() => {
+                    add(&decl.source.value, false, false, true);
+                }
+                _ => {}
+            }
+        }
+        modules
+    }
+
+    /// Insert the used runtime helpers at the top of the program.
+    fn add_helpers(&mut self, program: &mut Program<'a>) {
+        if self.helpers.is_empty() {
+            return;
+        }
+        self.helpers.sort_unstable();
+        let mut stmts = self.ast.new_vec();
+        for helper in &self.helpers {
+            let ret =
+                Parser::new(self.ast.allocator, helper.source(), SourceType::default()).parse();
+            stmts.extend(ret.program.body);
+        }
+        stmts.extend(self.ast.move_statement_vec(&mut program.body));
+        program.body = stmts;
+    }
+
+    /// Name the anonymous `export default function () {}` and `export default class {}`.
+    fn default_export_name(&self, id: &mut Option<BindingIdentifier>) -> Atom {
+        id.get_or_insert_with(|| BindingIdentifier::new(SPAN, self.create_binding("default")))
+            .name
+            .clone()
+    }
+
+    /// `_name`, `_name2`, ... not bound in the module yet
+    fn create_binding(&self, name: &str) -> Atom {
+        let scopes = self.ctx.scopes();
+        let root_scope_id = scopes.root_scope_id();
+        let name = (1..)
+            .map(|i| Atom::from(if i > 1 { format!("_{name}{i}") } else { format!("_{name}") }))
+            .find(|name| !scopes.has_binding(root_scope_id, name))
+            .unwrap();
+        drop(scopes);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    /// The namespace member read by a reference to an import, if `ident` is one.
+    fn import_reference(&self, ident: &IdentifierReference) -> Option<&ImportBinding> {
+        let import = self.imports.get(&ident.name)?;
+        // References created by the other transforms are not resolved.
+        if let Some(reference_id) = ident.reference_id.get() {
+            let root_scope_id = self.ctx.scopes().root_scope_id();
+            let symbols = self.ctx.symbols();
+            let symbol_id = symbols.get_reference(reference_id).symbol_id()?;
+            if symbols.get_scope_id(symbol_id) != root_scope_id {
+                return None;
+            }
+        }
+        Some(import)
+    }
+
+    /// `(0, _foo.bar)` for a reference to the named import `bar`
+    fn unbound_import_expression(&self, expr: &Expression<'a>) -> Option<Expression<'a>> {
+        let Expression::Identifier(ident) = expr else { return None };
+        let import = self.import_reference(ident).filter(|import| import.imported.is_some())?;
+        let member = self.import_expression(import);
+        let zero = self.ast.literal_number_expression(self.ast.number_literal(
+            SPAN,
+            0.0,
+            "0",
+            NumberBase::Decimal,
+        ));
+        let mut expressions = self.ast.new_vec_with_capacity(2);
+        expressions.push(zero);
+        expressions.push(member);
+        Some(self.ast.sequence_expression(SPAN, expressions))
+    }
+
+    /// The name of the import assigned to by `target`
+    fn assigned_import(&self, target: &SimpleAssignmentTarget) -> Option<Atom> {
+        let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target else { return None };
+        self.import_reference(ident).map(|_| ident.name.clone())
+    }
+
+    /// `_readOnlyError("foo")`
+    fn read_only_error(&mut self, name: Atom) -> Expression<'a> {
+        let name = self.ast.literal_string_expression(StringLiteral::new(SPAN, name));
+        self.call_helper(Helper::ReadOnlyError, name)
+    }
+
+    fn import_expression(&self, import: &ImportBinding) -> Expression<'a> {
+        let namespace = self.identifier(import.namespace.clone());
+        match &import.imported {
+            Some(imported) => self.ast.static_member_expression(
+                SPAN,
+                namespace,
+                IdentifierName::new(SPAN, imported.clone()),
+                false,
+            ),
+            None => namespace,
+        }
+    }
+
+    /// `import.meta.url`, `import.meta.filename` and `import.meta.dirname`
+    fn import_meta_property(&self, property: &str) -> Option<Expression<'a>> {
+        match property {
+            // require("url").pathToFileURL(__filename).href
+            "url" => {
+                let url = self.require("url".into());
+                let path_to_file_url = self.ast.static_member_expression(
+                    SPAN,
+                    url,
+                    IdentifierName::new(SPAN, "pathToFileURL".into()),
+                    false,
+                );
+                let file_url = self.call(path_to_file_url, [self.identifier("__filename".into())]);
+                Some(self.ast.static_member_expression(
+                    SPAN,
+                    file_url,
+                    IdentifierName::new(SPAN, "href".into()),
+                    false,
+                ))
+            }
+            "filename" => Some(self.identifier("__filename".into())),
+            "dirname" => Some(self.identifier("__dirname".into())),
+            _ => None,
+        }
+    }
+
+    /// `Promise.resolve(source).then(function (s) { return _interopRequireWildcard(require(s)); })`
+    fn dynamic_import(&mut self, source: Expression<'a>) -> Expression<'a> {
+        let specifier = Atom::from("s");
+        let require = self.require_expression(self.identifier(specifier.clone()));
+        let namespace = self.call_helper(Helper::InteropRequireWildcard, require);
+        let ident = self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, specifier));
+        let pattern = self.ast.binding_pattern(ident, None, false);
+        let param = self.ast.formal_parameter(SPAN, pattern, None, false, self.ast.new_vec());
+        let params = self.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            self.ast.new_vec_single(param),
+            None,
+        );
+        let callback = self.function_expression(params, namespace);
+
+        let promise = self.identifier("Promise".into());
+        let resolve = self.ast.static_member_expression(
+            SPAN,
+            promise,
+            IdentifierName::new(SPAN, "resolve".into()),
+            false,
+        );
+        let resolved = self.call(resolve, [source]);
+        let then = self.ast.static_member_expression(
+            SPAN,
+            resolved,
+            IdentifierName::new(SPAN, "then".into()),
+            false,
+        );
+        self.call(then, [callback])
+    }
+
+    /// `function () { return value; }`
+    fn getter(&self, value: Expression<'a>) -> Expression<'a> {
+        let params = self.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            self.ast.new_vec(),
+            None,
+        );
+        self.function_expression(params, value)
+    }
+
+    fn function_expression(
+        &self,
+        params: oxc_allocator::Box<'a, FormalParameters<'a>>,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        let statements = self.ast.new_vec_single(self.ast.return_statement(SPAN, Some(value)));
+        let body = self.ast.function_body(SPAN, self.ast.new_vec(), statements);
+        let func = self.ast.function(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            false,
+            false,
+            None,
+            params,
+            Some(body),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        self.ast.function_expression(func)
+    }
+
+    fn var(&self, name: Atom, init: Expression<'a>) -> Statement<'a> {
+        let ident = self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name));
+        let id = self.ast.binding_pattern(ident, None, false);
+        let kind = VariableDeclarationKind::Var;
+        let declarator = self.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        let declarations = self.ast.new_vec_single(declarator);
+        let decl = self.ast.variable_declaration(SPAN, kind, declarations, Modifiers::empty());
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+
+    /// `require("source")`
+    fn require(&self, source: Atom) -> Expression<'a> {
+        self.require_expression(
+            self.ast.literal_string_expression(StringLiteral::new(SPAN, source)),
+        )
+    }
+
+    fn require_expression(&self, source: Expression<'a>) -> Expression<'a> {
+        self.call(self.identifier("require".into()), [source])
+    }
+
+    fn call_helper(&mut self, helper: Helper, argument: Expression<'a>) -> Expression<'a> {
+        let callee = self.helper(helper);
+        self.call(callee, [argument])
+    }
+
+    fn helper(&mut self, helper: Helper) -> Expression<'a> {
+        if !self.helpers.contains(&helper) {
+            self.helpers.push(helper);
+        }
+        self.identifier(helper.name().into())
+    }
+
+    fn call<const N: usize>(
+        &self,
+        callee: Expression<'a>,
+        arguments: [Expression<'a>; N],
+    ) -> Expression<'a> {
+        let mut args = self.ast.new_vec_with_capacity(N);
+        for argument in arguments {
+            args.push(Argument::Expression(argument));
+        }
+        self.ast.call_expression(SPAN, callee, args, false, None)
+    }
+
+    fn identifier(&self, name: Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name))
+    }
+}
+
+impl<'a> VisitMut<'a> for CommonJs<'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::Identifier(ident) => {
+                if let Some(import) = self.import_reference(ident) {
+                    *expr = self.import_expression(import);
+                }
+            }
+            // `(0, _foo.bar)()` and `(0, _foo.bar)```, so that `this` is not the namespace object
+            Expression::CallExpression(call) => {
+                if let Some(callee) = self.unbound_import_expression(&call.callee) {
+                    call.callee = callee;
+                }
+            }
+            Expression::TaggedTemplateExpression(tagged) => {
+                if let Some(tag) = self.unbound_import_expression(&tagged.tag) {
+                    tagged.tag = tag;
+                }
+            }
+            // `(value, _readOnlyError("foo"))` for `foo = value`
+            Expression::AssignmentExpression(assignment) => {
+                if let AssignmentTarget::SimpleAssignmentTarget(target) = &assignment.left {
+                    if let Some(name) = self.assigned_import(target) {
+                        let value = self.ast.move_expression(&mut assignment.right);
+                        let mut expressions = self.ast.new_vec_with_capacity(2);
+                        expressions.push(value);
+                        expressions.push(self.read_only_error(name));
+                        *expr = self.ast.sequence_expression(SPAN, expressions);
+                    }
+                }
+            }
+            // `_readOnlyError("foo")` for `foo++`
+            Expression::UpdateExpression(update) => {
+                if let Some(name) = self.assigned_import(&update.argument) {
+                    *expr = self.read_only_error(name);
+                }
+            }
+            Expression::MemberExpression(member) => {
+                if let MemberExpression::StaticMemberExpression(member) = &**member {
+                    if matches!(&member.object, Expression::MetaProperty(meta) if is_import_meta(meta))
+                    {
+                        if let Some(property) = self.import_meta_property(&member.property.name) {
+                            *expr = property;
+                        }
+                    }
+                }
+            }
+            // { url, filename, dirname }
+            Expression::MetaProperty(meta) if is_import_meta(meta) => {
+                let mut properties = self.ast.new_vec_with_capacity(3);
+                for name in ["url", "filename", "dirname"] {
+                    let key =
+                        self.ast.property_key_identifier(IdentifierName::new(SPAN, name.into()));
+                    let value = self.import_meta_property(name).unwrap();
+                    properties.push(ObjectPropertyKind::ObjectProperty(self.ast.object_property(
+                        SPAN,
+                        PropertyKind::Init,
+                        key,
+                        value,
+                        None,
+                        false,
+                        false,
+                        false,
+                    )));
+                }
+                *expr = self.ast.object_expression(SPAN, properties, None);
+            }
+            Expression::ImportExpression(import) => {
+                let source = self.ast.move_expression(&mut import.source);
+                *expr = self.dynamic_import(source);
+            }
+            _ => {}
+        }
+        self.visit_expression_match(expr);
+    }
+}
+
+/// Type-only imports and exports, and `export =` which is not an ES module
+fn is_typescript_syntax(decl: &ModuleDeclaration) -> bool {
+    match decl {
+        ModuleDeclaration::ImportDeclaration(decl) => decl.import_kind.is_type(),
+        ModuleDeclaration::ExportAllDeclaration(decl) => decl.is_typescript_syntax(),
+        ModuleDeclaration::ExportDefaultDeclaration(decl) => decl.is_typescript_syntax(),
+        ModuleDeclaration::ExportNamedDeclaration(decl) => decl.is_typescript_syntax(),
+        ModuleDeclaration::TSExportAssignment(_)
+        | ModuleDeclaration::TSNamespaceExportDeclaration(_) => true,
+    }
+}
+
+fn is_import_meta(meta: &MetaProperty) -> bool {
+    meta.meta.name == "import" && meta.property.name == "meta"
+}
+
+/// `./foo-bar.js` -> `foo_bar`
+fn source_name(source: &str) -> String {
+    let name = source.rsplit('/').find(|part| !part.is_empty() && *part != "." && *part != "..");
+    let name = name.map_or("", |name| name.split('.').next().unwrap_or(name));
+    let name = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '$' || c == '_' { c } else { '_' })
+        .collect::<String>();
+    if name.is_empty() {
+        "module".to_string()
+    } else {
+        name
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let options =
+        TransformOptions { module: ModuleFormat::CommonJs, ..TransformOptions::default() };
+
+    let tests = &[
+        (
+            "import foo, { bar } from './foo.js'; bar(foo);",
+            "'use strict'; function _interopRequireWildcard(obj) { if (obj && obj.__esModule) return obj; var newObj = { default: obj }; if (obj != null && (typeof obj === 'object' || typeof obj === 'function')) { for (var key in obj) { if (key !== 'default' && Object.prototype.hasOwnProperty.call(obj, key)) { var desc = Object.getOwnPropertyDescriptor(obj, key); if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc); else newObj[key] = obj[key]; } } } return newObj; } var _foo = _interopRequireWildcard(require('./foo.js')); (0, _foo.bar)(_foo.default);",
+        ),
+        (
+            "import { a } from 'a'; import 'b'; export const b = a; export { a as c }; export default 1;",
+            "'use strict'; function _export(target, all) { for (var name in all) Object.defineProperty(target, name, { enumerable: true, get: all[name] }); } Object.defineProperty(exports, '__esModule', { value: true }); _export(exports, { b: function () { return b; }, c: function () { return _a.a; } }); var _a = require('a'); require('b'); const b = _a.a; exports.default = 1;",
+        ),
+        (
+            "export default function () {} export * from 'a';",
+            "'use strict'; function _export(target, all) { for (var name in all) Object.defineProperty(target, name, { enumerable: true, get: all[name] }); } function _exportStar(from, to) { Object.keys(from).forEach(function (key) { if (key === 'default' || key === '__esModule' || Object.prototype.hasOwnProperty.call(to, key)) return; Object.defineProperty(to, key, { enumerable: true, get: function () { return from[key]; } }); }); return from; } Object.defineProperty(exports, '__esModule', { value: true }); _export(exports, { default: function () { return _default; } }); _exportStar(require('a'), exports); function _default() {}",
+        ),
+        // shadowed bindings are not imports
+        (
+            "import a from 'a'; function f(a) { return a; } a;",
+            "'use strict'; function _interopRequireDefault(obj) { return obj && obj.__esModule ? obj : { default: obj }; } var _a = _interopRequireDefault(require('a')); function f(a) { return a; } _a.default;",
+        ),
+        // `this` is not the namespace object in tagged templates
+        (
+            "import { tag } from 'a'; tag`x`;",
+            "'use strict'; var _a = require('a'); (0, _a.tag)`x`;",
+        ),
+        (
+            "import { a } from 'a'; a = 1; a++;",
+            "'use strict'; function _readOnlyError(name) { throw new TypeError('\"' + name + '\" is read-only'); } var _a = require('a'); 1, _readOnlyError('a'); _readOnlyError('a');",
+        ),
+        (
+            "console.log(import.meta.url, import.meta.dirname);",
+            "'use strict'; console.log(require('url').pathToFileURL(__filename).href, __dirname);",
+        ),
+    ];
+
+    Tester::new("test.mjs", options).test(tests);
+}
@@ -4,9 +4,9 @@ use std::{
     rc::Rc,
 };
 
-use oxc_ast::AstBuilder;
+use oxc_ast::{ast::Program, AstBuilder};
 use oxc_diagnostics::Error;
-use oxc_semantic::{ScopeId, ScopeTree, Semantic, SymbolId, SymbolTable};
+use oxc_semantic::{ScopeId, ScopeTree, Semantic, SemanticBuilder, SymbolId, SymbolTable};
 use oxc_span::{Atom, SourceType};
 
 #[derive(Clone)]
@@ -25,6 +25,19 @@ impl<'a> TransformerCtx<'a> {
         self.semantic.borrow()
     }
 
+    /// Analyze the program again, after it was changed by a plugin.
+    pub(crate) fn rebuild_semantic(&self, program: &Program<'a>) {
+        let semantic = {
+            let previous = self.semantic();
+            SemanticBuilder::new(previous.source_text(), *previous.source_type())
+                .with_trivias_of(&previous)
+                .with_jsx_options(previous.jsx().clone())
+                .build(program)
+                .semantic
+        };
+        *self.semantic.borrow_mut() = semantic;
+    }
+
     pub fn symbols(&self) -> Ref<'_, SymbolTable> {
         Ref::map(self.semantic.borrow(), |semantic| semantic.symbols())
     }
@@ -0,0 +1,137 @@
+/// Runtime helpers inserted at the top of the program when used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Helper {
+    Decorate,
+    Metadata,
+    Param,
+    ApplyDecs,
+}
+
+impl Helper {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Decorate => "__decorate",
+            Self::Metadata => "__metadata",
+            Self::Param => "__param",
+            Self::ApplyDecs => "_applyDecs",
+        }
+    }
+
+    pub fn source(self) -> &'static str {
+        match self {
+            Self::Decorate => DECORATE,
+            Self::Metadata => METADATA,
+            Self::Param => PARAM,
+            Self::ApplyDecs => APPLY_DECS,
+        }
+    }
+}
+
+// Same as the helpers emitted by `tsc`, so `Reflect.decorate` from `reflect-metadata` is respected.
+pub const DECORATE: &str = r#"var __decorate = (this && this.__decorate) || function (decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+};
+"#;
+
+pub const METADATA: &str = r#"var __metadata = (this && this.__metadata) || function (k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+};
+"#;
+
+pub const PARAM: &str = r#"var __param = (this && this.__param) || function (paramIndex, decorator) {
+    return function (target, key) { decorator(target, key, paramIndex); }
+};
+"#;
+
+// `memberDecs` is a list of `[decorators, kind, name, isStatic]`, kind is one of
+// 0 (field), 1 (method), 2 (getter) and 3 (setter).
+//
+// Returns
+// * `e`: the initializers of the decorated fields, called with the instance and the initial value.
+// * `p`: runs the initializers added by instance member decorators, called with the instance.
+// * `c`: applies the class decorators and returns the decorated class.
+pub const APPLY_DECS: &str = r#"function _applyDecs(targetClass, memberDecs, classDecs) {
+    var metadataSymbol = Symbol.metadata || Symbol.for("Symbol.metadata");
+    var parentMetadata = Object.getPrototypeOf(targetClass)[metadataSymbol];
+    var metadata = Object.create(parentMetadata === undefined ? null : parentMetadata);
+    var protoInitializers = [], staticInitializers = [], classInitializers = [], fieldInitializers = [];
+    function createContext(kind, name, isStatic, initializers, state) {
+        var context = {
+            kind: kind,
+            name: name,
+            metadata: metadata,
+            addInitializer: function (initializer) {
+                if (state.done) throw new TypeError("addInitializer can only be called while decorating");
+                if (typeof initializer !== "function") throw new TypeError("An initializer must be a function");
+                initializers.push(initializer);
+            }
+        };
+        if (kind !== "class") {
+            context.static = isStatic;
+            context.private = false;
+            context.access = {
+                has: function (object) { return name in object; },
+                get: function (object) { return object[name]; }
+            };
+            if (kind === "field" || kind === "setter") context.access.set = function (object, value) { object[name] = value; };
+        }
+        return context;
+    }
+    function runInitializers(initializers, target) {
+        for (var i = 0; i < initializers.length; i++) initializers[i].call(target);
+        return target;
+    }
+    function createFieldInitializer(initializers) {
+        return function (instance, value) {
+            for (var i = 0; i < initializers.length; i++) value = initializers[i].call(instance, value);
+            return value;
+        };
+    }
+    for (var i = 0; i < memberDecs.length; i++) {
+        var decs = memberDecs[i][0], kind = memberDecs[i][1], name = memberDecs[i][2], isStatic = memberDecs[i][3];
+        var target = isStatic ? targetClass : targetClass.prototype;
+        var descriptor = kind === 0 ? undefined : Object.getOwnPropertyDescriptor(target, name);
+        var value = kind === 0 ? undefined : kind === 1 ? descriptor.value : kind === 2 ? descriptor.get : descriptor.set;
+        var initializers = [];
+        for (var j = decs.length - 1; j >= 0; j--) {
+            var state = { done: false };
+            var context = createContext(["field", "method", "getter", "setter"][kind], name, isStatic, isStatic ? staticInitializers : protoInitializers, state);
+            var result = decs[j](value, context);
+            state.done = true;
+            if (result === undefined) continue;
+            if (typeof result !== "function") throw new TypeError("A decorator must return a function or undefined");
+            if (kind === 0) initializers.push(result);
+            else value = result;
+        }
+        if (kind === 0) {
+            fieldInitializers.push(createFieldInitializer(initializers));
+        } else {
+            if (kind === 1) descriptor.value = value;
+            else if (kind === 2) descriptor.get = value;
+            else descriptor.set = value;
+            Object.defineProperty(target, name, descriptor);
+        }
+    }
+    runInitializers(staticInitializers, targetClass);
+    return {
+        e: fieldInitializers,
+        p: function (instance) { runInitializers(protoInitializers, instance); },
+        c: function () {
+            var newClass = targetClass;
+            for (var i = classDecs.length - 1; i >= 0; i--) {
+                var state = { done: false };
+                var result = classDecs[i](newClass, createContext("class", targetClass.name, false, classInitializers, state));
+                state.done = true;
+                if (result === undefined) continue;
+                if (typeof result !== "function") throw new TypeError("A class decorator must return a class or undefined");
+                newClass = result;
+            }
+            Object.defineProperty(targetClass, metadataSymbol, { enumerable: true, configurable: true, writable: true, value: metadata });
+            return runInitializers(classInitializers, newClass);
+        }
+    };
+}
+"#;
@@ -0,0 +1,125 @@
+use oxc_ast::ast::*;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+
+use super::{helpers::Helper, Decorators};
+
+/// Serialize type annotations into runtime values for `emitDecoratorMetadata`.
+///
+/// References:
+/// * <https://github.com/microsoft/TypeScript/blob/main/src/compiler/transformers/typeSerializer.ts>
+impl<'a> Decorators<'a> {
+    /// `__metadata("design:type", String)`
+    pub(super) fn metadata(&mut self, key: &str, value: Expression<'a>) -> Expression<'a> {
+        let mut arguments = self.ast.new_vec();
+        arguments.push(Argument::Expression(self.string_literal(key.into())));
+        arguments.push(Argument::Expression(value));
+        let callee = self.helper(Helper::Metadata);
+        self.ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    /// `[String, Number]`
+    pub(super) fn serialize_parameter_types(
+        &self,
+        params: &FormalParameters<'a>,
+    ) -> Expression<'a> {
+        let mut elements = self.ast.new_vec();
+        for param in &params.items {
+            let ty = self.serialize_type_annotation(param.pattern.type_annotation.as_deref());
+            elements.push(ArrayExpressionElement::Expression(ty));
+        }
+        if let Some(rest) = &params.rest {
+            let ty = self.serialize_type_annotation(rest.argument.type_annotation.as_deref());
+            elements.push(ArrayExpressionElement::Expression(ty));
+        }
+        self.ast.array_expression(SPAN, elements, None)
+    }
+
+    pub(super) fn serialize_return_type(&self, func: &Function<'a>) -> Expression<'a> {
+        match &func.return_type {
+            Some(return_type) => self.serialize_type(&return_type.type_annotation),
+            None if func.r#async => self.identifier("Promise"),
+            None => self.ast.void_0(),
+        }
+    }
+
+    pub(super) fn serialize_type_annotation(
+        &self,
+        type_annotation: Option<&TSTypeAnnotation<'a>>,
+    ) -> Expression<'a> {
+        type_annotation.map_or_else(
+            || self.identifier("Object"),
+            |ty| self.serialize_type(&ty.type_annotation),
+        )
+    }
+
+    fn serialize_type(&self, ty: &TSType<'a>) -> Expression<'a> {
+        match ty {
+            TSType::TSStringKeyword(_) | TSType::TSTemplateLiteralType(_) => {
+                self.identifier("String")
+            }
+            TSType::TSNumberKeyword(_) => self.identifier("Number"),
+            TSType::TSBooleanKeyword(_) => self.identifier("Boolean"),
+            TSType::TSBigIntKeyword(_) => self.identifier("BigInt"),
+            TSType::TSSymbolKeyword(_) => self.identifier("Symbol"),
+            TSType::TSVoidKeyword(_)
+            | TSType::TSUndefinedKeyword(_)
+            | TSType::TSNeverKeyword(_)
+            | TSType::TSNullKeyword(_) => self.ast.void_0(),
+            TSType::TSArrayType(_) | TSType::TSTupleType(_) => self.identifier("Array"),
+            TSType::TSFunctionType(_) | TSType::TSConstructorType(_) => self.identifier("Function"),
+            TSType::TSLiteralType(literal) => match &literal.literal {
+                TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => {
+                    self.identifier("String")
+                }
+                TSLiteral::NumberLiteral(_) | TSLiteral::UnaryExpression(_) => {
+                    self.identifier("Number")
+                }
+                TSLiteral::BooleanLiteral(_) => self.identifier("Boolean"),
+                TSLiteral::BigintLiteral(_) => self.identifier("BigInt"),
+                TSLiteral::NullLiteral(_) => self.ast.void_0(),
+                TSLiteral::RegExpLiteral(_) => self.identifier("Object"),
+            },
+            TSType::TSTypeReference(reference) => match &reference.type_name {
+                TSTypeName::IdentifierReference(ident) => {
+                    self.serialize_type_reference(&ident.name)
+                }
+                TSTypeName::QualifiedName(_) => self.identifier("Object"),
+            },
+            // `string | null` -> `String`
+            TSType::TSUnionType(union) => {
+                let mut types = union.types.iter().filter(|ty| {
+                    !matches!(
+                        ty,
+                        TSType::TSNullKeyword(_)
+                            | TSType::TSUndefinedKeyword(_)
+                            | TSType::TSNeverKeyword(_)
+                    )
+                });
+                match (types.next(), types.next()) {
+                    (Some(ty), None) => self.serialize_type(ty),
+                    _ => self.identifier("Object"),
+                }
+            }
+            _ => self.identifier("Object"),
+        }
+    }
+
+    /// The reference may be a type only declaration such as an interface,
+    /// `typeof Foo === "undefined" ? Object : Foo`
+    fn serialize_type_reference(&self, name: &Atom) -> Expression<'a> {
+        let reference = self.identifier(name);
+        let test = self.ast.binary_expression(
+            SPAN,
+            self.ast.unary_expression(SPAN, UnaryOperator::Typeof, reference),
+            BinaryOperator::StrictEquality,
+            self.string_literal("undefined".into()),
+        );
+        self.ast.conditional_expression(
+            SPAN,
+            test,
+            self.identifier("Object"),
+            self.identifier(name),
+        )
+    }
+}
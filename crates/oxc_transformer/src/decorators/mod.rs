@@ -0,0 +1,928 @@
+mod helpers;
+mod metadata;
+mod options;
+
+use std::{mem, rc::Rc};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_parser::Parser;
+use oxc_span::{Atom, SourceType, Span, SPAN};
+use oxc_syntax::{operator::AssignmentOperator, NumberBase};
+
+use self::helpers::Helper;
+pub use self::options::{DecoratorsOptions, DecoratorsVersion};
+use crate::{context::TransformerCtx, options::TransformOptions};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Legacy decorators are only supported on class declarations.")]
+#[diagnostic(help("Convert the class expression into a class declaration."))]
+struct LegacyDecoratorOnClassExpression(#[label] Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Decorators on computed or private class members are not supported yet.")]
+struct UnsupportedDecoratedMember(#[label] Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Decorators on `accessor` fields are not supported yet.")]
+struct UnsupportedDecoratedAccessor(#[label] Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Parameter decorators are only supported by legacy decorators.")]
+#[diagnostic(help("Set the decorators version to `legacy`."))]
+struct ParameterDecoratorInStage3(#[label] Span);
+
+/// Temporaries of a class transformed with the stage 3 decorators.
+struct Stage3Names {
+    /// `_decorated`, the result of `_applyDecs`
+    decorated: Atom,
+    /// `_classDecs`
+    class_decs: Atom,
+    /// `_memberDecs`
+    member_decs: Atom,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ClassExport {
+    None,
+    Named,
+    Default,
+}
+
+/// Decorators
+///
+/// * Legacy: TypeScript's `experimentalDecorators`, with `emitDecoratorMetadata` support.
+/// * Stage 3: the 2023-05 version of the TC39 proposal.
+///
+/// References:
+/// * <https://www.typescriptlang.org/tsconfig#experimentalDecorators>
+/// * <https://babeljs.io/docs/babel-plugin-proposal-decorators>
+/// * <https://github.com/tc39/proposal-decorators>
+pub struct Decorators<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    options: DecoratorsOptions,
+    helpers: std::vec::Vec<Helper>,
+}
+
+impl<'a> Decorators<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        options.decorators.map(|options| Self { ast, ctx, options, helpers: vec![] })
+    }
+
+    /// Insert the used runtime helpers at the top of the program.
+    pub fn add_helpers(&mut self, program: &mut Program<'a>) {
+        if self.helpers.is_empty() {
+            return;
+        }
+        self.helpers.sort_unstable();
+        let mut stmts = self.ast.new_vec();
+        for helper in &self.helpers {
+            let ret =
+                Parser::new(self.ast.allocator, helper.source(), SourceType::default()).parse();
+            stmts.extend(ret.program.body);
+        }
+        stmts.extend(self.ast.move_statement_vec(&mut program.body));
+        program.body = stmts;
+    }
+
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if !stmts.iter().any(|stmt| decorated_class(stmt).is_some()) {
+            return;
+        }
+        let mut new_stmts = self.ast.new_vec_with_capacity(stmts.len());
+        for stmt in self.ast.move_statement_vec(stmts) {
+            let Some(export) = decorated_class(&stmt) else {
+                new_stmts.push(stmt);
+                continue;
+            };
+            let class = match stmt {
+                Statement::Declaration(Declaration::ClassDeclaration(class)) => class,
+                Statement::ModuleDeclaration(decl) => match decl.unbox() {
+                    ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                        let Some(Declaration::ClassDeclaration(class)) = decl.unbox().declaration
+                        else {
+                            unreachable!()
+                        };
+                        class
+                    }
+                    ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                        let ExportDefaultDeclarationKind::ClassDeclaration(class) =
+                            decl.unbox().declaration
+                        else {
+                            unreachable!()
+                        };
+                        class
+                    }
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+            if self.options.version.is_legacy() {
+                self.transform_legacy_class(class, export, &mut new_stmts);
+            } else {
+                self.transform_stage3_class_declaration(class, export, &mut new_stmts);
+            }
+        }
+        *stmts = new_stmts;
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ClassExpression(class) = expr else { return };
+        if !has_decorators(class) {
+            return;
+        }
+        if self.options.version.is_legacy() {
+            self.ctx.error(LegacyDecoratorOnClassExpression(class.span));
+            return;
+        }
+        let Expression::ClassExpression(class) = self.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        *expr = self.transform_stage3_class(class);
+    }
+
+    /// ```TypeScript
+    /// @dec
+    /// class Foo {
+    ///   @dec method() {}
+    /// }
+    /// ```
+    /// ```JavaScript
+    /// let Foo = class Foo {
+    ///   method() {}
+    /// };
+    /// __decorate([dec], Foo.prototype, "method", null);
+    /// Foo = __decorate([dec], Foo);
+    /// ```
+    fn transform_legacy_class(
+        &mut self,
+        mut class: oxc_allocator::Box<'a, Class<'a>>,
+        export: ClassExport,
+        stmts: &mut Vec<'a, Statement<'a>>,
+    ) {
+        let class_name = match &class.id {
+            Some(id) => id.name.clone(),
+            None => self.generate_uid("default"),
+        };
+        if class.id.is_none() {
+            class.id = Some(BindingIdentifier::new(SPAN, class_name.clone()));
+        }
+        let emit_metadata = self.options.emit_decorator_metadata;
+
+        let mut class_decorators = self.take_decorators(&mut class.decorators);
+        let mut member_stmts = self.ast.new_vec();
+        // Name and staticness of the decorated accessors, with the index of their statement
+        let mut accessors: std::vec::Vec<((Atom, bool), usize)> = vec![];
+        for element in class.body.body.iter_mut() {
+            match element {
+                ClassElement::MethodDefinition(method)
+                    if method.kind == MethodDefinitionKind::Constructor =>
+                {
+                    let param_decorators = self.take_parameter_decorators(&mut method.value.params);
+                    class_decorators.extend(param_decorators);
+                    if emit_metadata {
+                        let types = self.serialize_parameter_types(&method.value.params);
+                        let metadata = self.metadata("design:paramtypes", types);
+                        class_decorators.push(ArrayExpressionElement::Expression(metadata));
+                    }
+                }
+                ClassElement::MethodDefinition(method) => {
+                    let mut decorators = self.take_decorators(&mut method.decorators);
+                    decorators.extend(self.take_parameter_decorators(&mut method.value.params));
+                    if decorators.is_empty() {
+                        continue;
+                    }
+                    let Some(name) = self.member_name(&method.key, method.span) else { continue };
+                    // The getter and the setter of a pair share one property descriptor, so the
+                    // decorators of both are applied once, like TypeScript does.
+                    if matches!(method.kind, MethodDefinitionKind::Get | MethodDefinitionKind::Set)
+                    {
+                        let pair = (name.clone(), method.r#static);
+                        if let Some(index) = accessors.iter().position(|(key, _)| *key == pair) {
+                            add_decorators(&mut member_stmts[accessors[index].1], decorators);
+                            continue;
+                        }
+                        accessors.push((pair, member_stmts.len()));
+                    }
+                    if emit_metadata {
+                        let func = &method.value;
+                        let (ty, return_type) = match method.kind {
+                            MethodDefinitionKind::Get => (self.serialize_return_type(func), None),
+                            MethodDefinitionKind::Set => {
+                                let ty = self.serialize_type_annotation(
+                                    func.params
+                                        .items
+                                        .first()
+                                        .and_then(|param| param.pattern.type_annotation.as_deref()),
+                                );
+                                (ty, None)
+                            }
+                            _ => (
+                                self.identifier("Function"),
+                                Some(self.serialize_return_type(func)),
+                            ),
+                        };
+                        let param_types = self.serialize_parameter_types(&func.params);
+                        for (key, value) in [
+                            ("design:type", Some(ty)),
+                            ("design:paramtypes", Some(param_types)),
+                            ("design:returntype", return_type),
+                        ] {
+                            if let Some(value) = value {
+                                let metadata = self.metadata(key, value);
+                                decorators.push(ArrayExpressionElement::Expression(metadata));
+                            }
+                        }
+                    }
+                    let target = self.decorator_target(&class_name, method.r#static);
+                    let desc = self.ast.literal_null_expression(NullLiteral::new(SPAN));
+                    let call = self.decorate(decorators, target, Some((name, desc)));
+                    member_stmts.push(self.ast.expression_statement(SPAN, call));
+                }
+                ClassElement::PropertyDefinition(prop) => {
+                    let mut decorators = self.take_decorators(&mut prop.decorators);
+                    if decorators.is_empty() {
+                        continue;
+                    }
+                    let Some(name) = self.member_name(&prop.key, prop.span) else { continue };
+                    if emit_metadata {
+                        let ty = self.serialize_type_annotation(prop.type_annotation.as_deref());
+                        let metadata = self.metadata("design:type", ty);
+                        decorators.push(ArrayExpressionElement::Expression(metadata));
+                    }
+                    let target = self.decorator_target(&class_name, prop.r#static);
+                    let desc = self.ast.void_0();
+                    let call = self.decorate(decorators, target, Some((name, desc)));
+                    member_stmts.push(self.ast.expression_statement(SPAN, call));
+                }
+                ClassElement::AccessorProperty(prop) => self.reject_accessor_decorators(prop),
+                _ => {}
+            }
+        }
+
+        let has_class_decorators =
+            class_decorators.iter().any(|decorator| !is_metadata_call(decorator));
+        if !has_class_decorators {
+            stmts.push(self.export_class(class, export));
+            stmts.extend(member_stmts);
+            return;
+        }
+
+        // let Foo = class Foo {};
+        class.r#type = ClassType::ClassExpression;
+        let class_expression = self.ast.class_expression(class);
+        let decl = self.variable_declaration(
+            VariableDeclarationKind::Let,
+            class_name.clone(),
+            Some(class_expression),
+        );
+        stmts.push(self.export_variable_declaration(decl, export));
+        stmts.extend(member_stmts);
+
+        // Foo = __decorate([dec], Foo);
+        let target = self.identifier(&class_name);
+        let call = self.decorate(class_decorators, target, None);
+        let assignee = self.ast.simple_assignment_target_identifier(IdentifierReference::new(
+            SPAN,
+            class_name.clone(),
+        ));
+        let assignment = self.ast.assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(assignee),
+            call,
+        );
+        stmts.push(self.ast.expression_statement(SPAN, assignment));
+
+        if matches!(export, ClassExport::Default) {
+            stmts.push(self.export_default(self.identifier(&class_name)));
+        }
+    }
+
+    fn transform_stage3_class_declaration(
+        &mut self,
+        class: oxc_allocator::Box<'a, Class<'a>>,
+        export: ClassExport,
+        stmts: &mut Vec<'a, Statement<'a>>,
+    ) {
+        let Some(class_name) = class.id.as_ref().map(|id| id.name.clone()) else {
+            // `export default class {}`
+            let expr = self.transform_stage3_class(class);
+            stmts.push(self.export_default(expr));
+            return;
+        };
+        let expr = self.transform_stage3_class(class);
+        let decl =
+            self.variable_declaration(VariableDeclarationKind::Let, class_name.clone(), Some(expr));
+        stmts.push(self.export_variable_declaration(decl, export));
+        if matches!(export, ClassExport::Default) {
+            stmts.push(self.export_default(self.identifier(&class_name)));
+        }
+    }
+
+    /// ```JavaScript
+    /// @dec
+    /// class Foo {
+    ///   @dec method() {}
+    ///   @dec field = 1;
+    /// }
+    /// ```
+    /// ```JavaScript
+    /// (() => {
+    ///   let _decorated;
+    ///   const _classDecs = [dec], _memberDecs = [[[dec], 1, "method", false], [[dec], 0, "field", false]];
+    ///   class Foo {
+    ///     static { _decorated = _applyDecs(this, _memberDecs, _classDecs); }
+    ///     #_initProto = _decorated.p(this);
+    ///     method() {}
+    ///     field = _decorated.e[0](this, 1);
+    ///   }
+    ///   return _decorated.c();
+    /// })()
+    /// ```
+    fn transform_stage3_class(
+        &mut self,
+        mut class: oxc_allocator::Box<'a, Class<'a>>,
+    ) -> Expression<'a> {
+        if class.id.is_none() {
+            class.id = Some(BindingIdentifier::new(SPAN, self.generate_uid("class")));
+        }
+        class.r#type = ClassType::ClassDeclaration;
+        let names = Stage3Names {
+            decorated: self.generate_uid("decorated"),
+            class_decs: self.generate_uid("classDecs"),
+            member_decs: self.generate_uid("memberDecs"),
+        };
+
+        let mut member_decs = self.ast.new_vec();
+        let mut field_index = 0;
+        let mut has_instance_member = false;
+        for element in class.body.body.iter_mut() {
+            match element {
+                ClassElement::MethodDefinition(method) => {
+                    for param in &method.value.params.items {
+                        if let Some(decorator) = param.decorators.first() {
+                            self.ctx.error(ParameterDecoratorInStage3(decorator.span));
+                        }
+                    }
+                    let kind = match method.kind {
+                        MethodDefinitionKind::Constructor => continue,
+                        MethodDefinitionKind::Method => 1,
+                        MethodDefinitionKind::Get => 2,
+                        MethodDefinitionKind::Set => 3,
+                    };
+                    let decorators = self.take_decorators(&mut method.decorators);
+                    if decorators.is_empty() {
+                        continue;
+                    }
+                    let Some(name) = self.member_name(&method.key, method.span) else { continue };
+                    has_instance_member |= !method.r#static;
+                    member_decs.push(self.member_decorators(
+                        decorators,
+                        kind,
+                        name,
+                        method.r#static,
+                    ));
+                }
+                ClassElement::PropertyDefinition(prop) => {
+                    let decorators = self.take_decorators(&mut prop.decorators);
+                    if decorators.is_empty() {
+                        continue;
+                    }
+                    let Some(name) = self.member_name(&prop.key, prop.span) else { continue };
+                    has_instance_member |= !prop.r#static;
+                    member_decs.push(self.member_decorators(decorators, 0, name, prop.r#static));
+                    // `field = _decorated.e[0](this, value)`
+                    let value = prop.value.take().unwrap_or_else(|| self.ast.void_0());
+                    prop.value = Some(self.field_initializer(&names.decorated, field_index, value));
+                    field_index += 1;
+                }
+                ClassElement::AccessorProperty(prop) => self.reject_accessor_decorators(prop),
+                _ => {}
+            }
+        }
+        let class_decs = self.take_decorators(&mut class.decorators);
+
+        // #_initProto = _decorated.p(this);
+        if has_instance_member {
+            let key = PropertyKey::PrivateIdentifier(
+                self.ast.alloc(PrivateIdentifier { span: SPAN, name: "_initProto".into() }),
+            );
+            let callee = self.decorated_member(&names.decorated, "p");
+            let call = self.call(callee, [self.ast.this_expression(SPAN)]);
+            let init_proto =
+                self.ast.class_property(SPAN, key, Some(call), false, false, self.ast.new_vec());
+            class.body.body.insert(0, init_proto);
+        }
+
+        // static { _decorated = _applyDecs(this, _memberDecs, _classDecs); }
+        let callee = self.helper(Helper::ApplyDecs);
+        let apply_decs = self.call(
+            callee,
+            [
+                self.ast.this_expression(SPAN),
+                self.identifier(&names.member_decs),
+                self.identifier(&names.class_decs),
+            ],
+        );
+        let assignee = self.ast.simple_assignment_target_identifier(IdentifierReference::new(
+            SPAN,
+            names.decorated.clone(),
+        ));
+        let assignment = self.ast.assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(assignee),
+            apply_decs,
+        );
+        let static_block = self.ast.static_block(
+            SPAN,
+            self.ast.new_vec_single(self.ast.expression_statement(SPAN, assignment)),
+        );
+        class.body.body.insert(0, static_block);
+
+        let mut statements = self.ast.new_vec();
+        // let _decorated;
+        let decl =
+            self.variable_declaration(VariableDeclarationKind::Let, names.decorated.clone(), None);
+        statements.push(Statement::Declaration(Declaration::VariableDeclaration(decl)));
+        // const _classDecs = [], _memberDecs = [];
+        // Class decorators are evaluated before member decorators.
+        let kind = VariableDeclarationKind::Const;
+        let mut declarations = self.ast.new_vec();
+        for (name, elements) in [(names.class_decs, class_decs), (names.member_decs, member_decs)] {
+            let init = self.ast.array_expression(SPAN, elements, None);
+            declarations.push(self.variable_declarator(kind, name, Some(init)));
+        }
+        let decl = self.ast.variable_declaration(SPAN, kind, declarations, Modifiers::empty());
+        statements.push(Statement::Declaration(Declaration::VariableDeclaration(decl)));
+        statements.push(self.ast.class_declaration(class));
+        // return _decorated.c();
+        let callee = self.decorated_member(&names.decorated, "c");
+        let decorated_class = self.call(callee, []);
+        statements.push(self.ast.return_statement(SPAN, Some(decorated_class)));
+
+        // (() => { ... })()
+        let params = self.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            self.ast.new_vec(),
+            None,
+        );
+        let body = self.ast.function_body(SPAN, self.ast.new_vec(), statements);
+        let arrow = self.ast.arrow_expression(SPAN, false, false, false, params, body, None, None);
+        let callee = self.ast.parenthesized_expression(SPAN, arrow);
+        self.call(callee, [])
+    }
+
+    /// `[[dec], 1, "method", false]`
+    fn member_decorators(
+        &self,
+        decorators: Vec<'a, ArrayExpressionElement<'a>>,
+        kind: u8,
+        name: Atom,
+        r#static: bool,
+    ) -> ArrayExpressionElement<'a> {
+        let mut elements = self.ast.new_vec();
+        for element in [
+            self.ast.array_expression(SPAN, decorators, None),
+            self.number_literal(kind.into()),
+            self.string_literal(name),
+            self.ast.literal_boolean_expression(self.ast.boolean_literal(SPAN, r#static)),
+        ] {
+            elements.push(ArrayExpressionElement::Expression(element));
+        }
+        ArrayExpressionElement::Expression(self.ast.array_expression(SPAN, elements, None))
+    }
+
+    /// `_decorated.e[0](this, value)`
+    fn field_initializer(
+        &self,
+        decorated: &Atom,
+        index: u32,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        let initializers = self.decorated_member(decorated, "e");
+        let callee = self.ast.computed_member_expression(
+            SPAN,
+            initializers,
+            self.number_literal(index.into()),
+            false,
+        );
+        self.call(callee, [self.ast.this_expression(SPAN), value])
+    }
+
+    fn decorated_member(&self, decorated: &Atom, name: &str) -> Expression<'a> {
+        self.ast.static_member_expression(
+            SPAN,
+            self.identifier(decorated),
+            IdentifierName::new(SPAN, name.into()),
+            false,
+        )
+    }
+
+    /// `__decorate([dec], target, "key", desc)`
+    fn decorate(
+        &mut self,
+        decorators: Vec<'a, ArrayExpressionElement<'a>>,
+        target: Expression<'a>,
+        key_and_desc: Option<(Atom, Expression<'a>)>,
+    ) -> Expression<'a> {
+        let callee = self.helper(Helper::Decorate);
+        let mut arguments = self.ast.new_vec();
+        arguments.push(Argument::Expression(self.ast.array_expression(SPAN, decorators, None)));
+        arguments.push(Argument::Expression(target));
+        if let Some((key, desc)) = key_and_desc {
+            arguments.push(Argument::Expression(self.string_literal(key)));
+            arguments.push(Argument::Expression(desc));
+        }
+        self.ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    /// `Foo` for static members, `Foo.prototype` for instance members.
+    fn decorator_target(&self, class_name: &Atom, r#static: bool) -> Expression<'a> {
+        let class = self.identifier(class_name);
+        if r#static {
+            return class;
+        }
+        self.ast.static_member_expression(
+            SPAN,
+            class,
+            IdentifierName::new(SPAN, "prototype".into()),
+            false,
+        )
+    }
+
+    fn take_decorators(
+        &self,
+        decorators: &mut Vec<'a, Decorator<'a>>,
+    ) -> Vec<'a, ArrayExpressionElement<'a>> {
+        let decorators = mem::replace(decorators, self.ast.new_vec());
+        let mut elements = self.ast.new_vec_with_capacity(decorators.len());
+        for decorator in decorators {
+            elements.push(ArrayExpressionElement::Expression(decorator.expression));
+        }
+        elements
+    }
+
+    /// `__param(0, dec)`
+    fn take_parameter_decorators(
+        &mut self,
+        params: &mut FormalParameters<'a>,
+    ) -> Vec<'a, ArrayExpressionElement<'a>> {
+        let mut elements = self.ast.new_vec();
+        for (index, param) in params.items.iter_mut().enumerate() {
+            let decorators = mem::replace(&mut param.decorators, self.ast.new_vec());
+            for decorator in decorators {
+                let callee = self.helper(Helper::Param);
+                #[allow(clippy::cast_precision_loss)]
+                let index = self.number_literal(index as f64);
+                let call = self.call(callee, [index, decorator.expression]);
+                elements.push(ArrayExpressionElement::Expression(call));
+            }
+        }
+        elements
+    }
+
+    /// Decorated members need a static name, computed keys would need to be evaluated only once.
+    fn member_name(&mut self, key: &PropertyKey<'a>, span: Span) -> Option<Atom> {
+        let name = match key {
+            PropertyKey::Identifier(ident) => Some(ident.name.clone()),
+            PropertyKey::Expression(
+                Expression::StringLiteral(_) | Expression::NumberLiteral(_),
+            ) => key.static_name(),
+            _ => None,
+        };
+        if name.is_none() {
+            self.ctx.error(UnsupportedDecoratedMember(span));
+        }
+        name
+    }
+
+    /// Decorated `accessor` fields would need the getter and setter pair of the auto-accessor.
+    fn reject_accessor_decorators(&mut self, prop: &AccessorProperty<'a>) {
+        if let Some(decorator) = prop.decorators.first() {
+            self.ctx.error(UnsupportedDecoratedAccessor(decorator.span));
+        }
+    }
+
+    /// A name which is not used anywhere in the program, declared by the transformed code.
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn export_class(
+        &self,
+        class: oxc_allocator::Box<'a, Class<'a>>,
+        export: ClassExport,
+    ) -> Statement<'a> {
+        match export {
+            ClassExport::None => self.ast.class_declaration(class),
+            ClassExport::Named => {
+                let decl = self.ast.export_named_declaration(
+                    SPAN,
+                    Some(Declaration::ClassDeclaration(class)),
+                    self.ast.new_vec(),
+                    None,
+                    ImportOrExportKind::Value,
+                );
+                self.ast.module_declaration(ModuleDeclaration::ExportNamedDeclaration(decl))
+            }
+            ClassExport::Default => {
+                let decl = self.ast.export_default_declaration(
+                    SPAN,
+                    ExportDefaultDeclarationKind::ClassDeclaration(class),
+                    ModuleExportName::Identifier(IdentifierName::new(SPAN, "default".into())),
+                );
+                self.ast.module_declaration(ModuleDeclaration::ExportDefaultDeclaration(decl))
+            }
+        }
+    }
+
+    /// `export default` is added separately because `export default let` is not valid.
+    fn export_variable_declaration(
+        &self,
+        decl: oxc_allocator::Box<'a, VariableDeclaration<'a>>,
+        export: ClassExport,
+    ) -> Statement<'a> {
+        let decl = Declaration::VariableDeclaration(decl);
+        match export {
+            ClassExport::None | ClassExport::Default => Statement::Declaration(decl),
+            ClassExport::Named => {
+                let decl = self.ast.export_named_declaration(
+                    SPAN,
+                    Some(decl),
+                    self.ast.new_vec(),
+                    None,
+                    ImportOrExportKind::Value,
+                );
+                self.ast.module_declaration(ModuleDeclaration::ExportNamedDeclaration(decl))
+            }
+        }
+    }
+
+    fn export_default(&self, expr: Expression<'a>) -> Statement<'a> {
+        let decl = self.ast.export_default_declaration(
+            SPAN,
+            ExportDefaultDeclarationKind::Expression(expr),
+            ModuleExportName::Identifier(IdentifierName::new(SPAN, "default".into())),
+        );
+        self.ast.module_declaration(ModuleDeclaration::ExportDefaultDeclaration(decl))
+    }
+
+    fn variable_declaration(
+        &self,
+        kind: VariableDeclarationKind,
+        name: Atom,
+        init: Option<Expression<'a>>,
+    ) -> oxc_allocator::Box<'a, VariableDeclaration<'a>> {
+        let declarations = self.ast.new_vec_single(self.variable_declarator(kind, name, init));
+        self.ast.variable_declaration(SPAN, kind, declarations, Modifiers::empty())
+    }
+
+    fn variable_declarator(
+        &self,
+        kind: VariableDeclarationKind,
+        name: Atom,
+        init: Option<Expression<'a>>,
+    ) -> VariableDeclarator<'a> {
+        let ident = self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name));
+        let id = self.ast.binding_pattern(ident, None, false);
+        self.ast.variable_declarator(SPAN, kind, id, init, false)
+    }
+
+    fn helper(&mut self, helper: Helper) -> Expression<'a> {
+        if !self.helpers.contains(&helper) {
+            self.helpers.push(helper);
+        }
+        self.identifier(helper.name())
+    }
+
+    fn call<const N: usize>(
+        &self,
+        callee: Expression<'a>,
+        arguments: [Expression<'a>; N],
+    ) -> Expression<'a> {
+        let mut args = self.ast.new_vec_with_capacity(N);
+        for argument in arguments {
+            args.push(Argument::Expression(argument));
+        }
+        self.ast.call_expression(SPAN, callee, args, false, None)
+    }
+
+    fn identifier(&self, name: &str) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.into()))
+    }
+
+    fn string_literal(&self, value: Atom) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(SPAN, value))
+    }
+
+    fn number_literal(&self, value: f64) -> Expression<'a> {
+        let raw = self.ast.new_str(&value.to_string());
+        self.ast.literal_number_expression(self.ast.number_literal(
+            SPAN,
+            value,
+            raw,
+            NumberBase::Decimal,
+        ))
+    }
+}
+
+/// Returns how the class is exported if the statement declares a class with decorators.
+fn decorated_class(stmt: &Statement) -> Option<ClassExport> {
+    let (class, export) = match stmt {
+        Statement::Declaration(Declaration::ClassDeclaration(class)) => (class, ClassExport::None),
+        Statement::ModuleDeclaration(decl) => match &**decl {
+            ModuleDeclaration::ExportNamedDeclaration(decl) => match &decl.declaration {
+                Some(Declaration::ClassDeclaration(class)) => (class, ClassExport::Named),
+                _ => return None,
+            },
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                    (class, ClassExport::Default)
+                }
+                _ => return None,
+            },
+            _ => return None,
+        },
+        _ => return None,
+    };
+    has_decorators(class).then_some(export)
+}
+
+fn has_decorators(class: &Class) -> bool {
+    if class.is_declare() {
+        return false;
+    }
+    !class.decorators.is_empty()
+        || class.body.body.iter().any(|element| match element {
+            ClassElement::MethodDefinition(method) => {
+                !method.decorators.is_empty()
+                    || method.value.params.items.iter().any(|param| !param.decorators.is_empty())
+            }
+            ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+            ClassElement::AccessorProperty(prop) => !prop.decorators.is_empty(),
+            _ => false,
+        })
+}
+
+/// Add decorators to the `__decorate([...], ...)` call of `stmt`, before its metadata.
+fn add_decorators<'a>(stmt: &mut Statement<'a>, decorators: Vec<'a, ArrayExpressionElement<'a>>) {
+    let Statement::ExpressionStatement(stmt) = stmt else { unreachable!() };
+    let Expression::CallExpression(call) = &mut stmt.expression else { unreachable!() };
+    let Some(Argument::Expression(Expression::ArrayExpression(array))) = call.arguments.first_mut()
+    else {
+        unreachable!()
+    };
+    let index = array.elements.iter().position(is_metadata_call).unwrap_or(array.elements.len());
+    for (i, decorator) in decorators.into_iter().enumerate() {
+        array.elements.insert(index + i, decorator);
+    }
+}
+
+fn is_metadata_call(element: &ArrayExpressionElement) -> bool {
+    matches!(
+        element,
+        ArrayExpressionElement::Expression(Expression::CallExpression(call))
+            if call.callee.is_specific_id(Helper::Metadata.name())
+    )
+}
+
+#[test]
+fn test() {
+    use self::helpers::{APPLY_DECS, DECORATE, METADATA, PARAM};
+    use crate::tester::Tester;
+
+    let legacy = DecoratorsOptions { version: DecoratorsVersion::Legacy, ..Default::default() };
+    let options = TransformOptions { decorators: Some(legacy), ..TransformOptions::default() };
+    let class = format!(
+        "{DECORATE}
+        let Foo = class Foo {{ method() {{}} value = 1; static create() {{}} }};
+        __decorate([log], Foo.prototype, 'method', null);
+        __decorate([observable], Foo.prototype, 'value', void 0);
+        __decorate([log], Foo, 'create', null);
+        Foo = __decorate([dec], Foo);"
+    );
+    let export = format!(
+        "{DECORATE}
+        export class Bar {{ method() {{}} }}
+        __decorate([log], Bar.prototype, 'method', null);"
+    );
+    let accessors = format!(
+        "{DECORATE}
+        class Foo {{ get x() {{ return 1; }} set x(v) {{}} }}
+        __decorate([a, b], Foo.prototype, 'x', null);"
+    );
+    let default_export = format!(
+        "{DECORATE}
+        let _default = 1;
+        let _default2 = class _default2 {{}};
+        _default2 = __decorate([dec], _default2);
+        export default _default2;"
+    );
+    let tester = Tester::new("test.js", options);
+    tester.test(&[
+        (
+            "@dec class Foo { @log method() {} @observable value = 1; @log static create() {} }",
+            class.as_str(),
+        ),
+        ("export class Bar { @log method() {} }", export.as_str()),
+        ("class Foo { @a get x() { return 1; } @b set x(v) {} }", accessors.as_str()),
+        ("let _default = 1; export default @dec class {}", default_export.as_str()),
+    ]);
+    tester.test_errors(&["class Foo { @dec accessor x = 1; }"]);
+
+    let metadata = DecoratorsOptions { emit_decorator_metadata: true, ..legacy };
+    let options = TransformOptions { decorators: Some(metadata), ..TransformOptions::default() };
+    let class = format!(
+        "{DECORATE}{METADATA}{PARAM}
+        let Foo = class Foo {{
+            constructor(bar: Bar, baz: string) {{}}
+            name: string;
+            onClick(event: Event): void {{}}
+        }};
+        __decorate([Input(), __metadata('design:type', String)], Foo.prototype, 'name', void 0);
+        __decorate([
+            HostListener('click'),
+            __metadata('design:type', Function),
+            __metadata('design:paramtypes', [typeof Event === 'undefined' ? Object : Event]),
+            __metadata('design:returntype', void 0)
+        ], Foo.prototype, 'onClick', null);
+        Foo = __decorate([
+            Injectable(),
+            __param(0, Inject(BAR)),
+            __metadata('design:paramtypes', [typeof Bar === 'undefined' ? Object : Bar, String])
+        ], Foo);"
+    );
+    Tester::new("test.ts", options).test(&[(
+        "@Injectable()
+        class Foo {
+            constructor(@Inject(BAR) bar: Bar, baz: string) {}
+            @Input() name: string;
+            @HostListener('click') onClick(event: Event): void {}
+        }",
+        class.as_str(),
+    )]);
+
+    let options = TransformOptions {
+        decorators: Some(DecoratorsOptions::default()),
+        ..TransformOptions::default()
+    };
+    let class = format!(
+        "{APPLY_DECS}
+        let Foo = (() => {{
+            let _decorated;
+            const _classDecs = [dec],
+                _memberDecs = [[[log], 1, 'method', false], [[observable], 0, 'value', false]];
+            class Foo {{
+                static {{ _decorated = _applyDecs(this, _memberDecs, _classDecs); }}
+                #_initProto = _decorated.p(this);
+                method() {{}}
+                value = _decorated.e[0](this, 1);
+            }}
+            return _decorated.c();
+        }})();"
+    );
+    let class_expression = format!(
+        "{APPLY_DECS}
+        const A = (() => {{
+            let _decorated;
+            const _classDecs = [dec], _memberDecs = [];
+            class _class {{
+                static {{ _decorated = _applyDecs(this, _memberDecs, _classDecs); }}
+            }}
+            return _decorated.c();
+        }})();"
+    );
+    let name_clash = format!(
+        "{APPLY_DECS}
+        let _decorated;
+        const A = (() => {{
+            let _decorated2;
+            const _classDecs = [dec], _memberDecs = [];
+            class _class {{
+                static {{ _decorated2 = _applyDecs(this, _memberDecs, _classDecs); }}
+            }}
+            return _decorated2.c();
+        }})();"
+    );
+    let tester = Tester::new("test.js", options);
+    tester.test(&[
+        ("@dec class Foo { @log method() {} @observable value = 1; }", class.as_str()),
+        ("const A = @dec class {};", class_expression.as_str()),
+        ("let _decorated; const A = @dec class {};", name_clash.as_str()),
+    ]);
+    tester.test_errors(&["class Foo { @dec accessor x = 1; }"]);
+}
@@ -0,0 +1,30 @@
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecoratorsOptions {
+    /// Decides which semantics to implement. default to the stage 3 proposal.
+    #[serde(default)]
+    pub version: DecoratorsVersion,
+    /// Emit `design:type`, `design:paramtypes` and `design:returntype` metadata for `reflect-metadata`,
+    /// same as TypeScript's `emitDecoratorMetadata`. Only used by legacy decorators.
+    #[serde(default)]
+    pub emit_decorator_metadata: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum DecoratorsVersion {
+    /// TypeScript's `experimentalDecorators`.
+    #[serde(rename = "legacy")]
+    Legacy,
+    /// The 2023-05 version of <https://github.com/tc39/proposal-decorators>.
+    #[default]
+    #[serde(rename = "2023-05")]
+    Stage3,
+}
+
+impl DecoratorsVersion {
+    pub fn is_legacy(&self) -> bool {
+        matches!(self, Self::Legacy)
+    }
+}
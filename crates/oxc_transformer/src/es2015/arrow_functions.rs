@@ -7,7 +7,7 @@ use serde::Deserialize;
 
 use crate::context::TransformerCtx;
 use crate::options::TransformOptions;
-use crate::TransformTarget;
+use crate::EsFeature;
 
 /// ES2015 Arrow Functions
 ///
@@ -63,10 +63,11 @@ impl<'a> ArrowFunctions<'a> {
         _: TransformerCtx<'a>,
         options: &TransformOptions,
     ) -> Option<Self> {
-        (options.target < TransformTarget::ES2015 || options.arrow_functions.is_some()).then(|| {
-            let nodes = ast.new_vec();
-            Self { ast, uid: 0, nodes, has_this: false, insert: false }
-        })
+        (!options.target.supports(EsFeature::ArrowFunctions) || options.arrow_functions.is_some())
+            .then(|| {
+                let nodes = ast.new_vec();
+                Self { ast, uid: 0, nodes, has_this: false, insert: false }
+            })
     }
 
     fn get_this_name(&self) -> Atom {
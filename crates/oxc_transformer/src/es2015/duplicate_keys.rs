@@ -5,7 +5,7 @@ use std::{collections::HashSet, rc::Rc};
 use oxc_ast::{ast::*, AstBuilder};
 use oxc_span::{Atom, SPAN};
 
-use crate::options::{TransformOptions, TransformTarget};
+use crate::options::{EsFeature, TransformOptions};
 
 /// ES2015: Duplicate Keys
 ///
@@ -18,7 +18,8 @@ pub struct DuplicateKeys<'a> {
 
 impl<'a> DuplicateKeys<'a> {
     pub fn new(ast: Rc<AstBuilder<'a>>, options: &TransformOptions) -> Option<Self> {
-        (options.target < TransformTarget::ES2015 || options.duplicate_keys).then(|| Self { ast })
+        (!options.target.supports(EsFeature::DuplicateKeys) || options.duplicate_keys)
+            .then(|| Self { ast })
     }
 
     pub fn transform_object_expression<'b>(&mut self, obj_expr: &'b mut ObjectExpression<'a>) {
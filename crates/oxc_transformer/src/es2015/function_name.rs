@@ -11,7 +11,7 @@ use oxc_syntax::unicode_id_start::is_id_continue;
 use crate::context::TransformerCtx;
 use crate::options::TransformOptions;
 use crate::utils::is_valid_identifier;
-use crate::TransformTarget;
+use crate::EsFeature;
 
 /// ES2015: Function Name
 ///
@@ -30,7 +30,7 @@ impl<'a> FunctionName<'a> {
         ctx: TransformerCtx<'a>,
         options: &TransformOptions,
     ) -> Option<Self> {
-        (options.target < TransformTarget::ES2015 || options.function_name).then(|| Self {
+        (!options.target.supports(EsFeature::FunctionName) || options.function_name).then(|| Self {
             _ast: ast,
             ctx,
             // TODO hook up the plugin
@@ -7,7 +7,7 @@ use oxc_syntax::operator::BinaryOperator;
 
 use crate::context::TransformerCtx;
 use crate::options::TransformOptions;
-use crate::TransformTarget;
+use crate::EsFeature;
 
 /// ES2015: instanceof
 ///
@@ -25,7 +25,8 @@ impl<'a> Instanceof<'a> {
         ctx: TransformerCtx<'a>,
         options: &TransformOptions,
     ) -> Option<Self> {
-        (options.target < TransformTarget::ES2015 || options.instanceof).then(|| Self { ast, ctx })
+        (!options.target.supports(EsFeature::Instanceof) || options.instanceof)
+            .then(|| Self { ast, ctx })
     }
 
     pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
@@ -3,7 +3,7 @@ use std::rc::Rc;
 use oxc_ast::{ast::*, AstBuilder};
 use oxc_span::GetSpan;
 
-use crate::options::{TransformOptions, TransformTarget};
+use crate::options::{EsFeature, TransformOptions};
 
 /// ES2015: Shorthand Properties
 ///
@@ -16,7 +16,7 @@ pub struct ShorthandProperties<'a> {
 
 impl<'a> ShorthandProperties<'a> {
     pub fn new(ast: Rc<AstBuilder<'a>>, options: &TransformOptions) -> Option<Self> {
-        (options.target < TransformTarget::ES2015 || options.shorthand_properties)
+        (!options.target.supports(EsFeature::ShorthandProperties) || options.shorthand_properties)
             .then(|| Self { ast })
     }
 
@@ -3,7 +3,7 @@ use oxc_ast::{ast::*, AstBuilder};
 use oxc_span::{Atom, Span, SPAN};
 use std::{mem, rc::Rc};
 
-use crate::{TransformOptions, TransformTarget};
+use crate::{EsFeature, TransformOptions};
 
 /// ES2015: Template Literals
 ///
@@ -16,7 +16,7 @@ pub struct TemplateLiterals<'a> {
 
 impl<'a> TemplateLiterals<'a> {
     pub fn new(ast: Rc<AstBuilder<'a>>, options: &TransformOptions) -> Option<Self> {
-        (options.target < TransformTarget::ES2015 || options.template_literals)
+        (!options.target.supports(EsFeature::TemplateLiterals) || options.template_literals)
             .then(|| Self { ast })
     }
 
@@ -7,7 +7,7 @@ use oxc_syntax::operator::{AssignmentOperator, BinaryOperator};
 
 use crate::{
     context::TransformerCtx,
-    options::{TransformOptions, TransformTarget},
+    options::{EsFeature, TransformOptions},
     utils::CreateVars,
 };
 
@@ -44,10 +44,12 @@ impl<'a> ExponentiationOperator<'a> {
         ctx: TransformerCtx<'a>,
         options: &TransformOptions,
     ) -> Option<Self> {
-        (options.target < TransformTarget::ES2016 || options.exponentiation_operator).then(|| {
-            let vars = ast.new_vec();
-            Self { ast, ctx, vars }
-        })
+        (!options.target.supports(EsFeature::ExponentiationOperator)
+            || options.exponentiation_operator)
+            .then(|| {
+                let vars = ast.new_vec();
+                Self { ast, ctx, vars }
+            })
     }
 
     pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
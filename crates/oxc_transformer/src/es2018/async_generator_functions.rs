@@ -0,0 +1,381 @@
+use std::rc::Rc;
+
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_parser::Parser;
+use oxc_semantic::ScopeFlags;
+use oxc_span::{SourceType, Span, SPAN};
+
+use crate::{
+    context::TransformerCtx,
+    options::{EsFeature, TransformOptions},
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("`for await` in async generator functions is not supported by the configured target.")]
+#[diagnostic(help("Use `es2018` or a later target."))]
+struct UnsupportedForAwait(#[label] Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("`super` in async generator methods is not supported by the configured target.")]
+#[diagnostic(help("Use `es2018` or a later target."))]
+struct UnsupportedSuper(#[label] Span);
+
+/// ES2018: Async Generator Functions
+///
+/// The body is moved into a generator which is driven by the `_wrapAsyncGenerator` runtime:
+/// `await x` is yielded as `_awaitAsyncGenerator(x)`, `yield* x` delegates to the async iterator of `x`.
+///
+/// ```JavaScript
+/// async function* foo(a) { yield await a; }
+/// ```
+/// ```JavaScript
+/// function foo(a) {
+///   return _wrapAsyncGenerator(function* () { yield yield _awaitAsyncGenerator(a); }).apply(this, arguments);
+/// }
+/// ```
+///
+/// References:
+/// * <https://babeljs.io/docs/babel-plugin-transform-async-generator-functions>
+pub struct AsyncGeneratorFunctions<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    uses_helpers: bool,
+}
+
+impl<'a> AsyncGeneratorFunctions<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (!options.target.supports(EsFeature::AsyncGeneratorFunctions)).then(|| Self {
+            ast,
+            ctx,
+            uses_helpers: false,
+        })
+    }
+
+    /// Insert the runtime at the top of the program when an async generator was lowered.
+    pub fn add_helpers(&mut self, program: &mut Program<'a>) {
+        if !self.uses_helpers {
+            return;
+        }
+        let ret = Parser::new(self.ast.allocator, HELPERS, SourceType::default()).parse();
+        let mut stmts = ret.program.body;
+        stmts.extend(self.ast.move_statement_vec(&mut program.body));
+        program.body = stmts;
+    }
+
+    pub fn transform_function(&mut self, func: &mut Function<'a>) {
+        if !(func.r#async && func.generator) {
+            return;
+        }
+        let Some(body) = &mut func.body else { return };
+        self.uses_helpers = true;
+        func.r#async = false;
+        func.generator = false;
+
+        let mut statements = self.ast.move_statement_vec(&mut body.statements);
+        let mut lowering = AwaitToYield { ast: &self.ast, ctx: self.ctx.clone(), arrow_depth: 0 };
+        for stmt in statements.iter_mut() {
+            lowering.visit_statement(stmt);
+        }
+
+        // _wrapAsyncGenerator(function* () { ... }).apply(this, arguments)
+        let params = self.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            self.ast.new_vec(),
+            None,
+        );
+        let generator_body = self.ast.function_body(SPAN, self.ast.new_vec(), statements);
+        let generator = self.ast.function(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            true,
+            false,
+            None,
+            params,
+            Some(generator_body),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        let wrap = self.call(
+            self.identifier("_wrapAsyncGenerator"),
+            [self.ast.function_expression(generator)],
+        );
+        let apply = self.ast.static_member_expression(
+            SPAN,
+            wrap,
+            IdentifierName::new(SPAN, "apply".into()),
+            false,
+        );
+        let call = self.call(apply, [self.ast.this_expression(SPAN), self.identifier("arguments")]);
+        body.statements.push(self.ast.return_statement(SPAN, Some(call)));
+    }
+
+    fn call<const N: usize>(
+        &self,
+        callee: Expression<'a>,
+        arguments: [Expression<'a>; N],
+    ) -> Expression<'a> {
+        call(&self.ast, callee, arguments)
+    }
+
+    fn identifier(&self, name: &str) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.into()))
+    }
+}
+
+fn call<'a, const N: usize>(
+    ast: &AstBuilder<'a>,
+    callee: Expression<'a>,
+    arguments: [Expression<'a>; N],
+) -> Expression<'a> {
+    let mut args = ast.new_vec_with_capacity(N);
+    for argument in arguments {
+        args.push(Argument::Expression(argument));
+    }
+    ast.call_expression(SPAN, callee, args, false, None)
+}
+
+/// Rewrite the `await` and `yield*` of the async generator body, nested functions have
+/// their own.
+struct AwaitToYield<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    ctx: TransformerCtx<'a>,
+    /// `await` in arrow functions belongs to the arrow function
+    arrow_depth: usize,
+}
+
+impl<'a, 'b> AwaitToYield<'a, 'b> {
+    fn helper_call(&self, name: &str, argument: Expression<'a>) -> Expression<'a> {
+        let callee =
+            self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.into()));
+        call(self.ast, callee, [argument])
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for AwaitToYield<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.visit_expression_match(expr);
+        if self.arrow_depth > 0 {
+            return;
+        }
+        match expr {
+            // `await x` -> `yield _awaitAsyncGenerator(x)`
+            Expression::AwaitExpression(await_expr) => {
+                let span = await_expr.span;
+                let argument = self.ast.move_expression(&mut await_expr.argument);
+                let argument = self.helper_call("_awaitAsyncGenerator", argument);
+                *expr = self.ast.yield_expression(span, false, Some(argument));
+            }
+            // `yield* x` -> `yield* _asyncGeneratorDelegate(_asyncIterator(x))`
+            Expression::YieldExpression(yield_expr) if yield_expr.delegate => {
+                if let Some(argument) = yield_expr.argument.take() {
+                    let iterator = self.helper_call("_asyncIterator", argument);
+                    let delegate = self.helper_call("_asyncGeneratorDelegate", iterator);
+                    yield_expr.argument = Some(delegate);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        if stmt.r#await && self.arrow_depth == 0 {
+            self.ctx.error(UnsupportedForAwait(stmt.span));
+        }
+        self.visit_for_statement_left(&mut stmt.left);
+        self.visit_expression(&mut stmt.right);
+        self.visit_statement(&mut stmt.body);
+    }
+
+    fn visit_super(&mut self, expr: &mut Super) {
+        self.ctx.error(UnsupportedSuper(expr.span));
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &mut ArrowExpression<'a>) {
+        self.arrow_depth += 1;
+        self.visit_formal_parameters(&mut expr.params);
+        self.visit_function_body(&mut expr.body);
+        self.arrow_depth -= 1;
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+/// Drives the generator of a lowered async generator, a yielded `_OverloadYield` is awaited
+/// (kind 0) or delegated to an async iterator (kind 1) instead of being yielded.
+const HELPERS: &str = r#"function _OverloadYield(value, kind) {
+    this.v = value;
+    this.k = kind;
+}
+function _awaitAsyncGenerator(value) {
+    return new _OverloadYield(value, 0);
+}
+function _wrapAsyncGenerator(fn) {
+    return function () {
+        return new _AsyncGenerator(fn.apply(this, arguments));
+    };
+}
+function _AsyncGenerator(gen) {
+    var front, back;
+    function send(key, arg) {
+        return new Promise(function (resolve, reject) {
+            var request = { key: key, arg: arg, resolve: resolve, reject: reject, next: null };
+            if (back) {
+                back = back.next = request;
+            } else {
+                front = back = request;
+                resume(key, arg);
+            }
+        });
+    }
+    function resume(key, arg) {
+        try {
+            var result = gen[key](arg), value = result.value, overloaded = value instanceof _OverloadYield;
+            Promise.resolve(overloaded ? value.v : value).then(function (arg) {
+                if (overloaded) {
+                    var nextKey = key === "return" ? "return" : "next";
+                    if (!value.k || arg.done) return resume(nextKey, arg);
+                    arg = gen[nextKey](arg).value;
+                }
+                settle(result.done ? "return" : "normal", arg);
+            }, function (err) {
+                resume("throw", err);
+            });
+        } catch (err) {
+            settle("throw", err);
+        }
+    }
+    function settle(type, value) {
+        if (type === "throw") front.reject(value);
+        else front.resolve({ value: value, done: type === "return" });
+        (front = front.next) ? resume(front.key, front.arg) : (back = null);
+    }
+    this._invoke = send;
+}
+_AsyncGenerator.prototype[typeof Symbol === "function" && Symbol.asyncIterator || "@@asyncIterator"] = function () {
+    return this;
+};
+_AsyncGenerator.prototype.next = function (arg) {
+    return this._invoke("next", arg);
+};
+_AsyncGenerator.prototype.throw = function (arg) {
+    return this._invoke("throw", arg);
+};
+_AsyncGenerator.prototype.return = function (arg) {
+    return this._invoke("return", arg);
+};
+function _asyncGeneratorDelegate(inner) {
+    var iter = {}, waiting = false;
+    function pump(key, value) {
+        waiting = true;
+        value = new Promise(function (resolve) {
+            resolve(inner[key](value));
+        });
+        return { done: false, value: new _OverloadYield(value, 1) };
+    }
+    iter[typeof Symbol === "function" && Symbol.iterator || "@@iterator"] = function () {
+        return this;
+    };
+    iter.next = function (value) {
+        if (waiting) {
+            waiting = false;
+            return value;
+        }
+        return pump("next", value);
+    };
+    if (typeof inner.throw === "function") {
+        iter.throw = function (value) {
+            if (waiting) {
+                waiting = false;
+                throw value;
+            }
+            return pump("throw", value);
+        };
+    }
+    if (typeof inner.return === "function") {
+        iter.return = function (value) {
+            if (waiting) {
+                waiting = false;
+                return value;
+            }
+            return pump("return", value);
+        };
+    }
+    return iter;
+}
+function _asyncIterator(iterable) {
+    var method = typeof Symbol === "function" && Symbol.asyncIterator && iterable[Symbol.asyncIterator];
+    if (method != null) return method.call(iterable);
+    var iterator = iterable[typeof Symbol === "function" && Symbol.iterator || "@@iterator"]();
+    function step(result) {
+        return Promise.resolve(result.value).then(function (value) {
+            return { value: value, done: result.done };
+        });
+    }
+    return {
+        next: function (value) {
+            return step(iterator.next(value));
+        },
+        throw: function (value) {
+            return typeof iterator.throw === "function" ? step(iterator.throw(value)) : Promise.reject(value);
+        },
+        return: function (value) {
+            return typeof iterator.return === "function" ? step(iterator.return(value)) : Promise.resolve({ value: value, done: true });
+        }
+    };
+}
+"#;
+
+#[test]
+fn test() {
+    use crate::{options::TransformTarget, tester::Tester};
+
+    let options =
+        TransformOptions { target: TransformTarget::ES2017, ..TransformOptions::default() };
+    let tester = Tester::new("test.js", options);
+    let function = format!(
+        "{HELPERS}
+        function foo(a) {{
+            return _wrapAsyncGenerator(function* () {{
+                yield yield _awaitAsyncGenerator(a);
+                yield* _asyncGeneratorDelegate(_asyncIterator(bar()));
+                const f = async () => await a;
+            }}).apply(this, arguments);
+        }}"
+    );
+    let method = format!(
+        "{HELPERS}
+        const o = {{
+            foo() {{
+                return _wrapAsyncGenerator(function* () {{
+                    yield this;
+                }}).apply(this, arguments);
+            }}
+        }};"
+    );
+    tester.test(&[
+        (
+            "async function* foo(a) { yield await a; yield* bar(); const f = async () => await a; }",
+            function.as_str(),
+        ),
+        ("const o = { async *foo() { yield this; } };", method.as_str()),
+        ("async function foo() { await a; }", "async function foo() { await a; }"),
+    ]);
+    tester.test_errors(&[
+        "async function* foo() { for await (const x of y) {} }",
+        "class A extends B { async *foo() { yield super.foo(); } }",
+    ]);
+}
@@ -0,0 +1,3 @@
+mod async_generator_functions;
+
+pub use async_generator_functions::AsyncGeneratorFunctions;
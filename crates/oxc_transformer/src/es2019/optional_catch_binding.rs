@@ -3,7 +3,7 @@ use std::rc::Rc;
 use oxc_ast::{ast::*, AstBuilder};
 use oxc_span::SPAN;
 
-use crate::options::{TransformOptions, TransformTarget};
+use crate::options::{EsFeature, TransformOptions};
 
 /// ES2019: Optional Catch Binding
 ///
@@ -16,7 +16,8 @@ pub struct OptionalCatchBinding<'a> {
 
 impl<'a> OptionalCatchBinding<'a> {
     pub fn new(ast: Rc<AstBuilder<'a>>, options: &TransformOptions) -> Option<Self> {
-        (options.target < TransformTarget::ES2019 || options.optional_catch_binding)
+        (!options.target.supports(EsFeature::OptionalCatchBinding)
+            || options.optional_catch_binding)
             .then(|| Self { ast })
     }
 
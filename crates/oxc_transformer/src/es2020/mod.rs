@@ -1,5 +1,7 @@
 mod nullish_coalescing_operator;
+mod optional_chaining;
 
 pub use nullish_coalescing_operator::{
     NullishCoalescingOperator, NullishCoalescingOperatorOptions,
 };
+pub use optional_chaining::OptionalChaining;
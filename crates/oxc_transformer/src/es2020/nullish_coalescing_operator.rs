@@ -8,7 +8,7 @@ use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator};
 
 use crate::{
     context::TransformerCtx,
-    options::{TransformOptions, TransformTarget},
+    options::{EsFeature, TransformOptions},
     utils::CreateVars,
 };
 
@@ -50,13 +50,14 @@ impl<'a> NullishCoalescingOperator<'a> {
         ctx: TransformerCtx<'a>,
         options: &TransformOptions,
     ) -> Option<Self> {
-        (options.target < TransformTarget::ES2020 || options.nullish_coalescing_operator.is_some())
-            .then(|| {
-                let no_document_all = options.assumptions.no_document_all
-                    || options.nullish_coalescing_operator.is_some_and(|o| o.loose);
-                let vars = ast.new_vec();
-                Self { no_document_all, ast, ctx, vars }
-            })
+        (!options.target.supports(EsFeature::NullishCoalescingOperator)
+            || options.nullish_coalescing_operator.is_some())
+        .then(|| {
+            let no_document_all = options.assumptions.no_document_all
+                || options.nullish_coalescing_operator.is_some_and(|o| o.loose);
+            let vars = ast.new_vec();
+            Self { no_document_all, ast, ctx, vars }
+        })
     }
 
     pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
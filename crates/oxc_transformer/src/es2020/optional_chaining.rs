@@ -0,0 +1,221 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::SPAN;
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator};
+
+use crate::{
+    context::TransformerCtx,
+    options::{EsFeature, TransformOptions},
+    utils::CreateVars,
+};
+
+/// ES2020: Optional Chaining
+///
+/// References:
+/// * <https://babeljs.io/docs/babel-plugin-transform-optional-chaining>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-optional-chaining>
+pub struct OptionalChaining<'a> {
+    no_document_all: bool,
+
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+
+    vars: Vec<'a, VariableDeclarator<'a>>,
+}
+
+impl<'a> CreateVars<'a> for OptionalChaining<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+}
+
+impl<'a> OptionalChaining<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (!options.target.supports(EsFeature::OptionalChaining) || options.optional_chaining).then(
+            || {
+                let no_document_all = options.assumptions.no_document_all;
+                let vars = ast.new_vec();
+                Self { no_document_all, ast, ctx, vars }
+            },
+        )
+    }
+
+    /// `a?.b.c` -> `(_a = a) === null || _a === void 0 ? void 0 : _a.b.c`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        if !matches!(expr, Expression::ChainExpression(_)) {
+            return;
+        }
+        let Expression::ChainExpression(chain_expr) = self.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let chain = match chain_expr.unbox().expression {
+            ChainElement::CallExpression(call_expr) => Expression::CallExpression(call_expr),
+            ChainElement::MemberExpression(member_expr) => {
+                Expression::MemberExpression(member_expr)
+            }
+        };
+        *expr = self.transform_chain(chain);
+    }
+
+    /// Short circuit the innermost optional link, the rest of the chain is transformed
+    /// recursively inside of the non-nullish branch.
+    fn transform_chain(&mut self, mut chain: Expression<'a>) -> Expression<'a> {
+        let Some(depth) = innermost_optional(&chain, 0) else { return chain };
+        let link = nth_link(&mut chain, depth);
+
+        let test = match link {
+            Expression::MemberExpression(member_expr) => {
+                set_member_optional(member_expr, false);
+                let object = member_object(member_expr);
+                let (test, reference) = self.nullish_test(self.ast.move_expression(object));
+                *object = reference;
+                test
+            }
+            Expression::CallExpression(call_expr) => {
+                call_expr.optional = false;
+                if let Expression::MemberExpression(member_expr) = &mut call_expr.callee {
+                    // `a.b?.()` -> `(_a$b = (_a = a).b) === null || _a$b === void 0 ? void 0 : _a$b.call(_a)`
+                    let object = member_object(member_expr);
+                    let this_arg = match self.memoise(object) {
+                        Some(ident) => {
+                            let value = self.ast.move_expression(object);
+                            *object = self.assign(ident.clone(), value);
+                            self.ast.identifier_reference_expression(ident)
+                        }
+                        None => self.ast.copy(object),
+                    };
+                    let callee = self.ast.move_expression(&mut call_expr.callee);
+                    let (test, reference) = self.nullish_test(callee);
+                    let property = IdentifierName::new(SPAN, "call".into());
+                    call_expr.callee =
+                        self.ast.static_member_expression(SPAN, reference, property, false);
+                    call_expr.arguments.insert(0, Argument::Expression(this_arg));
+                    test
+                } else {
+                    let callee = self.ast.move_expression(&mut call_expr.callee);
+                    let (test, reference) = self.nullish_test(callee);
+                    call_expr.callee = reference;
+                    test
+                }
+            }
+            _ => unreachable!(),
+        };
+
+        let consequent = self.ast.void_0();
+        let alternate = self.transform_chain(chain);
+        self.ast.conditional_expression(SPAN, test, consequent, alternate)
+    }
+
+    /// Returns `(_a = a) === null || _a === void 0` and a reference to `_a`.
+    fn nullish_test(&mut self, expr: Expression<'a>) -> (Expression<'a>, Expression<'a>) {
+        let (assignment, reference) = match self.memoise(&expr) {
+            Some(ident) => {
+                let reference = self.ast.identifier_reference_expression(ident.clone());
+                (self.assign(ident, expr), reference)
+            }
+            None => {
+                let reference = self.ast.copy(&expr);
+                (expr, reference)
+            }
+        };
+
+        let null = self.ast.literal_null_expression(NullLiteral::new(SPAN));
+        let test = if self.no_document_all {
+            self.ast.binary_expression(SPAN, assignment, BinaryOperator::Equality, null)
+        } else {
+            let op = BinaryOperator::StrictEquality;
+            let left = self.ast.binary_expression(SPAN, assignment, op, null);
+            let right =
+                self.ast.binary_expression(SPAN, self.ast.copy(&reference), op, self.ast.void_0());
+            self.ast.logical_expression(SPAN, left, LogicalOperator::Or, right)
+        };
+        (test, reference)
+    }
+
+    /// Temporary variables created by this transform have no reference id and are never reassigned.
+    fn memoise(&mut self, expr: &Expression<'a>) -> Option<IdentifierReference> {
+        if matches!(expr, Expression::Identifier(ident) if ident.reference_id.get().is_none()) {
+            return None;
+        }
+        self.maybe_generate_memoised(expr)
+    }
+
+    fn assign(&self, ident: IdentifierReference, value: Expression<'a>) -> Expression<'a> {
+        let target = AssignmentTarget::SimpleAssignmentTarget(
+            self.ast.simple_assignment_target_identifier(ident),
+        );
+        self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, value)
+    }
+}
+
+/// Number of links between the end of the chain and the optional link closest to its start.
+fn innermost_optional(expr: &Expression, depth: usize) -> Option<usize> {
+    let (optional, inner) = match expr {
+        Expression::MemberExpression(member_expr) => (member_expr.optional(), member_expr.object()),
+        Expression::CallExpression(call_expr) => (call_expr.optional, &call_expr.callee),
+        _ => return None,
+    };
+    innermost_optional(inner, depth + 1).or_else(|| optional.then_some(depth))
+}
+
+fn nth_link<'b, 'a>(expr: &'b mut Expression<'a>, depth: usize) -> &'b mut Expression<'a> {
+    if depth == 0 {
+        return expr;
+    }
+    let inner = match expr {
+        Expression::MemberExpression(member_expr) => member_object(member_expr),
+        Expression::CallExpression(call_expr) => &mut call_expr.callee,
+        _ => unreachable!(),
+    };
+    nth_link(inner, depth - 1)
+}
+
+fn member_object<'b, 'a>(member_expr: &'b mut MemberExpression<'a>) -> &'b mut Expression<'a> {
+    match member_expr {
+        MemberExpression::ComputedMemberExpression(expr) => &mut expr.object,
+        MemberExpression::StaticMemberExpression(expr) => &mut expr.object,
+        MemberExpression::PrivateFieldExpression(expr) => &mut expr.object,
+    }
+}
+
+fn set_member_optional(member_expr: &mut MemberExpression, optional: bool) {
+    match member_expr {
+        MemberExpression::ComputedMemberExpression(expr) => expr.optional = optional,
+        MemberExpression::StaticMemberExpression(expr) => expr.optional = optional,
+        MemberExpression::PrivateFieldExpression(expr) => expr.optional = optional,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options =
+        TransformOptions { target: TransformTarget::ES2019, ..TransformOptions::default() };
+
+    let tests = &[
+        (
+            "let a = {}; a?.b.c;",
+            "let a = {}; a === null || a === void 0 ? void 0 : a.b.c;",
+        ),
+        (
+            "foo?.bar?.();",
+            "var _foo, _foo$bar; (_foo = foo) === null || _foo === void 0 ? void 0 : (_foo$bar = _foo.bar) === null || _foo$bar === void 0 ? void 0 : _foo$bar.call(_foo);",
+        ),
+    ];
+
+    Tester::new("test.js", options).test(tests);
+}
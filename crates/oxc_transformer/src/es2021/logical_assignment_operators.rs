@@ -7,7 +7,7 @@ use oxc_syntax::operator::{AssignmentOperator, LogicalOperator};
 
 use crate::{
     context::TransformerCtx,
-    options::{TransformOptions, TransformTarget},
+    options::{EsFeature, TransformOptions},
     utils::CreateVars,
 };
 
@@ -39,12 +39,12 @@ impl<'a> LogicalAssignmentOperators<'a> {
         ctx: TransformerCtx<'a>,
         options: &TransformOptions,
     ) -> Option<Self> {
-        (options.target < TransformTarget::ES2021 || options.logical_assignment_operators).then(
-            || {
+        (!options.target.supports(EsFeature::LogicalAssignmentOperators)
+            || options.logical_assignment_operators)
+            .then(|| {
                 let vars = ast.new_vec();
                 Self { ast, ctx, vars }
-            },
-        )
+            })
     }
 
     pub fn transform_expression<'b>(&mut self, expr: &'b mut Expression<'a>) {
@@ -0,0 +1,672 @@
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder, AstKind, Visit, VisitMut};
+use oxc_semantic::{ReferenceId, ScopeFlags, SymbolId};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::operator::AssignmentOperator;
+
+use crate::{
+    context::TransformerCtx,
+    options::{EsFeature, TransformOptions},
+    utils::CreateVars,
+};
+
+/// ES2022: Class Properties
+///
+/// Public fields are moved into the constructor, static fields are assigned after the class
+/// declaration, or in a sequence expression for class expressions. `this` in static fields is
+/// replaced by the class.
+///
+/// Private and computed fields are kept as they are, so are the fields before them: all fields are
+/// still initialized in the order they are declared.
+///
+/// References:
+/// * <https://babeljs.io/docs/babel-plugin-transform-class-properties>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-class-properties>
+pub struct ClassProperties<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    set_public_class_fields: bool,
+    vars: Vec<'a, VariableDeclarator<'a>>,
+}
+
+impl<'a> CreateVars<'a> for ClassProperties<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+}
+
+impl<'a> ClassProperties<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (!options.target.supports(EsFeature::ClassProperties) || options.class_properties).then(
+            || Self {
+                vars: ast.new_vec(),
+                ast,
+                ctx,
+                set_public_class_fields: options.assumptions.set_public_class_fields,
+            },
+        )
+    }
+
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let mut has_static_fields = false;
+        for stmt in stmts.iter_mut() {
+            if let Some(class) = class_declaration(stmt) {
+                self.transform_class(class);
+                has_static_fields |= class.id.is_some() && lowered_fields(class, true).any();
+            }
+        }
+        if !has_static_fields {
+            return;
+        }
+
+        let mut new_stmts = self.ast.new_vec_with_capacity(stmts.len());
+        for mut stmt in self.ast.move_statement_vec(stmts) {
+            let assignments = class_declaration(&mut stmt)
+                .and_then(|class| {
+                    let name = class.id.as_ref()?.name.clone();
+                    Some(self.take_static_fields(class, &name))
+                })
+                .unwrap_or_default();
+            new_stmts.push(stmt);
+            for expr in assignments {
+                new_stmts.push(self.ast.expression_statement(SPAN, expr));
+            }
+        }
+        *stmts = new_stmts;
+    }
+
+    /// `class { static bar = 1 }` -> `(_class = class {}, _class.bar = 1, _class)`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ClassExpression(class) = expr else { return };
+        self.transform_class(class);
+        if !lowered_fields(class, true).any() {
+            return;
+        }
+        let name = self.ctx.scopes().generate_uid("class");
+        self.ctx.add_binding(name.clone());
+        let binding = BindingIdentifier::new(SPAN, name.clone());
+        let pattern = self.ast.binding_pattern_identifier(binding);
+        let pattern = self.ast.binding_pattern(pattern, None, false);
+        let kind = VariableDeclarationKind::Var;
+        self.vars.push(self.ast.variable_declarator(SPAN, kind, pattern, None, false));
+
+        let assignments = self.take_static_fields(class, &name);
+        let class = self.ast.move_expression(expr);
+        let mut expressions = self.ast.new_vec_with_capacity(assignments.len() + 2);
+        let assignee = self
+            .ast
+            .simple_assignment_target_identifier(IdentifierReference::new(SPAN, name.clone()));
+        expressions.push(self.ast.assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(assignee),
+            class,
+        ));
+        expressions.extend(assignments);
+        expressions.push(self.identifier(&name));
+        let sequence = self.ast.sequence_expression(SPAN, expressions);
+        *expr = self.ast.parenthesized_expression(SPAN, sequence);
+    }
+
+    /// `class Foo { bar = 1 }` -> `class Foo { constructor() { this.bar = 1; } }`
+    fn transform_class(&mut self, class: &mut Class<'a>) {
+        if !lowered_fields(class, false).any() {
+            return;
+        }
+        let is_derived = class.super_class.is_some();
+
+        // Fields are initialized right after `super()` returns in derived classes.
+        let constructor = class.body.body.iter().position(is_constructor);
+        let insert_at = match constructor.map(|index| &class.body.body[index]) {
+            Some(ClassElement::MethodDefinition(method)) if is_derived => {
+                let Some(body) = &method.value.body else { return };
+                let Some(index) = body.statements.iter().position(is_super_call) else { return };
+                index + 1
+            }
+            _ => 0,
+        };
+
+        let fields = self.take_fields(class, false, |ast| ast.this_expression(SPAN));
+        let mut names = ReferenceNames::default();
+        for expr in &fields {
+            names.visit_expression(expr);
+        }
+        let mut initializers = self.ast.new_vec();
+        for expr in fields {
+            initializers.push(self.ast.expression_statement(SPAN, expr));
+        }
+
+        match constructor {
+            Some(_) => {
+                let Some(ClassElement::MethodDefinition(method)) =
+                    class.body.body.iter_mut().find(|element| is_constructor(element))
+                else {
+                    unreachable!()
+                };
+                self.rename_shadowing_bindings(&mut method.value, &names.names);
+                let Some(body) = &mut method.value.body else { unreachable!() };
+                let rest = body.statements.split_off(insert_at);
+                body.statements.extend(initializers);
+                body.statements.extend(rest);
+            }
+            None => {
+                let constructor = self.constructor(is_derived, initializers);
+                class.body.body.insert(0, constructor);
+            }
+        }
+    }
+
+    /// Initializers moved into the constructor must not see its parameters and variables:
+    /// `class Foo { bar = x; constructor(x) {} }` -> `class Foo { constructor(_x) { this.bar = x; } }`
+    fn rename_shadowing_bindings(&self, constructor: &mut Function<'a>, names: &HashSet<Atom>) {
+        let mut bindings = ConstructorBindings::default();
+        bindings.visit_formal_parameters(&constructor.params);
+        if let Some(body) = &constructor.body {
+            bindings.visit_function_body(body);
+        }
+        let mut renamer = Renamer::default();
+        for (name, symbol_id) in bindings.bindings {
+            if !names.contains(&name) {
+                continue;
+            }
+            let new_name = self.ctx.scopes().generate_uid(&name);
+            self.ctx.add_binding(new_name.clone());
+            for reference_id in self.ctx.symbols().get_resolved_reference_ids(symbol_id) {
+                renamer.references.insert(*reference_id, new_name.clone());
+            }
+            renamer.symbols.insert(symbol_id, new_name);
+        }
+        if !renamer.symbols.is_empty() {
+            renamer.visit_formal_parameters(&mut constructor.params);
+            if let Some(body) = &mut constructor.body {
+                renamer.visit_function_body(body);
+            }
+        }
+    }
+
+    /// `class Foo { static bar = this.baz }` -> `class Foo {} Foo.bar = Foo.baz;`
+    fn take_static_fields(
+        &self,
+        class: &mut Class<'a>,
+        name: &Atom,
+    ) -> std::vec::Vec<Expression<'a>> {
+        let mut fields = self.take_fields(class, true, |ast| {
+            ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+        });
+        let mut replacer = ThisReplacer { ast: &self.ast, class_name: name, class_depth: 0 };
+        for expr in &mut fields {
+            replacer.visit_expression(expr);
+        }
+        fields
+    }
+
+    /// Remove the lowered fields from the class body and return their initializers.
+    fn take_fields(
+        &self,
+        class: &mut Class<'a>,
+        r#static: bool,
+        target: impl Fn(&AstBuilder<'a>) -> Expression<'a>,
+    ) -> std::vec::Vec<Expression<'a>> {
+        let lowered: std::vec::Vec<bool> = lowered_fields(class, r#static).lowered;
+        let mut fields = vec![];
+        for (element, lowered) in class.body.body.iter_mut().zip(&lowered) {
+            let ClassElement::PropertyDefinition(prop) = element else { continue };
+            if *lowered {
+                let value = prop.value.take().unwrap_or_else(|| self.ast.void_0());
+                fields.push(self.define_field(target(&self.ast), &prop.key, value));
+            }
+        }
+        let mut i = 0;
+        class.body.body.retain(|_| {
+            i += 1;
+            !lowered[i - 1]
+        });
+        fields
+    }
+
+    /// `this.bar = value` with `setPublicClassFields`, otherwise
+    /// `Object.defineProperty(this, "bar", { enumerable: true, configurable: true, writable: true, value })`
+    fn define_field(
+        &self,
+        target: Expression<'a>,
+        key: &PropertyKey<'a>,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        // Only fields with a static name are lowered.
+        let Some(name) = key.static_name() else { unreachable!() };
+        if self.set_public_class_fields {
+            let member = match key {
+                PropertyKey::Identifier(ident) => self.ast.static_member_expression(
+                    SPAN,
+                    target,
+                    IdentifierName::new(SPAN, ident.name.clone()),
+                    false,
+                ),
+                _ => self.ast.computed_member_expression(
+                    SPAN,
+                    target,
+                    self.string_literal(name),
+                    false,
+                ),
+            };
+            let Expression::MemberExpression(member) = member else { unreachable!() };
+            let left = AssignmentTarget::SimpleAssignmentTarget(
+                SimpleAssignmentTarget::MemberAssignmentTarget(member),
+            );
+            return self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, left, value);
+        }
+
+        let mut properties = self.ast.new_vec();
+        for property in ["enumerable", "configurable", "writable"] {
+            let value = self.ast.literal_boolean_expression(BooleanLiteral::new(SPAN, true));
+            properties.push(self.object_property(property, value));
+        }
+        properties.push(self.object_property("value", value));
+        let descriptor = self.ast.object_expression(SPAN, properties, None);
+
+        let object = self
+            .ast
+            .identifier_reference_expression(IdentifierReference::new(SPAN, "Object".into()));
+        let callee = self.ast.static_member_expression(
+            SPAN,
+            object,
+            IdentifierName::new(SPAN, "defineProperty".into()),
+            false,
+        );
+        let mut arguments = self.ast.new_vec();
+        arguments.push(Argument::Expression(target));
+        arguments.push(Argument::Expression(self.string_literal(name)));
+        arguments.push(Argument::Expression(descriptor));
+        self.ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    /// `constructor() {}` or `constructor(...args) { super(...args); }`
+    fn constructor(
+        &self,
+        is_derived: bool,
+        initializers: Vec<'a, Statement<'a>>,
+    ) -> ClassElement<'a> {
+        let mut statements = self.ast.new_vec();
+        let mut rest = None;
+        if is_derived {
+            let args: Atom = "args".into();
+            let ident =
+                self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, args.clone()));
+            let pattern = self.ast.binding_pattern(ident, None, false);
+            rest = Some(self.ast.rest_element(SPAN, pattern));
+
+            let argument =
+                self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, args));
+            let arguments = self
+                .ast
+                .new_vec_single(Argument::SpreadElement(self.ast.spread_element(SPAN, argument)));
+            let super_call =
+                self.ast.call_expression(SPAN, self.ast.super_(SPAN), arguments, false, None);
+            statements.push(self.ast.expression_statement(SPAN, super_call));
+        }
+        statements.extend(initializers);
+
+        let params = self.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            self.ast.new_vec(),
+            rest,
+        );
+        let body = self.ast.function_body(SPAN, self.ast.new_vec(), statements);
+        let value = self.ast.function(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            false,
+            false,
+            None,
+            params,
+            Some(body),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        ClassElement::MethodDefinition(self.ast.alloc(MethodDefinition {
+            span: SPAN,
+            key: self.ast.property_key_identifier(IdentifierName::new(SPAN, "constructor".into())),
+            value,
+            kind: MethodDefinitionKind::Constructor,
+            computed: false,
+            r#static: false,
+            r#override: false,
+            optional: false,
+            accessibility: None,
+            decorators: self.ast.new_vec(),
+        }))
+    }
+
+    fn object_property(&self, name: &str, value: Expression<'a>) -> ObjectPropertyKind<'a> {
+        let key = self.ast.property_key_identifier(IdentifierName::new(SPAN, name.into()));
+        ObjectPropertyKind::ObjectProperty(self.ast.object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            value,
+            None,
+            false,
+            false,
+            false,
+        ))
+    }
+
+    fn string_literal(&self, value: Atom) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(SPAN, value))
+    }
+
+    fn identifier(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+}
+
+fn class_declaration<'b, 'a>(stmt: &'b mut Statement<'a>) -> Option<&'b mut Class<'a>> {
+    match stmt {
+        Statement::Declaration(Declaration::ClassDeclaration(class)) => Some(class),
+        Statement::ModuleDeclaration(decl) => match &mut **decl {
+            ModuleDeclaration::ExportNamedDeclaration(decl) => match &mut decl.declaration {
+                Some(Declaration::ClassDeclaration(class)) => Some(class),
+                _ => None,
+            },
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => match &mut decl.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(class),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The fields of a class body which are lowered.
+struct LoweredFields {
+    lowered: std::vec::Vec<bool>,
+}
+
+impl LoweredFields {
+    fn any(&self) -> bool {
+        self.lowered.contains(&true)
+    }
+}
+
+/// Public fields with a static name which come after the last field that is kept, so the
+/// lowered fields are still initialized after the kept ones.
+fn lowered_fields(class: &Class, r#static: bool) -> LoweredFields {
+    let class_name = class.id.as_ref().map(|id| &id.name);
+    let elements = &class.body.body;
+    let lowerable: std::vec::Vec<bool> =
+        elements.iter().map(|element| is_lowerable_field(element, r#static, class_name)).collect();
+    let first = elements
+        .iter()
+        .zip(&lowerable)
+        .rposition(|(element, lowerable)| !lowerable && is_initialized(element, r#static))
+        .map_or(0, |index| index + 1);
+    let lowered =
+        lowerable.iter().enumerate().map(|(i, lowerable)| i >= first && *lowerable).collect();
+    LoweredFields { lowered }
+}
+
+/// Elements which run when the class is defined (static) or when an instance is created.
+fn is_initialized(element: &ClassElement, r#static: bool) -> bool {
+    match element {
+        ClassElement::PropertyDefinition(prop) => prop.r#static == r#static && !prop.declare,
+        ClassElement::AccessorProperty(prop) => prop.r#static == r#static,
+        ClassElement::StaticBlock(_) => r#static,
+        _ => false,
+    }
+}
+
+/// Public fields with a static name, static fields must not use `super` or refer to the name
+/// of a class expression, which are not in scope outside of the class body.
+fn is_lowerable_field(element: &ClassElement, r#static: bool, class_name: Option<&Atom>) -> bool {
+    let ClassElement::PropertyDefinition(prop) = element else { return false };
+    if prop.r#static != r#static || prop.declare || prop.key.is_private_identifier() {
+        return false;
+    }
+    let has_static_name = match &prop.key {
+        PropertyKey::Identifier(_) => true,
+        PropertyKey::Expression(Expression::StringLiteral(_) | Expression::NumberLiteral(_)) => {
+            true
+        }
+        _ => false,
+    };
+    if !has_static_name {
+        return false;
+    }
+    if !r#static {
+        return true;
+    }
+    let Some(value) = &prop.value else { return true };
+    let mut finder = ThisFinder::default();
+    finder.visit_expression(value);
+    let mut names = ReferenceNames::default();
+    names.visit_expression(value);
+    !finder.uses_super
+        && !finder.uses_nested_this
+        && class_name.map_or(true, |name| !names.names.contains(name))
+}
+
+fn is_constructor(element: &ClassElement) -> bool {
+    matches!(element, ClassElement::MethodDefinition(method) if method.kind == MethodDefinitionKind::Constructor)
+}
+
+fn is_super_call(stmt: &Statement) -> bool {
+    let Statement::ExpressionStatement(stmt) = stmt else { return false };
+    matches!(&stmt.expression, Expression::CallExpression(call) if matches!(call.callee, Expression::Super(_)))
+}
+
+/// How a static field initializer refers to its class.
+#[derive(Default)]
+struct ThisFinder {
+    uses_super: bool,
+    /// `this` in a nested class, where it is not replaced
+    uses_nested_this: bool,
+    class_depth: usize,
+}
+
+impl<'a> Visit<'a> for ThisFinder {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        if matches!(kind, AstKind::Class(_)) {
+            self.class_depth += 1;
+        }
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        if matches!(kind, AstKind::Class(_)) {
+            self.class_depth -= 1;
+        }
+    }
+
+    fn visit_this_expression(&mut self, _expr: &ThisExpression) {
+        self.uses_nested_this |= self.class_depth > 0;
+    }
+
+    fn visit_super(&mut self, _expr: &Super) {
+        self.uses_super = true;
+    }
+
+    // `this` is rebound in functions.
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+}
+
+/// Replace `this` with the class in static field initializers.
+struct ThisReplacer<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    class_name: &'b Atom,
+    class_depth: usize,
+}
+
+impl<'a, 'b> VisitMut<'a> for ThisReplacer<'a, 'b> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        if matches!(kind, AstKind::Class(_)) {
+            self.class_depth += 1;
+        }
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        if matches!(kind, AstKind::Class(_)) {
+            self.class_depth -= 1;
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if matches!(expr, Expression::ThisExpression(_)) && self.class_depth == 0 {
+            let ident = IdentifierReference::new(SPAN, self.class_name.clone());
+            *expr = self.ast.identifier_reference_expression(ident);
+            return;
+        }
+        self.visit_expression_match(expr);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: Option<ScopeFlags>) {}
+}
+
+/// Names of the identifiers referenced by the lowered initializers.
+#[derive(Default)]
+struct ReferenceNames {
+    names: HashSet<Atom>,
+}
+
+impl<'a> Visit<'a> for ReferenceNames {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference) {
+        self.names.insert(ident.name.clone());
+    }
+}
+
+/// Parameters and variables of the constructor, without the ones of nested functions.
+#[derive(Default)]
+struct ConstructorBindings {
+    bindings: std::vec::Vec<(Atom, SymbolId)>,
+}
+
+impl<'a> Visit<'a> for ConstructorBindings {
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier) {
+        if let Some(symbol_id) = ident.symbol_id.get() {
+            self.bindings.push((ident.name.clone(), symbol_id));
+        }
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, _flags: Option<ScopeFlags>) {
+        if let Some(id) = &func.id {
+            self.visit_binding_identifier(id);
+        }
+    }
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowExpression<'a>) {}
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if let Some(id) = &class.id {
+            self.visit_binding_identifier(id);
+        }
+    }
+}
+
+/// Rename bindings and their resolved references.
+#[derive(Default)]
+struct Renamer {
+    symbols: HashMap<SymbolId, Atom>,
+    references: HashMap<ReferenceId, Atom>,
+}
+
+impl<'a> VisitMut<'a> for Renamer {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier) {
+        if let Some(name) = ident.symbol_id.get().and_then(|id| self.symbols.get(&id)) {
+            ident.name = name.clone();
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference) {
+        if let Some(name) = ident.reference_id.get().and_then(|id| self.references.get(&id)) {
+            ident.name = name.clone();
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let mut options =
+        TransformOptions { target: TransformTarget::ES2021, ..TransformOptions::default() };
+    options.assumptions.set_public_class_fields = true;
+
+    let tests = &[
+        (
+            "class Foo { #baz = 2; bar = 1; static qux = 3; constructor(a) { this.a = a; } }",
+            "class Foo { #baz = 2; constructor(a) { this.bar = 1; this.a = a; } } Foo.qux = 3;",
+        ),
+        // Constructor bindings shadowing the names used by the initializers are renamed.
+        (
+            "let x = 1; class Foo { bar = x; constructor(x) { this.x = x; } }",
+            "let x = 1; class Foo { constructor(_x) { this.bar = x; this.x = _x; } }",
+        ),
+        (
+            "class Foo { bar = x; constructor() { let x = 2; foo(x); } }",
+            "class Foo { constructor() { this.bar = x; let _x = 2; foo(_x); } }",
+        ),
+        // Fields before a kept field are kept to preserve the initialization order.
+        (
+            "class Foo { bar = 1; #baz = this.bar; qux = 2; }",
+            "class Foo { constructor() { this.qux = 2; } bar = 1; #baz = this.bar; }",
+        ),
+        (
+            "class Foo { static a = 1; static #b = 2; static c = 3; }",
+            "class Foo { static a = 1; static #b = 2; } Foo.c = 3;",
+        ),
+        (
+            "class Foo { static a = 1; static b = this.a; static c = () => this; }",
+            "class Foo {} Foo.a = 1; Foo.b = Foo.a; Foo.c = () => Foo;",
+        ),
+        (
+            "class Foo extends Bar { static a = super.a; }",
+            "class Foo extends Bar { static a = super.a; }",
+        ),
+        (
+            "let A = class { static a = 1; b = this; };",
+            "var _class; let A = (_class = class { constructor() { this.b = this; } }, _class.a = 1, _class);",
+        ),
+        (
+            "let A = class B { static a = B; };",
+            "let A = class B { static a = B; };",
+        ),
+        (
+            "class Foo extends Bar { bar; }",
+            "class Foo extends Bar { constructor(...args) { super(...args); this.bar = void 0; } }",
+        ),
+        (
+            "let Foo = class extends Bar { 'bar' = 1; constructor() { foo(); super(); } }",
+            "let Foo = class extends Bar { constructor() { foo(); super(); this['bar'] = 1; } }",
+        ),
+    ];
+
+    Tester::new("test.js", options).test(tests);
+
+    let options =
+        TransformOptions { target: TransformTarget::ES2021, ..TransformOptions::default() };
+    let tests = &[(
+        "class Foo { bar = 1; }",
+        "class Foo { constructor() { Object.defineProperty(this, 'bar', { enumerable: true, configurable: true, writable: true, value: 1 }); } }",
+    )];
+
+    Tester::new("test.js", options).test(tests);
+}
@@ -3,7 +3,7 @@ use std::{collections::HashSet, rc::Rc};
 use oxc_ast::{ast::*, AstBuilder};
 use oxc_span::{Atom, SPAN};
 
-use crate::options::{TransformOptions, TransformTarget};
+use crate::options::{EsFeature, TransformOptions};
 
 /// ES2022: Class Static Block
 ///
@@ -16,7 +16,7 @@ pub struct ClassStaticBlock<'a> {
 
 impl<'a> ClassStaticBlock<'a> {
     pub fn new(ast: Rc<AstBuilder<'a>>, options: &TransformOptions) -> Option<Self> {
-        (options.target < TransformTarget::ES2022 || options.class_static_block)
+        (!options.target.supports(EsFeature::ClassStaticBlock) || options.class_static_block)
             .then(|| Self { ast })
     }
 
@@ -1,3 +1,5 @@
+mod class_properties;
 mod class_static_block;
 
+pub use class_properties::ClassProperties;
 pub use class_static_block::ClassStaticBlock;
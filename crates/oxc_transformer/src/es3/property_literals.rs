@@ -3,7 +3,7 @@ use oxc_span::SPAN;
 use std::rc::Rc;
 
 use crate::utils::is_valid_es3_identifier;
-use crate::{TransformOptions, TransformTarget};
+use crate::{EsFeature, TransformOptions};
 
 /// ES3: PropertyLiteral
 ///
@@ -15,7 +15,8 @@ pub struct PropertyLiteral<'a> {
 
 impl<'a> PropertyLiteral<'a> {
     pub fn new(ast: Rc<AstBuilder<'a>>, options: &TransformOptions) -> Option<Self> {
-        (options.target <= TransformTarget::ES3 || options.property_literals).then(|| Self { ast })
+        (!options.target.supports(EsFeature::PropertyLiterals) || options.property_literals)
+            .then(|| Self { ast })
     }
 
     pub fn transform_object_property<'b>(&mut self, expr: &'b mut ObjectProperty<'a>) {
@@ -7,16 +7,21 @@
 //! * <https://babel.dev/docs/presets>
 //! * <https://github.com/microsoft/TypeScript/blob/main/src/compiler/transformer.ts>
 
+mod commonjs;
 mod context;
+mod decorators;
 mod es2015;
 mod es2016;
+mod es2018;
 mod es2019;
 mod es2020;
 mod es2021;
 mod es2022;
 mod es3;
 mod options;
+mod plugin;
 mod react_jsx;
+mod react_refresh;
 mod regexp;
 #[cfg(test)]
 mod tester;
@@ -27,39 +32,62 @@ use std::{cell::RefCell, rc::Rc, sync::Arc};
 
 use es2015::TemplateLiterals;
 use oxc_allocator::Allocator;
-use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_ast::{ast::*, walk_function_mut, AstBuilder, VisitMut};
 use oxc_diagnostics::Error;
-use oxc_semantic::Semantic;
+use oxc_semantic::{ScopeFlags, Semantic};
 use oxc_span::SourceType;
 
 use crate::{
-    context::TransformerCtx, es2015::*, es2016::ExponentiationOperator,
-    es2019::OptionalCatchBinding, es2020::NullishCoalescingOperator,
-    es2021::LogicalAssignmentOperators, es2022::ClassStaticBlock, es3::PropertyLiteral,
-    react_jsx::ReactJsx, regexp::RegexpFlags, typescript::TypeScript, utils::CreateVars,
+    commonjs::CommonJs,
+    decorators::Decorators,
+    es2015::*,
+    es2016::ExponentiationOperator,
+    es2018::AsyncGeneratorFunctions,
+    es2019::OptionalCatchBinding,
+    es2020::{NullishCoalescingOperator, OptionalChaining},
+    es2021::LogicalAssignmentOperators,
+    es2022::{ClassProperties, ClassStaticBlock},
+    es3::PropertyLiteral,
+    react_jsx::ReactJsx,
+    react_refresh::ReactRefresh,
+    regexp::RegexpFlags,
+    typescript::TypeScript,
+    utils::CreateVars,
 };
 
 pub use crate::{
+    context::TransformerCtx,
+    decorators::{DecoratorsOptions, DecoratorsVersion},
     es2015::ArrowFunctionsOptions,
     es2020::NullishCoalescingOperatorOptions,
-    options::{TransformOptions, TransformTarget},
+    options::{EsFeature, ModuleFormat, TransformOptions, TransformTarget},
+    plugin::{TransformPipeline, TransformerPlugin},
     react_jsx::{ReactJsxOptions, ReactJsxRuntime, ReactJsxRuntimeOption},
+    react_refresh::ReactRefreshOptions,
 };
 
 pub struct Transformer<'a> {
     ctx: TransformerCtx<'a>,
+    plugins: Vec<Box<dyn TransformerPlugin<'a> + 'a>>,
     #[allow(unused)]
     typescript: Option<TypeScript<'a>>,
+    decorators: Option<Decorators<'a>>,
     react_jsx: Option<ReactJsx<'a>>,
+    react_refresh: Option<ReactRefresh<'a>>,
+    commonjs: Option<CommonJs<'a>>,
     regexp_flags: Option<RegexpFlags<'a>>,
     // es2022
     es2022_class_static_block: Option<ClassStaticBlock<'a>>,
+    es2022_class_properties: Option<ClassProperties<'a>>,
     // es2021
     es2021_logical_assignment_operators: Option<LogicalAssignmentOperators<'a>>,
     // es2020
     es2020_nullish_coalescing_operators: Option<NullishCoalescingOperator<'a>>,
+    es2020_optional_chaining: Option<OptionalChaining<'a>>,
     // es2019
     es2019_optional_catch_binding: Option<OptionalCatchBinding<'a>>,
+    // es2018
+    es2018_async_generator_functions: Option<AsyncGeneratorFunctions<'a>>,
     // es2016
     es2016_exponentiation_operator: Option<ExponentiationOperator<'a>>,
     // es2015
@@ -88,17 +116,23 @@ impl<'a> Transformer<'a> {
 
         Self {
             ctx: ctx.clone(),
+            plugins: vec![],
             // TODO: pass verbatim_module_syntax from user config
             typescript: source_type.is_typescript().then(|| TypeScript::new(Rc::clone(&ast), ctx.clone(), false)),
+            decorators: Decorators::new(Rc::clone(&ast), ctx.clone(), &options),
             regexp_flags: RegexpFlags::new(Rc::clone(&ast), &options),
             // es2022
             es2022_class_static_block: es2022::ClassStaticBlock::new(Rc::clone(&ast), &options),
+            es2022_class_properties: ClassProperties::new(Rc::clone(&ast), ctx.clone(), &options),
             // es2021
             es2021_logical_assignment_operators: LogicalAssignmentOperators::new(Rc::clone(&ast), ctx.clone(), &options),
             // es2020
             es2020_nullish_coalescing_operators: NullishCoalescingOperator::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2020_optional_chaining: OptionalChaining::new(Rc::clone(&ast), ctx.clone(), &options),
             // es2019
             es2019_optional_catch_binding: OptionalCatchBinding::new(Rc::clone(&ast), &options),
+            // es2018
+            es2018_async_generator_functions: AsyncGeneratorFunctions::new(Rc::clone(&ast), ctx.clone(), &options),
             // es2016
             es2016_exponentiation_operator: ExponentiationOperator::new(Rc::clone(&ast), ctx.clone(), &options),
             // es2015
@@ -110,14 +144,33 @@ impl<'a> Transformer<'a> {
             es2015_instanceof: Instanceof::new(Rc::clone(&ast), ctx.clone(), &options),
             // other
             es3_property_literal: PropertyLiteral::new(Rc::clone(&ast), &options),
+            commonjs: CommonJs::new(Rc::clone(&ast), ctx.clone(), &options),
+            react_refresh: ReactRefresh::new(Rc::clone(&ast), ctx.clone(), options.react_refresh.clone()),
             react_jsx: ReactJsx::new(Rc::clone(&ast), ctx.clone(), options)
         }
     }
 
+    /// Add a custom pass, plugins run in the order they are added.
+    #[must_use]
+    pub fn with_plugin<P: TransformerPlugin<'a> + 'a>(mut self, plugin: P) -> Self {
+        self.plugins.push(Box::new(plugin));
+        self
+    }
+
     /// # Errors
     /// Returns `Vec<Error>` if any errors were collected during the transformation.
     pub fn build(mut self, program: &mut Program<'a>) -> Result<(), Vec<Error>> {
+        for plugin in &mut self.plugins {
+            plugin.before_transform(program, &mut self.ctx);
+        }
+        // Symbols and references of the nodes added or removed by the plugins
+        if !self.plugins.is_empty() {
+            self.ctx.rebuild_semantic(program);
+        }
         self.visit_program(program);
+        for plugin in &mut self.plugins {
+            plugin.after_transform(program, &mut self.ctx);
+        }
         let errors: Vec<_> = self
             .ctx
             .errors()
@@ -142,7 +195,11 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
         self.typescript.as_mut().map(|t| t.transform_program(program));
         self.visit_statements(&mut program.body);
 
+        self.decorators.as_mut().map(|t| t.add_helpers(program));
+        self.es2018_async_generator_functions.as_mut().map(|t| t.add_helpers(program));
         self.react_jsx.as_mut().map(|t| t.add_react_jsx_runtime_imports(program));
+        self.react_refresh.as_mut().map(|t| t.transform_program(program));
+        self.commonjs.as_mut().map(|t| t.transform_program(program));
     }
 
     fn visit_assignment_expression(&mut self, expr: &mut AssignmentExpression<'a>) {
@@ -153,12 +210,20 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
     }
 
     fn visit_statements(&mut self, stmts: &mut oxc_allocator::Vec<'a, Statement<'a>>) {
+        for plugin in &mut self.plugins {
+            plugin.transform_statements(stmts, &mut self.ctx);
+        }
+        self.decorators.as_mut().map(|t| t.transform_statements(stmts));
+        self.es2022_class_properties.as_mut().map(|t| t.transform_statements(stmts));
+
         for stmt in stmts.iter_mut() {
             self.visit_statement(stmt);
         }
         // TODO: we need scope id to insert the vars into the correct statements
+        self.es2022_class_properties.as_mut().map(|t| t.add_vars_to_statements(stmts));
         self.es2021_logical_assignment_operators.as_mut().map(|t| t.add_vars_to_statements(stmts));
         self.es2020_nullish_coalescing_operators.as_mut().map(|t| t.add_vars_to_statements(stmts));
+        self.es2020_optional_chaining.as_mut().map(|t| t.add_vars_to_statements(stmts));
         self.es2016_exponentiation_operator.as_mut().map(|t| t.add_vars_to_statements(stmts));
         self.es2015_arrow_functions.as_mut().map(|t| t.transform_statements(stmts));
     }
@@ -174,12 +239,18 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
     }
 
     fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        for plugin in &mut self.plugins {
+            plugin.transform_expression(expr, &mut self.ctx);
+        }
         // self.typescript.as_mut().map(|t| t.transform_expression(expr));
+        self.decorators.as_mut().map(|t| t.transform_expression(expr));
         self.react_jsx.as_mut().map(|t| t.transform_expression(expr));
         self.regexp_flags.as_mut().map(|t| t.transform_expression(expr));
 
+        self.es2022_class_properties.as_mut().map(|t| t.transform_expression(expr));
         self.es2021_logical_assignment_operators.as_mut().map(|t| t.transform_expression(expr));
         self.es2020_nullish_coalescing_operators.as_mut().map(|t| t.transform_expression(expr));
+        self.es2020_optional_chaining.as_mut().map(|t| t.transform_expression(expr));
         self.es2015_arrow_functions.as_mut().map(|t| t.transform_expression(expr));
         self.es2015_instanceof.as_mut().map(|t| t.transform_expression(expr));
         self.es2016_exponentiation_operator.as_mut().map(|t| t.transform_expression(expr));
@@ -188,6 +259,12 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
         self.visit_expression_match(expr);
     }
 
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: Option<ScopeFlags>) {
+        self.es2018_async_generator_functions.as_mut().map(|t| t.transform_function(func));
+
+        walk_function_mut(self, func, flags);
+    }
+
     fn visit_catch_clause(&mut self, clause: &mut CatchClause<'a>) {
         self.es2019_optional_catch_binding.as_mut().map(|t| t.transform_catch_clause(clause));
 
@@ -1,23 +1,34 @@
+use std::str::FromStr;
+
 use oxc_syntax::assumptions::CompilerAssumptions;
 
 use crate::{
-    es2015::ArrowFunctionsOptions, es2020::NullishCoalescingOperatorOptions,
-    react_jsx::ReactJsxOptions,
+    decorators::DecoratorsOptions, es2015::ArrowFunctionsOptions,
+    es2020::NullishCoalescingOperatorOptions, react_jsx::ReactJsxOptions,
+    react_refresh::ReactRefreshOptions,
 };
 
 #[derive(Debug, Default, Clone)]
 pub struct TransformOptions {
     pub target: TransformTarget,
+    /// The module format of the output
+    pub module: ModuleFormat,
     pub assumptions: CompilerAssumptions,
 
     pub react_jsx: Option<ReactJsxOptions>,
 
+    pub react_refresh: Option<ReactRefreshOptions>,
+
+    pub decorators: Option<DecoratorsOptions>,
+
     // es2022
     pub class_static_block: bool,
+    pub class_properties: bool,
     // es2021
     pub logical_assignment_operators: bool,
     // es2020
     pub nullish_coalescing_operator: Option<NullishCoalescingOperatorOptions>,
+    pub optional_chaining: bool,
     // es2019
     pub optional_catch_binding: bool,
     // es2016
@@ -41,12 +52,112 @@ pub enum TransformTarget {
     ES5,
     ES2015,
     ES2016,
+    ES2017,
     ES2018,
     ES2019,
     ES2020,
     ES2021,
     ES2022,
+    ES2023,
     ES2024,
     #[default]
     ESNext,
 }
+
+impl TransformTarget {
+    /// Whether the target runtime supports `feature` natively.
+    pub fn supports(self, feature: EsFeature) -> bool {
+        self >= feature.target()
+    }
+}
+
+impl FromStr for TransformTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "es3" => Ok(Self::ES3),
+            "es5" => Ok(Self::ES5),
+            "es6" | "es2015" => Ok(Self::ES2015),
+            "es2016" => Ok(Self::ES2016),
+            "es2017" => Ok(Self::ES2017),
+            "es2018" => Ok(Self::ES2018),
+            "es2019" => Ok(Self::ES2019),
+            "es2020" => Ok(Self::ES2020),
+            "es2021" => Ok(Self::ES2021),
+            "es2022" => Ok(Self::ES2022),
+            "es2023" => Ok(Self::ES2023),
+            "es2024" => Ok(Self::ES2024),
+            "esnext" => Ok(Self::ESNext),
+            _ => Err(format!(
+                "expected `es3`, `es5`, `es2015` to `es2024` or `esnext`, received `{s}`"
+            )),
+        }
+    }
+}
+
+/// Module format of the output
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum ModuleFormat {
+    /// Keep `import` and `export`
+    #[default]
+    Esm,
+    /// Lower `import` and `export` to `require` and `exports`
+    CommonJs,
+}
+
+impl FromStr for ModuleFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "esm" | "es6" | "es2015" | "esnext" => Ok(Self::Esm),
+            "cjs" | "commonjs" => Ok(Self::CommonJs),
+            _ => Err(format!("expected `esm` or `cjs`, received `{s}`")),
+        }
+    }
+}
+
+/// Syntax lowered by the transformer, the feature table maps each one to the edition that introduced it.
+///
+/// See <https://compat-table.github.io/compat-table/es2016plus/>
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum EsFeature {
+    /// Reserved words as property names, `a.default`
+    PropertyLiterals,
+    ArrowFunctions,
+    DuplicateKeys,
+    FunctionName,
+    Instanceof,
+    ShorthandProperties,
+    TemplateLiterals,
+    ExponentiationOperator,
+    AsyncGeneratorFunctions,
+    OptionalCatchBinding,
+    NullishCoalescingOperator,
+    OptionalChaining,
+    LogicalAssignmentOperators,
+    ClassProperties,
+    ClassStaticBlock,
+}
+
+impl EsFeature {
+    /// The first target that supports this feature.
+    pub fn target(self) -> TransformTarget {
+        match self {
+            Self::PropertyLiterals => TransformTarget::ES5,
+            Self::ArrowFunctions
+            | Self::DuplicateKeys
+            | Self::FunctionName
+            | Self::Instanceof
+            | Self::ShorthandProperties
+            | Self::TemplateLiterals => TransformTarget::ES2015,
+            Self::ExponentiationOperator => TransformTarget::ES2016,
+            Self::AsyncGeneratorFunctions => TransformTarget::ES2018,
+            Self::OptionalCatchBinding => TransformTarget::ES2019,
+            Self::NullishCoalescingOperator | Self::OptionalChaining => TransformTarget::ES2020,
+            Self::LogicalAssignmentOperators => TransformTarget::ES2021,
+            Self::ClassProperties | Self::ClassStaticBlock => TransformTarget::ES2022,
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::ast::{Expression, Program, Statement};
+use oxc_diagnostics::Error;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+use crate::{context::TransformerCtx, TransformOptions, Transformer};
+
+/// A custom pass in the transformer pipeline.
+///
+/// Plugins run in the order they are added with [crate::Transformer::with_plugin] or
+/// [TransformPipeline::with_plugin], in each of the hooks:
+///
+/// 1. `before_parse` and `after_parse`, when the source text is parsed by a [TransformPipeline]
+/// 2. `before_transform`, the semantic analysis is rebuilt afterwards
+/// 3. `transform_statements` and `transform_expression`, during the traversal of the built-in
+///    passes and before them
+/// 4. `after_transform`
+///
+/// Visitors can be run from the program hooks by implementing [oxc_ast::VisitMut] and calling
+/// `visit_program`.
+///
+/// ```ignore
+/// impl<'a> VisitMut<'a> for StripInvariant { ... }
+///
+/// impl<'a> TransformerPlugin<'a> for StripInvariant {
+///     fn before_transform(&mut self, program: &mut Program<'a>, _ctx: &mut TransformerCtx<'a>) {
+///         self.visit_program(program);
+///     }
+/// }
+/// ```
+pub trait TransformerPlugin<'a> {
+    /// Runs on the source text, returns the text to parse instead of it.
+    fn before_parse(&mut self, _source_text: &str, _source_type: SourceType) -> Option<String> {
+        None
+    }
+
+    /// Runs on the parsed program, before the semantic analysis.
+    fn after_parse(&mut self, _program: &mut Program<'a>) {}
+
+    /// Runs on the parsed program, before any of the built-in passes.
+    /// TypeScript and JSX syntax is still present.
+    fn before_transform(&mut self, _program: &mut Program<'a>, _ctx: &mut TransformerCtx<'a>) {}
+
+    /// Runs on each list of statements of the built-in traversal, before the built-in passes.
+    fn transform_statements(
+        &mut self,
+        _stmts: &mut Vec<'a, Statement<'a>>,
+        _ctx: &mut TransformerCtx<'a>,
+    ) {
+    }
+
+    /// Runs on each expression of the built-in traversal, before the built-in passes.
+    fn transform_expression(&mut self, _expr: &mut Expression<'a>, _ctx: &mut TransformerCtx<'a>) {}
+
+    /// Runs after all of the built-in passes, right before the program is handed back for codegen.
+    fn after_transform(&mut self, _program: &mut Program<'a>, _ctx: &mut TransformerCtx<'a>) {}
+}
+
+/// Parse, analyze and transform a source text, with the parse hooks of the plugins.
+pub struct TransformPipeline<'a> {
+    allocator: &'a Allocator,
+    source_type: SourceType,
+    options: TransformOptions,
+    plugins: std::vec::Vec<Box<dyn TransformerPlugin<'a> + 'a>>,
+}
+
+impl<'a> TransformPipeline<'a> {
+    pub fn new(
+        allocator: &'a Allocator,
+        source_type: SourceType,
+        options: TransformOptions,
+    ) -> Self {
+        Self { allocator, source_type, options, plugins: vec![] }
+    }
+
+    /// Add a custom pass, plugins run in the order they are added.
+    #[must_use]
+    pub fn with_plugin<P: TransformerPlugin<'a> + 'a>(mut self, plugin: P) -> Self {
+        self.plugins.push(Box::new(plugin));
+        self
+    }
+
+    /// # Errors
+    /// Returns the parse errors, or the errors collected during the transformation.
+    pub fn build(
+        mut self,
+        source_text: &'a str,
+    ) -> Result<&'a mut Program<'a>, std::vec::Vec<Error>> {
+        let mut source_text = source_text;
+        for plugin in &mut self.plugins {
+            if let Some(text) = plugin.before_parse(source_text, self.source_type) {
+                source_text = self.allocator.alloc_str(&text);
+            }
+        }
+        let ret = Parser::new(self.allocator, source_text, self.source_type).parse();
+        if !ret.errors.is_empty() {
+            return Err(ret.errors);
+        }
+        let mut program = ret.program;
+        for plugin in &mut self.plugins {
+            plugin.after_parse(&mut program);
+        }
+        let semantic = SemanticBuilder::new(source_text, self.source_type)
+            .with_trivias(ret.trivias)
+            .build(&program)
+            .semantic;
+        let program = self.allocator.alloc(program);
+        let mut transformer =
+            Transformer::new(self.allocator, self.source_type, semantic, self.options);
+        transformer.plugins = self.plugins;
+        transformer.build(program)?;
+        Ok(program)
+    }
+}
+
+#[test]
+fn test() {
+    use oxc_ast::{ast::*, VisitMut};
+    use oxc_codegen::{Codegen, CodegenOptions};
+    use oxc_span::SPAN;
+
+    /// Removes `invariant(...)` calls.
+    struct StripInvariant;
+
+    impl<'a> VisitMut<'a> for StripInvariant {
+        fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+            stmts.retain(|stmt| match stmt {
+                Statement::ExpressionStatement(stmt) => !matches!(
+                    &stmt.expression,
+                    Expression::CallExpression(call) if call.callee.is_specific_id("invariant")
+                ),
+                _ => true,
+            });
+            for stmt in stmts.iter_mut() {
+                self.visit_statement(stmt);
+            }
+        }
+    }
+
+    impl<'a> TransformerPlugin<'a> for StripInvariant {
+        fn before_transform(&mut self, program: &mut Program<'a>, _ctx: &mut TransformerCtx<'a>) {
+            self.visit_program(program);
+        }
+    }
+
+    /// Prepends a side effect import to the output.
+    struct Banner;
+
+    impl<'a> TransformerPlugin<'a> for Banner {
+        fn after_transform(&mut self, program: &mut Program<'a>, ctx: &mut TransformerCtx<'a>) {
+            let source = StringLiteral::new(SPAN, "./banner".into());
+            let import =
+                ctx.ast.import_declaration(SPAN, None, source, None, ImportOrExportKind::Value);
+            let stmt = ctx.ast.module_declaration(ModuleDeclaration::ImportDeclaration(import));
+            program.body.insert(0, stmt);
+        }
+    }
+
+    let source_text = "function f(x: number) { invariant(x > 0); return x; }";
+    let expected = "import './banner'; function f(x) { return x; }";
+
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path("test.ts").unwrap();
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let semantic = SemanticBuilder::new(source_text, source_type).build(&program).semantic;
+    let program = allocator.alloc(program);
+    Transformer::new(&allocator, source_type, semantic, TransformOptions::default())
+        .with_plugin(StripInvariant)
+        .with_plugin(Banner)
+        .build(program)
+        .unwrap();
+    let printed = Codegen::<false>::new(source_text.len(), CodegenOptions).build(program);
+    let expected = {
+        let program = Parser::new(&allocator, expected, SourceType::default().with_module(true))
+            .parse()
+            .program;
+        Codegen::<false>::new(expected.len(), CodegenOptions).build(&program)
+    };
+    assert_eq!(printed, expected);
+}
+
+#[test]
+fn pipeline() {
+    use oxc_ast::ast::*;
+    use oxc_codegen::{Codegen, CodegenOptions};
+    use oxc_span::SPAN;
+
+    /// Replaces `__VERSION__` before parsing.
+    struct Version;
+
+    impl<'a> TransformerPlugin<'a> for Version {
+        fn before_parse(&mut self, source_text: &str, _source_type: SourceType) -> Option<String> {
+            Some(source_text.replace("__VERSION__", "'1.0'"))
+        }
+    }
+
+    /// Renames the identifier references named `.0` to `.1`.
+    struct Rename(&'static str, &'static str);
+
+    impl<'a> TransformerPlugin<'a> for Rename {
+        fn transform_expression(
+            &mut self,
+            expr: &mut Expression<'a>,
+            _ctx: &mut TransformerCtx<'a>,
+        ) {
+            if let Expression::Identifier(ident) = expr {
+                if ident.name == self.0 {
+                    ident.name = self.1.into();
+                }
+            }
+        }
+    }
+
+    /// Adds `console.log(A)`, which must keep the import of `A`.
+    struct UseImport;
+
+    impl<'a> TransformerPlugin<'a> for UseImport {
+        fn before_transform(&mut self, program: &mut Program<'a>, ctx: &mut TransformerCtx<'a>) {
+            let object = ctx
+                .ast
+                .identifier_reference_expression(IdentifierReference::new(SPAN, "console".into()));
+            let callee = ctx.ast.static_member_expression(
+                SPAN,
+                object,
+                IdentifierName::new(SPAN, "log".into()),
+                false,
+            );
+            let argument =
+                ctx.ast.identifier_reference_expression(IdentifierReference::new(SPAN, "A".into()));
+            let arguments = ctx.ast.new_vec_single(Argument::Expression(argument));
+            let call = ctx.ast.call_expression(SPAN, callee, arguments, false, None);
+            program.body.push(ctx.ast.expression_statement(SPAN, call));
+        }
+    }
+
+    let source_text = "import { A } from 'a'; let x: A = a(__VERSION__);";
+    let expected = "import { A } from 'a'; let x = c('1.0'); console.log(A);";
+
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path("test.ts").unwrap();
+    let program = TransformPipeline::new(&allocator, source_type, TransformOptions::default())
+        .with_plugin(Version)
+        .with_plugin(UseImport)
+        .with_plugin(Rename("a", "b"))
+        .with_plugin(Rename("b", "c"))
+        .build(source_text)
+        .unwrap();
+    let printed = Codegen::<false>::new(source_text.len(), CodegenOptions).build(program);
+    let expected = {
+        let program = Parser::new(&allocator, expected, SourceType::default().with_module(true))
+            .parse()
+            .program;
+        Codegen::<false>::new(expected.len(), CodegenOptions).build(&program)
+    };
+    assert_eq!(printed, expected);
+}
@@ -0,0 +1,207 @@
+mod options;
+
+use std::rc::Rc;
+
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::operator::AssignmentOperator;
+
+pub use self::options::ReactRefreshOptions;
+use crate::context::TransformerCtx;
+
+/// React Refresh (Fast Refresh)
+///
+/// Registers the components declared at the top level of the module with the runtime of React
+/// Refresh, so that the dev server can swap them without losing their state:
+///
+/// ```js
+/// function App() {}
+/// _c = App;
+/// var _c;
+/// $RefreshReg$(_c, "App");
+/// ```
+///
+/// Components are the functions and the arrow functions bound to a capitalized name.
+/// The signatures of the hooks (`$RefreshSig$`) are not generated.
+///
+/// References:
+/// * <https://github.com/facebook/react/tree/main/packages/react-refresh>
+/// * <https://github.com/facebook/react/blob/main/packages/react-refresh/src/ReactFreshBabelPlugin.js>
+pub struct ReactRefresh<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    options: ReactRefreshOptions,
+}
+
+impl<'a> ReactRefresh<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: Option<ReactRefreshOptions>,
+    ) -> Option<Self> {
+        options.map(|options| Self { ast, ctx, options })
+    }
+
+    pub fn transform_program(&mut self, program: &mut Program<'a>) {
+        let mut registrations = vec![];
+        let mut body = self.ast.new_vec_with_capacity(program.body.len());
+        for stmt in self.ast.move_statement_vec(&mut program.body) {
+            let names = component_names(&stmt);
+            body.push(stmt);
+            for name in names {
+                // _c = App;
+                let handle = self.create_handle();
+                let target = self.ast.simple_assignment_target_identifier(
+                    IdentifierReference::new(SPAN, handle.clone()),
+                );
+                let component = self
+                    .ast
+                    .identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()));
+                let assignment = self.ast.assignment_expression(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::SimpleAssignmentTarget(target),
+                    component,
+                );
+                body.push(self.ast.expression_statement(SPAN, assignment));
+                registrations.push((handle, name));
+            }
+        }
+
+        if !registrations.is_empty() {
+            // var _c, _c2;
+            let mut declarators = self.ast.new_vec_with_capacity(registrations.len());
+            for (handle, _) in &registrations {
+                let id = self
+                    .ast
+                    .binding_pattern_identifier(BindingIdentifier::new(SPAN, handle.clone()));
+                let id = self.ast.binding_pattern(id, None, false);
+                declarators.push(self.ast.variable_declarator(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    id,
+                    None,
+                    false,
+                ));
+            }
+            let decl = self.ast.variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarators,
+                Modifiers::empty(),
+            );
+            body.push(Statement::Declaration(Declaration::VariableDeclaration(decl)));
+
+            // $RefreshReg$(_c, "App");
+            let refresh_reg = Atom::from(self.options.refresh_reg.as_ref());
+            for (handle, name) in registrations {
+                let callee = self.ast.identifier_reference_expression(IdentifierReference::new(
+                    SPAN,
+                    refresh_reg.clone(),
+                ));
+                let mut arguments = self.ast.new_vec_with_capacity(2);
+                arguments.push(Argument::Expression(
+                    self.ast
+                        .identifier_reference_expression(IdentifierReference::new(SPAN, handle)),
+                ));
+                arguments.push(Argument::Expression(
+                    self.ast.literal_string_expression(StringLiteral::new(SPAN, name)),
+                ));
+                let call = self.ast.call_expression(SPAN, callee, arguments, false, None);
+                body.push(self.ast.expression_statement(SPAN, call));
+            }
+        }
+        program.body = body;
+    }
+
+    /// `_c`, `_c2`, ... not bound in the module yet
+    fn create_handle(&self) -> Atom {
+        let scopes = self.ctx.scopes();
+        let root_scope_id = scopes.root_scope_id();
+        let name = (1..)
+            .map(|i| Atom::from(if i > 1 { format!("_c{i}") } else { "_c".to_string() }))
+            .find(|name| !scopes.has_binding(root_scope_id, name))
+            .unwrap();
+        drop(scopes);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+}
+
+/// Names of the components declared by a top level statement
+fn component_names(stmt: &Statement) -> Vec<Atom> {
+    match stmt {
+        Statement::Declaration(decl) => declaration_component_names(decl),
+        Statement::ModuleDeclaration(decl) => match &**decl {
+            ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                decl.declaration.as_ref().map_or(vec![], declaration_component_names)
+            }
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                    function_component_name(func).into_iter().collect()
+                }
+                _ => vec![],
+            },
+            _ => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+fn declaration_component_names(decl: &Declaration) -> Vec<Atom> {
+    match decl {
+        Declaration::FunctionDeclaration(func) => {
+            function_component_name(func).into_iter().collect()
+        }
+        // `const App = () => {}`, `let App = function () {}`
+        Declaration::VariableDeclaration(decl) => decl
+            .declarations
+            .iter()
+            .filter(|declarator| declarator.init.as_ref().is_some_and(Expression::is_function))
+            .filter_map(|declarator| match &declarator.id.kind {
+                BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.clone()),
+                _ => None,
+            })
+            .filter(is_componentish_name)
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn function_component_name(func: &Function) -> Option<Atom> {
+    func.id.as_ref().map(|ident| ident.name.clone()).filter(is_componentish_name)
+}
+
+/// Components are capitalized, unlike hooks and other functions
+fn is_componentish_name(name: &Atom) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+#[test]
+fn test() {
+    use crate::{options::TransformOptions, tester::Tester};
+
+    let options = TransformOptions {
+        react_refresh: Some(ReactRefreshOptions::default()),
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        (
+            "function App() {} const Button = () => {}; function useHook() {}",
+            "function App() {} _c = App; const Button = () => {}; _c2 = Button; function useHook() {} var _c, _c2; $RefreshReg$(_c, \"App\"); $RefreshReg$(_c2, \"Button\");",
+        ),
+        (
+            "export default function Page() {} export const Nav = function () {}; const value = 1;",
+            "export default function Page() {} _c = Page; export const Nav = function () {}; _c2 = Nav; const value = 1; var _c, _c2; $RefreshReg$(_c, \"Page\"); $RefreshReg$(_c2, \"Nav\");",
+        ),
+        // the handles do not shadow the bindings of the module
+        (
+            "let _c = 0; function App() {}",
+            "let _c = 0; function App() {} _c2 = App; var _c2; $RefreshReg$(_c2, \"App\");",
+        ),
+        ("function foo() {}", "function foo() {}"),
+    ];
+
+    Tester::new("test.jsx", options).test(tests);
+}
@@ -0,0 +1,22 @@
+use std::borrow::Cow;
+
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactRefreshOptions {
+    /// Function called to register each component with the runtime of React Refresh.
+    /// default to `$RefreshReg$`
+    #[serde(default = "default_refresh_reg")]
+    pub refresh_reg: Cow<'static, str>,
+}
+
+fn default_refresh_reg() -> Cow<'static, str> {
+    Cow::Borrowed("$RefreshReg$")
+}
+
+impl Default for ReactRefreshOptions {
+    fn default() -> Self {
+        Self { refresh_reg: default_refresh_reg() }
+    }
+}
@@ -29,6 +29,13 @@ impl Tester {
         }
     }
 
+    /// The transform of each source text reports an error.
+    pub fn test_errors(&self, tests: &[&str]) {
+        for source_text in tests {
+            assert!(self.transform(source_text).is_err(), "{source_text}");
+        }
+    }
+
     fn transform(&self, source_text: &str) -> Result<std::string::String, std::vec::Vec<Error>> {
         let program = Parser::new(&self.allocator, source_text, self.source_type).parse().program;
         let semantic = SemanticBuilder::new(source_text, self.source_type).build(&program).semantic;
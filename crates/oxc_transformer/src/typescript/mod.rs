@@ -395,3 +395,25 @@ impl<'a> TypeScript<'a> {
         statements
     }
 }
+
+#[test]
+fn test() {
+    use crate::{tester::Tester, TransformOptions};
+
+    let tests = &[
+        // imports only used as types are removed
+        ("import A from 'a'; let a: A;", "let a: A;"),
+        ("import A from 'a'; let a: A.B.C;", "let a: A.B.C;"),
+        ("import A from 'a'; let a: typeof A;", "let a: typeof A;"),
+        ("import A from 'a'; class B implements A.C {}", "class B implements A.C {}"),
+        ("import A from 'a'; interface B extends A.C {}", "interface B extends A.C {}"),
+        // imports used as values are kept
+        ("import A from 'a'; let a = A;", "import A from 'a'; let a = A;"),
+        ("import A from 'a'; class B extends A.C {}", "import A from 'a'; class B extends A.C {}"),
+        (
+            "import A from 'a'; class B extends A implements A {}",
+            "import A from 'a'; class B extends A implements A {}",
+        ),
+    ];
+    Tester::new("test.ts", TransformOptions::default()).test(tests);
+}
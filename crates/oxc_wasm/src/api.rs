@@ -0,0 +1,297 @@
+//! Stateless functions taking JSON options, one per subsystem.
+//!
+//! Unlike [crate::Oxc], which keeps the editor state of the playground, each function parses the
+//! source text on its own, so issues can be reported with just the source text, the options and
+//! the `version()` of the build.
+
+use std::{path::PathBuf, rc::Rc};
+
+use oxc::{
+    allocator::Allocator,
+    codegen::{Codegen, CodegenOptions},
+    diagnostics::Error,
+    formatter::{Formatter, FormatterOptions},
+    minifier::{CompressOptions, Minifier, MinifierOptions},
+    parser::{Parser, ParserReturn},
+    semantic::SemanticBuilder,
+    span::SourceType,
+    transformer::{TransformOptions, TransformTarget, Transformer},
+};
+use oxc_linter::{AllowWarnDeny, LintContext, LintOptions, LintSettings, Linter};
+use oxc_prettier::{Prettier, PrettierOptions};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::OxcDiagnostic;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct ParseOptions {
+    /// Used to infer the source type, `test.tsx` by default
+    source_filename: Option<String>,
+    allow_return_outside_function: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct LintApiOptions {
+    #[serde(flatten)]
+    parse: ParseOptions,
+    /// `[["deny", "correctness"], ["allow", "no-debugger"]]`, applied in order like `-D` and `-A`
+    filter: Vec<(String, String)>,
+}
+
+impl Default for LintApiOptions {
+    fn default() -> Self {
+        Self {
+            parse: ParseOptions::default(),
+            filter: vec![("deny".to_string(), "correctness".to_string())],
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct TransformApiOptions {
+    #[serde(flatten)]
+    parse: ParseOptions,
+    /// `es5`, `es2015` to `es2024` or `esnext`
+    target: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct FormatApiOptions {
+    #[serde(flatten)]
+    parse: ParseOptions,
+    /// Format with the prettier port instead of the formatter
+    prettier: bool,
+    indentation: Option<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct MinifyApiOptions {
+    #[serde(flatten)]
+    parse: ParseOptions,
+    mangle: bool,
+    compress: bool,
+    whitespace: bool,
+}
+
+impl Default for MinifyApiOptions {
+    fn default() -> Self {
+        Self { parse: ParseOptions::default(), mangle: true, compress: true, whitespace: true }
+    }
+}
+
+#[derive(Serialize)]
+struct ParseResult {
+    program: serde_json::Value,
+    errors: Vec<OxcDiagnostic>,
+}
+
+#[derive(Serialize)]
+struct CodeResult {
+    code: String,
+    errors: Vec<OxcDiagnostic>,
+}
+
+/// Version of the crate the WASM module was built from.
+#[wasm_bindgen]
+pub fn version() -> String {
+    env!("CARGO_PKG_VERSION").to_string()
+}
+
+/// Returns `{ program, errors }` with the AST in JSON.
+///
+/// # Errors
+/// Invalid options
+#[wasm_bindgen]
+pub fn parse(source_text: &str, options: JsValue) -> Result<JsValue, JsError> {
+    let options: ParseOptions = from_js(options)?;
+    let allocator = Allocator::default();
+    let ret = parse_source(&allocator, source_text, &options)?;
+    let program = serde_json::to_value(&ret.program)?;
+    to_js(&ParseResult { program, errors: to_diagnostics(&ret.errors) })
+}
+
+/// Returns the diagnostics of the parser, the semantic checks and the enabled lint rules.
+///
+/// # Errors
+/// Invalid options
+#[wasm_bindgen]
+pub fn lint(source_text: &str, options: JsValue) -> Result<JsValue, JsError> {
+    let options: LintApiOptions = from_js(options)?;
+    let filter = options
+        .filter
+        .iter()
+        .map(|(severity, name)| {
+            AllowWarnDeny::try_from(severity.as_str())
+                .map(|severity| (severity, name.clone()))
+                .map_err(|err| JsError::new(&err.to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let linter = Linter::from_options(LintOptions::default().with_filter(filter))
+        .map_err(|err| JsError::new(&err.to_string()))?;
+
+    let allocator = Allocator::default();
+    let ret = parse_source(&allocator, source_text, &options.parse)?;
+    let mut errors = ret.errors;
+    let program = allocator.alloc(ret.program);
+    let semantic_ret = SemanticBuilder::new(source_text, source_type(&options.parse)?)
+        .with_trivias(ret.trivias)
+        .with_check_syntax_error(true)
+        .build(program);
+    errors.extend(semantic_ret.errors);
+
+    let semantic = Rc::new(semantic_ret.semantic);
+    let path = PathBuf::from(file_name(&options.parse)).into_boxed_path();
+    let ctx = LintContext::new(path, &semantic, LintSettings::default());
+    errors.extend(linter.run(ctx).into_iter().map(|message| message.error));
+    to_js(&to_diagnostics(&errors))
+}
+
+/// Returns `{ code, errors }` with TypeScript and JSX removed and syntax lowered to `target`.
+///
+/// # Errors
+/// Invalid options
+#[wasm_bindgen]
+pub fn transform(source_text: &str, options: JsValue) -> Result<JsValue, JsError> {
+    let options: TransformApiOptions = from_js(options)?;
+    let target = match &options.target {
+        Some(target) => target.parse().map_err(|err: String| JsError::new(&err))?,
+        None => TransformTarget::ESNext,
+    };
+
+    let allocator = Allocator::default();
+    let source_type = source_type(&options.parse)?;
+    let ret = parse_source(&allocator, source_text, &options.parse)?;
+    if !ret.errors.is_empty() {
+        return to_js(&CodeResult { code: String::new(), errors: to_diagnostics(&ret.errors) });
+    }
+    let program = allocator.alloc(ret.program);
+    let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+    let transform_options = TransformOptions { target, ..TransformOptions::default() };
+    let errors = Transformer::new(&allocator, source_type, semantic, transform_options)
+        .build(program)
+        .err()
+        .unwrap_or_default();
+    let code = Codegen::<false>::new(source_text.len(), CodegenOptions).build(program);
+    to_js(&CodeResult { code, errors: to_diagnostics(&errors) })
+}
+
+/// Returns `{ code, errors }`, the code is empty when there are syntax errors.
+///
+/// # Errors
+/// Invalid options
+#[wasm_bindgen]
+pub fn format(source_text: &str, options: JsValue) -> Result<JsValue, JsError> {
+    let options: FormatApiOptions = from_js(options)?;
+    let allocator = Allocator::default();
+    let source_type = source_type(&options.parse)?;
+    let ret = Parser::new(&allocator, source_text, source_type)
+        .allow_return_outside_function(options.parse.allow_return_outside_function)
+        .preserve_parens(!options.prettier)
+        .parse();
+    if !ret.errors.is_empty() {
+        return to_js(&CodeResult { code: String::new(), errors: to_diagnostics(&ret.errors) });
+    }
+    let code = if options.prettier {
+        Prettier::new(&allocator, source_text, ret.trivias, PrettierOptions::default())
+            .build(&ret.program)
+    } else {
+        let formatter_options = FormatterOptions {
+            indentation: options.indentation.unwrap_or(FormatterOptions::default().indentation),
+            ..FormatterOptions::default()
+        };
+        Formatter::new(source_text.len(), formatter_options).build(&ret.program)
+    };
+    to_js(&CodeResult { code, errors: vec![] })
+}
+
+/// Returns `{ code, errors }`, the code is empty when there are syntax errors.
+///
+/// # Errors
+/// Invalid options
+#[wasm_bindgen]
+pub fn minify(source_text: &str, options: JsValue) -> Result<JsValue, JsError> {
+    let options: MinifyApiOptions = from_js(options)?;
+    let allocator = Allocator::default();
+    let ret = parse_source(&allocator, source_text, &options.parse)?;
+    if !ret.errors.is_empty() {
+        return to_js(&CodeResult { code: String::new(), errors: to_diagnostics(&ret.errors) });
+    }
+    let program = allocator.alloc(ret.program);
+    let minifier_options = MinifierOptions {
+        mangle: options.mangle,
+        compress: if options.compress {
+            CompressOptions::all_true()
+        } else {
+            CompressOptions::all_false()
+        },
+        ..MinifierOptions::default()
+    };
+    Minifier::new(minifier_options).build(&allocator, program);
+    let code = if options.whitespace {
+        Codegen::<true>::new(source_text.len(), CodegenOptions).build(program)
+    } else {
+        Codegen::<false>::new(source_text.len(), CodegenOptions).build(program)
+    };
+    to_js(&CodeResult { code, errors: vec![] })
+}
+
+fn file_name(options: &ParseOptions) -> &str {
+    options.source_filename.as_deref().unwrap_or("test.tsx")
+}
+
+fn source_type(options: &ParseOptions) -> Result<SourceType, JsError> {
+    let file_name = file_name(options);
+    SourceType::from_path(file_name)
+        .map_err(|_| JsError::new(&format!("Unknown file extension of {file_name}")))
+}
+
+fn parse_source<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    options: &ParseOptions,
+) -> Result<ParserReturn<'a>, JsError> {
+    Ok(Parser::new(allocator, source_text, source_type(options)?)
+        .allow_return_outside_function(options.allow_return_outside_function)
+        .parse())
+}
+
+/// `undefined` and `null` options use the defaults.
+fn from_js<T: DeserializeOwned + Default>(options: JsValue) -> Result<T, JsError> {
+    if options.is_undefined() || options.is_null() {
+        return Ok(T::default());
+    }
+    serde_wasm_bindgen::from_value(options).map_err(|err| JsError::new(&err.to_string()))
+}
+
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsError> {
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    value.serialize(&serializer).map_err(|err| JsError::new(&err.to_string()))
+}
+
+/// One diagnostic per label, errors without labels are reported at the start of the file.
+fn to_diagnostics(errors: &[Error]) -> Vec<OxcDiagnostic> {
+    errors
+        .iter()
+        .flat_map(|error| {
+            let severity = format!("{:?}", error.severity().unwrap_or_default());
+            let message = format!("{error}");
+            let spans = error
+                .labels()
+                .map(|labels| labels.map(|label| (label.offset(), label.len())).collect())
+                .filter(|spans: &Vec<_>| !spans.is_empty())
+                .unwrap_or_else(|| vec![(0, 0)]);
+            spans.into_iter().map(move |(offset, len)| OxcDiagnostic {
+                start: offset,
+                end: offset + len,
+                severity: severity.clone(),
+                message: message.clone(),
+            })
+        })
+        .collect()
+}
@@ -1,3 +1,4 @@
+mod api;
 mod options;
 
 use std::{cell::RefCell, collections::BTreeMap, path::PathBuf, rc::Rc, sync::Arc};
@@ -289,6 +290,7 @@ impl Oxc {
                 } else {
                     CompressOptions::all_false()
                 },
+                ..MinifierOptions::default()
             };
             Minifier::new(options).build(&allocator, program);
         }
@@ -33,6 +33,26 @@ const enum OxcCommands {
 
 let client: LanguageClient;
 
+// The settings of the server, with the globs of `files.exclude` and `search.exclude`
+// so that the files hidden from the explorer and the search are not linted either.
+function getSettings(): any {
+  let settings: any = JSON.parse(
+    JSON.stringify(workspace.getConfiguration("oxc_language_server")),
+  );
+  let exclude = new Set<string>();
+  for (let section of ["files.exclude", "search.exclude"]) {
+    let globs = workspace.getConfiguration().get<Record<string, unknown>>(section) ?? {};
+    for (let [glob, enabled] of Object.entries(globs)) {
+      // `{ "when": ... }` conditions depend on sibling files, they are not forwarded
+      if (enabled === true) {
+        exclude.add(glob);
+      }
+    }
+  }
+  settings.exclude = [...exclude];
+  return settings;
+}
+
 let myStatusBarItem: StatusBarItem;
 
 export async function activate(context: ExtensionContext) {
@@ -116,9 +136,7 @@ export async function activate(context: ExtensionContext) {
   // If the extension is launched in debug mode then the debug server options are used
   // Otherwise the run options are used
   // Options to control the language client
-  let clientConfig: any = JSON.parse(
-    JSON.stringify(workspace.getConfiguration("oxc_language_server")),
-  );
+  let clientConfig: any = getSettings();
   let clientOptions: LanguageClientOptions = {
     // Register the server for plain text documents
     documentSelector: [
@@ -150,13 +168,14 @@ export async function activate(context: ExtensionContext) {
     clientOptions,
   );
   workspace.onDidChangeConfiguration((e) => {
-    let isAffected = e.affectsConfiguration("oxc_language_server");
+    let isAffected =
+      e.affectsConfiguration("oxc_language_server") ||
+      e.affectsConfiguration("files.exclude") ||
+      e.affectsConfiguration("search.exclude");
     if (!isAffected) {
       return;
     }
-    let settings: any = JSON.parse(
-      JSON.stringify(workspace.getConfiguration("oxc_language_server")),
-    );
+    let settings: any = getSettings();
     updateStatsBar(settings.enable);
     client.sendNotification("workspace/didChangeConfiguration", { settings });
   });
@@ -13,9 +13,67 @@ export interface ParserOptions {
   sourceFilename?: string
 }
 export interface ParseResult {
+  /** ESTree compatible AST serialized as JSON */
   program: string
+  comments: Array<Comment>
+  module: ModuleInfo
   errors: Array<string>
 }
+/**
+ * Result of `parseSyncRaw`, the AST is not serialized as JSON but handed over as a binary buffer
+ * which can be read lazily, see `raw.js`.
+ */
+export interface RawParseResult {
+  /** AST in flexbuffers format */
+  buffer: Buffer
+  comments: Array<Comment>
+  module: ModuleInfo
+  errors: Array<string>
+}
+/** ESTree comment, `start` and `end` include the comment delimiters. */
+export interface Comment {
+  type: 'Line' | 'Block'
+  /** Text between the delimiters */
+  value: string
+  start: number
+  end: number
+}
+/**
+ * Static import and export information of a module, derived from its module record.
+ *
+ * Names are `default` for default imports and exports, `*` for namespace imports and
+ * `export * as ns from` and `*` without an exported name for `export * from`.
+ */
+export interface ModuleInfo {
+  /** Specifiers of all `import` and `export ... from` declarations, in source order */
+  requestedModules: Array<RequestedModule>
+  imports: Array<ImportInfo>
+  exports: Array<ExportInfo>
+}
+export interface RequestedModule {
+  source: string
+  /** Start of the first occurrence of the specifier string */
+  start: number
+  end: number
+}
+export interface ImportInfo {
+  source: string
+  imported: string
+  local: string
+  /** Span of the local binding */
+  start: number
+  end: number
+}
+export interface ExportInfo {
+  /** Missing for exports of local declarations */
+  source?: string
+  imported?: string
+  exported?: string
+  local?: string
+  /** Span of the export declaration */
+  start: number
+  end: number
+}
 /**
  * Parse without returning anything.
  * This is for benchmark purposes such as measuring napi communication overhead.
@@ -38,6 +96,19 @@ export function parseSync(sourceText: string, options?: ParserOptions | undefine
  * This is a POC API. Error handling is not done yet.
  */
 export function parseSyncBuffer(sourceText: string, options?: ParserOptions | undefined | null): Buffer
+/**
+ * Returns the AST in flexbuffers format together with comments, module information and errors.
+ *
+ * The buffer is moved to JavaScript without copying, and nodes are only decoded when they are
+ * accessed through the lazy accessors in `raw.js`. This avoids building and parsing a JSON string
+ * for callers which only look at a few nodes.
+ *
+ * # Panics
+ *
+ * * File extension is invalid
+ * * FlexbufferSerializer serialization error
+ */
+export function parseSyncRaw(sourceText: string, options?: ParserOptions | undefined | null): RawParseResult
 /**
  * # Panics
  *
@@ -252,9 +252,10 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { parseWithoutReturn, parseSync, parseSyncBuffer, parseAsync } = nativeBinding
+const { parseWithoutReturn, parseSync, parseSyncBuffer, parseSyncRaw, parseAsync } = nativeBinding
 
 module.exports.parseWithoutReturn = parseWithoutReturn
 module.exports.parseSync = parseSync
 module.exports.parseSyncBuffer = parseSyncBuffer
+module.exports.parseSyncRaw = parseSyncRaw
 module.exports.parseAsync = parseAsync
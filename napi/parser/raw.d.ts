@@ -0,0 +1,5 @@
+/**
+ * Wrap the `buffer` of the result of `parseSyncRaw` into an object shaped like the program
+ * returned by `parseSync`, nodes are decoded from the buffer when their properties are read.
+ */
+export function deserializeLazy(buffer: Buffer | ArrayBuffer): any
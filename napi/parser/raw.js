@@ -0,0 +1,112 @@
+// Lazy accessors for the flexbuffers AST returned by `parseSyncRaw`.
+//
+// `deserializeLazy(buffer)` returns an object shaped like the ESTree program returned by
+// `parseSync`, but nodes are only decoded from the buffer when their properties are read.
+// This avoids creating and parsing a JSON string when only a part of the AST is inspected.
+
+const { Reference } = require('flatbuffers/js/flexbuffers/reference')
+const { keyForIndex } = require('flatbuffers/js/flexbuffers/reference-util')
+const { fromByteWidth } = require('flatbuffers/js/flexbuffers/bit-width-util')
+
+// `flexbuffers.toReference` only accepts an `ArrayBuffer`, a `Buffer` is usually a view into a
+// larger pooled one. The root reference is read from a view of the buffer instead of a copy.
+function toReference(buffer) {
+  const dataView = buffer instanceof ArrayBuffer
+    ? new DataView(buffer)
+    : new DataView(buffer.buffer, buffer.byteOffset, buffer.byteLength)
+  const length = dataView.byteLength
+  if (length < 3) {
+    throw new Error('Buffer needs to be bigger than 3')
+  }
+  const byteWidth = dataView.getUint8(length - 1)
+  const packedType = dataView.getUint8(length - 2)
+  return new Reference(dataView, length - byteWidth - 2, fromByteWidth(byteWidth), packedType, '/')
+}
+
+// The keys of a map are read from its keys vector, without decoding the values.
+function mapKeys(ref) {
+  const length = ref.length()
+  const keys = new Array(length)
+  for (let i = 0; i < length; i++) {
+    keys[i] = keyForIndex(i, ref.dataView, ref.offset, ref.parentWidth, ref.byteWidth)
+  }
+  return keys
+}
+
+function wrap(ref) {
+  if (ref.isNull()) {
+    return null
+  }
+  if (ref.isBool()) {
+    return ref.boolValue()
+  }
+  if (ref.isNumber()) {
+    return ref.numericValue()
+  }
+  if (ref.isString()) {
+    return ref.stringValue()
+  }
+  if (ref.isVector()) {
+    // Elements are wrapped without decoding them
+    const length = ref.length()
+    const array = new Array(length)
+    for (let i = 0; i < length; i++) {
+      array[i] = wrap(ref.get(i))
+    }
+    return array
+  }
+  if (ref.isMap()) {
+    return lazyObject(ref)
+  }
+  return ref.toObject()
+}
+
+function lazyObject(ref) {
+  const cache = new Map()
+  let keys = null
+  let keySet = null
+  const getKeys = () => (keys ??= mapKeys(ref))
+  const has = (key) => typeof key === 'string' && (keySet ??= new Set(getKeys())).has(key)
+  return new Proxy({}, {
+    get(target, key) {
+      if (typeof key !== 'string') {
+        return target[key]
+      }
+      if (key === 'toJSON') {
+        return () => ref.toObject()
+      }
+      if (!cache.has(key)) {
+        let value
+        try {
+          value = wrap(ref.get(key))
+        } catch {
+          // Not a property of this node
+          value = undefined
+        }
+        cache.set(key, value)
+      }
+      return cache.get(key)
+    },
+    has(_target, key) {
+      return has(key)
+    },
+    ownKeys() {
+      return getKeys()
+    },
+    getOwnPropertyDescriptor(target, key) {
+      if (!has(key)) {
+        return undefined
+      }
+      return { value: this.get(target, key), enumerable: true, configurable: true, writable: false }
+    },
+  })
+}
+
+/**
+ * @param {Buffer | ArrayBuffer} buffer `buffer` of the result of `parseSyncRaw`
+ */
+function deserializeLazy(buffer) {
+  return wrap(toReference(buffer))
+}
+
+module.exports.deserializeLazy = deserializeLazy
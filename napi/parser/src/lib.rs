@@ -1,6 +1,8 @@
 #![allow(clippy::trailing_empty_array)]
 
-use std::sync::Arc;
+mod module;
+
+use std::{path::PathBuf, sync::Arc};
 
 use flexbuffers::FlexbufferSerializer;
 use miette::NamedSource;
@@ -8,10 +10,14 @@ use napi::bindgen_prelude::Buffer;
 use napi_derive::napi;
 use oxc_allocator::Allocator;
 pub use oxc_ast::ast::Program;
+use oxc_ast::CommentKind;
 use oxc_parser::{Parser, ParserReturn};
+use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
 use serde::Serialize;
 
+pub use crate::module::{ExportInfo, ImportInfo, ModuleInfo, RequestedModule};
+
 /// Babel Parser Options
 ///
 /// <https://github.com/babel/babel/blob/main/packages/babel-parser/typings/babel-parser.d.ts>
@@ -25,26 +31,108 @@ pub struct ParserOptions {
 
 #[napi(object)]
 pub struct ParseResult {
+    /// ESTree compatible AST serialized as JSON
     pub program: String,
+    pub comments: Vec<Comment>,
+    pub module: ModuleInfo,
     pub errors: Vec<String>,
 }
 
-fn parse<'a>(
-    allocator: &'a Allocator,
-    source_text: &'a str,
-    options: &ParserOptions,
-) -> ParserReturn<'a> {
+/// Result of `parseSyncRaw`, the AST is not serialized as JSON but handed over as a binary buffer
+/// which can be read lazily, see `raw.js`.
+#[napi(object)]
+pub struct RawParseResult {
+    /// AST in flexbuffers format
+    pub buffer: Buffer,
+    pub comments: Vec<Comment>,
+    pub module: ModuleInfo,
+    pub errors: Vec<String>,
+}
+
+/// ESTree comment, `start` and `end` include the comment delimiters.
+#[napi(object)]
+pub struct Comment {
+    #[napi(js_name = "type", ts_type = "'Line' | 'Block'")]
+    pub kind: String,
+    /// Text between the delimiters
+    pub value: String,
+    pub start: u32,
+    pub end: u32,
+}
+
+fn source_type(options: &ParserOptions) -> SourceType {
     let source_type = options
         .source_filename
         .as_ref()
         .map(|name| SourceType::from_path(name).unwrap())
         .unwrap_or_default();
-    let source_type = match options.source_type.as_deref() {
+    match options.source_type.as_deref() {
         Some("script") => source_type.with_script(true),
         Some("module") => source_type.with_module(true),
         _ => source_type,
+    }
+}
+
+fn parse<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    options: &ParserOptions,
+) -> ParserReturn<'a> {
+    Parser::new(allocator, source_text, source_type(options)).parse()
+}
+
+/// Everything returned next to the AST.
+struct ParseOutput<T> {
+    program: T,
+    comments: Vec<Comment>,
+    module: ModuleInfo,
+    errors: Vec<String>,
+}
+
+/// Parse `source_text` and hand the program to `serialize`.
+fn parse_with<T, F>(source_text: &str, options: &ParserOptions, serialize: F) -> ParseOutput<T>
+where
+    F: FnOnce(&Program) -> T,
+{
+    let allocator = Allocator::default();
+    let ret = parse(&allocator, source_text, options);
+    let program = &*allocator.alloc(ret.program);
+
+    let comments = ret
+        .trivias
+        .comments
+        .iter()
+        .map(|&(start, end, kind)| {
+            let value = source_text[start as usize..end as usize].to_string();
+            // Trivia spans exclude the `//`, `/*` and `*/` delimiters
+            match kind {
+                CommentKind::SingleLine => {
+                    Comment { kind: "Line".to_string(), value, start: start - 2, end }
+                }
+                CommentKind::MultiLine => {
+                    Comment { kind: "Block".to_string(), value, start: start - 2, end: end + 2 }
+                }
+            }
+        })
+        .collect();
+
+    let file_name = options.source_filename.clone().unwrap_or_default();
+    let semantic = SemanticBuilder::new(source_text, source_type(options))
+        .build_module_record(PathBuf::from(&file_name), program);
+    let module = ModuleInfo::from(semantic.module_record().as_ref());
+
+    let errors = if ret.errors.is_empty() {
+        vec![]
+    } else {
+        let source = Arc::new(NamedSource::new(file_name, source_text.to_string()));
+        ret.errors
+            .into_iter()
+            .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
+            .map(|error| format!("{error:?}"))
+            .collect()
     };
-    Parser::new(allocator, source_text, source_type).parse()
+
+    ParseOutput { program: serialize(program), comments, module, errors }
 }
 
 /// Parse without returning anything.
@@ -70,24 +158,13 @@ pub fn parse_without_return(source_text: String, options: Option<ParserOptions>)
 #[napi]
 pub fn parse_sync(source_text: String, options: Option<ParserOptions>) -> ParseResult {
     let options = options.unwrap_or_default();
-
-    let allocator = Allocator::default();
-    let ret = parse(&allocator, &source_text, &options);
-    let program = serde_json::to_string(&ret.program).unwrap();
-
-    let errors = if ret.errors.is_empty() {
-        vec![]
-    } else {
-        let file_name = options.source_filename.unwrap_or_default();
-        let source = Arc::new(NamedSource::new(file_name, source_text.to_string()));
-        ret.errors
-            .into_iter()
-            .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
-            .map(|error| format!("{error:?}"))
-            .collect()
-    };
-
-    ParseResult { program, errors }
+    let ret = parse_with(&source_text, &options, |program| serde_json::to_string(program).unwrap());
+    ParseResult {
+        program: ret.program,
+        comments: ret.comments,
+        module: ret.module,
+        errors: ret.errors,
+    }
 }
 
 /// Returns a binary AST in flexbuffers format.
@@ -107,6 +184,33 @@ pub fn parse_sync_buffer(source_text: String, options: Option<ParserOptions>) ->
     serializer.take_buffer().into()
 }
 
+/// Returns the AST in flexbuffers format together with comments, module information and errors.
+///
+/// The buffer is moved to JavaScript without copying, and nodes are only decoded when they are
+/// accessed through the lazy accessors in `raw.js`. This avoids building and parsing a JSON string
+/// for callers which only look at a few nodes.
+///
+/// # Panics
+///
+/// * File extension is invalid
+/// * FlexbufferSerializer serialization error
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub fn parse_sync_raw(source_text: String, options: Option<ParserOptions>) -> RawParseResult {
+    let options = options.unwrap_or_default();
+    let ret = parse_with(&source_text, &options, |program| {
+        let mut serializer = FlexbufferSerializer::new();
+        program.serialize(&mut serializer).unwrap();
+        serializer.take_buffer()
+    });
+    RawParseResult {
+        buffer: ret.program.into(),
+        comments: ret.comments,
+        module: ret.module,
+        errors: ret.errors,
+    }
+}
+
 /// # Panics
 ///
 /// * Tokio crashes
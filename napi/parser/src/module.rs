@@ -0,0 +1,111 @@
+use napi_derive::napi;
+use oxc_syntax::module_record::{
+    ExportExportName, ExportImportName, ExportLocalName, ImportImportName, ModuleRecord,
+};
+
+/// Static import and export information of a module, derived from its module record.
+///
+/// Names are `default` for default imports and exports, `*` for namespace imports and
+/// `export * as ns from` and `*` without an exported name for `export * from`.
+#[napi(object)]
+pub struct ModuleInfo {
+    /// Specifiers of all `import` and `export ... from` declarations, in source order
+    pub requested_modules: Vec<RequestedModule>,
+    pub imports: Vec<ImportInfo>,
+    pub exports: Vec<ExportInfo>,
+}
+
+#[napi(object)]
+pub struct RequestedModule {
+    pub source: String,
+    /// Start of the first occurrence of the specifier string
+    pub start: u32,
+    pub end: u32,
+}
+
+#[napi(object)]
+pub struct ImportInfo {
+    pub source: String,
+    pub imported: String,
+    pub local: String,
+    /// Span of the local binding
+    pub start: u32,
+    pub end: u32,
+}
+
+#[napi(object)]
+pub struct ExportInfo {
+    /// Missing for exports of local declarations
+    pub source: Option<String>,
+    pub imported: Option<String>,
+    pub exported: Option<String>,
+    pub local: Option<String>,
+    /// Span of the export declaration
+    pub start: u32,
+    pub end: u32,
+}
+
+impl From<&ModuleRecord> for ModuleInfo {
+    fn from(record: &ModuleRecord) -> Self {
+        let requested_modules = record
+            .requested_modules
+            .iter()
+            .filter_map(|(source, spans)| {
+                let span = spans.first()?;
+                Some(RequestedModule {
+                    source: source.to_string(),
+                    start: span.start,
+                    end: span.end,
+                })
+            })
+            .collect();
+
+        let imports = record
+            .import_entries
+            .iter()
+            .map(|entry| ImportInfo {
+                source: entry.module_request.name().to_string(),
+                imported: match &entry.import_name {
+                    ImportImportName::Name(name) => name.name().to_string(),
+                    ImportImportName::NamespaceObject => "*".to_string(),
+                    ImportImportName::Default(_) => "default".to_string(),
+                },
+                local: entry.local_name.name().to_string(),
+                start: entry.local_name.span().start,
+                end: entry.local_name.span().end,
+            })
+            .collect();
+
+        let mut exports = record
+            .local_export_entries
+            .iter()
+            .chain(&record.indirect_export_entries)
+            .chain(&record.star_export_entries)
+            .map(|entry| ExportInfo {
+                source: entry.module_request.as_ref().map(|name| name.name().to_string()),
+                imported: match &entry.import_name {
+                    ExportImportName::Name(name) => Some(name.name().to_string()),
+                    ExportImportName::All | ExportImportName::AllButDefault => {
+                        Some("*".to_string())
+                    }
+                    ExportImportName::Null => None,
+                },
+                exported: match &entry.export_name {
+                    ExportExportName::Name(name) => Some(name.name().to_string()),
+                    ExportExportName::Default(_) => Some("default".to_string()),
+                    ExportExportName::Null => None,
+                },
+                local: match &entry.local_name {
+                    ExportLocalName::Name(name) => Some(name.name().to_string()),
+                    ExportLocalName::Default(_) => Some("default".to_string()),
+                    ExportLocalName::Null => None,
+                },
+                start: entry.span.start,
+                end: entry.span.end,
+            })
+            .collect::<Vec<_>>();
+        exports.sort_by_key(|export| export.start);
+
+        Self { requested_modules, imports, exports }
+    }
+}
@@ -1,4 +1,5 @@
 import oxc from './index.js';
+import raw from './raw.js';
 import assert from 'assert';
 
 console.log(`Testing on ${process.platform}-${process.arch}`)
@@ -12,6 +13,41 @@ function test(ret) {
 
 test(oxc.parseSync("foo"));
 
+function testComments() {
+  const ret = oxc.parseSync("// a\n/* b */ foo");
+  assert.deepEqual(ret.comments, [
+    { type: 'Line', value: ' a', start: 0, end: 4 },
+    { type: 'Block', value: ' b ', start: 5, end: 12 },
+  ]);
+}
+
+function testModule() {
+  const source = "import a, { b as c } from 'x'; import 'y'; export { d } from 'z'; export default 1;";
+  const { module } = oxc.parseSync(source, { sourceFilename: 'test.mjs' });
+  assert.deepEqual(module.requestedModules.map((m) => m.source), ['x', 'y', 'z']);
+  assert.deepEqual(module.imports.map((i) => [i.imported, i.local]), [['default', 'a'], ['b', 'c']]);
+  assert.deepEqual(module.exports.map((e) => [e.source, e.exported]), [['z', 'd'], [undefined, 'default']]);
+}
+
+function testRaw() {
+  const ret = oxc.parseSyncRaw("foo; // bar");
+  assert(ret.errors.length == 0);
+  assert.equal(ret.comments.length, 1);
+  const program = raw.deserializeLazy(ret.buffer);
+  assert.equal(program.type, 'Program');
+  assert.equal(program.body.length, 1);
+  assert.equal(program.body[0].expression.name, 'foo');
+  assert(Object.keys(program.body[0]).includes('expression'));
+  assert('type' in program.body[0] && !('foo' in program.body[0]));
+  // A view into a larger buffer is read in place
+  const view = Buffer.concat([Buffer.alloc(8), ret.buffer]).subarray(8);
+  assert.equal(raw.deserializeLazy(view).body[0].expression.name, 'foo');
+}
+
+testComments();
+testModule();
+testRaw();
+
 async function main() {
   test(await oxc.parseAsync("foo"));
 }
@@ -88,7 +88,7 @@ function writeManifest() {
   const content = JSON.stringify(manifestData);
   fs.writeFileSync(manifestPath, content);
 
-  let files = ["index.js", "index.d.ts"];
+  let files = ["index.js", "index.d.ts", "raw.js", "raw.d.ts"];
   for (const file of files) {
     fs.copyFileSync(resolve(BINARY_ROOT, file), resolve(packageRoot, file));
   }
@@ -11,8 +11,8 @@ use oxc_semantic::SemanticBuilder;
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 use oxc_tasks_common::{normalize_path, print_diff_in_terminal, BabelOptions};
 use oxc_transformer::{
-    ArrowFunctionsOptions, NullishCoalescingOperatorOptions, ReactJsxOptions, TransformOptions,
-    TransformTarget, Transformer,
+    ArrowFunctionsOptions, DecoratorsOptions, ModuleFormat, NullishCoalescingOperatorOptions,
+    ReactJsxOptions, ReactRefreshOptions, TransformOptions, TransformTarget, Transformer,
 };
 use serde::de::DeserializeOwned;
 use serde_json::Value;
@@ -88,12 +88,24 @@ pub trait TestCase {
         let options = self.options();
         TransformOptions {
             target: TransformTarget::ESNext,
+            module: if options.get_plugin("transform-modules-commonjs").is_some() {
+                ModuleFormat::CommonJs
+            } else {
+                ModuleFormat::Esm
+            },
             babel_8_breaking: options.babel_8_breaking,
             react_jsx: options
                 .get_plugin("transform-react-jsx")
                 .map(get_options::<ReactJsxOptions>),
+            react_refresh: options
+                .get_plugin("react-refresh/babel")
+                .map(get_options::<ReactRefreshOptions>),
+            decorators: options
+                .get_plugin("proposal-decorators")
+                .map(get_options::<DecoratorsOptions>),
             assumptions: options.assumptions,
             class_static_block: options.get_plugin("transform-class-static-block").is_some(),
+            class_properties: options.get_plugin("transform-class-properties").is_some(),
             instanceof: options.get_plugin("transform-instanceof").is_some(),
             function_name: options.get_plugin("transform-function-name").is_some(),
             arrow_functions: options
@@ -105,6 +117,7 @@ pub trait TestCase {
             nullish_coalescing_operator: options
                 .get_plugin("transform-nullish-coalescing-operator")
                 .map(get_options::<NullishCoalescingOperatorOptions>),
+            optional_chaining: options.get_plugin("transform-optional-chaining").is_some(),
             optional_catch_binding: options
                 .get_plugin("transform-optional-catch-binding")
                 .is_some(),